@@ -1,6 +1,7 @@
 //! Public keys associated with an account for n-signature authorization.
 
 use std::collections::BTreeMap;
+use std::io::Read;
 
 use borsh::{BorshDeserialize, BorshSerialize};
 use namada_core::collections::HashMap;
@@ -11,23 +12,28 @@ use namada_macros::BorshDeserializer;
 use namada_migrations::*;
 use serde::{Deserialize, Serialize};
 
+/// The weight of a public key that has no explicit weight. An account whose
+/// keys all have this weight uses its threshold as a number of signatures.
+pub const DEFAULT_PUBLIC_KEY_WEIGHT: u8 = 1;
+
 #[derive(
-    Debug,
-    Clone,
-    BorshSerialize,
-    BorshDeserialize,
-    BorshDeserializer,
-    Serialize,
-    Deserialize,
-    Default,
-    PartialEq,
+    Debug, Clone, BorshDeserializer, Serialize, Deserialize, Default, PartialEq,
 )]
-/// Holds the public key map data as a bimap for efficient querying
+/// Holds the public key map data as a bimap for efficient querying.
+///
+/// Its encoding starts with [`AccountPublicKeysMap::VERSION`] in place of
+/// the length of `pk_to_idx`, which can never be that long. The maps encoded
+/// before keys had weights, e.g. by older VPs, are thus still decoded, with
+/// the default weights.
 pub struct AccountPublicKeysMap {
     /// Hashmap from public key to index
     pub pk_to_idx: HashMap<common::PublicKey, u8>,
     /// Hashmap from index key to public key
     pub idx_to_pk: HashMap<u8, common::PublicKey>,
+    /// Hashmap from index key to the weight of the public key, for the keys
+    /// whose weight is not [`DEFAULT_PUBLIC_KEY_WEIGHT`]
+    #[serde(default)]
+    pub idx_to_weight: HashMap<u8, u8>,
 }
 
 impl FromIterator<common::PublicKey> for AccountPublicKeysMap {
@@ -56,11 +62,49 @@ impl FromIterator<common::PublicKey> for AccountPublicKeysMap {
         Self {
             pk_to_idx,
             idx_to_pk,
+            idx_to_weight: HashMap::new(),
+        }
+    }
+}
+
+impl BorshSerialize for AccountPublicKeysMap {
+    fn serialize<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> std::io::Result<()> {
+        Self::VERSION.serialize(writer)?;
+        self.pk_to_idx.serialize(writer)?;
+        self.idx_to_pk.serialize(writer)?;
+        self.idx_to_weight.serialize(writer)
+    }
+}
+
+impl BorshDeserialize for AccountPublicKeysMap {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+        let version = u32::deserialize_reader(reader)?;
+        if version != Self::VERSION {
+            // The legacy encoding, whose `pk_to_idx` length has already been
+            // read
+            let version = version.to_le_bytes();
+            let reader = &mut version.as_slice().chain(reader);
+            return Ok(Self {
+                pk_to_idx: BorshDeserialize::deserialize_reader(reader)?,
+                idx_to_pk: BorshDeserialize::deserialize_reader(reader)?,
+                idx_to_weight: HashMap::new(),
+            });
         }
+        Ok(Self {
+            pk_to_idx: BorshDeserialize::deserialize_reader(reader)?,
+            idx_to_pk: BorshDeserialize::deserialize_reader(reader)?,
+            idx_to_weight: BorshDeserialize::deserialize_reader(reader)?,
+        })
     }
 }
 
 impl AccountPublicKeysMap {
+    /// The version of the encoding of public key maps
+    pub const VERSION: u32 = u32::MAX;
+
     /// Retrieve a public key from the index
     pub fn get_public_key_from_index(
         &self,
@@ -77,6 +121,44 @@ impl AccountPublicKeysMap {
         self.pk_to_idx.get(public_key).cloned()
     }
 
+    /// Set the weights of the public keys at the given indices
+    pub fn with_weights(
+        mut self,
+        weights: impl IntoIterator<Item = (u8, u8)>,
+    ) -> Self {
+        for (index, weight) in weights {
+            if weight == DEFAULT_PUBLIC_KEY_WEIGHT {
+                self.idx_to_weight.remove(&index);
+            } else {
+                self.idx_to_weight.insert(index, weight);
+            }
+        }
+        self
+    }
+
+    /// Retrieve the weight of the public key at the given index
+    pub fn get_weight_from_index(&self, index: u8) -> u8 {
+        self.idx_to_weight
+            .get(&index)
+            .copied()
+            .unwrap_or(DEFAULT_PUBLIC_KEY_WEIGHT)
+    }
+
+    /// Sum the weights of the public keys at the given indices
+    pub fn signing_weight<'a>(
+        &self,
+        indices: impl IntoIterator<Item = &'a u8>,
+    ) -> u32 {
+        indices.into_iter().fold(0_u32, |acc, index| {
+            acc.saturating_add(u32::from(self.get_weight_from_index(*index)))
+        })
+    }
+
+    /// Sum the weights of all the public keys
+    pub fn total_weight(&self) -> u32 {
+        self.signing_weight(self.idx_to_pk.keys())
+    }
+
     /// Index the given set of secret keys
     pub fn index_secret_keys(
         &self,
@@ -91,3 +173,50 @@ impl AccountPublicKeysMap {
             .collect()
     }
 }
+
+#[cfg(test)]
+mod test_auth {
+    use namada_core::key::testing::{keypair_1, keypair_2, keypair_3};
+
+    use super::*;
+
+    #[test]
+    fn test_weighted_public_keys() {
+        let pks =
+            [keypair_1(), keypair_2(), keypair_3()].map(|sk| sk.to_public());
+        let map = AccountPublicKeysMap::from_iter(pks);
+
+        // without explicit weights, every key counts as one signature
+        assert_eq!(map.total_weight(), 3);
+        assert_eq!(map.signing_weight(&[0, 2]), 2);
+
+        let map = map.with_weights([(0, 2), (1, DEFAULT_PUBLIC_KEY_WEIGHT)]);
+        assert_eq!(map.get_weight_from_index(0), 2);
+        assert_eq!(map.get_weight_from_index(1), 1);
+        // default weights are not stored
+        assert_eq!(map.idx_to_weight.len(), 1);
+        assert_eq!(map.total_weight(), 4);
+        assert_eq!(map.signing_weight(&[0, 2]), 3);
+        assert_eq!(map.signing_weight(&[1, 2]), 2);
+    }
+
+    #[test]
+    fn test_public_keys_map_encoding() {
+        let pks =
+            [keypair_1(), keypair_2(), keypair_3()].map(|sk| sk.to_public());
+        let map = AccountPublicKeysMap::from_iter(pks).with_weights([(1, 3)]);
+
+        let encoded = borsh::to_vec(&map).unwrap();
+        assert_eq!(
+            AccountPublicKeysMap::try_from_slice(&encoded).unwrap(),
+            map
+        );
+
+        // a map encoded before keys had weights
+        let legacy = borsh::to_vec(&(&map.pk_to_idx, &map.idx_to_pk)).unwrap();
+        assert_eq!(
+            AccountPublicKeysMap::try_from_slice(&legacy).unwrap(),
+            map.with_weights([(1, DEFAULT_PUBLIC_KEY_WEIGHT)])
+        );
+    }
+}
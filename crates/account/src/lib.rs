@@ -20,11 +20,12 @@
 )]
 
 mod auth;
+mod recovery;
 mod storage;
 mod storage_key;
 mod types;
 
-pub use auth::AccountPublicKeysMap;
+pub use auth::{AccountPublicKeysMap, DEFAULT_PUBLIC_KEY_WEIGHT};
 use borsh::{BorshDeserialize, BorshSerialize};
 pub use namada_core::address::Address;
 pub use namada_core::hash::Hash;
@@ -33,6 +34,7 @@ pub use namada_core::storage::Key;
 use namada_macros::BorshDeserializer;
 #[cfg(feature = "migrations")]
 use namada_migrations::*;
+pub use recovery::*;
 use serde::{Deserialize, Serialize};
 pub use storage::*;
 pub use storage_key::*;
@@ -0,0 +1,528 @@
+//! Social recovery of established accounts. The owner of an account can opt
+//! in to let a set of guardians rotate the keys of the account, e.g. after the
+//! keys were lost. A rotation initiated by a threshold of the guardians only
+//! takes effect after a delay in epochs, during which the owner can still
+//! cancel it with the current keys.
+
+use std::collections::BTreeSet;
+
+use namada_core::address::Address;
+use namada_core::borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use namada_core::chain::Epoch;
+use namada_core::key::common;
+use namada_macros::BorshDeserializer;
+#[cfg(feature = "migrations")]
+use namada_migrations::*;
+use namada_storage::{Error, Result, StorageRead, StorageWrite};
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+/// The guardians that can recover the keys of an account.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSchema,
+    Serialize,
+    Deserialize,
+)]
+pub struct RecoveryConfig {
+    /// The addresses of the guardians
+    pub guardians: BTreeSet<Address>,
+    /// The minimum number of guardians that must approve a recovery
+    pub threshold: u8,
+    /// The number of epochs after which an initiated recovery can be
+    /// finalized
+    pub delay: u64,
+}
+
+impl RecoveryConfig {
+    /// Check that the threshold can be met by the guardians, none of which
+    /// is the recovered account itself.
+    pub fn validate(&self, owner: &Address) -> Result<()> {
+        if !matches!(owner, Address::Established(_)) {
+            return Err(Error::new_const(
+                "Only established accounts can opt in to social recovery",
+            ));
+        }
+        if self.guardians.contains(owner) {
+            return Err(Error::new_const(
+                "An account cannot be its own recovery guardian",
+            ));
+        }
+        if self.threshold == 0
+            || usize::from(self.threshold) > self.guardians.len()
+        {
+            return Err(Error::new_alloc(format!(
+                "The recovery threshold must be between 1 and the number of \
+                 guardians ({}), got {}",
+                self.guardians.len(),
+                self.threshold
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// A rotation of the keys of an account that was initiated by its guardians.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSchema,
+    Serialize,
+    Deserialize,
+)]
+pub struct PendingRecovery {
+    /// The new public keys of the account
+    pub public_keys: Vec<common::PublicKey>,
+    /// The new signature threshold of the account
+    pub threshold: u8,
+    /// The weights of the new public keys, by index. If empty, every key has
+    /// the default weight of 1.
+    pub weights: Vec<u8>,
+    /// The guardians that approved the recovery
+    pub approvals: BTreeSet<Address>,
+    /// The first epoch in which the recovery can be finalized
+    pub activation_epoch: Epoch,
+}
+
+impl PendingRecovery {
+    /// The public keys map of the account once the recovery is finalized.
+    pub fn public_keys_map(&self) -> AccountPublicKeysMap {
+        AccountPublicKeysMap::from_iter(self.public_keys.clone())
+            .with_weights((0..=u8::MAX).zip(self.weights.iter().copied()))
+    }
+
+    /// Check that the new keys of the account can meet its new threshold.
+    pub fn validate(&self) -> Result<()> {
+        if self.public_keys.is_empty() {
+            return Err(Error::new_const(
+                "A recovery must set at least one public key",
+            ));
+        }
+        if !self.weights.is_empty()
+            && (self.weights.len() != self.public_keys.len()
+                || self.weights.contains(&0))
+        {
+            return Err(Error::new_const(
+                "A recovery must set one non-zero weight for each public key",
+            ));
+        }
+        if self.threshold == 0
+            || u32::from(self.threshold) > self.public_keys_map().total_weight()
+        {
+            return Err(Error::new_alloc(format!(
+                "The recovered account threshold must be between 1 and the \
+                 total weight of its public keys, got {}",
+                self.threshold
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Read the social recovery config of an account.
+pub fn read_recovery_config<S>(
+    storage: &S,
+    owner: &Address,
+) -> Result<Option<RecoveryConfig>>
+where
+    S: StorageRead,
+{
+    storage.read(&recovery_config_key(owner))
+}
+
+/// Write the social recovery config of an account, or remove it to opt out of
+/// social recovery.
+pub fn write_recovery_config<S>(
+    storage: &mut S,
+    owner: &Address,
+    config: Option<RecoveryConfig>,
+) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let key = recovery_config_key(owner);
+    match config {
+        Some(config) => {
+            config.validate(owner)?;
+            storage.write(&key, config)
+        }
+        None => storage.delete(&key),
+    }
+}
+
+/// Read the pending social recovery of an account, if any.
+pub fn read_pending_recovery<S>(
+    storage: &S,
+    owner: &Address,
+) -> Result<Option<PendingRecovery>>
+where
+    S: StorageRead,
+{
+    storage.read(&pending_recovery_key(owner))
+}
+
+/// Initiate a recovery of the keys of an account, approved by the given
+/// guardians. The recovery can be finalized once the delay of the recovery
+/// config of the account has elapsed.
+///
+/// Returns an `Err` if the account has not opted in to social recovery, if a
+/// recovery is already pending, or if the approvals don't meet the threshold
+/// of the guardians.
+pub fn initiate_recovery<S>(
+    storage: &mut S,
+    owner: &Address,
+    approvals: BTreeSet<Address>,
+    public_keys: Vec<common::PublicKey>,
+    threshold: u8,
+    weights: Vec<u8>,
+) -> Result<PendingRecovery>
+where
+    S: StorageRead + StorageWrite,
+{
+    let config = read_recovery_config(storage, owner)?.ok_or_else(|| {
+        Error::new_alloc(format!("{owner} has not opted in to social recovery"))
+    })?;
+    if read_pending_recovery(storage, owner)?.is_some() {
+        return Err(Error::new_alloc(format!(
+            "A recovery of {owner} is already pending"
+        )));
+    }
+    if !approvals.is_subset(&config.guardians) {
+        return Err(Error::new_alloc(format!(
+            "Only the guardians of {owner} can approve its recovery"
+        )));
+    }
+    if approvals.len() < usize::from(config.threshold) {
+        return Err(Error::new_alloc(format!(
+            "The recovery of {owner} must be approved by at least {} \
+             guardians, got {}",
+            config.threshold,
+            approvals.len()
+        )));
+    }
+    let activation_epoch = storage
+        .get_block_epoch()?
+        .checked_add(config.delay)
+        .ok_or_else(|| Error::new_const("Overflowed in recovery delay"))?;
+    let recovery = PendingRecovery {
+        public_keys,
+        threshold,
+        weights,
+        approvals,
+        activation_epoch,
+    };
+    recovery.validate()?;
+    storage.write(&pending_recovery_key(owner), &recovery)?;
+    Ok(recovery)
+}
+
+/// Cancel the pending recovery of an account.
+pub fn cancel_recovery<S>(storage: &mut S, owner: &Address) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    if read_pending_recovery(storage, owner)?.is_none() {
+        return Err(Error::new_alloc(format!(
+            "There is no pending recovery of {owner}"
+        )));
+    }
+    storage.delete(&pending_recovery_key(owner))
+}
+
+/// Finalize the pending recovery of an account, replacing its public keys,
+/// their weights and its threshold.
+///
+/// Returns an `Err` if there is no pending recovery or if its delay has not
+/// elapsed yet.
+pub fn finalize_recovery<S>(storage: &mut S, owner: &Address) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let recovery = read_pending_recovery(storage, owner)?.ok_or_else(|| {
+        Error::new_alloc(format!("There is no pending recovery of {owner}"))
+    })?;
+    let epoch = storage.get_block_epoch()?;
+    if epoch < recovery.activation_epoch {
+        return Err(Error::new_alloc(format!(
+            "The recovery of {owner} can only be finalized from epoch {}",
+            recovery.activation_epoch
+        )));
+    }
+    clear_public_keys(storage, owner)?;
+    for (index, public_key) in (0..=u8::MAX).zip(&recovery.public_keys) {
+        set_public_key_at(storage, owner, public_key, index)?;
+    }
+    set_public_key_weights(storage, owner, &recovery.weights)?;
+    storage.write(&threshold_key(owner), recovery.threshold)?;
+    storage.delete(&pending_recovery_key(owner))
+}
+
+/// Check if a recovery of the keys of `owner` was validly initiated from the
+/// `pre` to the `post` state. The approvals of the recovery must be a
+/// subset of the `authorized_guardians`, i.e. the guardians that authorized
+/// the recovery in the tx.
+pub fn is_valid_recovery_initiation<PRE, POST>(
+    pre: &PRE,
+    post: &POST,
+    owner: &Address,
+    authorized_guardians: &BTreeSet<Address>,
+) -> Result<bool>
+where
+    PRE: StorageRead,
+    POST: StorageRead,
+{
+    if read_pending_recovery(pre, owner)?.is_some() {
+        return Ok(false);
+    }
+    let (Some(config), Some(recovery)) = (
+        read_recovery_config(pre, owner)?,
+        read_pending_recovery(post, owner)?,
+    ) else {
+        return Ok(false);
+    };
+    let min_activation_epoch = pre.get_block_epoch()?.checked_add(config.delay);
+    Ok(recovery.validate().is_ok()
+        && recovery.approvals.is_subset(&config.guardians)
+        && recovery.approvals.is_subset(authorized_guardians)
+        && recovery.approvals.len() >= usize::from(config.threshold)
+        && min_activation_epoch
+            .is_some_and(|epoch| recovery.activation_epoch >= epoch))
+}
+
+/// Check if the pending recovery of the keys of `owner` was finalized from
+/// the `pre` to the `post` state, i.e. if its delay has elapsed and the keys
+/// and threshold of the account were replaced by the recovered ones.
+pub fn is_valid_recovery_finalization<PRE, POST>(
+    pre: &PRE,
+    post: &POST,
+    owner: &Address,
+) -> Result<bool>
+where
+    PRE: StorageRead,
+    POST: StorageRead,
+{
+    let Some(recovery) = read_pending_recovery(pre, owner)? else {
+        return Ok(false);
+    };
+    if read_pending_recovery(post, owner)?.is_some()
+        || pre.get_block_epoch()? < recovery.activation_epoch
+    {
+        return Ok(false);
+    }
+    let is_keys_recovered =
+        public_keys_index_map(post, owner)? == recovery.public_keys_map();
+    let is_threshold_recovered =
+        threshold(post, owner)? == Some(recovery.threshold);
+    Ok(is_keys_recovered && is_threshold_recovered)
+}
+
+#[cfg(test)]
+mod test_recovery {
+    use namada_core::address::testing::{
+        established_address_1, established_address_2, established_address_3,
+    };
+    use namada_core::key::testing::{keypair_1, keypair_2, keypair_3};
+    use namada_storage::testing::TestStorage;
+
+    use super::*;
+
+    fn config(delay: u64) -> RecoveryConfig {
+        RecoveryConfig {
+            guardians: [established_address_2(), established_address_3()]
+                .into(),
+            threshold: 2,
+            delay,
+        }
+    }
+
+    #[test]
+    fn test_validate_recovery_config() {
+        let owner = established_address_1();
+        assert!(config(2).validate(&owner).is_ok());
+
+        let mut invalid = config(2);
+        invalid.threshold = 0;
+        assert!(invalid.validate(&owner).is_err());
+        invalid.threshold = 3;
+        assert!(invalid.validate(&owner).is_err());
+
+        let mut invalid = config(2);
+        invalid.guardians.insert(owner.clone());
+        assert!(invalid.validate(&owner).is_err());
+    }
+
+    #[test]
+    fn test_recovery_flow() {
+        let mut storage = TestStorage::default();
+        let owner = established_address_1();
+        let guardians = config(2).guardians;
+        init_account_storage(
+            &mut storage,
+            &owner,
+            &[keypair_1().to_public()],
+            1,
+        )
+        .unwrap();
+        let new_keys = vec![keypair_2().to_public(), keypair_3().to_public()];
+
+        // recovery requires opting in
+        let res = initiate_recovery(
+            &mut storage,
+            &owner,
+            guardians.clone(),
+            new_keys.clone(),
+            2,
+            vec![],
+        );
+        assert!(res.is_err());
+        write_recovery_config(&mut storage, &owner, Some(config(2))).unwrap();
+
+        // and a threshold of guardians
+        let res = initiate_recovery(
+            &mut storage,
+            &owner,
+            [established_address_2()].into(),
+            new_keys.clone(),
+            2,
+            vec![],
+        );
+        assert!(res.is_err());
+
+        // the new keys must meet the new threshold
+        let res = initiate_recovery(
+            &mut storage,
+            &owner,
+            guardians.clone(),
+            new_keys.clone(),
+            3,
+            vec![],
+        );
+        assert!(res.is_err());
+
+        let recovery = initiate_recovery(
+            &mut storage,
+            &owner,
+            guardians.clone(),
+            new_keys.clone(),
+            3,
+            vec![2, 1],
+        )
+        .unwrap();
+        assert_eq!(recovery.activation_epoch, Epoch(2));
+
+        // only one recovery can be pending
+        let res = initiate_recovery(
+            &mut storage,
+            &owner,
+            guardians.clone(),
+            new_keys.clone(),
+            1,
+            vec![],
+        );
+        assert!(res.is_err());
+
+        // the recovery can't be finalized before the delay has elapsed
+        storage.set_epoch(Epoch(1));
+        let res = finalize_recovery(&mut storage, &owner);
+        assert!(res.is_err());
+        assert_eq!(
+            public_keys(&storage, &owner).unwrap(),
+            vec![keypair_1().to_public()]
+        );
+
+        storage.set_epoch(Epoch(2));
+        finalize_recovery(&mut storage, &owner).unwrap();
+        assert_eq!(public_keys(&storage, &owner).unwrap(), new_keys);
+        assert_eq!(threshold(&storage, &owner).unwrap(), Some(3));
+        assert_eq!(
+            public_keys_index_map(&storage, &owner).unwrap(),
+            recovery.public_keys_map()
+        );
+        assert!(read_pending_recovery(&storage, &owner).unwrap().is_none());
+
+        // a pending recovery can be cancelled
+        initiate_recovery(
+            &mut storage,
+            &owner,
+            guardians,
+            vec![keypair_1().to_public()],
+            1,
+            vec![],
+        )
+        .unwrap();
+        cancel_recovery(&mut storage, &owner).unwrap();
+        assert!(read_pending_recovery(&storage, &owner).unwrap().is_none());
+        let res = cancel_recovery(&mut storage, &owner);
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_recovery_validation() {
+        let owner = established_address_1();
+        let guardians = config(2).guardians;
+        let new_keys = vec![keypair_2().to_public()];
+        let setup = |initiated: bool| {
+            let mut storage = TestStorage::default();
+            init_account_storage(
+                &mut storage,
+                &owner,
+                &[keypair_1().to_public()],
+                1,
+            )
+            .unwrap();
+            write_recovery_config(&mut storage, &owner, Some(config(2)))
+                .unwrap();
+            if initiated {
+                initiate_recovery(
+                    &mut storage,
+                    &owner,
+                    guardians.clone(),
+                    new_keys.clone(),
+                    1,
+                    vec![],
+                )
+                .unwrap();
+            }
+            storage
+        };
+
+        // the initiation must be authorized by the approving guardians
+        let (pre, post) = (setup(false), setup(true));
+        let is_valid_initiation = |authorized| {
+            is_valid_recovery_initiation(&pre, &post, &owner, authorized)
+                .unwrap()
+        };
+        assert!(is_valid_initiation(&guardians));
+        assert!(!is_valid_initiation(&[established_address_2()].into()));
+        assert!(!is_valid_recovery_finalization(&pre, &post, &owner).unwrap());
+
+        // the finalization must happen after the delay
+        let (mut pre, mut post) = (setup(true), setup(true));
+        post.set_epoch(Epoch(2));
+        finalize_recovery(&mut post, &owner).unwrap();
+        assert!(!is_valid_recovery_finalization(&pre, &post, &owner).unwrap());
+        pre.set_epoch(Epoch(2));
+        assert!(is_valid_recovery_finalization(&pre, &post, &owner).unwrap());
+        assert!(
+            !is_valid_recovery_initiation(&pre, &post, &owner, &guardians)
+                .unwrap()
+        );
+
+        // and it must set the recovered keys
+        set_public_key_at(&mut post, &owner, &keypair_3().to_public(), 1)
+            .unwrap();
+        assert!(!is_valid_recovery_finalization(&pre, &post, &owner).unwrap());
+    }
+}
@@ -1,7 +1,7 @@
 //! Cryptographic signature keys storage API
 
 use namada_core::storage;
-use namada_storage::{Result, ResultExt, StorageRead, StorageWrite};
+use namada_storage::{Error, Result, ResultExt, StorageRead, StorageWrite};
 
 use super::*;
 
@@ -38,6 +38,92 @@ where
     storage.write(&threshold_key, threshold)
 }
 
+/// Set the weights of the public keys of an account, by index. Any previous
+/// weights are cleared. If no weights are given, every key uses the default
+/// weight.
+///
+/// Returns an `Err` unless there is one non-zero weight for each public key
+/// of the account.
+pub fn set_public_key_weights<S>(
+    storage: &mut S,
+    owner: &Address,
+    weights: &[u8],
+) -> Result<()>
+where
+    S: StorageWrite + StorageRead,
+{
+    if !weights.is_empty() {
+        let total_pks = pks_handle(owner).len(storage)?;
+        if u64::try_from(weights.len()).ok() != Some(total_pks) {
+            return Err(Error::new_alloc(format!(
+                "Expected one weight for each of the {total_pks} public keys \
+                 of {owner}, got {}",
+                weights.len()
+            )));
+        }
+        if weights.contains(&0) {
+            return Err(Error::new_const(
+                "Public key weights must be non-zero",
+            ));
+        }
+    }
+    clear_public_key_weights(storage, owner)?;
+    for (index, weight) in weights.iter().enumerate() {
+        if *weight == DEFAULT_PUBLIC_KEY_WEIGHT {
+            continue;
+        }
+        let index = u8::try_from(index).into_storage_result()?;
+        weights_handle(owner).insert(storage, index, *weight)?;
+    }
+    Ok(())
+}
+
+/// Get the weights of the public keys associated with an account that don't
+/// use the default weight, by index
+pub fn public_key_weights<S>(
+    storage: &S,
+    owner: &Address,
+) -> Result<Vec<(u8, u8)>>
+where
+    S: StorageRead,
+{
+    weights_handle(owner).iter(storage)?.collect()
+}
+
+/// Check that every stored weight of the public keys of an account is
+/// non-zero and belongs to one of its public keys, and that the total weight
+/// of its public keys can meet its threshold.
+pub fn validate_public_key_weights<S>(
+    storage: &S,
+    owner: &Address,
+) -> Result<()>
+where
+    S: StorageRead,
+{
+    let total_pks = pks_handle(owner).len(storage)?;
+    let weights = public_key_weights(storage, owner)?;
+    if weights
+        .iter()
+        .any(|(index, weight)| *weight == 0 || u64::from(*index) >= total_pks)
+    {
+        return Err(Error::new_alloc(format!(
+            "Every public key weight of {owner} must be non-zero and belong \
+             to one of its {total_pks} public keys"
+        )));
+    }
+    let Some(threshold) = threshold(storage, owner)? else {
+        return Ok(());
+    };
+    let total_weight = public_keys_index_map(storage, owner)?.total_weight();
+    if u32::from(threshold) > total_weight {
+        return Err(Error::new_alloc(format!(
+            "The threshold {threshold} of {owner} exceeds the total weight \
+             {total_weight} of its public keys"
+        )));
+    }
+    Ok(())
+}
+
 /// Get the threshold associated with an account
 pub fn threshold<S>(storage: &S, owner: &Address) -> Result<Option<u8>>
 where
@@ -75,8 +161,9 @@ where
     S: StorageRead,
 {
     let public_keys = public_keys(storage, owner)?;
+    let weights = public_key_weights(storage, owner)?;
 
-    Ok(AccountPublicKeysMap::from_iter(public_keys))
+    Ok(AccountPublicKeysMap::from_iter(public_keys).with_weights(weights))
 }
 
 /// Check if a user account exists in storage
@@ -120,3 +207,65 @@ where
     }
     Ok(())
 }
+
+/// Clear the public key weights account subtorage space
+pub fn clear_public_key_weights<S>(
+    storage: &mut S,
+    owner: &Address,
+) -> Result<()>
+where
+    S: StorageWrite + StorageRead,
+{
+    let indices = weights_handle(owner)
+        .iter(storage)?
+        .map(|data| data.map(|(index, _weight)| index))
+        .collect::<Result<Vec<u8>>>()?;
+    for index in indices {
+        weights_handle(owner).remove(storage, &index)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test_storage {
+    use namada_core::address::testing::established_address_1;
+    use namada_core::key::testing::{keypair_1, keypair_2, keypair_3};
+    use namada_storage::testing::TestStorage;
+
+    use super::*;
+
+    #[test]
+    fn test_public_key_weights() {
+        let mut storage = TestStorage::default();
+        let owner = established_address_1();
+        let pks =
+            [keypair_1(), keypair_2(), keypair_3()].map(|sk| sk.to_public());
+        init_account_storage(&mut storage, &owner, &pks, 4).unwrap();
+
+        // the default weights can't meet the threshold
+        assert!(validate_public_key_weights(&storage, &owner).is_err());
+
+        // there must be one non-zero weight for each key
+        for weights in [&[2, 1][..], &[2, 1, 1, 1], &[2, 0, 2]] {
+            let result = set_public_key_weights(&mut storage, &owner, weights);
+            assert!(result.is_err());
+        }
+
+        set_public_key_weights(&mut storage, &owner, &[2, 1, 1]).unwrap();
+        assert_eq!(public_key_weights(&storage, &owner).unwrap(), [(0, 2)]);
+        assert!(validate_public_key_weights(&storage, &owner).is_ok());
+
+        // the threshold can't exceed the total weight
+        storage.write(&threshold_key(&owner), 5_u8).unwrap();
+        assert!(validate_public_key_weights(&storage, &owner).is_err());
+        storage.write(&threshold_key(&owner), 4_u8).unwrap();
+
+        // stored weights must be non-zero and belong to a key
+        weights_handle(&owner).insert(&mut storage, 1, 0).unwrap();
+        assert!(validate_public_key_weights(&storage, &owner).is_err());
+        weights_handle(&owner).insert(&mut storage, 1, 2).unwrap();
+        assert!(validate_public_key_weights(&storage, &owner).is_ok());
+        weights_handle(&owner).insert(&mut storage, 3, 2).unwrap();
+        assert!(validate_public_key_weights(&storage, &owner).is_err());
+    }
+}
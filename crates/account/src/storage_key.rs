@@ -9,8 +9,11 @@ use namada_storage::collections::{lazy_map, LazyCollection};
 #[derive(StorageKeys)]
 struct Keys {
     public_keys: &'static str,
+    public_key_weights: &'static str,
     threshold: &'static str,
     protocol_public_keys: &'static str,
+    recovery_config: &'static str,
+    pending_recovery: &'static str,
 }
 
 /// Obtain a storage key for user's public key.
@@ -46,6 +49,41 @@ pub fn is_pks_key(key: &storage::Key) -> Option<&Address> {
     }
 }
 
+/// Obtain a storage key prefix for the weights of user's public keys.
+pub fn weights_key_prefix(owner: &Address) -> storage::Key {
+    storage::Key {
+        segments: vec![
+            DbKeySeg::AddressSeg(owner.to_owned()),
+            DbKeySeg::StringSeg(Keys::VALUES.public_key_weights.to_string()),
+        ],
+    }
+}
+
+/// LazyMap handler for the weights of the user's public keys, indexed like
+/// the public keys. A missing weight means the default weight of 1.
+pub fn weights_handle(owner: &Address) -> LazyMap<u8, u8> {
+    LazyMap::open(weights_key_prefix(owner))
+}
+
+/// Check if the given storage key is a public key weight. If it is, returns
+/// the owner.
+pub fn is_weights_key(key: &storage::Key) -> Option<&Address> {
+    match &key.segments[..] {
+        [
+            DbKeySeg::AddressSeg(owner),
+            DbKeySeg::StringSeg(prefix),
+            DbKeySeg::StringSeg(data),
+            DbKeySeg::StringSeg(index),
+        ] if prefix.as_str() == Keys::VALUES.public_key_weights
+            && data.as_str() == lazy_map::DATA_SUBKEY
+            && index.parse::<u8>().is_ok() =>
+        {
+            Some(owner)
+        }
+        _ => None,
+    }
+}
+
 /// Check if the given storage key is a threshol key.
 pub fn is_threshold_key(key: &storage::Key) -> Option<&Address> {
     match &key.segments[..] {
@@ -89,3 +127,49 @@ pub fn is_protocol_pk_key(key: &storage::Key) -> Option<&Address> {
         _ => None,
     }
 }
+
+/// Obtain the storage key for the social recovery config of an account.
+pub fn recovery_config_key(owner: &Address) -> storage::Key {
+    storage::Key {
+        segments: vec![
+            DbKeySeg::AddressSeg(owner.to_owned()),
+            DbKeySeg::StringSeg(Keys::VALUES.recovery_config.to_string()),
+        ],
+    }
+}
+
+/// Check if the given storage key is a social recovery config key. If it is,
+/// returns the owner.
+pub fn is_recovery_config_key(key: &storage::Key) -> Option<&Address> {
+    match &key.segments[..] {
+        [DbKeySeg::AddressSeg(owner), DbKeySeg::StringSeg(key)]
+            if key.as_str() == Keys::VALUES.recovery_config =>
+        {
+            Some(owner)
+        }
+        _ => None,
+    }
+}
+
+/// Obtain the storage key for the pending social recovery of an account.
+pub fn pending_recovery_key(owner: &Address) -> storage::Key {
+    storage::Key {
+        segments: vec![
+            DbKeySeg::AddressSeg(owner.to_owned()),
+            DbKeySeg::StringSeg(Keys::VALUES.pending_recovery.to_string()),
+        ],
+    }
+}
+
+/// Check if the given storage key is a pending social recovery key. If it
+/// is, returns the owner.
+pub fn is_pending_recovery_key(key: &storage::Key) -> Option<&Address> {
+    match &key.segments[..] {
+        [DbKeySeg::AddressSeg(owner), DbKeySeg::StringSeg(key)]
+            if key.as_str() == Keys::VALUES.pending_recovery =>
+        {
+            Some(owner)
+        }
+        _ => None,
+    }
+}
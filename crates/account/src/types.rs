@@ -1,4 +1,8 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::Read;
+
 use namada_core::address::Address;
+use namada_core::borsh::schema::{self, Declaration, Definition, Fields};
 use namada_core::borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
 use namada_core::hash::Hash;
 use namada_core::key::common;
@@ -7,18 +11,16 @@ use namada_macros::BorshDeserializer;
 use namada_migrations::*;
 use serde::{Deserialize, Serialize};
 
-/// A tx data type to initialize a new established account
+use crate::RecoveryConfig;
+
+/// A tx data type to initialize a new established account.
+///
+/// Its encoding starts with [`InitAccount::VERSION`] in place of the length
+/// of the public keys, which can never be that long. The data of older txs,
+/// which had no weights, is thus still decoded, with the default weights.
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(
-    Debug,
-    Clone,
-    PartialEq,
-    BorshSerialize,
-    BorshDeserialize,
-    BorshDeserializer,
-    BorshSchema,
-    Serialize,
-    Deserialize,
+    Debug, Clone, PartialEq, BorshDeserializer, Serialize, Deserialize,
 )]
 pub struct InitAccount {
     /// Public keys to be written into the account's storage. This can be used
@@ -27,11 +29,185 @@ pub struct InitAccount {
     pub public_keys: Vec<common::PublicKey>,
     /// The VP code hash
     pub vp_code_hash: Hash,
-    /// The account signature threshold
+    /// The account signature threshold, i.e. the minimum total weight of
+    /// the public keys that must sign
     pub threshold: u8,
+    /// The weights of the public keys, by index. If empty, every key has the
+    /// default weight of 1.
+    pub weights: Vec<u8>,
 }
 
-/// A tx data type to update an account's validity predicate
+/// A tx data type to update an account's validity predicate.
+///
+/// Its encoding starts with the [`UpdateAccount::VERSION`] byte, which is
+/// never the tag of an [`Address`]. The data of older txs, which had no
+/// weights, is thus still decoded, with the default weights.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(
+    Debug, Clone, PartialEq, BorshDeserializer, Serialize, Deserialize,
+)]
+pub struct UpdateAccount {
+    /// An address of the account
+    pub addr: Address,
+    /// The new VP code hash
+    pub vp_code_hash: Option<Hash>,
+    /// Public keys to be written into the account's storage. This can be used
+    /// for signature verification of transactions for the newly created
+    /// account.
+    pub public_keys: Vec<common::PublicKey>,
+    /// The account signature threshold, i.e. the minimum total weight of
+    /// the public keys that must sign
+    pub threshold: Option<u8>,
+    /// The weights of the public keys, by index. If empty, every key has the
+    /// default weight of 1. The weights apply to the new public keys if any
+    /// are given, otherwise to the current public keys of the account.
+    pub weights: Vec<u8>,
+}
+
+impl InitAccount {
+    /// The version of the encoding of account initializations
+    pub const VERSION: u32 = u32::MAX;
+}
+
+impl BorshSerialize for InitAccount {
+    fn serialize<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> std::io::Result<()> {
+        Self::VERSION.serialize(writer)?;
+        self.public_keys.serialize(writer)?;
+        self.vp_code_hash.serialize(writer)?;
+        self.threshold.serialize(writer)?;
+        self.weights.serialize(writer)
+    }
+}
+
+impl BorshDeserialize for InitAccount {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+        let version = u32::deserialize_reader(reader)?;
+        if version != Self::VERSION {
+            // The legacy encoding, whose public keys length has already been
+            // read
+            let version = version.to_le_bytes();
+            let reader = &mut version.as_slice().chain(reader);
+            return Ok(Self {
+                public_keys: BorshDeserialize::deserialize_reader(reader)?,
+                vp_code_hash: BorshDeserialize::deserialize_reader(reader)?,
+                threshold: BorshDeserialize::deserialize_reader(reader)?,
+                weights: vec![],
+            });
+        }
+        Ok(Self {
+            public_keys: BorshDeserialize::deserialize_reader(reader)?,
+            vp_code_hash: BorshDeserialize::deserialize_reader(reader)?,
+            threshold: BorshDeserialize::deserialize_reader(reader)?,
+            weights: BorshDeserialize::deserialize_reader(reader)?,
+        })
+    }
+}
+
+impl BorshSchema for InitAccount {
+    fn add_definitions_recursively(
+        definitions: &mut BTreeMap<Declaration, Definition>,
+    ) {
+        u32::add_definitions_recursively(definitions);
+        Vec::<common::PublicKey>::add_definitions_recursively(definitions);
+        Hash::add_definitions_recursively(definitions);
+        u8::add_definitions_recursively(definitions);
+        Vec::<u8>::add_definitions_recursively(definitions);
+        let fields = Fields::NamedFields(vec![
+            ("version".into(), u32::declaration()),
+            (
+                "public_keys".into(),
+                Vec::<common::PublicKey>::declaration(),
+            ),
+            ("vp_code_hash".into(), Hash::declaration()),
+            ("threshold".into(), u8::declaration()),
+            ("weights".into(), Vec::<u8>::declaration()),
+        ]);
+        let definition = Definition::Struct { fields };
+        schema::add_definition(Self::declaration(), definition, definitions);
+    }
+
+    fn declaration() -> Declaration {
+        "InitAccount".into()
+    }
+}
+
+impl UpdateAccount {
+    /// The version of the encoding of account updates
+    pub const VERSION: u8 = u8::MAX;
+}
+
+impl BorshSerialize for UpdateAccount {
+    fn serialize<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> std::io::Result<()> {
+        Self::VERSION.serialize(writer)?;
+        self.addr.serialize(writer)?;
+        self.vp_code_hash.serialize(writer)?;
+        self.public_keys.serialize(writer)?;
+        self.threshold.serialize(writer)?;
+        self.weights.serialize(writer)
+    }
+}
+
+impl BorshDeserialize for UpdateAccount {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+        let version = u8::deserialize_reader(reader)?;
+        if version != Self::VERSION {
+            // The legacy encoding, whose address tag has already been read
+            let reader = &mut [version].as_slice().chain(reader);
+            return Ok(Self {
+                addr: BorshDeserialize::deserialize_reader(reader)?,
+                vp_code_hash: BorshDeserialize::deserialize_reader(reader)?,
+                public_keys: BorshDeserialize::deserialize_reader(reader)?,
+                threshold: BorshDeserialize::deserialize_reader(reader)?,
+                weights: vec![],
+            });
+        }
+        Ok(Self {
+            addr: BorshDeserialize::deserialize_reader(reader)?,
+            vp_code_hash: BorshDeserialize::deserialize_reader(reader)?,
+            public_keys: BorshDeserialize::deserialize_reader(reader)?,
+            threshold: BorshDeserialize::deserialize_reader(reader)?,
+            weights: BorshDeserialize::deserialize_reader(reader)?,
+        })
+    }
+}
+
+impl BorshSchema for UpdateAccount {
+    fn add_definitions_recursively(
+        definitions: &mut BTreeMap<Declaration, Definition>,
+    ) {
+        u8::add_definitions_recursively(definitions);
+        Address::add_definitions_recursively(definitions);
+        Option::<Hash>::add_definitions_recursively(definitions);
+        Vec::<common::PublicKey>::add_definitions_recursively(definitions);
+        Option::<u8>::add_definitions_recursively(definitions);
+        Vec::<u8>::add_definitions_recursively(definitions);
+        let fields = Fields::NamedFields(vec![
+            ("version".into(), u8::declaration()),
+            ("addr".into(), Address::declaration()),
+            ("vp_code_hash".into(), Option::<Hash>::declaration()),
+            (
+                "public_keys".into(),
+                Vec::<common::PublicKey>::declaration(),
+            ),
+            ("threshold".into(), Option::<u8>::declaration()),
+            ("weights".into(), Vec::<u8>::declaration()),
+        ]);
+        let definition = Definition::Struct { fields };
+        schema::add_definition(Self::declaration(), definition, definitions);
+    }
+
+    fn declaration() -> Declaration {
+        "UpdateAccount".into()
+    }
+}
+
+/// A tx data type to opt in to or out of the social recovery of an account
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(
     Debug,
@@ -44,17 +220,40 @@ pub struct InitAccount {
     Serialize,
     Deserialize,
 )]
-pub struct UpdateAccount {
+pub struct ConfigureRecovery {
     /// An address of the account
     pub addr: Address,
-    /// The new VP code hash
-    pub vp_code_hash: Option<Hash>,
-    /// Public keys to be written into the account's storage. This can be used
-    /// for signature verification of transactions for the newly created
-    /// account.
+    /// The new recovery config of the account, or `None` to opt out of
+    /// social recovery
+    pub config: Option<RecoveryConfig>,
+}
+
+/// A tx data type for the guardians of an account to initiate the recovery
+/// of its keys
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSchema,
+    Serialize,
+    Deserialize,
+)]
+pub struct InitiateRecovery {
+    /// An address of the recovered account
+    pub addr: Address,
+    /// The guardians that approve the recovery
+    pub guardians: BTreeSet<Address>,
+    /// The new public keys of the account
     pub public_keys: Vec<common::PublicKey>,
-    /// The account signature threshold
-    pub threshold: Option<u8>,
+    /// The new signature threshold of the account
+    pub threshold: u8,
+    /// The weights of the new public keys, by index. If empty, every key has
+    /// the default weight of 1.
+    pub weights: Vec<u8>,
 }
 
 #[allow(clippy::cast_possible_truncation)]
@@ -75,6 +274,7 @@ pub mod tests {
             public_keys in collection::vec(arb_common_pk(), 0..10),
         )(
             threshold in 0..=public_keys.len() as u8,
+            weights in collection::vec(1..=3u8, public_keys.len()),
             public_keys in Just(public_keys),
             vp_code_hash in arb_hash(),
         ) -> InitAccount {
@@ -82,6 +282,7 @@ pub mod tests {
                 public_keys,
                 vp_code_hash,
                 threshold,
+                weights,
             }
         }
     }
@@ -94,6 +295,7 @@ pub mod tests {
             addr in arb_non_internal_address(),
             vp_code_hash in option::of(arb_hash()),
             threshold in option::of(0..=public_keys.len() as u8),
+            weights in collection::vec(1..=3u8, public_keys.len()),
             public_keys in Just(public_keys),
         ) -> UpdateAccount {
             UpdateAccount {
@@ -101,7 +303,69 @@ pub mod tests {
                 vp_code_hash,
                 public_keys,
                 threshold,
+                weights,
             }
         }
     }
 }
+
+#[cfg(test)]
+mod test_types {
+    use namada_core::address::testing::established_address_1;
+    use namada_core::borsh::BorshSerializeExt;
+    use namada_core::key::testing::{keypair_1, keypair_2};
+
+    use super::*;
+
+    #[test]
+    fn test_init_account_encoding() {
+        let init = InitAccount {
+            public_keys: vec![keypair_1().to_public(), keypair_2().to_public()],
+            vp_code_hash: Hash::sha256(b"vp"),
+            threshold: 3,
+            weights: vec![2, 1],
+        };
+        let encoded = init.serialize_to_vec();
+        assert_eq!(InitAccount::try_from_slice(&encoded).unwrap(), init);
+
+        // the data of an older tx, without weights
+        let legacy = (&init.public_keys, &init.vp_code_hash, init.threshold)
+            .serialize_to_vec();
+        assert_eq!(
+            InitAccount::try_from_slice(&legacy).unwrap(),
+            InitAccount {
+                weights: vec![],
+                ..init
+            }
+        );
+    }
+
+    #[test]
+    fn test_update_account_encoding() {
+        let update = UpdateAccount {
+            addr: established_address_1(),
+            vp_code_hash: None,
+            public_keys: vec![keypair_1().to_public()],
+            threshold: Some(2),
+            weights: vec![2],
+        };
+        let encoded = update.serialize_to_vec();
+        assert_eq!(UpdateAccount::try_from_slice(&encoded).unwrap(), update);
+
+        // the data of an older tx, without weights
+        let legacy = (
+            &update.addr,
+            &update.vp_code_hash,
+            &update.public_keys,
+            update.threshold,
+        )
+            .serialize_to_vec();
+        assert_eq!(
+            UpdateAccount::try_from_slice(&legacy).unwrap(),
+            UpdateAccount {
+                weights: vec![],
+                ..update
+            }
+        );
+    }
+}
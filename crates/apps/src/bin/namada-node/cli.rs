@@ -58,6 +58,22 @@ pub fn main() -> Result<()> {
                 node::rollback(chain_ctx.config.ledger)
                     .wrap_err("Failed to rollback the Namada node")?;
             }
+            cmds::Ledger::Snapshot(cmds::LedgerSnapshot::Export(
+                cmds::LedgerSnapshotExport(args),
+            )) => {
+                let chain_ctx = ctx.take_chain_or_exit();
+                node::export_snapshot(chain_ctx.config.ledger, args).wrap_err(
+                    "Failed to export a snapshot of the Namada node",
+                )?;
+            }
+            cmds::Ledger::Snapshot(cmds::LedgerSnapshot::Import(
+                cmds::LedgerSnapshotImport(args),
+            )) => {
+                let chain_ctx = ctx.take_chain_or_exit();
+                node::import_snapshot(chain_ctx.config.ledger, args).wrap_err(
+                    "Failed to import a snapshot into the Namada node",
+                )?;
+            }
             cmds::Ledger::UpdateDB(cmds::LedgerUpdateDB(args)) => {
                 #[cfg(not(feature = "migrations"))]
                 {
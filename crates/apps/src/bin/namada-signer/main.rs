@@ -0,0 +1,49 @@
+//! Namada remote signer CLI.
+
+use color_eyre::eyre::{eyre, Result};
+use namada_apps_lib::cli::{self, cmds};
+use namada_apps_lib::logging;
+use namada_node::remote_signer::{self, LocalSigner};
+use tracing_subscriber::filter::LevelFilter;
+
+fn main() -> Result<()> {
+    // init error reporting
+    color_eyre::install()?;
+
+    // init logging
+    let _log_guard = logging::init_from_env_or(LevelFilter::INFO)?;
+
+    let (cmd, ctx) = cli::namada_signer_cli()?;
+    match cmd {
+        cmds::NamadaSigner::Run(cmds::SignerRun(args)) => {
+            let mut chain_ctx = ctx.take_chain_or_exit();
+            let data =
+                chain_ctx.wallet.take_validator_data().ok_or_else(|| {
+                    eyre!("Validator data must be stored in the wallet")
+                })?;
+            let chain_dir = chain_ctx.config.ledger.chain_dir();
+            let state_path = args
+                .state_path
+                .unwrap_or_else(|| chain_dir.join("signer_state"));
+            let auth_key = remote_signer::load_or_generate_key(
+                &args
+                    .auth_key_path
+                    .unwrap_or_else(|| chain_dir.join("signer_auth_key")),
+            )?;
+            tracing::info!("Running the signer of validator {}", data.address);
+            let signer = LocalSigner {
+                protocol_key: data.keys.protocol_keypair,
+                eth_bridge_key: data.keys.eth_bridge_keypair,
+            };
+            remote_signer::run_signer(
+                &args.listen,
+                data.address,
+                signer,
+                state_path,
+                auth_key,
+                args.node_public_key,
+            )?;
+        }
+    }
+    Ok(())
+}
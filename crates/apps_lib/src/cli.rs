@@ -216,6 +216,45 @@ pub mod cmds {
         }
     }
 
+    /// Used as top-level commands (`Cmd` instance) in `namadas` binary.
+    #[derive(Clone, Debug)]
+    pub enum NamadaSigner {
+        Run(SignerRun),
+    }
+
+    impl Cmd for NamadaSigner {
+        fn add_sub(app: App) -> App {
+            app.subcommand(SignerRun::def())
+        }
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            SubCmd::parse(matches).map(Self::Run)
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct SignerRun(pub args::SignerRun);
+
+    impl SubCmd for SignerRun {
+        const CMD: &'static str = "run";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::SignerRun::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Run a signer of a validator's vote extensions and \
+                     protocol transactions with the validator keys stored in \
+                     the wallet."
+                ))
+                .add_args::<args::SignerRun>()
+        }
+    }
+
     /// Used as top-level commands (`Cmd` instance) in `namadac` binary.
     /// Used as sub-commands (`SubCmd` instance) in `namada` binary.
     #[derive(Clone, Debug)]
@@ -235,6 +274,9 @@ pub mod cmds {
                 // Simple transactions
                 .subcommand(TxCustom::def().display_order(1))
                 .subcommand(TxTransparentTransfer::def().display_order(1))
+                .subcommand(TxApprove::def().display_order(1))
+                .subcommand(TxTransferFrom::def().display_order(1))
+                .subcommand(TxVestingTransfer::def().display_order(1))
                 .subcommand(TxShieldedTransfer::def().display_order(1))
                 .subcommand(TxShieldingTransfer::def().display_order(1))
                 .subcommand(TxUnshieldingTransfer::def().display_order(1))
@@ -242,9 +284,17 @@ pub mod cmds {
                 .subcommand(TxUpdateAccount::def().display_order(1))
                 .subcommand(TxInitAccount::def().display_order(1))
                 .subcommand(TxRevealPk::def().display_order(1))
+                .subcommand(TxConfigureRecovery::def().display_order(1))
+                .subcommand(TxInitiateRecovery::def().display_order(1))
+                .subcommand(TxCancelRecovery::def().display_order(1))
+                .subcommand(TxFinalizeRecovery::def().display_order(1))
                 // Governance transactions
                 .subcommand(TxInitProposal::def().display_order(1))
                 .subcommand(TxVoteProposal::def().display_order(1))
+                .subcommand(TxDelegateVotes::def().display_order(1))
+                .subcommand(TxDepositProposal::def().display_order(1))
+                .subcommand(TxCancelProposal::def().display_order(1))
+                .subcommand(TxAmendProposal::def().display_order(1))
                 // PoS transactions
                 .subcommand(TxBecomeValidator::def().display_order(2))
                 .subcommand(TxInitValidator::def().display_order(2))
@@ -256,11 +306,15 @@ pub mod cmds {
                 .subcommand(Withdraw::def().display_order(2))
                 .subcommand(Redelegate::def().display_order(2))
                 .subcommand(ClaimRewards::def().display_order(2))
+                .subcommand(AutoCompound::def().display_order(2))
+                .subcommand(LiquidBond::def().display_order(2))
+                .subcommand(LiquidUnbond::def().display_order(2))
                 .subcommand(TxCommissionRateChange::def().display_order(2))
                 .subcommand(TxChangeConsensusKey::def().display_order(2))
                 .subcommand(TxMetadataChange::def().display_order(2))
                 // Ethereum bridge transactions
                 .subcommand(AddToEthBridgePool::def().display_order(3))
+                .subcommand(BumpEthBridgePoolFee::def().display_order(3))
                 // PGF transactions
                 .subcommand(TxUpdateStewardCommission::def().display_order(4))
                 .subcommand(TxResignSteward::def().display_order(4))
@@ -269,20 +323,26 @@ pub mod cmds {
                 .subcommand(QueryNextEpochInfo::def().display_order(5))
                 .subcommand(QueryStatus::def().display_order(5))
                 .subcommand(QueryAccount::def().display_order(5))
+                .subcommand(QueryTxHistory::def().display_order(5))
                 .subcommand(QueryConversions::def().display_order(5))
                 .subcommand(QueryMaspRewardTokens::def().display_order(5))
                 .subcommand(QueryBlock::def().display_order(5))
                 .subcommand(QueryBalance::def().display_order(5))
+                .subcommand(QueryAllowance::def().display_order(5))
+                .subcommand(QueryVesting::def().display_order(5))
                 .subcommand(QueryRewardsEstimate::def().display_order(5))
                 .subcommand(QueryBonds::def().display_order(5))
                 .subcommand(QueryBondedStake::def().display_order(5))
                 .subcommand(QuerySlashes::def().display_order(5))
+                .subcommand(QuerySlashReport::def().display_order(5))
                 .subcommand(QueryDelegations::def().display_order(5))
                 .subcommand(QueryFindValidator::def().display_order(5))
                 .subcommand(QueryResult::def().display_order(5))
                 .subcommand(QueryRawBytes::def().display_order(5))
                 .subcommand(QueryProposal::def().display_order(5))
                 .subcommand(QueryProposalVotes::def().display_order(5))
+                .subcommand(QueryProposalDeposits::def().display_order(5))
+                .subcommand(QueryVoteDelegation::def().display_order(5))
                 .subcommand(QueryProposalResult::def().display_order(5))
                 .subcommand(QueryProtocolParameters::def().display_order(5))
                 .subcommand(QueryPgf::def().display_order(5))
@@ -306,6 +366,11 @@ pub mod cmds {
             let tx_custom = Self::parse_with_ctx(matches, TxCustom);
             let tx_transparent_transfer =
                 Self::parse_with_ctx(matches, TxTransparentTransfer);
+            let tx_approve = Self::parse_with_ctx(matches, TxApprove);
+            let tx_transfer_from =
+                Self::parse_with_ctx(matches, TxTransferFrom);
+            let tx_vesting_transfer =
+                Self::parse_with_ctx(matches, TxVestingTransfer);
             let tx_shielded_transfer =
                 Self::parse_with_ctx(matches, TxShieldedTransfer);
             let tx_shielding_transfer =
@@ -316,6 +381,14 @@ pub mod cmds {
             let tx_update_account =
                 Self::parse_with_ctx(matches, TxUpdateAccount);
             let tx_init_account = Self::parse_with_ctx(matches, TxInitAccount);
+            let tx_configure_recovery =
+                Self::parse_with_ctx(matches, TxConfigureRecovery);
+            let tx_initiate_recovery =
+                Self::parse_with_ctx(matches, TxInitiateRecovery);
+            let tx_cancel_recovery =
+                Self::parse_with_ctx(matches, TxCancelRecovery);
+            let tx_finalize_recovery =
+                Self::parse_with_ctx(matches, TxFinalizeRecovery);
             let tx_become_validator =
                 Self::parse_with_ctx(matches, TxBecomeValidator);
             let tx_init_validator =
@@ -331,6 +404,14 @@ pub mod cmds {
                 Self::parse_with_ctx(matches, TxInitProposal);
             let tx_vote_proposal =
                 Self::parse_with_ctx(matches, TxVoteProposal);
+            let tx_delegate_votes =
+                Self::parse_with_ctx(matches, TxDelegateVotes);
+            let tx_deposit_proposal =
+                Self::parse_with_ctx(matches, TxDepositProposal);
+            let tx_cancel_proposal =
+                Self::parse_with_ctx(matches, TxCancelProposal);
+            let tx_amend_proposal =
+                Self::parse_with_ctx(matches, TxAmendProposal);
             let tx_update_steward_commission =
                 Self::parse_with_ctx(matches, TxUpdateStewardCommission);
             let tx_resign_steward =
@@ -346,23 +427,32 @@ pub mod cmds {
             let withdraw = Self::parse_with_ctx(matches, Withdraw);
             let redelegate = Self::parse_with_ctx(matches, Redelegate);
             let claim_rewards = Self::parse_with_ctx(matches, ClaimRewards);
+            let auto_compound = Self::parse_with_ctx(matches, AutoCompound);
+            let liquid_bond = Self::parse_with_ctx(matches, LiquidBond);
+            let liquid_unbond = Self::parse_with_ctx(matches, LiquidUnbond);
             let query_epoch = Self::parse_with_ctx(matches, QueryEpoch);
             let query_next_epoch_info =
                 Self::parse_with_ctx(matches, QueryNextEpochInfo);
             let query_status = Self::parse_with_ctx(matches, QueryStatus);
             let query_account = Self::parse_with_ctx(matches, QueryAccount);
+            let query_tx_history =
+                Self::parse_with_ctx(matches, QueryTxHistory);
             let query_conversions =
                 Self::parse_with_ctx(matches, QueryConversions);
             let query_masp_reward_tokens =
                 Self::parse_with_ctx(matches, QueryMaspRewardTokens);
             let query_block = Self::parse_with_ctx(matches, QueryBlock);
             let query_balance = Self::parse_with_ctx(matches, QueryBalance);
+            let query_allowance = Self::parse_with_ctx(matches, QueryAllowance);
+            let query_vesting = Self::parse_with_ctx(matches, QueryVesting);
             let query_rewards_estimate =
                 Self::parse_with_ctx(matches, QueryRewardsEstimate);
             let query_bonds = Self::parse_with_ctx(matches, QueryBonds);
             let query_bonded_stake =
                 Self::parse_with_ctx(matches, QueryBondedStake);
             let query_slashes = Self::parse_with_ctx(matches, QuerySlashes);
+            let query_slash_report =
+                Self::parse_with_ctx(matches, QuerySlashReport);
             let query_rewards = Self::parse_with_ctx(matches, QueryRewards);
             let query_delegations =
                 Self::parse_with_ctx(matches, QueryDelegations);
@@ -379,6 +469,10 @@ pub mod cmds {
             let query_proposal = Self::parse_with_ctx(matches, QueryProposal);
             let query_proposal_votes =
                 Self::parse_with_ctx(matches, QueryProposalVotes);
+            let query_proposal_deposits =
+                Self::parse_with_ctx(matches, QueryProposalDeposits);
+            let query_vote_delegation =
+                Self::parse_with_ctx(matches, QueryVoteDelegation);
             let query_proposal_result =
                 Self::parse_with_ctx(matches, QueryProposalResult);
             let query_protocol_parameters =
@@ -391,21 +485,34 @@ pub mod cmds {
             let query_metadata = Self::parse_with_ctx(matches, QueryMetaData);
             let add_to_eth_bridge_pool =
                 Self::parse_with_ctx(matches, AddToEthBridgePool);
+            let bump_eth_bridge_pool_fee =
+                Self::parse_with_ctx(matches, BumpEthBridgePoolFee);
             let shielded_sync = Self::parse_with_ctx(matches, ShieldedSync);
             let gen_ibc_shielding =
                 Self::parse_with_ctx(matches, GenIbcShieldingTransfer);
             let utils = SubCmd::parse(matches).map(Self::WithoutContext);
             tx_custom
                 .or(tx_transparent_transfer)
+                .or(tx_approve)
+                .or(tx_transfer_from)
+                .or(tx_vesting_transfer)
                 .or(tx_shielded_transfer)
                 .or(tx_shielding_transfer)
                 .or(tx_unshielding_transfer)
                 .or(tx_ibc_transfer)
                 .or(tx_update_account)
                 .or(tx_init_account)
+                .or(tx_configure_recovery)
+                .or(tx_initiate_recovery)
+                .or(tx_cancel_recovery)
+                .or(tx_finalize_recovery)
                 .or(tx_reveal_pk)
                 .or(tx_init_proposal)
                 .or(tx_vote_proposal)
+                .or(tx_delegate_votes)
+                .or(tx_deposit_proposal)
+                .or(tx_cancel_proposal)
+                .or(tx_amend_proposal)
                 .or(tx_become_validator)
                 .or(tx_init_validator)
                 .or(tx_commission_rate_change)
@@ -419,7 +526,11 @@ pub mod cmds {
                 .or(withdraw)
                 .or(redelegate)
                 .or(claim_rewards)
+                .or(auto_compound)
+                .or(liquid_bond)
+                .or(liquid_unbond)
                 .or(add_to_eth_bridge_pool)
+                .or(bump_eth_bridge_pool_fee)
                 .or(tx_update_steward_commission)
                 .or(tx_resign_steward)
                 .or(query_epoch)
@@ -429,10 +540,13 @@ pub mod cmds {
                 .or(query_masp_reward_tokens)
                 .or(query_block)
                 .or(query_balance)
+                .or(query_allowance)
+                .or(query_vesting)
                 .or(query_rewards_estimate)
                 .or(query_bonds)
                 .or(query_bonded_stake)
                 .or(query_slashes)
+                .or(query_slash_report)
                 .or(query_rewards)
                 .or(query_delegations)
                 .or(query_find_validator)
@@ -440,6 +554,8 @@ pub mod cmds {
                 .or(query_raw_bytes)
                 .or(query_proposal)
                 .or(query_proposal_votes)
+                .or(query_proposal_deposits)
+                .or(query_vote_delegation)
                 .or(query_proposal_result)
                 .or(query_protocol_parameters)
                 .or(query_pgf)
@@ -450,6 +566,7 @@ pub mod cmds {
                 .or(query_native_supply)
                 .or(query_staking_rewards_rate)
                 .or(query_account)
+                .or(query_tx_history)
                 .or(shielded_sync)
                 .or(gen_ibc_shielding)
                 .or(utils)
@@ -491,6 +608,9 @@ pub mod cmds {
         // Ledger cmds
         TxCustom(TxCustom),
         TxTransparentTransfer(TxTransparentTransfer),
+        TxApprove(TxApprove),
+        TxTransferFrom(TxTransferFrom),
+        TxVestingTransfer(TxVestingTransfer),
         TxShieldedTransfer(TxShieldedTransfer),
         TxShieldingTransfer(TxShieldingTransfer),
         TxUnshieldingTransfer(TxUnshieldingTransfer),
@@ -498,6 +618,10 @@ pub mod cmds {
         QueryResult(QueryResult),
         TxUpdateAccount(TxUpdateAccount),
         TxInitAccount(TxInitAccount),
+        TxConfigureRecovery(TxConfigureRecovery),
+        TxInitiateRecovery(TxInitiateRecovery),
+        TxCancelRecovery(TxCancelRecovery),
+        TxFinalizeRecovery(TxFinalizeRecovery),
         TxBecomeValidator(TxBecomeValidator),
         TxInitValidator(TxInitValidator),
         TxCommissionRateChange(TxCommissionRateChange),
@@ -508,29 +632,41 @@ pub mod cmds {
         TxReactivateValidator(TxReactivateValidator),
         TxInitProposal(TxInitProposal),
         TxVoteProposal(TxVoteProposal),
+        TxDelegateVotes(TxDelegateVotes),
+        TxDepositProposal(TxDepositProposal),
+        TxCancelProposal(TxCancelProposal),
+        TxAmendProposal(TxAmendProposal),
         TxRevealPk(TxRevealPk),
         Bond(Bond),
         Unbond(Unbond),
         Withdraw(Withdraw),
         ClaimRewards(ClaimRewards),
+        AutoCompound(AutoCompound),
+        LiquidBond(LiquidBond),
+        LiquidUnbond(LiquidUnbond),
         Redelegate(Redelegate),
         AddToEthBridgePool(AddToEthBridgePool),
+        BumpEthBridgePoolFee(BumpEthBridgePoolFee),
         TxUpdateStewardCommission(TxUpdateStewardCommission),
         TxResignSteward(TxResignSteward),
         QueryEpoch(QueryEpoch),
         QueryNextEpochInfo(QueryNextEpochInfo),
         QueryStatus(QueryStatus),
         QueryAccount(QueryAccount),
+        QueryTxHistory(QueryTxHistory),
         QueryConversions(QueryConversions),
         QueryMaspRewardTokens(QueryMaspRewardTokens),
         QueryBlock(QueryBlock),
         QueryBalance(QueryBalance),
+        QueryAllowance(QueryAllowance),
+        QueryVesting(QueryVesting),
         QueryRewardsEstimate(QueryRewardsEstimate),
         QueryBonds(QueryBonds),
         QueryBondedStake(QueryBondedStake),
         QueryCommissionRate(QueryCommissionRate),
         QueryMetaData(QueryMetaData),
         QuerySlashes(QuerySlashes),
+        QuerySlashReport(QuerySlashReport),
         QueryDelegations(QueryDelegations),
         QueryTotalSupply(QueryTotalSupply),
         QueryEffNativeSupply(QueryEffNativeSupply),
@@ -539,6 +675,8 @@ pub mod cmds {
         QueryRawBytes(QueryRawBytes),
         QueryProposal(QueryProposal),
         QueryProposalVotes(QueryProposalVotes),
+        QueryProposalDeposits(QueryProposalDeposits),
+        QueryVoteDelegation(QueryVoteDelegation),
         QueryProposalResult(QueryProposalResult),
         QueryProtocolParameters(QueryProtocolParameters),
         QueryPgf(QueryPgf),
@@ -893,6 +1031,7 @@ pub mod cmds {
         UpdateDB(LedgerUpdateDB),
         QueryDB(LedgerQueryDB),
         RollBack(LedgerRollBack),
+        Snapshot(LedgerSnapshot),
     }
 
     impl SubCmd for Ledger {
@@ -907,12 +1046,14 @@ pub mod cmds {
                 let query_db = SubCmd::parse(matches).map(Self::QueryDB);
                 let rollback = SubCmd::parse(matches).map(Self::RollBack);
                 let run_until = SubCmd::parse(matches).map(Self::RunUntil);
+                let snapshot = SubCmd::parse(matches).map(Self::Snapshot);
                 run.or(reset)
                     .or(dump_db)
                     .or(update_db)
                     .or(query_db)
                     .or(rollback)
                     .or(run_until)
+                    .or(snapshot)
                     // The `run` command is the default if no sub-command given
                     .or(Some(Self::Run(LedgerRun(args::LedgerRun {
                         start_time: None,
@@ -936,6 +1077,7 @@ pub mod cmds {
                 .subcommand(LedgerUpdateDB::def())
                 .subcommand(LedgerQueryDB::def())
                 .subcommand(LedgerRollBack::def())
+                .subcommand(LedgerSnapshot::def())
         }
     }
 
@@ -1084,6 +1226,83 @@ pub mod cmds {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub enum LedgerSnapshot {
+        Export(LedgerSnapshotExport),
+        Import(LedgerSnapshotImport),
+    }
+
+    impl SubCmd for LedgerSnapshot {
+        const CMD: &'static str = "snapshot";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).and_then(|matches| {
+                let export = SubCmd::parse(matches).map(Self::Export);
+                let import = SubCmd::parse(matches).map(Self::Import);
+                export.or(import)
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .subcommand_required(true)
+                .arg_required_else_help(true)
+                .about(wrap!(
+                    "Offline DB snapshot sub-commands. The node must be \
+                     stopped."
+                ))
+                .subcommand(LedgerSnapshotExport::def())
+                .subcommand(LedgerSnapshotImport::def())
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct LedgerSnapshotExport(pub args::LedgerSnapshotExport);
+
+    impl SubCmd for LedgerSnapshotExport {
+        const CMD: &'static str = "export";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::LedgerSnapshotExport::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Export a snapshot of Namada ledger node's DB, in the \
+                     same chunked format that is served over state sync."
+                ))
+                .add_args::<args::LedgerSnapshotExport>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct LedgerSnapshotImport(pub args::LedgerSnapshotImport);
+
+    impl SubCmd for LedgerSnapshotImport {
+        const CMD: &'static str = "import";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::LedgerSnapshotImport::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Import an exported snapshot into Namada ledger node's \
+                     DB. The state of the snapshot is verified against a \
+                     trusted app hash before the DB is restored. The node \
+                     must not have an existing DB and CometBFT's state must \
+                     be bootstrapped separately."
+                ))
+                .add_args::<args::LedgerSnapshotImport>()
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub enum Config {
         Gen(ConfigGen),
@@ -1212,6 +1431,62 @@ pub mod cmds {
         }
     }
 
+    #[derive(Debug, Clone)]
+    pub struct QueryProposalDeposits(
+        pub args::QueryProposalDeposits<args::CliTypes>,
+    );
+
+    impl SubCmd for QueryProposalDeposits {
+        const CMD: &'static str = "query-proposal-deposits";
+
+        fn parse(matches: &ArgMatches) -> Option<Self>
+        where
+            Self: Sized,
+        {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                QueryProposalDeposits(args::QueryProposalDeposits::parse(
+                    matches,
+                ))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Query the deposits locked in a proposal by each \
+                     depositor."
+                ))
+                .add_args::<args::QueryProposalDeposits<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct QueryVoteDelegation(
+        pub args::QueryVoteDelegation<args::CliTypes>,
+    );
+
+    impl SubCmd for QueryVoteDelegation {
+        const CMD: &'static str = "query-vote-delegation";
+
+        fn parse(matches: &ArgMatches) -> Option<Self>
+        where
+            Self: Sized,
+        {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                QueryVoteDelegation(args::QueryVoteDelegation::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Query the governance representative and the constituents \
+                     of an address."
+                ))
+                .add_args::<args::QueryVoteDelegation<args::CliTypes>>()
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct QueryProposal(pub args::QueryProposal<args::CliTypes>);
 
@@ -1349,6 +1624,78 @@ pub mod cmds {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct TxApprove(pub args::TxApprove<crate::cli::args::CliTypes>);
+
+    impl SubCmd for TxApprove {
+        const CMD: &'static str = "approve";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| TxApprove(args::TxApprove::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Approve a spender to transfer some of your tokens. This \
+                     replaces any previous allowance of the spender, and a \
+                     zero amount revokes it."
+                ))
+                .add_args::<args::TxApprove<crate::cli::args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct TxTransferFrom(
+        pub args::TxTransferFrom<crate::cli::args::CliTypes>,
+    );
+
+    impl SubCmd for TxTransferFrom {
+        const CMD: &'static str = "transfer-from";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                TxTransferFrom(args::TxTransferFrom::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Transfer the tokens of an owner that approved you as a \
+                     spender, from your allowance."
+                ))
+                .add_args::<args::TxTransferFrom<crate::cli::args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct TxVestingTransfer(
+        pub args::TxVestingTransfer<crate::cli::args::CliTypes>,
+    );
+
+    impl SubCmd for TxVestingTransfer {
+        const CMD: &'static str = "vesting-transfer";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                TxVestingTransfer(args::TxVestingTransfer::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Send a transparent transfer of tokens that are locked in \
+                     the balance of the target until they unlock according \
+                     to a vesting schedule."
+                ))
+                .add_args::<args::TxVestingTransfer<crate::cli::args::CliTypes>>()
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct TxShieldedTransfer(
         pub args::TxShieldedTransfer<crate::cli::args::CliTypes>,
@@ -1455,6 +1802,100 @@ pub mod cmds {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct TxConfigureRecovery(
+        pub args::TxConfigureRecovery<args::CliTypes>,
+    );
+
+    impl SubCmd for TxConfigureRecovery {
+        const CMD: &'static str = "configure-recovery";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                TxConfigureRecovery(args::TxConfigureRecovery::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Send a signed transaction to set the guardians that can \
+                     recover the keys of an account. Without guardians, the \
+                     account opts out of social recovery."
+                ))
+                .add_args::<args::TxConfigureRecovery<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct TxInitiateRecovery(pub args::TxInitiateRecovery<args::CliTypes>);
+
+    impl SubCmd for TxInitiateRecovery {
+        const CMD: &'static str = "initiate-recovery";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                TxInitiateRecovery(args::TxInitiateRecovery::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Send a transaction signed by the guardians of an account \
+                     to rotate its keys. The rotation can be finalized after \
+                     the recovery delay of the account, until which it can be \
+                     cancelled with the current keys."
+                ))
+                .add_args::<args::TxInitiateRecovery<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct TxCancelRecovery(pub args::TxCancelRecovery<args::CliTypes>);
+
+    impl SubCmd for TxCancelRecovery {
+        const CMD: &'static str = "cancel-recovery";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                TxCancelRecovery(args::TxCancelRecovery::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Send a signed transaction to cancel the pending recovery \
+                     of an account."
+                ))
+                .add_args::<args::TxCancelRecovery<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct TxFinalizeRecovery(pub args::TxFinalizeRecovery<args::CliTypes>);
+
+    impl SubCmd for TxFinalizeRecovery {
+        const CMD: &'static str = "finalize-recovery";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                TxFinalizeRecovery(args::TxFinalizeRecovery::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Send a transaction to finalize the pending recovery of \
+                     an account once its delay has elapsed. The transaction \
+                     can be signed by anyone paying its fees."
+                ))
+                .add_args::<args::TxFinalizeRecovery<args::CliTypes>>()
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct TxInitAccount(pub args::TxInitAccount<args::CliTypes>);
 
@@ -1698,84 +2139,150 @@ pub mod cmds {
     }
 
     #[derive(Clone, Debug)]
-    pub struct Redelegate(pub args::Redelegate<args::CliTypes>);
+    pub struct AutoCompound(pub args::AutoCompound<args::CliTypes>);
 
-    impl SubCmd for Redelegate {
-        const CMD: &'static str = "redelegate";
+    impl SubCmd for AutoCompound {
+        const CMD: &'static str = "auto-compound";
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
             matches
                 .subcommand_matches(Self::CMD)
-                .map(|matches| Redelegate(args::Redelegate::parse(matches)))
+                .map(|matches| AutoCompound(args::AutoCompound::parse(matches)))
         }
 
         fn def() -> App {
             App::new(Self::CMD)
                 .about(wrap!(
-                    "Redelegate bonded tokens from one validator to another."
+                    "Enable or disable the automatic re-bonding of the \
+                     rewards of a bond at every epoch."
                 ))
-                .add_args::<args::Redelegate<args::CliTypes>>()
+                .add_args::<args::AutoCompound<args::CliTypes>>()
         }
     }
 
     #[derive(Clone, Debug)]
-    pub struct QueryEpoch(pub args::Query<args::CliTypes>);
+    pub struct LiquidBond(pub args::LiquidBond<args::CliTypes>);
 
-    impl SubCmd for QueryEpoch {
-        const CMD: &'static str = "epoch";
+    impl SubCmd for LiquidBond {
+        const CMD: &'static str = "liquid-bond";
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
             matches
                 .subcommand_matches(Self::CMD)
-                .map(|matches| QueryEpoch(args::Query::parse(matches)))
+                .map(|matches| LiquidBond(args::LiquidBond::parse(matches)))
         }
 
         fn def() -> App {
             App::new(Self::CMD)
-                .about(wrap!("Query the epoch of the last committed block."))
-                .add_args::<args::Query<args::CliTypes>>()
+                .about(wrap!(
+                    "Bond tokens to the liquid staking pool of a validator in \
+                     exchange for transferable shares of the pool."
+                ))
+                .add_args::<args::LiquidBond<args::CliTypes>>()
         }
     }
 
     #[derive(Clone, Debug)]
-    pub struct QueryNextEpochInfo(pub args::Query<args::CliTypes>);
+    pub struct LiquidUnbond(pub args::LiquidUnbond<args::CliTypes>);
 
-    impl SubCmd for QueryNextEpochInfo {
-        const CMD: &'static str = "next-epoch-info";
+    impl SubCmd for LiquidUnbond {
+        const CMD: &'static str = "liquid-unbond";
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
             matches
                 .subcommand_matches(Self::CMD)
-                .map(|matches| QueryNextEpochInfo(args::Query::parse(matches)))
+                .map(|matches| LiquidUnbond(args::LiquidUnbond::parse(matches)))
         }
 
         fn def() -> App {
             App::new(Self::CMD)
                 .about(wrap!(
-                    "Query some info to help discern when the next epoch will \
-                     begin."
+                    "Redeem liquid staking shares of a validator, unbonding \
+                     the tokens that they are worth from its pool."
                 ))
-                .add_args::<args::Query<args::CliTypes>>()
+                .add_args::<args::LiquidUnbond<args::CliTypes>>()
         }
     }
 
     #[derive(Clone, Debug)]
-    pub struct QueryStatus(pub args::Query<args::CliTypes>);
+    pub struct Redelegate(pub args::Redelegate<args::CliTypes>);
 
-    impl SubCmd for QueryStatus {
-        const CMD: &'static str = "status";
+    impl SubCmd for Redelegate {
+        const CMD: &'static str = "redelegate";
 
         fn parse(matches: &ArgMatches) -> Option<Self> {
             matches
                 .subcommand_matches(Self::CMD)
-                .map(|matches| QueryStatus(args::Query::parse(matches)))
+                .map(|matches| Redelegate(args::Redelegate::parse(matches)))
         }
 
         fn def() -> App {
             App::new(Self::CMD)
-                .about(wrap!("Query the node's status."))
-                .add_args::<args::Query<args::CliTypes>>()
-        }
+                .about(wrap!(
+                    "Redelegate bonded tokens from one validator to another."
+                ))
+                .add_args::<args::Redelegate<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct QueryEpoch(pub args::Query<args::CliTypes>);
+
+    impl SubCmd for QueryEpoch {
+        const CMD: &'static str = "epoch";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| QueryEpoch(args::Query::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!("Query the epoch of the last committed block."))
+                .add_args::<args::Query<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct QueryNextEpochInfo(pub args::Query<args::CliTypes>);
+
+    impl SubCmd for QueryNextEpochInfo {
+        const CMD: &'static str = "next-epoch-info";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| QueryNextEpochInfo(args::Query::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Query some info to help discern when the next epoch will \
+                     begin."
+                ))
+                .add_args::<args::Query<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct QueryStatus(pub args::Query<args::CliTypes>);
+
+    impl SubCmd for QueryStatus {
+        const CMD: &'static str = "status";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| QueryStatus(args::Query::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!("Query the node's status."))
+                .add_args::<args::Query<args::CliTypes>>()
+        }
     }
 
     #[derive(Clone, Debug)]
@@ -1800,6 +2307,29 @@ pub mod cmds {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct QueryTxHistory(pub args::QueryTxHistory<args::CliTypes>);
+
+    impl SubCmd for QueryTxHistory {
+        const CMD: &'static str = "query-history";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                QueryTxHistory(args::QueryTxHistory::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Query the history of the transactions that touched an \
+                     address. Only available on nodes with the tx history \
+                     index enabled."
+                ))
+                .add_args::<args::QueryTxHistory<args::CliTypes>>()
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct QueryConversions(pub args::QueryConversions<args::CliTypes>);
 
@@ -2044,6 +2574,32 @@ pub mod cmds {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct QuerySlashReport(pub args::QuerySlashReport<args::CliTypes>);
+
+    impl SubCmd for QuerySlashReport {
+        const CMD: &'static str = "query-slash-report";
+
+        fn parse(matches: &ArgMatches) -> Option<Self>
+        where
+            Self: Sized,
+        {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                QuerySlashReport(args::QuerySlashReport::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Query the evidence and the computation of a PoS slash, \
+                     explaining the rate applied to each bond and \
+                     redelegation."
+                ))
+                .add_args::<args::QuerySlashReport<args::CliTypes>>()
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct QueryRewards(pub args::QueryRewards<args::CliTypes>);
 
@@ -2090,6 +2646,50 @@ pub mod cmds {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct QueryAllowance(pub args::QueryAllowance<args::CliTypes>);
+
+    impl SubCmd for QueryAllowance {
+        const CMD: &'static str = "query-allowance";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                QueryAllowance(args::QueryAllowance::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Query the allowance of a spender to transfer the tokens \
+                     of an owner."
+                ))
+                .add_args::<args::QueryAllowance<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct QueryVesting(pub args::QueryVesting<args::CliTypes>);
+
+    impl SubCmd for QueryVesting {
+        const CMD: &'static str = "query-vesting";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| QueryVesting(args::QueryVesting::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Query the locked and spendable parts of a token balance \
+                     that is subject to vesting schedules."
+                ))
+                .add_args::<args::QueryVesting<args::CliTypes>>()
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct QueryTotalSupply(pub args::QueryTotalSupply<args::CliTypes>);
 
@@ -2387,6 +2987,110 @@ pub mod cmds {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct TxDelegateVotes(pub args::DelegateVotes<args::CliTypes>);
+
+    impl SubCmd for TxDelegateVotes {
+        const CMD: &'static str = "delegate-votes";
+
+        fn parse(matches: &ArgMatches) -> Option<Self>
+        where
+            Self: Sized,
+        {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                TxDelegateVotes(args::DelegateVotes::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Delegate the governance votes of an address to a \
+                     representative, or revoke the delegation. The change is \
+                     effective from the next epoch."
+                ))
+                .add_args::<args::DelegateVotes<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct TxDepositProposal(pub args::DepositProposal<args::CliTypes>);
+
+    impl SubCmd for TxDepositProposal {
+        const CMD: &'static str = "deposit-proposal";
+
+        fn parse(matches: &ArgMatches) -> Option<Self>
+        where
+            Self: Sized,
+        {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                TxDepositProposal(args::DepositProposal::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Deposit funds to a governance proposal that has not yet \
+                     reached the minimum proposal fund. Deposits are accepted \
+                     until the voting start epoch of the proposal."
+                ))
+                .add_args::<args::DepositProposal<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct TxCancelProposal(pub args::CancelProposal<args::CliTypes>);
+
+    impl SubCmd for TxCancelProposal {
+        const CMD: &'static str = "cancel-proposal";
+
+        fn parse(matches: &ArgMatches) -> Option<Self>
+        where
+            Self: Sized,
+        {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                TxCancelProposal(args::CancelProposal::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Cancel a governance proposal before its voting start \
+                     epoch. Only a part of the author's deposit, set by a \
+                     governance parameter, is refunded, while other \
+                     depositors are refunded in full."
+                ))
+                .add_args::<args::CancelProposal<args::CliTypes>>()
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct TxAmendProposal(pub args::AmendProposal<args::CliTypes>);
+
+    impl SubCmd for TxAmendProposal {
+        const CMD: &'static str = "amend-proposal";
+
+        fn parse(matches: &ArgMatches) -> Option<Self>
+        where
+            Self: Sized,
+        {
+            matches.subcommand_matches(Self::CMD).map(|matches| {
+                TxAmendProposal(args::AmendProposal::parse(matches))
+            })
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Replace the content of a governance proposal before its \
+                     voting start epoch."
+                ))
+                .add_args::<args::AmendProposal<args::CliTypes>>()
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct TxRevealPk(pub args::RevealPk<args::CliTypes>);
 
@@ -3008,6 +3712,31 @@ pub mod cmds {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct BumpEthBridgePoolFee(
+        pub args::BumpBridgePoolFee<args::CliTypes>,
+    );
+
+    impl SubCmd for BumpEthBridgePoolFee {
+        const CMD: &'static str = "bump-bridge-pool-fee";
+
+        fn parse(matches: &ArgMatches) -> Option<Self> {
+            matches
+                .subcommand_matches(Self::CMD)
+                .map(|matches| Self(args::BumpBridgePoolFee::parse(matches)))
+        }
+
+        fn def() -> App {
+            App::new(Self::CMD)
+                .about(wrap!(
+                    "Increase the relayer fee of a transfer in the Ethereum \
+                     Bridge pool."
+                ))
+                .arg_required_else_help(true)
+                .add_args::<args::BumpBridgePoolFee<args::CliTypes>>()
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct ConstructProof(pub args::BridgePoolProof<args::CliTypes>);
 
@@ -3376,15 +4105,21 @@ pub mod args {
     use namada_sdk::token::NATIVE_MAX_DECIMAL_PLACES;
     use namada_sdk::tx::data::GasLimit;
     pub use namada_sdk::tx::{
+        TX_AMEND_PROPOSAL_WASM, TX_APPROVE_WASM, TX_AUTO_COMPOUND_WASM,
         TX_BECOME_VALIDATOR_WASM, TX_BOND_WASM, TX_BRIDGE_POOL_WASM,
+        TX_CANCEL_PROPOSAL_WASM, TX_CANCEL_RECOVERY_WASM,
         TX_CHANGE_COMMISSION_WASM, TX_CHANGE_CONSENSUS_KEY_WASM,
         TX_CHANGE_METADATA_WASM, TX_CLAIM_REWARDS_WASM,
-        TX_DEACTIVATE_VALIDATOR_WASM, TX_IBC_WASM, TX_INIT_ACCOUNT_WASM,
-        TX_INIT_PROPOSAL, TX_REACTIVATE_VALIDATOR_WASM, TX_REDELEGATE_WASM,
-        TX_RESIGN_STEWARD, TX_REVEAL_PK, TX_TRANSFER_WASM, TX_UNBOND_WASM,
+        TX_CONFIGURE_RECOVERY_WASM, TX_DEACTIVATE_VALIDATOR_WASM,
+        TX_DELEGATE_VOTES_WASM, TX_DEPOSIT_PROPOSAL_WASM,
+        TX_FINALIZE_RECOVERY_WASM, TX_IBC_WASM, TX_INITIATE_RECOVERY_WASM,
+        TX_INIT_ACCOUNT_WASM, TX_INIT_PROPOSAL, TX_LIQUID_BOND_WASM,
+        TX_LIQUID_UNBOND_WASM, TX_REACTIVATE_VALIDATOR_WASM,
+        TX_REDELEGATE_WASM, TX_RESIGN_STEWARD, TX_REVEAL_PK,
+        TX_TRANSFER_FROM_WASM, TX_TRANSFER_WASM, TX_UNBOND_WASM,
         TX_UNJAIL_VALIDATOR_WASM, TX_UPDATE_ACCOUNT_WASM,
-        TX_UPDATE_STEWARD_COMMISSION, TX_VOTE_PROPOSAL, TX_WITHDRAW_WASM,
-        VP_USER_WASM,
+        TX_UPDATE_STEWARD_COMMISSION, TX_VESTING_TRANSFER_WASM,
+        TX_VOTE_PROPOSAL, TX_WITHDRAW_WASM, VP_USER_WASM,
     };
     use namada_sdk::{token, DEFAULT_GAS_LIMIT};
 
@@ -3393,7 +4128,7 @@ pub mod args {
     use super::{ArgGroup, ArgMatches};
     use crate::client::utils::PRE_GENESIS_DIR;
     use crate::config::genesis::AddrOrPk;
-    use crate::config::{self, Action, ActionAtHeight};
+    use crate::config::{self, Action, ActionAtHeight, SignerEndpoint};
     use crate::tendermint::Timeout;
     use crate::tendermint_rpc::Url;
     use crate::wrap;
@@ -3422,6 +4157,8 @@ pub mod args {
     pub const BLOCK_HEIGHT: Arg<BlockHeight> = arg("block-height");
     pub const BLOCK_HEIGHT_OPT: ArgOpt<BlockHeight> = arg_opt("height");
     pub const BLOCK_HEIGHT_TO_OPT: ArgOpt<BlockHeight> = arg_opt("to-height");
+    pub const BRIDGE_POOL_EXPIRY: ArgOpt<BlockHeight> =
+        arg_opt("expiry-height");
     pub const BRIDGE_POOL_GAS_AMOUNT: ArgDefault<token::DenominatedAmount> =
         arg_default(
             "pool-gas-amount",
@@ -3441,11 +4178,13 @@ pub mod args {
         DefaultFn(|| "".parse().unwrap()),
     );
     pub const BRIDGE_POOL_TARGET: Arg<EthAddress> = arg("target");
+    pub const BRIDGE_POOL_TRANSFER: Arg<KeccakHash> = arg("transfer-hash");
     pub const BROADCAST_ONLY: ArgFlag = flag("broadcast-only");
     pub const CHAIN_ID: Arg<ChainId> = arg("chain-id");
     pub const CHAIN_ID_OPT: ArgOpt<ChainId> = CHAIN_ID.opt();
     pub const CHAIN_ID_PREFIX: Arg<ChainIdPrefix> = arg("chain-prefix");
     pub const CHANNEL_ID: Arg<ChannelId> = arg("channel-id");
+    pub const CLIFF_EPOCH: ArgOpt<Epoch> = arg_opt("cliff-epoch");
     pub const CODE_PATH: Arg<PathBuf> = arg("code-path");
     pub const CODE_PATH_OPT: ArgOpt<PathBuf> = CODE_PATH.opt();
     pub const COMMISSION_RATE: Arg<Dec> = arg("commission-rate");
@@ -3471,6 +4210,7 @@ pub mod args {
         DefaultFn(|| storage::SUBSPACE_CF.to_string()),
     );
     pub const DECRYPT: ArgFlag = flag("decrypt");
+    pub const DISABLE: ArgFlag = flag("disable");
     pub const DESCRIPTION_OPT: ArgOpt<String> = arg_opt("description");
     pub const DISPOSABLE_SIGNING_KEY: ArgFlag = flag("disposable-gas-payer");
     pub const DESTINATION_VALIDATOR: Arg<WalletAddress> =
@@ -3482,6 +4222,7 @@ pub mod args {
     pub const DUMP_TX: ArgFlag = flag("dump-tx");
     pub const DUMP_WRAPPER_TX: ArgFlag = flag("dump-wrapper-tx");
     pub const DUMP_CONVERSION_TREE: ArgFlag = flag("dump-conversion-tree");
+    pub const END_EPOCH: Arg<Epoch> = arg("end-epoch");
     pub const EPOCH: ArgOpt<Epoch> = arg_opt("epoch");
     pub const ERC20: Arg<EthAddress> = arg("erc20");
     pub const ETH_CONFIRMATIONS: Arg<u64> = arg("confirmations");
@@ -3495,6 +4236,7 @@ pub mod args {
     );
     pub const ETH_SYNC: ArgFlag = flag("sync");
     pub const EXPIRATION_OPT: ArgOpt<DateTimeUtc> = arg_opt("expiration");
+    pub const EXPIRY_EPOCH: ArgOpt<Epoch> = arg_opt("expiry-epoch");
     pub const EMAIL: Arg<String> = arg("email");
     pub const EMAIL_OPT: ArgOpt<String> = EMAIL.opt();
     pub const FEE_AMOUNT_OPT: ArgOpt<token::DenominatedAmount> =
@@ -3510,6 +4252,8 @@ pub mod args {
         arg_opt("gas-spending-key");
     pub const FEE_TOKEN: ArgDefaultFromCtx<WalletAddrOrNativeToken> =
         arg_default_from_ctx("gas-token", DefaultFn(|| "".parse().unwrap()));
+    pub const GUARDIANS: ArgMulti<WalletAddress, GlobStar> =
+        arg_multi("guardians");
     pub const GENESIS_BOND_SOURCE: ArgOpt<AddrOrPk> = arg_opt("source");
     pub const GENESIS_PATH: Arg<PathBuf> = arg("genesis-path");
     pub const GENESIS_TIME: Arg<DateTimeUtc> = arg("genesis-time");
@@ -3571,9 +4315,12 @@ pub mod args {
         arg_opt("output-folder-path");
     pub const OWNER: Arg<WalletAddress> = arg("owner");
     pub const OWNER_OPT: ArgOpt<WalletAddress> = OWNER.opt();
+    pub const PAGE: ArgDefault<u64> = arg_default("page", DefaultFn(|| 0));
     pub const PATH: Arg<PathBuf> = arg("path");
     pub const PATH_OPT: ArgOpt<PathBuf> = arg_opt("path");
     pub const PAYMENT_ADDRESS_TARGET: Arg<WalletPaymentAddr> = arg("target");
+    pub const PER_PAGE: ArgDefault<u64> =
+        arg_default("per-page", DefaultFn(|| 20));
     pub const PORT_ID: ArgDefault<PortId> = arg_default(
         "port-id",
         DefaultFn(|| PortId::from_str("transfer").unwrap()),
@@ -3608,10 +4355,19 @@ pub mod args {
     pub const RAW_PUBLIC_KEY_HASH_OPT: ArgOpt<String> =
         RAW_PUBLIC_KEY_HASH.opt();
     pub const RECEIVER: Arg<String> = arg("receiver");
+    pub const RECOVERY_DELAY: ArgOpt<u64> = arg_opt("delay");
     pub const REFUND_TARGET: ArgOpt<WalletTransferTarget> =
         arg_opt("refund-target");
     pub const RELAYER: Arg<Address> = arg("relayer");
+    pub const REPRESENTATIVE_OPT: ArgOpt<WalletAddress> =
+        arg_opt("representative");
+    pub const RESET_REWARD_DESTINATION: ArgFlag =
+        flag("reset-reward-destination");
     pub const RETRIES: ArgOpt<u64> = arg_opt("retries");
+    pub const REWARD_DESTINATIONS: ArgMulti<WalletAddress, GlobStar> =
+        arg_multi("reward-destinations");
+    pub const REWARD_RATIOS: ArgMulti<Dec, GlobStar> =
+        arg_multi("reward-ratios");
     pub const SCHEME: ArgDefault<SchemeType> =
         arg_default("scheme", DefaultFn(|| SchemeType::Ed25519));
     pub const SHELL: Arg<Shell> = arg("shell");
@@ -3621,15 +4377,25 @@ pub mod args {
     pub const SHIELDED: ArgFlag = flag("shielded");
     pub const SHOW_IBC_TOKENS: ArgFlag = flag("show-ibc-tokens");
     pub const SIGNER: ArgOpt<WalletAddress> = arg_opt("signer");
+    pub const SIGNER_AUTH_KEY_PATH: ArgOpt<PathBuf> = arg_opt("auth-key-path");
+    pub const SIGNER_LISTEN: Arg<SignerEndpoint> = arg("listen");
+    pub const SIGNER_NODE_PUBLIC_KEY: Arg<common::PublicKey> =
+        arg("node-public-key");
+    pub const SIGNER_STATE_PATH: ArgOpt<PathBuf> = arg_opt("state-path");
     pub const SIGNING_KEYS: ArgMulti<WalletPublicKey, GlobStar> =
         arg_multi("signing-keys");
     pub const SIGNATURES: ArgMulti<PathBuf, GlobStar> = arg_multi("signatures");
+    pub const SNAPSHOT_APP_HASH: Arg<Hash> = arg("app-hash");
+    pub const SNAPSHOT_FROM_DIR: Arg<PathBuf> = arg("from");
+    pub const SNAPSHOT_OUT_DIR: Arg<PathBuf> = arg("out");
     pub const SOURCE: Arg<WalletAddress> = arg("source");
     pub const SOURCE_OPT: ArgOpt<WalletAddress> = SOURCE.opt();
     pub const SOURCE_VALIDATOR: Arg<WalletAddress> = arg("source-validator");
+    pub const SPENDER: Arg<WalletAddress> = arg("spender");
     pub const SPENDING_KEY_SOURCE: Arg<WalletSpendingKey> = arg("source");
     pub const SPENDING_KEYS: ArgMulti<WalletSpendingKey, GlobStar> =
         arg_multi("spending-keys");
+    pub const START_EPOCH: Arg<Epoch> = arg("start-epoch");
     pub const STEWARD: Arg<WalletAddress> = arg("steward");
     pub const STORAGE_KEY: Arg<storage::Key> = arg("storage-key");
     pub const SUSPEND_ACTION: ArgFlag = flag("suspend");
@@ -3677,6 +4443,7 @@ pub mod args {
     pub const WASM_CHECKSUMS_PATH: Arg<PathBuf> = arg("wasm-checksums-path");
     pub const WASM_DIR: ArgOpt<PathBuf> = arg_opt("wasm-dir");
     pub const WEBSITE_OPT: ArgOpt<String> = arg_opt("website");
+    pub const WEIGHTS: ArgMulti<u8, GlobStar> = arg_multi("weights");
     pub const WITH_INDEXER: ArgOpt<String> = arg_opt("with-indexer");
     pub const WRAPPER_SIGNATURE_OPT: ArgOpt<PathBuf> = arg_opt("gas-signature");
     pub const TX_PATH: Arg<PathBuf> = arg("tx-path");
@@ -3751,6 +4518,51 @@ pub mod args {
         }
     }
 
+    #[derive(Clone, Debug)]
+    pub struct SignerRun {
+        pub listen: SignerEndpoint,
+        pub node_public_key: common::PublicKey,
+        pub auth_key_path: Option<PathBuf>,
+        pub state_path: Option<PathBuf>,
+    }
+
+    impl Args for SignerRun {
+        fn parse(matches: &ArgMatches) -> Self {
+            let listen = SIGNER_LISTEN.parse(matches);
+            let node_public_key = SIGNER_NODE_PUBLIC_KEY.parse(matches);
+            let auth_key_path = SIGNER_AUTH_KEY_PATH.parse(matches);
+            let state_path = SIGNER_STATE_PATH.parse(matches);
+            Self {
+                listen,
+                node_public_key,
+                auth_key_path,
+                state_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(SIGNER_LISTEN.def().help(wrap!(
+                "The endpoint to listen on for requests from the node, either \
+                 \"unix://{path}\" or \"tcp://{ip}:{port}\"."
+            )))
+            .arg(SIGNER_NODE_PUBLIC_KEY.def().help(wrap!(
+                "The pinned public key with which the node authenticates \
+                 itself to the signer."
+            )))
+            .arg(SIGNER_AUTH_KEY_PATH.def().help(wrap!(
+                "The file holding the secret key with which the signer \
+                 authenticates itself to the node. The key is generated if \
+                 the file doesn't exist. Defaults to \"signer_auth_key\" in \
+                 the chain directory."
+            )))
+            .arg(SIGNER_STATE_PATH.def().help(wrap!(
+                "The file in which the last signed vote extensions are \
+                 recorded, to protect the validator from double signing. \
+                 Defaults to \"signer_state\" in the chain directory."
+            )))
+        }
+    }
+
     #[derive(Clone, Debug)]
     pub struct LedgerRun {
         pub start_time: Option<DateTimeUtc>,
@@ -3900,35 +4712,98 @@ pub mod args {
     }
 
     #[derive(Clone, Debug)]
-    pub struct LedgerUpdateDb {
-        pub updates: PathBuf,
-        pub dry_run: bool,
-        pub last_height: BlockHeight,
+    pub struct LedgerSnapshotExport {
+        pub height: Option<BlockHeight>,
+        pub out_dir: PathBuf,
     }
 
-    impl Args for LedgerUpdateDb {
+    impl Args for LedgerSnapshotExport {
         fn parse(matches: &ArgMatches) -> Self {
-            let updates = PATH.parse(matches);
-            let dry_run = DRY_RUN_TX.parse(matches);
-            let last_height = BLOCK_HEIGHT.parse(matches);
-            Self {
-                updates,
-                dry_run,
-                last_height,
-            }
+            let height = BLOCK_HEIGHT_OPT.parse(matches);
+            let out_dir = SNAPSHOT_OUT_DIR.parse(matches);
+            Self { height, out_dir }
         }
 
         fn def(app: App) -> App {
-            app.arg(PATH.def().help(wrap!(
-                "The path to a json of key-value pairs to update the DB with."
+            app.arg(BLOCK_HEIGHT_OPT.def().help(wrap!(
+                "The block height of the snapshot. Must be the latest \
+                 committed block, which is the default."
             )))
-            .arg(DRY_RUN_TX.def().help(wrap!(
-                "If set, applies the updates but does not persist them. Using \
-                 for testing and debugging."
+            .arg(SNAPSHOT_OUT_DIR.def().help(wrap!(
+                "The directory to write the snapshot to. The snapshot is \
+                 written to \"snapshots/block-{height}\" in this directory."
             )))
-            .arg(
-                BLOCK_HEIGHT.def().help(wrap!(
-                    "The height at which the hard fork is happening."
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct LedgerSnapshotImport {
+        pub from_dir: PathBuf,
+        pub height: Option<BlockHeight>,
+        pub app_hash: Hash,
+    }
+
+    impl Args for LedgerSnapshotImport {
+        fn parse(matches: &ArgMatches) -> Self {
+            let from_dir = SNAPSHOT_FROM_DIR.parse(matches);
+            let height = BLOCK_HEIGHT_OPT.parse(matches);
+            let app_hash = SNAPSHOT_APP_HASH.parse(matches);
+            Self {
+                from_dir,
+                height,
+                app_hash,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(
+                SNAPSHOT_FROM_DIR
+                    .def()
+                    .help(wrap!("The directory a snapshot was exported to.")),
+            )
+            .arg(BLOCK_HEIGHT_OPT.def().help(wrap!(
+                "The block height of the snapshot to import. Defaults to the \
+                 latest snapshot found in the directory."
+            )))
+            .arg(SNAPSHOT_APP_HASH.def().help(wrap!(
+                "The trusted app hash of the snapshot's height, which is \
+                 found in the header of the following block. It must be \
+                 obtained from a trusted source, e.g. a light client, as the \
+                 snapshot is only imported if its state matches it."
+            )))
+        }
+    }
+
+    #[derive(Clone, Debug)]
+    pub struct LedgerUpdateDb {
+        pub updates: PathBuf,
+        pub dry_run: bool,
+        pub last_height: BlockHeight,
+    }
+
+    impl Args for LedgerUpdateDb {
+        fn parse(matches: &ArgMatches) -> Self {
+            let updates = PATH.parse(matches);
+            let dry_run = DRY_RUN_TX.parse(matches);
+            let last_height = BLOCK_HEIGHT.parse(matches);
+            Self {
+                updates,
+                dry_run,
+                last_height,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.arg(PATH.def().help(wrap!(
+                "The path to a json of key-value pairs to update the DB with."
+            )))
+            .arg(DRY_RUN_TX.def().help(wrap!(
+                "If set, applies the updates but does not persist them. Using \
+                 for testing and debugging."
+            )))
+            .arg(
+                BLOCK_HEIGHT.def().help(wrap!(
+                    "The height at which the hard fork is happening."
                 )),
             )
         }
@@ -4100,6 +4975,7 @@ pub mod args {
                     .fee_payer
                     .map(|fee_payer| chain_ctx.get(&fee_payer)),
                 fee_token: chain_ctx.get(&self.fee_token).into(),
+                expiry: self.expiry,
                 code_path: self.code_path,
             })
         }
@@ -4116,6 +4992,7 @@ pub mod args {
                 InputAmount::Unvalidated(BRIDGE_POOL_GAS_AMOUNT.parse(matches));
             let fee_payer = BRIDGE_POOL_GAS_PAYER.parse(matches);
             let fee_token = BRIDGE_POOL_GAS_TOKEN.parse(matches);
+            let expiry = BRIDGE_POOL_EXPIRY.parse(matches);
             let code_path = PathBuf::from(TX_BRIDGE_POOL_WASM);
             let nut = NUT.parse(matches);
             Self {
@@ -4127,6 +5004,7 @@ pub mod args {
                 fee_amount,
                 fee_payer,
                 fee_token,
+                expiry,
                 code_path,
                 nut,
             }
@@ -4168,6 +5046,55 @@ pub mod args {
                     "Add Non Usable Tokens (NUTs) to the Bridge pool. These \
                      are usually obtained from invalid transfers to Namada."
                 )))
+                .arg(BRIDGE_POOL_EXPIRY.def().help(wrap!(
+                    "The block height from which the transfer is refunded, if \
+                     it hasn't been relayed to Ethereum yet. Refunds take \
+                     place when the next batch of transfers is relayed."
+                )))
+        }
+    }
+
+    impl CliToSdk<BumpBridgePoolFee<SdkTypes>> for BumpBridgePoolFee<CliTypes> {
+        type Error = std::io::Error;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<BumpBridgePoolFee<SdkTypes>, Self::Error> {
+            let tx = self.tx.to_sdk(ctx)?;
+            Ok(BumpBridgePoolFee::<SdkTypes> {
+                tx,
+                transfer: self.transfer,
+                amount: self.amount,
+                code_path: self.code_path,
+            })
+        }
+    }
+
+    impl Args for BumpBridgePoolFee<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let transfer = BRIDGE_POOL_TRANSFER.parse(matches);
+            let amount = InputAmount::Unvalidated(AMOUNT.parse(matches));
+            let code_path = PathBuf::from(TX_BRIDGE_POOL_WASM);
+            Self {
+                tx,
+                transfer,
+                amount,
+                code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(BRIDGE_POOL_TRANSFER.def().help(wrap!(
+                    "The Keccak hash of the transfer in the Bridge pool."
+                )))
+                .arg(AMOUNT.def().help(wrap!(
+                    "The amount to add to the relayer fee of the transfer. It \
+                     is paid by the gas payer of the transfer, in the same \
+                     token as its gas fees."
+                )))
         }
     }
 
@@ -4715,6 +5642,208 @@ pub mod args {
         }
     }
 
+    impl CliToSdk<TxApprove<SdkTypes>> for TxApprove<CliTypes> {
+        type Error = std::io::Error;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<TxApprove<SdkTypes>, Self::Error> {
+            let tx = self.tx.to_sdk(ctx)?;
+            let chain_ctx = ctx.borrow_mut_chain_or_exit();
+
+            Ok(TxApprove::<SdkTypes> {
+                tx,
+                owner: chain_ctx.get(&self.owner),
+                spender: chain_ctx.get(&self.spender),
+                token: chain_ctx.get(&self.token),
+                amount: self.amount,
+                expiry: self.expiry,
+                tx_code_path: self.tx_code_path.to_path_buf(),
+            })
+        }
+    }
+
+    impl Args for TxApprove<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let owner = OWNER.parse(matches);
+            let spender = SPENDER.parse(matches);
+            let token = TOKEN.parse(matches);
+            let amount = InputAmount::Unvalidated(AMOUNT.parse(matches));
+            let expiry = EXPIRY_EPOCH.parse(matches);
+            let tx_code_path = PathBuf::from(TX_APPROVE_WASM);
+
+            Self {
+                tx,
+                owner,
+                spender,
+                token,
+                amount,
+                expiry,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(OWNER.def().help(wrap!(
+                    "The owner of the tokens. The owner's key may be used to \
+                     produce the signature."
+                )))
+                .arg(SPENDER.def().help(wrap!(
+                    "The spender allowed to transfer the tokens of the owner."
+                )))
+                .arg(TOKEN.def().help(wrap!("The token address.")))
+                .arg(AMOUNT.def().help(wrap!(
+                    "The amount that may be spent in decimal. A zero amount \
+                     revokes the allowance."
+                )))
+                .arg(EXPIRY_EPOCH.def().help(wrap!(
+                    "The last epoch in which the allowance can be spent. \
+                     Without it, the allowance never expires."
+                )))
+        }
+    }
+
+    impl CliToSdk<TxTransferFrom<SdkTypes>> for TxTransferFrom<CliTypes> {
+        type Error = std::io::Error;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<TxTransferFrom<SdkTypes>, Self::Error> {
+            let tx = self.tx.to_sdk(ctx)?;
+            let chain_ctx = ctx.borrow_mut_chain_or_exit();
+
+            Ok(TxTransferFrom::<SdkTypes> {
+                tx,
+                owner: chain_ctx.get(&self.owner),
+                spender: chain_ctx.get(&self.spender),
+                target: chain_ctx.get(&self.target),
+                token: chain_ctx.get(&self.token),
+                amount: self.amount,
+                tx_code_path: self.tx_code_path.to_path_buf(),
+            })
+        }
+    }
+
+    impl Args for TxTransferFrom<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let owner = OWNER.parse(matches);
+            let spender = SPENDER.parse(matches);
+            let target = TARGET.parse(matches);
+            let token = TOKEN.parse(matches);
+            let amount = InputAmount::Unvalidated(AMOUNT.parse(matches));
+            let tx_code_path = PathBuf::from(TX_TRANSFER_FROM_WASM);
+
+            Self {
+                tx,
+                owner,
+                spender,
+                target,
+                token,
+                amount,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(OWNER.def().help(wrap!("The owner of the tokens.")))
+                .arg(SPENDER.def().help(wrap!(
+                    "The spender of the allowance. The spender's key may be \
+                     used to produce the signature."
+                )))
+                .arg(TARGET.def().help(wrap!("The target account address.")))
+                .arg(TOKEN.def().help(wrap!("The token address.")))
+                .arg(
+                    AMOUNT
+                        .def()
+                        .help(wrap!("The amount to transfer in decimal.")),
+                )
+        }
+    }
+
+    impl CliToSdk<TxVestingTransfer<SdkTypes>> for TxVestingTransfer<CliTypes> {
+        type Error = std::io::Error;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<TxVestingTransfer<SdkTypes>, Self::Error> {
+            let tx = self.tx.to_sdk(ctx)?;
+            let chain_ctx = ctx.borrow_mut_chain_or_exit();
+
+            Ok(TxVestingTransfer::<SdkTypes> {
+                tx,
+                source: chain_ctx.get(&self.source),
+                target: chain_ctx.get(&self.target),
+                token: chain_ctx.get(&self.token),
+                amount: self.amount,
+                start_epoch: self.start_epoch,
+                cliff_epoch: self.cliff_epoch,
+                end_epoch: self.end_epoch,
+                tx_code_path: self.tx_code_path.to_path_buf(),
+            })
+        }
+    }
+
+    impl Args for TxVestingTransfer<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let source = SOURCE.parse(matches);
+            let target = TARGET.parse(matches);
+            let token = TOKEN.parse(matches);
+            let amount = InputAmount::Unvalidated(AMOUNT.parse(matches));
+            let start_epoch = START_EPOCH.parse(matches);
+            let cliff_epoch = CLIFF_EPOCH.parse(matches).unwrap_or(start_epoch);
+            let end_epoch = END_EPOCH.parse(matches);
+            let tx_code_path = PathBuf::from(TX_VESTING_TRANSFER_WASM);
+
+            Self {
+                tx,
+                source,
+                target,
+                token,
+                amount,
+                start_epoch,
+                cliff_epoch,
+                end_epoch,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(SOURCE.def().help(wrap!(
+                    "The source account address. The source's key may be used \
+                     to produce the signature."
+                )))
+                .arg(TARGET.def().help(wrap!(
+                    "The target account address, whose received tokens are \
+                     locked."
+                )))
+                .arg(TOKEN.def().help(wrap!("The token address.")))
+                .arg(
+                    AMOUNT
+                        .def()
+                        .help(wrap!("The amount to transfer in decimal.")),
+                )
+                .arg(START_EPOCH.def().help(wrap!(
+                    "The epoch from which the amount starts vesting."
+                )))
+                .arg(CLIFF_EPOCH.def().help(wrap!(
+                    "The first epoch in which any of the amount is unlocked. \
+                     Defaults to the start epoch."
+                )))
+                .arg(END_EPOCH.def().help(wrap!(
+                    "The epoch from which the whole amount is unlocked."
+                )))
+        }
+    }
+
     impl CliToSdk<TxShieldedTransfer<SdkTypes>> for TxShieldedTransfer<CliTypes> {
         type Error = std::io::Error;
 
@@ -5126,6 +6255,7 @@ pub mod args {
                     .map(|pk| chain_ctx.get(pk))
                     .collect(),
                 threshold: self.threshold,
+                weights: self.weights,
             })
         }
     }
@@ -5139,11 +6269,13 @@ pub mod args {
             let tx_code_path = PathBuf::from(TX_INIT_ACCOUNT_WASM);
             let public_keys = PUBLIC_KEYS.parse(matches);
             let threshold = THRESHOLD.parse(matches);
+            let weights = WEIGHTS.parse(matches);
             Self {
                 tx,
                 vp_code_path,
                 public_keys,
                 threshold,
+                weights,
                 tx_code_path,
             }
         }
@@ -5164,6 +6296,12 @@ pub mod args {
                      authorization. Must be less then the maximum number of \
                      public keys provided."
                 )))
+                .arg(WEIGHTS.def().help(wrap!(
+                    "A list of weights of the public keys, in the same order. \
+                     When given, the threshold is the minimum total weight of \
+                     the keys that must sign. Defaults to a weight of 1 for \
+                     every key."
+                )))
         }
     }
 
@@ -5499,6 +6637,7 @@ pub mod args {
                     .map(|pk| chain_ctx.get(pk))
                     .collect(),
                 threshold: self.threshold,
+                weights: self.weights,
             })
         }
     }
@@ -5511,6 +6650,7 @@ pub mod args {
             let tx_code_path = PathBuf::from(TX_UPDATE_ACCOUNT_WASM);
             let public_keys = PUBLIC_KEYS.parse(matches);
             let threshold = THRESHOLD.parse(matches);
+            let weights = WEIGHTS.parse(matches);
             Self {
                 tx,
                 vp_code_path,
@@ -5518,6 +6658,7 @@ pub mod args {
                 tx_code_path,
                 public_keys,
                 threshold,
+                weights,
             }
         }
 
@@ -5539,40 +6680,273 @@ pub mod args {
                      authorization. Must be less then the maximum number of \
                      public keys provided."
                 )))
+                .arg(WEIGHTS.def().help(wrap!(
+                    "A list of weights of the public keys, in the same order. \
+                     When public keys are not given, the weights apply to the \
+                     current keys of the account. When given, the threshold \
+                     is the minimum total weight of the keys that must sign. \
+                     Defaults to a weight of 1 for every key."
+                )))
         }
     }
 
-    impl CliToSdk<Bond<SdkTypes>> for Bond<CliTypes> {
+    impl CliToSdk<TxConfigureRecovery<SdkTypes>> for TxConfigureRecovery<CliTypes> {
         type Error = std::io::Error;
 
         fn to_sdk(
             self,
             ctx: &mut Context,
-        ) -> Result<Bond<SdkTypes>, Self::Error> {
+        ) -> Result<TxConfigureRecovery<SdkTypes>, Self::Error> {
             let tx = self.tx.to_sdk(ctx)?;
-            let chain_ctx = ctx.borrow_chain_or_exit();
+            let chain_ctx = ctx.borrow_mut_chain_or_exit();
 
-            Ok(Bond::<SdkTypes> {
+            Ok(TxConfigureRecovery::<SdkTypes> {
                 tx,
-                validator: chain_ctx.get(&self.validator),
-                amount: self.amount,
-                source: self.source.map(|x| chain_ctx.get(&x)),
-                tx_code_path: self.tx_code_path.to_path_buf(),
+                addr: chain_ctx.get(&self.addr),
+                guardians: self
+                    .guardians
+                    .iter()
+                    .map(|guardian| chain_ctx.get(guardian))
+                    .collect(),
+                threshold: self.threshold,
+                delay: self.delay,
+                tx_code_path: self.tx_code_path,
             })
         }
     }
 
-    impl Args for Bond<CliTypes> {
+    impl Args for TxConfigureRecovery<CliTypes> {
         fn parse(matches: &ArgMatches) -> Self {
             let tx = Tx::parse(matches);
-            let validator = VALIDATOR.parse(matches);
-            let amount = AMOUNT.parse(matches);
-            let amount = amount
-                .canonical()
-                .increase_precision(NATIVE_MAX_DECIMAL_PLACES.into())
-                .unwrap_or_else(|e| {
-                    println!("Could not parse bond amount: {:?}", e);
-                    safe_exit(1);
+            let addr = ADDRESS.parse(matches);
+            let guardians = GUARDIANS.parse(matches);
+            // Both are required by clap when guardians are given
+            let threshold = THRESHOLD.parse(matches).unwrap_or_default();
+            let delay = RECOVERY_DELAY.parse(matches).unwrap_or_default();
+            let tx_code_path = PathBuf::from(TX_CONFIGURE_RECOVERY_WASM);
+            Self {
+                tx,
+                addr,
+                guardians,
+                threshold,
+                delay,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(ADDRESS.def().help(wrap!(
+                    "The account's address. It's key is used to produce the \
+                     signature."
+                )))
+                .arg(
+                    GUARDIANS
+                        .def()
+                        .help(wrap!(
+                            "The addresses of the guardians that can recover \
+                             the keys of the account. Without guardians, the \
+                             account opts out of social recovery."
+                        ))
+                        .requires(THRESHOLD.name)
+                        .requires(RECOVERY_DELAY.name),
+                )
+                .arg(THRESHOLD.def().help(wrap!(
+                    "The minimum number of guardians that must approve a \
+                     recovery."
+                )))
+                .arg(RECOVERY_DELAY.def().help(wrap!(
+                    "The number of epochs after which a recovery initiated by \
+                     the guardians can be finalized."
+                )))
+        }
+    }
+
+    impl CliToSdk<TxInitiateRecovery<SdkTypes>> for TxInitiateRecovery<CliTypes> {
+        type Error = std::io::Error;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<TxInitiateRecovery<SdkTypes>, Self::Error> {
+            let tx = self.tx.to_sdk(ctx)?;
+            let chain_ctx = ctx.borrow_mut_chain_or_exit();
+
+            Ok(TxInitiateRecovery::<SdkTypes> {
+                tx,
+                addr: chain_ctx.get(&self.addr),
+                guardians: self
+                    .guardians
+                    .iter()
+                    .map(|guardian| chain_ctx.get(guardian))
+                    .collect(),
+                public_keys: self
+                    .public_keys
+                    .iter()
+                    .map(|pk| chain_ctx.get(pk))
+                    .collect(),
+                threshold: self.threshold,
+                weights: self.weights,
+                tx_code_path: self.tx_code_path,
+            })
+        }
+    }
+
+    impl Args for TxInitiateRecovery<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let addr = ADDRESS.parse(matches);
+            let guardians = GUARDIANS.parse(matches);
+            let public_keys = PUBLIC_KEYS.parse(matches);
+            let threshold = THRESHOLD.parse(matches);
+            let weights = WEIGHTS.parse(matches);
+            let tx_code_path = PathBuf::from(TX_INITIATE_RECOVERY_WASM);
+            Self {
+                tx,
+                addr,
+                guardians,
+                public_keys,
+                threshold,
+                weights,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(
+                    ADDRESS
+                        .def()
+                        .help(wrap!("The address of the recovered account.")),
+                )
+                .arg(GUARDIANS.def().help(wrap!(
+                    "The guardians that approve the recovery. The transaction \
+                     must be signed by each of them, e.g. with \
+                     `--signing-keys`."
+                )))
+                .arg(PUBLIC_KEYS.def().help(wrap!(
+                    "The new public keys of the account in hexadecimal \
+                     encoding."
+                )))
+                .arg(THRESHOLD.def().help(wrap!(
+                    "The new minimum total weight of signatures to be \
+                     provided for authorization."
+                )))
+                .arg(WEIGHTS.def().help(wrap!(
+                    "A list of weights of the new public keys, in the same \
+                     order. Defaults to a weight of 1 for every key."
+                )))
+        }
+    }
+
+    impl CliToSdk<TxCancelRecovery<SdkTypes>> for TxCancelRecovery<CliTypes> {
+        type Error = std::io::Error;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<TxCancelRecovery<SdkTypes>, Self::Error> {
+            let tx = self.tx.to_sdk(ctx)?;
+            let chain_ctx = ctx.borrow_mut_chain_or_exit();
+
+            Ok(TxCancelRecovery::<SdkTypes> {
+                tx,
+                addr: chain_ctx.get(&self.addr),
+                tx_code_path: self.tx_code_path,
+            })
+        }
+    }
+
+    impl Args for TxCancelRecovery<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let addr = ADDRESS.parse(matches);
+            let tx_code_path = PathBuf::from(TX_CANCEL_RECOVERY_WASM);
+            Self {
+                tx,
+                addr,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>().arg(ADDRESS.def().help(wrap!(
+                "The account's address. Its current keys are used to produce \
+                 the signature."
+            )))
+        }
+    }
+
+    impl CliToSdk<TxFinalizeRecovery<SdkTypes>> for TxFinalizeRecovery<CliTypes> {
+        type Error = std::io::Error;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<TxFinalizeRecovery<SdkTypes>, Self::Error> {
+            let tx = self.tx.to_sdk(ctx)?;
+            let chain_ctx = ctx.borrow_mut_chain_or_exit();
+
+            Ok(TxFinalizeRecovery::<SdkTypes> {
+                tx,
+                addr: chain_ctx.get(&self.addr),
+                tx_code_path: self.tx_code_path,
+            })
+        }
+    }
+
+    impl Args for TxFinalizeRecovery<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let addr = ADDRESS.parse(matches);
+            let tx_code_path = PathBuf::from(TX_FINALIZE_RECOVERY_WASM);
+            Self {
+                tx,
+                addr,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>().arg(
+                ADDRESS
+                    .def()
+                    .help(wrap!("The address of the recovered account.")),
+            )
+        }
+    }
+
+    impl CliToSdk<Bond<SdkTypes>> for Bond<CliTypes> {
+        type Error = std::io::Error;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<Bond<SdkTypes>, Self::Error> {
+            let tx = self.tx.to_sdk(ctx)?;
+            let chain_ctx = ctx.borrow_chain_or_exit();
+
+            Ok(Bond::<SdkTypes> {
+                tx,
+                validator: chain_ctx.get(&self.validator),
+                amount: self.amount,
+                source: self.source.map(|x| chain_ctx.get(&x)),
+                tx_code_path: self.tx_code_path.to_path_buf(),
+            })
+        }
+    }
+
+    impl Args for Bond<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let validator = VALIDATOR.parse(matches);
+            let amount = AMOUNT.parse(matches);
+            let amount = amount
+                .canonical()
+                .increase_precision(NATIVE_MAX_DECIMAL_PLACES.into())
+                .unwrap_or_else(|e| {
+                    println!("Could not parse bond amount: {:?}", e);
+                    safe_exit(1);
                 })
                 .amount();
             let source = SOURCE_OPT.parse(matches);
@@ -5912,12 +7286,219 @@ pub mod args {
             app.add_args::<Tx<CliTypes>>()
                 .arg(PROPOSAL_ID.def().help(wrap!("The proposal identifier.")))
                 .arg(PROPOSAL_VOTE.def().help(wrap!(
-                    "The vote for the proposal. Either yay, nay, or abstain."
+                    "The vote for the proposal. Either yay, nay, abstain or \
+                     veto. A veto votes nay and, above the veto threshold of \
+                     the proposal type, rejects the proposal."
                 )))
                 .arg(ADDRESS.def().help(wrap!("The address of the voter.")))
         }
     }
 
+    impl CliToSdk<DelegateVotes<SdkTypes>> for DelegateVotes<CliTypes> {
+        type Error = std::io::Error;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<DelegateVotes<SdkTypes>, Self::Error> {
+            let tx = self.tx.to_sdk(ctx)?;
+            let chain_ctx = ctx.borrow_chain_or_exit();
+
+            Ok(DelegateVotes::<SdkTypes> {
+                tx,
+                delegator: chain_ctx.get(&self.delegator),
+                representative: self
+                    .representative
+                    .map(|representative| chain_ctx.get(&representative)),
+                tx_code_path: self.tx_code_path.to_path_buf(),
+            })
+        }
+    }
+
+    impl Args for DelegateVotes<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let delegator = ADDRESS.parse(matches);
+            let representative = REPRESENTATIVE_OPT.parse(matches);
+            let tx_code_path = PathBuf::from(TX_DELEGATE_VOTES_WASM);
+
+            Self {
+                tx,
+                delegator,
+                representative,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(ADDRESS.def().help(wrap!(
+                    "The address delegating its governance votes."
+                )))
+                .arg(REPRESENTATIVE_OPT.def().help(wrap!(
+                    "The representative voting on behalf of the delegator. \
+                     Omit to revoke the current delegation."
+                )))
+        }
+    }
+
+    impl CliToSdk<DepositProposal<SdkTypes>> for DepositProposal<CliTypes> {
+        type Error = std::io::Error;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<DepositProposal<SdkTypes>, Self::Error> {
+            let tx = self.tx.to_sdk(ctx)?;
+            let chain_ctx = ctx.borrow_chain_or_exit();
+
+            Ok(DepositProposal::<SdkTypes> {
+                tx,
+                proposal_id: self.proposal_id,
+                depositor: chain_ctx.get(&self.depositor),
+                amount: self.amount,
+                tx_code_path: self.tx_code_path.to_path_buf(),
+            })
+        }
+    }
+
+    impl Args for DepositProposal<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let proposal_id = PROPOSAL_ID.parse(matches);
+            let depositor = ADDRESS.parse(matches);
+            let amount = AMOUNT.parse(matches);
+            let amount = amount
+                .canonical()
+                .increase_precision(NATIVE_MAX_DECIMAL_PLACES.into())
+                .unwrap_or_else(|e| {
+                    println!("Could not parse deposit amount: {:?}", e);
+                    safe_exit(1);
+                })
+                .amount();
+            let tx_code_path = PathBuf::from(TX_DEPOSIT_PROPOSAL_WASM);
+
+            Self {
+                tx,
+                proposal_id,
+                depositor,
+                amount,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(PROPOSAL_ID.def().help(wrap!("The proposal identifier.")))
+                .arg(ADDRESS.def().help(wrap!(
+                    "The address depositing the funds to the proposal."
+                )))
+                .arg(
+                    AMOUNT
+                        .def()
+                        .help(wrap!("The amount of native tokens to deposit.")),
+                )
+        }
+    }
+
+    impl CliToSdk<CancelProposal<SdkTypes>> for CancelProposal<CliTypes> {
+        type Error = std::io::Error;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<CancelProposal<SdkTypes>, Self::Error> {
+            let tx = self.tx.to_sdk(ctx)?;
+            let chain_ctx = ctx.borrow_chain_or_exit();
+
+            Ok(CancelProposal::<SdkTypes> {
+                tx,
+                proposal_id: self.proposal_id,
+                author: chain_ctx.get(&self.author),
+                tx_code_path: self.tx_code_path.to_path_buf(),
+            })
+        }
+    }
+
+    impl Args for CancelProposal<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let proposal_id = PROPOSAL_ID.parse(matches);
+            let author = ADDRESS.parse(matches);
+            let tx_code_path = PathBuf::from(TX_CANCEL_PROPOSAL_WASM);
+
+            Self {
+                tx,
+                proposal_id,
+                author,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(PROPOSAL_ID.def().help(wrap!("The proposal identifier.")))
+                .arg(
+                    ADDRESS
+                        .def()
+                        .help(wrap!("The address of the proposal author.")),
+                )
+        }
+    }
+
+    impl CliToSdk<AmendProposal<SdkTypes>> for AmendProposal<CliTypes> {
+        type Error = std::io::Error;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<AmendProposal<SdkTypes>, Self::Error> {
+            let tx = self.tx.to_sdk(ctx)?;
+            let content = std::fs::read(self.content)?;
+            let chain_ctx = ctx.borrow_chain_or_exit();
+
+            Ok(AmendProposal::<SdkTypes> {
+                tx,
+                proposal_id: self.proposal_id,
+                author: chain_ctx.get(&self.author),
+                content,
+                tx_code_path: self.tx_code_path.to_path_buf(),
+            })
+        }
+    }
+
+    impl Args for AmendProposal<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let proposal_id = PROPOSAL_ID.parse(matches);
+            let author = ADDRESS.parse(matches);
+            let content = DATA_PATH.parse(matches);
+            let tx_code_path = PathBuf::from(TX_AMEND_PROPOSAL_WASM);
+
+            Self {
+                tx,
+                proposal_id,
+                author,
+                content,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(PROPOSAL_ID.def().help(wrap!("The proposal identifier.")))
+                .arg(
+                    ADDRESS
+                        .def()
+                        .help(wrap!("The address of the proposal author.")),
+                )
+                .arg(DATA_PATH.def().help(wrap!(
+                    "The data path file (json) that describes the new \
+                     proposal content."
+                )))
+        }
+    }
+
     impl CliToSdk<RevealPk<SdkTypes>> for RevealPk<CliTypes> {
         type Error = std::io::Error;
 
@@ -6019,6 +7600,65 @@ pub mod args {
         }
     }
 
+    impl CliToSdk<QueryProposalDeposits<SdkTypes>>
+        for QueryProposalDeposits<CliTypes>
+    {
+        type Error = std::convert::Infallible;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<QueryProposalDeposits<SdkTypes>, Self::Error> {
+            Ok(QueryProposalDeposits::<SdkTypes> {
+                query: self.query.to_sdk(ctx)?,
+                proposal_id: self.proposal_id,
+            })
+        }
+    }
+
+    impl Args for QueryProposalDeposits<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let query = Query::parse(matches);
+            let proposal_id = PROPOSAL_ID.parse(matches);
+            Self { query, proposal_id }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Query<CliTypes>>()
+                .arg(PROPOSAL_ID.def().help(wrap!("The proposal identifier.")))
+        }
+    }
+
+    impl CliToSdk<QueryVoteDelegation<SdkTypes>> for QueryVoteDelegation<CliTypes> {
+        type Error = std::convert::Infallible;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<QueryVoteDelegation<SdkTypes>, Self::Error> {
+            Ok(QueryVoteDelegation::<SdkTypes> {
+                query: self.query.to_sdk(ctx)?,
+                address: ctx.borrow_chain_or_exit().get(&self.address),
+            })
+        }
+    }
+
+    impl Args for QueryVoteDelegation<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let query = Query::parse(matches);
+            let address = ADDRESS.parse(matches);
+            Self { query, address }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Query<CliTypes>>()
+                .arg(ADDRESS.def().help(wrap!(
+                    "The address whose representative and constituents to \
+                     query."
+                )))
+        }
+    }
+
     impl Args for QueryProposalVotes<CliTypes> {
         fn parse(matches: &ArgMatches) -> Self {
             let query = Query::parse(matches);
@@ -6110,59 +7750,207 @@ pub mod args {
         }
     }
 
-    impl Args for QueryPgf<CliTypes> {
+    impl Args for QueryPgf<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let query = Query::parse(matches);
+
+            Self { query }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Query<CliTypes>>()
+        }
+    }
+
+    impl CliToSdk<QueryPgf<SdkTypes>> for QueryPgf<CliTypes> {
+        type Error = std::convert::Infallible;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<QueryPgf<SdkTypes>, Self::Error> {
+            Ok(QueryPgf::<SdkTypes> {
+                query: self.query.to_sdk(ctx)?,
+            })
+        }
+    }
+
+    impl CliToSdk<Withdraw<SdkTypes>> for Withdraw<CliTypes> {
+        type Error = std::io::Error;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<Withdraw<SdkTypes>, Self::Error> {
+            let tx = self.tx.to_sdk(ctx)?;
+            let chain_ctx = ctx.borrow_chain_or_exit();
+
+            Ok(Withdraw::<SdkTypes> {
+                tx,
+                validator: chain_ctx.get(&self.validator),
+                source: self.source.map(|x| chain_ctx.get(&x)),
+                tx_code_path: self.tx_code_path.to_path_buf(),
+            })
+        }
+    }
+
+    impl Args for Withdraw<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let validator = VALIDATOR.parse(matches);
+            let source = SOURCE_OPT.parse(matches);
+            let tx_code_path = PathBuf::from(TX_WITHDRAW_WASM);
+            Self {
+                tx,
+                validator,
+                source,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(VALIDATOR.def().help(wrap!("Validator address.")))
+                .arg(SOURCE_OPT.def().help(wrap!(
+                    "Source address for withdrawing from delegations. For \
+                     withdrawing from self-bonds, this arg does not need to \
+                     be supplied."
+                )))
+        }
+    }
+
+    impl CliToSdk<ClaimRewards<SdkTypes>> for ClaimRewards<CliTypes> {
+        type Error = std::io::Error;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<ClaimRewards<SdkTypes>, Self::Error> {
+            let tx = self.tx.to_sdk(ctx)?;
+            let chain_ctx = ctx.borrow_chain_or_exit();
+
+            Ok(ClaimRewards::<SdkTypes> {
+                tx,
+                validator: chain_ctx.get(&self.validator),
+                source: self.source.map(|x| chain_ctx.get(&x)),
+                tx_code_path: self.tx_code_path.to_path_buf(),
+            })
+        }
+    }
+
+    impl Args for ClaimRewards<CliTypes> {
         fn parse(matches: &ArgMatches) -> Self {
-            let query = Query::parse(matches);
-
-            Self { query }
+            let tx = Tx::parse(matches);
+            let validator = VALIDATOR.parse(matches);
+            let source = SOURCE_OPT.parse(matches);
+            let tx_code_path = PathBuf::from(TX_CLAIM_REWARDS_WASM);
+            Self {
+                tx,
+                validator,
+                source,
+                tx_code_path,
+            }
         }
 
         fn def(app: App) -> App {
-            app.add_args::<Query<CliTypes>>()
+            app.add_args::<Tx<CliTypes>>()
+                .arg(VALIDATOR.def().help(wrap!("Validator address.")))
+                .arg(SOURCE_OPT.def().help(wrap!(
+                    "Source address for claiming rewards for a bond. For \
+                     self-bonds, the validator is also the source."
+                )))
         }
     }
 
-    impl CliToSdk<QueryPgf<SdkTypes>> for QueryPgf<CliTypes> {
-        type Error = std::convert::Infallible;
+    impl CliToSdk<AutoCompound<SdkTypes>> for AutoCompound<CliTypes> {
+        type Error = std::io::Error;
 
         fn to_sdk(
             self,
             ctx: &mut Context,
-        ) -> Result<QueryPgf<SdkTypes>, Self::Error> {
-            Ok(QueryPgf::<SdkTypes> {
-                query: self.query.to_sdk(ctx)?,
+        ) -> Result<AutoCompound<SdkTypes>, Self::Error> {
+            let tx = self.tx.to_sdk(ctx)?;
+            let chain_ctx = ctx.borrow_chain_or_exit();
+
+            Ok(AutoCompound::<SdkTypes> {
+                tx,
+                validator: chain_ctx.get(&self.validator),
+                source: self.source.map(|x| chain_ctx.get(&x)),
+                enabled: self.enabled,
+                tx_code_path: self.tx_code_path.to_path_buf(),
             })
         }
     }
 
-    impl CliToSdk<Withdraw<SdkTypes>> for Withdraw<CliTypes> {
+    impl Args for AutoCompound<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let tx = Tx::parse(matches);
+            let validator = VALIDATOR.parse(matches);
+            let source = SOURCE_OPT.parse(matches);
+            let enabled = !DISABLE.parse(matches);
+            let tx_code_path = PathBuf::from(TX_AUTO_COMPOUND_WASM);
+            Self {
+                tx,
+                validator,
+                source,
+                enabled,
+                tx_code_path,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Tx<CliTypes>>()
+                .arg(VALIDATOR.def().help(wrap!("Validator address.")))
+                .arg(SOURCE_OPT.def().help(wrap!(
+                    "Source address of the bond whose rewards are compounded. \
+                     For self-bonds, the validator is also the source."
+                )))
+                .arg(DISABLE.def().help(wrap!(
+                    "Disable the automatic compounding of the rewards of the \
+                     bond, which is enabled otherwise."
+                )))
+        }
+    }
+
+    impl CliToSdk<LiquidBond<SdkTypes>> for LiquidBond<CliTypes> {
         type Error = std::io::Error;
 
         fn to_sdk(
             self,
             ctx: &mut Context,
-        ) -> Result<Withdraw<SdkTypes>, Self::Error> {
+        ) -> Result<LiquidBond<SdkTypes>, Self::Error> {
             let tx = self.tx.to_sdk(ctx)?;
             let chain_ctx = ctx.borrow_chain_or_exit();
 
-            Ok(Withdraw::<SdkTypes> {
+            Ok(LiquidBond::<SdkTypes> {
                 tx,
                 validator: chain_ctx.get(&self.validator),
-                source: self.source.map(|x| chain_ctx.get(&x)),
+                amount: self.amount,
+                source: chain_ctx.get(&self.source),
                 tx_code_path: self.tx_code_path.to_path_buf(),
             })
         }
     }
 
-    impl Args for Withdraw<CliTypes> {
+    impl Args for LiquidBond<CliTypes> {
         fn parse(matches: &ArgMatches) -> Self {
             let tx = Tx::parse(matches);
             let validator = VALIDATOR.parse(matches);
-            let source = SOURCE_OPT.parse(matches);
-            let tx_code_path = PathBuf::from(TX_WITHDRAW_WASM);
+            let amount = AMOUNT.parse(matches);
+            let amount = amount
+                .canonical()
+                .increase_precision(NATIVE_MAX_DECIMAL_PLACES.into())
+                .unwrap_or_else(|e| {
+                    println!("Could not parse bond amount: {:?}", e);
+                    safe_exit(1);
+                })
+                .amount();
+            let source = SOURCE.parse(matches);
+            let tx_code_path = PathBuf::from(TX_LIQUID_BOND_WASM);
             Self {
                 tx,
                 validator,
+                amount,
                 source,
                 tx_code_path,
             }
@@ -6171,42 +7959,55 @@ pub mod args {
         fn def(app: App) -> App {
             app.add_args::<Tx<CliTypes>>()
                 .arg(VALIDATOR.def().help(wrap!("Validator address.")))
-                .arg(SOURCE_OPT.def().help(wrap!(
-                    "Source address for withdrawing from delegations. For \
-                     withdrawing from self-bonds, this arg does not need to \
-                     be supplied."
+                .arg(AMOUNT.def().help(wrap!(
+                    "Amount of tokens to bond to the liquid staking pool."
+                )))
+                .arg(SOURCE.def().help(wrap!(
+                    "Source address of the bonded tokens, which receives the \
+                     liquid staking shares."
                 )))
         }
     }
 
-    impl CliToSdk<ClaimRewards<SdkTypes>> for ClaimRewards<CliTypes> {
+    impl CliToSdk<LiquidUnbond<SdkTypes>> for LiquidUnbond<CliTypes> {
         type Error = std::io::Error;
 
         fn to_sdk(
             self,
             ctx: &mut Context,
-        ) -> Result<ClaimRewards<SdkTypes>, Self::Error> {
+        ) -> Result<LiquidUnbond<SdkTypes>, Self::Error> {
             let tx = self.tx.to_sdk(ctx)?;
             let chain_ctx = ctx.borrow_chain_or_exit();
 
-            Ok(ClaimRewards::<SdkTypes> {
+            Ok(LiquidUnbond::<SdkTypes> {
                 tx,
                 validator: chain_ctx.get(&self.validator),
-                source: self.source.map(|x| chain_ctx.get(&x)),
+                shares: self.shares,
+                source: chain_ctx.get(&self.source),
                 tx_code_path: self.tx_code_path.to_path_buf(),
             })
         }
     }
 
-    impl Args for ClaimRewards<CliTypes> {
+    impl Args for LiquidUnbond<CliTypes> {
         fn parse(matches: &ArgMatches) -> Self {
             let tx = Tx::parse(matches);
             let validator = VALIDATOR.parse(matches);
-            let source = SOURCE_OPT.parse(matches);
-            let tx_code_path = PathBuf::from(TX_CLAIM_REWARDS_WASM);
+            let shares = AMOUNT.parse(matches);
+            let shares = shares
+                .canonical()
+                .increase_precision(NATIVE_MAX_DECIMAL_PLACES.into())
+                .unwrap_or_else(|e| {
+                    println!("Could not parse the amount of shares: {:?}", e);
+                    safe_exit(1);
+                })
+                .amount();
+            let source = SOURCE.parse(matches);
+            let tx_code_path = PathBuf::from(TX_LIQUID_UNBOND_WASM);
             Self {
                 tx,
                 validator,
+                shares,
                 source,
                 tx_code_path,
             }
@@ -6215,9 +8016,14 @@ pub mod args {
         fn def(app: App) -> App {
             app.add_args::<Tx<CliTypes>>()
                 .arg(VALIDATOR.def().help(wrap!("Validator address.")))
-                .arg(SOURCE_OPT.def().help(wrap!(
-                    "Source address for claiming rewards for a bond. For \
-                     self-bonds, the validator is also the source."
+                .arg(
+                    AMOUNT.def().help(wrap!(
+                        "Amount of liquid staking shares to redeem."
+                    )),
+                )
+                .arg(SOURCE.def().help(wrap!(
+                    "Owner of the redeemed shares, which receives the \
+                     unbonded tokens."
                 )))
         }
     }
@@ -6297,6 +8103,53 @@ pub mod args {
         }
     }
 
+    impl CliToSdk<QueryTxHistory<SdkTypes>> for QueryTxHistory<CliTypes> {
+        type Error = std::convert::Infallible;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<QueryTxHistory<SdkTypes>, Self::Error> {
+            Ok(QueryTxHistory::<SdkTypes> {
+                query: self.query.to_sdk(ctx)?,
+                owner: ctx.borrow_chain_or_exit().get(&self.owner),
+                page: self.page,
+                per_page: self.per_page,
+            })
+        }
+    }
+
+    impl Args for QueryTxHistory<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let query = Query::parse(matches);
+            let owner = OWNER.parse(matches);
+            let page = PAGE.parse(matches);
+            let per_page = PER_PAGE.parse(matches);
+            Self {
+                query,
+                owner,
+                page,
+                per_page,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Query<CliTypes>>()
+                .arg(
+                    OWNER
+                        .def()
+                        .help(wrap!("The address whose history to query."))
+                        .required(true),
+                )
+                .arg(PAGE.def().help(wrap!(
+                    "The page of the history to query, starting from 0."
+                )))
+                .arg(PER_PAGE.def().help(wrap!(
+                    "The number of entries per page, at most 100."
+                )))
+        }
+    }
+
     impl CliToSdk<QueryBalance<SdkTypes>> for QueryBalance<CliTypes> {
         type Error = std::convert::Infallible;
 
@@ -6628,6 +8481,19 @@ pub mod args {
                 discord_handle: self.discord_handle,
                 avatar: self.avatar,
                 name: self.name,
+                reward_destination: self.reward_destination.map(
+                    |destination| {
+                        destination
+                            .into_iter()
+                            .map(|(address, ratio)| {
+                                (
+                                    ctx.borrow_chain_or_exit().get(&address),
+                                    ratio,
+                                )
+                            })
+                            .collect()
+                    },
+                ),
                 commission_rate: self.commission_rate,
                 tx_code_path: self.tx_code_path.to_path_buf(),
             })
@@ -6644,6 +8510,22 @@ pub mod args {
             let discord_handle = DISCORD_OPT.parse(matches);
             let avatar = AVATAR_OPT.parse(matches);
             let name = VALIDATOR_NAME_OPT.parse(matches);
+            let reward_destination = if RESET_REWARD_DESTINATION.parse(matches)
+            {
+                Some(vec![])
+            } else {
+                let destinations = REWARD_DESTINATIONS.parse(matches);
+                let ratios = REWARD_RATIOS.parse(matches);
+                if destinations.len() != ratios.len() {
+                    eprintln!(
+                        "The number of reward destinations must match the \
+                         number of reward ratios"
+                    );
+                    safe_exit(1);
+                }
+                (!destinations.is_empty())
+                    .then(|| destinations.into_iter().zip(ratios).collect())
+            };
             let commission_rate = COMMISSION_RATE_OPT.parse(matches);
             let tx_code_path = PathBuf::from(TX_CHANGE_METADATA_WASM);
             Self {
@@ -6655,6 +8537,7 @@ pub mod args {
                 discord_handle,
                 avatar,
                 name,
+                reward_destination,
                 commission_rate,
                 tx_code_path,
             }
@@ -6692,6 +8575,31 @@ pub mod args {
                      validator in online services. To remove the existing \
                      validator alias, pass an empty string to this argument."
                 )))
+                .arg(
+                    REWARD_DESTINATIONS
+                        .def()
+                        .help(wrap!(
+                            "A list of addresses to which the validator's \
+                             commission is paid instead of the validator \
+                             itself."
+                        ))
+                        .requires(REWARD_RATIOS.name)
+                        .conflicts_with(RESET_REWARD_DESTINATION.name),
+                )
+                .arg(
+                    REWARD_RATIOS
+                        .def()
+                        .help(wrap!(
+                            "The ratios of the commission paid to each of the \
+                             reward destinations, in the same order. The \
+                             ratios must sum up to 1."
+                        ))
+                        .requires(REWARD_DESTINATIONS.name),
+                )
+                .arg(RESET_REWARD_DESTINATION.def().help(wrap!(
+                    "Reset the reward destination such that the commission is \
+                     paid to the validator itself."
+                )))
                 .arg(
                     COMMISSION_RATE_OPT
                         .def()
@@ -7157,6 +9065,46 @@ pub mod args {
         }
     }
 
+    impl CliToSdk<QuerySlashReport<SdkTypes>> for QuerySlashReport<CliTypes> {
+        type Error = std::convert::Infallible;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<QuerySlashReport<SdkTypes>, Self::Error> {
+            Ok(QuerySlashReport::<SdkTypes> {
+                query: self.query.to_sdk(ctx)?,
+                validator: ctx.borrow_chain_or_exit().get(&self.validator),
+                block_height: self.block_height,
+            })
+        }
+    }
+
+    impl Args for QuerySlashReport<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let query = Query::parse(matches);
+            let validator = VALIDATOR.parse(matches);
+            let block_height = BLOCK_HEIGHT.parse(matches);
+            Self {
+                query,
+                validator,
+                block_height,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Query<CliTypes>>()
+                .arg(
+                    VALIDATOR
+                        .def()
+                        .help(wrap!("The address of the slashed validator.")),
+                )
+                .arg(BLOCK_HEIGHT.def().help(wrap!(
+                    "The block height of the infraction that was slashed."
+                )))
+        }
+    }
+
     impl CliToSdk<QueryRewards<SdkTypes>> for QueryRewards<CliTypes> {
         type Error = std::convert::Infallible;
 
@@ -7240,6 +9188,84 @@ pub mod args {
         }
     }
 
+    impl CliToSdk<QueryAllowance<SdkTypes>> for QueryAllowance<CliTypes> {
+        type Error = std::convert::Infallible;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<QueryAllowance<SdkTypes>, Self::Error> {
+            let query = self.query.to_sdk(ctx)?;
+            let chain_ctx = ctx.borrow_chain_or_exit();
+
+            Ok(QueryAllowance::<SdkTypes> {
+                query,
+                owner: chain_ctx.get(&self.owner),
+                spender: chain_ctx.get(&self.spender),
+                token: chain_ctx.get(&self.token),
+            })
+        }
+    }
+
+    impl Args for QueryAllowance<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let query = Query::parse(matches);
+            let owner = OWNER.parse(matches);
+            let spender = SPENDER.parse(matches);
+            let token = TOKEN.parse(matches);
+            Self {
+                query,
+                owner,
+                spender,
+                token,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Query<CliTypes>>()
+                .arg(OWNER.def().help(wrap!("The owner of the tokens.")))
+                .arg(SPENDER.def().help(wrap!("The spender of the allowance.")))
+                .arg(TOKEN.def().help(wrap!("The token address.")))
+        }
+    }
+
+    impl CliToSdk<QueryVesting<SdkTypes>> for QueryVesting<CliTypes> {
+        type Error = std::convert::Infallible;
+
+        fn to_sdk(
+            self,
+            ctx: &mut Context,
+        ) -> Result<QueryVesting<SdkTypes>, Self::Error> {
+            let query = self.query.to_sdk(ctx)?;
+            let chain_ctx = ctx.borrow_chain_or_exit();
+
+            Ok(QueryVesting::<SdkTypes> {
+                query,
+                owner: chain_ctx.get(&self.owner),
+                token: chain_ctx.get(&self.token),
+            })
+        }
+    }
+
+    impl Args for QueryVesting<CliTypes> {
+        fn parse(matches: &ArgMatches) -> Self {
+            let query = Query::parse(matches);
+            let owner = OWNER.parse(matches);
+            let token = TOKEN.parse(matches);
+            Self {
+                query,
+                owner,
+                token,
+            }
+        }
+
+        fn def(app: App) -> App {
+            app.add_args::<Query<CliTypes>>()
+                .arg(OWNER.def().help(wrap!("The owner of the tokens.")))
+                .arg(TOKEN.def().help(wrap!("The token address.")))
+        }
+    }
+
     impl CliToSdk<QueryTotalSupply<SdkTypes>> for QueryTotalSupply<CliTypes> {
         type Error = std::convert::Infallible;
 
@@ -8986,6 +11012,11 @@ pub fn namada_relayer_cli() -> Result<NamadaRelayer> {
     }
 }
 
+pub fn namada_signer_cli() -> Result<(cmds::NamadaSigner, Context)> {
+    let app = namada_signer_app();
+    cmds::NamadaSigner::parse_or_print_help(app)
+}
+
 pub fn namada_app() -> App {
     let app = App::new(APP_NAME)
         .version(namada_version())
@@ -9026,6 +11057,16 @@ pub fn namada_wallet_app() -> App {
     cmds::NamadaWallet::add_sub(args::Global::def(app))
 }
 
+pub fn namada_signer_app() -> App {
+    let app = App::new(APP_NAME)
+        .version(namada_version())
+        .about("Namada remote signer command line interface.")
+        .color(ColorChoice::Auto)
+        .subcommand_required(true)
+        .arg_required_else_help(true);
+    cmds::NamadaSigner::add_sub(args::Global::def(app))
+}
+
 pub fn namada_relayer_app() -> App {
     let app = App::new(APP_NAME)
         .version(namada_version())
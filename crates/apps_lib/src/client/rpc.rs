@@ -38,8 +38,8 @@ use namada_sdk::parameters::{
 };
 use namada_sdk::proof_of_stake::rewards::PosRewardsRates;
 use namada_sdk::proof_of_stake::types::{
-    CommissionPair, Slash, ValidatorMetaData, ValidatorState,
-    ValidatorStateInfo, WeightedValidator,
+    CommissionPair, Slash, SlashReport, SlashableAmountComputation,
+    ValidatorMetaData, ValidatorState, ValidatorStateInfo, WeightedValidator,
 };
 use namada_sdk::proof_of_stake::{OwnedPosParams, PosParams};
 use namada_sdk::queries::RPC;
@@ -315,6 +315,112 @@ pub async fn query_proposal_votes(
     }
 }
 
+/// Query the deposits locked in a proposal by each depositor
+pub async fn query_proposal_deposits(
+    context: &impl Namada,
+    args: args::QueryProposalDeposits,
+) {
+    let proposal_id = args.proposal_id;
+    let deposits = unwrap_sdk_result(
+        namada_sdk::rpc::query_proposal_deposits(context.client(), proposal_id)
+            .await,
+    );
+    if deposits.is_empty() {
+        display_line!(
+            context.io(),
+            "No deposits found for proposal {proposal_id}"
+        );
+        return;
+    }
+
+    let total = deposits
+        .values()
+        .try_fold(Amount::zero(), |acc, amount| acc.checked_add(*amount))
+        .expect("Total proposal deposits should not overflow");
+    let params =
+        namada_sdk::rpc::query_governance_parameters(context.client()).await;
+    display_line!(
+        context.io(),
+        "Deposits of proposal {proposal_id} (total {}, minimum {}):",
+        total.to_string_native(),
+        params.min_proposal_fund.to_string_native()
+    );
+    for (depositor, amount) in deposits {
+        display_line!(
+            context.io(),
+            "  {depositor}: {}",
+            amount.to_string_native()
+        );
+    }
+}
+
+/// Query the governance representative and the constituents of an address
+pub async fn query_vote_delegation(
+    context: &impl Namada,
+    args: args::QueryVoteDelegation,
+) {
+    let address = args.address;
+    let current_epoch = query_and_print_epoch(context).await;
+    let next_epoch = current_epoch.next();
+
+    let representative = unwrap_sdk_result(
+        namada_sdk::rpc::query_governance_representative(
+            context.client(),
+            &address,
+            Some(current_epoch),
+        )
+        .await,
+    );
+    let next_representative = unwrap_sdk_result(
+        namada_sdk::rpc::query_governance_representative(
+            context.client(),
+            &address,
+            Some(next_epoch),
+        )
+        .await,
+    );
+    match &representative {
+        Some(representative) => display_line!(
+            context.io(),
+            "The governance votes of {address} are delegated to \
+             {representative}"
+        ),
+        None => display_line!(
+            context.io(),
+            "The governance votes of {address} are not delegated"
+        ),
+    }
+    if next_representative != representative {
+        match next_representative {
+            Some(representative) => display_line!(
+                context.io(),
+                "From epoch {next_epoch}, they will be delegated to \
+                 {representative}"
+            ),
+            None => display_line!(
+                context.io(),
+                "From epoch {next_epoch}, the delegation will be revoked"
+            ),
+        }
+    }
+
+    let constituents = unwrap_sdk_result(
+        namada_sdk::rpc::query_governance_constituents(
+            context.client(),
+            &address,
+        )
+        .await,
+    );
+    if constituents.is_empty() {
+        display_line!(context.io(), "{address} has no constituents");
+    } else {
+        display_line!(context.io(), "Constituents of {address}:");
+        for constituent in constituents {
+            display_line!(context.io(), "  {constituent}");
+        }
+    }
+}
+
 /// Query Proposals
 pub async fn query_proposal(context: &impl Namada, args: args::QueryProposal) {
     let current_epoch = query_and_print_epoch(context).await;
@@ -599,14 +705,104 @@ pub async fn query_account(context: &impl Namada, args: args::QueryAccount) {
         display_line!(context.io(), "Address: {}", account.address);
         display_line!(context.io(), "Threshold: {}", account.threshold);
         display_line!(context.io(), "Public keys:");
-        for (public_key, _) in account.public_keys_map.pk_to_idx {
-            display_line!(context.io(), "- {}", public_key);
+        for (public_key, index) in &account.public_keys_map.pk_to_idx {
+            let weight = account.public_keys_map.get_weight_from_index(*index);
+            display_line!(context.io(), "- {public_key} (weight: {weight})");
+        }
+        let recovery_config =
+            rpc::get_recovery_config(context.client(), &args.owner)
+                .await
+                .unwrap();
+        if let Some(config) = recovery_config {
+            display_line!(
+                context.io(),
+                "Recovery: {} of {} guardians, with a delay of {} epochs",
+                config.threshold,
+                config.guardians.len(),
+                config.delay
+            );
+            for guardian in &config.guardians {
+                display_line!(context.io(), "- {guardian}");
+            }
+        }
+        let pending_recovery =
+            rpc::get_pending_recovery(context.client(), &args.owner)
+                .await
+                .unwrap();
+        if let Some(recovery) = pending_recovery {
+            display_line!(
+                context.io(),
+                "Pending recovery, finalizable from epoch {}:",
+                recovery.activation_epoch
+            );
+            display_line!(context.io(), "Threshold: {}", recovery.threshold);
+            display_line!(context.io(), "Public keys:");
+            let public_keys_map = recovery.public_keys_map();
+            for (public_key, index) in &public_keys_map.pk_to_idx {
+                let weight = public_keys_map.get_weight_from_index(*index);
+                display_line!(
+                    context.io(),
+                    "- {public_key} (weight: {weight})"
+                );
+            }
         }
     } else {
         display_line!(context.io(), "No account exists for {}", args.owner);
     }
 }
 
+/// Query the tx history of an address
+pub async fn query_tx_history(
+    context: &impl Namada,
+    args: args::QueryTxHistory,
+) {
+    let history = match rpc::query_tx_history(
+        context.client(),
+        &args.owner,
+        args.page,
+        args.per_page,
+    )
+    .await
+    {
+        Ok(history) => history,
+        Err(err) => {
+            edisplay_line!(
+                context.io(),
+                "Failed to query the tx history of {}: {err}",
+                args.owner
+            );
+            cli::safe_exit(1)
+        }
+    };
+    if history.entries.is_empty() {
+        display_line!(
+            context.io(),
+            "No tx history found for {} on page {} (total entries: {}).",
+            args.owner,
+            args.page,
+            history.total
+        );
+        return;
+    }
+    display_line!(
+        context.io(),
+        "Tx history of {} (page {}, total entries: {}):",
+        args.owner,
+        args.page,
+        history.total
+    );
+    for entry in history.entries {
+        display_line!(
+            context.io(),
+            "{:4}height {}, inner tx {}: {}",
+            "",
+            entry.height,
+            entry.inner_tx_hash,
+            entry.kind
+        );
+    }
+}
+
 pub async fn query_pgf(context: &impl Namada, _args: args::QueryPgf) {
     let stewards = query_pgf_stewards(context.client()).await;
     let fundings = query_pgf_fundings(context.client()).await;
@@ -664,12 +860,19 @@ pub async fn query_protocol_parameters(
 ) {
     let GovernanceParameters {
         min_proposal_fund,
+        min_proposal_initial_deposit,
+        proposal_cancellation_refund,
         max_proposal_code_size,
         min_proposal_voting_period,
         max_proposal_period,
         max_proposal_content_size,
         min_proposal_grace_epochs,
         max_proposal_latency,
+        default_tally_params,
+        default_with_wasm_tally_params,
+        pgf_steward_tally_params,
+        pgf_payment_tally_params,
+        pgf_steward_payment_tally_params,
     } = query_governance_parameters(context.client()).await;
 
     display_line!(context.io(), "\nGovernance Parameters");
@@ -679,6 +882,18 @@ pub async fn query_protocol_parameters(
         "",
         min_proposal_fund.to_string_native()
     );
+    display_line!(
+        context.io(),
+        "{:4}Min. proposal initial deposit: {} native tokens",
+        "",
+        min_proposal_initial_deposit.to_string_native()
+    );
+    display_line!(
+        context.io(),
+        "{:4}Proposal cancellation refund: {}",
+        "",
+        proposal_cancellation_refund
+    );
     display_line!(
         context.io(),
         "{:4}Max. proposal code size: {} bytes",
@@ -715,6 +930,24 @@ pub async fn query_protocol_parameters(
         "",
         max_proposal_latency
     );
+    for (proposal_type, tally_params) in [
+        ("default", default_tally_params),
+        ("default with wasm", default_with_wasm_tally_params),
+        ("PGF steward", pgf_steward_tally_params),
+        ("PGF payment", pgf_payment_tally_params),
+        ("PGF steward payment", pgf_steward_payment_tally_params),
+    ] {
+        display_line!(
+            context.io(),
+            "{:4}Tally of {} proposals: quorum {}, threshold {}, veto \
+             threshold {}",
+            "",
+            proposal_type,
+            tally_params.quorum,
+            tally_params.threshold,
+            tally_params.veto_threshold
+        );
+    }
 
     let PgfParameters {
         stewards: _,
@@ -929,6 +1162,8 @@ pub async fn query_protocol_parameters(
                 liveness_threshold,
                 rewards_gain_p,
                 rewards_gain_d,
+                min_self_bond_ratio,
+                max_commission_rate,
             },
         max_proposal_period: _,
     } = query_pos_parameters(context.client()).await;
@@ -962,6 +1197,18 @@ pub async fn query_protocol_parameters(
         "",
         validator_stake_threshold.to_string_native()
     );
+    display_line!(
+        context.io(),
+        "{:4}Min. validator self-bond ratio: {}",
+        "",
+        min_self_bond_ratio
+    );
+    display_line!(
+        context.io(),
+        "{:4}Max. validator commission rate: {}",
+        "",
+        max_commission_rate
+    );
     display_line!(
         context.io(),
         "{:4}Duplicate vote minimum slash rate: {}",
@@ -1055,9 +1302,7 @@ pub async fn query_unbond_with_slashing<C: Client + Sync>(
 }
 
 pub async fn query_pos_parameters<C: Client + Sync>(client: &C) -> PosParams {
-    unwrap_client_response::<C, PosParams>(
-        RPC.vp().pos().pos_params(client).await,
-    )
+    unwrap_sdk_result(rpc::get_pos_params(client).await)
 }
 
 pub async fn query_consensus_keys<C: Client + Sync>(
@@ -1453,6 +1698,76 @@ pub async fn query_total_supply<N: Namada>(
     );
 }
 
+/// Query the allowance of a spender to transfer the tokens of an owner
+pub async fn query_allowance<N: Namada>(
+    context: &N,
+    args: args::QueryAllowance,
+) {
+    let args::QueryAllowance {
+        owner,
+        spender,
+        token,
+        ..
+    } = args;
+    let allowance = unwrap_sdk_result(
+        rpc::get_token_allowance(context.client(), &token, &owner, &spender)
+            .await,
+    );
+    match allowance {
+        Some(allowance) => {
+            let amount_str = format_denominated_amount(
+                context.client(),
+                context.io(),
+                &token,
+                allowance.amount,
+            )
+            .await;
+            let expiry_str = allowance
+                .expiry
+                .map(|expiry| format!(" until the end of epoch {expiry}"))
+                .unwrap_or_default();
+            display_line!(
+                context.io(),
+                "{spender} may transfer {} of token {token} owned by {owner}{}",
+                amount_str,
+                expiry_str
+            );
+        }
+        None => display_line!(
+            context.io(),
+            "{spender} has no allowance to transfer the token {token} owned \
+             by {owner}"
+        ),
+    }
+}
+
+/// Query the locked and spendable parts of a vested token balance
+pub async fn query_vesting<N: Namada>(context: &N, args: args::QueryVesting) {
+    let args::QueryVesting { owner, token, .. } = args;
+    let balance = unwrap_sdk_result(
+        rpc::get_token_vesting_balance(context.client(), &token, &owner).await,
+    );
+    let locked_str = format_denominated_amount(
+        context.client(),
+        context.io(),
+        &token,
+        balance.locked,
+    )
+    .await;
+    let spendable_str = format_denominated_amount(
+        context.client(),
+        context.io(),
+        &token,
+        balance.spendable,
+    )
+    .await;
+    display_line!(
+        context.io(),
+        "{owner} has {locked_str} of token {token} locked by vesting \
+         schedules and {spendable_str} spendable"
+    );
+}
+
 /// Query the effective total supply of the native token
 pub async fn query_effective_native_supply<N: Namada>(context: &N) {
     let native_supply = unwrap_client_response::<N::Client, token::Amount>(
@@ -1603,6 +1918,7 @@ pub async fn query_and_print_metadata(
             discord_handle,
             avatar,
             name,
+            reward_destination,
         }) => {
             display_line!(
                 context.io(),
@@ -1639,6 +1955,14 @@ pub async fn query_and_print_metadata(
             } else {
                 display_line!(context.io(), "No avatar");
             }
+            if let Some(reward_destination) = reward_destination {
+                display_line!(context.io(), "Reward destination:");
+                for (address, ratio) in reward_destination.0 {
+                    display_line!(context.io(), "  {}: {}", address, ratio);
+                }
+            } else {
+                display_line!(context.io(), "Reward destination: validator");
+            }
         }
         None => display_line!(
             context.io(),
@@ -1831,6 +2155,133 @@ pub async fn query_slashes<N: Namada>(context: &N, args: args::QuerySlashes) {
     }
 }
 
+/// Query and print the report of a PoS slash, explaining the rate and the
+/// amount slashed from each bond and redelegation
+pub async fn query_and_print_slash_report(
+    context: &impl Namada,
+    args: args::QuerySlashReport,
+) {
+    let validator = args.validator;
+    let report = rpc::query_slash_report(
+        context.client(),
+        &validator,
+        args.block_height.0,
+    )
+    .await
+    .unwrap();
+    let Some(SlashReport {
+        slash,
+        record,
+        type_rate,
+        bonds,
+        redelegations,
+        ..
+    }) = report
+    else {
+        edisplay_line!(
+            context.io(),
+            "No processed slash found for {} at block height {}",
+            validator.encode(),
+            args.block_height
+        );
+        return;
+    };
+
+    display_line!(
+        context.io(),
+        "Slash of validator {} for {} at block height {} (infraction epoch {})",
+        validator.encode(),
+        slash.r#type,
+        slash.block_height,
+        slash.epoch
+    );
+    let cubic_window = record.and_then(|record| {
+        if let Some(hash) = record.evidence_hash {
+            display_line!(context.io(), "Evidence hash: {}", hash);
+        }
+        record.cubic_window
+    });
+    if let Some(window) = cubic_window {
+        display_line!(context.io(), "Cubic slashing window:");
+        for epoch in window.epochs {
+            display_line!(
+                context.io(),
+                "  Epoch {}: infracting stake {} out of consensus stake {}",
+                epoch.epoch,
+                epoch.infracting_stake.to_string_native(),
+                epoch.consensus_stake.to_string_native()
+            );
+        }
+        display_line!(
+            context.io(),
+            "Sum of the infracting voting power fractions: {}",
+            window.sum_vp_fraction
+        );
+        display_line!(
+            context.io(),
+            "Cubic slash rate (9 * sum^2): {}",
+            window.cubic_rate
+        );
+    }
+    display_line!(
+        context.io(),
+        "Minimum slash rate for {}: {}",
+        slash.r#type,
+        type_rate
+    );
+    display_line!(
+        context.io(),
+        "Final slash rate (capped at 1): {}",
+        slash.rate
+    );
+
+    let print_computation = |computation: &SlashableAmountComputation| {
+        display_line!(
+            context.io(),
+            "    Amount: {}, slashed by prior slashes: {}, slashable: {}, \
+             rate: {}, slashed: {}",
+            computation.amount.to_string_native(),
+            computation
+                .prior_slashed_amounts
+                .values()
+                .fold(token::Amount::zero(), |acc, amount| acc
+                    .checked_add(*amount)
+                    .unwrap_or_default())
+                .to_string_native(),
+            computation.slashable_amount.to_string_native(),
+            computation.rate,
+            computation.slashed_amount.to_string_native()
+        );
+    };
+    if bonds.is_empty() {
+        display_line!(context.io(), "No slashed bonds found");
+    } else {
+        display_line!(context.io(), "Bonds:");
+        for bond in bonds {
+            display_line!(
+                context.io(),
+                "  From {} starting at epoch {}",
+                bond.source.encode(),
+                bond.start
+            );
+            print_computation(&bond.computation);
+        }
+    }
+    if !redelegations.is_empty() {
+        display_line!(context.io(), "Redelegations:");
+        for redelegation in redelegations {
+            display_line!(
+                context.io(),
+                "  To {} of bond starting at epoch {}, redelegated at epoch {}",
+                redelegation.dest_validator.encode(),
+                redelegation.bond_start,
+                redelegation.redelegation_start
+            );
+            print_computation(&redelegation.computation);
+        }
+    }
+}
+
 pub async fn query_and_print_rewards<N: Namada>(
     context: &N,
     args: args::QueryRewards,
@@ -1843,6 +2294,17 @@ pub async fn query_and_print_rewards<N: Namada>(
         "Current rewards available for claim: {} NAM",
         rewards.to_string_native()
     );
+
+    let auto_compound = unwrap_sdk_result(
+        rpc::query_auto_compound(context.client(), &validator, source.as_ref())
+            .await,
+    );
+    if auto_compound {
+        display_line!(
+            context.io(),
+            "The rewards are automatically re-bonded at every epoch."
+        );
+    }
 }
 
 pub async fn query_delegations<N: Namada>(
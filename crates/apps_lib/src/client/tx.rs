@@ -300,6 +300,24 @@ pub async fn submit_bridge_pool_tx<N: Namada>(
     Ok(())
 }
 
+pub async fn submit_bump_bridge_pool_fee<N: Namada>(
+    namada: &N,
+    args: args::BumpBridgePoolFee,
+) -> Result<(), error::Error> {
+    let fee_bump_tx_data = args.clone().build(namada).await?;
+
+    if args.tx.dump_tx || args.tx.dump_wrapper_tx {
+        tx::dump_tx(namada.io(), &args.tx, fee_bump_tx_data.0)?;
+    } else {
+        // NB: the gas payer of the transfer already paid its gas
+        // fees, so there is no public key to reveal
+        batch_opt_reveal_pk_and_submit(namada, &args.tx, &[], fee_bump_tx_data)
+            .await?;
+    }
+
+    Ok(())
+}
+
 pub async fn submit_custom<N: Namada>(
     namada: &N,
     args: args::TxCustom,
@@ -371,6 +389,96 @@ where
     Ok(())
 }
 
+/// Submit a transaction to configure the social recovery of an account
+pub async fn submit_configure_recovery<N: Namada>(
+    namada: &N,
+    args: args::TxConfigureRecovery,
+) -> Result<(), error::Error>
+where
+    <N::Client as namada_sdk::io::Client>::Error: std::fmt::Display,
+{
+    let (mut tx, signing_data) = args.build(namada).await?;
+
+    if args.tx.dump_tx || args.tx.dump_wrapper_tx {
+        tx::dump_tx(namada.io(), &args.tx, tx)?;
+    } else {
+        sign(namada, &mut tx, &args.tx, signing_data).await?;
+
+        namada.submit(tx, &args.tx).await?;
+    }
+
+    Ok(())
+}
+
+/// Submit a transaction for the guardians of an account to initiate the
+/// recovery of its keys
+pub async fn submit_initiate_recovery<N: Namada>(
+    namada: &N,
+    args: args::TxInitiateRecovery,
+) -> Result<(), error::Error>
+where
+    <N::Client as namada_sdk::io::Client>::Error: std::fmt::Display,
+{
+    let recovery_data = args.build(namada).await?;
+
+    if args.tx.dump_tx || args.tx.dump_wrapper_tx {
+        tx::dump_tx(namada.io(), &args.tx, recovery_data.0)?;
+    } else {
+        let guardians: Vec<_> = args.guardians.iter().collect();
+        batch_opt_reveal_pk_and_submit(
+            namada,
+            &args.tx,
+            &guardians,
+            recovery_data,
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// Submit a transaction to cancel the pending recovery of an account
+pub async fn submit_cancel_recovery<N: Namada>(
+    namada: &N,
+    args: args::TxCancelRecovery,
+) -> Result<(), error::Error>
+where
+    <N::Client as namada_sdk::io::Client>::Error: std::fmt::Display,
+{
+    let (mut tx, signing_data) = args.build(namada).await?;
+
+    if args.tx.dump_tx || args.tx.dump_wrapper_tx {
+        tx::dump_tx(namada.io(), &args.tx, tx)?;
+    } else {
+        sign(namada, &mut tx, &args.tx, signing_data).await?;
+
+        namada.submit(tx, &args.tx).await?;
+    }
+
+    Ok(())
+}
+
+/// Submit a transaction to finalize the pending recovery of an account
+pub async fn submit_finalize_recovery<N: Namada>(
+    namada: &N,
+    args: args::TxFinalizeRecovery,
+) -> Result<(), error::Error>
+where
+    <N::Client as namada_sdk::io::Client>::Error: std::fmt::Display,
+{
+    let (mut tx, signing_data) = args.build(namada).await?;
+
+    if args.tx.dump_tx || args.tx.dump_wrapper_tx {
+        tx::dump_tx(namada.io(), &args.tx, tx)?;
+    } else {
+        sign(namada, &mut tx, &args.tx, signing_data).await?;
+
+        namada.submit(tx, &args.tx).await?;
+    }
+
+    Ok(())
+}
+
 pub async fn submit_init_account<N: Namada>(
     namada: &N,
     args: args::TxInitAccount,
@@ -791,6 +899,7 @@ pub async fn submit_init_validator(
             tx_code_path: tx_init_account_code_path,
             public_keys: account_keys,
             threshold,
+            weights: vec![],
         },
     )
     .await?;
@@ -868,6 +977,75 @@ pub async fn submit_transparent_transfer(
     Ok(())
 }
 
+/// Submit a transaction to approve a spender to transfer the tokens of an
+/// owner
+pub async fn submit_approve(
+    namada: &impl Namada,
+    args: args::TxApprove,
+) -> Result<(), error::Error> {
+    let approve_data = args.build(namada).await?;
+
+    if args.tx.dump_tx || args.tx.dump_wrapper_tx {
+        tx::dump_tx(namada.io(), &args.tx, approve_data.0)?;
+    } else {
+        batch_opt_reveal_pk_and_submit(
+            namada,
+            &args.tx,
+            &[&args.owner],
+            approve_data,
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// Submit a transaction for a spender to transfer the tokens of an owner, from
+/// its allowance
+pub async fn submit_transfer_from(
+    namada: &impl Namada,
+    args: args::TxTransferFrom,
+) -> Result<(), error::Error> {
+    let transfer_data = args.build(namada).await?;
+
+    if args.tx.dump_tx || args.tx.dump_wrapper_tx {
+        tx::dump_tx(namada.io(), &args.tx, transfer_data.0)?;
+    } else {
+        batch_opt_reveal_pk_and_submit(
+            namada,
+            &args.tx,
+            &[&args.spender],
+            transfer_data,
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// Submit a transaction for a transfer of tokens that are locked in the
+/// balance of the target according to a vesting schedule
+pub async fn submit_vesting_transfer(
+    namada: &impl Namada,
+    args: args::TxVestingTransfer,
+) -> Result<(), error::Error> {
+    let transfer_data = args.build(namada).await?;
+
+    if args.tx.dump_tx || args.tx.dump_wrapper_tx {
+        tx::dump_tx(namada.io(), &args.tx, transfer_data.0)?;
+    } else {
+        batch_opt_reveal_pk_and_submit(
+            namada,
+            &args.tx,
+            &[&args.source],
+            transfer_data,
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
 // A mapper that replaces authorization signatures with those in a built-in map
 struct MapSaplingSigAuth(
     HashMap<usize, <sapling::Authorized as sapling::Authorization>::AuthSig>,
@@ -1415,6 +1593,102 @@ where
     Ok(())
 }
 
+pub async fn submit_delegate_votes<N: Namada>(
+    namada: &N,
+    args: args::DelegateVotes,
+) -> Result<(), error::Error>
+where
+    <N::Client as namada_sdk::io::Client>::Error: std::fmt::Display,
+{
+    let submit_delegate_votes_data = args.build(namada).await?;
+
+    if args.tx.dump_tx || args.tx.dump_wrapper_tx {
+        tx::dump_tx(namada.io(), &args.tx, submit_delegate_votes_data.0)?;
+    } else {
+        batch_opt_reveal_pk_and_submit(
+            namada,
+            &args.tx,
+            &[&args.delegator],
+            submit_delegate_votes_data,
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+pub async fn submit_deposit_proposal<N: Namada>(
+    namada: &N,
+    args: args::DepositProposal,
+) -> Result<(), error::Error>
+where
+    <N::Client as namada_sdk::io::Client>::Error: std::fmt::Display,
+{
+    let submit_deposit_proposal_data = args.build(namada).await?;
+
+    if args.tx.dump_tx || args.tx.dump_wrapper_tx {
+        tx::dump_tx(namada.io(), &args.tx, submit_deposit_proposal_data.0)?;
+    } else {
+        batch_opt_reveal_pk_and_submit(
+            namada,
+            &args.tx,
+            &[&args.depositor],
+            submit_deposit_proposal_data,
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+pub async fn submit_cancel_proposal<N: Namada>(
+    namada: &N,
+    args: args::CancelProposal,
+) -> Result<(), error::Error>
+where
+    <N::Client as namada_sdk::io::Client>::Error: std::fmt::Display,
+{
+    let submit_cancel_proposal_data = args.build(namada).await?;
+
+    if args.tx.dump_tx || args.tx.dump_wrapper_tx {
+        tx::dump_tx(namada.io(), &args.tx, submit_cancel_proposal_data.0)?;
+    } else {
+        batch_opt_reveal_pk_and_submit(
+            namada,
+            &args.tx,
+            &[&args.author],
+            submit_cancel_proposal_data,
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+pub async fn submit_amend_proposal<N: Namada>(
+    namada: &N,
+    args: args::AmendProposal,
+) -> Result<(), error::Error>
+where
+    <N::Client as namada_sdk::io::Client>::Error: std::fmt::Display,
+{
+    let submit_amend_proposal_data = args.build(namada).await?;
+
+    if args.tx.dump_tx || args.tx.dump_wrapper_tx {
+        tx::dump_tx(namada.io(), &args.tx, submit_amend_proposal_data.0)?;
+    } else {
+        batch_opt_reveal_pk_and_submit(
+            namada,
+            &args.tx,
+            &[&args.author],
+            submit_amend_proposal_data,
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
 pub async fn submit_reveal_pk<N: Namada>(
     namada: &N,
     args: args::RevealPk,
@@ -1536,6 +1810,66 @@ where
     Ok(())
 }
 
+pub async fn submit_auto_compound<N: Namada>(
+    namada: &N,
+    args: args::AutoCompound,
+) -> Result<(), error::Error>
+where
+    <N::Client as namada_sdk::io::Client>::Error: std::fmt::Display,
+{
+    let (mut tx, signing_data) = args.build(namada).await?;
+
+    if args.tx.dump_tx || args.tx.dump_wrapper_tx {
+        tx::dump_tx(namada.io(), &args.tx, tx)?;
+    } else {
+        sign(namada, &mut tx, &args.tx, signing_data).await?;
+
+        namada.submit(tx, &args.tx).await?;
+    }
+
+    Ok(())
+}
+
+pub async fn submit_liquid_bond<N: Namada>(
+    namada: &N,
+    args: args::LiquidBond,
+) -> Result<(), error::Error>
+where
+    <N::Client as namada_sdk::io::Client>::Error: std::fmt::Display,
+{
+    let (mut tx, signing_data) = args.build(namada).await?;
+
+    if args.tx.dump_tx || args.tx.dump_wrapper_tx {
+        tx::dump_tx(namada.io(), &args.tx, tx)?;
+    } else {
+        sign(namada, &mut tx, &args.tx, signing_data).await?;
+
+        namada.submit(tx, &args.tx).await?;
+    }
+
+    Ok(())
+}
+
+pub async fn submit_liquid_unbond<N: Namada>(
+    namada: &N,
+    args: args::LiquidUnbond,
+) -> Result<(), error::Error>
+where
+    <N::Client as namada_sdk::io::Client>::Error: std::fmt::Display,
+{
+    let (mut tx, signing_data) = args.build(namada).await?;
+
+    if args.tx.dump_tx || args.tx.dump_wrapper_tx {
+        tx::dump_tx(namada.io(), &args.tx, tx)?;
+    } else {
+        sign(namada, &mut tx, &args.tx, signing_data).await?;
+
+        namada.submit(tx, &args.tx).await?;
+    }
+
+    Ok(())
+}
+
 pub async fn submit_redelegate<N: Namada>(
     namada: &N,
     args: args::Redelegate,
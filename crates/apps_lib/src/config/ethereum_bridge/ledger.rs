@@ -1,8 +1,12 @@
 //! Runtime configuration for a validator node.
+use std::num::NonZeroUsize;
+
 #[allow(unused_imports)]
 use namada_sdk::ethereum_events::EthereumEvent;
 use serde::{Deserialize, Serialize};
 
+use crate::config::{Error, Result};
+
 /// Default [Ethereum JSON-RPC](https://ethereum.org/en/developers/docs/apis/json-rpc/) endpoint used by the oracle
 pub const DEFAULT_ORACLE_RPC_ENDPOINT: &str = "http://127.0.0.1:8545";
 
@@ -33,6 +37,16 @@ pub struct Config {
     /// The Ethereum JSON-RPC endpoint that the Ethereum event oracle will use
     /// to listen for events from the Ethereum bridge smart contracts
     pub oracle_rpc_endpoint: String,
+    /// Additional Ethereum JSON-RPC endpoints, in order of preference, that
+    /// the oracle fails over to when `oracle_rpc_endpoint` returns errors,
+    /// and that it cross-checks its responses with
+    #[serde(default)]
+    pub oracle_rpc_fallback_endpoints: Vec<String>,
+    /// The number of Ethereum JSON-RPC endpoints that must agree on the
+    /// hash and the events of a block before the oracle processes it. It
+    /// cannot exceed the number of endpoints. The default is 1.
+    #[serde(default = "default_oracle_rpc_quorum")]
+    pub oracle_rpc_quorum: NonZeroUsize,
     /// The size of bounded channel between the Ethereum oracle and main
     /// ledger subprocesses. This is the number of Ethereum events that
     /// can be held in the channel. The default is 1000.
@@ -44,7 +58,36 @@ impl Default for Config {
         Self {
             mode: Mode::RemoteEndpoint,
             oracle_rpc_endpoint: DEFAULT_ORACLE_RPC_ENDPOINT.to_owned(),
+            oracle_rpc_fallback_endpoints: vec![],
+            oracle_rpc_quorum: default_oracle_rpc_quorum(),
             channel_buffer_size: ORACLE_CHANNEL_BUFFER_SIZE,
         }
     }
 }
+
+impl Config {
+    /// Get all the Ethereum JSON-RPC endpoints of the oracle, in order of
+    /// preference.
+    pub fn oracle_rpc_endpoints(&self) -> Vec<String> {
+        std::iter::once(&self.oracle_rpc_endpoint)
+            .chain(&self.oracle_rpc_fallback_endpoints)
+            .cloned()
+            .collect()
+    }
+
+    /// Check that the quorum of the Ethereum JSON-RPC endpoints can be
+    /// reached with the configured endpoints.
+    pub fn validate(&self) -> Result<()> {
+        let quorum = self.oracle_rpc_quorum.get();
+        let endpoints =
+            self.oracle_rpc_fallback_endpoints.len().saturating_add(1);
+        if quorum > endpoints {
+            return Err(Error::InvalidOracleRpcQuorum { quorum, endpoints });
+        }
+        Ok(())
+    }
+}
+
+fn default_oracle_rpc_quorum() -> NonZeroUsize {
+    NonZeroUsize::MIN
+}
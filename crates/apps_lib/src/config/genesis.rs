@@ -591,6 +591,7 @@ pub fn make_dev_genesis(
                     discord_handle: None,
                     avatar: None,
                     name: None,
+                    reward_destination: None,
                 },
                 net_address: SocketAddr::new(
                     IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)),
@@ -408,6 +408,8 @@ impl Finalized {
             liveness_threshold,
             rewards_gain_p,
             rewards_gain_d,
+            min_self_bond_ratio,
+            max_commission_rate,
         } = self.parameters.pos_params.clone();
 
         namada_sdk::proof_of_stake::parameters::PosParams {
@@ -428,6 +430,8 @@ impl Finalized {
                 liveness_threshold,
                 rewards_gain_p,
                 rewards_gain_d,
+                min_self_bond_ratio,
+                max_commission_rate,
             },
             max_proposal_period: self.parameters.gov_params.max_proposal_period,
         }
@@ -438,21 +442,37 @@ impl Finalized {
     ) -> namada_sdk::governance::parameters::GovernanceParameters {
         let templates::GovernanceParams {
             min_proposal_fund,
+            min_proposal_initial_deposit,
+            proposal_cancellation_refund,
             max_proposal_code_size,
             min_proposal_voting_period,
             max_proposal_period,
             max_proposal_content_size,
             min_proposal_grace_epochs,
             max_proposal_latency,
+            default_tally_params,
+            default_with_wasm_tally_params,
+            pgf_steward_tally_params,
+            pgf_payment_tally_params,
+            pgf_steward_payment_tally_params,
         } = self.parameters.gov_params.clone();
         namada_sdk::governance::parameters::GovernanceParameters {
             min_proposal_fund: Amount::native_whole(min_proposal_fund),
+            min_proposal_initial_deposit: Amount::native_whole(
+                min_proposal_initial_deposit.unwrap_or(min_proposal_fund),
+            ),
+            proposal_cancellation_refund,
             max_proposal_code_size,
             max_proposal_period,
             max_proposal_content_size,
             min_proposal_grace_epochs,
             min_proposal_voting_period,
             max_proposal_latency,
+            default_tally_params,
+            default_with_wasm_tally_params,
+            pgf_steward_tally_params,
+            pgf_payment_tally_params,
+            pgf_steward_payment_tally_params,
         }
     }
 
@@ -9,11 +9,17 @@ use namada_macros::BorshDeserializer;
 use namada_migrations::*;
 use namada_sdk::address::Address;
 use namada_sdk::borsh::{BorshDeserialize, BorshSerialize};
+use namada_sdk::chain::Epoch;
 use namada_sdk::dec::Dec;
 use namada_sdk::eth_bridge::storage::parameters::{
     Contracts, Erc20WhitelistEntry, MinimumConfirmations,
 };
+use namada_sdk::governance::parameters::GovernanceParameters;
+use namada_sdk::governance::utils::TallyParams;
 use namada_sdk::parameters::ProposalBytes;
+use namada_sdk::token::vesting::{
+    MAX_VESTING_SCHEDULES, MIN_VESTING_WHOLE_AMOUNT,
+};
 use namada_sdk::token::{
     Amount, DenominatedAmount, Denomination, NATIVE_MAX_DECIMAL_PLACES,
 };
@@ -74,6 +80,9 @@ pub fn read_transactions(
 )]
 pub struct UndenominatedBalances {
     pub token: BTreeMap<Alias, RawTokenBalances>,
+    /// Vesting schedules that lock some of the balances of each token
+    #[serde(default)]
+    pub vesting: BTreeMap<Alias, Vec<VestingSchedule>>,
 }
 
 impl UndenominatedBalances {
@@ -85,6 +94,7 @@ impl UndenominatedBalances {
     ) -> eyre::Result<DenominatedBalances> {
         let mut balances = DenominatedBalances {
             token: BTreeMap::new(),
+            vesting: BTreeMap::new(),
         };
         for (alias, bals) in self.token {
             let denom = tokens
@@ -107,6 +117,29 @@ impl UndenominatedBalances {
                 .token
                 .insert(alias, TokenBalances(denominated_bals));
         }
+        for (alias, schedules) in self.vesting {
+            let denom = tokens
+                .token
+                .get(&alias)
+                .ok_or_else(|| {
+                    eyre::eyre!(
+                        "A vesting schedule of token {} was found, but this \
+                         token was not found in the `tokens.toml` file",
+                        alias
+                    )
+                })?
+                .denom;
+            let denominated_schedules = schedules
+                .into_iter()
+                .map(|schedule| {
+                    Ok(VestingSchedule {
+                        amount: schedule.amount.increase_precision(denom)?,
+                        ..schedule
+                    })
+                })
+                .collect::<eyre::Result<Vec<_>>>()?;
+            balances.vesting.insert(alias, denominated_schedules);
+        }
         Ok(balances)
     }
 }
@@ -125,6 +158,9 @@ impl UndenominatedBalances {
 )]
 pub struct DenominatedBalances {
     pub token: BTreeMap<Alias, TokenBalances>,
+    /// Vesting schedules that lock some of the balances of each token
+    #[serde(default)]
+    pub vesting: BTreeMap<Alias, Vec<VestingSchedule>>,
 }
 
 /// Genesis balances for a given token
@@ -155,6 +191,45 @@ pub struct RawTokenBalances(pub BTreeMap<Address, token::DenominatedAmount>);
 )]
 pub struct TokenBalances(pub BTreeMap<Address, token::DenominatedAmount>);
 
+/// A genesis vesting schedule that locks some of the balance of an owner.
+/// Nothing is unlocked before the cliff epoch. From the cliff epoch on, the
+/// amount is unlocked linearly from the start epoch until the end epoch.
+#[derive(
+    Clone,
+    Debug,
+    Deserialize,
+    Serialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSerialize,
+    PartialEq,
+    Eq,
+)]
+pub struct VestingSchedule {
+    /// The owner of the locked balance
+    pub owner: Address,
+    /// The vested amount
+    pub amount: token::DenominatedAmount,
+    /// The epoch from which the amount starts vesting
+    pub start_epoch: Epoch,
+    /// The first epoch in which any of the amount is unlocked
+    pub cliff_epoch: Epoch,
+    /// The epoch from which the whole amount is unlocked
+    pub end_epoch: Epoch,
+}
+
+impl VestingSchedule {
+    /// The vesting schedule to be written to storage
+    pub fn schedule(&self) -> token::vesting::VestingSchedule {
+        token::vesting::VestingSchedule {
+            amount: self.amount.amount(),
+            start_epoch: self.start_epoch,
+            cliff_epoch: self.cliff_epoch,
+            end_epoch: self.end_epoch,
+        }
+    }
+}
+
 /// Genesis validity predicates
 #[derive(
     Clone,
@@ -415,6 +490,12 @@ pub struct PosParams {
     pub rewards_gain_p: Dec,
     /// PoS gain d (read only)
     pub rewards_gain_d: Dec,
+    /// The minimum ratio of a validator's stake that must be self-bonded
+    #[serde(default)]
+    pub min_self_bond_ratio: Dec,
+    /// The maximum commission rate that a validator can set
+    #[serde(default = "Dec::one")]
+    pub max_commission_rate: Dec,
 }
 
 #[derive(
@@ -431,6 +512,15 @@ pub struct PosParams {
 pub struct GovernanceParams {
     /// Min funds to stake to submit a proposal
     pub min_proposal_fund: u64,
+    /// Min funds deposited by the author of a proposal, the rest of the min
+    /// proposal fund can be deposited by anyone until its voting start epoch.
+    /// Defaults to the whole min proposal fund
+    #[serde(default)]
+    pub min_proposal_initial_deposit: Option<u64>,
+    /// Fraction of the author's deposit refunded when a proposal is cancelled
+    /// by its author. Other depositors are refunded in full
+    #[serde(default = "default_proposal_cancellation_refund")]
+    pub proposal_cancellation_refund: Dec,
     /// Maximum size of proposal in bytes
     pub max_proposal_code_size: u64,
     /// Minimum number of epochs between the proposal end epoch and start epoch
@@ -444,6 +534,20 @@ pub struct GovernanceParams {
     pub min_proposal_grace_epochs: u64,
     /// Maximum number of epochs between current epoch and start epochs
     pub max_proposal_latency: u64,
+    /// Tally parameters of default proposals
+    pub default_tally_params: TallyParams,
+    /// Tally parameters of default proposals with wasm code
+    pub default_with_wasm_tally_params: TallyParams,
+    /// Tally parameters of PGF stewards proposals
+    pub pgf_steward_tally_params: TallyParams,
+    /// Tally parameters of PGF funding proposals
+    pub pgf_payment_tally_params: TallyParams,
+    /// Tally parameters of PGF funding proposals authored by a steward
+    pub pgf_steward_payment_tally_params: TallyParams,
+}
+
+fn default_proposal_cancellation_refund() -> Dec {
+    GovernanceParameters::default().proposal_cancellation_refund
 }
 
 #[derive(
@@ -883,6 +987,51 @@ pub fn validate_parameters(
             );
         }
     }
+    // check that the tally parameters of each proposal type are fractions
+    let gov_params = &parameters.gov_params;
+    for (proposal_type, tally_params) in [
+        ("default", &gov_params.default_tally_params),
+        (
+            "default with wasm",
+            &gov_params.default_with_wasm_tally_params,
+        ),
+        ("PGF steward", &gov_params.pgf_steward_tally_params),
+        ("PGF payment", &gov_params.pgf_payment_tally_params),
+        (
+            "PGF steward payment",
+            &gov_params.pgf_steward_payment_tally_params,
+        ),
+    ] {
+        if !tally_params.is_valid() {
+            eprintln!(
+                "The tally parameters of {proposal_type} proposals must be \
+                 between 0 and 1"
+            );
+            is_valid = false;
+        }
+    }
+    // check that the initial deposit of a proposal doesn't exceed its fund
+    if let Some(min_proposal_initial_deposit) =
+        gov_params.min_proposal_initial_deposit
+    {
+        if min_proposal_initial_deposit > gov_params.min_proposal_fund {
+            eprintln!(
+                "The min. proposal initial deposit {} must not exceed the \
+                 min. proposal fund {}",
+                min_proposal_initial_deposit, gov_params.min_proposal_fund
+            );
+            is_valid = false;
+        }
+    }
+    // check that the cancellation refund is a fraction of the deposit
+    let refund = gov_params.proposal_cancellation_refund;
+    if refund.is_negative() || refund > Dec::one() {
+        eprintln!(
+            "The proposal cancellation refund {} must be between 0 and 1",
+            gov_params.proposal_cancellation_refund
+        );
+        is_valid = false;
+    }
     let Parameters {
         parameters,
         pos_params,
@@ -976,6 +1125,69 @@ pub fn validate_balances(
             }
         }
     });
+
+    balances.vesting.iter().for_each(|(token, schedules)| {
+        // The vested amounts of every owner must be covered by its balance
+        let mut vested: BTreeMap<&Address, token::Amount> = BTreeMap::new();
+        // and every owner is subject to the same limits as on-chain vesting
+        let mut counts: BTreeMap<&Address, usize> = BTreeMap::new();
+        for schedule in schedules {
+            let owner = &schedule.owner;
+            if let Err(err) = schedule.schedule().validate() {
+                is_valid = false;
+                eprintln!(
+                    "Invalid vesting schedule of token {token} for {owner}: \
+                     {err}"
+                );
+            }
+            let min_amount = Amount::from_uint(
+                MIN_VESTING_WHOLE_AMOUNT,
+                schedule.amount.denom(),
+            );
+            if !min_amount.is_ok_and(|min| schedule.amount.amount() >= min) {
+                is_valid = false;
+                eprintln!(
+                    "A vesting schedule of token {token} for {owner} must \
+                     vest at least {MIN_VESTING_WHOLE_AMOUNT} whole token(s)"
+                );
+            }
+            let count = counts.entry(owner).or_default();
+            *count = count.saturating_add(1);
+            let sum = vested.entry(owner).or_default();
+            match sum.checked_add(schedule.amount.amount()) {
+                Some(new_sum) => *sum = new_sum,
+                None => {
+                    is_valid = false;
+                    eprintln!(
+                        "Vested amounts of token {token} for {owner} overflow \
+                         `token::Amount`"
+                    );
+                }
+            }
+        }
+        for (owner, count) in counts {
+            if count > MAX_VESTING_SCHEDULES {
+                is_valid = false;
+                eprintln!(
+                    "The token {token} of {owner} cannot have more than \
+                     {MAX_VESTING_SCHEDULES} vesting schedules"
+                );
+            }
+        }
+        let token_balances = balances.token.get(token);
+        for (owner, sum) in vested {
+            let balance = token_balances
+                .and_then(|balances| balances.get(owner))
+                .unwrap_or_default();
+            if sum > balance {
+                is_valid = false;
+                eprintln!(
+                    "Vested amounts of token {token} for {owner} exceed its \
+                     balance in the Balances file."
+                );
+            }
+        }
+    });
     is_valid
 }
 
@@ -1057,4 +1269,76 @@ mod tests {
         let example_balance = balances.token.get(&token_alias).unwrap();
         assert_eq!(balance, example_balance.0.get(&address).unwrap().amount());
     }
+
+    #[test]
+    fn test_read_and_validate_vesting() {
+        let test_dir = tempdir().unwrap();
+        let path = test_dir.path().join(BALANCES_FILE_NAME);
+        let address: Address = (&key::testing::keypair_1().ref_to()).into();
+        let token_alias = Alias::from("Some_token".to_string());
+        let contents = format!(
+            r#"
+		[token.{token_alias}]
+		{address} = "100"
+
+		[[vesting.{token_alias}]]
+		owner = "{address}"
+		amount = "60"
+		start_epoch = 0
+		cliff_epoch = 2
+		end_epoch = 10
+	    "#
+        );
+        fs::write(&path, contents).unwrap();
+
+        let tokens = Tokens {
+            token: BTreeMap::from([(
+                token_alias.clone(),
+                TokenConfig {
+                    denom: Denomination(6),
+                    masp_params: None,
+                },
+            )]),
+        };
+        let mut balances =
+            read_balances(&path).unwrap().denominate(&tokens).unwrap();
+        let schedules = balances.vesting.get(&token_alias).unwrap();
+        assert_eq!(
+            schedules[0].schedule(),
+            token::vesting::VestingSchedule {
+                amount: token::Amount::from_uint(60, 6).unwrap(),
+                start_epoch: Epoch(0),
+                cliff_epoch: Epoch(2),
+                end_epoch: Epoch(10),
+            }
+        );
+        assert!(validate_balances(&balances, Some(&tokens), None));
+
+        // the vested amounts must be covered by the owner's balance
+        let schedules = balances.vesting.get_mut(&token_alias).unwrap();
+        schedules.push(schedules[0].clone());
+        assert!(!validate_balances(&balances, Some(&tokens), None));
+
+        // and the schedules must be well-formed
+        let schedules = balances.vesting.get_mut(&token_alias).unwrap();
+        schedules.pop();
+        schedules[0].cliff_epoch = Epoch(11);
+        assert!(!validate_balances(&balances, Some(&tokens), None));
+
+        // a schedule must vest at least the minimum whole amount
+        let schedules = balances.vesting.get_mut(&token_alias).unwrap();
+        schedules[0].cliff_epoch = Epoch(2);
+        schedules[0].amount = "0.5".parse().unwrap();
+        assert!(!validate_balances(&balances, Some(&tokens), None));
+
+        // and an owner cannot have too many schedules
+        let schedules = balances.vesting.get_mut(&token_alias).unwrap();
+        schedules[0].amount = "1.000000".parse().unwrap();
+        let schedule = schedules[0].clone();
+        schedules.resize(MAX_VESTING_SCHEDULES, schedule.clone());
+        assert!(validate_balances(&balances, Some(&tokens), None));
+        let schedules = balances.vesting.get_mut(&token_alias).unwrap();
+        schedules.push(schedule);
+        assert!(!validate_balances(&balances, Some(&tokens), None));
+    }
 }
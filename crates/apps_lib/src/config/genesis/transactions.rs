@@ -330,6 +330,7 @@ pub fn init_validator(
             discord_handle,
             avatar,
             name,
+            reward_destination: None,
         },
     };
     let unsigned_validator_addr =
@@ -5,14 +5,20 @@ pub mod genesis;
 pub mod global;
 pub mod utils;
 
+use std::fmt::{self, Display};
 use std::fs::{create_dir_all, File};
 use std::io::Write;
+use std::net::SocketAddr;
 use std::num::NonZeroU64;
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
 use directories::ProjectDirs;
+use namada_sdk::address::Address;
 use namada_sdk::chain::{BlockHeight, ChainId};
 use namada_sdk::collections::HashMap;
+use namada_sdk::key::common;
+use namada_sdk::state::StorageMode;
 use namada_sdk::time::Rfc3339String;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
@@ -114,6 +120,10 @@ pub struct Shell {
     /// When set, will limit the how many block heights in the past can the
     /// storage be queried for reading values.
     pub storage_read_past_height_limit: Option<u64>,
+    /// The retention mode of the historical state in the DB. In the pruned
+    /// mode, queries at heights older than the retained blocks fail.
+    #[serde(default)]
+    pub storage_mode: StorageMode,
     /// Use the [`Ledger::db_dir()`] method to read the value.
     db_dir: PathBuf,
     /// Use the [`Ledger::cometbft_dir()`] method to read the value.
@@ -127,6 +137,111 @@ pub struct Shell {
     pub blocks_between_snapshots: Option<NonZeroU64>,
     /// Number of snapshots to keep
     pub snapshots_to_keep: Option<NonZeroU64>,
+    /// The policy used by the block proposer to order the wrapper txs
+    /// retrieved from the mempool
+    #[serde(default)]
+    pub tx_ordering: TxOrdering,
+    /// When set, the node indexes the inner txs that touched each address,
+    /// such that the tx history of an address can be queried
+    #[serde(default)]
+    pub tx_history_index: bool,
+    /// When set, the node serves Prometheus metrics at `/metrics` on the
+    /// given address
+    #[serde(default)]
+    pub prometheus_listen_addr: Option<SocketAddr>,
+    /// When set, the protocol and Ethereum bridge keys of the validator are
+    /// held by a remote signer instead of the node's wallet
+    #[serde(default)]
+    pub remote_signer: Option<RemoteSignerConfig>,
+}
+
+/// The configuration of a validator node's remote signer.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RemoteSignerConfig {
+    /// The address of the validator whose keys are held by the signer
+    pub validator_address: Address,
+    /// The endpoint on which the signer listens for requests
+    pub endpoint: SignerEndpoint,
+    /// The pinned public key with which the signer authenticates itself to
+    /// the node
+    pub signer_public_key: common::PublicKey,
+    /// The file holding the secret key with which the node authenticates
+    /// itself to the signer. The key is generated if the file doesn't exist.
+    /// Defaults to "remote_signer_auth_key" in the chain directory.
+    #[serde(default)]
+    pub auth_key_path: Option<PathBuf>,
+    /// The timeout of a request to the signer, in milliseconds
+    #[serde(default = "RemoteSignerConfig::default_timeout_ms")]
+    pub timeout_ms: u64,
+}
+
+impl RemoteSignerConfig {
+    /// The default timeout of a request to the signer
+    pub fn default_timeout_ms() -> u64 {
+        1000
+    }
+}
+
+/// The endpoint of a remote signer
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SignerEndpoint {
+    /// A Unix domain socket at the given path
+    Unix(PathBuf),
+    /// A TCP socket at the given address
+    Tcp(SocketAddr),
+}
+
+impl FromStr for SignerEndpoint {
+    type Err = SerdeError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        if let Some(path) = s.strip_prefix("unix://") {
+            Ok(Self::Unix(path.into()))
+        } else if let Some(addr) = s.strip_prefix("tcp://") {
+            addr.parse().map(Self::Tcp).map_err(|err| {
+                SerdeError::Message(format!(
+                    "Invalid signer TCP address {addr}: {err}"
+                ))
+            })
+        } else {
+            Err(SerdeError::Message(format!(
+                "Invalid signer endpoint {s}. Expected either \
+                 `unix://{{path}}` or `tcp://{{ip}}:{{port}}`"
+            )))
+        }
+    }
+}
+
+impl Display for SignerEndpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unix(path) => write!(f, "unix://{}", path.display()),
+            Self::Tcp(addr) => write!(f, "tcp://{addr}"),
+        }
+    }
+}
+
+/// The policy used to order wrapper transactions retrieved from the
+/// mempool when building a block proposal.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "policy", rename_all = "snake_case")]
+pub enum TxOrdering {
+    /// Include txs in the order in which CometBFT's mempool handed them
+    /// over.
+    #[default]
+    Fifo,
+    /// Include txs with the highest effective fee per gas unit first. The
+    /// fee is normalised by the minimum gas price of the fee token, such
+    /// that fees paid in different whitelisted tokens can be compared.
+    FeePriority,
+    /// Like [`TxOrdering::FeePriority`], but include at most
+    /// `max_txs_per_signer` txs of any given wrapper signer in a single
+    /// block.
+    SignerFairness {
+        /// The maximum number of txs per signer allowed in a proposal
+        max_txs_per_signer: NonZeroU64,
+    },
 }
 
 impl Ledger {
@@ -151,12 +266,17 @@ impl Ledger {
                 tx_wasm_compilation_cache_bytes: None,
                 // Default corresponds to 1 hour of past blocks at 1 block/sec
                 storage_read_past_height_limit: Some(3600),
+                storage_mode: StorageMode::default(),
                 db_dir: DB_DIR.into(),
                 cometbft_dir: COMETBFT_DIR.into(),
                 action_at_height: None,
                 tendermint_mode: mode,
                 blocks_between_snapshots: None,
                 snapshots_to_keep: None,
+                tx_ordering: TxOrdering::default(),
+                tx_history_index: false,
+                prometheus_listen_addr: None,
+                remote_signer: None,
             },
             cometbft: tendermint_config,
             ethereum_bridge: ethereum_bridge::ledger::Config::default(),
@@ -210,6 +330,11 @@ pub enum Error {
          {{protocol}}/{{ip}}/tcp/{{port}}/p2p/{{peerid}}"
     )]
     BadBootstrapPeerFormat(String),
+    #[error(
+        "The quorum of {quorum} Ethereum RPC endpoints can't be reached with \
+         {endpoints} configured endpoint(s)"
+    )]
+    InvalidOracleRpcQuorum { quorum: usize, endpoints: usize },
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -292,10 +417,13 @@ impl Config {
                     .separator("__"),
             );
 
-        let config = builder.build().map_err(Error::ReadError)?;
-        config
+        let config: Self = builder
+            .build()
+            .map_err(Error::ReadError)?
             .try_deserialize()
-            .map_err(Error::DeserializationError)
+            .map_err(Error::DeserializationError)?;
+        config.ledger.ethereum_bridge.validate()?;
+        Ok(config)
     }
 
     /// Generate configuration and write it to a file.
@@ -857,11 +985,12 @@ namespace = "cometbft"
 #[cfg(test)]
 mod tests {
     use std::env;
+    use std::num::NonZeroUsize;
 
     use namada_sdk::chain::ChainId;
     use tempfile::tempdir;
 
-    use super::{Config, DEFAULT_COMETBFT_CONFIG};
+    use super::{ethereum_bridge, Config, Error, DEFAULT_COMETBFT_CONFIG};
     use crate::config::TendermintMode;
     use crate::tendermint_config::TendermintConfig;
 
@@ -870,6 +999,28 @@ mod tests {
         assert!(TendermintConfig::parse_toml(DEFAULT_COMETBFT_CONFIG).is_ok());
     }
 
+    /// Check that the quorum of the Ethereum RPC endpoints can't exceed the
+    /// number of endpoints
+    #[test]
+    fn test_oracle_rpc_quorum_validation() {
+        let mut config = ethereum_bridge::ledger::Config::default();
+        assert!(config.validate().is_ok());
+
+        config.oracle_rpc_quorum = NonZeroUsize::new(2).unwrap();
+        assert!(matches!(
+            config.validate(),
+            Err(Error::InvalidOracleRpcQuorum {
+                quorum: 2,
+                endpoints: 1
+            })
+        ));
+
+        config
+            .oracle_rpc_fallback_endpoints
+            .push("http://127.0.0.1:8546".to_owned());
+        assert!(config.validate().is_ok());
+    }
+
     /// Check that a key-val set from an env var gets applied to a loaded config
     #[test]
     fn test_config_env_var() {
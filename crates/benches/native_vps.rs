@@ -18,7 +18,9 @@ use namada_apps_lib::collections::HashMap;
 use namada_apps_lib::eth_bridge::read_native_erc20_address;
 use namada_apps_lib::eth_bridge::storage::eth_bridge_queries::is_bridge_comptime_enabled;
 use namada_apps_lib::eth_bridge::storage::whitelist;
-use namada_apps_lib::eth_bridge_pool::{GasFee, PendingTransfer};
+use namada_apps_lib::eth_bridge_pool::{
+    BridgePoolChange, GasFee, PendingTransfer,
+};
 use namada_apps_lib::gas::{TxGasMeter, VpGasMeter};
 use namada_apps_lib::governance::pgf::storage::steward::StewardDetail;
 use namada_apps_lib::governance::storage::proposal::ProposalType;
@@ -144,6 +146,7 @@ fn governance(c: &mut Criterion) {
                             .unchecked_add(3_u64),
                         activation_epoch: voting_start_epoch
                             .unchecked_add(9_u64),
+                        deposit: None,
                     },
                     None,
                     Some(vec![content_section]),
@@ -201,6 +204,7 @@ fn governance(c: &mut Criterion) {
                             .unchecked_add(3_u64),
                         activation_epoch: voting_start_epoch
                             .unchecked_add(9_u64),
+                        deposit: None,
                     },
                     None,
                     Some(vec![content_section, wasm_code_section]),
@@ -1294,7 +1298,7 @@ fn eth_bridge_nut(c: &mut Criterion) {
     let native_erc20_addres = read_native_erc20_address(&shell.state).unwrap();
 
     let signed_tx = {
-        let data = PendingTransfer {
+        let transfer = PendingTransfer {
             transfer: namada_apps_lib::eth_bridge_pool::TransferToEthereum {
                 kind:
                     namada_apps_lib::eth_bridge_pool::TransferToEthereumKind::Erc20,
@@ -1311,7 +1315,10 @@ fn eth_bridge_nut(c: &mut Criterion) {
         };
         shell.generate_tx(
             TX_BRIDGE_POOL_WASM,
-            data,
+            BridgePoolChange::AddTransfer {
+                transfer,
+                expiry: None,
+            },
             None,
             None,
             vec![&defaults::albert_keypair()],
@@ -1367,7 +1374,7 @@ fn eth_bridge(c: &mut Criterion) {
     let native_erc20_addres = read_native_erc20_address(&shell.state).unwrap();
 
     let signed_tx = {
-        let data = PendingTransfer {
+        let transfer = PendingTransfer {
             transfer: namada_apps_lib::eth_bridge_pool::TransferToEthereum {
                 kind:
                     namada_apps_lib::eth_bridge_pool::TransferToEthereumKind::Erc20,
@@ -1384,7 +1391,10 @@ fn eth_bridge(c: &mut Criterion) {
         };
         shell.generate_tx(
             TX_BRIDGE_POOL_WASM,
-            data,
+            BridgePoolChange::AddTransfer {
+                transfer,
+                expiry: None,
+            },
             None,
             None,
             vec![&defaults::albert_keypair()],
@@ -1464,7 +1474,7 @@ fn eth_bridge_pool(c: &mut Criterion) {
     shell.state.write(&denom_key, 0).unwrap();
 
     let signed_tx = {
-        let data = PendingTransfer {
+        let transfer = PendingTransfer {
             transfer: namada_apps_lib::eth_bridge_pool::TransferToEthereum {
                 kind:
                     namada_apps_lib::eth_bridge_pool::TransferToEthereumKind::Erc20,
@@ -1481,7 +1491,10 @@ fn eth_bridge_pool(c: &mut Criterion) {
         };
         shell.generate_tx(
             TX_BRIDGE_POOL_WASM,
-            data,
+            BridgePoolChange::AddTransfer {
+                transfer,
+                expiry: None,
+            },
             None,
             None,
             vec![&defaults::albert_keypair()],
@@ -147,6 +147,11 @@ impl From<raw::Address<'_, raw::Validated>> for Address {
             raw::Discriminant::ReplayProtection => {
                 Address::Internal(InternalAddress::ReplayProtection)
             }
+            raw::Discriminant::StakeShare => Address::Internal(
+                InternalAddress::StakeShare(EstablishedAddress {
+                    hash: *raw_addr.data(),
+                }),
+            ),
         }
     }
 }
@@ -253,6 +258,14 @@ impl<'addr> From<&'addr Address> for raw::Address<'addr, raw::Validated> {
                 .validate()
                 .expect("This raw address is valid")
             }
+            Address::Internal(InternalAddress::StakeShare(
+                EstablishedAddress { hash },
+            )) => {
+                raw::Address::from_discriminant(raw::Discriminant::StakeShare)
+                    .with_data_array_ref(hash)
+                    .validate()
+                    .expect("This raw address is valid")
+            }
         }
     }
 }
@@ -594,6 +607,8 @@ pub enum InternalAddress {
     /// Address with temporary storage is used to pass data from txs to VPs
     /// which is never committed to DB
     TempStorage,
+    /// Liquid staking share token of the validator with the given address
+    StakeShare(EstablishedAddress),
 }
 
 impl Display for InternalAddress {
@@ -617,6 +632,9 @@ impl Display for InternalAddress {
                 Self::Masp => "MASP".to_string(),
                 Self::ReplayProtection => "ReplayProtection".to_string(),
                 Self::TempStorage => "TempStorage".to_string(),
+                Self::StakeShare(validator) => {
+                    format!("StakeShare: {validator}")
+                }
             }
         )
     }
@@ -847,8 +865,9 @@ pub mod testing {
             InternalAddress::Masp => {}
             InternalAddress::Multitoken => {}
             InternalAddress::ReplayProtection => {}
-            InternalAddress::TempStorage => {} /* Add new addresses in the
-                                                * `prop_oneof` below. */
+            InternalAddress::TempStorage => {}
+            InternalAddress::StakeShare(_) => {} /* Add new addresses in the
+                                                  * `prop_oneof` below. */
         };
         prop_oneof![
             Just(InternalAddress::PoS),
@@ -866,6 +885,7 @@ pub mod testing {
             Just(InternalAddress::Masp),
             Just(InternalAddress::ReplayProtection),
             Just(InternalAddress::TempStorage),
+            arb_stake_share(),
         ]
     }
 
@@ -902,6 +922,10 @@ pub mod testing {
         })
     }
 
+    fn arb_stake_share() -> impl Strategy<Value = InternalAddress> {
+        arb_established_address().prop_map(InternalAddress::StakeShare)
+    }
+
     /// NAM token address for testing
     pub fn nam() -> Address {
         Address::decode("tnam1q99c37u38grkdcc2qze0hz4zjjd8zr3yucd3mzgz")
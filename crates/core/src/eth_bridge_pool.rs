@@ -16,6 +16,7 @@ use super::storage::{self, KeySeg};
 use crate as namada_core; // This is needed for `StorageKeys` macro
 use crate::address::Address;
 use crate::borsh::BorshSerializeExt;
+use crate::chain::BlockHeight;
 use crate::eth_abi::Encode;
 use crate::ethereum_events::{
     EthAddress, TransferToEthereum as TransferToEthereumEvent,
@@ -35,6 +36,12 @@ pub struct Segments {
     pub signed_root: &'static str,
     /// Bridge pool nonce storage key
     pub bridge_pool_nonce: &'static str,
+    /// Sub-storage of the expiry heights of pending transfers
+    pub expiry: &'static str,
+    /// Sub-storage of the relayer fee bumps of pending transfers
+    pub fee_bump: &'static str,
+    /// Height of the last refund of expired transfers
+    pub refund_height: &'static str,
 }
 
 /// Check if a key is for a pending transfer
@@ -65,6 +72,75 @@ pub fn get_key_from_hash(hash: &KeccakHash) -> Key {
     }
 }
 
+/// Get the storage key for the expiry height of a pending transfer
+pub fn get_expiry_key(hash: &KeccakHash) -> Key {
+    Key {
+        segments: vec![
+            DbKeySeg::AddressSeg(BRIDGE_POOL_ADDRESS),
+            DbKeySeg::StringSeg(Segments::VALUES.expiry.into()),
+            hash.to_db_key(),
+        ],
+    }
+}
+
+/// Get the storage key prefix of the expiry heights of pending transfers
+pub fn get_expiry_prefix() -> Key {
+    Key {
+        segments: vec![
+            DbKeySeg::AddressSeg(BRIDGE_POOL_ADDRESS),
+            DbKeySeg::StringSeg(Segments::VALUES.expiry.into()),
+        ],
+    }
+}
+
+/// Get the hash of the pending transfer of an expiry height key
+pub fn is_expiry_key(key: &Key) -> Option<KeccakHash> {
+    match &key.segments[..] {
+        [DbKeySeg::AddressSeg(addr), DbKeySeg::StringSeg(segment), DbKeySeg::StringSeg(hash)]
+            if addr == &BRIDGE_POOL_ADDRESS
+                && segment == Segments::VALUES.expiry =>
+        {
+            KeccakHash::try_from(hash.as_str()).ok()
+        }
+        _ => None,
+    }
+}
+
+/// Get the storage key for the total relayer fee bump of a pending
+/// transfer
+pub fn get_fee_bump_key(hash: &KeccakHash) -> Key {
+    Key {
+        segments: vec![
+            DbKeySeg::AddressSeg(BRIDGE_POOL_ADDRESS),
+            DbKeySeg::StringSeg(Segments::VALUES.fee_bump.into()),
+            hash.to_db_key(),
+        ],
+    }
+}
+
+/// Get the storage key prefix of the relayer fee bumps of pending transfers
+pub fn get_fee_bump_prefix() -> Key {
+    Key {
+        segments: vec![
+            DbKeySeg::AddressSeg(BRIDGE_POOL_ADDRESS),
+            DbKeySeg::StringSeg(Segments::VALUES.fee_bump.into()),
+        ],
+    }
+}
+
+/// Get the hash of the pending transfer of a relayer fee bump key
+pub fn is_fee_bump_key(key: &Key) -> Option<KeccakHash> {
+    match &key.segments[..] {
+        [DbKeySeg::AddressSeg(addr), DbKeySeg::StringSeg(segment), DbKeySeg::StringSeg(hash)]
+            if addr == &BRIDGE_POOL_ADDRESS
+                && segment == Segments::VALUES.fee_bump =>
+        {
+            KeccakHash::try_from(hash.as_str()).ok()
+        }
+        _ => None,
+    }
+}
+
 /// A version used in our Ethereuem smart contracts
 const VERSION: u8 = 1;
 
@@ -338,6 +414,97 @@ impl From<&PendingTransfer> for Key {
     }
 }
 
+/// A change to the Ethereum bridge pool, requested by a tx.
+///
+/// Its encoding starts with the [`BridgePoolChange::VERSION`] byte, which
+/// is never the first byte of an encoded [`PendingTransfer`], i.e. the tag
+/// of its [`TransferToEthereumKind`]. The data of older Bridge pool txs,
+/// which only held a [`PendingTransfer`], is thus still decoded, as a
+/// transfer to add without an expiry.
+#[derive(
+    Debug, Clone, PartialEq, Eq, Serialize, Deserialize, BorshDeserializer,
+)]
+pub enum BridgePoolChange {
+    /// Add a transfer to the pool
+    AddTransfer {
+        /// The transfer to add
+        transfer: PendingTransfer,
+        /// The block height from which the transfer is refunded, if it
+        /// hasn't been relayed yet
+        expiry: Option<BlockHeight>,
+    },
+    /// Increase the fee paid to the relayer of a pending transfer. The fee
+    /// bump is paid by the gas fee payer of the transfer, in the token of
+    /// its gas fee.
+    BumpFee {
+        /// The hash of the pending transfer
+        transfer: KeccakHash,
+        /// The amount by which the fee is increased
+        amount: Amount,
+    },
+}
+
+impl BridgePoolChange {
+    /// The version of the encoding of Bridge pool changes
+    pub const VERSION: u8 = u8::MAX;
+}
+
+impl BorshSerialize for BridgePoolChange {
+    fn serialize<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> std::io::Result<()> {
+        Self::VERSION.serialize(writer)?;
+        match self {
+            Self::AddTransfer { transfer, expiry } => {
+                0_u8.serialize(writer)?;
+                transfer.serialize(writer)?;
+                expiry.serialize(writer)
+            }
+            Self::BumpFee { transfer, amount } => {
+                1_u8.serialize(writer)?;
+                transfer.serialize(writer)?;
+                amount.serialize(writer)
+            }
+        }
+    }
+}
+
+impl BorshDeserialize for BridgePoolChange {
+    fn deserialize_reader<R: std::io::Read>(
+        reader: &mut R,
+    ) -> std::io::Result<Self> {
+        use std::io::Read;
+
+        let version = u8::deserialize_reader(reader)?;
+        if version != Self::VERSION {
+            // The legacy encoding of a transfer to add, whose first byte
+            // has already been read
+            let transfer = PendingTransfer::deserialize_reader(
+                &mut [version].as_slice().chain(reader),
+            )?;
+            return Ok(Self::AddTransfer {
+                transfer,
+                expiry: None,
+            });
+        }
+        match u8::deserialize_reader(reader)? {
+            0 => Ok(Self::AddTransfer {
+                transfer: BorshDeserialize::deserialize_reader(reader)?,
+                expiry: BorshDeserialize::deserialize_reader(reader)?,
+            }),
+            1 => Ok(Self::BumpFee {
+                transfer: BorshDeserialize::deserialize_reader(reader)?,
+                amount: BorshDeserialize::deserialize_reader(reader)?,
+            }),
+            tag => Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("Unknown Bridge pool change tag {tag}"),
+            )),
+        }
+    }
+}
+
 /// The amount of fees to be paid, in Namada, to the relayer
 /// of a transfer across the Ethereum Bridge, compensating
 /// for Ethereum gas costs.
@@ -467,4 +634,77 @@ mod test_eth_bridge_pool_types {
         let event: TransferToEthereumEvent = (&pending).into();
         assert_eq!(pending.keccak256(), event.keccak256());
     }
+
+    /// Test that Bridge pool changes round-trip through their versioned
+    /// encoding, and that the legacy encoding of a transfer to add is still
+    /// decoded.
+    #[test]
+    fn test_bridge_pool_change_encoding() {
+        for kind in [TransferToEthereumKind::Erc20, TransferToEthereumKind::Nut]
+        {
+            let transfer = PendingTransfer {
+                transfer: TransferToEthereum {
+                    kind,
+                    asset: EthAddress([1; 20]),
+                    recipient: EthAddress([2; 20]),
+                    sender: established_address_1(),
+                    amount: 1.into(),
+                },
+                gas_fee: GasFee {
+                    token: nam(),
+                    amount: 2.into(),
+                    payer: established_address_1(),
+                },
+            };
+            let legacy = transfer.serialize_to_vec();
+            assert_ne!(legacy[0], BridgePoolChange::VERSION);
+            assert_eq!(
+                BridgePoolChange::try_from_slice(&legacy).unwrap(),
+                BridgePoolChange::AddTransfer {
+                    transfer: transfer.clone(),
+                    expiry: None,
+                }
+            );
+
+            let changes = [
+                BridgePoolChange::AddTransfer {
+                    transfer: transfer.clone(),
+                    expiry: Some(BlockHeight(10)),
+                },
+                BridgePoolChange::BumpFee {
+                    transfer: transfer.keccak256(),
+                    amount: 3.into(),
+                },
+            ];
+            for change in changes {
+                let encoded = change.serialize_to_vec();
+                assert_eq!(encoded[0], BridgePoolChange::VERSION);
+                assert_eq!(
+                    BridgePoolChange::try_from_slice(&encoded).unwrap(),
+                    change
+                );
+            }
+        }
+    }
+
+    /// Test that the transfer hash can be recovered from fee bump and
+    /// expiry keys, and that other Bridge pool keys aren't mistaken for
+    /// them.
+    #[test]
+    fn test_is_fee_bump_key() {
+        let hash = KeccakHash([0xab; 32]);
+        let key = get_fee_bump_key(&hash);
+        assert!(key.split_prefix(&get_fee_bump_prefix()).is_some());
+        assert_eq!(is_fee_bump_key(&key), Some(hash));
+        assert!(!is_pending_transfer_key(&key));
+        assert_eq!(is_fee_bump_key(&get_expiry_key(&hash)), None);
+        assert_eq!(is_fee_bump_key(&get_key_from_hash(&hash)), None);
+
+        let key = get_expiry_key(&hash);
+        assert!(key.split_prefix(&get_expiry_prefix()).is_some());
+        assert_eq!(is_expiry_key(&key), Some(hash));
+        assert!(!is_pending_transfer_key(&key));
+        assert_eq!(is_expiry_key(&get_fee_bump_key(&hash)), None);
+        assert_eq!(is_expiry_key(&get_key_from_hash(&hash)), None);
+    }
 }
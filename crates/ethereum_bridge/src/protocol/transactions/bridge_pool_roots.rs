@@ -4,15 +4,13 @@ use eyre::Result;
 use namada_core::address::Address;
 use namada_core::chain::BlockHeight;
 use namada_core::collections::{HashMap, HashSet};
-use namada_core::keccak::keccak_hash;
-use namada_core::key::{common, SignableEthMessage};
 use namada_core::token::Amount;
 use namada_state::{DBIter, StorageHasher, WlState, DB};
 use namada_storage::{StorageRead, StorageWrite};
 use namada_systems::governance;
 use namada_tx::data::BatchedTxResult;
-use namada_tx::Signed;
 use namada_vote_ext::bridge_pool_roots::{self, MultiSignedVext, SignedVext};
+use namada_vote_ext::signer::{SignRequest, ValidatorSigner};
 
 use crate::protocol::transactions::utils::GetVoters;
 use crate::protocol::transactions::votes::update::NewVotes;
@@ -23,31 +21,50 @@ use crate::storage::eth_bridge_queries::EthBridgeQueries;
 use crate::storage::proof::BridgePoolRootProof;
 use crate::storage::vote_tallies::{self, BridgePoolRoot};
 
-/// Sign the latest Bridge pool root, and return the associated
-/// vote extension protocol transaction.
-pub fn sign_bridge_pool_root<D, H>(
+/// Sign the latest Bridge pool root with the Ethereum bridge and protocol
+/// keys of a validator's `signer`, and return the associated vote extension
+/// protocol transaction.
+pub fn sign_bridge_pool_root<D, H, S>(
     state: &WlState<D, H>,
     validator_addr: &Address,
-    eth_hot_key: &common::SecretKey,
-    protocol_key: &common::SecretKey,
+    signer: &S,
 ) -> Option<bridge_pool_roots::SignedVext>
 where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
+    S: ValidatorSigner,
 {
     if !state.ethbridge_queries().is_bridge_active() {
         return None;
     }
-    let bp_root = state.ethbridge_queries().get_bridge_pool_root().0;
-    let nonce = state.ethbridge_queries().get_bridge_pool_nonce().to_bytes();
-    let to_sign = keccak_hash([bp_root.as_slice(), nonce.as_slice()].concat());
-    let signed = Signed::<_, SignableEthMessage>::new(eth_hot_key, to_sign);
-    let ext = bridge_pool_roots::Vext {
-        block_height: state.in_mem().get_last_block_height(),
-        validator_addr: validator_addr.clone(),
-        sig: signed.sig,
-    };
-    Some(ext.sign(protocol_key))
+    let root = state.ethbridge_queries().get_bridge_pool_root();
+    let nonce = state.ethbridge_queries().get_bridge_pool_nonce();
+    let block_height = state.in_mem().get_last_block_height();
+    let signed = signer
+        .sign(&SignRequest::BridgePoolRoot {
+            block_height,
+            root,
+            nonce,
+        })
+        .and_then(|sig| {
+            let ext = bridge_pool_roots::Vext {
+                block_height,
+                validator_addr: validator_addr.clone(),
+                sig,
+            };
+            ext.sign_with(signer)
+        });
+    match signed {
+        Ok(signed) => Some(signed),
+        Err(err) => {
+            tracing::error!(
+                %err,
+                %block_height,
+                "Failed to sign the bridge pool root"
+            );
+            None
+        }
+    }
 }
 
 /// Applies a tally of signatures on over the Ethereum
@@ -264,7 +281,8 @@ mod test_apply_bp_roots_to_storage {
     use assert_matches::assert_matches;
     use namada_core::address;
     use namada_core::ethereum_events::Uint;
-    use namada_core::keccak::KeccakHash;
+    use namada_core::keccak::{keccak_hash, KeccakHash};
+    use namada_core::key::SignableEthMessage;
     use namada_core::storage::Key;
     use namada_core::voting_power::FractionalVotingPower;
     use namada_proof_of_stake::parameters::OwnedPosParams;
@@ -273,6 +291,7 @@ mod test_apply_bp_roots_to_storage {
         read_consensus_validator_set_addresses_with_stake, write_pos_params,
     };
     use namada_state::testing::TestState;
+    use namada_tx::Signed;
 
     use super::*;
     use crate::protocol::transactions::votes::{
@@ -19,6 +19,7 @@ use namada_core::ethereum_events::{
 };
 use namada_core::hints;
 use namada_core::storage::{Key, KeySeg};
+use namada_core::token::Amount;
 use namada_core::uint::Uint;
 use namada_parameters::read_epoch_duration_parameter;
 use namada_state::{DBIter, StorageHasher, WlState, DB};
@@ -29,7 +30,9 @@ use token::{burn_tokens, decrement_total_supply, increment_total_supply};
 
 use crate::event::EthBridgeEvent;
 use crate::storage::bridge_pool::{
-    get_nonce_key, is_pending_transfer_key, BRIDGE_POOL_ADDRESS,
+    get_expiry_key, get_expiry_prefix, get_fee_bump_key, get_key_from_hash,
+    get_nonce_key, get_refund_height_key, is_expiry_key,
+    is_pending_transfer_key, BRIDGE_POOL_ADDRESS,
 };
 use crate::storage::eth_bridge_queries::{EthAssetMint, EthBridgeQueries};
 use crate::storage::parameters::read_native_erc20_address;
@@ -286,18 +289,21 @@ where
             state,
             &pending_transfer,
         )?);
+        let (fee_bump, mut keys) =
+            remove_fee_bump_and_expiry(state, &pending_transfer)?;
+        changed_keys.append(&mut keys);
         let pool_balance_key =
             balance_key(&pending_transfer.gas_fee.token, &BRIDGE_POOL_ADDRESS);
         let relayer_rewards_key =
             balance_key(&pending_transfer.gas_fee.token, relayer);
-        // give the relayer the gas fee for this transfer and remove it from
-        // escrow.
+        // give the relayer the gas fee for this transfer, along with
+        // any fee bumps, and remove it from escrow.
         token::transfer(
             state,
             &pending_transfer.gas_fee.token,
             &BRIDGE_POOL_ADDRESS,
             relayer,
-            pending_transfer.gas_fee.amount,
+            total_gas_fee(&pending_transfer, fee_bump)?,
         )?;
 
         state.delete(&key)?;
@@ -320,35 +326,107 @@ where
     // and refunded.
     let epoch_duration = read_epoch_duration_parameter(state)?;
     let timeout_offset = epoch_duration.min_num_of_blocks;
-
-    // Check time out and refund
-    if state.in_mem().block.height.0 > timeout_offset {
-        let timeout_height = BlockHeight(
-            state
-                .in_mem()
-                .block
-                .height
-                .0
-                .checked_sub(timeout_offset)
-                .expect("Cannot underflow - checked above"),
-        );
-        for key in pending_keys {
-            let inserted_height = BlockHeight::try_from_slice(
-                &state.in_mem().block.tree.get(&key)?,
-            )
-            .expect("BlockHeight should be decoded");
-            if inserted_height <= timeout_height {
-                let (mut keys, mut new_tx_events) =
-                    refund_transfer(state, key)?;
-                changed_keys.append(&mut keys);
-                tx_events.append(&mut new_tx_events);
+    let current_height = state.in_mem().block.height;
+    let timeout_height = current_height
+        .0
+        .checked_sub(timeout_offset)
+        .filter(|height| *height > 0)
+        .map(BlockHeight);
+
+    // Check time out and expiry, and refund.
+    //
+    // NB: now that the Bridge pool nonce has been incremented, the roots
+    // signed with the previous nonce can no longer be relayed to Ethereum,
+    // so all the expired transfers can be refunded. expired transfers that
+    // aren't covered by a signed root are also refunded at the end of every
+    // block, by `refund_expired_transfers`.
+    for key in pending_keys {
+        let timed_out = match timeout_height {
+            Some(timeout_height) => {
+                let inserted_height = BlockHeight::try_from_slice(
+                    &state.in_mem().block.tree.get(&key)?,
+                )
+                .expect("BlockHeight should be decoded");
+                inserted_height <= timeout_height
             }
+            None => false,
+        };
+        if timed_out || is_transfer_expired(state, &key, current_height)? {
+            let (mut keys, mut new_tx_events) = refund_transfer(state, key)?;
+            changed_keys.append(&mut keys);
+            tx_events.append(&mut new_tx_events);
         }
     }
 
     Ok((changed_keys, tx_events))
 }
 
+/// Refund the expired transfers of the Bridge pool which can't be relayed
+/// to Ethereum anymore. Meant to be called at the end of every block,
+/// after the votes on Bridge pool roots have been applied.
+///
+/// A transfer could be relayed with any root signed with the current
+/// nonce, from the height at which it was added to the pool onwards.
+/// Thus, expired transfers added after the latest root signed with the
+/// current nonce are refunded, and the current height is recorded, such
+/// that roots of earlier heights, which might contain the refunded
+/// transfers, are never signed anymore. The other expired transfers are
+/// refunded once the nonce is incremented, when a relay is acted on.
+pub(super) fn refund_expired_transfers<D, H>(
+    state: &mut WlState<D, H>,
+) -> Result<(BTreeSet<Key>, BTreeSet<EthBridgeEvent>)>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let mut changed_keys = BTreeSet::default();
+    let mut tx_events = BTreeSet::default();
+
+    let current_height = state.in_mem().block.height;
+    let expired: Vec<Key> = state
+        .iter_prefix(&get_expiry_prefix())
+        .context("Failed to iterate over storage")?
+        .filter_map(|(k, v, _)| {
+            let key =
+                Key::from_str(k.as_str()).expect("Key should be parsable");
+            let hash = is_expiry_key(&key)?;
+            let expiry = BlockHeight::try_from_slice(&v)
+                .expect("BlockHeight should be decoded");
+            (expiry <= current_height).then(|| get_key_from_hash(&hash))
+        })
+        .collect();
+    if expired.is_empty() {
+        return Ok((changed_keys, tx_events));
+    }
+
+    // the height of the latest root signed with the current nonce
+    let nonce = state.ethbridge_queries().get_bridge_pool_nonce();
+    let signed_height = state
+        .ethbridge_queries()
+        .get_signed_bridge_pool_root()
+        .and_then(|(root, height)| (root.data.1 == nonce).then_some(height));
+
+    for key in expired {
+        let inserted_height =
+            BlockHeight::try_from_slice(&state.in_mem().block.tree.get(&key)?)
+                .expect("BlockHeight should be decoded");
+        if signed_height.is_some_and(|height| height >= inserted_height) {
+            continue;
+        }
+        let (mut keys, mut new_tx_events) = refund_transfer(state, key)?;
+        changed_keys.append(&mut keys);
+        tx_events.append(&mut new_tx_events);
+    }
+
+    if !tx_events.is_empty() {
+        let refund_height_key = get_refund_height_key();
+        state.write(&refund_height_key, current_height)?;
+        _ = changed_keys.insert(refund_height_key);
+    }
+
+    Ok((changed_keys, tx_events))
+}
+
 fn increment_bp_nonce<D, H>(
     nonce_key: &Key,
     state: &mut WlState<D, H>,
@@ -378,7 +456,9 @@ where
     let mut tx_events = BTreeSet::default();
 
     let transfer = state.read(&key)?.expect("No PendingTransfer");
-    changed_keys.append(&mut refund_transfer_fees(state, &transfer)?);
+    let (fee_bump, mut keys) = remove_fee_bump_and_expiry(state, &transfer)?;
+    changed_keys.append(&mut keys);
+    changed_keys.append(&mut refund_transfer_fees(state, &transfer, fee_bump)?);
     changed_keys.append(&mut refund_transferred_assets(state, &transfer)?);
 
     // Delete the key from the bridge pool
@@ -396,6 +476,7 @@ where
 fn refund_transfer_fees<D, H>(
     state: &mut WlState<D, H>,
     transfer: &PendingTransfer,
+    fee_bump: Amount,
 ) -> Result<BTreeSet<Key>>
 where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
@@ -413,7 +494,7 @@ where
         &transfer.gas_fee.token,
         &BRIDGE_POOL_ADDRESS,
         &transfer.gas_fee.payer,
-        transfer.gas_fee.amount,
+        total_gas_fee(transfer, fee_bump)?,
     )?;
 
     tracing::debug!(?transfer, "Refunded Bridge pool transfer fees");
@@ -422,6 +503,67 @@ where
     Ok(changed_keys)
 }
 
+/// Check if a pending transfer has reached its expiry height.
+fn is_transfer_expired<D, H>(
+    state: &WlState<D, H>,
+    key: &Key,
+    current_height: BlockHeight,
+) -> Result<bool>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let transfer: PendingTransfer =
+        state.read(key)?.expect("No PendingTransfer");
+    let expiry: Option<BlockHeight> =
+        state.read(&get_expiry_key(&transfer.keccak256()))?;
+    Ok(expiry.is_some_and(|expiry| expiry <= current_height))
+}
+
+/// Remove the fee bump and expiry height of a transfer leaving the
+/// Bridge pool, returning the fee bump that was escrowed for it.
+fn remove_fee_bump_and_expiry<D, H>(
+    state: &mut WlState<D, H>,
+    transfer: &PendingTransfer,
+) -> Result<(Amount, BTreeSet<Key>)>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let mut changed_keys = BTreeSet::default();
+    let transfer_hash = transfer.keccak256();
+
+    let fee_bump_key = get_fee_bump_key(&transfer_hash);
+    let fee_bump = match state.read(&fee_bump_key)? {
+        Some(fee_bump) => {
+            state.delete(&fee_bump_key)?;
+            _ = changed_keys.insert(fee_bump_key);
+            fee_bump
+        }
+        None => Amount::zero(),
+    };
+
+    let expiry_key = get_expiry_key(&transfer_hash);
+    if state.has_key(&expiry_key)? {
+        state.delete(&expiry_key)?;
+        _ = changed_keys.insert(expiry_key);
+    }
+
+    Ok((fee_bump, changed_keys))
+}
+
+/// The gas fee of a transfer, along with its fee bumps.
+fn total_gas_fee(
+    transfer: &PendingTransfer,
+    fee_bump: Amount,
+) -> Result<Amount> {
+    transfer
+        .gas_fee
+        .amount
+        .checked_add(fee_bump)
+        .ok_or_else(|| eyre::eyre!("Bridge pool gas fee overflowed"))
+}
+
 fn refund_transferred_assets<D, H>(
     state: &mut WlState<D, H>,
     transfer: &PendingTransfer,
@@ -532,14 +674,14 @@ mod tests {
         arbitrary_keccak_hash, arbitrary_nonce, DAI_ERC20_ETH_ADDRESS,
     };
     use namada_core::time::DurationSecs;
-    use namada_core::token::Amount;
     use namada_core::{address, eth_bridge_pool};
     use namada_parameters::{update_epoch_parameter, EpochDuration};
     use namada_state::testing::TestState;
     use token::increment_balance;
 
     use super::*;
-    use crate::storage::bridge_pool::get_pending_key;
+    use crate::storage::bridge_pool::{get_pending_key, get_signed_root_key};
+    use crate::storage::proof::BridgePoolRootProof;
     use crate::storage::wrapped_erc20s;
     use crate::test_utils::{self, stored_keys_count};
 
@@ -1133,6 +1275,268 @@ mod tests {
         }
     }
 
+    #[test]
+    /// Test that transfers which reached their expiry height are refunded,
+    /// along with their fee bumps, when we act on a TransfersToEthereum
+    fn test_act_on_expiry_for_transfers_to_eth() {
+        let mut state = TestState::default();
+        test_utils::bootstrap_ethereum_bridge(&mut state);
+        state.commit_block().expect("Test failed");
+        init_storage(&mut state);
+        let pending_transfers = init_bridge_pool(&mut state);
+        init_balance(&mut state, &pending_transfers);
+        // the first transfer expires, and its fee was bumped
+        let expired = &pending_transfers[0];
+        let expiry_key = get_expiry_key(&expired.keccak256());
+        let fee_bump_key = get_fee_bump_key(&expired.keccak256());
+        state
+            .write(&expiry_key, BlockHeight(2))
+            .expect("Test failed");
+        state
+            .write(&fee_bump_key, Amount::from(1))
+            .expect("Test failed");
+        increment_balance(
+            &mut state,
+            &nam(),
+            &BRIDGE_POOL_ADDRESS,
+            Amount::from(1),
+        )
+        .expect("Test failed");
+        state.commit_block().expect("Test failed");
+        // the transfers have not timed out yet
+        state.in_mem_mut().block.height = BlockHeight(2);
+
+        let event = EthereumEvent::TransfersToEthereum {
+            nonce: arbitrary_nonce(),
+            transfers: vec![],
+            relayer: gen_implicit_address(),
+        };
+        let (changed_keys, tx_events) = act_on(&mut state, event).unwrap();
+
+        assert!(changed_keys.contains(&get_pending_key(expired)));
+        assert!(changed_keys.contains(&expiry_key));
+        assert!(changed_keys.contains(&fee_bump_key));
+        assert!(tx_events.contains(&EthBridgeEvent::new_bridge_pool_expired(
+            expired.keccak256()
+        )));
+        // the other transfer is still pending
+        let prefix = BRIDGE_POOL_ADDRESS.to_db_key().into();
+        assert_eq!(
+            state.iter_prefix(&prefix).expect("Test failed").count(),
+            // NOTE: we should have two writes -- one of them being
+            // the bridge pool nonce update
+            2
+        );
+        assert!(
+            state
+                .has_key(&get_pending_key(&pending_transfers[1]))
+                .expect("Test failed")
+        );
+
+        // the gas fee and the fee bump were refunded
+        let payer = address::testing::established_address_2();
+        let payer_balance: Amount = state
+            .read(&balance_key(&nam(), &payer))
+            .expect("Test failed")
+            .expect("Test failed");
+        assert_eq!(payer_balance, Amount::from(2));
+        let pool_balance: Amount = state
+            .read(&balance_key(&nam(), &BRIDGE_POOL_ADDRESS))
+            .expect("Test failed")
+            .expect("Test failed");
+        assert_eq!(pool_balance, Amount::from(1));
+
+        // the transferred assets were refunded
+        let token = expired.token_address();
+        let sender_balance: Amount = state
+            .read(&balance_key(&token, &expired.transfer.sender))
+            .expect("Test failed")
+            .expect("Test failed");
+        assert_eq!(sender_balance, expired.transfer.amount);
+    }
+
+    #[test]
+    /// Test that expired transfers are refunded at the end of a block only
+    /// if no root signed with the current nonce covers them, and that roots
+    /// of earlier heights can't be signed afterwards
+    fn test_refund_expired_transfers() {
+        let mut state = TestState::default();
+        test_utils::bootstrap_ethereum_bridge(&mut state);
+        state.commit_block().expect("Test failed");
+        init_storage(&mut state);
+        state.in_mem_mut().block.height = BlockHeight(2);
+        let pending_transfers = init_bridge_pool(&mut state);
+        init_balance(&mut state, &pending_transfers);
+        for transfer in &pending_transfers {
+            state
+                .write(&get_expiry_key(&transfer.keccak256()), BlockHeight(3))
+                .expect("Test failed");
+        }
+        state.commit_block().expect("Test failed");
+        let inserted_height = BlockHeight::try_from_slice(
+            &state
+                .in_mem()
+                .block
+                .tree
+                .get(&get_pending_key(&pending_transfers[0]))
+                .expect("Test failed"),
+        )
+        .expect("Test failed");
+        state.in_mem_mut().block.height = BlockHeight(3);
+        let nonce = state.ethbridge_queries().get_bridge_pool_nonce();
+        let signed_root = |nonce, height| {
+            (
+                BridgePoolRootProof::new((arbitrary_keccak_hash(), nonce)),
+                height,
+            )
+        };
+
+        // a root signed with the current nonce covers the transfers
+        state
+            .write(&get_signed_root_key(), signed_root(nonce, inserted_height))
+            .expect("Test failed");
+        let (changed_keys, tx_events) =
+            refund_expired_transfers(&mut state).expect("Test failed");
+        assert!(changed_keys.is_empty());
+        assert!(tx_events.is_empty());
+
+        // the latest root signed with the current nonce was issued before
+        // the transfers were added
+        state
+            .write(
+                &get_signed_root_key(),
+                signed_root(
+                    nonce,
+                    inserted_height.prev_height().expect("Test failed"),
+                ),
+            )
+            .expect("Test failed");
+        let (changed_keys, tx_events) =
+            refund_expired_transfers(&mut state).expect("Test failed");
+        for transfer in &pending_transfers {
+            assert!(changed_keys.contains(&get_pending_key(transfer)));
+            assert!(tx_events.contains(
+                &EthBridgeEvent::new_bridge_pool_expired(transfer.keccak256())
+            ));
+            assert!(!state.has_key(&get_pending_key(transfer)).unwrap());
+            assert!(
+                !state
+                    .has_key(&get_expiry_key(&transfer.keccak256()))
+                    .unwrap()
+            );
+        }
+        assert_eq!(
+            state.ethbridge_queries().get_bridge_pool_refund_height(),
+            Some(BlockHeight(3))
+        );
+        let payer = address::testing::established_address_2();
+        let payer_balance: Amount = state
+            .read(&balance_key(&nam(), &payer))
+            .expect("Test failed")
+            .expect("Test failed");
+        assert_eq!(payer_balance, Amount::from(2));
+    }
+
+    #[test]
+    /// Test that expired transfers covered by a root signed with the
+    /// current nonce are refunded once the nonce is incremented
+    fn test_refund_expired_transfers_after_nonce_increment() {
+        let mut state = TestState::default();
+        test_utils::bootstrap_ethereum_bridge(&mut state);
+        state.commit_block().expect("Test failed");
+        init_storage(&mut state);
+        state.in_mem_mut().block.height = BlockHeight(2);
+        let pending_transfers = init_bridge_pool(&mut state);
+        init_balance(&mut state, &pending_transfers);
+        let expired = &pending_transfers[0];
+        state
+            .write(&get_expiry_key(&expired.keccak256()), BlockHeight(3))
+            .expect("Test failed");
+        state.commit_block().expect("Test failed");
+        state.in_mem_mut().block.height = BlockHeight(3);
+        let nonce = state.ethbridge_queries().get_bridge_pool_nonce();
+        state
+            .write(
+                &get_signed_root_key(),
+                (
+                    BridgePoolRootProof::new((arbitrary_keccak_hash(), nonce)),
+                    BlockHeight(3),
+                ),
+            )
+            .expect("Test failed");
+
+        // the signed root may still be relayed
+        let (_, tx_events) =
+            refund_expired_transfers(&mut state).expect("Test failed");
+        assert!(tx_events.is_empty());
+
+        // the signed root was relayed, without the expired transfer
+        let event = EthereumEvent::TransfersToEthereum {
+            nonce,
+            transfers: vec![],
+            relayer: gen_implicit_address(),
+        };
+        let (_, tx_events) = act_on(&mut state, event).unwrap();
+        assert_eq!(
+            tx_events,
+            BTreeSet::from([EthBridgeEvent::new_bridge_pool_expired(
+                expired.keccak256()
+            )])
+        );
+        assert!(!state.has_key(&get_pending_key(expired)).unwrap());
+        assert!(
+            state
+                .has_key(&get_pending_key(&pending_transfers[1]))
+                .unwrap()
+        );
+    }
+
+    #[test]
+    /// Test that relayers are paid the fee bumps of the transfers they relay
+    fn test_act_on_pays_fee_bumps_to_relayer() {
+        let mut state = TestState::default();
+        test_utils::bootstrap_ethereum_bridge(&mut state);
+        state.commit_block().expect("Test failed");
+        init_storage(&mut state);
+        let pending_transfers = init_bridge_pool(&mut state);
+        init_balance(&mut state, &pending_transfers);
+        let relayed = &pending_transfers[0];
+        let expiry_key = get_expiry_key(&relayed.keccak256());
+        let fee_bump_key = get_fee_bump_key(&relayed.keccak256());
+        state
+            .write(&expiry_key, BlockHeight(100))
+            .expect("Test failed");
+        state
+            .write(&fee_bump_key, Amount::from(2))
+            .expect("Test failed");
+        increment_balance(
+            &mut state,
+            &nam(),
+            &BRIDGE_POOL_ADDRESS,
+            Amount::from(2),
+        )
+        .expect("Test failed");
+        state.commit_block().expect("Test failed");
+
+        let relayer = gen_established_address("random");
+        let event = EthereumEvent::TransfersToEthereum {
+            nonce: arbitrary_nonce(),
+            transfers: vec![TransferToEthereum::from(relayed)],
+            relayer: relayer.clone(),
+        };
+        let (changed_keys, _) = act_on(&mut state, event).unwrap();
+
+        assert!(changed_keys.contains(&expiry_key));
+        assert!(changed_keys.contains(&fee_bump_key));
+        assert!(!state.has_key(&expiry_key).expect("Test failed"));
+        assert!(!state.has_key(&fee_bump_key).expect("Test failed"));
+        let relayer_balance: Amount = state
+            .read(&balance_key(&nam(), &relayer))
+            .expect("Test failed")
+            .expect("Test failed");
+        assert_eq!(relayer_balance, Amount::from(3));
+    }
+
     #[test]
     fn test_redeem_native_token() -> Result<()> {
         let mut state = TestState::default();
@@ -12,7 +12,6 @@ use namada_core::address::Address;
 use namada_core::chain::{BlockHeight, Epoch};
 use namada_core::collections::{HashMap, HashSet};
 use namada_core::ethereum_events::EthereumEvent;
-use namada_core::key::common;
 use namada_core::storage::Key;
 use namada_core::token::Amount;
 use namada_proof_of_stake::storage::read_owned_pos_params;
@@ -21,6 +20,7 @@ use namada_state::{DBIter, StorageHasher, WlState, DB};
 use namada_systems::governance;
 use namada_tx::data::BatchedTxResult;
 use namada_vote_ext::ethereum_events::{MultiSignedEthEvent, SignedVext, Vext};
+use namada_vote_ext::signer::ValidatorSigner;
 
 use super::ChangedKeys;
 use crate::event::EthBridgeEvent;
@@ -45,15 +45,16 @@ impl utils::GetVoters for &HashSet<EthMsgUpdate> {
 ///
 /// __INVARIANT__: Assume `ethereum_events` are sorted in ascending
 /// order.
-pub fn sign_ethereum_events<D, H>(
+pub fn sign_ethereum_events<D, H, S>(
     state: &WlState<D, H>,
     validator_addr: &Address,
-    protocol_key: &common::SecretKey,
+    signer: &S,
     ethereum_events: Vec<EthereumEvent>,
 ) -> Option<SignedVext>
 where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
+    S: ValidatorSigner,
 {
     if !state.ethbridge_queries().is_bridge_active() {
         return None;
@@ -73,7 +74,18 @@ where
         tracing::debug!("New Ethereum events - {:#?}", ext.ethereum_events);
     }
 
-    Some(ext.sign(protocol_key).into())
+    let block_height = ext.block_height;
+    match ext.sign_with(signer) {
+        Ok(signed) => Some(signed.into()),
+        Err(err) => {
+            tracing::error!(
+                %err,
+                %block_height,
+                "Failed to sign Ethereum events"
+            );
+            None
+        }
+    }
 }
 
 /// Applies derived state changes to storage, based on Ethereum `events` which
@@ -133,6 +145,28 @@ where
     })
 }
 
+/// Refund the expired transfers of the Ethereum bridge pool which can no
+/// longer be relayed to Ethereum, returning the events of the refunds.
+///
+/// This must be called at the end of every block, once all of its
+/// protocol txs have been applied.
+pub fn refund_expired_transfers<D, H>(
+    state: &mut WlState<D, H>,
+) -> Result<BTreeSet<EthBridgeEvent>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let (_, tx_events) = events::refund_expired_transfers(state)?;
+    if !tx_events.is_empty() {
+        tracing::info!(
+            refunds = tx_events.len(),
+            "Refunded expired Bridge pool transfers"
+        );
+    }
+    Ok(tx_events)
+}
+
 /// Apply votes to Ethereum events in storage and act on any events which are
 /// confirmed.
 ///
@@ -4,11 +4,11 @@ use eyre::Result;
 use namada_core::address::Address;
 use namada_core::chain::{BlockHeight, Epoch};
 use namada_core::collections::{HashMap, HashSet};
-use namada_core::key::common;
 use namada_core::token::Amount;
 use namada_state::{DBIter, StorageHasher, WlState, DB};
 use namada_systems::governance;
 use namada_tx::data::BatchedTxResult;
+use namada_vote_ext::signer::ValidatorSigner;
 use namada_vote_ext::validator_set_update;
 
 use super::ChangedKeys;
@@ -32,40 +32,51 @@ impl utils::GetVoters for (&validator_set_update::VextDigest, BlockHeight) {
     }
 }
 
-/// Sign the next set of validators, and return the associated
-/// vote extension protocol transaction.
-pub fn sign_validator_set_update<D, H, Gov>(
+/// Sign the next set of validators with the Ethereum bridge key of a
+/// validator's `signer`, and return the associated vote extension protocol
+/// transaction.
+pub fn sign_validator_set_update<D, H, Gov, S>(
     state: &WlState<D, H>,
     validator_addr: &Address,
-    eth_hot_key: &common::SecretKey,
+    signer: &S,
 ) -> Option<validator_set_update::SignedVext>
 where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
     Gov: governance::Read<WlState<D, H>>,
+    S: ValidatorSigner,
 {
-    state
+    if !state
         .ethbridge_queries()
         .must_send_valset_upd(SendValsetUpd::Now)
-        .then(|| {
-            let next_epoch = state.in_mem().get_current_epoch().0.next();
+    {
+        return None;
+    }
+    let next_epoch = state.in_mem().get_current_epoch().0.next();
 
-            let voting_powers = state
-                .ethbridge_queries()
-                .get_consensus_eth_addresses::<Gov>(next_epoch)
-                .map(|(eth_addr_book, _, voting_power)| {
-                    (eth_addr_book, voting_power)
-                })
-                .collect();
-
-            let ext = validator_set_update::Vext {
-                voting_powers,
-                validator_addr: validator_addr.clone(),
-                signing_epoch: state.in_mem().get_current_epoch().0,
-            };
-
-            ext.sign(eth_hot_key)
-        })
+    let voting_powers = state
+        .ethbridge_queries()
+        .get_consensus_eth_addresses::<Gov>(next_epoch)
+        .map(|(eth_addr_book, _, voting_power)| (eth_addr_book, voting_power))
+        .collect();
+
+    let ext = validator_set_update::Vext {
+        voting_powers,
+        validator_addr: validator_addr.clone(),
+        signing_epoch: state.in_mem().get_current_epoch().0,
+    };
+
+    match ext.sign_with(signer) {
+        Ok(signed) => Some(signed),
+        Err(err) => {
+            tracing::error!(
+                %err,
+                signing_epoch = %ext.signing_epoch,
+                "Failed to sign the validator set update"
+            );
+            None
+        }
+    }
 }
 
 /// Aggregate validators' votes
@@ -23,6 +23,7 @@ use crate::storage::eth_bridge_queries::EthBridgeQueries;
 ///  * The validator correctly signed the extension.
 ///  * The validator signed over the correct height inside of the extension.
 ///  * Check that the inner signature is valid.
+///  * The root wasn't issued before the last refund of expired transfers.
 pub fn validate_bp_roots_vext<D, H, Gov>(
     state: &WlState<D, H>,
     ext: &Signed<bridge_pool_roots::Vext>,
@@ -72,6 +73,21 @@ where
         tracing::debug!("Dropping vote extension issued at genesis");
         return Err(VoteExtensionError::UnexpectedBlockHeight);
     }
+    // NB: roots of heights before the last refund of expired transfers
+    // might contain refunded transfers, and must never be signed
+    if let Some(refund_height) =
+        state.ethbridge_queries().get_bridge_pool_refund_height()
+    {
+        if ext.data.block_height < refund_height {
+            tracing::debug!(
+                ext_height = ?ext.data.block_height,
+                ?refund_height,
+                "Bridge pool root's vote extension issued for a block height \
+                 before the last refund of expired transfers."
+            );
+            return Err(VoteExtensionError::UnexpectedBlockHeight);
+        }
+    }
 
     // get the public key associated with this validator
     let validator = &ext.data.validator_addr;
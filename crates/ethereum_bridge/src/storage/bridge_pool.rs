@@ -3,8 +3,9 @@
 
 use namada_core::eth_bridge_pool::Segments;
 pub use namada_core::eth_bridge_pool::{
-    get_key_from_hash, get_pending_key, is_pending_transfer_key,
-    BRIDGE_POOL_ADDRESS,
+    get_expiry_key, get_expiry_prefix, get_fee_bump_key, get_fee_bump_prefix,
+    get_key_from_hash, get_pending_key, is_expiry_key, is_fee_bump_key,
+    is_pending_transfer_key, BRIDGE_POOL_ADDRESS,
 };
 use namada_core::storage::{DbKeySeg, Key};
 pub use namada_state::merkle_tree::eth_bridge_pool::BridgePoolTree;
@@ -31,6 +32,18 @@ pub fn get_nonce_key() -> Key {
     }
 }
 
+/// Get the storage key for the height of the last refund of expired
+/// transfers from the bridge pool. Roots of earlier heights are no
+/// longer signed.
+pub fn get_refund_height_key() -> Key {
+    Key {
+        segments: vec![
+            DbKeySeg::AddressSeg(BRIDGE_POOL_ADDRESS),
+            DbKeySeg::StringSeg(Segments::VALUES.refund_height.into()),
+        ],
+    }
+}
+
 /// Check if a key belongs to the bridge pools sub-storage
 pub fn is_bridge_pool_key(key: &Key) -> bool {
     matches!(&key.segments[0], DbKeySeg::AddressSeg(addr) if addr == &BRIDGE_POOL_ADDRESS)
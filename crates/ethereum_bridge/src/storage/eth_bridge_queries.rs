@@ -276,6 +276,15 @@ where
             .expect("Reading signed Bridge pool root shouldn't fail.")
     }
 
+    /// Get the height of the last refund of expired transfers from the
+    /// Ethereum bridge pool, if any. Roots of earlier heights may no
+    /// longer be signed.
+    pub fn get_bridge_pool_refund_height(self) -> Option<BlockHeight> {
+        self.state
+            .read(&bridge_pool::get_refund_height_key())
+            .expect("Reading the Bridge pool refund height shouldn't fail.")
+    }
+
     /// Get the root of the Ethereum bridge
     /// pool Merkle tree at a given height.
     pub fn get_bridge_pool_root_at_height(
@@ -9,7 +9,9 @@
 //! This VP checks that additions to the pool are handled
 //! correctly. This means that the appropriate data is
 //! added to the pool and gas fees are submitted appropriately
-//! and that tokens to be transferred are escrowed.
+//! and that tokens to be transferred are escrowed. It also
+//! checks that bumps to the relayer fee of pending transfers
+//! are escrowed from the gas payer of the transfer.
 
 use std::borrow::Cow;
 use std::collections::BTreeSet;
@@ -19,11 +21,14 @@ use borsh::BorshDeserialize;
 use namada_core::address::{Address, InternalAddress};
 use namada_core::arith::{checked, CheckedAdd, CheckedNeg, CheckedSub};
 use namada_core::booleans::BoolResultUnitExt;
+use namada_core::chain::BlockHeight;
 use namada_core::eth_bridge_pool::{
-    erc20_token_address, PendingTransfer, TransferToEthereumKind,
+    erc20_token_address, BridgePoolChange, PendingTransfer,
+    TransferToEthereumKind,
 };
 use namada_core::ethereum_events::EthAddress;
 use namada_core::hints;
+use namada_core::keccak::KeccakHash;
 use namada_core::storage::Key;
 use namada_core::uint::I320;
 use namada_state::ResultExt;
@@ -32,7 +37,8 @@ use namada_tx::BatchedTxRef;
 use namada_vp_env::{Error, Result, StorageRead, VpEnv};
 
 use crate::storage::bridge_pool::{
-    get_pending_key, is_bridge_pool_key, BRIDGE_POOL_ADDRESS,
+    get_expiry_key, get_fee_bump_key, get_key_from_hash, get_pending_key,
+    is_bridge_pool_key, BRIDGE_POOL_ADDRESS,
 };
 use crate::storage::eth_bridge_queries::is_bridge_active_at;
 use crate::storage::parameters::read_native_erc20_address;
@@ -90,11 +96,30 @@ where
         let Some(tx_data) = batched_tx.tx.data(batched_tx.cmt) else {
             return Err(Error::new_const("No transaction data found"));
         };
-        let transfer: PendingTransfer =
+        let change: BridgePoolChange =
             BorshDeserialize::try_from_slice(&tx_data[..])
                 .into_storage_result()?;
 
+        match change {
+            BridgePoolChange::AddTransfer { transfer, expiry } => {
+                Self::validate_add_transfer(ctx, keys_changed, transfer, expiry)
+            }
+            BridgePoolChange::BumpFee { transfer, amount } => {
+                Self::validate_fee_bump(ctx, keys_changed, &transfer, amount)
+            }
+        }
+    }
+
+    /// Validate the addition of a transfer to the pool, along with
+    /// its optional expiry height.
+    fn validate_add_transfer(
+        ctx: &'ctx CTX,
+        keys_changed: &BTreeSet<Key>,
+        transfer: PendingTransfer,
+        expiry: Option<BlockHeight>,
+    ) -> Result<()> {
         let pending_key = get_pending_key(&transfer);
+        let expiry_key = get_expiry_key(&transfer.keccak256());
         // check that transfer is not already in the pool
         match ctx.pre().read::<PendingTransfer>(&pending_key) {
             Ok(Some(_)) => {
@@ -111,7 +136,7 @@ where
             _ => {}
         }
         for key in keys_changed.iter().filter(|k| is_bridge_pool_key(k)) {
-            if *key != pending_key {
+            if *key != pending_key && (expiry.is_none() || *key != expiry_key) {
                 let error = Error::new_alloc(format!(
                     "Rejecting transaction as it is attempting to change an \
                      incorrect key in the Ethereum bridge pool: {key}.\n \
@@ -136,6 +161,9 @@ where
             tracing::debug!("{error}");
             return Err(error);
         }
+        if let Some(expiry) = expiry {
+            Self::check_expiry(ctx, &expiry_key, expiry)?;
+        }
         // The deltas in the escrowed amounts we must check.
         let wnam_address = read_native_erc20_address(&ctx.pre())?;
         let escrow_checks =
@@ -198,6 +226,114 @@ where
         })
     }
 
+    /// Check that the expiry height of a newly added transfer
+    /// is in the future and was written to storage.
+    fn check_expiry(
+        ctx: &'ctx CTX,
+        expiry_key: &Key,
+        expiry: BlockHeight,
+    ) -> Result<()> {
+        let current_height = ctx.get_block_height()?;
+        if expiry <= current_height {
+            let error = Error::new_alloc(format!(
+                "Rejecting transaction as the expiry height {expiry} of the \
+                 transfer is not above the current height {current_height}",
+            ));
+            tracing::debug!("{error}");
+            return Err(error);
+        }
+        let written: Option<BlockHeight> = ctx.post().read(expiry_key)?;
+        if written != Some(expiry) {
+            let error = Error::new_alloc(format!(
+                "An incorrect expiry height was written to the Ethereum \
+                 bridge pool: {written:?}.\n Expected: {expiry}",
+            ));
+            tracing::debug!("{error}");
+            return Err(error);
+        }
+        Ok(())
+    }
+
+    /// Validate a bump of the relayer fee of a pending transfer.
+    ///
+    /// The bump must be paid by the gas payer of the transfer, in
+    /// the same token as its gas fees, and accumulated under the
+    /// fee bump key of the transfer.
+    fn validate_fee_bump(
+        ctx: &'ctx CTX,
+        keys_changed: &BTreeSet<Key>,
+        transfer_hash: &KeccakHash,
+        amount: Amount,
+    ) -> Result<()> {
+        let transfer: PendingTransfer = ctx
+            .pre()
+            .read(&get_key_from_hash(transfer_hash))?
+            .ok_or_else(|| {
+                Error::new_const(
+                    "Rejecting transaction as the transfer whose fee is \
+                     bumped is not in the Ethereum bridge pool.",
+                )
+            })?;
+        if amount.is_zero() {
+            return Err(Error::new_const(
+                "Rejecting transaction as the fee bump is zero.",
+            ));
+        }
+        let fee_bump_key = get_fee_bump_key(transfer_hash);
+        for key in keys_changed.iter().filter(|k| is_bridge_pool_key(k)) {
+            if *key != fee_bump_key {
+                let error = Error::new_alloc(format!(
+                    "Rejecting transaction as it is attempting to change an \
+                     incorrect key in the Ethereum bridge pool: {key}.\n \
+                     Expected key: {fee_bump_key}",
+                ));
+                tracing::debug!("{error}");
+                return Err(error);
+            }
+        }
+        let pre_bump: Amount =
+            ctx.pre().read(&fee_bump_key)?.unwrap_or_default();
+        let post_bump: Option<Amount> = ctx.post().read(&fee_bump_key)?;
+        match pre_bump.checked_add(amount) {
+            Some(expected) if post_bump == Some(expected) => {}
+            expected => {
+                let error = Error::new_alloc(format!(
+                    "An incorrect fee bump was written to the Ethereum bridge \
+                     pool: {post_bump:?}.\n Expected: {expected:?}",
+                ));
+                tracing::debug!("{error}");
+                return Err(error);
+            }
+        }
+        let gas_check = EscrowDelta {
+            token: Cow::Borrowed(&transfer.gas_fee.token),
+            payer_account: &transfer.gas_fee.payer,
+            escrow_account: &BRIDGE_POOL_ADDRESS,
+            expected_debit: amount,
+            expected_credit: amount,
+            transferred_amount: &amount,
+            _kind: PhantomData,
+        };
+        if !gas_check.validate::<TokenKeys>(keys_changed) {
+            return Err(Error::new_const(
+                "Invalid storage modifications in the Bridge pool",
+            ));
+        }
+        let wnam_address = read_native_erc20_address(&ctx.pre())?;
+        if !Self::check_gas_escrow(ctx, &wnam_address, &transfer, gas_check)? {
+            return Err(Error::new_const(
+                "The fee bump was not correctly escrowed into the Bridge pool \
+                 storage",
+            ));
+        }
+        tracing::info!(
+            ?transfer,
+            %amount,
+            "The Ethereum bridge pool VP accepted a fee bump of the transfer.",
+        );
+        Ok(())
+    }
+
     /// Get the change in the balance of an account
     /// associated with an address
     fn account_balance_delta(
@@ -944,6 +1080,30 @@ mod test_bridge_pool_vp {
         expect: Expect,
     ) where
         F: FnOnce(&mut PendingTransfer, &mut WriteLog) -> BTreeSet<Key>,
+    {
+        assert_bridge_pool_with_expiry(
+            payer_gas_delta,
+            gas_escrow_delta,
+            payer_delta,
+            escrow_delta,
+            None,
+            insert_transfer,
+            expect,
+        )
+    }
+
+    /// Same as [`assert_bridge_pool`], but the transfer is added
+    /// to the pool with the given expiry height
+    fn assert_bridge_pool_with_expiry<F>(
+        payer_gas_delta: I320,
+        gas_escrow_delta: I320,
+        payer_delta: I320,
+        escrow_delta: I320,
+        expiry: Option<BlockHeight>,
+        insert_transfer: F,
+        expect: Expect,
+    ) where
+        F: FnOnce(&mut PendingTransfer, &mut WriteLog) -> BTreeSet<Key>,
     {
         // setup
         let mut state = setup_storage();
@@ -1006,7 +1166,7 @@ mod test_bridge_pool_vp {
         let ctx = setup_ctx(&tx, &state, &gas_meter, &keys_changed, &verifiers);
 
         let mut tx = Tx::new(state.in_mem().chain_id.clone(), None);
-        tx.add_data(transfer);
+        tx.add_data(BridgePoolChange::AddTransfer { transfer, expiry });
 
         let tx = tx.batch_ref_first_tx().unwrap();
         let res = BridgePool::validate_tx(&ctx, &tx, &keys_changed, &verifiers);
@@ -1362,7 +1522,10 @@ mod test_bridge_pool_vp {
         let ctx = setup_ctx(&tx, &state, &gas_meter, &keys_changed, &verifiers);
 
         let mut tx = Tx::new(state.in_mem().chain_id.clone(), None);
-        tx.add_data(transfer);
+        tx.add_data(BridgePoolChange::AddTransfer {
+            transfer,
+            expiry: None,
+        });
 
         let tx = tx.batch_ref_first_tx().unwrap();
         let res = BridgePool::validate_tx(&ctx, &tx, &keys_changed, &verifiers);
@@ -1420,7 +1583,10 @@ mod test_bridge_pool_vp {
         let ctx = setup_ctx(&tx, &state, &gas_meter, &keys_changed, &verifiers);
 
         let mut tx = Tx::new(state.in_mem().chain_id.clone(), None);
-        tx.add_data(transfer);
+        tx.add_data(BridgePoolChange::AddTransfer {
+            transfer,
+            expiry: None,
+        });
 
         let tx = tx.batch_ref_first_tx().unwrap();
         let res = BridgePool::validate_tx(&ctx, &tx, &keys_changed, &verifiers);
@@ -1499,7 +1665,10 @@ mod test_bridge_pool_vp {
         let ctx = setup_ctx(&tx, &state, &gas_meter, &keys_changed, &verifiers);
 
         let mut tx = Tx::new(state.in_mem().chain_id.clone(), None);
-        tx.add_data(transfer);
+        tx.add_data(BridgePoolChange::AddTransfer {
+            transfer,
+            expiry: None,
+        });
 
         let tx = tx.batch_ref_first_tx().unwrap();
         let res = BridgePool::validate_tx(&ctx, &tx, &keys_changed, &verifiers);
@@ -1573,7 +1742,10 @@ mod test_bridge_pool_vp {
         let ctx = setup_ctx(&tx, &state, &gas_meter, &keys_changed, &verifiers);
 
         let mut tx = Tx::new(state.in_mem().chain_id.clone(), None);
-        tx.add_data(transfer);
+        tx.add_data(BridgePoolChange::AddTransfer {
+            transfer,
+            expiry: None,
+        });
 
         let tx = tx.batch_ref_first_tx().unwrap();
         let res = BridgePool::validate_tx(&ctx, &tx, &keys_changed, &verifiers);
@@ -1664,7 +1836,10 @@ mod test_bridge_pool_vp {
         let ctx = setup_ctx(&tx, &state, &gas_meter, &keys_changed, &verifiers);
 
         let mut tx = Tx::new(state.in_mem().chain_id.clone(), None);
-        tx.add_data(transfer);
+        tx.add_data(BridgePoolChange::AddTransfer {
+            transfer,
+            expiry: None,
+        });
 
         let tx = tx.batch_ref_first_tx().unwrap();
         let res = BridgePool::validate_tx(&ctx, &tx, &keys_changed, &verifiers);
@@ -1742,7 +1917,10 @@ mod test_bridge_pool_vp {
 
         let mut tx = Tx::from_type(TxType::Raw);
         tx.push_default_inner_tx();
-        tx.add_data(transfer);
+        tx.add_data(BridgePoolChange::AddTransfer {
+            transfer,
+            expiry: None,
+        });
 
         let tx = tx.batch_ref_first_tx().unwrap();
         let res = BridgePool::validate_tx(&ctx, &tx, &keys_changed, &verifiers);
@@ -1907,4 +2085,289 @@ mod test_bridge_pool_vp {
 
         assert!(!delta.validate::<TokenKeys>(&some_changed_keys));
     }
+
+    /// Test that a transfer can be added to the pool along
+    /// with an expiry height in the future.
+    #[test]
+    fn test_add_transfer_with_expiry() {
+        let expiry = BlockHeight(1_000);
+        assert_bridge_pool_with_expiry(
+            -I320::from(GAS_FEE),
+            I320::from(GAS_FEE),
+            -I320::from(TOKENS),
+            I320::from(TOKENS),
+            Some(expiry),
+            |transfer, log| {
+                let expiry_key = get_expiry_key(&transfer.keccak256());
+                let _ = log
+                    .write(
+                        &get_pending_key(transfer),
+                        transfer.serialize_to_vec(),
+                    )
+                    .unwrap();
+                let _ =
+                    log.write(&expiry_key, expiry.serialize_to_vec()).unwrap();
+                BTreeSet::from([get_pending_key(transfer), expiry_key])
+            },
+            Expect::Accepted,
+        );
+    }
+
+    /// Test that transfers which have already expired
+    /// cannot be added to the pool.
+    #[test]
+    fn test_add_expired_transfer_rejected() {
+        let expiry = BlockHeight(0);
+        assert_bridge_pool_with_expiry(
+            -I320::from(GAS_FEE),
+            I320::from(GAS_FEE),
+            -I320::from(TOKENS),
+            I320::from(TOKENS),
+            Some(expiry),
+            |transfer, log| {
+                let expiry_key = get_expiry_key(&transfer.keccak256());
+                let _ = log
+                    .write(
+                        &get_pending_key(transfer),
+                        transfer.serialize_to_vec(),
+                    )
+                    .unwrap();
+                let _ =
+                    log.write(&expiry_key, expiry.serialize_to_vec()).unwrap();
+                BTreeSet::from([get_pending_key(transfer), expiry_key])
+            },
+            Expect::Rejected,
+        );
+    }
+
+    /// Test that the expiry height in the tx data
+    /// must be written to storage.
+    #[test]
+    fn test_expiry_not_written_rejected() {
+        assert_bridge_pool_with_expiry(
+            -I320::from(GAS_FEE),
+            I320::from(GAS_FEE),
+            -I320::from(TOKENS),
+            I320::from(TOKENS),
+            Some(BlockHeight(1_000)),
+            |transfer, log| {
+                let _ = log
+                    .write(
+                        &get_pending_key(transfer),
+                        transfer.serialize_to_vec(),
+                    )
+                    .unwrap();
+                BTreeSet::from([get_pending_key(transfer)])
+            },
+            Expect::Rejected,
+        );
+    }
+
+    /// Test that an expiry height cannot be written
+    /// if none was requested in the tx data.
+    #[test]
+    fn test_unrequested_expiry_rejected() {
+        assert_bridge_pool(
+            -I320::from(GAS_FEE),
+            I320::from(GAS_FEE),
+            -I320::from(TOKENS),
+            I320::from(TOKENS),
+            |transfer, log| {
+                let expiry_key = get_expiry_key(&transfer.keccak256());
+                let _ = log
+                    .write(
+                        &get_pending_key(transfer),
+                        transfer.serialize_to_vec(),
+                    )
+                    .unwrap();
+                let _ = log
+                    .write(&expiry_key, BlockHeight(1_000).serialize_to_vec())
+                    .unwrap();
+                BTreeSet::from([get_pending_key(transfer), expiry_key])
+            },
+            Expect::Rejected,
+        );
+    }
+
+    /// Helper function that tests bumping the relayer fee
+    /// of a transfer in the pool
+    fn assert_fee_bump<F>(
+        transfer_hash: KeccakHash,
+        payer_gas_delta: I320,
+        gas_escrow_delta: I320,
+        amount: Amount,
+        write_fee_bump: F,
+        expect: Expect,
+    ) where
+        F: FnOnce(&KeccakHash, &mut WriteLog) -> BTreeSet<Key>,
+    {
+        // setup
+        let mut state = setup_storage();
+        let mut tx = Tx::from_type(TxType::Raw);
+        tx.push_default_inner_tx();
+
+        // bump the fee of the transfer
+        let mut keys_changed =
+            write_fee_bump(&transfer_hash, state.write_log_mut());
+
+        // change the balances of Bertha, the gas payer
+        let mut new_keys_changed = update_balances(
+            state.write_log_mut(),
+            Balance {
+                asset: ASSET,
+                kind: TransferToEthereumKind::Erc20,
+                owner: bertha_address(),
+                gas: BERTHA_WEALTH.into(),
+                token: BERTHA_TOKENS.into(),
+            },
+            payer_gas_delta,
+            I320::from(0),
+        );
+        keys_changed.append(&mut new_keys_changed);
+
+        // change the bridge pool balances
+        let mut new_keys_changed = update_balances(
+            state.write_log_mut(),
+            Balance {
+                asset: ASSET,
+                kind: TransferToEthereumKind::Erc20,
+                owner: BRIDGE_POOL_ADDRESS,
+                gas: ESCROWED_AMOUNT.into(),
+                token: ESCROWED_TOKENS.into(),
+            },
+            gas_escrow_delta,
+            I320::from(0),
+        );
+        keys_changed.append(&mut new_keys_changed);
+        let verifiers = BTreeSet::default();
+        // create the data to be given to the vp
+        let gas_meter = RefCell::new(VpGasMeter::new_from_tx_meter(
+            &TxGasMeter::new(u64::MAX),
+        ));
+        let ctx = setup_ctx(&tx, &state, &gas_meter, &keys_changed, &verifiers);
+
+        let mut tx = Tx::new(state.in_mem().chain_id.clone(), None);
+        tx.add_data(BridgePoolChange::BumpFee {
+            transfer: transfer_hash,
+            amount,
+        });
+
+        let tx = tx.batch_ref_first_tx().unwrap();
+        let res = BridgePool::validate_tx(&ctx, &tx, &keys_changed, &verifiers);
+        match (expect, res) {
+            (Expect::Accepted, Ok(())) => (),
+            (Expect::Accepted, Err(err)) => {
+                panic!("Expected VP success, but got: {err}")
+            }
+            (Expect::Rejected, Err(_)) => (),
+            (Expect::Rejected, Ok(())) => {
+                panic!("Expected VP failure, but the tx was accepted")
+            }
+        }
+    }
+
+    /// Write a fee bump of the given amount
+    fn write_fee_bump(
+        transfer_hash: &KeccakHash,
+        log: &mut WriteLog,
+        amount: u64,
+    ) -> BTreeSet<Key> {
+        let fee_bump_key = get_fee_bump_key(transfer_hash);
+        let _ = log
+            .write(&fee_bump_key, Amount::from(amount).serialize_to_vec())
+            .unwrap();
+        BTreeSet::from([fee_bump_key])
+    }
+
+    /// Test that escrowing a fee bump of a pending transfer passes the vp
+    #[test]
+    fn test_fee_bump_happy_flow() {
+        assert_fee_bump(
+            initial_pool().keccak256(),
+            -I320::from(GAS_FEE),
+            I320::from(GAS_FEE),
+            GAS_FEE.into(),
+            |hash, log| write_fee_bump(hash, log, GAS_FEE),
+            Expect::Accepted,
+        );
+    }
+
+    /// Test that the fee of a transfer which is not
+    /// in the pool cannot be bumped.
+    #[test]
+    fn test_fee_bump_of_missing_transfer_rejected() {
+        assert_fee_bump(
+            KeccakHash([1; 32]),
+            -I320::from(GAS_FEE),
+            I320::from(GAS_FEE),
+            GAS_FEE.into(),
+            |hash, log| write_fee_bump(hash, log, GAS_FEE),
+            Expect::Rejected,
+        );
+    }
+
+    /// Test that fee bumps of zero are rejected.
+    #[test]
+    fn test_zero_fee_bump_rejected() {
+        assert_fee_bump(
+            initial_pool().keccak256(),
+            I320::from(0),
+            I320::from(0),
+            Amount::zero(),
+            |hash, log| write_fee_bump(hash, log, 0),
+            Expect::Rejected,
+        );
+    }
+
+    /// Test that the fee bump written to storage must
+    /// match the one in the tx data.
+    #[test]
+    fn test_incorrect_fee_bump_written() {
+        assert_fee_bump(
+            initial_pool().keccak256(),
+            -I320::from(GAS_FEE),
+            I320::from(GAS_FEE),
+            GAS_FEE.into(),
+            |hash, log| write_fee_bump(hash, log, GAS_FEE + 1),
+            Expect::Rejected,
+        );
+    }
+
+    /// Test that a fee bump must be escrowed from the gas payer.
+    #[test]
+    fn test_fee_bump_not_escrowed() {
+        assert_fee_bump(
+            initial_pool().keccak256(),
+            -I320::from(10),
+            I320::from(GAS_FEE),
+            GAS_FEE.into(),
+            |hash, log| write_fee_bump(hash, log, GAS_FEE),
+            Expect::Rejected,
+        );
+    }
+
+    /// Test that a fee bump may not modify the pending transfer.
+    #[test]
+    fn test_fee_bump_changing_transfer_rejected() {
+        assert_fee_bump(
+            initial_pool().keccak256(),
+            -I320::from(GAS_FEE),
+            I320::from(GAS_FEE),
+            GAS_FEE.into(),
+            |hash, log| {
+                let mut transfer = initial_pool();
+                transfer.gas_fee.amount = GAS_FEE.into();
+                let _ = log
+                    .write(
+                        &get_key_from_hash(hash),
+                        transfer.serialize_to_vec(),
+                    )
+                    .unwrap();
+                let mut keys_changed = write_fee_bump(hash, log, GAS_FEE);
+                keys_changed.insert(get_key_from_hash(hash));
+                keys_changed
+            },
+            Expect::Rejected,
+        );
+    }
 }
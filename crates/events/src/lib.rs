@@ -149,6 +149,20 @@ impl Display for EventLevel {
     }
 }
 
+impl FromStr for EventLevel {
+    type Err = EventError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "block" => Ok(EventLevel::Block),
+            "tx" => Ok(EventLevel::Tx),
+            _ => Err(EventError::AttributeEncoding(format!(
+                "Invalid event level {s:?}"
+            ))),
+        }
+    }
+}
+
 /// ABCI event type.
 ///
 /// It is comprised of an event domain and sub-domain, plus any other
@@ -529,3 +543,44 @@ impl From<Event> for namada_core::tendermint::abci::Event {
         }
     }
 }
+
+impl TryFrom<namada_core::tendermint::abci::Event> for Event {
+    type Error = EventError;
+
+    fn try_from(
+        event: namada_core::tendermint::abci::Event,
+    ) -> Result<Self, Self::Error> {
+        use extend::{Domain, EventAttributeEntry};
+
+        let mut level = None;
+        let mut attributes = BTreeMap::new();
+        for attr in event.attributes {
+            let key = attr
+                .key_str()
+                .map_err(|err| EventError::AttributeEncoding(err.to_string()))?
+                .to_owned();
+            let value = attr
+                .value_str()
+                .map_err(|err| EventError::AttributeEncoding(err.to_string()))?
+                .to_owned();
+            if key == "event-level" {
+                level = Some(value.parse()?);
+            } else {
+                attributes.insert(key, value);
+            }
+        }
+
+        // NB: ibc events encode their domain in the attributes, rather than
+        // in the event type
+        let event_type = match attributes.get(Domain::<Event>::KEY) {
+            Some(domain) => format!("{domain}/{}", event.kind).parse()?,
+            None => event.kind.parse()?,
+        };
+
+        Ok(Self {
+            level: level.ok_or(EventError::MissingAttribute("event-level"))?,
+            event_type,
+            attributes,
+        })
+    }
+}
@@ -12,9 +12,10 @@ use serde::{Deserialize, Serialize};
 
 use super::validation::{
     is_valid_activation_epoch, is_valid_author_balance, is_valid_content,
-    is_valid_default_proposal_data, is_valid_end_epoch,
-    is_valid_pgf_funding_data, is_valid_pgf_stewards_data,
-    is_valid_proposal_period, is_valid_start_epoch, ProposalValidation,
+    is_valid_default_proposal_data, is_valid_default_proposal_steps,
+    is_valid_deposit, is_valid_end_epoch, is_valid_pgf_funding_data,
+    is_valid_pgf_stewards_data, is_valid_proposal_period, is_valid_start_epoch,
+    ProposalValidation,
 };
 use crate::parameters::GovernanceParameters;
 use crate::storage::proposal::PGFTarget;
@@ -40,6 +41,22 @@ pub struct OnChainProposal {
     pub voting_end_epoch: Epoch,
     /// The epoch in which any changes are executed and become active
     pub activation_epoch: Epoch,
+    /// The amount locked by the author, the minimum proposal fund if not
+    /// specified. A lower deposit must be topped up to the minimum proposal
+    /// fund before the voting start epoch.
+    #[serde(default)]
+    pub deposit: Option<token::Amount>,
+}
+
+impl OnChainProposal {
+    /// The amount locked by the author
+    pub fn deposit(
+        &self,
+        governance_parameters: &GovernanceParameters,
+    ) -> token::Amount {
+        self.deposit
+            .unwrap_or(governance_parameters.min_proposal_fund)
+    }
 }
 
 /// PGF default proposal
@@ -57,6 +74,28 @@ pub struct DefaultProposal {
     pub proposal: OnChainProposal,
     /// The default proposal extra data
     pub data: Option<Vec<u8>>,
+    /// The steps of a batch proposal, executed in order at activation.
+    /// Cannot be combined with `data`.
+    #[serde(default)]
+    pub steps: Vec<DefaultProposalStep>,
+}
+
+/// A step of a batch default proposal
+#[derive(
+    Debug,
+    Clone,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+)]
+pub struct DefaultProposalStep {
+    /// The wasm code of the step
+    pub code: Vec<u8>,
+    /// The data passed to the code of the step
+    #[serde(default)]
+    pub data: Vec<u8>,
 }
 
 impl DefaultProposal {
@@ -89,10 +128,12 @@ impl DefaultProposal {
             self.proposal.activation_epoch,
             governance_parameters.max_proposal_period,
         )?;
-        is_valid_author_balance(
-            balance,
-            governance_parameters.min_proposal_fund,
+        let deposit = self.proposal.deposit(governance_parameters);
+        is_valid_deposit(
+            deposit,
+            governance_parameters.min_proposal_initial_deposit,
         )?;
+        is_valid_author_balance(balance, deposit)?;
         is_valid_content(
             &self.proposal.content,
             governance_parameters.max_proposal_content_size,
@@ -101,6 +142,11 @@ impl DefaultProposal {
             &self.data,
             governance_parameters.max_proposal_code_size,
         )?;
+        is_valid_default_proposal_steps(
+            &self.data,
+            &self.steps,
+            governance_parameters.max_proposal_code_size,
+        )?;
 
         Ok(self)
     }
@@ -162,10 +208,12 @@ impl PgfStewardProposal {
             self.proposal.activation_epoch,
             governance_parameters.max_proposal_period,
         )?;
-        is_valid_author_balance(
-            balance,
-            governance_parameters.min_proposal_fund,
+        let deposit = self.proposal.deposit(governance_parameters);
+        is_valid_deposit(
+            deposit,
+            governance_parameters.min_proposal_initial_deposit,
         )?;
+        is_valid_author_balance(balance, deposit)?;
         is_valid_content(
             &self.proposal.content,
             governance_parameters.max_proposal_content_size,
@@ -230,6 +278,10 @@ impl PgfFundingProposal {
             self.proposal.activation_epoch,
             governance_parameters.max_proposal_period,
         )?;
+        is_valid_deposit(
+            self.proposal.deposit(governance_parameters),
+            governance_parameters.min_proposal_initial_deposit,
+        )?;
         is_valid_content(
             &self.proposal.content,
             governance_parameters.max_proposal_content_size,
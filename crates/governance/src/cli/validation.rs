@@ -6,7 +6,7 @@ use namada_core::chain::Epoch;
 use namada_core::token;
 use thiserror::Error;
 
-use super::onchain::{PgfFunding, StewardsUpdate};
+use super::onchain::{DefaultProposalStep, PgfFunding, StewardsUpdate};
 
 /// This enum represents proposal data
 #[derive(Debug, Error)]
@@ -46,6 +46,12 @@ pub enum ProposalValidation {
          minimum is {1}"
     )]
     InvalidBalance(String, String),
+    /// The proposal deposit is below the minimum initial deposit
+    #[error(
+        "Invalid proposal deposit: the deposit is {0} but the minimum initial \
+         deposit is {1}"
+    )]
+    InvalidDeposit(String, String),
     /// The proposal content is too large
     #[error(
         "Invalid proposal content length: the proposal content length is {0} \
@@ -58,6 +64,18 @@ pub enum ProposalValidation {
          ({0}) is to big (max {1})"
     )]
     InvalidDefaultProposalExtraData(u64, u64),
+    /// The proposal has both wasm code and batch steps
+    #[error(
+        "Invalid proposal extra data: a proposal cannot have both wasm code \
+         and batch steps"
+    )]
+    InvalidDefaultProposalSteps,
+    /// The wasm code of a batch proposal step is not valid
+    #[error(
+        "Invalid proposal step {0}: the code is empty or its size ({1}) is \
+         too big (max {2})"
+    )]
+    InvalidDefaultProposalStepCode(usize, u64, u64),
     /// The PGF stewards data is not valid
     #[error("Invalid proposal extra data: cannot be empty.")]
     InvalidPgfStewardsExtraData,
@@ -70,14 +88,28 @@ pub enum ProposalValidation {
 
 pub fn is_valid_author_balance(
     author_balance: token::Amount,
-    min_proposal_fund: token::Amount,
+    deposit: token::Amount,
 ) -> Result<(), ProposalValidation> {
-    if author_balance.can_spend(&min_proposal_fund) {
+    if author_balance.can_spend(&deposit) {
         Ok(())
     } else {
         Err(ProposalValidation::InvalidBalance(
             author_balance.to_string_native(),
-            min_proposal_fund.to_string_native(),
+            deposit.to_string_native(),
+        ))
+    }
+}
+
+pub fn is_valid_deposit(
+    deposit: token::Amount,
+    min_proposal_initial_deposit: token::Amount,
+) -> Result<(), ProposalValidation> {
+    if deposit >= min_proposal_initial_deposit {
+        Ok(())
+    } else {
+        Err(ProposalValidation::InvalidDeposit(
+            deposit.to_string_native(),
+            min_proposal_initial_deposit.to_string_native(),
         ))
     }
 }
@@ -201,6 +233,30 @@ pub fn is_valid_default_proposal_data(
     }
 }
 
+pub fn is_valid_default_proposal_steps(
+    data: &Option<Vec<u8>>,
+    steps: &[DefaultProposalStep],
+    max_extra_data_size: u64,
+) -> Result<(), ProposalValidation> {
+    if steps.is_empty() {
+        return Ok(());
+    }
+    if data.is_some() {
+        return Err(ProposalValidation::InvalidDefaultProposalSteps);
+    }
+    for (index, step) in steps.iter().enumerate() {
+        let code_length = step.code.len() as u64;
+        if code_length == 0 || code_length > max_extra_data_size {
+            return Err(ProposalValidation::InvalidDefaultProposalStepCode(
+                index,
+                code_length,
+                max_extra_data_size,
+            ));
+        }
+    }
+    Ok(())
+}
+
 pub fn is_valid_pgf_stewards_data(
     data: &StewardsUpdate,
     author: &Address,
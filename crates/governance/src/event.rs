@@ -5,7 +5,7 @@ use std::collections::BTreeMap;
 use namada_events::extend::{EventAttributeEntry, ExtendAttributesMap};
 use namada_events::{Event, EventLevel, EventToEmit};
 
-use crate::utils::TallyResult as GovTallyResult;
+use crate::utils::{ProposalStepResult, TallyResult as GovTallyResult};
 use crate::ProposalType as GovProposalType;
 
 pub mod types {
@@ -36,6 +36,24 @@ pub mod types {
     pub const NEW_PROPOSAL: EventType =
         namada_events::event_type!(GovernanceEvent, PROPOSAL_SUBDOMAIN, "new");
 
+    /// Proposal cancelled by its author.
+    pub const PROPOSAL_CANCELLED: EventType = namada_events::event_type!(
+        GovernanceEvent,
+        PROPOSAL_SUBDOMAIN,
+        "cancelled"
+    );
+
+    /// Proposal content amended by its author.
+    pub const PROPOSAL_AMENDED: EventType = namada_events::event_type!(
+        GovernanceEvent,
+        PROPOSAL_SUBDOMAIN,
+        "amended"
+    );
+
+    /// Step of a batch proposal executed.
+    pub const PROPOSAL_STEP: EventType =
+        namada_events::event_type!(GovernanceEvent, PROPOSAL_SUBDOMAIN, "step");
+
     #[cfg(test)]
     mod tests {
         use super::*;
@@ -96,6 +114,34 @@ impl GovernanceEvent {
             kind: ProposalEventKind::Rejected { has_proposal_code },
         }
     }
+
+    /// Event for a proposal cancelled by its author
+    pub fn cancelled_proposal(proposal_id: u64) -> Self {
+        Self::Proposal {
+            id: proposal_id,
+            kind: ProposalEventKind::Cancelled,
+        }
+    }
+
+    /// Event for a proposal whose content was amended by its author
+    pub fn amended_proposal(proposal_id: u64) -> Self {
+        Self::Proposal {
+            id: proposal_id,
+            kind: ProposalEventKind::Amended,
+        }
+    }
+
+    /// Event for the execution of a step of a batch proposal
+    pub fn proposal_step(
+        proposal_id: u64,
+        step: u64,
+        result: ProposalStepResult,
+    ) -> Self {
+        Self::Proposal {
+            id: proposal_id,
+            kind: ProposalEventKind::Step { step, result },
+        }
+    }
 }
 
 /// Proposal event kinds
@@ -118,6 +164,17 @@ pub enum ProposalEventKind {
         /// Does the proposal contain code?
         has_proposal_code: bool,
     },
+    /// Proposal cancelled by its author
+    Cancelled,
+    /// Proposal content amended by its author
+    Amended,
+    /// Step of a batch proposal executed
+    Step {
+        /// Index of the step in the batch
+        step: u64,
+        /// Result of the execution of the step
+        result: ProposalStepResult,
+    },
 }
 
 impl From<GovernanceEvent> for Event {
@@ -159,6 +216,24 @@ impl From<GovernanceEvent> for Event {
                 );
                 (event_type, attributes)
             }
+            ProposalEventKind::Cancelled => {
+                let event_type = types::PROPOSAL_CANCELLED;
+                let attributes = proposal_id_attributes(proposal_id);
+                (event_type, attributes)
+            }
+            ProposalEventKind::Amended => {
+                let event_type = types::PROPOSAL_AMENDED;
+                let attributes = proposal_id_attributes(proposal_id);
+                (event_type, attributes)
+            }
+            ProposalEventKind::Step { step, result } => {
+                let event_type = types::PROPOSAL_STEP;
+                let mut attributes = proposal_id_attributes(proposal_id);
+                attributes
+                    .with_attribute(ProposalStep(step))
+                    .with_attribute(ProposalStepStatus(result));
+                (event_type, attributes)
+            }
         };
 
         let mut event = Self::new(event_type, EventLevel::Block);
@@ -201,6 +276,14 @@ fn new_governance_proposal_attributes(
     attrs
 }
 
+/// Return the attributes of a governance proposal that only carry its id.
+#[inline]
+fn proposal_id_attributes(id: u64) -> BTreeMap<String, String> {
+    let mut attrs = BTreeMap::new();
+    attrs.with_attribute(ProposalId(id));
+    attrs
+}
+
 impl EventToEmit for GovernanceEvent {
     const DOMAIN: &'static str = "governance";
 }
@@ -274,3 +357,31 @@ impl EventAttributeEntry<'static> for ProposalCodeExitStatus {
         self.0
     }
 }
+
+/// Extend an [`Event`] with the index of a step of a batch proposal.
+pub struct ProposalStep(pub u64);
+
+impl EventAttributeEntry<'static> for ProposalStep {
+    type Value = u64;
+    type ValueOwned = Self::Value;
+
+    const KEY: &'static str = "proposal_step";
+
+    fn into_value(self) -> Self::Value {
+        self.0
+    }
+}
+
+/// Extend an [`Event`] with the result of a step of a batch proposal.
+pub struct ProposalStepStatus(pub ProposalStepResult);
+
+impl EventAttributeEntry<'static> for ProposalStepStatus {
+    type Value = ProposalStepResult;
+    type ValueOwned = Self::Value;
+
+    const KEY: &'static str = "proposal_step_result";
+
+    fn into_value(self) -> Self::Value {
+        self.0
+    }
+}
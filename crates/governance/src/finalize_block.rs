@@ -1,11 +1,12 @@
 //! Governance logic applied on an end of a block.
 
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 
 use borsh::BorshDeserialize;
 use namada_core::address::Address;
+use namada_core::arith::checked;
 use namada_core::chain::Epoch;
-use namada_core::collections::HashMap;
+use namada_core::collections::{HashMap, HashSet};
 use namada_core::encode;
 use namada_core::ibc::PGFIbcTarget;
 use namada_events::extend::{ComposeEvent, Height};
@@ -20,11 +21,13 @@ use crate::pgf::storage::keys as pgf_keys;
 use crate::pgf::storage::steward::StewardDetail;
 use crate::pgf::{storage as pgf_storage, ADDRESS as PGF_ADDRESS};
 use crate::storage::proposal::{
-    AddRemove, PGFAction, PGFTarget, ProposalType, StoragePgfFunding,
+    AddRemove, PGFAction, PGFTarget, ProposalStepData, ProposalType,
+    StoragePgfFunding, WasmProposalStep,
 };
 use crate::storage::{keys, load_proposals};
 use crate::utils::{
-    compute_proposal_result, ProposalVotes, TallyResult, TallyType, VotePower,
+    compute_proposal_result, ProposalStepResult, ProposalVotes, TallyResult,
+    TallyType, VotePower,
 };
 use crate::{storage, ProposalVote, ADDRESS as GOV_ADDRESS};
 
@@ -72,6 +75,9 @@ where
     FnTx: FnMut(&Tx, &mut S) -> Result<bool>,
     FnIbcTransfer: Fn(&mut S, &Address, &Address, &PGFIbcTarget) -> Result<()>,
 {
+    drop_cancelled_proposals::<S, Token>(state)?;
+    drop_unfunded_proposals::<S, Token>(state, current_epoch)?;
+
     let proposal_ids = load_proposals(state, current_epoch)?;
 
     execute_governance_proposals::<S, Token, PoS, FnTx, FnIbcTransfer>(
@@ -83,6 +89,132 @@ where
     )
 }
 
+/// Drop the proposals whose deposit phase ended without reaching the minimum
+/// proposal fund and refund their deposits.
+fn drop_unfunded_proposals<S, Token>(
+    state: &mut S,
+    current_epoch: Epoch,
+) -> Result<()>
+where
+    S: StateRead + State,
+    Token: token::Read<S> + token::Write<S> + token::Events<S>,
+{
+    let pending = storage::load_pending_deposit_proposals(state)?;
+    for (id, voting_start_epoch) in pending {
+        if voting_start_epoch > current_epoch {
+            continue;
+        }
+        let deposits = storage::get_proposal_deposits(state, id)?;
+
+        const DESCRIPTOR: &str = "governance-proposal-deposit-refund";
+
+        refund_deposits::<S, Token>(state, deposits, DESCRIPTOR)?;
+        storage::remove_proposal(state, id)?;
+
+        tracing::info!(
+            "Governance proposal {} didn't reach the minimum proposal fund by \
+             its voting start epoch {}. It has been dropped and its deposits \
+             refunded.",
+            id,
+            voting_start_epoch
+        );
+    }
+    Ok(())
+}
+
+/// Drop the proposals cancelled by their authors. The deposits of other
+/// depositors are refunded in full. Only a fraction of the author's own
+/// deposit, set by the `proposal_cancellation_refund` parameter, is refunded
+/// and the rest is burned.
+fn drop_cancelled_proposals<S, Token>(state: &mut S) -> Result<()>
+where
+    S: StateRead + State,
+    Token: token::Read<S> + token::Write<S> + token::Events<S>,
+{
+    let cancelled = storage::load_cancelled_proposals(state)?;
+    if cancelled.is_empty() {
+        return Ok(());
+    }
+    let refund_ratio =
+        storage::get_parameters(state)?.proposal_cancellation_refund;
+    let native_token = state.get_native_token()?;
+
+    for id in cancelled {
+        let author = storage::get_proposal_author(state, id)?;
+        let mut refunds = BTreeMap::new();
+        let mut burned = token::Amount::zero();
+        for (depositor, amount) in storage::get_proposal_deposits(state, id)? {
+            let refund = if author.as_ref() == Some(&depositor) {
+                amount.mul_floor(refund_ratio)?
+            } else {
+                amount
+            };
+            let burn = checked!(amount - refund)?;
+            burned = checked!(burned + burn)?;
+            refunds.insert(depositor, refund);
+        }
+
+        const REFUND_DESCRIPTOR: &str = "governance-proposal-cancel-refund";
+
+        refund_deposits::<S, Token>(state, refunds, REFUND_DESCRIPTOR)?;
+        if !burned.is_zero() {
+            Token::burn_tokens(state, &native_token, &GOV_ADDRESS, burned)?;
+
+            const BURN_DESCRIPTOR: &str = "governance-proposal-cancel-burn";
+
+            Token::emit_burn_event(
+                state,
+                BURN_DESCRIPTOR.into(),
+                &native_token,
+                burned,
+                &GOV_ADDRESS,
+            )?;
+        }
+        storage::remove_proposal(state, id)?;
+
+        tracing::info!(
+            "Governance proposal {} has been cancelled by its author and \
+             dropped, {} of its funds have been burned.",
+            id,
+            burned.to_string_native()
+        );
+    }
+    Ok(())
+}
+
+/// Refund the deposits locked in a proposal to their depositors.
+fn refund_deposits<S, Token>(
+    state: &mut S,
+    deposits: BTreeMap<Address, token::Amount>,
+    descriptor: &'static str,
+) -> Result<()>
+where
+    S: StateRead + State,
+    Token: token::Read<S> + token::Write<S> + token::Events<S>,
+{
+    let native_token = state.get_native_token()?;
+    for (depositor, amount) in deposits {
+        Token::transfer(
+            state,
+            &native_token,
+            &GOV_ADDRESS,
+            &depositor,
+            amount,
+        )?;
+
+        Token::emit_transfer_event(
+            state,
+            descriptor.into(),
+            EventLevel::Tx,
+            &native_token,
+            amount,
+            token::UserAccount::Internal(GOV_ADDRESS),
+            token::UserAccount::Internal(depositor),
+        )?;
+    }
+    Ok(())
+}
+
 fn execute_governance_proposals<S, Token, PoS, FnTx, FnIbcTransfer>(
     state: &mut S,
     events: &mut impl EmitEvents,
@@ -117,17 +249,20 @@ where
         >(state, proposal_end_epoch)?;
 
         let tally_type = TallyType::from(proposal_type.clone(), is_steward);
+        let tally_params = storage::get_parameters(state)?
+            .tally_params(&proposal_type, is_steward);
         let votes =
             compute_proposal_votes::<S, PoS>(state, id, proposal_end_epoch)?;
         let proposal_result = compute_proposal_result(
             votes,
             total_active_voting_power,
             tally_type,
+            tally_params,
         )
         .expect("Proposal result calculation must not over/underflow");
         storage::write_proposal_result(state, id, proposal_result)?;
 
-        let transfer_address = match proposal_result.result {
+        let refunds = match proposal_result.result {
             TallyResult::Passed => {
                 let proposal_event = match proposal_type {
                     ProposalType::Default => {
@@ -157,6 +292,31 @@ where
 
                         GovernanceEvent::passed_proposal(id, true, result)
                     }
+                    ProposalType::DefaultWithWasmBatch(steps) => {
+                        let step_codes =
+                            storage::get_proposal_step_codes(state, id)?;
+                        let result = execute_batch_proposal(
+                            state,
+                            events,
+                            id,
+                            steps,
+                            step_codes,
+                            &mut dispatch_tx,
+                        )?;
+                        tracing::info!(
+                            "Governance proposal #{} (default with wasm \
+                             batch) has passed and been executed, wasm \
+                             execution: {}.",
+                            id,
+                            if result {
+                                "successful"
+                            } else {
+                                "unsuccessful - no state change occurred"
+                            }
+                        );
+
+                        GovernanceEvent::passed_proposal(id, true, result)
+                    }
                     ProposalType::PGFSteward(stewards) => {
                         let result =
                             execute_pgf_steward_proposal(state, stewards)?;
@@ -207,9 +367,17 @@ where
                         .map(|event| event.with(Height(current_height))),
                 );
 
-                storage::get_proposal_author(state, id)?
+                Some(storage::get_proposal_deposits(state, id)?)
             }
             TallyResult::Rejected => {
+                if proposal_result.is_vetoed() {
+                    tracing::info!(
+                        "Governance proposal {} was vetoed by {} of the \
+                         voting power. Its locked funds are being burned.",
+                        id,
+                        proposal_result.total_veto_power.to_string_native()
+                    );
+                }
                 if let ProposalType::PGFPayment(_) = proposal_type {
                     if proposal_result.two_thirds_nay_over_two_thirds_total() {
                         pgf_storage::remove_steward(state, &proposal_author)?;
@@ -226,7 +394,11 @@ where
                 }
                 let proposal_event = GovernanceEvent::rejected_proposal(
                     id,
-                    matches!(proposal_type, ProposalType::DefaultWithWasm(_)),
+                    matches!(
+                        proposal_type,
+                        ProposalType::DefaultWithWasm(_)
+                            | ProposalType::DefaultWithWasmBatch(_)
+                    ),
                 );
                 events.emit(proposal_event);
 
@@ -240,26 +412,10 @@ where
         };
 
         let native_token = state.get_native_token()?;
-        if let Some(address) = transfer_address {
-            Token::transfer(
-                state,
-                &native_token,
-                &GOV_ADDRESS,
-                &address,
-                funds,
-            )?;
-
+        if let Some(deposits) = refunds {
             const DESCRIPTOR: &str = "governance-locked-funds-refund";
 
-            Token::emit_transfer_event(
-                state,
-                DESCRIPTOR.into(),
-                EventLevel::Tx,
-                &native_token,
-                funds,
-                token::UserAccount::Internal(GOV_ADDRESS),
-                token::UserAccount::Internal(address),
-            )?;
+            refund_deposits::<S, Token>(state, deposits, DESCRIPTOR)?;
         } else {
             Token::burn_tokens(state, &native_token, &GOV_ADDRESS, funds)?;
 
@@ -301,6 +457,14 @@ where
 
     let mut validator_cache: HashMap<Address, bool> = HashMap::default();
 
+    // The delegators that voted themselves override the votes of their
+    // representatives
+    let direct_voters: HashSet<Address> = votes
+        .iter()
+        .filter(|vote| !vote.is_validator())
+        .map(|vote| vote.delegator.clone())
+        .collect();
+
     for vote in votes {
         let validator = &vote.validator;
 
@@ -352,6 +516,55 @@ where
         }
     }
 
+    // Tally the votes cast by representatives on behalf of their constituents
+    for (constituent, representative) in
+        storage::get_delegations(storage, epoch)?
+    {
+        if direct_voters.contains(&constituent) {
+            continue;
+        }
+        let representative_vote_key =
+            keys::get_representative_vote_key(proposal_id, &representative);
+        let Some(vote_data) =
+            storage.read::<ProposalVote>(&representative_vote_key)?
+        else {
+            continue;
+        };
+
+        for validator in
+            PoS::delegation_validators(storage, &constituent, epoch)?
+        {
+            let is_active_validator = if let Some(is_active_validator) =
+                validator_cache.get(&validator)
+            {
+                *is_active_validator
+            } else {
+                let is_active_validator =
+                    PoS::is_active_validator::<crate::Store<_>>(
+                        storage, &validator, epoch,
+                    )?;
+                validator_cache.insert(validator.clone(), is_active_validator);
+                is_active_validator
+            };
+            if !is_active_validator {
+                continue;
+            }
+
+            if let Ok(stake) = PoS::bond_amount::<crate::Store<_>>(
+                storage,
+                &validator,
+                &constituent,
+                epoch,
+            ) {
+                delegators_vote.insert(constituent.clone(), vote_data.clone());
+                delegator_voting_power
+                    .entry(constituent.clone())
+                    .or_default()
+                    .insert(validator, stake);
+            }
+        }
+    }
+
     Ok(ProposalVotes {
         validators_vote,
         validator_voting_power,
@@ -379,12 +592,78 @@ where
     tx.set_code(Code::new(proposal_code, None));
 
     let dispatch_result = dispatch_tx(&tx, state);
+    if let Ok(true) = dispatch_result {
+        state.write_log_mut().commit_batch_only();
+    }
     state
         .delete(&pending_execution_key)
         .expect("Should be able to delete the storage.");
     dispatch_result
 }
 
+/// Execute the steps of a batch proposal in order. The changes of every step
+/// are kept in the batch write log, and they are committed only if all the
+/// steps succeed. After the first failed step, the remaining ones are
+/// skipped and all the changes of the batch are dropped. An event is emitted
+/// with the result of each step.
+fn execute_batch_proposal<S, FnTx>(
+    state: &mut S,
+    events: &mut impl EmitEvents,
+    id: u64,
+    steps: Vec<WasmProposalStep>,
+    step_codes: Vec<Vec<u8>>,
+    dispatch_tx: &mut FnTx,
+) -> Result<bool>
+where
+    S: StateRead + State,
+    FnTx: FnMut(&Tx, &mut S) -> Result<bool>,
+{
+    let pending_execution_key = keys::get_proposal_execution_key(id);
+    state.write(&pending_execution_key, ())?;
+
+    let mut is_successful = true;
+    for ((step, WasmProposalStep { data, .. }), code) in
+        (0_u64..).zip(steps).zip(step_codes)
+    {
+        let result = if is_successful {
+            let mut tx = Tx::from_type(TxType::Raw);
+            tx.header.chain_id = state.get_chain_id()?;
+            tx.set_data(Data::new(encode(&ProposalStepData {
+                id,
+                step,
+                data,
+            })));
+            tx.set_code(Code::new(code, None));
+
+            if dispatch_tx(&tx, state)? {
+                ProposalStepResult::Success
+            } else {
+                is_successful = false;
+                ProposalStepResult::Failure
+            }
+        } else {
+            ProposalStepResult::Skipped
+        };
+        tracing::info!(
+            "Governance proposal #{} batch step {} execution: {}.",
+            id,
+            step,
+            result
+        );
+        events.emit(GovernanceEvent::proposal_step(id, step, result));
+    }
+
+    if is_successful {
+        state.write_log_mut().commit_batch_only();
+    } else {
+        state.write_log_mut().drop_batch();
+    }
+    state
+        .delete(&pending_execution_key)
+        .expect("Should be able to delete the storage.");
+    Ok(is_successful)
+}
+
 fn execute_pgf_steward_proposal<S>(
     storage: &mut S,
     stewards: BTreeSet<AddRemove<Address>>,
@@ -558,3 +837,108 @@ where
         .transpose()
         .expect("Storage key must be present.")
 }
+
+#[cfg(test)]
+mod test_batch_proposal {
+    use namada_events::Event;
+    use namada_state::testing::TestState;
+
+    use super::*;
+    use crate::event::types::PROPOSAL_STEP;
+    use crate::event::ProposalStepStatus;
+
+    /// Execute a batch proposal whose steps each write their index to a key,
+    /// and fail at the given step, if any.
+    fn execute(
+        state: &mut TestState,
+        failing_step: Option<u64>,
+    ) -> (bool, Vec<Event>) {
+        let steps = (0..3_u8)
+            .map(|index| WasmProposalStep {
+                code: Default::default(),
+                data: vec![index],
+            })
+            .collect();
+        let step_codes = vec![vec![]; 3];
+        let mut events = vec![];
+        // Mimic the dispatch of the ledger, which keeps the changes of an
+        // accepted tx in the batch write log and drops the whole batch on
+        // failure
+        let mut dispatch_tx =
+            |tx: &Tx, state: &mut TestState| -> Result<bool> {
+                let cmt = tx.first_commitments().unwrap();
+                let data = ProposalStepData::try_from_slice(
+                    &tx.data(cmt).unwrap_or_default(),
+                )
+                .unwrap();
+                let is_accepted = storage::is_proposal_accepted(
+                    &*state,
+                    &tx.data(cmt).unwrap_or_default(),
+                );
+                assert!(is_accepted.unwrap());
+                let key = Key::parse(format!("step/{}", data.step)).unwrap();
+                state.write_log_mut().write(&key, data.data).unwrap();
+                if failing_step == Some(data.step) {
+                    state.write_log_mut().drop_batch();
+                    Ok(false)
+                } else {
+                    state.write_log_mut().commit_tx_to_batch();
+                    Ok(true)
+                }
+            };
+        let result = execute_batch_proposal(
+            state,
+            &mut events,
+            0,
+            steps,
+            step_codes,
+            &mut dispatch_tx,
+        )
+        .unwrap();
+        (result, events)
+    }
+
+    fn step_results(events: &[Event]) -> Vec<ProposalStepResult> {
+        events
+            .iter()
+            .map(|event| {
+                assert_eq!(*event.kind(), PROPOSAL_STEP);
+                event.read_attribute::<ProposalStepStatus>().unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_batch_proposal_all_steps_succeed() {
+        let mut state = TestState::default();
+        let (result, events) = execute(&mut state, None);
+        assert!(result);
+        assert_eq!(step_results(&events), [ProposalStepResult::Success; 3]);
+        for step in 0..3_u8 {
+            let key = Key::parse(format!("step/{step}")).unwrap();
+            assert_eq!(state.read_bytes(&key).unwrap(), Some(vec![step]));
+        }
+        let execution_key = keys::get_proposal_execution_key(0);
+        assert!(!state.has_key(&execution_key).unwrap());
+    }
+
+    #[test]
+    fn test_batch_proposal_is_atomic() {
+        let mut state = TestState::default();
+        let (result, events) = execute(&mut state, Some(1));
+        assert!(!result);
+        assert_eq!(
+            step_results(&events),
+            [
+                ProposalStepResult::Success,
+                ProposalStepResult::Failure,
+                ProposalStepResult::Skipped
+            ]
+        );
+        // the changes of the successful step are dropped too
+        for step in 0..3_u8 {
+            let key = Key::parse(format!("step/{step}")).unwrap();
+            assert_eq!(state.read_bytes(&key).unwrap(), None);
+        }
+    }
+}
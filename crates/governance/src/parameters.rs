@@ -1,4 +1,5 @@
 use namada_core::borsh::{BorshDeserialize, BorshSerialize};
+use namada_core::dec::Dec;
 use namada_core::token;
 use namada_macros::BorshDeserializer;
 #[cfg(feature = "migrations")]
@@ -7,6 +8,8 @@ use namada_state::{Result, StorageRead, StorageWrite};
 use serde::Serialize;
 
 use super::storage::keys as goverance_storage;
+use super::storage::proposal::ProposalType;
+use super::utils::{TallyParams, TallyType};
 
 #[derive(
     Clone,
@@ -25,6 +28,14 @@ use super::storage::keys as goverance_storage;
 pub struct GovernanceParameters {
     /// Minimum amount of locked funds
     pub min_proposal_fund: token::Amount,
+    /// Minimum amount locked by the author of a proposal, the rest of the
+    /// minimum proposal fund can be deposited by anyone until the voting
+    /// start epoch
+    pub min_proposal_initial_deposit: token::Amount,
+    /// Fraction of the author's deposit refunded when a proposal is cancelled
+    /// by its author, the rest is burned. Other depositors are refunded in
+    /// full
+    pub proposal_cancellation_refund: Dec,
     /// Maximum length for proposal code in bytes
     pub max_proposal_code_size: u64,
     /// Minimum number of epochs between the proposal end epoch and start epoch
@@ -38,23 +49,67 @@ pub struct GovernanceParameters {
     pub min_proposal_grace_epochs: u64,
     /// Maximum number of epochs between current epoch and start epoch
     pub max_proposal_latency: u64,
+    /// Tally parameters of default proposals
+    pub default_tally_params: TallyParams,
+    /// Tally parameters of default proposals with wasm code
+    pub default_with_wasm_tally_params: TallyParams,
+    /// Tally parameters of PGF stewards proposals
+    pub pgf_steward_tally_params: TallyParams,
+    /// Tally parameters of PGF funding proposals
+    pub pgf_payment_tally_params: TallyParams,
+    /// Tally parameters of PGF funding proposals authored by a steward
+    pub pgf_steward_payment_tally_params: TallyParams,
 }
 
 impl Default for GovernanceParameters {
     fn default() -> Self {
         Self {
             min_proposal_fund: token::Amount::native_whole(500),
+            min_proposal_initial_deposit: token::Amount::native_whole(100),
+            proposal_cancellation_refund: Dec::new(5, 1).expect("Cannot fail"),
             max_proposal_code_size: 300_000,
             min_proposal_voting_period: 3,
             max_proposal_period: 27,
             max_proposal_content_size: 10_000,
             min_proposal_grace_epochs: 6,
             max_proposal_latency: 30,
+            default_tally_params: TallyType::TwoFifths.default_params(),
+            default_with_wasm_tally_params: TallyType::TwoFifths
+                .default_params(),
+            pgf_steward_tally_params: TallyType::OneHalfOverOneThird
+                .default_params(),
+            pgf_payment_tally_params: TallyType::OneHalfOverOneThird
+                .default_params(),
+            pgf_steward_payment_tally_params:
+                TallyType::LessOneHalfOverOneThirdNay.default_params(),
         }
     }
 }
 
 impl GovernanceParameters {
+    /// The tally parameters of a proposal type. The `is_steward` flag tells
+    /// if the author of the proposal is a PGF steward.
+    pub fn tally_params(
+        &self,
+        proposal_type: &ProposalType,
+        is_steward: bool,
+    ) -> TallyParams {
+        match (proposal_type, is_steward) {
+            (ProposalType::Default, _) => self.default_tally_params,
+            (ProposalType::DefaultWithWasm(_), _)
+            | (ProposalType::DefaultWithWasmBatch(_), _) => {
+                self.default_with_wasm_tally_params
+            }
+            (ProposalType::PGFSteward(_), _) => self.pgf_steward_tally_params,
+            (ProposalType::PGFPayment(_), true) => {
+                self.pgf_steward_payment_tally_params
+            }
+            (ProposalType::PGFPayment(_), false) => {
+                self.pgf_payment_tally_params
+            }
+        }
+    }
+
     /// Initialize governance parameters into storage
     pub fn init_storage<S>(&self, storage: &mut S) -> Result<()>
     where
@@ -62,18 +117,39 @@ impl GovernanceParameters {
     {
         let Self {
             min_proposal_fund,
+            min_proposal_initial_deposit,
+            proposal_cancellation_refund,
             max_proposal_code_size,
             min_proposal_voting_period,
             max_proposal_period,
             max_proposal_content_size,
             min_proposal_grace_epochs,
             max_proposal_latency,
+            default_tally_params,
+            default_with_wasm_tally_params,
+            pgf_steward_tally_params,
+            pgf_payment_tally_params,
+            pgf_steward_payment_tally_params,
         } = self;
 
         let min_proposal_fund_key =
             goverance_storage::get_min_proposal_fund_key();
         storage.write(&min_proposal_fund_key, min_proposal_fund)?;
 
+        let min_proposal_initial_deposit_key =
+            goverance_storage::get_min_proposal_initial_deposit_key();
+        storage.write(
+            &min_proposal_initial_deposit_key,
+            min_proposal_initial_deposit,
+        )?;
+
+        let proposal_cancellation_refund_key =
+            goverance_storage::get_proposal_cancellation_refund_key();
+        storage.write(
+            &proposal_cancellation_refund_key,
+            proposal_cancellation_refund,
+        )?;
+
         let max_proposal_code_size_key =
             goverance_storage::get_max_proposal_code_size_key();
         storage.write(&max_proposal_code_size_key, max_proposal_code_size)?;
@@ -103,6 +179,34 @@ impl GovernanceParameters {
             goverance_storage::get_max_proposal_latency_key();
         storage.write(&max_proposal_latency_key, max_proposal_latency)?;
 
+        let default_tally_params_key =
+            goverance_storage::get_default_tally_params_key();
+        storage.write(&default_tally_params_key, default_tally_params)?;
+
+        let default_with_wasm_tally_params_key =
+            goverance_storage::get_default_with_wasm_tally_params_key();
+        storage.write(
+            &default_with_wasm_tally_params_key,
+            default_with_wasm_tally_params,
+        )?;
+
+        let pgf_steward_tally_params_key =
+            goverance_storage::get_pgf_steward_tally_params_key();
+        storage
+            .write(&pgf_steward_tally_params_key, pgf_steward_tally_params)?;
+
+        let pgf_payment_tally_params_key =
+            goverance_storage::get_pgf_payment_tally_params_key();
+        storage
+            .write(&pgf_payment_tally_params_key, pgf_payment_tally_params)?;
+
+        let pgf_steward_payment_tally_params_key =
+            goverance_storage::get_pgf_steward_payment_tally_params_key();
+        storage.write(
+            &pgf_steward_payment_tally_params_key,
+            pgf_steward_payment_tally_params,
+        )?;
+
         let counter_key = goverance_storage::get_counter_key();
         storage.write(&counter_key, u64::MIN)
     }
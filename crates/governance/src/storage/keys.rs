@@ -1,4 +1,5 @@
 use namada_core::address::Address;
+use namada_core::chain::Epoch;
 use namada_core::storage::{DbKeySeg, Key, KeySeg};
 use namada_macros::StorageKeys;
 
@@ -17,17 +18,32 @@ struct Keys {
     activation_epoch: &'static str,
     funds: &'static str,
     proposal_code: &'static str,
+    step_code: &'static str,
     committing_epoch: &'static str,
     min_fund: &'static str,
+    min_initial_deposit: &'static str,
     max_code_size: &'static str,
     min_period: &'static str,
     max_period: &'static str,
     max_content: &'static str,
     max_latency: &'static str,
     min_grace_epochs: &'static str,
+    default_tally_params: &'static str,
+    default_with_wasm_tally_params: &'static str,
+    pgf_steward_tally_params: &'static str,
+    pgf_payment_tally_params: &'static str,
+    pgf_steward_payment_tally_params: &'static str,
     counter: &'static str,
     pending: &'static str,
     result: &'static str,
+    result_tally: &'static str,
+    delegate: &'static str,
+    representative: &'static str,
+    representative_vote: &'static str,
+    deposit: &'static str,
+    deposit_pending: &'static str,
+    cancellation_refund: &'static str,
+    cancelled: &'static str,
 }
 
 /// Check if key is inside governance address space
@@ -55,6 +71,96 @@ pub fn is_vote_key(key: &Key) -> bool {
     }
 }
 
+/// Check if a key is a representative vote key, returning the address of the
+/// representative
+pub fn is_representative_vote_key(key: &Key) -> Option<&Address> {
+    match &key.segments[..] {
+        [
+            DbKeySeg::AddressSeg(addr),
+            DbKeySeg::StringSeg(prefix),
+            DbKeySeg::StringSeg(id),
+            DbKeySeg::StringSeg(vote),
+            DbKeySeg::AddressSeg(representative),
+        ] if addr == &ADDRESS
+            && prefix == Keys::VALUES.proposal
+            && vote == Keys::VALUES.representative_vote
+            && id.parse::<u64>().is_ok() =>
+        {
+            Some(representative)
+        }
+        _ => None,
+    }
+}
+
+/// Check if a key is a governance delegate key, returning the address of the
+/// delegator and the epoch from which the delegate is effective
+pub fn is_delegate_key(key: &Key) -> Option<(&Address, Epoch)> {
+    match &key.segments[..] {
+        [
+            DbKeySeg::AddressSeg(addr),
+            DbKeySeg::StringSeg(prefix),
+            DbKeySeg::AddressSeg(delegator),
+            DbKeySeg::StringSeg(epoch),
+        ] if addr == &ADDRESS && prefix == Keys::VALUES.delegate => {
+            Epoch::parse(epoch.clone())
+                .ok()
+                .map(|epoch| (delegator, epoch))
+        }
+        _ => None,
+    }
+}
+
+/// Check if a key is a constituent key of a representative, returning the
+/// addresses of the representative and of the constituent
+pub fn is_constituent_key(key: &Key) -> Option<(&Address, &Address)> {
+    match &key.segments[..] {
+        [
+            DbKeySeg::AddressSeg(addr),
+            DbKeySeg::StringSeg(prefix),
+            DbKeySeg::AddressSeg(representative),
+            DbKeySeg::AddressSeg(constituent),
+        ] if addr == &ADDRESS && prefix == Keys::VALUES.representative => {
+            Some((representative, constituent))
+        }
+        _ => None,
+    }
+}
+
+/// Check if a key is a deposit key of a proposal, returning the address of
+/// the depositor
+pub fn is_deposit_key(key: &Key) -> Option<&Address> {
+    match &key.segments[..] {
+        [
+            DbKeySeg::AddressSeg(addr),
+            DbKeySeg::StringSeg(prefix),
+            DbKeySeg::StringSeg(id),
+            DbKeySeg::StringSeg(deposit),
+            DbKeySeg::AddressSeg(depositor),
+        ] if addr == &ADDRESS
+            && prefix == Keys::VALUES.proposal
+            && deposit == Keys::VALUES.deposit
+            && id.parse::<u64>().is_ok() =>
+        {
+            Some(depositor)
+        }
+        _ => None,
+    }
+}
+
+/// Check if a key is the key of a proposal that is still in its deposit phase
+pub fn is_deposit_pending_key(key: &Key) -> bool {
+    match &key.segments[..] {
+        [
+            DbKeySeg::AddressSeg(addr),
+            DbKeySeg::StringSeg(prefix),
+            DbKeySeg::StringSeg(id),
+        ] if addr == &ADDRESS && prefix == Keys::VALUES.deposit_pending => {
+            id.parse::<u64>().is_ok()
+        }
+        _ => false,
+    }
+}
+
 /// Check if key is author key
 pub fn is_author_key(key: &Key) -> bool {
     match &key.segments[..] {
@@ -91,6 +197,25 @@ pub fn is_proposal_code_key(key: &Key) -> bool {
     }
 }
 
+/// Check if key is the code key of a step of a batch proposal
+pub fn is_proposal_step_code_key(key: &Key) -> bool {
+    match &key.segments[..] {
+        [
+            DbKeySeg::AddressSeg(addr),
+            DbKeySeg::StringSeg(prefix),
+            DbKeySeg::StringSeg(id),
+            DbKeySeg::StringSeg(step_code),
+            DbKeySeg::StringSeg(index),
+        ] if addr == &ADDRESS
+            && prefix == Keys::VALUES.proposal
+            && step_code == Keys::VALUES.step_code =>
+        {
+            id.parse::<u64>().is_ok() && index.parse::<u64>().is_ok()
+        }
+        _ => false,
+    }
+}
+
 /// Check if key is activation epoch key
 pub fn is_activation_epoch_key(key: &Key) -> bool {
     match &key.segments[..] {
@@ -212,6 +337,24 @@ pub fn is_min_proposal_fund_key(key: &Key) -> bool {
          ] if addr == &ADDRESS && min_funds_param == Keys::VALUES.min_fund)
 }
 
+/// Check if key is a proposal initial deposit parameter key
+pub fn is_min_proposal_initial_deposit_key(key: &Key) -> bool {
+    matches!(&key.segments[..], [
+             DbKeySeg::AddressSeg(addr),
+             DbKeySeg::StringSeg(min_initial_deposit_param),
+         ] if addr == &ADDRESS
+             && min_initial_deposit_param == Keys::VALUES.min_initial_deposit)
+}
+
+/// Check if key is a proposal cancellation refund parameter key
+pub fn is_proposal_cancellation_refund_key(key: &Key) -> bool {
+    matches!(&key.segments[..], [
+             DbKeySeg::AddressSeg(addr),
+             DbKeySeg::StringSeg(cancellation_refund_param),
+         ] if addr == &ADDRESS
+             && cancellation_refund_param == Keys::VALUES.cancellation_refund)
+}
+
 /// Check if key is a proposal max content parameter key
 pub fn is_max_content_size_key(key: &Key) -> bool {
     matches!(&key.segments[..], [
@@ -271,14 +414,32 @@ pub fn is_min_grace_epochs_key(key: &Key) -> bool {
                     && min_grace_epochs_param == Keys::VALUES.min_grace_epochs)
 }
 
+/// Check if key is a tally parameters key of any proposal type
+pub fn is_tally_params_key(key: &Key) -> bool {
+    matches!(&key.segments[..], [
+                    DbKeySeg::AddressSeg(addr),
+                    DbKeySeg::StringSeg(tally_params),
+                ] if addr == &ADDRESS
+                    && [
+                        Keys::VALUES.default_tally_params,
+                        Keys::VALUES.default_with_wasm_tally_params,
+                        Keys::VALUES.pgf_steward_tally_params,
+                        Keys::VALUES.pgf_payment_tally_params,
+                        Keys::VALUES.pgf_steward_payment_tally_params,
+                    ].contains(&tally_params.as_str()))
+}
+
 /// Check if key is parameter key
 pub fn is_parameter_key(key: &Key) -> bool {
     is_min_proposal_fund_key(key)
+        || is_min_proposal_initial_deposit_key(key)
+        || is_proposal_cancellation_refund_key(key)
         || is_max_content_size_key(key)
         || is_max_proposal_code_size_key(key)
         || is_min_proposal_voting_period_key(key)
         || is_max_proposal_period_key(key)
         || is_min_grace_epochs_key(key)
+        || is_tally_params_key(key)
 }
 
 /// Check if key is start epoch or end epoch key
@@ -300,6 +461,20 @@ pub fn get_min_proposal_fund_key() -> Key {
         .expect("Cannot obtain a storage key")
 }
 
+/// Get key for the minimum initial deposit of a proposal
+pub fn get_min_proposal_initial_deposit_key() -> Key {
+    Key::from(ADDRESS.to_db_key())
+        .push(&Keys::VALUES.min_initial_deposit.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get key for the fraction of the funds refunded on a proposal cancellation
+pub fn get_proposal_cancellation_refund_key() -> Key {
+    Key::from(ADDRESS.to_db_key())
+        .push(&Keys::VALUES.cancellation_refund.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
 /// Get maximum proposal code size key
 pub fn get_max_proposal_code_size_key() -> Key {
     Key::from(ADDRESS.to_db_key())
@@ -342,6 +517,41 @@ pub fn get_min_proposal_grace_epochs_key() -> Key {
         .expect("Cannot obtain a storage key")
 }
 
+/// Get tally parameters key of default proposals
+pub fn get_default_tally_params_key() -> Key {
+    Key::from(ADDRESS.to_db_key())
+        .push(&Keys::VALUES.default_tally_params.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get tally parameters key of default proposals with wasm code
+pub fn get_default_with_wasm_tally_params_key() -> Key {
+    Key::from(ADDRESS.to_db_key())
+        .push(&Keys::VALUES.default_with_wasm_tally_params.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get tally parameters key of PGF stewards proposals
+pub fn get_pgf_steward_tally_params_key() -> Key {
+    Key::from(ADDRESS.to_db_key())
+        .push(&Keys::VALUES.pgf_steward_tally_params.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get tally parameters key of PGF funding proposals
+pub fn get_pgf_payment_tally_params_key() -> Key {
+    Key::from(ADDRESS.to_db_key())
+        .push(&Keys::VALUES.pgf_payment_tally_params.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get tally parameters key of PGF funding proposals authored by a steward
+pub fn get_pgf_steward_payment_tally_params_key() -> Key {
+    Key::from(ADDRESS.to_db_key())
+        .push(&Keys::VALUES.pgf_steward_payment_tally_params.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
 /// Get key of proposal ids counter
 pub fn get_counter_key() -> Key {
     Key::from(ADDRESS.to_db_key())
@@ -430,6 +640,17 @@ pub fn get_proposal_code_key(id: u64) -> Key {
         .expect("Cannot obtain a storage key")
 }
 
+/// Get the code key of a step of a batch proposal
+pub fn get_proposal_step_code_key(id: u64, index: u64) -> Key {
+    proposal_prefix()
+        .push(&id.to_string())
+        .expect("Cannot obtain a storage key")
+        .push(&Keys::VALUES.step_code.to_owned())
+        .expect("Cannot obtain a storage key")
+        .push(&index.to_string())
+        .expect("Cannot obtain a storage key")
+}
+
 /// Get the committing proposal key
 pub fn get_committing_proposals_key(id: u64, epoch: u64) -> Key {
     get_commiting_proposals_prefix(epoch)
@@ -459,6 +680,107 @@ pub fn get_vote_proposal_key(
         .expect("Cannot obtain a storage key")
 }
 
+/// Get the representative vote prefix key for a specific proposal id
+pub fn get_representative_vote_prefix_key(id: u64) -> Key {
+    proposal_prefix()
+        .push(&id.to_string())
+        .expect("Cannot obtain a storage key")
+        .push(&Keys::VALUES.representative_vote.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get the vote key of a representative for a specific proposal id
+pub fn get_representative_vote_key(id: u64, representative: &Address) -> Key {
+    get_representative_vote_prefix_key(id)
+        .push(representative)
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get the prefix of the governance delegate keys
+pub fn get_delegate_prefix_key() -> Key {
+    Key::from(ADDRESS.to_db_key())
+        .push(&Keys::VALUES.delegate.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get the prefix of the governance delegate keys of a delegator
+pub fn get_delegator_delegate_prefix_key(delegator: &Address) -> Key {
+    get_delegate_prefix_key()
+        .push(delegator)
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get the key of the representative of a delegator, effective from the
+/// given epoch
+pub fn get_delegate_key(delegator: &Address, epoch: Epoch) -> Key {
+    get_delegator_delegate_prefix_key(delegator)
+        .push(&epoch)
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get the prefix of the constituent keys of a representative
+pub fn get_constituents_prefix_key(representative: &Address) -> Key {
+    Key::from(ADDRESS.to_db_key())
+        .push(&Keys::VALUES.representative.to_owned())
+        .expect("Cannot obtain a storage key")
+        .push(representative)
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get the key of a constituent of a representative
+pub fn get_constituent_key(
+    representative: &Address,
+    constituent: &Address,
+) -> Key {
+    get_constituents_prefix_key(representative)
+        .push(constituent)
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get the prefix of the deposit keys of a proposal
+pub fn get_deposit_prefix_key(id: u64) -> Key {
+    proposal_prefix()
+        .push(&id.to_string())
+        .expect("Cannot obtain a storage key")
+        .push(&Keys::VALUES.deposit.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get the key of the deposit of a depositor to a proposal
+pub fn get_deposit_key(id: u64, depositor: &Address) -> Key {
+    get_deposit_prefix_key(id)
+        .push(depositor)
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get the prefix of the keys of the proposals in their deposit phase
+pub fn get_deposit_pending_prefix_key() -> Key {
+    Key::from(ADDRESS.to_db_key())
+        .push(&Keys::VALUES.deposit_pending.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get the key of a proposal in its deposit phase
+pub fn get_deposit_pending_key(id: u64) -> Key {
+    get_deposit_pending_prefix_key()
+        .push(&id.to_string())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get the prefix of the keys of the proposals cancelled by their authors
+pub fn get_cancelled_prefix_key() -> Key {
+    Key::from(ADDRESS.to_db_key())
+        .push(&Keys::VALUES.cancelled.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get the key of a proposal cancelled by its author
+pub fn get_cancelled_key(id: u64) -> Key {
+    get_cancelled_prefix_key()
+        .push(&id.to_string())
+        .expect("Cannot obtain a storage key")
+}
+
 /// Get the proposal execution key
 pub fn get_proposal_execution_key(id: u64) -> Key {
     Key::from(ADDRESS.to_db_key())
@@ -477,6 +799,16 @@ pub fn get_proposal_result_key(id: u64) -> Key {
         .expect("Cannot obtain a storage key")
 }
 
+/// Get the key of the tally parameters and `NoWithVeto` voting power of a
+/// proposal result
+pub fn get_proposal_result_tally_key(id: u64) -> Key {
+    proposal_prefix()
+        .push(&id.to_string())
+        .expect("Cannot obtain a storage key")
+        .push(&Keys::VALUES.result_tally.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
 /// Get proposal id from key
 pub fn get_proposal_id(key: &Key) -> Option<u64> {
     match key.get_at(2) {
@@ -488,6 +820,17 @@ pub fn get_proposal_id(key: &Key) -> Option<u64> {
     }
 }
 
+/// Get the step index from the code key of a step of a batch proposal
+pub fn get_proposal_step_index(key: &Key) -> Option<u64> {
+    match key.get_at(4) {
+        Some(id) => match id {
+            DbKeySeg::AddressSeg(_) => None,
+            DbKeySeg::StringSeg(res) => res.parse::<u64>().ok(),
+        },
+        None => None,
+    }
+}
+
 /// Get the committing epoch from a proposal committing key
 pub fn get_commit_proposal_epoch(key: &Key) -> Option<u64> {
     match key.get_at(3) {
@@ -10,9 +10,11 @@ pub mod vote;
 use std::collections::{BTreeMap, BTreeSet};
 
 use namada_core::address::Address;
-use namada_core::borsh::BorshDeserialize;
+use namada_core::arith::checked;
+use namada_core::borsh::{BorshDeserialize, BorshSerialize};
 use namada_core::chain::Epoch;
 use namada_core::collections::HashSet;
+use namada_core::dec::Dec;
 use namada_core::hash::Hash;
 use namada_core::token;
 use namada_state::{iter_prefix, Error, Result, StorageRead, StorageWrite};
@@ -21,18 +23,24 @@ use namada_systems::trans_token;
 use crate::parameters::GovernanceParameters;
 use crate::storage::keys as governance_keys;
 use crate::storage::proposal::{
-    InitProposalData, ProposalType, StorageProposal, VoteProposalData,
+    AmendProposalData, CancelProposalData, DelegateVotesData,
+    DepositProposalData, InitProposalData, ProposalStepData, ProposalType,
+    StorageProposal, VoteProposalData, WasmProposalStep,
 };
 use crate::storage::vote::ProposalVote;
-use crate::utils::{ProposalResult, Vote};
+use crate::utils::{
+    ProposalResult, TallyParams, TallyResult, TallyType, Vote, VotePower,
+};
 use crate::ADDRESS as governance_address;
 
-/// A proposal creation transaction.
+/// A proposal creation transaction. The `step_codes` are the codes of the
+/// steps of a batch proposal, in execution order.
 pub fn init_proposal<S, TransToken>(
     storage: &mut S,
     data: &InitProposalData,
     content: Vec<u8>,
     code: Option<Vec<u8>>,
+    step_codes: Vec<Vec<u8>>,
 ) -> Result<u64>
 where
     S: StorageRead + StorageWrite,
@@ -62,6 +70,28 @@ where
                 code.ok_or(Error::new_const("Missing proposal code"))?;
             storage.write(&proposal_code_key, proposal_code)?;
         }
+        ProposalType::DefaultWithWasmBatch(ref steps) => {
+            if steps.is_empty() {
+                return Err(Error::new_const(
+                    "A batch proposal must have at least one step",
+                ));
+            }
+            if steps.len() != step_codes.len() {
+                return Err(Error::new_alloc(format!(
+                    "Expected the codes of {} proposal steps, got {}",
+                    steps.len(),
+                    step_codes.len()
+                )));
+            }
+            storage.write(&proposal_type_key, data.r#type.clone())?;
+            for (index, step_code) in (0_u64..).zip(step_codes) {
+                let step_code_key = governance_keys::get_proposal_step_code_key(
+                    proposal_id,
+                    index,
+                );
+                storage.write(&step_code_key, step_code)?;
+            }
+        }
         _ => storage.write(&proposal_type_key, data.r#type.clone())?,
     }
 
@@ -88,8 +118,26 @@ where
     let min_proposal_funds: token::Amount =
         storage.read(&min_proposal_funds_key)?.unwrap();
 
+    // Without an explicit deposit, the author locks the whole minimum fund
+    let deposit = data.deposit.unwrap_or(min_proposal_funds);
+    let min_initial_deposit = get_min_proposal_initial_deposit(storage)?;
+    if deposit < min_initial_deposit {
+        return Err(Error::new_alloc(format!(
+            "The initial deposit of a proposal must be at least \
+             {min_initial_deposit}, got {deposit}"
+        )));
+    }
+
     let funds_key = governance_keys::get_funds_key(proposal_id);
-    storage.write(&funds_key, min_proposal_funds)?;
+    storage.write(&funds_key, deposit)?;
+
+    // The proposal stays in its deposit phase until it is funded with the
+    // minimum proposal fund
+    if deposit < min_proposal_funds {
+        let deposit_pending_key =
+            governance_keys::get_deposit_pending_key(proposal_id);
+        storage.write(&deposit_pending_key, data.voting_start_epoch)?;
+    }
 
     // this key must always be written for each proposal
     let committing_proposals_key =
@@ -104,12 +152,158 @@ where
         &storage.get_native_token()?,
         &data.author,
         &governance_address,
-        min_proposal_funds,
+        deposit,
     )?;
 
     Ok(proposal_id)
 }
 
+/// A proposal deposit transaction. Deposits can be made to a proposal until
+/// its voting start epoch.
+pub fn deposit_proposal<S, TransToken>(
+    storage: &mut S,
+    data: &DepositProposalData,
+) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+    TransToken: trans_token::Write<S>,
+{
+    let DepositProposalData {
+        id,
+        depositor,
+        amount,
+    } = data;
+    if amount.is_zero() {
+        return Err(Error::new_const("A proposal deposit cannot be zero"));
+    }
+
+    let voting_start_epoch_key =
+        governance_keys::get_voting_start_epoch_key(*id);
+    let voting_start_epoch: Epoch =
+        storage.read(&voting_start_epoch_key)?.ok_or_else(|| {
+            Error::new_alloc(format!("Proposal {id} does not exist"))
+        })?;
+    let current_epoch = storage.get_block_epoch()?;
+    if current_epoch >= voting_start_epoch {
+        return Err(Error::new_alloc(format!(
+            "The deposit phase of proposal {id} ended at epoch \
+             {voting_start_epoch}"
+        )));
+    }
+    if is_proposal_cancelled(storage, *id)? {
+        return Err(Error::new_alloc(format!(
+            "Proposal {id} has been cancelled"
+        )));
+    }
+
+    let funds_key = governance_keys::get_funds_key(*id);
+    let funds: token::Amount = storage.read(&funds_key)?.unwrap_or_default();
+    let funds = checked!(funds + *amount)?;
+    storage.write(&funds_key, funds)?;
+
+    let deposit_key = governance_keys::get_deposit_key(*id, depositor);
+    let deposit: token::Amount =
+        storage.read(&deposit_key)?.unwrap_or_default();
+    storage.write(&deposit_key, checked!(deposit + *amount)?)?;
+
+    let min_proposal_funds_key = governance_keys::get_min_proposal_fund_key();
+    let min_proposal_funds: token::Amount =
+        storage.read(&min_proposal_funds_key)?.unwrap();
+    if funds >= min_proposal_funds {
+        storage.delete(&governance_keys::get_deposit_pending_key(*id))?;
+    }
+
+    TransToken::transfer(
+        storage,
+        &storage.get_native_token()?,
+        depositor,
+        &governance_address,
+        *amount,
+    )
+}
+
+/// Check that a proposal can still be modified by its author, i.e. that it
+/// exists, that `author` is its author, that its voting period hasn't started
+/// and that it hasn't been cancelled.
+fn check_proposal_modifiable<S>(
+    storage: &S,
+    proposal_id: u64,
+    author: &Address,
+) -> Result<()>
+where
+    S: StorageRead,
+{
+    let proposal_author = get_proposal_author(storage, proposal_id)?
+        .ok_or_else(|| {
+            Error::new_alloc(format!("Proposal {proposal_id} does not exist"))
+        })?;
+    if &proposal_author != author {
+        return Err(Error::new_alloc(format!(
+            "Proposal {proposal_id} can only be modified by its author \
+             {proposal_author}"
+        )));
+    }
+    let voting_start_epoch_key =
+        governance_keys::get_voting_start_epoch_key(proposal_id);
+    let voting_start_epoch: Epoch =
+        storage.read(&voting_start_epoch_key)?.ok_or_else(|| {
+            Error::new_alloc(format!("Proposal {proposal_id} does not exist"))
+        })?;
+    let current_epoch = storage.get_block_epoch()?;
+    if current_epoch >= voting_start_epoch {
+        return Err(Error::new_alloc(format!(
+            "The voting period of proposal {proposal_id} started at epoch \
+             {voting_start_epoch}"
+        )));
+    }
+    if is_proposal_cancelled(storage, proposal_id)? {
+        return Err(Error::new_alloc(format!(
+            "Proposal {proposal_id} has been cancelled"
+        )));
+    }
+    Ok(())
+}
+
+/// A proposal cancellation transaction. The proposal is marked as cancelled
+/// and it is removed, with its funds partially refunded, at the start of the
+/// next epoch.
+pub fn cancel_proposal<S>(
+    storage: &mut S,
+    data: &CancelProposalData,
+) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    check_proposal_modifiable(storage, data.id, &data.author)?;
+    let current_epoch = storage.get_block_epoch()?;
+    let cancelled_key = governance_keys::get_cancelled_key(data.id);
+    storage.write(&cancelled_key, current_epoch)
+}
+
+/// A proposal amendment transaction, replacing the content of the proposal.
+pub fn amend_proposal<S>(
+    storage: &mut S,
+    data: &AmendProposalData,
+    content: Vec<u8>,
+) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    check_proposal_modifiable(storage, data.id, &data.author)?;
+    let content_key = governance_keys::get_content_key(data.id);
+    // The content should have been already encoded with borsh
+    storage.write_bytes(&content_key, content)
+}
+
+/// Check if a proposal has been cancelled by its author.
+pub fn is_proposal_cancelled<S>(storage: &S, proposal_id: u64) -> Result<bool>
+where
+    S: StorageRead,
+{
+    let cancelled_key = governance_keys::get_cancelled_key(proposal_id);
+    storage.has_key(&cancelled_key)
+}
+
 /// A proposal vote transaction.
 pub fn vote_proposal<S>(
     storage: &mut S,
@@ -127,9 +321,189 @@ where
         );
         storage.write(&vote_key, data.vote.clone())?;
     }
+    // The vote of a representative is also cast on behalf of the
+    // constituents that don't vote themselves
+    if has_constituents(storage, &data.voter)? {
+        let representative_vote_key =
+            governance_keys::get_representative_vote_key(data.id, &data.voter);
+        storage.write(&representative_vote_key, data.vote)?;
+    }
     Ok(())
 }
 
+/// A governance vote delegation transaction. The delegation (or its
+/// revocation, if no representative is given) is effective from the next
+/// epoch.
+pub fn delegate_votes<S>(storage: &mut S, data: DelegateVotesData) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let DelegateVotesData {
+        delegator,
+        representative,
+    } = data;
+    if representative.as_ref() == Some(&delegator) {
+        return Err(Error::new_alloc(format!(
+            "The address {delegator} cannot delegate its votes to itself"
+        )));
+    }
+
+    let current_epoch = storage.get_block_epoch()?;
+    let effective_epoch = current_epoch.next();
+
+    // Update the index of the constituents of the representatives
+    let last_representative =
+        get_representative(storage, &delegator, Epoch(u64::MAX))?;
+    if let Some(last_representative) = last_representative {
+        storage.delete(&governance_keys::get_constituent_key(
+            &last_representative,
+            &delegator,
+        ))?;
+    }
+    if let Some(representative) = &representative {
+        storage.write(
+            &governance_keys::get_constituent_key(representative, &delegator),
+            effective_epoch,
+        )?;
+    }
+
+    // Prune the delegations that are no longer needed to tally votes, i.e.
+    // all but the last one that is effective in the current epoch
+    let prefix = governance_keys::get_delegator_delegate_prefix_key(&delegator);
+    let mut past_keys = vec![];
+    for res in iter_prefix::<Option<Address>>(storage, &prefix)? {
+        let (key, _) = res?;
+        match governance_keys::is_delegate_key(&key) {
+            Some((_, epoch)) if epoch <= current_epoch => past_keys.push(key),
+            _ => {}
+        }
+    }
+    past_keys.pop();
+    for key in past_keys {
+        storage.delete(&key)?;
+    }
+
+    storage.write(
+        &governance_keys::get_delegate_key(&delegator, effective_epoch),
+        representative,
+    )
+}
+
+/// Read the representative of a delegator that is effective in the given
+/// epoch, if any.
+pub fn get_representative<S>(
+    storage: &S,
+    delegator: &Address,
+    epoch: Epoch,
+) -> Result<Option<Address>>
+where
+    S: StorageRead,
+{
+    let prefix = governance_keys::get_delegator_delegate_prefix_key(delegator);
+    let mut representative = None;
+    for res in iter_prefix::<Option<Address>>(storage, &prefix)? {
+        let (key, value) = res?;
+        match governance_keys::is_delegate_key(&key) {
+            Some((_, key_epoch)) if key_epoch <= epoch => {
+                representative = value;
+            }
+            _ => break,
+        }
+    }
+    Ok(representative)
+}
+
+/// Read all the delegators with the representatives that are effective in the
+/// given epoch.
+pub fn get_delegations<S>(
+    storage: &S,
+    epoch: Epoch,
+) -> Result<BTreeMap<Address, Address>>
+where
+    S: StorageRead,
+{
+    let prefix = governance_keys::get_delegate_prefix_key();
+    let mut delegations = BTreeMap::new();
+    // The keys are ordered by delegator and then by epoch, so the last
+    // effective delegation of a delegator overrides the previous ones
+    for res in iter_prefix::<Option<Address>>(storage, &prefix)? {
+        let (key, representative) = res?;
+        let Some((delegator, key_epoch)) =
+            governance_keys::is_delegate_key(&key)
+        else {
+            continue;
+        };
+        if key_epoch > epoch {
+            continue;
+        }
+        match representative {
+            Some(representative) => {
+                delegations.insert(delegator.clone(), representative);
+            }
+            None => {
+                delegations.remove(delegator);
+            }
+        }
+    }
+    Ok(delegations)
+}
+
+/// Read the constituents of a representative, including the ones whose
+/// delegation is not yet effective.
+pub fn get_constituents<S>(
+    storage: &S,
+    representative: &Address,
+) -> Result<BTreeSet<Address>>
+where
+    S: StorageRead,
+{
+    let prefix = governance_keys::get_constituents_prefix_key(representative);
+    iter_prefix::<Epoch>(storage, &prefix)?
+        .map(|res| {
+            let (key, _) = res?;
+            governance_keys::is_constituent_key(&key)
+                .map(|(_, constituent)| constituent.clone())
+                .ok_or_else(|| {
+                    Error::new_alloc(format!("Invalid constituent key {key}"))
+                })
+        })
+        .collect()
+}
+
+/// Check if an address has any constituents.
+pub fn has_constituents<S>(
+    storage: &S,
+    representative: &Address,
+) -> Result<bool>
+where
+    S: StorageRead,
+{
+    let prefix = governance_keys::get_constituents_prefix_key(representative);
+    Ok(iter_prefix::<Epoch>(storage, &prefix)?.next().is_some())
+}
+
+/// The part of a [`ProposalResult`] stored under the proposal result key.
+/// Its layout is left unchanged, such that the results of the proposals
+/// tallied before the tally parameters and the `NoWithVeto` votes were
+/// introduced remain readable.
+#[derive(BorshSerialize, BorshDeserialize)]
+struct StoredProposalResult {
+    result: TallyResult,
+    tally_type: TallyType,
+    total_voting_power: VotePower,
+    total_yay_power: VotePower,
+    total_nay_power: VotePower,
+    total_abstain_power: VotePower,
+}
+
+/// The part of a [`ProposalResult`] stored under its own key, missing from
+/// older results
+#[derive(BorshSerialize, BorshDeserialize)]
+struct StoredProposalTally {
+    tally_params: TallyParams,
+    total_veto_power: VotePower,
+}
+
 /// Write the proposal result to storage.
 pub fn write_proposal_result<S>(
     storage: &mut S,
@@ -139,9 +513,38 @@ pub fn write_proposal_result<S>(
 where
     S: StorageRead + StorageWrite,
 {
+    let ProposalResult {
+        result,
+        tally_type,
+        tally_params,
+        total_voting_power,
+        total_yay_power,
+        total_nay_power,
+        total_abstain_power,
+        total_veto_power,
+    } = proposal_result;
     let proposal_result_key =
         governance_keys::get_proposal_result_key(proposal_id);
-    storage.write(&proposal_result_key, proposal_result)
+    storage.write(
+        &proposal_result_key,
+        StoredProposalResult {
+            result,
+            tally_type,
+            total_voting_power,
+            total_yay_power,
+            total_nay_power,
+            total_abstain_power,
+        },
+    )?;
+    let proposal_tally_key =
+        governance_keys::get_proposal_result_tally_key(proposal_id);
+    storage.write(
+        &proposal_tally_key,
+        StoredProposalTally {
+            tally_params,
+            total_veto_power,
+        },
+    )
 }
 
 /// Read a proposal by id from storage
@@ -175,6 +578,19 @@ where
                 storage.read(&proposal_code_key)?.unwrap_or_default();
             let proposal_code_hash = Hash::sha256(proposal_code);
             ProposalType::DefaultWithWasm(proposal_code_hash)
+        } else if let ProposalType::DefaultWithWasmBatch(steps) = proposal_type
+        {
+            let step_codes = get_proposal_step_codes(storage, id)?;
+            ProposalType::DefaultWithWasmBatch(
+                steps
+                    .into_iter()
+                    .zip(step_codes)
+                    .map(|(step, code)| WasmProposalStep {
+                        code: Hash::sha256(code),
+                        data: step.data,
+                    })
+                    .collect(),
+            )
         } else {
             proposal_type
         }
@@ -228,12 +644,16 @@ where
     Ok(votes)
 }
 
-/// Check if an accepted proposal is being executed
+/// Check if an accepted proposal is being executed. The tx data is either
+/// the id of the proposal or the [`ProposalStepData`] of a step of a batch
+/// proposal.
 pub fn is_proposal_accepted<S>(storage: &S, tx_data: &[u8]) -> Result<bool>
 where
     S: StorageRead,
 {
-    let proposal_id = u64::try_from_slice(tx_data);
+    let proposal_id = u64::try_from_slice(tx_data).or_else(|_| {
+        ProposalStepData::try_from_slice(tx_data).map(|step| step.id)
+    });
     if let Ok(id) = proposal_id {
         let proposal_execution_key =
             governance_keys::get_proposal_execution_key(id);
@@ -255,6 +675,34 @@ where
     storage.read(&proposal_code_key)
 }
 
+/// Get the codes of the steps of a batch proposal, in execution order
+pub fn get_proposal_step_codes<S>(
+    storage: &S,
+    proposal_id: u64,
+) -> Result<Vec<Vec<u8>>>
+where
+    S: StorageRead,
+{
+    let proposal_type_key = governance_keys::get_proposal_type_key(proposal_id);
+    let Some(ProposalType::DefaultWithWasmBatch(steps)) =
+        storage.read(&proposal_type_key)?
+    else {
+        return Ok(vec![]);
+    };
+    (0..steps.len() as u64)
+        .map(|index| {
+            let step_code_key =
+                governance_keys::get_proposal_step_code_key(proposal_id, index);
+            storage.read(&step_code_key)?.ok_or_else(|| {
+                Error::new_alloc(format!(
+                    "Missing the code of step {index} of proposal \
+                     {proposal_id}"
+                ))
+            })
+        })
+        .collect()
+}
+
 /// Get the code associated with a proposal
 pub fn get_proposal_author<S>(
     storage: &S,
@@ -284,6 +732,17 @@ where
     let min_proposal_fund: token::Amount =
         storage.read(&key)?.expect("Parameter should be defined.");
 
+    let min_proposal_initial_deposit =
+        get_min_proposal_initial_deposit(storage)?;
+
+    let key = governance_keys::get_proposal_cancellation_refund_key();
+    // The cancellation refund may be missing from the storage of chains that
+    // were initialized before proposals could be cancelled
+    let proposal_cancellation_refund: Dec =
+        storage.read(&key)?.unwrap_or_else(|| {
+            GovernanceParameters::default().proposal_cancellation_refund
+        });
+
     let key = governance_keys::get_min_proposal_grace_epochs_key();
     let min_proposal_grace_epochs: u64 =
         storage.read(&key)?.expect("Parameter should be defined.");
@@ -298,17 +757,73 @@ where
     let max_proposal_latency: u64 =
         storage.read(&key)?.expect("Parameter should be defined.");
 
+    let key = governance_keys::get_default_tally_params_key();
+    // The tally parameters may be missing from the storage of chains that
+    // were initialized before they were added, so they fall back to the
+    // defaults of their tally type
+    let default_tally_params: TallyParams = storage
+        .read(&key)?
+        .unwrap_or_else(|| TallyType::TwoFifths.default_params());
+
+    let key = governance_keys::get_default_with_wasm_tally_params_key();
+    let default_with_wasm_tally_params: TallyParams = storage
+        .read(&key)?
+        .unwrap_or_else(|| TallyType::TwoFifths.default_params());
+
+    let key = governance_keys::get_pgf_steward_tally_params_key();
+    let pgf_steward_tally_params: TallyParams = storage
+        .read(&key)?
+        .unwrap_or_else(|| TallyType::OneHalfOverOneThird.default_params());
+
+    let key = governance_keys::get_pgf_payment_tally_params_key();
+    let pgf_payment_tally_params: TallyParams = storage
+        .read(&key)?
+        .unwrap_or_else(|| TallyType::OneHalfOverOneThird.default_params());
+
+    let key = governance_keys::get_pgf_steward_payment_tally_params_key();
+    let pgf_steward_payment_tally_params: TallyParams =
+        storage.read(&key)?.unwrap_or_else(|| {
+            TallyType::LessOneHalfOverOneThirdNay.default_params()
+        });
+
     Ok(GovernanceParameters {
         min_proposal_fund,
+        min_proposal_initial_deposit,
+        proposal_cancellation_refund,
         max_proposal_code_size,
         min_proposal_voting_period,
         max_proposal_period,
         max_proposal_content_size,
         min_proposal_grace_epochs,
         max_proposal_latency,
+        default_tally_params,
+        default_with_wasm_tally_params,
+        pgf_steward_tally_params,
+        pgf_payment_tally_params,
+        pgf_steward_payment_tally_params,
     })
 }
 
+/// Get governance "min_proposal_initial_deposit" parameter. On chains that
+/// were initialized before the deposit phase of proposals was added, the
+/// parameter is missing and the whole "min_proposal_fund" has to be deposited
+/// by the author, as before.
+pub fn get_min_proposal_initial_deposit<S>(storage: &S) -> Result<token::Amount>
+where
+    S: StorageRead,
+{
+    let key = governance_keys::get_min_proposal_initial_deposit_key();
+    match storage.read(&key)? {
+        Some(min_proposal_initial_deposit) => Ok(min_proposal_initial_deposit),
+        None => {
+            let key = governance_keys::get_min_proposal_fund_key();
+            let min_proposal_fund: token::Amount =
+                storage.read(&key)?.expect("Parameter should be defined.");
+            Ok(min_proposal_fund)
+        }
+    }
+}
+
 /// Get governance "max_proposal_period" parameter
 pub fn get_max_proposal_period<S>(storage: &S) -> Result<u64>
 where
@@ -329,8 +844,137 @@ where
     S: StorageRead,
 {
     let key = governance_keys::get_proposal_result_key(proposal_id);
-    let proposal_result: Option<ProposalResult> = storage.read(&key)?;
-    Ok(proposal_result)
+    let Some(StoredProposalResult {
+        result,
+        tally_type,
+        total_voting_power,
+        total_yay_power,
+        total_nay_power,
+        total_abstain_power,
+    }) = storage.read(&key)?
+    else {
+        return Ok(None);
+    };
+    // Older results were tallied with the default parameters of their
+    // tally type, without `NoWithVeto` votes
+    let key = governance_keys::get_proposal_result_tally_key(proposal_id);
+    let StoredProposalTally {
+        tally_params,
+        total_veto_power,
+    } = storage.read(&key)?.unwrap_or_else(|| StoredProposalTally {
+        tally_params: tally_type.default_params(),
+        total_veto_power: VotePower::zero(),
+    });
+    Ok(Some(ProposalResult {
+        result,
+        tally_type,
+        tally_params,
+        total_voting_power,
+        total_yay_power,
+        total_nay_power,
+        total_abstain_power,
+        total_veto_power,
+    }))
+}
+
+/// Read the deposits locked in a proposal by each depositor. The deposit of
+/// the author is the part of the proposal funds that was not deposited by
+/// others.
+pub fn get_proposal_deposits<S>(
+    storage: &S,
+    proposal_id: u64,
+) -> Result<BTreeMap<Address, token::Amount>>
+where
+    S: StorageRead,
+{
+    let funds_key = governance_keys::get_funds_key(proposal_id);
+    let funds: token::Amount = storage.read(&funds_key)?.unwrap_or_default();
+
+    let mut deposits = BTreeMap::new();
+    let mut deposited = token::Amount::zero();
+    let prefix = governance_keys::get_deposit_prefix_key(proposal_id);
+    for res in iter_prefix::<token::Amount>(storage, &prefix)? {
+        let (key, amount) = res?;
+        let depositor =
+            governance_keys::is_deposit_key(&key).ok_or_else(|| {
+                Error::new_alloc(format!("Invalid deposit key {key}"))
+            })?;
+        deposited = checked!(deposited + amount)?;
+        deposits.insert(depositor.clone(), amount);
+    }
+
+    if let Some(author) = get_proposal_author(storage, proposal_id)? {
+        let author_deposit = checked!(funds - deposited)?;
+        let deposit = deposits.get(&author).copied().unwrap_or_default();
+        deposits.insert(author, checked!(deposit + author_deposit)?);
+    }
+    Ok(deposits)
+}
+
+/// Load the proposals that are still in their deposit phase, with their
+/// voting start epochs.
+pub fn load_pending_deposit_proposals<S>(
+    storage: &S,
+) -> Result<BTreeMap<u64, Epoch>>
+where
+    S: StorageRead,
+{
+    let prefix = governance_keys::get_deposit_pending_prefix_key();
+    iter_prefix::<Epoch>(storage, &prefix)?
+        .map(|res| {
+            let (key, voting_start_epoch) = res?;
+            governance_keys::get_proposal_id(&key)
+                .map(|id| (id, voting_start_epoch))
+                .ok_or_else(|| {
+                    Error::new_alloc(format!(
+                        "Invalid pending deposit key {key}"
+                    ))
+                })
+        })
+        .collect()
+}
+
+/// Load the ids of the proposals cancelled by their authors.
+pub fn load_cancelled_proposals<S>(storage: &S) -> Result<BTreeSet<u64>>
+where
+    S: StorageRead,
+{
+    let prefix = governance_keys::get_cancelled_prefix_key();
+    iter_prefix::<Epoch>(storage, &prefix)?
+        .map(|res| {
+            let (key, _cancellation_epoch) = res?;
+            governance_keys::get_proposal_id(&key).ok_or_else(|| {
+                Error::new_alloc(format!(
+                    "Invalid cancelled proposal key {key}"
+                ))
+            })
+        })
+        .collect()
+}
+
+/// Remove a proposal that didn't reach the minimum proposal fund or that was
+/// cancelled from storage. The deposits must have been refunded beforehand.
+pub fn remove_proposal<S>(storage: &mut S, proposal_id: u64) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let activation_epoch_key =
+        governance_keys::get_activation_epoch_key(proposal_id);
+    if let Some(activation_epoch) =
+        storage.read::<Epoch>(&activation_epoch_key)?
+    {
+        storage.delete(&governance_keys::get_committing_proposals_key(
+            proposal_id,
+            activation_epoch.0,
+        ))?;
+    }
+    storage.delete(&governance_keys::get_deposit_pending_key(proposal_id))?;
+    storage.delete(&governance_keys::get_cancelled_key(proposal_id))?;
+
+    let prefix = governance_keys::proposal_prefix()
+        .push(&proposal_id.to_string())
+        .expect("Cannot obtain a storage key");
+    storage.delete_prefix(&prefix)
 }
 
 /// Load proposals for execution in the current epoch.
@@ -357,3 +1001,574 @@ where
 
     Ok(ids)
 }
+
+#[cfg(test)]
+mod test_delegation {
+    use namada_core::address::testing::{
+        established_address_1, established_address_2, established_address_3,
+    };
+    use namada_state::testing::TestState;
+
+    use super::*;
+
+    fn delegate(
+        storage: &mut TestState,
+        delegator: &Address,
+        representative: Option<&Address>,
+    ) -> Result<()> {
+        delegate_votes(
+            storage,
+            DelegateVotesData {
+                delegator: delegator.clone(),
+                representative: representative.cloned(),
+            },
+        )
+    }
+
+    #[test]
+    fn test_delegate_votes() {
+        let mut storage = TestState::default();
+        let delegator = established_address_1();
+        let alice = established_address_2();
+        let bob = established_address_3();
+
+        // self-delegation is rejected
+        assert!(delegate(&mut storage, &delegator, Some(&delegator)).is_err());
+
+        // a delegation is effective from the next epoch
+        storage.in_mem_mut().block.epoch = Epoch(2);
+        delegate(&mut storage, &delegator, Some(&alice)).unwrap();
+        let representative = |storage: &TestState, epoch| {
+            get_representative(storage, &delegator, Epoch(epoch)).unwrap()
+        };
+        assert_eq!(representative(&storage, 2), None);
+        assert_eq!(representative(&storage, 3), Some(alice.clone()));
+        assert_eq!(
+            get_constituents(&storage, &alice).unwrap(),
+            BTreeSet::from([delegator.clone()])
+        );
+
+        // a change of representative overrides the pending one
+        delegate(&mut storage, &delegator, Some(&bob)).unwrap();
+        assert_eq!(representative(&storage, 3), Some(bob.clone()));
+        assert!(!has_constituents(&storage, &alice).unwrap());
+        assert!(has_constituents(&storage, &bob).unwrap());
+
+        // a revocation keeps the current representative until the next epoch
+        storage.in_mem_mut().block.epoch = Epoch(5);
+        delegate(&mut storage, &delegator, None).unwrap();
+        assert_eq!(representative(&storage, 5), Some(bob.clone()));
+        assert_eq!(representative(&storage, 6), None);
+        assert!(!has_constituents(&storage, &bob).unwrap());
+        assert_eq!(
+            get_delegations(&storage, Epoch(5)).unwrap(),
+            BTreeMap::from([(delegator.clone(), bob.clone())])
+        );
+        assert!(get_delegations(&storage, Epoch(6)).unwrap().is_empty());
+
+        // the delegations that are no longer needed are pruned
+        storage.in_mem_mut().block.epoch = Epoch(7);
+        delegate(&mut storage, &delegator, Some(&alice)).unwrap();
+        let prefix =
+            governance_keys::get_delegator_delegate_prefix_key(&delegator);
+        let epochs: Vec<Epoch> =
+            iter_prefix::<Option<Address>>(&storage, &prefix)
+                .unwrap()
+                .map(|res| {
+                    let (key, _) = res.unwrap();
+                    governance_keys::is_delegate_key(&key).unwrap().1
+                })
+                .collect();
+        assert_eq!(epochs, vec![Epoch(6), Epoch(8)]);
+        assert_eq!(representative(&storage, 7), None);
+        assert_eq!(representative(&storage, 8), Some(alice));
+    }
+}
+
+#[cfg(test)]
+mod test_deposit {
+    use namada_core::address::testing::{
+        established_address_1, established_address_2,
+    };
+    use namada_state::testing::TestState;
+
+    use super::*;
+
+    type TransToken = namada_token::Store<TestState>;
+
+    fn init_proposal_with_deposit(
+        storage: &mut TestState,
+        author: &Address,
+        deposit: Option<token::Amount>,
+    ) -> Result<u64> {
+        init_proposal::<_, TransToken>(
+            storage,
+            &InitProposalData {
+                content: Hash::default(),
+                author: author.clone(),
+                r#type: ProposalType::Default,
+                voting_start_epoch: Epoch(3),
+                voting_end_epoch: Epoch(9),
+                activation_epoch: Epoch(12),
+                deposit,
+            },
+            vec![],
+            None,
+            vec![],
+        )
+    }
+
+    fn deposit(
+        storage: &mut TestState,
+        id: u64,
+        depositor: &Address,
+        amount: u64,
+    ) -> Result<()> {
+        deposit_proposal::<_, TransToken>(
+            storage,
+            &DepositProposalData {
+                id,
+                depositor: depositor.clone(),
+                amount: token::Amount::native_whole(amount),
+            },
+        )
+    }
+
+    #[test]
+    fn test_deposit_proposal() {
+        let mut storage = TestState::default();
+        GovernanceParameters::default()
+            .init_storage(&mut storage)
+            .unwrap();
+        let native_token = storage.get_native_token().unwrap();
+        let author = established_address_1();
+        let depositor = established_address_2();
+        for owner in [&author, &depositor] {
+            namada_token::credit_tokens(
+                &mut storage,
+                &native_token,
+                owner,
+                token::Amount::native_whole(1000),
+            )
+            .unwrap();
+        }
+
+        // the initial deposit must be at least the minimum initial deposit
+        let res = init_proposal_with_deposit(
+            &mut storage,
+            &author,
+            Some(token::Amount::native_whole(99)),
+        );
+        assert!(res.is_err());
+
+        let id = init_proposal_with_deposit(
+            &mut storage,
+            &author,
+            Some(token::Amount::native_whole(100)),
+        )
+        .unwrap();
+        assert_eq!(
+            load_pending_deposit_proposals(&storage).unwrap(),
+            BTreeMap::from([(id, Epoch(3))])
+        );
+
+        // top-ups are accounted to their depositors
+        deposit(&mut storage, id, &depositor, 150).unwrap();
+        deposit(&mut storage, id, &author, 50).unwrap();
+        assert_eq!(
+            get_proposal_deposits(&storage, id).unwrap(),
+            BTreeMap::from([
+                (author.clone(), token::Amount::native_whole(150)),
+                (depositor.clone(), token::Amount::native_whole(150)),
+            ])
+        );
+        assert!(!load_pending_deposit_proposals(&storage).unwrap().is_empty());
+
+        // the proposal leaves its deposit phase once the minimum fund is
+        // reached
+        deposit(&mut storage, id, &depositor, 200).unwrap();
+        assert!(load_pending_deposit_proposals(&storage).unwrap().is_empty());
+        let funds: token::Amount = storage
+            .read(&governance_keys::get_funds_key(id))
+            .unwrap()
+            .unwrap();
+        assert_eq!(funds, token::Amount::native_whole(500));
+
+        // deposits are rejected from the voting start epoch and for unknown
+        // proposals
+        assert!(deposit(&mut storage, id + 1, &depositor, 1).is_err());
+        storage.in_mem_mut().block.epoch = Epoch(3);
+        assert!(deposit(&mut storage, id, &depositor, 1).is_err());
+
+        // a removed proposal leaves no trace in storage
+        remove_proposal(&mut storage, id).unwrap();
+        assert!(get_proposal_by_id(&storage, id).unwrap().is_none());
+        assert!(get_proposal_deposits(&storage, id).unwrap().is_empty());
+        assert!(load_proposals(&storage, Epoch(12)).unwrap().is_empty());
+    }
+
+    /// Test the parameters of a chain that was initialized before the
+    /// deposit phase of proposals was added
+    #[test]
+    fn test_deposit_params_fallback() {
+        let mut storage = TestState::default();
+        let params = GovernanceParameters::default();
+        params.init_storage(&mut storage).unwrap();
+        storage
+            .delete(&governance_keys::get_min_proposal_initial_deposit_key())
+            .unwrap();
+        storage
+            .delete(&governance_keys::get_proposal_cancellation_refund_key())
+            .unwrap();
+
+        let read_params = get_parameters(&storage).unwrap();
+        assert_eq!(
+            read_params.min_proposal_initial_deposit,
+            params.min_proposal_fund
+        );
+        assert_eq!(
+            read_params.proposal_cancellation_refund,
+            params.proposal_cancellation_refund
+        );
+
+        // the author must deposit the whole minimum fund
+        let native_token = storage.get_native_token().unwrap();
+        let author = established_address_1();
+        namada_token::credit_tokens(
+            &mut storage,
+            &native_token,
+            &author,
+            token::Amount::native_whole(1000),
+        )
+        .unwrap();
+        let res = init_proposal_with_deposit(
+            &mut storage,
+            &author,
+            Some(token::Amount::native_whole(100)),
+        );
+        assert!(res.is_err());
+        let id =
+            init_proposal_with_deposit(&mut storage, &author, None).unwrap();
+        assert!(load_pending_deposit_proposals(&storage).unwrap().is_empty());
+        assert!(get_proposal_by_id(&storage, id).unwrap().is_some());
+    }
+}
+
+#[cfg(test)]
+mod test_proposal_result {
+    use namada_state::testing::TestState;
+
+    use super::*;
+
+    /// Test that proposal results round-trip through storage, and that the
+    /// results stored before the tally parameters and the `NoWithVeto`
+    /// votes were introduced are still readable
+    #[test]
+    fn test_proposal_result_storage() {
+        let mut storage = TestState::default();
+        let tally_params = TallyParams {
+            quorum: Dec::one_third(),
+            threshold: Dec::two_thirds(),
+            veto_threshold: Dec::new(4, 1).unwrap(),
+        };
+        write_proposal_result(
+            &mut storage,
+            0,
+            ProposalResult {
+                result: TallyResult::Rejected,
+                tally_type: TallyType::TwoFifths,
+                tally_params,
+                total_voting_power: VotePower::from(100),
+                total_yay_power: VotePower::from(40),
+                total_nay_power: VotePower::from(10),
+                total_abstain_power: VotePower::from(5),
+                total_veto_power: VotePower::from(40),
+            },
+        )
+        .unwrap();
+        let result = get_proposal_result(&storage, 0).unwrap().unwrap();
+        assert!(matches!(result.result, TallyResult::Rejected));
+        assert_eq!(result.tally_params, tally_params);
+        assert_eq!(result.total_yay_power, VotePower::from(40));
+        assert_eq!(result.total_veto_power, VotePower::from(40));
+        assert!(result.is_vetoed());
+
+        // a result written with the layout preceding the tally parameters
+        // and the `NoWithVeto` votes
+        let legacy = (
+            TallyResult::Passed,
+            TallyType::OneHalfOverOneThird,
+            VotePower::from(100),
+            VotePower::from(60),
+            VotePower::from(10),
+            VotePower::from(5),
+        );
+        storage
+            .write(&governance_keys::get_proposal_result_key(1), legacy)
+            .unwrap();
+        let result = get_proposal_result(&storage, 1).unwrap().unwrap();
+        assert!(matches!(result.result, TallyResult::Passed));
+        assert_eq!(
+            result.tally_params,
+            TallyType::OneHalfOverOneThird.default_params()
+        );
+        assert_eq!(result.total_voting_power, VotePower::from(100));
+        assert_eq!(result.total_yay_power, VotePower::from(60));
+        assert_eq!(result.total_nay_power, VotePower::from(10));
+        assert_eq!(result.total_abstain_power, VotePower::from(5));
+        assert!(result.total_veto_power.is_zero());
+
+        assert!(get_proposal_result(&storage, 2).unwrap().is_none());
+    }
+}
+
+#[cfg(test)]
+mod test_cancellation {
+    use namada_core::address::testing::{
+        established_address_1, established_address_2,
+    };
+    use namada_state::testing::TestState;
+
+    use super::*;
+
+    type TransToken = namada_token::Store<TestState>;
+
+    #[test]
+    fn test_cancel_and_amend_proposal() {
+        let mut storage = TestState::default();
+        GovernanceParameters::default()
+            .init_storage(&mut storage)
+            .unwrap();
+        let native_token = storage.get_native_token().unwrap();
+        let author = established_address_1();
+        let other = established_address_2();
+        namada_token::credit_tokens(
+            &mut storage,
+            &native_token,
+            &author,
+            token::Amount::native_whole(1000),
+        )
+        .unwrap();
+        let id = init_proposal::<_, TransToken>(
+            &mut storage,
+            &InitProposalData {
+                content: Hash::default(),
+                author: author.clone(),
+                r#type: ProposalType::Default,
+                voting_start_epoch: Epoch(3),
+                voting_end_epoch: Epoch(9),
+                activation_epoch: Epoch(12),
+                deposit: None,
+            },
+            vec![1],
+            None,
+            vec![],
+        )
+        .unwrap();
+        let content_key = governance_keys::get_content_key(id);
+        let amend = |storage: &mut TestState, author: &Address| {
+            amend_proposal(
+                storage,
+                &AmendProposalData {
+                    id,
+                    author: author.clone(),
+                    content: Hash::default(),
+                },
+                vec![2],
+            )
+        };
+        let cancel = |storage: &mut TestState, author: &Address| {
+            cancel_proposal(
+                storage,
+                &CancelProposalData {
+                    id,
+                    author: author.clone(),
+                },
+            )
+        };
+
+        // only the author can amend the content
+        assert!(amend(&mut storage, &other).is_err());
+        amend(&mut storage, &author).unwrap();
+        assert_eq!(storage.read_bytes(&content_key).unwrap(), Some(vec![2]));
+
+        // only the author can cancel the proposal
+        assert!(cancel(&mut storage, &other).is_err());
+        assert!(!is_proposal_cancelled(&storage, id).unwrap());
+        cancel(&mut storage, &author).unwrap();
+        assert!(is_proposal_cancelled(&storage, id).unwrap());
+        assert_eq!(
+            load_cancelled_proposals(&storage).unwrap(),
+            BTreeSet::from([id])
+        );
+
+        // a cancelled proposal cannot be modified anymore
+        assert!(cancel(&mut storage, &author).is_err());
+        assert!(amend(&mut storage, &author).is_err());
+        let res = deposit_proposal::<_, TransToken>(
+            &mut storage,
+            &DepositProposalData {
+                id,
+                depositor: author.clone(),
+                amount: token::Amount::native_whole(1),
+            },
+        );
+        assert!(res.is_err());
+
+        remove_proposal(&mut storage, id).unwrap();
+        assert!(load_cancelled_proposals(&storage).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_cannot_modify_started_proposal() {
+        let mut storage = TestState::default();
+        GovernanceParameters::default()
+            .init_storage(&mut storage)
+            .unwrap();
+        let native_token = storage.get_native_token().unwrap();
+        let author = established_address_1();
+        namada_token::credit_tokens(
+            &mut storage,
+            &native_token,
+            &author,
+            token::Amount::native_whole(1000),
+        )
+        .unwrap();
+        let id = init_proposal::<_, TransToken>(
+            &mut storage,
+            &InitProposalData {
+                content: Hash::default(),
+                author: author.clone(),
+                r#type: ProposalType::Default,
+                voting_start_epoch: Epoch(3),
+                voting_end_epoch: Epoch(9),
+                activation_epoch: Epoch(12),
+                deposit: None,
+            },
+            vec![1],
+            None,
+            vec![],
+        )
+        .unwrap();
+
+        storage.in_mem_mut().block.epoch = Epoch(3);
+        let res = cancel_proposal(
+            &mut storage,
+            &CancelProposalData {
+                id,
+                author: author.clone(),
+            },
+        );
+        assert!(res.is_err());
+        let res = amend_proposal(
+            &mut storage,
+            &AmendProposalData {
+                id,
+                author,
+                content: Hash::default(),
+            },
+            vec![2],
+        );
+        assert!(res.is_err());
+    }
+}
+
+#[cfg(test)]
+mod test_batch_proposal {
+    use namada_core::address::testing::established_address_1;
+    use namada_core::borsh::BorshSerializeExt;
+    use namada_state::testing::TestState;
+
+    use super::*;
+
+    type TransToken = namada_token::Store<TestState>;
+
+    #[test]
+    fn test_init_batch_proposal() {
+        let mut storage = TestState::default();
+        GovernanceParameters::default()
+            .init_storage(&mut storage)
+            .unwrap();
+        let native_token = storage.get_native_token().unwrap();
+        let author = established_address_1();
+        namada_token::credit_tokens(
+            &mut storage,
+            &native_token,
+            &author,
+            token::Amount::native_whole(1000),
+        )
+        .unwrap();
+        let steps = vec![
+            WasmProposalStep {
+                code: Hash::default(),
+                data: vec![1],
+            },
+            WasmProposalStep {
+                code: Hash::default(),
+                data: vec![2],
+            },
+        ];
+        let data = InitProposalData {
+            content: Hash::default(),
+            author: author.clone(),
+            r#type: ProposalType::DefaultWithWasmBatch(steps),
+            voting_start_epoch: Epoch(3),
+            voting_end_epoch: Epoch(9),
+            activation_epoch: Epoch(12),
+            deposit: None,
+        };
+        let content = BTreeMap::<String, String>::new().serialize_to_vec();
+
+        // the code of every step is required
+        let res = init_proposal::<_, TransToken>(
+            &mut storage,
+            &data,
+            content.clone(),
+            None,
+            vec![vec![3]],
+        );
+        assert!(res.is_err());
+
+        let id = init_proposal::<_, TransToken>(
+            &mut storage,
+            &data,
+            content,
+            None,
+            vec![vec![3], vec![4]],
+        )
+        .unwrap();
+        assert_eq!(
+            get_proposal_step_codes(&storage, id).unwrap(),
+            vec![vec![3], vec![4]]
+        );
+
+        // the steps of a stored proposal refer to the hashes of their codes
+        let proposal = get_proposal_by_id(&storage, id).unwrap().unwrap();
+        assert_eq!(
+            proposal.r#type,
+            ProposalType::DefaultWithWasmBatch(vec![
+                WasmProposalStep {
+                    code: Hash::sha256([3]),
+                    data: vec![1],
+                },
+                WasmProposalStep {
+                    code: Hash::sha256([4]),
+                    data: vec![2],
+                },
+            ])
+        );
+
+        // the steps of an accepted proposal are executed with its id
+        let step_data = ProposalStepData {
+            id,
+            step: 1,
+            data: vec![2],
+        }
+        .serialize_to_vec();
+        assert!(!is_proposal_accepted(&storage, &step_data).unwrap());
+        storage
+            .write(&governance_keys::get_proposal_execution_key(id), ())
+            .unwrap();
+        assert!(is_proposal_accepted(&storage, &step_data).unwrap());
+    }
+}
@@ -54,6 +54,8 @@ pub struct InitProposalData {
     pub voting_end_epoch: Epoch,
     /// The epoch in which any changes are executed and become active
     pub activation_epoch: Epoch,
+    /// The amount locked by the author, the minimum proposal fund if `None`
+    pub deposit: Option<token::Amount>,
 }
 
 impl InitProposalData {
@@ -64,6 +66,17 @@ impl InitProposalData {
             _ => None,
         }
     }
+
+    /// Get the hashes of the extra data sections holding the codes of the
+    /// steps of a batch proposal, in execution order
+    pub fn get_section_step_code_hashes(&self) -> Vec<Hash> {
+        match &self.r#type {
+            ProposalType::DefaultWithWasmBatch(steps) => {
+                steps.iter().map(|step| step.code).collect()
+            }
+            _ => vec![],
+        }
+    }
 }
 
 /// A tx data type to hold vote proposal data
@@ -88,6 +101,93 @@ pub struct VoteProposalData {
     pub voter: Address,
 }
 
+/// A tx data type to top up the deposit of a proposal
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSchema,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+)]
+pub struct DepositProposalData {
+    /// The proposal id
+    pub id: u64,
+    /// The address topping up the deposit
+    pub depositor: Address,
+    /// The amount added to the deposit
+    pub amount: token::Amount,
+}
+
+/// A tx data type to cancel a proposal before its voting start epoch
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSchema,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+)]
+pub struct CancelProposalData {
+    /// The proposal id
+    pub id: u64,
+    /// The proposal author address
+    pub author: Address,
+}
+
+/// A tx data type to replace the content of a proposal before its voting
+/// start epoch
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSchema,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+)]
+pub struct AmendProposalData {
+    /// The proposal id
+    pub id: u64,
+    /// The proposal author address
+    pub author: Address,
+    /// The hash of the new proposal content
+    pub content: Hash,
+}
+
+/// A tx data type to delegate the voting power of an address to a
+/// representative, or to revoke the delegation
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSchema,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+)]
+pub struct DelegateVotesData {
+    /// The address delegating its voting power
+    pub delegator: Address,
+    /// The representative voting on behalf of the delegator, or `None` to
+    /// revoke the delegation
+    pub representative: Option<Address>,
+}
+
 impl TryFrom<DefaultProposal> for InitProposalData {
     type Error = ProposalError;
 
@@ -103,11 +203,24 @@ impl TryFrom<DefaultProposal> for InitProposalData {
                         ProposalType::DefaultWithWasm(Hash::default())
                     }
                 }
+                None if !value.steps.is_empty() => {
+                    ProposalType::DefaultWithWasmBatch(
+                        value
+                            .steps
+                            .into_iter()
+                            .map(|step| WasmProposalStep {
+                                code: Hash::default(),
+                                data: step.data,
+                            })
+                            .collect(),
+                    )
+                }
                 None => ProposalType::Default,
             },
             voting_start_epoch: value.proposal.voting_start_epoch,
             voting_end_epoch: value.proposal.voting_end_epoch,
             activation_epoch: value.proposal.activation_epoch,
+            deposit: value.proposal.deposit,
         })
     }
 }
@@ -126,6 +239,7 @@ impl TryFrom<PgfStewardProposal> for InitProposalData {
             voting_start_epoch: value.proposal.voting_start_epoch,
             voting_end_epoch: value.proposal.voting_end_epoch,
             activation_epoch: value.proposal.activation_epoch,
+            deposit: value.proposal.deposit,
         })
     }
 }
@@ -165,6 +279,7 @@ impl TryFrom<PgfFundingProposal> for InitProposalData {
             voting_start_epoch: value.proposal.voting_start_epoch,
             voting_end_epoch: value.proposal.voting_end_epoch,
             activation_epoch: value.proposal.activation_epoch,
+            deposit: value.proposal.deposit,
         })
     }
 }
@@ -222,6 +337,54 @@ pub enum ProposalType {
     PGFSteward(BTreeSet<AddRemove<Address>>),
     /// PGF funding proposal
     PGFPayment(BTreeSet<PGFAction>),
+    /// Governance proposal with an ordered batch of wasm codes, executed
+    /// atomically
+    DefaultWithWasmBatch(Vec<WasmProposalStep>),
+}
+
+/// A step of a batch of wasm codes executed by a proposal
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSchema,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+)]
+pub struct WasmProposalStep {
+    /// The hash of the extra data section holding the code of the step
+    pub code: Hash,
+    /// The data passed to the code of the step
+    pub data: Vec<u8>,
+}
+
+/// A tx data type passed to the code of a step of a batch proposal
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSchema,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+)]
+pub struct ProposalStepData {
+    /// The proposal id
+    pub id: u64,
+    /// The index of the step in the batch
+    pub step: u64,
+    /// The data of the step
+    pub data: Vec<u8>,
 }
 
 /// An add or remove action for PGF
@@ -373,9 +536,26 @@ impl ProposalType {
         matches!(self, ProposalType::DefaultWithWasm(_))
     }
 
+    /// Check if the proposal type is a batch of wasm codes
+    pub fn is_default_with_wasm_batch(&self) -> bool {
+        matches!(self, ProposalType::DefaultWithWasmBatch(_))
+    }
+
     fn format_data(&self) -> String {
         match self {
             ProposalType::DefaultWithWasm(hash) => format!("Hash: {}", &hash),
+            ProposalType::DefaultWithWasmBatch(steps) => format!(
+                "Steps:{}",
+                steps
+                    .iter()
+                    .enumerate()
+                    .map(|(index, step)| format!(
+                        "\n  {index}: Hash: {}, Data: {} bytes",
+                        step.code,
+                        step.data.len()
+                    ))
+                    .join("")
+            ),
             ProposalType::Default => "".to_string(),
             ProposalType::PGFSteward(addresses) => format!(
                 "Addresses:{}",
@@ -400,6 +580,9 @@ impl Display for ProposalType {
         match self {
             ProposalType::Default => write!(f, "Default"),
             ProposalType::DefaultWithWasm(_) => write!(f, "Default with Wasm"),
+            ProposalType::DefaultWithWasmBatch(_) => {
+                write!(f, "Default with Wasm batch")
+            }
             ProposalType::PGFSteward(_) => write!(f, "PGF steward"),
             ProposalType::PGFPayment(_) => write!(f, "PGF funding"),
         }
@@ -587,7 +770,7 @@ pub mod testing {
     use namada_core::ibc::core::host::types::identifiers::{ChannelId, PortId};
     use namada_core::token::testing::arb_amount;
     use proptest::prelude::*;
-    use proptest::{collection, prop_compose};
+    use proptest::{collection, option, prop_compose};
 
     use super::*;
     use crate::storage::vote::testing::arb_proposal_vote;
@@ -668,11 +851,23 @@ pub mod testing {
         ]
     }
 
+    prop_compose! {
+        /// Generate an arbitrary step of a batch proposal
+        pub fn arb_wasm_proposal_step()(
+            code in arb_hash(),
+            data in collection::vec(any::<u8>(), 0..64),
+        ) -> WasmProposalStep {
+            WasmProposalStep { code, data }
+        }
+    }
+
     /// Generate an arbitrary proposal type
     pub fn arb_proposal_type() -> impl Strategy<Value = ProposalType> {
         prop_oneof![
             Just(ProposalType::Default),
             arb_hash().prop_map(ProposalType::DefaultWithWasm),
+            collection::vec(arb_wasm_proposal_step(), 1..10)
+                .prop_map(ProposalType::DefaultWithWasmBatch),
             collection::btree_set(
                 arb_add_remove(arb_non_internal_address()),
                 0..10,
@@ -692,6 +887,7 @@ pub mod testing {
             voting_start_epoch in arb_epoch(),
             voting_end_epoch in arb_epoch(),
             activation_epoch in arb_epoch(),
+            deposit in option::of(arb_amount()),
         ) -> InitProposalData {
             InitProposalData {
                 content,
@@ -700,6 +896,7 @@ pub mod testing {
                 voting_start_epoch,
                 voting_end_epoch,
                 activation_epoch,
+                deposit,
             }
         }
     }
@@ -27,6 +27,9 @@ pub enum ProposalVote {
     Nay,
     /// Abstain
     Abstain,
+    /// No, with a veto that rejects the proposal if enough of the voting
+    /// power casts it, irrespective of the `yay` votes
+    NoWithVeto,
 }
 
 impl ProposalVote {
@@ -45,6 +48,11 @@ impl ProposalVote {
         matches!(self, ProposalVote::Abstain)
     }
 
+    /// Check if a vote is a veto
+    pub fn is_veto(&self) -> bool {
+        matches!(self, ProposalVote::NoWithVeto)
+    }
+
     /// Check if two votes are equal, returns an error if the variants of the
     /// two instances are different
     #[allow(clippy::match_like_matches_macro)]
@@ -53,6 +61,7 @@ impl ProposalVote {
             (ProposalVote::Yay, ProposalVote::Yay) => true,
             (ProposalVote::Nay, ProposalVote::Nay) => true,
             (ProposalVote::Abstain, ProposalVote::Abstain) => true,
+            (ProposalVote::NoWithVeto, ProposalVote::NoWithVeto) => true,
             _ => false,
         }
     }
@@ -64,6 +73,7 @@ impl Display for ProposalVote {
             ProposalVote::Yay => write!(f, "yay"),
             ProposalVote::Nay => write!(f, "nay"),
             ProposalVote::Abstain => write!(f, "abstain"),
+            ProposalVote::NoWithVeto => write!(f, "veto"),
         }
     }
 }
@@ -76,6 +86,7 @@ impl TryFrom<String> for ProposalVote {
             "yay" => Ok(ProposalVote::Yay),
             "nay" => Ok(ProposalVote::Nay),
             "abstain" => Ok(ProposalVote::Abstain),
+            "veto" | "no-with-veto" => Ok(ProposalVote::NoWithVeto),
             _ => Err("invalid vote".to_string()),
         }
     }
@@ -94,6 +105,7 @@ pub mod testing {
             Just(ProposalVote::Yay),
             Just(ProposalVote::Nay),
             Just(ProposalVote::Abstain),
+            Just(ProposalVote::NoWithVeto),
         ]
     }
 }
@@ -12,6 +12,7 @@ use namada_core::uint::Uint;
 use namada_macros::BorshDeserializer;
 #[cfg(feature = "migrations")]
 use namada_migrations::*;
+use serde::{Deserialize, Serialize};
 
 use super::storage::proposal::ProposalType;
 use super::storage::vote::ProposalVote;
@@ -65,19 +66,23 @@ impl Vote {
 }
 
 /// Represents a tally type that describes the voting requirements for a
-/// proposal to pass.
+/// proposal to pass. The fractions of voting power involved are given by the
+/// [`TallyParams`] of the proposal type.
 #[derive(
     Copy, Debug, Clone, BorshSerialize, BorshDeserialize, BorshDeserializer,
 )]
 pub enum TallyType {
-    /// The `yay` votes are at least 2/3 of the non-abstain votes, and 2/3 of
-    /// the total voting power has voted
+    /// The `yay` votes are at least the threshold (2/3 by default) of the
+    /// non-abstain votes, and the quorum (2/5 by default) of the total voting
+    /// power has voted
     TwoFifths,
-    /// There are more `yay` votes than `nay` votes, and at least 1/3 of the
+    /// The `yay` votes are more than the threshold (1/2 by default) of the
+    /// non-abstain votes, and at least the quorum (1/3 by default) of the
     /// total voting power has voted
     OneHalfOverOneThird,
-    /// Either less than 1/3 of the total voting power voted, or there are more
-    /// `yay` votes than `nay` votes
+    /// Either less than the quorum (1/3 by default) of the total voting power
+    /// voted, or the `yay` votes are more than the threshold (1/2 by default)
+    /// of the non-abstain votes
     LessOneHalfOverOneThirdNay,
 }
 
@@ -86,7 +91,10 @@ impl TallyType {
     pub fn from(proposal_type: ProposalType, is_steward: bool) -> Self {
         match (proposal_type, is_steward) {
             (ProposalType::Default, _) => TallyType::TwoFifths,
-            (ProposalType::DefaultWithWasm(_), _) => TallyType::TwoFifths,
+            (ProposalType::DefaultWithWasm(_), _)
+            | (ProposalType::DefaultWithWasmBatch(_), _) => {
+                TallyType::TwoFifths
+            }
             (ProposalType::PGFSteward(_), _) => TallyType::OneHalfOverOneThird,
             (ProposalType::PGFPayment(_), true) => {
                 TallyType::LessOneHalfOverOneThirdNay
@@ -96,6 +104,82 @@ impl TallyType {
             }
         }
     }
+
+    /// The default tally parameters of this type of tally
+    pub fn default_params(&self) -> TallyParams {
+        let (quorum, threshold) = match self {
+            TallyType::TwoFifths => (Dec::two_fifths(), Dec::two_thirds()),
+            TallyType::OneHalfOverOneThird
+            | TallyType::LessOneHalfOverOneThirdNay => {
+                (Dec::one_third(), Dec::new(5, 1).expect("Cannot fail"))
+            }
+        };
+        TallyParams {
+            quorum,
+            threshold,
+            veto_threshold: Dec::one_third(),
+        }
+    }
+}
+
+/// The fractions of voting power required by a tally, set for each proposal
+/// type in the governance parameters
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+)]
+pub struct TallyParams {
+    /// The fraction of the total voting power that must vote
+    pub quorum: Dec,
+    /// The fraction of the non-abstain votes that the `yay` votes must reach
+    pub threshold: Dec,
+    /// The fraction of the votes that, once exceeded by `NoWithVeto` votes,
+    /// rejects the proposal
+    pub veto_threshold: Dec,
+}
+
+impl TallyParams {
+    /// Check that all the fractions are between 0 and 1
+    pub fn is_valid(&self) -> bool {
+        [self.quorum, self.threshold, self.veto_threshold]
+            .iter()
+            .all(|frac| !frac.is_negative() && *frac <= Dec::one())
+    }
+
+    /// Check if the voted power reaches the quorum of the total voting power
+    pub fn is_quorum_reached(
+        &self,
+        total_voted_power: VotePower,
+        total_voting_power: VotePower,
+    ) -> Result<bool, arith::Error> {
+        Ok(total_voted_power >= total_voting_power.mul_ceil(self.quorum)?)
+    }
+
+    /// Check if the quorum is reached and the `NoWithVeto` votes exceed the
+    /// veto threshold of the voted power
+    pub fn is_vetoed(
+        &self,
+        veto_voting_power: VotePower,
+        total_voted_power: VotePower,
+        total_voting_power: VotePower,
+    ) -> Result<bool, arith::Error> {
+        let quorum_reached =
+            self.is_quorum_reached(total_voted_power, total_voting_power)?;
+        let veto_above_threshold = veto_voting_power
+            > total_voted_power.mul_floor(self.veto_threshold)?;
+        Ok(quorum_reached && veto_above_threshold)
+    }
 }
 
 /// The result of a proposal
@@ -133,68 +217,112 @@ impl FromStr for TallyResult {
     }
 }
 
+/// The result of the execution of a step of a batch proposal
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+)]
+pub enum ProposalStepResult {
+    /// The code of the step was executed successfully
+    Success,
+    /// The code of the step failed or was rejected by a VP
+    Failure,
+    /// The step was not executed, because a previous step failed
+    Skipped,
+}
+
+impl Display for ProposalStepResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProposalStepResult::Success => write!(f, "success"),
+            ProposalStepResult::Failure => write!(f, "failure"),
+            ProposalStepResult::Skipped => write!(f, "skipped"),
+        }
+    }
+}
+
+impl FromStr for ProposalStepResult {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "success" => Ok(Self::Success),
+            "failure" => Ok(Self::Failure),
+            "skipped" => Ok(Self::Skipped),
+            t => Err(format!(
+                "Proposal step result value of {t:?} does not match \
+                 \"success\", \"failure\" nor \"skipped\""
+            )),
+        }
+    }
+}
+
 impl TallyResult {
-    /// Create a new tally result
+    /// Create a new tally result. The `NoWithVeto` votes count as `nay`
+    /// votes, and reject the proposal if they exceed the veto threshold.
     pub fn new(
         tally_type: &TallyType,
+        tally_params: &TallyParams,
         yay_voting_power: VotePower,
         nay_voting_power: VotePower,
         abstain_voting_power: VotePower,
+        veto_voting_power: VotePower,
         total_voting_power: VotePower,
     ) -> Result<Self, arith::Error> {
+        let total_voted_power = checked!(
+            yay_voting_power
+                + nay_voting_power
+                + abstain_voting_power
+                + veto_voting_power
+        )?;
+        let non_abstain_power =
+            checked!(yay_voting_power + nay_voting_power + veto_voting_power)?;
+        let quorum_reached = tally_params
+            .is_quorum_reached(total_voted_power, total_voting_power)?;
+
         let passed = match tally_type {
             TallyType::TwoFifths => {
-                let at_least_two_fifths_voted = Self::get_total_voted_power(
-                    yay_voting_power,
-                    nay_voting_power,
-                    abstain_voting_power,
-                )? >= total_voting_power
-                    .mul_ceil(Dec::two_fifths())?;
-
-                // yay >= 2/3 * (yay + nay) ---> yay >= 2 * nay
-                let at_least_two_third_voted_yay = yay_voting_power
-                    >= checked!(nay_voting_power + nay_voting_power)?;
-
-                at_least_two_fifths_voted && at_least_two_third_voted_yay
+                // yay >= threshold * (yay + nay)
+                let yay_reached_threshold = yay_voting_power
+                    >= non_abstain_power.mul_ceil(tally_params.threshold)?;
+
+                quorum_reached && yay_reached_threshold
             }
             TallyType::OneHalfOverOneThird => {
-                let at_least_one_third_voted = Self::get_total_voted_power(
-                    yay_voting_power,
-                    nay_voting_power,
-                    abstain_voting_power,
-                )? >= total_voting_power
-                    .mul_ceil(Dec::one_third())?;
-
-                // Yay votes must be more than half of the total votes
-                let more_than_half_voted_yay =
-                    yay_voting_power > nay_voting_power;
-                at_least_one_third_voted && more_than_half_voted_yay
+                // yay > threshold * (yay + nay)
+                let yay_above_threshold = yay_voting_power
+                    > non_abstain_power.mul_floor(tally_params.threshold)?;
+
+                quorum_reached && yay_above_threshold
             }
             TallyType::LessOneHalfOverOneThirdNay => {
-                let less_than_one_third = Self::get_total_voted_power(
-                    yay_voting_power,
-                    nay_voting_power,
-                    abstain_voting_power,
-                )? < total_voting_power
-                    .mul_ceil(Dec::one_third())?;
-
-                // Nay votes must be less than half of the total votes
-                let more_than_half_voted_yay =
-                    yay_voting_power > nay_voting_power;
-
-                less_than_one_third || more_than_half_voted_yay
+                // yay > threshold * (yay + nay)
+                let yay_above_threshold = yay_voting_power
+                    > non_abstain_power.mul_floor(tally_params.threshold)?;
+
+                !quorum_reached || yay_above_threshold
             }
         };
-
-        Ok(if passed { Self::Passed } else { Self::Rejected })
-    }
-
-    fn get_total_voted_power(
-        yay_voting_power: VotePower,
-        nay_voting_power: VotePower,
-        abstain_voting_power: VotePower,
-    ) -> Result<VotePower, arith::Error> {
-        checked!(yay_voting_power + nay_voting_power + abstain_voting_power)
+        let vetoed = tally_params.is_vetoed(
+            veto_voting_power,
+            total_voted_power,
+            total_voting_power,
+        )?;
+
+        Ok(if passed && !vetoed {
+            Self::Passed
+        } else {
+            Self::Rejected
+        })
     }
 }
 
@@ -207,6 +335,8 @@ pub struct ProposalResult {
     pub result: TallyResult,
     /// The type of tally required for this proposal
     pub tally_type: TallyType,
+    /// The tally parameters of this proposal
+    pub tally_params: TallyParams,
     /// The total voting power during the proposal tally
     pub total_voting_power: VotePower,
     /// The total voting power from yay votes
@@ -215,27 +345,26 @@ pub struct ProposalResult {
     pub total_nay_power: VotePower,
     /// The total voting power from abstained votes
     pub total_abstain_power: VotePower,
+    /// The total voting power from `NoWithVeto` votes
+    pub total_veto_power: VotePower,
 }
 
 impl ProposalResult {
     /// Return true if at least 2/3 of the total voting power voted and at least
-    /// two third of the non-abstained voting power voted nay.
-    /// Returns `false` if any arithmetic fails.
+    /// two third of the non-abstained voting power voted nay, including the
+    /// `NoWithVeto` votes. Returns `false` if any arithmetic fails.
     #[allow(clippy::disallowed_methods)]
     pub fn two_thirds_nay_over_two_thirds_total(&self) -> bool {
         (|| {
             let two_thirds_power =
                 self.total_voting_power.mul_ceil(Dec::two_thirds())?;
-            let at_least_two_third_voted = checked!(
-                self.total_yay_power
-                    + self.total_nay_power
-                    + self.total_abstain_power
-                    >= two_thirds_power
-            )?;
+            let at_least_two_third_voted =
+                self.total_voted_power()? >= two_thirds_power;
 
             // nay >= 2/3 * (yay + nay) ---> nay >= 2 * yay
-            let at_least_two_thirds_voted_nay = self.total_nay_power
-                >= checked!(self.total_yay_power + self.total_yay_power)?;
+            let at_least_two_thirds_voted_nay =
+                checked!(self.total_nay_power + self.total_veto_power)?
+                    >= checked!(self.total_yay_power + self.total_yay_power)?;
 
             Ok::<bool, arith::Error>(
                 at_least_two_third_voted && at_least_two_thirds_voted_nay,
@@ -243,16 +372,37 @@ impl ProposalResult {
         })()
         .unwrap_or_default()
     }
+
+    /// Return true if the `NoWithVeto` votes rejected the proposal. Returns
+    /// `false` if any arithmetic fails.
+    #[allow(clippy::disallowed_methods)]
+    pub fn is_vetoed(&self) -> bool {
+        self.total_voted_power()
+            .and_then(|total_voted_power| {
+                self.tally_params.is_vetoed(
+                    self.total_veto_power,
+                    total_voted_power,
+                    self.total_voting_power,
+                )
+            })
+            .unwrap_or_default()
+    }
+
+    fn total_voted_power(&self) -> Result<VotePower, arith::Error> {
+        checked!(
+            self.total_yay_power
+                + self.total_nay_power
+                + self.total_abstain_power
+                + self.total_veto_power
+        )
+    }
 }
 
 impl Display for ProposalResult {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let threshold = match self.tally_type {
-            TallyType::TwoFifths => {
-                self.total_voting_power.mul_ceil(Dec::two_fifths())
-            }
             TallyType::LessOneHalfOverOneThirdNay => Ok(token::Amount::zero()),
-            _ => self.total_voting_power.mul_ceil(Dec::one_third()),
+            _ => self.total_voting_power.mul_ceil(self.tally_params.quorum),
         }
         .unwrap();
 
@@ -263,13 +413,14 @@ impl Display for ProposalResult {
 
         write!(
             f,
-            "{} with {} yay votes, {} nay votes and {} abstain votes, total \
-             voting power: {}, threshold (fraction) of total voting power \
-             needed to tally: {} ({})",
+            "{} with {} yay votes, {} nay votes, {} abstain votes and {} veto \
+             votes, total voting power: {}, threshold (fraction) of total \
+             voting power needed to tally: {} ({})",
             self.result,
             self.total_yay_power.to_string_native(),
             self.total_nay_power.to_string_native(),
             self.total_abstain_power.to_string_native(),
+            self.total_veto_power.to_string_native(),
             self.total_voting_power.to_string_native(),
             threshold.to_string_native(),
             thresh_frac
@@ -328,26 +479,58 @@ impl ProposalVotes {
     }
 }
 
+/// The voting power accumulated by each vote option
+#[derive(Default)]
+struct VotesPower {
+    yay: VotePower,
+    nay: VotePower,
+    abstain: VotePower,
+    veto: VotePower,
+}
+
+impl VotesPower {
+    fn power_mut(&mut self, vote: &ProposalVote) -> &mut VotePower {
+        match vote {
+            ProposalVote::Yay => &mut self.yay,
+            ProposalVote::Nay => &mut self.nay,
+            ProposalVote::Abstain => &mut self.abstain,
+            ProposalVote::NoWithVeto => &mut self.veto,
+        }
+    }
+
+    fn add(
+        &mut self,
+        vote: &ProposalVote,
+        vote_power: VotePower,
+    ) -> Result<(), arith::Error> {
+        let power = *self.power_mut(vote);
+        *self.power_mut(vote) = checked!(power + vote_power)?;
+        Ok(())
+    }
+
+    fn sub(
+        &mut self,
+        vote: &ProposalVote,
+        vote_power: VotePower,
+    ) -> Result<(), arith::Error> {
+        let power = *self.power_mut(vote);
+        *self.power_mut(vote) = checked!(power - vote_power)?;
+        Ok(())
+    }
+}
+
 /// Compute the result of a proposal
 pub fn compute_proposal_result(
     votes: ProposalVotes,
     total_voting_power: VotePower,
     tally_type: TallyType,
+    tally_params: TallyParams,
 ) -> Result<ProposalResult, arith::Error> {
-    let mut yay_voting_power = VotePower::default();
-    let mut nay_voting_power = VotePower::default();
-    let mut abstain_voting_power = VotePower::default();
+    let mut power = VotesPower::default();
 
     for (address, vote_power) in votes.validator_voting_power {
-        let vote_type = votes.validators_vote.get(&address);
-        if let Some(vote) = vote_type {
-            if vote.is_yay() {
-                checked!(yay_voting_power += vote_power)?;
-            } else if vote.is_nay() {
-                checked!(nay_voting_power += vote_power)?;
-            } else if vote.is_abstain() {
-                checked!(abstain_voting_power += vote_power)?;
-            }
+        if let Some(vote) = votes.validators_vote.get(&address) {
+            power.add(vote, vote_power)?;
         }
     }
 
@@ -357,69 +540,39 @@ pub fn compute_proposal_result(
             None => continue,
         };
         for (validator, vote_power) in delegations {
-            let validator_vote = votes.validators_vote.get(&validator);
-            if let Some(validator_vote) = validator_vote {
-                let validator_vote_is_same_side =
-                    validator_vote.is_same_side(delegator_vote);
-
-                if !validator_vote_is_same_side {
-                    if delegator_vote.is_yay() {
-                        yay_voting_power =
-                            checked!(yay_voting_power + vote_power)?;
-                        if validator_vote.is_nay() {
-                            nay_voting_power =
-                                checked!(nay_voting_power - vote_power)?;
-                        } else if validator_vote.is_abstain() {
-                            abstain_voting_power =
-                                checked!(abstain_voting_power - vote_power)?;
-                        }
-                    } else if delegator_vote.is_nay() {
-                        nay_voting_power =
-                            checked!(nay_voting_power + vote_power)?;
-                        if validator_vote.is_yay() {
-                            yay_voting_power =
-                                checked!(yay_voting_power - vote_power)?;
-                        } else if validator_vote.is_abstain() {
-                            abstain_voting_power =
-                                checked!(abstain_voting_power - vote_power)?;
-                        }
-                    } else if delegator_vote.is_abstain() {
-                        abstain_voting_power =
-                            checked!(abstain_voting_power + vote_power)?;
-                        if validator_vote.is_yay() {
-                            yay_voting_power =
-                                checked!(yay_voting_power - vote_power)?;
-                        } else if validator_vote.is_nay() {
-                            nay_voting_power =
-                                checked!(nay_voting_power - vote_power)?;
-                        }
+            match votes.validators_vote.get(&validator) {
+                Some(validator_vote) => {
+                    // The delegator's vote overrides the vote of the
+                    // validator for the delegated voting power
+                    if !validator_vote.is_same_side(delegator_vote) {
+                        power.add(delegator_vote, vote_power)?;
+                        power.sub(validator_vote, vote_power)?;
                     }
                 }
-            } else if delegator_vote.is_yay() {
-                checked!(yay_voting_power += vote_power)?;
-            } else if delegator_vote.is_nay() {
-                checked!(nay_voting_power += vote_power)?;
-            } else if delegator_vote.is_abstain() {
-                checked!(abstain_voting_power += vote_power)?;
+                None => power.add(delegator_vote, vote_power)?,
             }
         }
     }
 
     let tally_result = TallyResult::new(
         &tally_type,
-        yay_voting_power,
-        nay_voting_power,
-        abstain_voting_power,
+        &tally_params,
+        power.yay,
+        power.nay,
+        power.abstain,
+        power.veto,
         total_voting_power,
     )?;
 
     Ok(ProposalResult {
         result: tally_result,
         tally_type,
+        tally_params,
         total_voting_power,
-        total_yay_power: yay_voting_power,
-        total_nay_power: nay_voting_power,
-        total_abstain_power: abstain_voting_power,
+        total_yay_power: power.yay,
+        total_nay_power: power.nay,
+        total_abstain_power: power.abstain,
+        total_veto_power: power.veto,
     })
 }
 
@@ -510,6 +663,7 @@ mod test {
                 proposal_votes.clone(),
                 token::Amount::from_u64(1),
                 tally_type,
+                tally_type.default_params(),
             )
             .unwrap();
             let _result = if matches!(
@@ -548,6 +702,7 @@ mod test {
                 proposal_votes.clone(),
                 validator_voting_power,
                 tally_type,
+                tally_type.default_params(),
             )
             .unwrap();
             assert!(
@@ -597,6 +752,7 @@ mod test {
                 proposal_votes.clone(),
                 validator_voting_power,
                 tally_type,
+                tally_type.default_params(),
             )
             .unwrap();
             assert!(
@@ -646,6 +802,7 @@ mod test {
                 proposal_votes.clone(),
                 validator_voting_power,
                 tally_type,
+                tally_type.default_params(),
             )
             .unwrap();
             assert!(
@@ -707,6 +864,7 @@ mod test {
                 proposal_votes.clone(),
                 validator_voting_power,
                 tally_type,
+                tally_type.default_params(),
             )
             .unwrap();
             assert!(
@@ -775,6 +933,7 @@ mod test {
                 proposal_votes.clone(),
                 validator_voting_power,
                 tally_type,
+                tally_type.default_params(),
             )
             .unwrap();
             assert!(
@@ -834,6 +993,7 @@ mod test {
                 proposal_votes.clone(),
                 validator_voting_power,
                 tally_type,
+                tally_type.default_params(),
             )
             .unwrap();
             assert!(
@@ -891,6 +1051,7 @@ mod test {
                 proposal_votes.clone(),
                 validator_voting_power.add(validator_voting_power_two),
                 tally_type,
+                tally_type.default_params(),
             )
             .unwrap();
             let _result = if matches!(
@@ -964,6 +1125,7 @@ mod test {
                 proposal_votes.clone(),
                 validator_voting_power.add(validator_voting_power_two),
                 tally_type,
+                tally_type.default_params(),
             )
             .unwrap();
             let _result =
@@ -1032,6 +1194,7 @@ mod test {
             proposal_votes.clone(),
             validator_voting_power.add(validator_voting_power_two),
             TallyType::TwoFifths,
+            TallyType::TwoFifths.default_params(),
         )
         .unwrap();
 
@@ -1101,6 +1264,7 @@ mod test {
             proposal_votes.clone(),
             validator_voting_power.add(validator_voting_power_two),
             TallyType::TwoFifths,
+            TallyType::TwoFifths.default_params(),
         )
         .unwrap();
 
@@ -1157,6 +1321,7 @@ mod test {
             proposal_votes.clone(),
             delegator_voting_power_two.add(delegator_voting_power),
             TallyType::TwoFifths,
+            TallyType::TwoFifths.default_params(),
         )
         .unwrap();
 
@@ -1210,6 +1375,7 @@ mod test {
             proposal_votes.clone(),
             token::Amount::from(200),
             TallyType::TwoFifths,
+            TallyType::TwoFifths.default_params(),
         )
         .unwrap();
 
@@ -1265,6 +1431,7 @@ mod test {
             proposal_votes.clone(),
             token::Amount::from(403),
             TallyType::OneHalfOverOneThird,
+            TallyType::OneHalfOverOneThird.default_params(),
         )
         .unwrap();
 
@@ -1320,6 +1487,7 @@ mod test {
             proposal_votes.clone(),
             token::Amount::from(402),
             TallyType::OneHalfOverOneThird,
+            TallyType::OneHalfOverOneThird.default_params(),
         )
         .unwrap();
 
@@ -1375,6 +1543,7 @@ mod test {
             proposal_votes.clone(),
             token::Amount::from(100),
             TallyType::LessOneHalfOverOneThirdNay,
+            TallyType::LessOneHalfOverOneThirdNay.default_params(),
         )
         .unwrap();
 
@@ -1432,6 +1601,7 @@ mod test {
             proposal_votes.clone(),
             token::Amount::from(271),
             TallyType::LessOneHalfOverOneThirdNay,
+            TallyType::LessOneHalfOverOneThirdNay.default_params(),
         )
         .unwrap();
 
@@ -1460,6 +1630,140 @@ mod test {
         assert!(!proposal_result.two_thirds_nay_over_two_thirds_total())
     }
 
+    #[test]
+    fn test_proposal_veto() {
+        let mut proposal_votes = ProposalVotes::default();
+
+        let validator_address = address::testing::established_address_1();
+        let validator_voting_power = token::Amount::from_u64(100);
+        proposal_votes.add_validator(
+            &validator_address,
+            validator_voting_power,
+            ProposalVote::Yay,
+        );
+
+        let delegator_address = address::testing::established_address_2();
+        let delegator_voting_power = token::Amount::from_u64(30);
+        proposal_votes.add_delegator(
+            &delegator_address,
+            &validator_address,
+            delegator_voting_power,
+            ProposalVote::NoWithVeto,
+        );
+
+        for tally_type in [
+            TallyType::OneHalfOverOneThird,
+            TallyType::LessOneHalfOverOneThirdNay,
+            TallyType::TwoFifths,
+        ] {
+            // 30% of vetoes is below the default veto threshold of 1/3
+            let proposal_result = compute_proposal_result(
+                proposal_votes.clone(),
+                validator_voting_power,
+                tally_type,
+                tally_type.default_params(),
+            )
+            .unwrap();
+            assert!(
+                matches!(proposal_result.result, TallyResult::Passed),
+                "{tally_type:?}"
+            );
+            assert!(!proposal_result.is_vetoed());
+            assert_eq!(
+                proposal_result.total_yay_power,
+                validator_voting_power.sub(delegator_voting_power),
+                "yay"
+            );
+            assert_eq!(
+                proposal_result.total_nay_power,
+                token::Amount::zero(),
+                "nay"
+            );
+            assert_eq!(
+                proposal_result.total_veto_power, delegator_voting_power,
+                "veto"
+            );
+
+            // but above a lower veto threshold, which rejects the proposal
+            let tally_params = TallyParams {
+                veto_threshold: Dec::new(25, 2).unwrap(),
+                ..tally_type.default_params()
+            };
+            let proposal_result = compute_proposal_result(
+                proposal_votes.clone(),
+                validator_voting_power,
+                tally_type,
+                tally_params,
+            )
+            .unwrap();
+            assert!(
+                matches!(proposal_result.result, TallyResult::Rejected),
+                "{tally_type:?}"
+            );
+            assert!(proposal_result.is_vetoed());
+        }
+    }
+
+    #[test]
+    fn test_proposal_custom_tally_params() {
+        let mut proposal_votes = ProposalVotes::default();
+
+        let validator_address = address::testing::established_address_1();
+        let validator_voting_power = token::Amount::from_u64(60);
+        proposal_votes.add_validator(
+            &validator_address,
+            validator_voting_power,
+            ProposalVote::Yay,
+        );
+
+        let validator_address_two = address::testing::established_address_2();
+        let validator_voting_power_two = token::Amount::from_u64(40);
+        proposal_votes.add_validator(
+            &validator_address_two,
+            validator_voting_power_two,
+            ProposalVote::Nay,
+        );
+
+        // 60% of yay votes don't reach the default threshold of 2/3
+        let tally_type = TallyType::TwoFifths;
+        let proposal_result = compute_proposal_result(
+            proposal_votes.clone(),
+            token::Amount::from_u64(200),
+            tally_type,
+            tally_type.default_params(),
+        )
+        .unwrap();
+        assert!(matches!(proposal_result.result, TallyResult::Rejected));
+
+        // but reach a threshold of 3/5
+        let tally_params = TallyParams {
+            threshold: Dec::new(6, 1).unwrap(),
+            ..tally_type.default_params()
+        };
+        let proposal_result = compute_proposal_result(
+            proposal_votes.clone(),
+            token::Amount::from_u64(200),
+            tally_type,
+            tally_params,
+        )
+        .unwrap();
+        assert!(matches!(proposal_result.result, TallyResult::Passed));
+
+        // unless the quorum isn't reached
+        let tally_params = TallyParams {
+            quorum: Dec::new(6, 1).unwrap(),
+            ..tally_params
+        };
+        let proposal_result = compute_proposal_result(
+            proposal_votes.clone(),
+            token::Amount::from_u64(200),
+            tally_type,
+            tally_params,
+        )
+        .unwrap();
+        assert!(matches!(proposal_result.result, TallyResult::Rejected));
+    }
+
     #[test]
     fn test_validator_voting_period() {
         // Voting period of 2 epochs
@@ -20,8 +20,11 @@ use thiserror::Error;
 use self::utils::ReadType;
 use crate::address::{Address, InternalAddress};
 use crate::storage::proposal::{AddRemove, PGFAction, ProposalType};
-use crate::storage::{is_proposal_accepted, keys as gov_storage};
-use crate::utils::is_valid_validator_voting_period;
+use crate::storage::{
+    get_min_proposal_initial_deposit, get_representative, has_constituents,
+    is_proposal_accepted, keys as gov_storage,
+};
+use crate::utils::{is_valid_validator_voting_period, TallyParams};
 use crate::ProposalVote;
 
 /// The governance internal address
@@ -124,6 +127,54 @@ where
                             .into());
                         }
                     }
+                    GovAction::DelegateVotes { delegator } => {
+                        if !verifiers.contains(&delegator) {
+                            tracing::info!(
+                                "Unauthorized GovAction::DelegateVotes"
+                            );
+                            return Err(VpError::Unauthorized(
+                                "DelegateVotes",
+                                delegator,
+                            )
+                            .into());
+                        }
+                    }
+                    GovAction::DepositProposal { id: _, depositor } => {
+                        if !verifiers.contains(&depositor) {
+                            tracing::info!(
+                                "Unauthorized GovAction::DepositProposal"
+                            );
+                            return Err(VpError::Unauthorized(
+                                "DepositProposal",
+                                depositor,
+                            )
+                            .into());
+                        }
+                    }
+                    GovAction::CancelProposal { id: _, author } => {
+                        if !verifiers.contains(&author) {
+                            tracing::info!(
+                                "Unauthorized GovAction::CancelProposal"
+                            );
+                            return Err(VpError::Unauthorized(
+                                "CancelProposal",
+                                author,
+                            )
+                            .into());
+                        }
+                    }
+                    GovAction::AmendProposal { id: _, author } => {
+                        if !verifiers.contains(&author) {
+                            tracing::info!(
+                                "Unauthorized GovAction::AmendProposal"
+                            );
+                            return Err(VpError::Unauthorized(
+                                "AmendProposal",
+                                author,
+                            )
+                            .into());
+                        }
+                    }
                 },
                 _ => {
                     // Other actions are not relevant to Governance VP
@@ -140,8 +191,16 @@ where
                 (KeyType::VOTE, Some(proposal_id)) => {
                     Self::is_valid_vote_key(ctx, proposal_id, key, verifiers)
                 }
+                (KeyType::REPRESENTATIVE_VOTE, Some(proposal_id)) => {
+                    Self::is_valid_representative_vote_key(
+                        ctx,
+                        proposal_id,
+                        key,
+                        verifiers,
+                    )
+                }
                 (KeyType::CONTENT, Some(proposal_id)) => {
-                    Self::is_valid_content_key(ctx, proposal_id)
+                    Self::is_valid_content_key(ctx, proposal_id, verifiers)
                 }
                 (KeyType::TYPE, Some(proposal_id)) => {
                     Self::is_valid_proposal_type(ctx, proposal_id)
@@ -149,6 +208,9 @@ where
                 (KeyType::PROPOSAL_CODE, Some(proposal_id)) => {
                     Self::is_valid_proposal_code(ctx, proposal_id)
                 }
+                (KeyType::PROPOSAL_STEP_CODE, Some(proposal_id)) => {
+                    Self::is_valid_proposal_step_code(ctx, proposal_id, key)
+                }
                 (KeyType::ACTIVATION_EPOCH, Some(proposal_id)) => {
                     Self::is_valid_activation_epoch(ctx, proposal_id)
                 }
@@ -158,21 +220,48 @@ where
                 (KeyType::END_EPOCH, Some(proposal_id)) => {
                     Self::is_valid_end_epoch(ctx, proposal_id)
                 }
-                (KeyType::FUNDS, Some(proposal_id)) => {
-                    Self::is_valid_funds(ctx, proposal_id, &native_token)
+                (KeyType::FUNDS, Some(proposal_id)) => Self::is_valid_funds(
+                    ctx,
+                    proposal_id,
+                    &native_token,
+                    keys_changed,
+                ),
+                (KeyType::DEPOSIT, Some(proposal_id)) => {
+                    Self::is_valid_deposit_key(
+                        ctx,
+                        proposal_id,
+                        key,
+                        keys_changed,
+                        verifiers,
+                    )
+                }
+                (KeyType::DEPOSIT_PENDING, Some(proposal_id)) => {
+                    Self::is_valid_deposit_pending_key(
+                        proposal_id,
+                        keys_changed,
+                    )
+                }
+                (KeyType::CANCELLED, Some(proposal_id)) => {
+                    Self::is_valid_cancelled_key(ctx, proposal_id, verifiers)
                 }
                 (KeyType::AUTHOR, Some(proposal_id)) => {
                     Self::is_valid_author(ctx, proposal_id, verifiers)
                 }
                 (KeyType::COUNTER, _) => Self::is_valid_counter(ctx, set_count),
+                (KeyType::DELEGATE, _) => {
+                    Self::is_valid_delegate_key(ctx, key, verifiers)
+                }
+                (KeyType::CONSTITUENT, _) => {
+                    Self::is_valid_constituent_key(ctx, key, verifiers)
+                }
                 (KeyType::PROPOSAL_COMMIT, _) => {
                     Self::is_valid_proposal_commit(ctx)
                 }
                 (KeyType::PARAMETER, _) => {
-                    Self::is_valid_parameter(ctx, tx_data)
+                    Self::is_valid_parameter(ctx, key, tx_data)
                 }
                 (KeyType::BALANCE, _) => {
-                    Self::is_valid_balance(ctx, &native_token)
+                    Self::is_valid_balance(ctx, &native_token, keys_changed)
                 }
                 (KeyType::UNKNOWN_GOVERNANCE, _) => Err(Error::new_alloc(
                     format!("Unkown governance key change: {key}"),
@@ -344,10 +433,170 @@ where
         Ok(())
     }
 
-    /// Validate a content key
+    /// Validate the vote of a representative, cast on behalf of its
+    /// constituents
+    fn is_valid_representative_vote_key(
+        ctx: &'ctx CTX,
+        proposal_id: u64,
+        key: &storage::Key,
+        verifiers: &BTreeSet<Address>,
+    ) -> Result<()> {
+        let representative = gov_storage::is_representative_vote_key(key)
+            .ok_or_else(|| {
+                Error::new_alloc(format!(
+                    "Failed to parse a representative from the vote key {key}"
+                ))
+            })?;
+        if !verifiers.contains(representative) {
+            return Err(VpError::Unauthorized(
+                "VoteProposal",
+                representative.clone(),
+            )
+            .into());
+        }
+
+        let counter_key = gov_storage::get_counter_key();
+        let pre_counter: u64 =
+            Self::force_read(ctx, &counter_key, ReadType::Pre)?;
+        if pre_counter <= proposal_id {
+            let error = Error::new_alloc(format!(
+                "Invalid proposal ID. Expected {pre_counter} or lower, got \
+                 {proposal_id}"
+            ));
+            tracing::info!("{error}");
+            return Err(error);
+        }
+
+        if Self::force_read::<ProposalVote>(ctx, key, ReadType::Post).is_err() {
+            return Err(Error::new_alloc(format!(
+                "Vote key is not valid: {key}"
+            )));
+        }
+
+        let current_epoch = ctx.get_block_epoch()?;
+        let pre_voting_start_epoch: Epoch = Self::force_read(
+            ctx,
+            &gov_storage::get_voting_start_epoch_key(proposal_id),
+            ReadType::Pre,
+        )?;
+        let pre_voting_end_epoch: Epoch = Self::force_read(
+            ctx,
+            &gov_storage::get_voting_end_epoch_key(proposal_id),
+            ReadType::Pre,
+        )?;
+        if !Self::is_valid_voting_window(
+            current_epoch,
+            pre_voting_start_epoch,
+            pre_voting_end_epoch,
+            false,
+        ) {
+            let error = Error::new_alloc(format!(
+                "Voted outside voting window. Current epoch: {current_epoch}, \
+                 start: {pre_voting_start_epoch}, end: {pre_voting_end_epoch}."
+            ));
+            tracing::info!("{error}");
+            return Err(error);
+        }
+
+        if !has_constituents(&ctx.pre(), representative)? {
+            return Err(Error::new_alloc(format!(
+                "Address {representative} is not the representative of any \
+                 constituents"
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Validate a change of the representative of a delegator
+    fn is_valid_delegate_key(
+        ctx: &'ctx CTX,
+        key: &storage::Key,
+        verifiers: &BTreeSet<Address>,
+    ) -> Result<()> {
+        let (delegator, epoch) =
+            gov_storage::is_delegate_key(key).ok_or_else(|| {
+                Error::new_alloc(format!("Invalid delegate key {key}"))
+            })?;
+        if !verifiers.contains(delegator) {
+            return Err(VpError::Unauthorized(
+                "DelegateVotes",
+                delegator.clone(),
+            )
+            .into());
+        }
+
+        let current_epoch = ctx.get_block_epoch()?;
+        let Some(representative) = ctx.post().read::<Option<Address>>(key)?
+        else {
+            // Only the delegations that are already effective may be pruned
+            return (epoch <= current_epoch).ok_or_else(|| {
+                Error::new_alloc(format!(
+                    "Cannot remove the pending delegation of {delegator} \
+                     effective from epoch {epoch}"
+                ))
+            });
+        };
+
+        // Delegations are effective from the next epoch
+        if epoch != current_epoch.next() {
+            return Err(Error::new_alloc(format!(
+                "A delegation must be effective from epoch {}, got {epoch}",
+                current_epoch.next()
+            )));
+        }
+        // Validators vote with their own stake
+        if PoS::is_validator(&ctx.pre(), delegator)? {
+            return Err(Error::new_alloc(format!(
+                "Validator {delegator} cannot delegate its votes"
+            )));
+        }
+        if representative.as_ref() == Some(delegator) {
+            return Err(Error::new_alloc(format!(
+                "Address {delegator} cannot delegate its votes to itself"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Validate a change of the constituents of a representative, which must
+    /// be consistent with the last delegation of the constituent
+    fn is_valid_constituent_key(
+        ctx: &'ctx CTX,
+        key: &storage::Key,
+        verifiers: &BTreeSet<Address>,
+    ) -> Result<()> {
+        let (representative, constituent) =
+            gov_storage::is_constituent_key(key).ok_or_else(|| {
+                Error::new_alloc(format!("Invalid constituent key {key}"))
+            })?;
+        if !verifiers.contains(constituent) {
+            return Err(VpError::Unauthorized(
+                "DelegateVotes",
+                constituent.clone(),
+            )
+            .into());
+        }
+
+        let last_representative =
+            get_representative(&ctx.post(), constituent, Epoch(u64::MAX))?;
+        let is_constituent = ctx.post().read::<Epoch>(key)?.is_some();
+        let is_consistent = is_constituent
+            == (last_representative.as_ref() == Some(representative));
+        is_consistent.ok_or_else(|| {
+            Error::new_alloc(format!(
+                "The constituents of {representative} are inconsistent with \
+                 the delegation of {constituent}"
+            ))
+        })
+    }
+
+    /// Validate a content key. The content of an existing proposal can only
+    /// be amended by its author before its voting start epoch.
     pub fn is_valid_content_key(
         ctx: &'ctx CTX,
         proposal_id: u64,
+        verifiers: &BTreeSet<Address>,
     ) -> Result<()> {
         let content_key: storage::Key =
             gov_storage::get_content_key(proposal_id);
@@ -356,10 +605,7 @@ where
 
         let has_pre_content: bool = ctx.has_key_pre(&content_key)?;
         if has_pre_content {
-            return Err(Error::new_alloc(format!(
-                "Proposal with id {proposal_id} already had content written \
-                 to storage."
-            )));
+            Self::is_modifiable_by_author(ctx, proposal_id, verifiers)?;
         }
 
         let max_content_length: usize = Self::force_read(
@@ -539,6 +785,28 @@ where
                     )
                 })
             }
+            ProposalType::DefaultWithWasmBatch(steps) => {
+                if steps.is_empty() {
+                    return Err(Error::new_const(
+                        "A batch proposal must have at least one step",
+                    ));
+                }
+                // the code of every step must be written, each one is
+                // validated with its own key
+                for index in 0..steps.len() as u64 {
+                    let step_code_key = gov_storage::get_proposal_step_code_key(
+                        proposal_id,
+                        index,
+                    );
+                    if !ctx.has_key_post(&step_code_key)? {
+                        return Err(Error::new_alloc(format!(
+                            "Batch proposal with id {proposal_id} is missing \
+                             the code of step {index}",
+                        )));
+                    }
+                }
+                Ok(())
+            }
             // Default proposal condition are checked already for all other
             // proposals.
             // default_with_wasm proposal needs to check only for valid code
@@ -592,6 +860,60 @@ where
         Ok(())
     }
 
+    /// Validate the code of a step of a batch proposal
+    pub fn is_valid_proposal_step_code(
+        ctx: &'ctx CTX,
+        proposal_id: u64,
+        key: &storage::Key,
+    ) -> Result<()> {
+        let proposal_type_key = gov_storage::get_proposal_type_key(proposal_id);
+        let proposal_type: ProposalType =
+            Self::force_read(ctx, &proposal_type_key, ReadType::Post)?;
+
+        let ProposalType::DefaultWithWasmBatch(steps) = proposal_type else {
+            return Err(Error::new_alloc(format!(
+                "Proposal with id {proposal_id} modified a proposal step code \
+                 key, but its type is not allowed this change.",
+            )));
+        };
+
+        let index = gov_storage::get_proposal_step_index(key).ok_or(
+            Error::new_const("Proposal step code key without a step index"),
+        )?;
+        if index >= steps.len() as u64 {
+            return Err(Error::new_alloc(format!(
+                "Proposal with id {proposal_id} wrote the code of step \
+                 {index}, but it only has {} steps.",
+                steps.len(),
+            )));
+        }
+
+        let has_pre_code: bool = ctx.has_key_pre(key)?;
+        if has_pre_code {
+            return Err(Error::new_alloc(format!(
+                "Proposal with id {proposal_id} already had wasm code written \
+                 to storage in the slot of step {index}.",
+            )));
+        }
+
+        let max_code_size_parameter_key =
+            gov_storage::get_max_proposal_code_size_key();
+        let max_proposal_length: usize =
+            Self::force_read(ctx, &max_code_size_parameter_key, ReadType::Pre)?;
+        let post_code: Vec<u8> = ctx.read_post(key)?.unwrap_or_default();
+
+        if post_code.len() > max_proposal_length {
+            return Err(Error::new_alloc(format!(
+                "Proposal with id {proposal_id} wrote wasm code of step \
+                 {index} with length {} to storage, but the max allowed \
+                 length is {max_proposal_length}.",
+                post_code.len(),
+            )));
+        }
+
+        Ok(())
+    }
+
     /// Validate an activation_epoch key
     pub fn is_valid_activation_epoch(
         ctx: &'ctx CTX,
@@ -813,11 +1135,14 @@ where
         })
     }
 
-    /// Validate a funds key
+    /// Validate a funds key. The funds of a new proposal must cover the
+    /// minimum initial deposit, and the funds of an existing proposal can
+    /// only be topped up by deposits until its voting start epoch.
     pub fn is_valid_funds(
         ctx: &'ctx CTX,
         proposal_id: u64,
         native_token_address: &Address,
+        keys_changed: &BTreeSet<storage::Key>,
     ) -> Result<()> {
         let funds_key = gov_storage::get_funds_key(proposal_id);
         let balance_key =
@@ -826,96 +1151,303 @@ where
 
         let min_funds_parameter: token::Amount =
             Self::force_read(ctx, &min_funds_parameter_key, ReadType::Pre)?;
-        let pre_balance: Option<token::Amount> =
-            ctx.pre().read(&balance_key)?;
+        let min_initial_deposit_parameter =
+            get_min_proposal_initial_deposit(&ctx.pre())?;
+        let pre_balance: token::Amount =
+            ctx.pre().read(&balance_key)?.unwrap_or_default();
         let post_balance: token::Amount =
             Self::force_read(ctx, &balance_key, ReadType::Post)?;
+        let pre_funds: Option<token::Amount> = ctx.pre().read(&funds_key)?;
         let post_funds: token::Amount =
             Self::force_read(ctx, &funds_key, ReadType::Post)?;
 
-        pre_balance.map_or_else(
-            // null pre balance
-            || {
+        match pre_funds {
+            // a new proposal
+            None => {
                 let is_post_funds_greater_than_minimum =
-                    post_funds >= min_funds_parameter;
+                    post_funds >= min_initial_deposit_parameter;
                 is_post_funds_greater_than_minimum.ok_or_else(|| {
                     Error::new_alloc(format!(
-                        "Funds must be greater than the minimum funds of {}",
-                        min_funds_parameter.native_denominated()
+                        "Funds {} must be greater than the minimum initial \
+                         deposit of {}",
+                        post_funds.native_denominated(),
+                        min_initial_deposit_parameter.native_denominated()
                     ))
                 })?;
-
-                let post_balance_is_same = post_balance == post_funds;
-                post_balance_is_same.ok_or_else(|| {
+            }
+            // a deposit to an existing proposal
+            Some(pre_funds) => {
+                let voting_start_epoch_key =
+                    gov_storage::get_voting_start_epoch_key(proposal_id);
+                let voting_start_epoch: Epoch = Self::force_read(
+                    ctx,
+                    &voting_start_epoch_key,
+                    ReadType::Pre,
+                )?;
+                let current_epoch = ctx.get_block_epoch()?;
+                (current_epoch < voting_start_epoch).ok_or_else(|| {
                     Error::new_alloc(format!(
-                        "Funds and the balance of the governance account have \
-                         diverged: funds {} != balance {}",
-                        post_funds.native_denominated(),
-                        post_balance.native_denominated()
+                        "The deposit phase of proposal {proposal_id} ended at \
+                         epoch {voting_start_epoch}"
                     ))
-                })
-            },
-            // there was some non-zero balance in the governance account
-            |pre_balance| {
-                let is_post_funds_greater_than_minimum =
-                    post_funds >= min_funds_parameter;
-                is_post_funds_greater_than_minimum.ok_or_else(|| {
+                })?;
+                let cancelled_key = gov_storage::get_cancelled_key(proposal_id);
+                (!ctx.has_key_pre(&cancelled_key)?).ok_or_else(|| {
                     Error::new_alloc(format!(
-                        "Funds {} must be greater than the minimum funds of {}",
-                        post_funds.native_denominated(),
-                        min_funds_parameter.native_denominated()
+                        "Proposal {proposal_id} has been cancelled"
                     ))
                 })?;
 
-                let is_valid_funds = post_balance >= pre_balance
-                    && checked!(post_balance - pre_balance)? == post_funds;
-                is_valid_funds.ok_or_else(|| {
+                let deposited =
+                    Self::deposited_amount(ctx, proposal_id, keys_changed)?;
+                let is_valid_deposit = post_funds > pre_funds
+                    && checked!(post_funds - pre_funds)? == deposited;
+                is_valid_deposit.ok_or_else(|| {
                     Error::new_alloc(format!(
-                        "Invalid funds {} have been written to storage",
+                        "Funds of proposal {proposal_id} must increase by the \
+                         deposited amount of {}, got {} -> {}",
+                        deposited.native_denominated(),
+                        pre_funds.native_denominated(),
                         post_funds.native_denominated()
                     ))
-                })
-            },
-        )
+                })?;
+            }
+        }
+
+        let is_valid_funds = post_balance >= pre_balance
+            && checked!(post_balance - pre_balance)?
+                == checked!(post_funds - pre_funds.unwrap_or_default())?;
+        is_valid_funds.ok_or_else(|| {
+            Error::new_alloc(format!(
+                "Invalid funds {} have been written to storage",
+                post_funds.native_denominated()
+            ))
+        })?;
+
+        // The proposal is in its deposit phase for as long as its funds are
+        // below the minimum
+        let deposit_pending_key =
+            gov_storage::get_deposit_pending_key(proposal_id);
+        let deposit_pending: Option<Epoch> =
+            ctx.post().read(&deposit_pending_key)?;
+        match deposit_pending {
+            Some(epoch) => {
+                let voting_start_epoch_key =
+                    gov_storage::get_voting_start_epoch_key(proposal_id);
+                let voting_start_epoch: Epoch = Self::force_read(
+                    ctx,
+                    &voting_start_epoch_key,
+                    ReadType::Post,
+                )?;
+                (post_funds < min_funds_parameter
+                    && epoch == voting_start_epoch)
+                    .ok_or_else(|| {
+                        Error::new_alloc(format!(
+                            "Proposal {proposal_id} with funds {} must not be \
+                             pending deposits until epoch {epoch}",
+                            post_funds.native_denominated()
+                        ))
+                    })
+            }
+            None => (post_funds >= min_funds_parameter).ok_or_else(|| {
+                Error::new_alloc(format!(
+                    "Funds {} must be greater than the minimum funds of {}",
+                    post_funds.native_denominated(),
+                    min_funds_parameter.native_denominated()
+                ))
+            }),
+        }
     }
 
-    /// Validate a balance key
-    fn is_valid_balance(
+    /// Validate a deposit key of a proposal
+    fn is_valid_deposit_key(
         ctx: &'ctx CTX,
-        native_token_address: &Address,
+        proposal_id: u64,
+        key: &storage::Key,
+        keys_changed: &BTreeSet<storage::Key>,
+        verifiers: &BTreeSet<Address>,
     ) -> Result<()> {
-        let balance_key =
-            TokenKeys::balance_key(native_token_address, &ADDRESS);
-        let min_funds_parameter_key = gov_storage::get_min_proposal_fund_key();
+        let depositor = gov_storage::is_deposit_key(key).ok_or_else(|| {
+            Error::new_alloc(format!("Invalid deposit key {key}"))
+        })?;
+        verifiers.contains(depositor).ok_or_else(|| {
+            Error::new_alloc(format!(
+                "The VP of the depositor {depositor} should have been \
+                 triggered"
+            ))
+        })?;
 
-        let pre_balance: Option<token::Amount> =
-            ctx.pre().read(&balance_key)?;
+        // Deposits can only be made to existing proposals
+        let funds_key = gov_storage::get_funds_key(proposal_id);
+        (ctx.has_key_pre(&funds_key)? && keys_changed.contains(&funds_key))
+            .ok_or_else(|| {
+                Error::new_alloc(format!(
+                    "A deposit must top up the funds of the existing proposal \
+                     {proposal_id}"
+                ))
+            })?;
 
-        let min_funds_parameter: token::Amount =
-            Self::force_read(ctx, &min_funds_parameter_key, ReadType::Pre)?;
-        let post_balance: token::Amount =
-            Self::force_read(ctx, &balance_key, ReadType::Post)?;
+        // Nor to cancelled proposals, including those cancelled in this tx
+        let cancelled_key = gov_storage::get_cancelled_key(proposal_id);
+        let is_cancelled = ctx.has_key_pre(&cancelled_key)?
+            || ctx.has_key_post(&cancelled_key)?;
+        (!is_cancelled).ok_or_else(|| {
+            Error::new_alloc(format!(
+                "No deposit can be made to the cancelled proposal \
+                 {proposal_id}"
+            ))
+        })?;
 
-        let balance_is_valid = if let Some(pre_balance) = pre_balance {
-            post_balance > pre_balance
-                && checked!(post_balance - pre_balance)? >= min_funds_parameter
-        } else {
-            post_balance >= min_funds_parameter
-        };
+        let pre: token::Amount = ctx.pre().read(key)?.unwrap_or_default();
+        let post: token::Amount = Self::force_read(ctx, key, ReadType::Post)?;
+        (post > pre).ok_or_else(|| {
+            Error::new_alloc(format!(
+                "The deposit of {depositor} to proposal {proposal_id} can \
+                 only increase"
+            ))
+        })
+    }
 
-        balance_is_valid.ok_or_else(|| {
+    /// Validate a deposit pending key of a proposal. Its consistency with
+    /// the funds of the proposal is checked by [`Self::is_valid_funds`].
+    fn is_valid_deposit_pending_key(
+        proposal_id: u64,
+        keys_changed: &BTreeSet<storage::Key>,
+    ) -> Result<()> {
+        let funds_key = gov_storage::get_funds_key(proposal_id);
+        keys_changed.contains(&funds_key).ok_or_else(|| {
             Error::new_alloc(format!(
-                "Invalid balance {} has been written to storage",
-                post_balance.native_denominated()
+                "The deposit phase of proposal {proposal_id} can only change \
+                 with its funds"
             ))
         })
     }
 
-    /// Validate a author key
-    pub fn is_valid_author(
+    /// Sum the amounts deposited to a proposal in the tx
+    fn deposited_amount(
         ctx: &'ctx CTX,
         proposal_id: u64,
-        verifiers: &BTreeSet<Address>,
+        keys_changed: &BTreeSet<storage::Key>,
+    ) -> Result<token::Amount> {
+        let mut deposited = token::Amount::zero();
+        for key in keys_changed {
+            if gov_storage::is_deposit_key(key).is_none()
+                || gov_storage::get_proposal_id(key) != Some(proposal_id)
+            {
+                continue;
+            }
+            let pre: token::Amount = ctx.pre().read(key)?.unwrap_or_default();
+            let post: token::Amount =
+                Self::force_read(ctx, key, ReadType::Post)?;
+            let deposit = checked!(post - pre)?;
+            deposited = checked!(deposited + deposit)?;
+        }
+        Ok(deposited)
+    }
+
+    /// Validate a balance key. The balance of the governance account can
+    /// only increase by the funds locked in proposals.
+    fn is_valid_balance(
+        ctx: &'ctx CTX,
+        native_token_address: &Address,
+        keys_changed: &BTreeSet<storage::Key>,
+    ) -> Result<()> {
+        let balance_key =
+            TokenKeys::balance_key(native_token_address, &ADDRESS);
+
+        let pre_balance: token::Amount =
+            ctx.pre().read(&balance_key)?.unwrap_or_default();
+        let post_balance: token::Amount =
+            Self::force_read(ctx, &balance_key, ReadType::Post)?;
+
+        let mut locked_funds = token::Amount::zero();
+        for key in keys_changed {
+            if !gov_storage::is_balance_key(key) {
+                continue;
+            }
+            let pre_funds: token::Amount =
+                ctx.pre().read(key)?.unwrap_or_default();
+            let post_funds: token::Amount =
+                Self::force_read(ctx, key, ReadType::Post)?;
+            let funds = checked!(post_funds - pre_funds)?;
+            locked_funds = checked!(locked_funds + funds)?;
+        }
+
+        let balance_is_valid = post_balance > pre_balance
+            && checked!(post_balance - pre_balance)? == locked_funds;
+
+        balance_is_valid.ok_or_else(|| {
+            Error::new_alloc(format!(
+                "Invalid balance {} has been written to storage",
+                post_balance.native_denominated()
+            ))
+        })
+    }
+
+    /// Validate a cancelled key. A proposal can only be cancelled once, by
+    /// its author and before its voting start epoch.
+    fn is_valid_cancelled_key(
+        ctx: &'ctx CTX,
+        proposal_id: u64,
+        verifiers: &BTreeSet<Address>,
+    ) -> Result<()> {
+        Self::is_modifiable_by_author(ctx, proposal_id, verifiers)?;
+
+        let cancelled_key = gov_storage::get_cancelled_key(proposal_id);
+        let cancellation_epoch: Epoch =
+            Self::force_read(ctx, &cancelled_key, ReadType::Post)?;
+        let current_epoch = ctx.get_block_epoch()?;
+        (cancellation_epoch == current_epoch).ok_or_else(|| {
+            Error::new_alloc(format!(
+                "Proposal {proposal_id} must be cancelled in the current \
+                 epoch {current_epoch}, got {cancellation_epoch}"
+            ))
+        })
+    }
+
+    /// Check that an existing proposal can be modified in the tx, i.e. that
+    /// its author is a verifier, that its voting period hasn't started and
+    /// that it hasn't been cancelled.
+    fn is_modifiable_by_author(
+        ctx: &'ctx CTX,
+        proposal_id: u64,
+        verifiers: &BTreeSet<Address>,
+    ) -> Result<()> {
+        let author_key = gov_storage::get_author_key(proposal_id);
+        let author: Address =
+            Self::force_read(ctx, &author_key, ReadType::Pre)?;
+        verifiers.contains(&author).ok_or_else(|| {
+            Error::new_alloc(format!(
+                "The VP of the proposal with id {proposal_id}'s author \
+                 {author} should have been triggered"
+            ))
+        })?;
+
+        let voting_start_epoch_key =
+            gov_storage::get_voting_start_epoch_key(proposal_id);
+        let voting_start_epoch: Epoch =
+            Self::force_read(ctx, &voting_start_epoch_key, ReadType::Pre)?;
+        let current_epoch = ctx.get_block_epoch()?;
+        (current_epoch < voting_start_epoch).ok_or_else(|| {
+            Error::new_alloc(format!(
+                "Proposal {proposal_id} cannot be modified from its voting \
+                 start epoch {voting_start_epoch}"
+            ))
+        })?;
+
+        let cancelled_key = gov_storage::get_cancelled_key(proposal_id);
+        (!ctx.has_key_pre(&cancelled_key)?).ok_or_else(|| {
+            Error::new_alloc(format!(
+                "Proposal {proposal_id} has been cancelled"
+            ))
+        })
+    }
+
+    /// Validate a author key
+    pub fn is_valid_author(
+        ctx: &'ctx CTX,
+        proposal_id: u64,
+        verifiers: &BTreeSet<Address>,
     ) -> Result<()> {
         let author_key = gov_storage::get_author_key(proposal_id);
 
@@ -986,8 +1518,18 @@ where
     /// Validate a governance parameter
     pub fn is_valid_parameter(
         ctx: &'ctx CTX,
+        key: &storage::Key,
         batched_tx: &BatchedTxRef<'_>,
     ) -> Result<()> {
+        if gov_storage::is_tally_params_key(key) {
+            let tally_params: Option<TallyParams> = ctx.read_post(key)?;
+            if tally_params.is_some_and(|params| !params.is_valid()) {
+                return Err(Error::new_alloc(format!(
+                    "The tally parameters written to {key} must be fractions \
+                     between 0 and 1"
+                )));
+            }
+        }
         let BatchedTxRef { tx, cmt } = batched_tx;
         tx.data(cmt).map_or_else(
             || {
@@ -1088,10 +1630,18 @@ enum KeyType {
     #[allow(non_camel_case_types)]
     VOTE,
     #[allow(non_camel_case_types)]
+    REPRESENTATIVE_VOTE,
+    #[allow(non_camel_case_types)]
+    DELEGATE,
+    #[allow(non_camel_case_types)]
+    CONSTITUENT,
+    #[allow(non_camel_case_types)]
     CONTENT,
     #[allow(non_camel_case_types)]
     PROPOSAL_CODE,
     #[allow(non_camel_case_types)]
+    PROPOSAL_STEP_CODE,
+    #[allow(non_camel_case_types)]
     TYPE,
     #[allow(non_camel_case_types)]
     PROPOSAL_COMMIT,
@@ -1106,6 +1656,12 @@ enum KeyType {
     #[allow(non_camel_case_types)]
     BALANCE,
     #[allow(non_camel_case_types)]
+    DEPOSIT,
+    #[allow(non_camel_case_types)]
+    DEPOSIT_PENDING,
+    #[allow(non_camel_case_types)]
+    CANCELLED,
+    #[allow(non_camel_case_types)]
     AUTHOR,
     #[allow(non_camel_case_types)]
     PARAMETER,
@@ -1122,12 +1678,20 @@ impl KeyType {
     {
         if gov_storage::is_vote_key(key) {
             Self::VOTE
+        } else if gov_storage::is_representative_vote_key(key).is_some() {
+            Self::REPRESENTATIVE_VOTE
+        } else if gov_storage::is_delegate_key(key).is_some() {
+            Self::DELEGATE
+        } else if gov_storage::is_constituent_key(key).is_some() {
+            Self::CONSTITUENT
         } else if gov_storage::is_content_key(key) {
             KeyType::CONTENT
         } else if gov_storage::is_proposal_type_key(key) {
             Self::TYPE
         } else if gov_storage::is_proposal_code_key(key) {
             Self::PROPOSAL_CODE
+        } else if gov_storage::is_proposal_step_code_key(key) {
+            Self::PROPOSAL_STEP_CODE
         } else if gov_storage::is_activation_epoch_key(key) {
             KeyType::ACTIVATION_EPOCH
         } else if gov_storage::is_start_epoch_key(key) {
@@ -1138,6 +1702,12 @@ impl KeyType {
             KeyType::END_EPOCH
         } else if gov_storage::is_balance_key(key) {
             KeyType::FUNDS
+        } else if gov_storage::is_deposit_key(key).is_some() {
+            KeyType::DEPOSIT
+        } else if gov_storage::is_deposit_pending_key(key) {
+            KeyType::DEPOSIT_PENDING
+        } else if gov_storage::is_cancelled_key(key) {
+            KeyType::CANCELLED
         } else if gov_storage::is_author_key(key) {
             KeyType::AUTHOR
         } else if gov_storage::is_counter_key(key) {
@@ -1162,11 +1732,13 @@ mod test {
 
     use assert_matches::assert_matches;
     use namada_core::address::testing::{
-        established_address_1, established_address_3, nam,
+        established_address_1, established_address_2, established_address_3,
+        nam,
     };
     use namada_core::address::Address;
     use namada_core::borsh::BorshSerializeExt;
     use namada_core::chain::testing::get_dummy_header;
+    use namada_core::dec::Dec;
     use namada_core::key::testing::keypair_1;
     use namada_core::key::RefTo;
     use namada_core::parameters::Parameters;
@@ -1191,11 +1763,15 @@ mod test {
     use namada_vp::native_vp::{self, CtxPreStorageRead};
 
     use crate::storage::keys::{
-        get_activation_epoch_key, get_author_key, get_committing_proposals_key,
-        get_content_key, get_counter_key, get_funds_key, get_proposal_type_key,
+        get_activation_epoch_key, get_author_key, get_cancelled_key,
+        get_committing_proposals_key, get_constituent_key, get_content_key,
+        get_counter_key, get_default_tally_params_key, get_delegate_key,
+        get_deposit_key, get_deposit_pending_key, get_funds_key,
+        get_proposal_execution_key, get_proposal_type_key,
         get_vote_proposal_key, get_voting_end_epoch_key,
         get_voting_start_epoch_key,
     };
+    use crate::utils::{TallyParams, TallyType};
     use crate::{ProposalType, ProposalVote, ADDRESS};
 
     type CA = WasmCacheRwAccess;
@@ -1290,6 +1866,72 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_governance_invalid_tally_params_failed() {
+        let mut state = init_storage();
+
+        // The parameter change is executed by an accepted proposal
+        let proposal_id = 0_u64;
+        state
+            .db_write(
+                &get_proposal_execution_key(proposal_id),
+                ().serialize_to_vec(),
+            )
+            .unwrap();
+
+        let tally_params_key = get_default_tally_params_key();
+        let keys_changed = BTreeSet::from([tally_params_key.clone()]);
+
+        let gas_meter = RefCell::new(VpGasMeter::new_from_tx_meter(
+            &TxGasMeter::new(u64::MAX),
+        ));
+        let (vp_wasm_cache, _vp_cache_dir) =
+            wasm::compilation_cache::common::testing::vp_cache();
+        let tx_index = TxIndex::default();
+        let verifiers = BTreeSet::new();
+
+        let mut tx = Tx::from_type(TxType::Raw);
+        tx.header.chain_id = state.in_mem().chain_id.clone();
+        tx.set_code(Code::new(vec![], None));
+        tx.set_data(Data::new(proposal_id.serialize_to_vec()));
+        let batched_tx = tx.batch_ref_first_tx().unwrap();
+
+        let mut validate = |tally_params: TallyParams| {
+            let _ = state
+                .write_log_mut()
+                .write(&tally_params_key, tally_params.serialize_to_vec())
+                .unwrap();
+            let ctx = Ctx::new(
+                &ADDRESS,
+                &state,
+                batched_tx.tx,
+                batched_tx.cmt,
+                &tx_index,
+                &gas_meter,
+                &keys_changed,
+                &verifiers,
+                vp_wasm_cache.clone(),
+            );
+            GovernanceVp::validate_tx(
+                &ctx,
+                &batched_tx,
+                &keys_changed,
+                &verifiers,
+            )
+        };
+
+        // Valid tally parameters are accepted
+        let tally_params = TallyType::TwoFifths.default_params();
+        assert_matches!(validate(tally_params), Ok(_));
+
+        // A quorum over the total voting power is rejected
+        let tally_params = TallyParams {
+            quorum: Dec::new(11, 1).unwrap(),
+            ..tally_params
+        };
+        assert_matches!(validate(tally_params), Err(_));
+    }
+
     fn initialize_account_balance<S>(
         state: &mut S,
         address: &Address,
@@ -3082,4 +3724,583 @@ mod test {
             Err(_)
         );
     }
+
+    fn write_delegation(
+        state: &mut TestState,
+        delegator: &Address,
+        representative: &Address,
+    ) -> BTreeSet<Key> {
+        let epoch = state.in_mem().block.epoch.next();
+        let delegate_key = get_delegate_key(delegator, epoch);
+        let constituent_key = get_constituent_key(representative, delegator);
+        state
+            .push_action(Action::Gov(GovAction::DelegateVotes {
+                delegator: delegator.clone(),
+            }))
+            .unwrap();
+        let _ = state
+            .write_log_mut()
+            .write(
+                &delegate_key,
+                Some(representative.clone()).serialize_to_vec(),
+            )
+            .unwrap();
+        let _ = state
+            .write_log_mut()
+            .write(&constituent_key, epoch.serialize_to_vec())
+            .unwrap();
+        BTreeSet::from([delegate_key, constituent_key])
+    }
+
+    #[test]
+    fn test_governance_delegate_votes() {
+        let mut state = init_storage();
+        state.commit_block().unwrap();
+
+        let gas_meter = RefCell::new(VpGasMeter::new_from_tx_meter(
+            &TxGasMeter::new(u64::MAX),
+        ));
+        let (vp_wasm_cache, _vp_cache_dir) =
+            wasm::compilation_cache::common::testing::vp_cache();
+
+        let tx_index = TxIndex::default();
+
+        let mut tx = Tx::from_type(TxType::Raw);
+        tx.header.chain_id = state.in_mem().chain_id.clone();
+        tx.set_code(Code::new(vec![], None));
+        tx.set_data(Data::new(vec![]));
+        let batched_tx = tx.batch_ref_first_tx().unwrap();
+
+        let validator_address = established_address_1();
+        let representative = established_address_2();
+        let delegator_address = established_address_3();
+
+        let keys_changed =
+            write_delegation(&mut state, &delegator_address, &representative);
+
+        // The delegation must be authorized by the delegator
+        for (verifiers, is_valid) in [
+            (BTreeSet::from([delegator_address.clone()]), true),
+            (BTreeSet::from([representative.clone()]), false),
+        ] {
+            let ctx = Ctx::new(
+                &ADDRESS,
+                &state,
+                batched_tx.tx,
+                batched_tx.cmt,
+                &tx_index,
+                &gas_meter,
+                &keys_changed,
+                &verifiers,
+                vp_wasm_cache.clone(),
+            );
+            let result = GovernanceVp::validate_tx(
+                &ctx,
+                &batched_tx,
+                &keys_changed,
+                &verifiers,
+            );
+            assert_eq!(result.is_ok(), is_valid);
+        }
+        state.write_log_mut().drop_tx();
+
+        // Validators cannot delegate their votes
+        let keys_changed =
+            write_delegation(&mut state, &validator_address, &representative);
+        let verifiers = BTreeSet::from([validator_address]);
+        let ctx = Ctx::new(
+            &ADDRESS,
+            &state,
+            batched_tx.tx,
+            batched_tx.cmt,
+            &tx_index,
+            &gas_meter,
+            &keys_changed,
+            &verifiers,
+            vp_wasm_cache,
+        );
+        assert_matches!(
+            GovernanceVp::validate_tx(
+                &ctx,
+                &batched_tx,
+                &keys_changed,
+                &verifiers
+            ),
+            Err(_)
+        );
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn write_deposit<S>(
+        state: &mut S,
+        proposal_id: u64,
+        depositor: &Address,
+        pre_funds: u64,
+        transferred: u64,
+        deposited: u64,
+        min_funds: u64,
+    ) -> BTreeSet<Key>
+    where
+        S: State + namada_tx::action::Write,
+    {
+        let funds_key = get_funds_key(proposal_id);
+        let deposit_key = get_deposit_key(proposal_id, depositor);
+        let deposit_pending_key = get_deposit_pending_key(proposal_id);
+
+        transfer(state, depositor, &ADDRESS, transferred);
+
+        state
+            .push_action(Action::Gov(GovAction::DepositProposal {
+                id: proposal_id,
+                depositor: depositor.clone(),
+            }))
+            .unwrap();
+
+        let post_funds = pre_funds + transferred;
+        let _ = state
+            .write_log_mut()
+            .write(
+                &funds_key,
+                token::Amount::native_whole(post_funds).serialize_to_vec(),
+            )
+            .unwrap();
+        let _ = state
+            .write_log_mut()
+            .write(
+                &deposit_key,
+                token::Amount::native_whole(deposited).serialize_to_vec(),
+            )
+            .unwrap();
+        if post_funds >= min_funds {
+            let _ = state.write_log_mut().delete(&deposit_pending_key).unwrap();
+        }
+
+        BTreeSet::from([funds_key, deposit_key, deposit_pending_key])
+    }
+
+    #[test]
+    fn test_governance_deposit_proposal() {
+        let mut state = init_storage();
+
+        let proposal_id = 0;
+        let activation_epoch = 19;
+
+        let gas_meter = RefCell::new(VpGasMeter::new_from_tx_meter(
+            &TxGasMeter::new(u64::MAX),
+        ));
+        let (vp_wasm_cache, _vp_cache_dir) =
+            wasm::compilation_cache::common::testing::vp_cache();
+
+        let tx_index = TxIndex::default();
+
+        let signer = keypair_1();
+        let signer_address = Address::from(&signer.clone().ref_to());
+        let depositor = established_address_2();
+
+        initialize_account_balance(
+            &mut state,
+            &signer_address.clone(),
+            token::Amount::native_whole(510),
+        );
+        initialize_account_balance(
+            &mut state,
+            &depositor,
+            token::Amount::native_whole(500),
+        );
+        initialize_account_balance(
+            &mut state,
+            &ADDRESS,
+            token::Amount::native_whole(0),
+        );
+        state.commit_block().unwrap();
+
+        let mut tx = Tx::from_type(TxType::Raw);
+        tx.header.chain_id = state.in_mem().chain_id.clone();
+        tx.set_code(Code::new(vec![], None));
+        tx.set_data(Data::new(vec![]));
+        let batched_tx = tx.batch_ref_first_tx().unwrap();
+
+        // A proposal below the minimum funds must be pending deposits
+        init_proposal(
+            &mut state,
+            proposal_id,
+            200,
+            3,
+            9,
+            activation_epoch,
+            &signer_address,
+            false,
+        );
+        let mut keys_changed = get_proposal_keys(proposal_id, activation_epoch);
+        let verifiers = BTreeSet::from([signer_address.clone()]);
+        for (is_pending, is_valid) in [(false, false), (true, true)] {
+            let deposit_pending_key = get_deposit_pending_key(proposal_id);
+            if is_pending {
+                let _ = state
+                    .write_log_mut()
+                    .write(&deposit_pending_key, Epoch(3).serialize_to_vec())
+                    .unwrap();
+                keys_changed.insert(deposit_pending_key);
+            }
+            let ctx = Ctx::new(
+                &ADDRESS,
+                &state,
+                batched_tx.tx,
+                batched_tx.cmt,
+                &tx_index,
+                &gas_meter,
+                &keys_changed,
+                &verifiers,
+                vp_wasm_cache.clone(),
+            );
+            let result = GovernanceVp::validate_tx(
+                &ctx,
+                &batched_tx,
+                &keys_changed,
+                &verifiers,
+            );
+            assert_eq!(result.is_ok(), is_valid);
+        }
+        state.write_log_mut().commit_batch_and_current_tx();
+        state.commit_block().unwrap();
+
+        // The deposit must match the increase of the funds and be authorized
+        // by the depositor
+        for (deposited, verifiers, is_valid) in [
+            (200, BTreeSet::from([depositor.clone()]), false),
+            (300, BTreeSet::from([signer_address.clone()]), false),
+            (300, BTreeSet::from([depositor.clone()]), true),
+        ] {
+            let keys_changed = write_deposit(
+                &mut state,
+                proposal_id,
+                &depositor,
+                200,
+                300,
+                deposited,
+                500,
+            );
+            let ctx = Ctx::new(
+                &ADDRESS,
+                &state,
+                batched_tx.tx,
+                batched_tx.cmt,
+                &tx_index,
+                &gas_meter,
+                &keys_changed,
+                &verifiers,
+                vp_wasm_cache.clone(),
+            );
+            let result = GovernanceVp::validate_tx(
+                &ctx,
+                &batched_tx,
+                &keys_changed,
+                &verifiers,
+            );
+            assert_eq!(result.is_ok(), is_valid);
+            state.write_log_mut().drop_tx();
+        }
+
+        // Deposits are rejected from the voting start epoch
+        state.in_mem_mut().block.epoch = Epoch(3);
+        let keys_changed = write_deposit(
+            &mut state,
+            proposal_id,
+            &depositor,
+            200,
+            300,
+            300,
+            500,
+        );
+        let verifiers = BTreeSet::from([depositor]);
+        let ctx = Ctx::new(
+            &ADDRESS,
+            &state,
+            batched_tx.tx,
+            batched_tx.cmt,
+            &tx_index,
+            &gas_meter,
+            &keys_changed,
+            &verifiers,
+            vp_wasm_cache,
+        );
+        assert_matches!(
+            GovernanceVp::validate_tx(
+                &ctx,
+                &batched_tx,
+                &keys_changed,
+                &verifiers
+            ),
+            Err(_)
+        );
+    }
+
+    fn write_cancellation<S>(
+        state: &mut S,
+        proposal_id: u64,
+        author: &Address,
+        epoch: Epoch,
+    ) -> BTreeSet<Key>
+    where
+        S: State + namada_tx::action::Write,
+    {
+        let cancelled_key = get_cancelled_key(proposal_id);
+        state
+            .push_action(Action::Gov(GovAction::CancelProposal {
+                id: proposal_id,
+                author: author.clone(),
+            }))
+            .unwrap();
+        let _ = state
+            .write_log_mut()
+            .write(&cancelled_key, epoch.serialize_to_vec())
+            .unwrap();
+        BTreeSet::from([cancelled_key])
+    }
+
+    fn write_amendment<S>(
+        state: &mut S,
+        proposal_id: u64,
+        author: &Address,
+        content: Vec<u8>,
+    ) -> BTreeSet<Key>
+    where
+        S: State + namada_tx::action::Write,
+    {
+        let content_key = get_content_key(proposal_id);
+        state
+            .push_action(Action::Gov(GovAction::AmendProposal {
+                id: proposal_id,
+                author: author.clone(),
+            }))
+            .unwrap();
+        let _ = state.write_log_mut().write(&content_key, content).unwrap();
+        BTreeSet::from([content_key])
+    }
+
+    #[test]
+    fn test_governance_cancel_and_amend_proposal() {
+        let mut state = init_storage();
+
+        let proposal_id = 0;
+        let activation_epoch = 19;
+
+        let gas_meter = RefCell::new(VpGasMeter::new_from_tx_meter(
+            &TxGasMeter::new(u64::MAX),
+        ));
+        let (vp_wasm_cache, _vp_cache_dir) =
+            wasm::compilation_cache::common::testing::vp_cache();
+
+        let tx_index = TxIndex::default();
+
+        let signer = keypair_1();
+        let signer_address = Address::from(&signer.clone().ref_to());
+        let other = established_address_2();
+
+        initialize_account_balance(
+            &mut state,
+            &signer_address.clone(),
+            token::Amount::native_whole(510),
+        );
+        initialize_account_balance(
+            &mut state,
+            &ADDRESS,
+            token::Amount::native_whole(0),
+        );
+        state.commit_block().unwrap();
+
+        let mut tx = Tx::from_type(TxType::Raw);
+        tx.header.chain_id = state.in_mem().chain_id.clone();
+        tx.set_code(Code::new(vec![], None));
+        tx.set_data(Data::new(vec![]));
+        let batched_tx = tx.batch_ref_first_tx().unwrap();
+
+        init_proposal(
+            &mut state,
+            proposal_id,
+            500,
+            3,
+            9,
+            activation_epoch,
+            &signer_address,
+            false,
+        );
+        state.write_log_mut().commit_batch_and_current_tx();
+        state.commit_block().unwrap();
+        let current_epoch = state.in_mem().block.epoch;
+
+        // Only the author can amend the content of the proposal, within the
+        // maximum content size
+        for (content_len, verifiers, is_valid) in [
+            (10, BTreeSet::from([other.clone()]), false),
+            (10_001, BTreeSet::from([signer_address.clone()]), false),
+            (10, BTreeSet::from([signer_address.clone()]), true),
+        ] {
+            let author = verifiers.first().unwrap().clone();
+            let keys_changed = write_amendment(
+                &mut state,
+                proposal_id,
+                &author,
+                vec![0; content_len],
+            );
+            let ctx = Ctx::new(
+                &ADDRESS,
+                &state,
+                batched_tx.tx,
+                batched_tx.cmt,
+                &tx_index,
+                &gas_meter,
+                &keys_changed,
+                &verifiers,
+                vp_wasm_cache.clone(),
+            );
+            let result = GovernanceVp::validate_tx(
+                &ctx,
+                &batched_tx,
+                &keys_changed,
+                &verifiers,
+            );
+            assert_eq!(result.is_ok(), is_valid);
+            state.write_log_mut().drop_tx();
+        }
+
+        // No deposit can be made to a proposal that is cancelled in the same
+        // tx
+        let mut keys_changed = write_cancellation(
+            &mut state,
+            proposal_id,
+            &signer_address,
+            current_epoch,
+        );
+        keys_changed.extend(write_deposit(
+            &mut state,
+            proposal_id,
+            &signer_address,
+            500,
+            10,
+            10,
+            500,
+        ));
+        let verifiers = BTreeSet::from([signer_address.clone()]);
+        let ctx = Ctx::new(
+            &ADDRESS,
+            &state,
+            batched_tx.tx,
+            batched_tx.cmt,
+            &tx_index,
+            &gas_meter,
+            &keys_changed,
+            &verifiers,
+            vp_wasm_cache.clone(),
+        );
+        assert_matches!(
+            GovernanceVp::validate_tx(
+                &ctx,
+                &batched_tx,
+                &keys_changed,
+                &verifiers
+            ),
+            Err(_)
+        );
+        state.write_log_mut().drop_tx();
+
+        // Only the author can cancel the proposal, in the current epoch
+        for (epoch, verifiers, is_valid) in [
+            (current_epoch, BTreeSet::from([other.clone()]), false),
+            (
+                current_epoch.next(),
+                BTreeSet::from([signer_address.clone()]),
+                false,
+            ),
+            (
+                current_epoch,
+                BTreeSet::from([signer_address.clone()]),
+                true,
+            ),
+        ] {
+            let author = verifiers.first().unwrap().clone();
+            let keys_changed =
+                write_cancellation(&mut state, proposal_id, &author, epoch);
+            let ctx = Ctx::new(
+                &ADDRESS,
+                &state,
+                batched_tx.tx,
+                batched_tx.cmt,
+                &tx_index,
+                &gas_meter,
+                &keys_changed,
+                &verifiers,
+                vp_wasm_cache.clone(),
+            );
+            let result = GovernanceVp::validate_tx(
+                &ctx,
+                &batched_tx,
+                &keys_changed,
+                &verifiers,
+            );
+            assert_eq!(result.is_ok(), is_valid);
+            if is_valid {
+                state.write_log_mut().commit_batch_and_current_tx();
+            } else {
+                state.write_log_mut().drop_tx();
+            }
+        }
+        state.commit_block().unwrap();
+
+        // A cancelled proposal cannot be amended
+        let keys_changed =
+            write_amendment(&mut state, proposal_id, &signer_address, vec![1]);
+        let verifiers = BTreeSet::from([signer_address.clone()]);
+        let ctx = Ctx::new(
+            &ADDRESS,
+            &state,
+            batched_tx.tx,
+            batched_tx.cmt,
+            &tx_index,
+            &gas_meter,
+            &keys_changed,
+            &verifiers,
+            vp_wasm_cache.clone(),
+        );
+        assert_matches!(
+            GovernanceVp::validate_tx(
+                &ctx,
+                &batched_tx,
+                &keys_changed,
+                &verifiers
+            ),
+            Err(_)
+        );
+        state.write_log_mut().drop_tx();
+
+        // Nor can a proposal whose voting period started
+        let _ = state
+            .write_log_mut()
+            .delete(&get_cancelled_key(proposal_id))
+            .unwrap();
+        state.write_log_mut().commit_batch_and_current_tx();
+        state.in_mem_mut().block.epoch = Epoch(3);
+        let keys_changed =
+            write_amendment(&mut state, proposal_id, &signer_address, vec![1]);
+        let ctx = Ctx::new(
+            &ADDRESS,
+            &state,
+            batched_tx.tx,
+            batched_tx.cmt,
+            &tx_index,
+            &gas_meter,
+            &keys_changed,
+            &verifiers,
+            vp_wasm_cache,
+        );
+        assert_matches!(
+            GovernanceVp::validate_tx(
+                &ctx,
+                &batched_tx,
+                &keys_changed,
+                &verifiers
+            ),
+            Err(_)
+        );
+    }
 }
@@ -59,6 +59,15 @@ where
         self.state.iter_prefix(prefix)
     }
 
+    fn iter_prefix_from<'iter>(
+        &'iter self,
+        prefix: &Key,
+        from: &str,
+        reverse: bool,
+    ) -> Result<Option<Self::PrefixIter<'iter>>> {
+        self.state.iter_prefix_from(prefix, from, reverse)
+    }
+
     fn iter_next<'iter>(
         &'iter self,
         iter: &mut Self::PrefixIter<'iter>,
@@ -22,12 +22,14 @@ impl InitAccount {
         public_keys: Vec<common::PublicKey>,
         vp_code_hash: Hash,
         threshold: u8,
+        weights: Vec<u8>,
         args: GlobalArgs,
     ) -> Self {
         let init_account = namada_sdk::account::InitAccount {
             public_keys,
             vp_code_hash,
             threshold,
+            weights,
         };
 
         Self(transaction::build_tx(
@@ -175,6 +177,7 @@ impl UpdateAccount {
         vp_code_hash: Option<Hash>,
         public_keys: Vec<common::PublicKey>,
         threshold: Option<u8>,
+        weights: Vec<u8>,
         args: GlobalArgs,
     ) -> Self {
         let update_account = namada_sdk::account::UpdateAccount {
@@ -182,6 +185,7 @@ impl UpdateAccount {
             vp_code_hash,
             public_keys,
             threshold,
+            weights,
         };
 
         Self(transaction::build_tx(
@@ -36,6 +36,7 @@ impl InitProposal {
             voting_start_epoch,
             voting_end_epoch,
             activation_epoch,
+            deposit: None,
         };
 
         Self(transaction::build_tx(
@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use namada_sdk::address::Address;
 use namada_sdk::dec::Dec;
 use namada_sdk::hash::Hash;
@@ -465,6 +467,7 @@ impl ChangeMetaData {
         discord_handle: Option<String>,
         avatar: Option<String>,
         name: Option<String>,
+        reward_destination: Option<BTreeMap<Address, Dec>>,
         commission_rate: Option<Dec>,
         args: GlobalArgs,
     ) -> Self {
@@ -476,6 +479,7 @@ impl ChangeMetaData {
             discord_handle,
             avatar,
             name,
+            reward_destination,
             commission_rate,
         };
 
@@ -634,6 +634,7 @@ impl Default for BenchShell {
                 voting_start_epoch,
                 voting_end_epoch: voting_start_epoch.unchecked_add(3_u64),
                 activation_epoch: voting_start_epoch.unchecked_add(9_u64),
+                deposit: None,
             },
             None,
             Some(vec![content_section]),
@@ -900,6 +901,7 @@ impl Client for BenchShell {
                 vp_wasm_cache: shell.vp_wasm_cache.read_only(),
                 tx_wasm_cache: shell.tx_wasm_cache.read_only(),
                 storage_read_past_height_limit: None,
+                tx_history_index: false,
             };
             RPC.handle(ctx, &request)
         }
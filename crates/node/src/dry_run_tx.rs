@@ -241,6 +241,7 @@ mod test {
                     vp_wasm_cache: self.vp_wasm_cache.clone(),
                     tx_wasm_cache: self.tx_wasm_cache.clone(),
                     storage_read_past_height_limit: None,
+                    tx_history_index: false,
                 };
                 self.rpc.handle(ctx, &request)
             }
@@ -1,7 +1,9 @@
 pub mod control;
 pub mod events;
+pub mod quorum;
 pub mod test_tools;
 
+use std::num::NonZeroUsize;
 use std::ops::ControlFlow;
 
 use async_trait::async_trait;
@@ -21,6 +23,7 @@ use tokio::sync::mpsc::Sender as BoundedSender;
 use tokio::task::LocalSet;
 
 use self::events::PendingEvent;
+use self::quorum::{endpoint_stats, QuorumClient};
 use super::abortable::AbortableSpawner;
 use crate::oracle::control::Command;
 
@@ -36,6 +39,16 @@ pub enum Error {
         "Couldn't check for events ({0} from {1}) with the RPC endpoint: {2}"
     )]
     CheckEvents(String, Address, String),
+    #[error("Couldn't get the hash of a block with the RPC endpoint: {0}")]
+    BlockHash(String),
+    #[error(
+        "Less than {quorum} RPC endpoints agreed on the hash and the events \
+         of Ethereum block {block}"
+    )]
+    NoQuorum {
+        block: ethereum_structs::BlockHeight,
+        quorum: usize,
+    },
     #[error("Could not send all bridge events ({0} from {1}) to the shell")]
     Channel(String, Address),
     #[error(
@@ -89,6 +102,13 @@ pub trait RpcClient {
         abi_signature: &str,
     ) -> Result<Vec<Self::Log>, Error>;
 
+    /// Get the hash of the block at the given height, or `None` if the
+    /// fullnode doesn't know about the block.
+    async fn block_hash(
+        &self,
+        block: ethereum_structs::BlockHeight,
+    ) -> Result<Option<[u8; 32]>, Error>;
+
     /// Check if the fullnode we are connected to is syncing or is up
     /// to date with the Ethereum (an return the block height).
     ///
@@ -124,12 +144,7 @@ impl RpcClient for Provider<Http> {
         contract_address: Address,
         abi_signature: &str,
     ) -> Result<Vec<Self::Log>, Error> {
-        let height = {
-            let n: Uint256 = block.into();
-            let n: u64 =
-                n.0.try_into().expect("Ethereum block number overflow");
-            n
-        };
+        let height = block_number(block);
         self.get_logs(
             &ethers::types::Filter::new()
                 .from_block(height)
@@ -147,6 +162,16 @@ impl RpcClient for Provider<Http> {
         })
     }
 
+    async fn block_hash(
+        &self,
+        block: ethereum_structs::BlockHeight,
+    ) -> Result<Option<[u8; 32]>, Error> {
+        self.get_block(block_number(block))
+            .await
+            .map(|block| block.and_then(|block| block.hash).map(|hash| hash.0))
+            .map_err(|error| Error::BlockHash(error.to_string()))
+    }
+
     async fn syncing(
         &self,
         last_processed_block: Option<&ethereum_structs::BlockHeight>,
@@ -172,11 +197,20 @@ impl RpcClient for Provider<Http> {
     fn may_recover(&self, error: &Error) -> bool {
         !matches!(
             error,
-            Error::Timeout | Error::Channel(_, _) | Error::CheckEvents(_, _, _)
+            Error::Timeout
+                | Error::Channel(_, _)
+                | Error::CheckEvents(_, _, _)
+                | Error::BlockHash(_)
         )
     }
 }
 
+/// Convert an Ethereum block height to a block number.
+fn block_number(block: ethereum_structs::BlockHeight) -> u64 {
+    let n: Uint256 = block.into();
+    n.0.try_into().expect("Ethereum block number overflow")
+}
+
 /// A client that can talk to geth and parse
 /// and relay events relevant to Namada to the
 /// ledger process
@@ -276,15 +310,20 @@ async fn await_initial_configuration(
 }
 
 /// Set up an Oracle and run the process where the Oracle
-/// processes and forwards Ethereum events to the ledger
+/// processes and forwards Ethereum events to the ledger.
+///
+/// The oracle follows the given RPC endpoints, in order of preference,
+/// and only processes the blocks whose hash and events a `quorum` of
+/// them agree on.
 pub fn run_oracle<C: RpcClient>(
-    url: impl AsRef<str>,
+    urls: Vec<String>,
+    quorum: NonZeroUsize,
     sender: BoundedSender<EthereumEvent>,
     control: control::Receiver,
     last_processed_block: last_processed_block::Sender,
+    endpoint_stats: endpoint_stats::Sender,
     spawner: &mut AbortableSpawner,
 ) {
-    let url = url.as_ref().to_owned();
     spawner
         .abortable("Ethereum Oracle", move |aborter| {
             let rt = tokio::runtime::Handle::current();
@@ -292,12 +331,19 @@ pub fn run_oracle<C: RpcClient>(
                 LocalSet::new()
                     .run_until(async move {
                         tracing::info!(
-                            ?url,
+                            ?urls,
+                            %quorum,
                             "Ethereum event oracle is starting"
                         );
 
-                        let oracle = Oracle::<C>::new(
-                            Either::Right(&url),
+                        let clients =
+                            urls.iter().map(|url| C::new_client(url)).collect();
+                        let oracle = Oracle::new(
+                            Either::Left(QuorumClient::<C>::new(
+                                clients,
+                                quorum,
+                                endpoint_stats,
+                            )),
                             sender,
                             last_processed_block,
                             DEFAULT_BACKOFF,
@@ -307,7 +353,7 @@ pub fn run_oracle<C: RpcClient>(
                         run_oracle_aux(oracle).await;
 
                         tracing::info!(
-                            ?url,
+                            ?urls,
                             "Ethereum event oracle is no longer running"
                         );
                     })
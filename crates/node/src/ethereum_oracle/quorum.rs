@@ -0,0 +1,442 @@
+//! An RPC client that follows several Ethereum RPC endpoints, failing over
+//! to the next endpoint when one returns an error, and cross-checking the
+//! hash and the events of every block across a quorum of them.
+
+use std::num::NonZeroUsize;
+
+use async_trait::async_trait;
+use ethabi::Address;
+use futures::future::join_all;
+use namada_sdk::control_flow::time::{Duration, Instant};
+use namada_sdk::ethereum_structs;
+
+use super::{Error, IntoEthAbiLog, RpcClient, SyncStatus};
+
+pub mod endpoint_stats {
+    //! Functionality to do with publishing the statistics of the RPC
+    //! endpoints followed by the oracle.
+    use tokio::sync::watch;
+
+    /// Statistics of a single RPC endpoint
+    #[derive(Clone, Debug, Default, PartialEq, Eq)]
+    pub struct EndpointStats {
+        /// The number of requests to the endpoint that failed
+        pub errors: u64,
+        /// The number of responses of the endpoint that disagreed with the
+        /// ones of a quorum of endpoints
+        pub divergences: u64,
+    }
+
+    /// Statistics of the RPC endpoints followed by the oracle
+    #[derive(Clone, Debug, Default, PartialEq, Eq)]
+    pub struct Stats {
+        /// The statistics of each endpoint, in order of preference
+        pub endpoints: Vec<EndpointStats>,
+        /// The number of times the endpoints failed to reach a quorum on the
+        /// hash and the events of a block
+        pub failed_quorums: u64,
+    }
+
+    pub type Sender = watch::Sender<Stats>;
+    pub type Receiver = watch::Receiver<Stats>;
+
+    /// Construct a [`tokio::sync::watch`] channel to publish the statistics
+    /// of the RPC endpoints followed by the oracle.
+    pub fn channel() -> (Sender, Receiver) {
+        watch::channel(Stats::default())
+    }
+}
+
+/// The response of an RPC endpoint to a query for the events of a block
+#[derive(Debug, PartialEq)]
+struct BlockEvents {
+    /// The hash of the block, if it was requested
+    block_hash: Option<[u8; 32]>,
+    /// The event logs emitted in the block
+    logs: Vec<ethabi::RawLog>,
+}
+
+/// A client of several Ethereum RPC endpoints, in order of preference.
+///
+/// The events of a block are only returned once a `quorum` of the endpoints
+/// returned the same events and block hash for it. The endpoints are
+/// queried one after the other, so with a quorum of one, the secondary
+/// endpoints are only queried when the ones before them fail.
+pub struct QuorumClient<C> {
+    /// The clients of each endpoint
+    clients: Vec<C>,
+    /// The number of endpoints that must agree on a response
+    quorum: usize,
+    /// Where to publish the statistics of the endpoints
+    stats: endpoint_stats::Sender,
+}
+
+impl<C> QuorumClient<C> {
+    /// Create a client of the given endpoints, which publishes their
+    /// statistics to `stats`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the quorum is greater than the number of endpoints, which
+    /// the ledger config rejects when it's read.
+    pub fn new(
+        clients: Vec<C>,
+        quorum: NonZeroUsize,
+        stats: endpoint_stats::Sender,
+    ) -> Self {
+        let quorum = quorum.get();
+        assert!(
+            quorum <= clients.len(),
+            "The quorum of {quorum} Ethereum RPC endpoints can't be reached \
+             with {} endpoints",
+            clients.len()
+        );
+        stats.send_modify(|stats| {
+            stats.endpoints = vec![Default::default(); clients.len()];
+        });
+        Self {
+            clients,
+            quorum,
+            stats,
+        }
+    }
+
+    /// Record a failed request to the endpoint at the given index
+    fn record_error(&self, index: usize, error: &Error) {
+        tracing::warn!(endpoint = index, %error, "Ethereum RPC endpoint failed");
+        self.stats.send_modify(|stats| {
+            let endpoint = &mut stats.endpoints[index];
+            endpoint.errors = endpoint.errors.saturating_add(1);
+        });
+    }
+}
+
+impl<C: RpcClient> QuorumClient<C> {
+    /// Query an endpoint for the events of a block and, if the response
+    /// must be cross-checked with other endpoints, for the hash of the block.
+    async fn block_events(
+        &self,
+        client: &C,
+        block: ethereum_structs::BlockHeight,
+        address: Address,
+        abi_signature: &str,
+    ) -> Result<BlockEvents, Error> {
+        let block_hash = if self.quorum > 1 {
+            client.block_hash(block.clone()).await?
+        } else {
+            None
+        };
+        let logs = client
+            .check_events_in_block(block, address, abi_signature)
+            .await?
+            .into_iter()
+            .map(IntoEthAbiLog::into_ethabi_log)
+            .collect();
+        Ok(BlockEvents { block_hash, logs })
+    }
+}
+
+#[async_trait(?Send)]
+impl<C: RpcClient> RpcClient for QuorumClient<C> {
+    type Log = ethabi::RawLog;
+
+    fn new_client(rpc_url: &str) -> Self
+    where
+        Self: Sized,
+    {
+        let (stats, _) = endpoint_stats::channel();
+        Self::new(vec![C::new_client(rpc_url)], NonZeroUsize::MIN, stats)
+    }
+
+    async fn check_events_in_block(
+        &self,
+        block: ethereum_structs::BlockHeight,
+        address: Address,
+        abi_signature: &str,
+    ) -> Result<Vec<Self::Log>, Error> {
+        // distinct responses, along with the endpoints that returned them
+        let mut responses: Vec<(BlockEvents, Vec<usize>)> = vec![];
+        let mut first_error = None;
+        for (index, client) in self.clients.iter().enumerate() {
+            let events = match self
+                .block_events(client, block.clone(), address, abi_signature)
+                .await
+            {
+                Ok(events) => events,
+                Err(error) => {
+                    self.record_error(index, &error);
+                    first_error.get_or_insert(error);
+                    continue;
+                }
+            };
+            let position =
+                match responses.iter().position(|(e, _)| *e == events) {
+                    Some(position) => position,
+                    None => {
+                        responses.push((events, vec![]));
+                        responses.len().saturating_sub(1)
+                    }
+                };
+            let endpoints = &mut responses[position].1;
+            endpoints.push(index);
+            if endpoints.len() < self.quorum {
+                continue;
+            }
+            let (events, _) = responses.swap_remove(position);
+            let diverging: Vec<_> = responses
+                .into_iter()
+                .flat_map(|(_, endpoints)| endpoints)
+                .collect();
+            if !diverging.is_empty() {
+                tracing::warn!(
+                    ?block,
+                    abi_signature,
+                    ?diverging,
+                    "Ethereum RPC endpoints disagreed with a quorum of \
+                     endpoints on the hash or the events of a block"
+                );
+                self.stats.send_modify(|stats| {
+                    for index in diverging {
+                        let endpoint = &mut stats.endpoints[index];
+                        endpoint.divergences =
+                            endpoint.divergences.saturating_add(1);
+                    }
+                });
+            }
+            return Ok(events.logs);
+        }
+        let responded: usize =
+            responses.iter().map(|(_, endpoints)| endpoints.len()).sum();
+        match first_error {
+            // not enough endpoints are available to reach a quorum
+            Some(error) if responded < self.quorum => Err(error),
+            _ => {
+                tracing::warn!(
+                    ?block,
+                    abi_signature,
+                    responses = responses.len(),
+                    "Ethereum RPC endpoints failed to reach a quorum on the \
+                     hash and the events of a block"
+                );
+                self.stats.send_modify(|stats| {
+                    stats.failed_quorums =
+                        stats.failed_quorums.saturating_add(1);
+                });
+                Err(Error::NoQuorum {
+                    block,
+                    quorum: self.quorum,
+                })
+            }
+        }
+    }
+
+    async fn block_hash(
+        &self,
+        block: ethereum_structs::BlockHeight,
+    ) -> Result<Option<[u8; 32]>, Error> {
+        let mut first_error = None;
+        for (index, client) in self.clients.iter().enumerate() {
+            match client.block_hash(block.clone()).await {
+                Ok(hash) => return Ok(hash),
+                Err(error) => {
+                    self.record_error(index, &error);
+                    first_error.get_or_insert(error);
+                }
+            }
+        }
+        Err(first_error.expect("There is at least one Ethereum RPC endpoint"))
+    }
+
+    async fn syncing(
+        &self,
+        last_processed_block: Option<&ethereum_structs::BlockHeight>,
+        backoff: Duration,
+        deadline: Instant,
+    ) -> Result<SyncStatus, Error> {
+        // query the endpoints concurrently, such that an unresponsive
+        // endpoint doesn't exhaust the deadline of the others
+        let statuses = join_all(self.clients.iter().map(|client| {
+            client.syncing(last_processed_block, backoff, deadline)
+        }))
+        .await;
+        let mut heights = vec![];
+        let mut syncing = false;
+        let mut first_error = None;
+        for (index, status) in statuses.into_iter().enumerate() {
+            match status {
+                Ok(SyncStatus::AtHeight(height)) => heights.push(height),
+                Ok(SyncStatus::Syncing) => syncing = true,
+                Err(error) => {
+                    self.record_error(index, &error);
+                    first_error.get_or_insert(error);
+                }
+            }
+        }
+        // the highest block that a quorum of the endpoints have reached
+        heights.sort_unstable_by(|a, b| b.cmp(a));
+        if let Some(height) =
+            heights.into_iter().nth(self.quorum.saturating_sub(1))
+        {
+            return Ok(SyncStatus::AtHeight(height));
+        }
+        match first_error {
+            Some(error) if !syncing => Err(error),
+            _ => Ok(SyncStatus::Syncing),
+        }
+    }
+
+    fn may_recover(&self, error: &Error) -> bool {
+        match error {
+            // endpoints that disagree on a block may agree on it once they
+            // have synced with the rest of the network
+            Error::NoQuorum { .. } => true,
+            error => {
+                self.clients.iter().all(|client| client.may_recover(error))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_quorum {
+    use ethbridge_bridge_events::TransferToChainFilter;
+    use num256::Uint256;
+    use tokio::sync::oneshot::{channel, Receiver};
+
+    use super::*;
+    use crate::ethereum_oracle::test_tools::event_log::GetLog;
+    use crate::ethereum_oracle::test_tools::mock_web3_client::{
+        event_signature, TestCmd, Web3Client, Web3Controller,
+    };
+
+    /// Set up a quorum client of the given number of mock endpoints
+    fn setup(
+        endpoints: usize,
+        quorum: usize,
+    ) -> (
+        QuorumClient<Web3Client>,
+        Vec<Web3Controller>,
+        endpoint_stats::Receiver,
+    ) {
+        let (clients, controllers) = (0..endpoints)
+            .map(|_| {
+                let (_, client) = Web3Client::setup();
+                let controller = client.controller();
+                (client, controller)
+            })
+            .unzip();
+        let (stats_sender, stats_receiver) = endpoint_stats::channel();
+        let client = QuorumClient::new(
+            clients,
+            NonZeroUsize::new(quorum).unwrap(),
+            stats_sender,
+        );
+        (client, controllers, stats_receiver)
+    }
+
+    /// Make the endpoint of the given controller emit a new event at
+    /// block height 1
+    fn new_event(controller: &Web3Controller, nonce: u64) -> Receiver<()> {
+        let (seen, seen_recv) = channel();
+        controller.apply_cmd(TestCmd::NewEvent {
+            event_type: event_signature::<TransferToChainFilter>(),
+            log: TransferToChainFilter {
+                nonce: nonce.into(),
+                transfers: vec![],
+                confirmations: 100.into(),
+            }
+            .get_log(),
+            height: 1,
+            seen,
+        });
+        seen_recv
+    }
+
+    /// Check for new events at block height 1
+    async fn check_events(
+        client: &QuorumClient<Web3Client>,
+    ) -> Result<Vec<ethabi::RawLog>, Error> {
+        client
+            .check_events_in_block(
+                1u64.into(),
+                Address::zero(),
+                &event_signature::<TransferToChainFilter>(),
+            )
+            .await
+    }
+
+    /// Test that the events that a quorum of endpoints agree on are
+    /// returned, and that the endpoints that disagree are reported
+    #[tokio::test]
+    async fn test_quorum_of_events() {
+        let (client, controllers, stats) = setup(3, 2);
+        let _seen = [
+            new_event(&controllers[0], 0),
+            new_event(&controllers[1], 1),
+            new_event(&controllers[2], 1),
+        ];
+        let logs = check_events(&client).await.expect("Test failed");
+        assert_eq!(
+            logs,
+            vec![TransferToChainFilter {
+                nonce: 1.into(),
+                transfers: vec![],
+                confirmations: 100.into(),
+            }
+            .get_log()]
+        );
+        let stats = stats.borrow();
+        assert_eq!(stats.endpoints[0].divergences, 1);
+        assert_eq!(stats.endpoints[1].divergences, 0);
+        assert_eq!(stats.endpoints[2].divergences, 0);
+        assert_eq!(stats.failed_quorums, 0);
+    }
+
+    /// Test that endpoints on different forks of Ethereum don't reach a
+    /// quorum, even if they agree on the events of a block
+    #[tokio::test]
+    async fn test_no_quorum_across_forks() {
+        let (client, controllers, stats) = setup(2, 2);
+        controllers[1].apply_cmd(TestCmd::Fork(1));
+        let result = check_events(&client).await;
+        assert!(matches!(result, Err(Error::NoQuorum { quorum: 2, .. })));
+        assert!(client.may_recover(&result.unwrap_err()));
+        assert_eq!(stats.borrow().failed_quorums, 1);
+    }
+
+    /// Test that the client fails over to the next endpoint if an endpoint
+    /// is unresponsive, unless too few endpoints are left to reach a quorum
+    #[tokio::test]
+    async fn test_failover() {
+        let (client, controllers, stats) = setup(2, 1);
+        controllers[0].apply_cmd(TestCmd::Unresponsive);
+        let _seen = new_event(&controllers[1], 0);
+        let logs = check_events(&client).await.expect("Test failed");
+        assert_eq!(logs.len(), 1);
+        assert_eq!(stats.borrow().endpoints[0].errors, 1);
+        assert_eq!(stats.borrow().endpoints[1].errors, 0);
+
+        let (client, controllers, stats) = setup(2, 2);
+        controllers[0].apply_cmd(TestCmd::Unresponsive);
+        let result = check_events(&client).await;
+        assert!(matches!(result, Err(Error::BlockHash(_))));
+        assert_eq!(stats.borrow().failed_quorums, 0);
+    }
+
+    /// Test that the latest block of the endpoints is the highest block that
+    /// a quorum of them have reached
+    #[tokio::test]
+    async fn test_syncing_quorum() {
+        let (client, controllers, _stats) = setup(3, 2);
+        for (controller, height) in controllers.iter().zip([100u32, 80, 90]) {
+            controller.apply_cmd(TestCmd::NewHeight(Uint256::from(height)));
+        }
+        let status = client
+            .syncing(None, Duration::from_secs(1), Instant::now())
+            .await
+            .expect("Test failed");
+        assert!(
+            matches!(status, SyncStatus::AtHeight(height) if height == Uint256::from(90u32))
+        );
+    }
+}
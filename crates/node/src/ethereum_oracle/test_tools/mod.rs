@@ -87,6 +87,9 @@ pub mod mock_web3_client {
         Normal,
         Unresponsive,
         NewHeight(Uint256),
+        /// Follow a fork of the chain, whose blocks have different hashes
+        /// from the ones of other forks
+        Fork(u8),
         NewEvent {
             event_type: MockEventType,
             log: ethabi::RawLog,
@@ -115,6 +118,7 @@ pub mod mock_web3_client {
                 TestCmd::NewHeight(height) => {
                     oracle.latest_block_height = height
                 }
+                TestCmd::Fork(fork) => oracle.fork = fork,
                 TestCmd::NewEvent {
                     event_type: ty,
                     log,
@@ -139,6 +143,7 @@ pub mod mock_web3_client {
     pub struct Web3ClientInner {
         active: bool,
         latest_block_height: Uint256,
+        fork: u8,
         events: Vec<(MockEventType, ethabi::RawLog, u32, Sender<()>)>,
         blocks_processed: UnboundedSender<Uint256>,
         last_block_processed: Option<Uint256>,
@@ -199,6 +204,18 @@ pub mod mock_web3_client {
             }
         }
 
+        async fn block_hash(
+            &self,
+            _: BlockHeight,
+        ) -> Result<Option<[u8; 32]>, Error> {
+            let client = self.0.lock().unwrap();
+            if client.active {
+                Ok(Some([client.fork; 32]))
+            } else {
+                Err(Error::BlockHash("Test oracle is not responding".into()))
+            }
+        }
+
         async fn syncing(
             &self,
             _: Option<&BlockHeight>,
@@ -226,6 +243,7 @@ pub mod mock_web3_client {
                 Self(Arc::new(Mutex::new(Web3ClientInner {
                     active: true,
                     latest_block_height: Default::default(),
+                    fork: 0,
                     events: vec![],
                     blocks_processed: block_processed_send,
                     last_block_processed: None,
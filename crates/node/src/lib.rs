@@ -20,6 +20,7 @@ mod broadcaster;
 mod dry_run_tx;
 pub mod ethereum_oracle;
 pub mod protocol;
+pub mod remote_signer;
 pub mod shell;
 pub mod shims;
 pub mod storage;
@@ -29,6 +30,8 @@ pub mod utils;
 use std::convert::TryInto;
 use std::net::SocketAddr;
 use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Instant;
 
 use byte_unit::Byte;
 use data_encoding::HEXUPPER;
@@ -56,6 +59,7 @@ use tokio::sync::mpsc;
 
 use self::abortable::AbortableSpawner;
 use self::ethereum_oracle::last_processed_block;
+use self::shell::metrics::{self, Handler, Metrics};
 use self::shell::EthereumOracleChannels;
 use self::shims::abcipp_shim::AbciService;
 use crate::broadcaster::Broadcaster;
@@ -113,10 +117,11 @@ impl Shell {
             Request::Query(query) => Ok(Response::Query(self.query(query))),
             Request::PrepareProposal(block) => {
                 tracing::debug!("Request PrepareProposal");
+                let start = Instant::now();
                 // TODO: use TM domain type in the handler
-                Ok(Response::PrepareProposal(
-                    self.prepare_proposal(block.into()),
-                ))
+                let response = self.prepare_proposal(block.into());
+                self.observe_handler_duration(Handler::PrepareProposal, start);
+                Ok(Response::PrepareProposal(response))
             }
             Request::VerifyHeader(_req) => {
                 Ok(Response::VerifyHeader(self.verify_header(_req)))
@@ -130,8 +135,10 @@ impl Shell {
                 // function will not be correctly replicated in the other
                 // locations
                 let block_hash = block.hash.try_into();
+                let start = Instant::now();
                 let (response, tx_results) =
                     self.process_proposal(block.into());
+                self.observe_handler_duration(Handler::ProcessProposal, start);
                 // Cache the response in case of future calls from Namada. If
                 // hash conversion fails avoid caching
                 if let Ok(block_hash) = block_hash {
@@ -160,7 +167,10 @@ impl Shell {
                 tracing::debug!("Request FinalizeBlock");
 
                 self.try_recheck_process_proposal(&finalize)?;
-                self.finalize_block(finalize).map(Response::FinalizeBlock)
+                let start = Instant::now();
+                let response = self.finalize_block(finalize);
+                self.observe_handler_duration(Handler::FinalizeBlock, start);
+                response.map(Response::FinalizeBlock)
             }
             Request::Commit => {
                 tracing::debug!("Request Commit");
@@ -420,6 +430,43 @@ pub fn rollback(config: config::Ledger) -> Result<(), shell::Error> {
     shell::rollback(config)
 }
 
+/// Export a snapshot of the DB of a stopped Namada ledger node
+pub fn export_snapshot(
+    config: config::Ledger,
+    args::LedgerSnapshotExport { height, out_dir }: args::LedgerSnapshotExport,
+) -> Result<(), shell::Error> {
+    let meta = shell::export_snapshot(config, height, out_dir.clone())?;
+    tracing::info!(
+        "Exported snapshot at height {} with {} chunks and root hash {} to {}",
+        meta.height,
+        meta.chunk_hashes.len(),
+        meta.root_hash,
+        storage::SnapshotPath(out_dir, meta.height)
+            .base()
+            .to_string_lossy(),
+    );
+    Ok(())
+}
+
+/// Import a snapshot into the DB of a stopped Namada ledger node
+pub fn import_snapshot(
+    config: config::Ledger,
+    args::LedgerSnapshotImport {
+        from_dir,
+        height,
+        app_hash,
+    }: args::LedgerSnapshotImport,
+) -> Result<(), shell::Error> {
+    let meta = shell::import_snapshot(config, from_dir, height, app_hash)?;
+    tracing::info!(
+        "Imported snapshot at height {} with {} chunks and root hash {}",
+        meta.height,
+        meta.chunk_hashes.len(),
+        meta.root_hash,
+    );
+    Ok(())
+}
+
 /// Runs and monitors a few concurrent tasks.
 ///
 /// This includes:
@@ -635,6 +682,7 @@ fn start_abci_broadcaster_shell(
     let tendermint_mode = config.shell.tendermint_mode.clone();
     let proxy_app_address =
         convert_tm_addr_to_socket_addr(&config.cometbft.proxy_app);
+    let prometheus_listen_addr = config.shell.prometheus_listen_addr;
 
     let (shell, abci_service, service_handle) = AbcippShim::new(
         config,
@@ -647,6 +695,13 @@ fn start_abci_broadcaster_shell(
         tx_wasm_compilation_cache,
     );
 
+    // Start the metrics server
+    if let (Some(listen_addr), Some(node_metrics)) =
+        (prometheus_listen_addr, shell.metrics())
+    {
+        start_metrics_server(spawner, listen_addr, node_metrics);
+    }
+
     // Channel for signalling shut down to ABCI server
     let (abci_abort_send, abci_abort_recv) = tokio::sync::oneshot::channel();
 
@@ -693,6 +748,32 @@ fn start_abci_broadcaster_shell(
         .spawn_blocking();
 }
 
+/// Spawns a server of the node's Prometheus metrics into the asynchronous
+/// runtime.
+fn start_metrics_server(
+    spawner: &mut AbortableSpawner,
+    listen_addr: SocketAddr,
+    node_metrics: Arc<Metrics>,
+) {
+    let (metrics_abort_send, metrics_abort_recv) =
+        tokio::sync::oneshot::channel::<()>();
+
+    spawner
+        .abortable("Metrics", move |aborter| async move {
+            let res =
+                metrics::serve(listen_addr, node_metrics, metrics_abort_recv)
+                    .await;
+            tracing::info!("Metrics server is no longer running.");
+
+            drop(aborter);
+            res
+        })
+        .with_cleanup(async move {
+            let _ = metrics_abort_send.send(());
+        })
+        .spawn();
+}
+
 /// Runs the an asynchronous ABCI server with four sub-components for consensus,
 /// mempool, snapshot, and info.
 async fn run_abci(
@@ -813,22 +894,24 @@ async fn maybe_start_ethereum_oracle(
         return EthereumOracleTask::NotEnabled;
     }
 
-    let ethereum_url = config.ethereum_bridge.oracle_rpc_endpoint.clone();
-
     // Start the oracle for listening to Ethereum events
     let (eth_sender, eth_receiver) =
         mpsc::channel(config.ethereum_bridge.channel_buffer_size);
     let (last_processed_block_sender, last_processed_block_receiver) =
         last_processed_block::channel();
     let (control_sender, control_receiver) = oracle::control::channel();
+    let (endpoint_stats_sender, endpoint_stats_receiver) =
+        oracle::quorum::endpoint_stats::channel();
 
     match config.ethereum_bridge.mode {
         ethereum_bridge::ledger::Mode::RemoteEndpoint => {
             oracle::run_oracle::<Provider<Http>>(
-                ethereum_url,
+                config.ethereum_bridge.oracle_rpc_endpoints(),
+                config.ethereum_bridge.oracle_rpc_quorum,
                 eth_sender,
                 control_receiver,
                 last_processed_block_sender,
+                endpoint_stats_sender,
                 spawner,
             );
 
@@ -837,10 +920,13 @@ async fn maybe_start_ethereum_oracle(
                     eth_receiver,
                     control_sender,
                     last_processed_block_receiver,
+                    endpoint_stats_receiver,
                 ),
             }
         }
         ethereum_bridge::ledger::Mode::SelfHostedEndpoint => {
+            let listen_addr =
+                config.ethereum_bridge.oracle_rpc_endpoint.clone();
             let (oracle_abort_send, oracle_abort_recv) =
                 tokio::sync::oneshot::channel::<tokio::sync::oneshot::Sender<()>>(
                 );
@@ -849,7 +935,7 @@ async fn maybe_start_ethereum_oracle(
                     "Ethereum Events Endpoint",
                     move |aborter| async move {
                         oracle::test_tools::events_endpoint::serve(
-                            ethereum_url,
+                            listen_addr,
                             eth_sender,
                             control_receiver,
                             oracle_abort_recv,
@@ -889,6 +975,7 @@ async fn maybe_start_ethereum_oracle(
                     eth_receiver,
                     control_sender,
                     last_processed_block_receiver,
+                    endpoint_stats_receiver,
                 ),
             }
         }
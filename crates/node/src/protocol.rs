@@ -1295,7 +1295,8 @@ where
                                 .map_err(Error::NativeVpError)
                             }
                             internal_addr @ (InternalAddress::IbcToken(_)
-                            | InternalAddress::Erc20(_)) => {
+                            | InternalAddress::Erc20(_)
+                            | InternalAddress::StakeShare(_)) => {
                                 // The address should be a part of a multitoken
                                 // key
                                 verifiers
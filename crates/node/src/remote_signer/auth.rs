@@ -0,0 +1,371 @@
+//! Mutual authentication of a node and its signer.
+//!
+//! Both ends hold an ed25519 key and pin the public key of the other end. A
+//! connection starts with a handshake, in which each end signs the random
+//! challenges of both ends together with both public keys:
+//!
+//! 1. the node sends its public key and challenge in a [`Hello`]
+//! 2. the signer checks that the node's key is the pinned one and replies with
+//!    its public key, its challenge and its signature in a [`HelloAck`]
+//! 3. the node checks that the signer's key is the pinned one and its
+//!    signature, and replies with its own signature in an [`Auth`]
+//!
+//! Every following message is sent in a [`Sealed`] envelope, signed together
+//! with the hash of the handshake and a sequence number, such that messages
+//! can't be forged, replayed, reordered or moved to another connection.
+
+use std::fs;
+use std::io::{self, Read, Write};
+#[cfg(unix)]
+use std::os::unix::fs::PermissionsExt;
+use std::path::Path;
+
+use namada_sdk::borsh::{BorshDeserialize, BorshSerialize, BorshSerializeExt};
+use namada_sdk::hash::Hash;
+use namada_sdk::key::{common, ed25519, RefTo, SigScheme};
+use rand::rngs::OsRng;
+use rand::RngCore;
+
+use super::{read_message, write_message, Error};
+
+/// Domain separator of the handshake
+const HANDSHAKE_DOMAIN: &str = "namada-remote-signer-v1";
+
+/// A random challenge of one end of a connection
+type Challenge = [u8; 32];
+
+/// An end of a connection
+#[derive(Clone, Copy, Debug, PartialEq, Eq, BorshSerialize)]
+enum Peer {
+    Node,
+    Signer,
+}
+
+/// The first message of a node to its signer
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+struct Hello {
+    node_pk: common::PublicKey,
+    challenge: Challenge,
+}
+
+/// The response of a signer to a [`Hello`]
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+struct HelloAck {
+    signer_pk: common::PublicKey,
+    challenge: Challenge,
+    sig: common::Signature,
+}
+
+/// The last message of the handshake, from the node to its signer
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+struct Auth {
+    sig: common::Signature,
+}
+
+/// A message sent after the handshake
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+struct Sealed {
+    seq: u64,
+    message: Vec<u8>,
+    sig: common::Signature,
+}
+
+/// An authenticated connection between a node and its signer
+#[derive(Debug)]
+pub struct Session {
+    /// The hash of the handshake
+    id: Hash,
+    /// The local end of the connection
+    local: Peer,
+    /// The key of the local end
+    key: common::SecretKey,
+    /// The pinned key of the remote end
+    peer_pk: common::PublicKey,
+    /// The sequence number of the next sent message
+    sent: u64,
+    /// The sequence number of the next received message
+    received: u64,
+}
+
+impl Session {
+    /// Authenticate a connection to the signer with the given pinned key
+    pub fn connect(
+        stream: &mut (impl Read + Write),
+        key: &common::SecretKey,
+        signer_pk: &common::PublicKey,
+    ) -> Result<Self, Error> {
+        let hello = Hello {
+            node_pk: key.ref_to(),
+            challenge: gen_challenge(),
+        };
+        write_message(stream, &hello)?;
+        let ack: HelloAck = read_message(stream)?;
+        if &ack.signer_pk != signer_pk {
+            return Err(Error::Authentication(format!(
+                "The signer authenticated with the key {}, expected {}",
+                ack.signer_pk, signer_pk
+            )));
+        }
+        let id = handshake_hash(
+            &hello.node_pk,
+            signer_pk,
+            &hello.challenge,
+            &ack.challenge,
+        );
+        common::SigScheme::verify_signature(
+            signer_pk,
+            &proof_hash(&id, Peer::Signer),
+            &ack.sig,
+        )
+        .map_err(|err| {
+            Error::Authentication(format!("Invalid signer proof: {err}"))
+        })?;
+        let sig = common::SigScheme::sign(key, proof_hash(&id, Peer::Node));
+        write_message(stream, &Auth { sig })?;
+        Ok(Self::new(id, Peer::Node, key.clone(), signer_pk.clone()))
+    }
+
+    /// Authenticate a connection from the node with the given pinned key
+    pub fn accept(
+        stream: &mut (impl Read + Write),
+        key: &common::SecretKey,
+        node_pk: &common::PublicKey,
+    ) -> Result<Self, Error> {
+        let hello: Hello = read_message(stream)?;
+        if &hello.node_pk != node_pk {
+            return Err(Error::Authentication(format!(
+                "Unknown node key {}",
+                hello.node_pk
+            )));
+        }
+        let signer_pk = key.ref_to();
+        let challenge = gen_challenge();
+        let id =
+            handshake_hash(node_pk, &signer_pk, &hello.challenge, &challenge);
+        let sig = common::SigScheme::sign(key, proof_hash(&id, Peer::Signer));
+        write_message(
+            stream,
+            &HelloAck {
+                signer_pk,
+                challenge,
+                sig,
+            },
+        )?;
+        let auth: Auth = read_message(stream)?;
+        common::SigScheme::verify_signature(
+            node_pk,
+            &proof_hash(&id, Peer::Node),
+            &auth.sig,
+        )
+        .map_err(|err| {
+            Error::Authentication(format!("Invalid node proof: {err}"))
+        })?;
+        Ok(Self::new(id, Peer::Signer, key.clone(), node_pk.clone()))
+    }
+
+    fn new(
+        id: Hash,
+        local: Peer,
+        key: common::SecretKey,
+        peer_pk: common::PublicKey,
+    ) -> Self {
+        Self {
+            id,
+            local,
+            key,
+            peer_pk,
+            sent: 0,
+            received: 0,
+        }
+    }
+
+    /// Send a sealed message to the remote end
+    pub fn send<T: BorshSerialize>(
+        &mut self,
+        stream: &mut impl Write,
+        message: &T,
+    ) -> Result<(), Error> {
+        let seq = self.sent;
+        let message = message.serialize_to_vec();
+        let sig = common::SigScheme::sign(
+            &self.key,
+            self.seal_hash(self.local, seq, &message),
+        );
+        write_message(stream, &Sealed { seq, message, sig })?;
+        self.sent = next_seq(seq)?;
+        Ok(())
+    }
+
+    /// Receive a sealed message from the remote end
+    pub fn receive<T: BorshDeserialize>(
+        &mut self,
+        stream: &mut impl Read,
+    ) -> Result<T, Error> {
+        let Sealed { seq, message, sig } = read_message(stream)?;
+        if seq != self.received {
+            return Err(Error::Authentication(format!(
+                "Received message {seq}, expected {}",
+                self.received
+            )));
+        }
+        let remote = match self.local {
+            Peer::Node => Peer::Signer,
+            Peer::Signer => Peer::Node,
+        };
+        common::SigScheme::verify_signature(
+            &self.peer_pk,
+            &self.seal_hash(remote, seq, &message),
+            &sig,
+        )
+        .map_err(|err| {
+            Error::Authentication(format!("Invalid message signature: {err}"))
+        })?;
+        self.received = next_seq(seq)?;
+        Ok(T::try_from_slice(&message)?)
+    }
+
+    /// The hash of a message signed by its sender
+    fn seal_hash(&self, sender: Peer, seq: u64, message: &[u8]) -> Hash {
+        Hash::sha256((self.id, sender, seq, message).serialize_to_vec())
+    }
+}
+
+/// Load the authentication key from the given file, or generate it if the
+/// file doesn't exist
+pub fn load_or_generate_key(path: &Path) -> io::Result<common::SecretKey> {
+    match fs::read_to_string(path) {
+        Ok(key) => key.trim().parse().map_err(|err| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Invalid key in {}: {err}", path.display()),
+            )
+        }),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            let key = common::SecretKey::Ed25519(ed25519::SigScheme::generate(
+                &mut OsRng,
+            ));
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            fs::write(path, key.to_string())?;
+            // Only the user may read the key
+            #[cfg(unix)]
+            fs::set_permissions(path, fs::Permissions::from_mode(0o600))?;
+            tracing::info!(
+                "Generated a new authentication key in {} with the public key \
+                 {}",
+                path.display(),
+                key.ref_to()
+            );
+            Ok(key)
+        }
+        Err(err) => Err(err),
+    }
+}
+
+fn gen_challenge() -> Challenge {
+    let mut challenge = Challenge::default();
+    OsRng.fill_bytes(&mut challenge);
+    challenge
+}
+
+/// The hash of the handshake, which identifies a session
+fn handshake_hash(
+    node_pk: &common::PublicKey,
+    signer_pk: &common::PublicKey,
+    node_challenge: &Challenge,
+    signer_challenge: &Challenge,
+) -> Hash {
+    Hash::sha256(
+        (
+            HANDSHAKE_DOMAIN,
+            node_pk,
+            signer_pk,
+            node_challenge,
+            signer_challenge,
+        )
+            .serialize_to_vec(),
+    )
+}
+
+/// The hash signed by an end of the handshake to prove its identity
+fn proof_hash(id: &Hash, peer: Peer) -> Hash {
+    Hash::sha256((id, peer).serialize_to_vec())
+}
+
+fn next_seq(seq: u64) -> Result<u64, Error> {
+    seq.checked_add(1).ok_or_else(|| {
+        Error::Authentication("Ran out of sequence numbers".to_string())
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use namada_apps_lib::wallet::defaults;
+
+    use super::*;
+
+    /// Test that sealed messages can't be replayed, tampered with or moved
+    /// to another session
+    #[test]
+    fn test_sealed_messages() {
+        let node_key = defaults::albert_keypair();
+        let signer_key = defaults::bertha_keypair();
+        let id = Hash::sha256(b"session");
+        let mut node =
+            Session::new(id, Peer::Node, node_key.clone(), signer_key.ref_to());
+        let mut signer = Session::new(
+            id,
+            Peer::Signer,
+            signer_key.clone(),
+            node_key.ref_to(),
+        );
+
+        let mut sent = vec![];
+        node.send(&mut sent, &1_u64).unwrap();
+        assert_eq!(signer.receive::<u64>(&mut sent.as_slice()).unwrap(), 1);
+        assert!(matches!(
+            signer.receive::<u64>(&mut sent.as_slice()),
+            Err(Error::Authentication(_))
+        ));
+
+        // Flip a bit of the message, after its length, sequence number and
+        // the length of its bytes
+        let mut sent = vec![];
+        node.send(&mut sent, &2_u64).unwrap();
+        sent[16] ^= 1;
+        assert!(matches!(
+            signer.receive::<u64>(&mut sent.as_slice()),
+            Err(Error::Authentication(_))
+        ));
+
+        // A message of another session with the same keys
+        let mut other = Session::new(
+            Hash::sha256(b"other session"),
+            Peer::Node,
+            node_key,
+            signer_key.ref_to(),
+        );
+        other.sent = 1;
+        let mut sent = vec![];
+        other.send(&mut sent, &2_u64).unwrap();
+        assert!(matches!(
+            signer.receive::<u64>(&mut sent.as_slice()),
+            Err(Error::Authentication(_))
+        ));
+
+        // A message of the signer reflected back to it
+        let mut sent = vec![];
+        signer.send(&mut sent, &2_u64).unwrap();
+        let mut reflected = Session::new(
+            id,
+            Peer::Signer,
+            signer_key.clone(),
+            signer_key.ref_to(),
+        );
+        assert!(matches!(
+            reflected.receive::<u64>(&mut sent.as_slice()),
+            Err(Error::Authentication(_))
+        ));
+    }
+}
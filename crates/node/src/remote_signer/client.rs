@@ -0,0 +1,127 @@
+//! The node's client of a remote signer
+
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use namada_sdk::key::common;
+use namada_vote_ext::signer::{SignRequest, ValidatorKeyRole, ValidatorSigner};
+
+use super::auth::Session;
+use super::{Error, Request, Response, Stream};
+use crate::config::{RemoteSignerConfig, SignerEndpoint};
+
+/// A client of a remote signer that holds the keys of a validator.
+///
+/// The connection to the signer is established lazily and re-established
+/// once whenever a request fails to be sent or its response fails to be
+/// received. The signer must authenticate itself with its pinned key and the
+/// signatures that it returns are verified against the public keys that it
+/// reported.
+#[derive(Debug)]
+pub struct RemoteSigner {
+    /// The endpoint the signer listens on
+    endpoint: SignerEndpoint,
+    /// The key with which the node authenticates itself
+    auth_key: common::SecretKey,
+    /// The pinned key of the signer
+    signer_pk: common::PublicKey,
+    /// Connection and I/O timeout
+    timeout: Duration,
+    /// The current authenticated connection to the signer, if any
+    connection: Mutex<Option<(Stream, Session)>>,
+    /// Public keys received from the signer
+    public_keys: Mutex<BTreeMap<ValidatorKeyRole, common::PublicKey>>,
+}
+
+impl RemoteSigner {
+    /// Create a client of the signer from its config, which authenticates
+    /// with the given key. No connection is made until the first request.
+    pub fn new(
+        config: &RemoteSignerConfig,
+        auth_key: common::SecretKey,
+    ) -> Self {
+        Self {
+            endpoint: config.endpoint.clone(),
+            auth_key,
+            signer_pk: config.signer_public_key.clone(),
+            timeout: Duration::from_millis(config.timeout_ms),
+            connection: Mutex::new(None),
+            public_keys: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    /// Send a request to the signer and wait for its response
+    fn request(&self, request: &Request) -> Result<Response, Error> {
+        let mut connection = self.connection.lock().unwrap();
+        let mut retried = false;
+        loop {
+            let (stream, session) = match connection.as_mut() {
+                Some(connection) => connection,
+                None => connection.insert(self.connect()?),
+            };
+            let response = session
+                .send(stream, request)
+                .and_then(|()| session.receive(stream));
+            match response {
+                Err(Error::Io(err)) if !retried => {
+                    tracing::debug!(
+                        "Reconnecting to the remote signer at {} after an \
+                         error: {err}",
+                        self.endpoint
+                    );
+                    *connection = None;
+                    retried = true;
+                }
+                Err(err) => {
+                    *connection = None;
+                    return Err(err);
+                }
+                Ok(Response::Error(msg)) => return Err(Error::Refused(msg)),
+                Ok(response) => return Ok(response),
+            }
+        }
+    }
+
+    /// Open an authenticated connection to the signer
+    fn connect(&self) -> Result<(Stream, Session), Error> {
+        let mut stream = Stream::connect(&self.endpoint, self.timeout)?;
+        let session =
+            Session::connect(&mut stream, &self.auth_key, &self.signer_pk)?;
+        Ok((stream, session))
+    }
+}
+
+impl ValidatorSigner for RemoteSigner {
+    type Error = Error;
+
+    fn public_key(
+        &self,
+        role: ValidatorKeyRole,
+    ) -> Result<common::PublicKey, Self::Error> {
+        if let Some(pk) = self.public_keys.lock().unwrap().get(&role) {
+            return Ok(pk.clone());
+        }
+        match self.request(&Request::PublicKey(role))? {
+            Response::PublicKey(pk) => {
+                self.public_keys.lock().unwrap().insert(role, pk.clone());
+                Ok(pk)
+            }
+            response => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+
+    fn sign(
+        &self,
+        request: &SignRequest,
+    ) -> Result<common::Signature, Self::Error> {
+        let pk = self.public_key(request.key_role())?;
+        match self.request(&Request::Sign(request.clone()))? {
+            Response::Signature(sig) => {
+                request.verify(&pk, &sig).map_err(Error::InvalidSignature)?;
+                Ok(sig)
+            }
+            response => Err(Error::UnexpectedResponse(response)),
+        }
+    }
+}
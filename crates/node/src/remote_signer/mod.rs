@@ -0,0 +1,223 @@
+//! Remote signing of a validator's vote extensions and protocol txs.
+//!
+//! When a validator node is configured with a remote signer, its protocol and
+//! Ethereum bridge keys don't have to be stored in the node's wallet. Instead,
+//! the node sends every [`SignRequest`] over a Unix domain or TCP socket to a
+//! signer process that holds the keys. The signer refuses requests that would
+//! make the validator double sign vote extensions or sign a stale bridge pool
+//! root, vote extensions of other validators and protocol txs that don't
+//! carry a vote extension of the validator.
+//!
+//! The node and its signer authenticate each other with pinned keys and sign
+//! every message that they exchange, so the signer may be reached over the
+//! network. Messages are borsh encoded, each prefixed with its length as a
+//! big-endian `u32`.
+
+mod auth;
+mod client;
+mod server;
+
+use std::convert::Infallible;
+use std::io::{self, Read, Write};
+use std::net::TcpStream;
+#[cfg(unix)]
+use std::os::unix::net::UnixStream;
+use std::time::Duration;
+
+use namada_sdk::borsh::{BorshDeserialize, BorshSerialize, BorshSerializeExt};
+use namada_sdk::key::{common, VerifySigError};
+pub use namada_vote_ext::signer::LocalSigner;
+use namada_vote_ext::signer::{SignRequest, ValidatorKeyRole, ValidatorSigner};
+use thiserror::Error;
+
+pub use self::auth::load_or_generate_key;
+pub use self::client::RemoteSigner;
+pub use self::server::{run_signer, DoubleSignError, SignerState, VoteKind};
+use crate::config::SignerEndpoint;
+
+/// The maximum length of a message exchanged with a signer
+const MAX_MESSAGE_LEN: u32 = 16 * 1024 * 1024;
+
+/// Errors of a remote signer
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("Failed to communicate with the remote signer: {0}")]
+    Io(#[from] io::Error),
+    #[error("Message of {0} bytes exceeds the maximum length")]
+    MessageTooLong(usize),
+    #[error("Unix domain sockets are not supported on this platform")]
+    UnsupportedEndpoint,
+    #[error("Failed to authenticate the remote end: {0}")]
+    Authentication(String),
+    #[error("The remote signer refused the request: {0}")]
+    Refused(String),
+    #[error("Unexpected response from the remote signer: {0:?}")]
+    UnexpectedResponse(Response),
+    #[error("Invalid signature from the remote signer: {0}")]
+    InvalidSignature(VerifySigError),
+}
+
+/// A request from a node to its signer
+#[derive(Clone, Debug, PartialEq, BorshSerialize, BorshDeserialize)]
+pub enum Request {
+    /// Get the public key of the given role
+    PublicKey(ValidatorKeyRole),
+    /// Sign the data with the key of its role
+    Sign(SignRequest),
+}
+
+/// A response from a signer to a [`Request`]
+#[derive(Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum Response {
+    /// The requested public key
+    PublicKey(common::PublicKey),
+    /// The signature of the requested data
+    Signature(common::Signature),
+    /// The request couldn't be served
+    Error(String),
+}
+
+/// The signer of a validator node, which either holds the validator's keys
+/// in memory or requests signatures from a remote signer.
+#[derive(Debug)]
+#[allow(clippy::large_enum_variant)]
+pub enum NodeSigner {
+    /// The keys are loaded from the node's wallet
+    Local(LocalSigner),
+    /// The keys are held by a remote signer
+    Remote(RemoteSigner),
+}
+
+impl ValidatorSigner for NodeSigner {
+    type Error = Error;
+
+    fn public_key(
+        &self,
+        role: ValidatorKeyRole,
+    ) -> Result<common::PublicKey, Self::Error> {
+        match self {
+            Self::Local(signer) => signer
+                .public_key(role)
+                .map_err(|err: Infallible| match err {}),
+            Self::Remote(signer) => signer.public_key(role),
+        }
+    }
+
+    fn sign(
+        &self,
+        request: &SignRequest,
+    ) -> Result<common::Signature, Self::Error> {
+        match self {
+            Self::Local(signer) => {
+                signer.sign(request).map_err(|err: Infallible| match err {})
+            }
+            Self::Remote(signer) => signer.sign(request),
+        }
+    }
+}
+
+/// A connection between a node and its signer
+#[derive(Debug)]
+enum Stream {
+    Tcp(TcpStream),
+    #[cfg(unix)]
+    Unix(UnixStream),
+}
+
+impl Stream {
+    /// Connect to a signer listening on the given endpoint
+    fn connect(
+        endpoint: &SignerEndpoint,
+        timeout: Duration,
+    ) -> Result<Self, Error> {
+        let stream = match endpoint {
+            SignerEndpoint::Tcp(addr) => {
+                Self::Tcp(TcpStream::connect_timeout(addr, timeout)?)
+            }
+            #[cfg(unix)]
+            SignerEndpoint::Unix(path) => {
+                Self::Unix(UnixStream::connect(path)?)
+            }
+            #[cfg(not(unix))]
+            SignerEndpoint::Unix(_) => return Err(Error::UnsupportedEndpoint),
+        };
+        stream.set_timeout(Some(timeout))?;
+        Ok(stream)
+    }
+
+    /// Set the read and write timeouts of the stream
+    fn set_timeout(&self, timeout: Option<Duration>) -> io::Result<()> {
+        match self {
+            Self::Tcp(stream) => {
+                stream.set_read_timeout(timeout)?;
+                stream.set_write_timeout(timeout)
+            }
+            #[cfg(unix)]
+            Self::Unix(stream) => {
+                stream.set_read_timeout(timeout)?;
+                stream.set_write_timeout(timeout)
+            }
+        }
+    }
+}
+
+impl Read for Stream {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self {
+            Self::Tcp(stream) => stream.read(buf),
+            #[cfg(unix)]
+            Self::Unix(stream) => stream.read(buf),
+        }
+    }
+}
+
+impl Write for Stream {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            Self::Tcp(stream) => stream.write(buf),
+            #[cfg(unix)]
+            Self::Unix(stream) => stream.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Self::Tcp(stream) => stream.flush(),
+            #[cfg(unix)]
+            Self::Unix(stream) => stream.flush(),
+        }
+    }
+}
+
+/// Write a length-prefixed message
+fn write_message<T: BorshSerialize>(
+    stream: &mut impl Write,
+    message: &T,
+) -> Result<(), Error> {
+    let bytes = message.serialize_to_vec();
+    let len = u32::try_from(bytes.len())
+        .ok()
+        .filter(|len| *len <= MAX_MESSAGE_LEN)
+        .ok_or(Error::MessageTooLong(bytes.len()))?;
+    stream.write_all(&len.to_be_bytes())?;
+    stream.write_all(&bytes)?;
+    stream.flush()?;
+    Ok(())
+}
+
+/// Read a length-prefixed message
+fn read_message<T: BorshDeserialize>(
+    stream: &mut impl Read,
+) -> Result<T, Error> {
+    let mut len = [0; 4];
+    stream.read_exact(&mut len)?;
+    let len = u32::from_be_bytes(len);
+    let len_usize =
+        usize::try_from(len).map_err(|_| Error::MessageTooLong(usize::MAX))?;
+    if len > MAX_MESSAGE_LEN {
+        return Err(Error::MessageTooLong(len_usize));
+    }
+    let mut bytes = vec![0; len_usize];
+    stream.read_exact(&mut bytes)?;
+    Ok(T::try_from_slice(&bytes)?)
+}
@@ -0,0 +1,790 @@
+//! A reference signer that holds the keys of a validator and serves the
+//! requests of its node
+
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::net::TcpListener;
+#[cfg(unix)]
+use std::os::unix::fs::PermissionsExt;
+#[cfg(unix)]
+use std::os::unix::net::UnixListener;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use namada_sdk::address::Address;
+use namada_sdk::borsh::{BorshDeserialize, BorshSerialize, BorshSerializeExt};
+use namada_sdk::ethereum_events::EthereumEvent;
+use namada_sdk::hash::Hash;
+use namada_sdk::key::{common, RefTo};
+use namada_sdk::uint::Uint;
+use namada_vote_ext::signer::{LocalSigner, SignRequest};
+use thiserror::Error;
+
+use super::auth::Session;
+use super::{Error, Request, Response, Stream};
+use crate::config::SignerEndpoint;
+
+/// The time given to a node to authenticate itself after it connected
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The kinds of vote extensions that are protected from double signing
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    BorshSerialize,
+    BorshDeserialize,
+)]
+pub enum VoteKind {
+    /// Ethereum events vote extensions
+    EthereumEvents,
+    /// Bridge pool root vote extensions
+    BridgePoolRootVext,
+    /// Signatures over the bridge pool root and nonce
+    BridgePoolRoot,
+    /// Validator set update vote extensions
+    ValidatorSetUpdate,
+}
+
+impl VoteKind {
+    /// Get the kind of vote that is requested to be signed, together with
+    /// its height and the hash of its data bound to its kind and height.
+    /// Protocol txs are not protected from double signing, as they can only
+    /// carry vote extensions that were signed beforehand, so we return `None`
+    /// for them.
+    fn of(request: &SignRequest) -> Option<(Self, u64, Hash)> {
+        let (kind, height, data) = match request {
+            SignRequest::EthereumEvents(ext) => (
+                Self::EthereumEvents,
+                ext.block_height.0,
+                ext.serialize_to_vec(),
+            ),
+            SignRequest::BridgePoolRootVext(ext) => (
+                Self::BridgePoolRootVext,
+                ext.block_height.0,
+                ext.serialize_to_vec(),
+            ),
+            SignRequest::BridgePoolRoot {
+                block_height,
+                root,
+                nonce,
+            } => (
+                Self::BridgePoolRoot,
+                block_height.0,
+                (root, nonce).serialize_to_vec(),
+            ),
+            // Validator set updates are voted on once per epoch
+            SignRequest::ValidatorSetUpdate(ext) => (
+                Self::ValidatorSetUpdate,
+                ext.signing_epoch.0,
+                ext.serialize_to_vec(),
+            ),
+            SignRequest::ProtocolTx(_) => return None,
+        };
+        Some((
+            kind,
+            height,
+            Hash::sha256((kind, height, data).serialize_to_vec()),
+        ))
+    }
+}
+
+/// The kind and nonce of an Ethereum event, which identify it. Different
+/// events with the same id conflict with each other.
+type EventId = (u8, Uint);
+
+/// Get the ids and hashes of the Ethereum events of a vote extension
+fn event_hashes(
+    events: &[EthereumEvent],
+) -> Result<BTreeMap<EventId, Hash>, DoubleSignError> {
+    let mut hashes = BTreeMap::new();
+    for event in events {
+        let id = match event {
+            EthereumEvent::TransfersToNamada { nonce, .. } => (0, *nonce),
+            EthereumEvent::TransfersToEthereum { nonce, .. } => (1, *nonce),
+            EthereumEvent::ValidatorSetUpdate { nonce, .. } => (2, *nonce),
+        };
+        let hash = Hash::sha256(event.serialize_to_vec());
+        if hashes.insert(id, hash).is_some_and(|other| other != hash) {
+            return Err(DoubleSignError::ConflictingEvent { nonce: id.1 });
+        }
+    }
+    Ok(hashes)
+}
+
+/// The last vote of some kind signed by a signer
+#[derive(Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+struct LastVote {
+    /// The block height or epoch of the vote
+    height: u64,
+    /// The hash of the signed data
+    data_hash: Hash,
+}
+
+/// A vote was refused to be signed, as it could make the validator double
+/// sign or sign a stale bridge pool root
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum DoubleSignError {
+    #[error(
+        "Refusing to sign a {kind:?} vote at height {height}, lower than the \
+         last signed height {last_height}"
+    )]
+    HeightRegression {
+        kind: VoteKind,
+        height: u64,
+        last_height: u64,
+    },
+    #[error(
+        "Refusing to sign a {kind:?} vote that conflicts with the one signed \
+         at height {height}"
+    )]
+    ConflictingVote { kind: VoteKind, height: u64 },
+    #[error(
+        "Refusing to sign a bridge pool root with nonce {nonce}, lower than \
+         the last signed nonce {last_nonce}"
+    )]
+    NonceRegression { nonce: Uint, last_nonce: Uint },
+    #[error(
+        "Refusing to sign an Ethereum event with nonce {nonce} that conflicts \
+         with another event signed at the same height"
+    )]
+    ConflictingEvent { nonce: Uint },
+}
+
+/// The double signing protection state of a signer, which records the last
+/// vote of each kind that it signed
+#[derive(Clone, Debug, Default, BorshSerialize, BorshDeserialize)]
+pub struct SignerState {
+    last_votes: BTreeMap<VoteKind, LastVote>,
+    /// The nonce of the last signed bridge pool root. The nonce only ever
+    /// increases, so a root with a lower nonce is stale.
+    last_bridge_pool_nonce: Option<Uint>,
+    /// The Ethereum events signed at the height of the last Ethereum events
+    /// vote
+    last_eth_events: BTreeMap<EventId, Hash>,
+}
+
+impl SignerState {
+    /// Load the state from the given file. A missing file is treated as an
+    /// empty state.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read(path) {
+            Ok(bytes) => Self::try_from_slice(&bytes),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                Ok(Self::default())
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Atomically write the state to the given file
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let tmp_path = path.with_extension("tmp");
+        {
+            let mut file = File::create(&tmp_path)?;
+            file.write_all(&self.serialize_to_vec())?;
+            file.sync_all()?;
+        }
+        fs::rename(tmp_path, path)
+    }
+
+    /// Check that signing the request cannot make the validator double sign
+    /// and record it as the last signed vote of its kind
+    pub fn check_and_record(
+        &mut self,
+        request: &SignRequest,
+    ) -> Result<(), DoubleSignError> {
+        let Some((kind, height, data_hash)) = VoteKind::of(request) else {
+            return Ok(());
+        };
+        let bridge_pool_nonce = match request {
+            SignRequest::BridgePoolRoot { nonce, .. } => Some(*nonce),
+            _ => None,
+        };
+        let eth_events = match request {
+            SignRequest::EthereumEvents(ext) => {
+                event_hashes(&ext.ethereum_events)?
+            }
+            _ => BTreeMap::new(),
+        };
+        if let (Some(nonce), Some(last_nonce)) =
+            (bridge_pool_nonce, self.last_bridge_pool_nonce)
+        {
+            if nonce < last_nonce {
+                return Err(DoubleSignError::NonceRegression {
+                    nonce,
+                    last_nonce,
+                });
+            }
+        }
+        let same_height = match self.last_votes.get(&kind) {
+            Some(last) if height < last.height => {
+                return Err(DoubleSignError::HeightRegression {
+                    kind,
+                    height,
+                    last_height: last.height,
+                });
+            }
+            Some(last) if height == last.height => {
+                if data_hash != last.data_hash {
+                    self.check_conflict(kind, height, &eth_events)?;
+                }
+                true
+            }
+            _ => false,
+        };
+        self.last_votes.insert(kind, LastVote { height, data_hash });
+        if bridge_pool_nonce.is_some() {
+            self.last_bridge_pool_nonce = bridge_pool_nonce;
+        }
+        if kind == VoteKind::EthereumEvents {
+            if !same_height {
+                self.last_eth_events.clear();
+            }
+            self.last_eth_events.extend(eth_events);
+        }
+        Ok(())
+    }
+
+    /// Check if a vote that differs from the last one of its kind signed at
+    /// the same height conflicts with it. Ethereum events are voted on as
+    /// they are seen, and the ones that expired in the mempool are re-signed
+    /// in a new vote extension at the last block height, so an Ethereum
+    /// events vote only conflicts if it has an event with the same kind and
+    /// nonce as a different event signed at the same height.
+    fn check_conflict(
+        &self,
+        kind: VoteKind,
+        height: u64,
+        eth_events: &BTreeMap<EventId, Hash>,
+    ) -> Result<(), DoubleSignError> {
+        if kind != VoteKind::EthereumEvents {
+            return Err(DoubleSignError::ConflictingVote { kind, height });
+        }
+        match eth_events.iter().find(|(id, hash)| {
+            self.last_eth_events
+                .get(id)
+                .is_some_and(|signed| signed != *hash)
+        }) {
+            Some(((_, nonce), _)) => {
+                Err(DoubleSignError::ConflictingEvent { nonce: *nonce })
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+/// Run a signer with the keys of the `validator` on the given endpoint,
+/// until an error prevents it from listening. The signer authenticates
+/// itself with the `auth_key` and only serves the node that authenticates
+/// with the pinned `node_pk`. Vote extensions of other validators are
+/// refused.
+///
+/// The double signing protection state is loaded from and persisted to the
+/// file at `state_path`, before responding to every signing request.
+pub fn run_signer(
+    endpoint: &SignerEndpoint,
+    validator: Address,
+    signer: LocalSigner,
+    state_path: PathBuf,
+    auth_key: common::SecretKey,
+    node_pk: common::PublicKey,
+) -> Result<(), Error> {
+    let server = Arc::new(Server {
+        validator,
+        signer,
+        auth_key,
+        node_pk,
+        state: Mutex::new(SignerState::load(&state_path)?),
+        state_path,
+    });
+    tracing::info!(
+        "Signer listening on {endpoint} with the authentication key {}",
+        server.auth_key.ref_to()
+    );
+    match endpoint {
+        SignerEndpoint::Tcp(addr) => {
+            let listener = TcpListener::bind(addr)?;
+            for stream in listener.incoming() {
+                server.spawn(Stream::Tcp(stream?));
+            }
+        }
+        #[cfg(unix)]
+        SignerEndpoint::Unix(path) => {
+            // Remove a socket left over by a previous run
+            if path.exists() {
+                fs::remove_file(path)?;
+            }
+            let listener = UnixListener::bind(path)?;
+            // Only the user of the signer may connect to the socket
+            fs::set_permissions(path, fs::Permissions::from_mode(0o600))?;
+            for stream in listener.incoming() {
+                server.spawn(Stream::Unix(stream?));
+            }
+        }
+        #[cfg(not(unix))]
+        SignerEndpoint::Unix(_) => return Err(Error::UnsupportedEndpoint),
+    }
+    Ok(())
+}
+
+/// A signer serving the requests of a node
+struct Server {
+    validator: Address,
+    signer: LocalSigner,
+    auth_key: common::SecretKey,
+    node_pk: common::PublicKey,
+    state: Mutex<SignerState>,
+    state_path: PathBuf,
+}
+
+impl Server {
+    /// Serve a connection on its own thread, such that a peer that doesn't
+    /// authenticate can't hold up the node
+    fn spawn(self: &Arc<Self>, stream: Stream) {
+        let server = Arc::clone(self);
+        std::thread::spawn(move || server.serve(stream));
+    }
+
+    /// Authenticate the node on a connection and serve its requests until
+    /// it's closed
+    fn serve(&self, mut stream: Stream) {
+        let session = stream
+            .set_timeout(Some(HANDSHAKE_TIMEOUT))
+            .map_err(Error::from)
+            .and_then(|()| {
+                Session::accept(&mut stream, &self.auth_key, &self.node_pk)
+            })
+            .and_then(|session| {
+                stream.set_timeout(None)?;
+                Ok(session)
+            });
+        let mut session = match session {
+            Ok(session) => session,
+            Err(err) => {
+                tracing::warn!("Refused a connection to the signer: {err}");
+                return;
+            }
+        };
+        tracing::info!("Node connected to the signer");
+        loop {
+            let request = match session.receive::<Request>(&mut stream) {
+                Ok(request) => request,
+                Err(Error::Io(err))
+                    if err.kind() == io::ErrorKind::UnexpectedEof =>
+                {
+                    tracing::info!("Node disconnected from the signer");
+                    return;
+                }
+                Err(err) => {
+                    tracing::error!("Failed to read a signer request: {err}");
+                    return;
+                }
+            };
+            let response = self.respond(request);
+            if let Err(err) = session.send(&mut stream, &response) {
+                tracing::error!("Failed to write a signer response: {err}");
+                return;
+            }
+        }
+    }
+
+    /// Respond to a request of the node
+    fn respond(&self, request: Request) -> Response {
+        match request {
+            Request::PublicKey(role) => {
+                Response::PublicKey(self.signer.secret_key(role).ref_to())
+            }
+            Request::Sign(request) => {
+                if let Some(validator) = request
+                    .validator_addr()
+                    .filter(|addr| *addr != &self.validator)
+                {
+                    tracing::warn!(
+                        "Refusing to sign a vote extension of the validator \
+                         {validator}"
+                    );
+                    return Response::Error(format!(
+                        "The signer only holds the keys of the validator {}",
+                        self.validator
+                    ));
+                }
+                if let Err(err) = request.check_protocol_tx(
+                    &self.validator,
+                    &self.signer.protocol_key.ref_to(),
+                    &self.signer.eth_bridge_key.ref_to(),
+                ) {
+                    tracing::warn!("Refusing to sign a protocol tx: {err}");
+                    return Response::Error(err);
+                }
+                let mut signed_state = self.state.lock().unwrap();
+                let mut state = signed_state.clone();
+                if let Err(err) = state.check_and_record(&request) {
+                    tracing::warn!("{err}");
+                    return Response::Error(err.to_string());
+                }
+                if let Err(err) = state.save(&self.state_path) {
+                    tracing::error!(
+                        "Failed to persist the signer state: {err}"
+                    );
+                    return Response::Error(format!(
+                        "Failed to persist the signer state: {err}"
+                    ));
+                }
+                *signed_state = state;
+                let key = self.signer.secret_key(request.key_role());
+                Response::Signature(request.sign(key))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use namada_apps_lib::wallet::defaults;
+    use namada_sdk::chain::{BlockHeight, ChainId};
+    use namada_sdk::keccak::KeccakHash;
+    use namada_sdk::storage::Epoch;
+    use namada_sdk::tx::data::TxType;
+    use namada_sdk::tx::Tx;
+    use namada_vote_ext::signer::{ValidatorKeyRole, ValidatorSigner};
+    use namada_vote_ext::{
+        bridge_pool_roots, ethereum_events, validator_set_update,
+        EthereumTxData,
+    };
+
+    use super::*;
+    use crate::config::RemoteSignerConfig;
+    use crate::remote_signer::RemoteSigner;
+
+    /// Make a request to sign the bridge pool root and nonce at the given
+    /// height
+    fn bp_root(height: u64, root: u8, nonce: u64) -> SignRequest {
+        SignRequest::BridgePoolRoot {
+            block_height: BlockHeight(height),
+            root: KeccakHash([root; 32]),
+            nonce: Uint::from(nonce),
+        }
+    }
+
+    /// Make a request to sign the Ethereum events at the given height
+    fn eth_events(height: u64, events: Vec<EthereumEvent>) -> SignRequest {
+        SignRequest::EthereumEvents(ethereum_events::Vext {
+            block_height: BlockHeight(height),
+            validator_addr: defaults::validator_address(),
+            ethereum_events: events,
+        })
+    }
+
+    /// Make a bridge pool root vote extension of the given validator
+    fn bp_root_vext(validator_addr: Address) -> bridge_pool_roots::Vext {
+        bridge_pool_roots::Vext {
+            validator_addr,
+            block_height: BlockHeight(1),
+            sig: bp_root(1, 0, 0).sign(&defaults::validator_keys().1),
+        }
+    }
+
+    /// Test that votes can't be signed at a lower height than the last
+    /// signed one, nor conflict with a vote signed at the same height
+    #[test]
+    fn test_double_sign_protection() {
+        let mut state = SignerState::default();
+        state.check_and_record(&bp_root(5, 1, 0)).unwrap();
+        // Signing the same data again is allowed
+        state.check_and_record(&bp_root(5, 1, 0)).unwrap();
+        assert_eq!(
+            state.check_and_record(&bp_root(5, 2, 0)),
+            Err(DoubleSignError::ConflictingVote {
+                kind: VoteKind::BridgePoolRoot,
+                height: 5,
+            })
+        );
+        assert_eq!(
+            state.check_and_record(&bp_root(4, 1, 0)),
+            Err(DoubleSignError::HeightRegression {
+                kind: VoteKind::BridgePoolRoot,
+                height: 4,
+                last_height: 5,
+            })
+        );
+        state.check_and_record(&bp_root(6, 2, 0)).unwrap();
+
+        // Votes of other kinds are tracked separately
+        let vext = bp_root_vext(defaults::validator_address());
+        state
+            .check_and_record(&SignRequest::BridgePoolRootVext(vext))
+            .unwrap();
+
+        // Validator set updates are tracked by their epoch
+        let valset = |epoch| {
+            SignRequest::ValidatorSetUpdate(validator_set_update::Vext {
+                voting_powers: Default::default(),
+                validator_addr: defaults::validator_address(),
+                signing_epoch: Epoch(epoch),
+            })
+        };
+        state.check_and_record(&valset(10)).unwrap();
+        assert!(state.check_and_record(&valset(9)).is_err());
+
+        // Protocol txs are not protected
+        let tx = SignRequest::ProtocolTx(Box::new(Tx::from_type(TxType::Raw)));
+        state.check_and_record(&tx).unwrap();
+        state.check_and_record(&tx).unwrap();
+    }
+
+    /// Test that different Ethereum events votes can be signed at the same
+    /// height, as long as they don't have conflicting events
+    #[test]
+    fn test_eth_events_protection() {
+        let transfers = |nonce: u64, relayer: &Address| {
+            EthereumEvent::TransfersToEthereum {
+                nonce: Uint::from(nonce),
+                transfers: vec![],
+                relayer: relayer.clone(),
+            }
+        };
+        let albert = defaults::albert_address();
+        let bertha = defaults::bertha_address();
+
+        let mut state = SignerState::default();
+        state
+            .check_and_record(&eth_events(5, vec![transfers(1, &albert)]))
+            .unwrap();
+        // New events may be signed at the same height
+        state
+            .check_and_record(&eth_events(
+                5,
+                vec![transfers(1, &albert), transfers(2, &albert)],
+            ))
+            .unwrap();
+        state
+            .check_and_record(&eth_events(5, vec![transfers(3, &albert)]))
+            .unwrap();
+        // ... but not an event that conflicts with one signed at this height
+        assert_eq!(
+            state.check_and_record(&eth_events(5, vec![transfers(2, &bertha)])),
+            Err(DoubleSignError::ConflictingEvent {
+                nonce: Uint::from(2)
+            })
+        );
+        // ... nor conflicting events in a single vote
+        assert_eq!(
+            state.check_and_record(&eth_events(
+                6,
+                vec![transfers(4, &albert), transfers(4, &bertha)],
+            )),
+            Err(DoubleSignError::ConflictingEvent {
+                nonce: Uint::from(4)
+            })
+        );
+        assert!(matches!(
+            state.check_and_record(&eth_events(4, vec![])),
+            Err(DoubleSignError::HeightRegression { .. })
+        ));
+        // The events signed at a lower height don't conflict with new votes
+        state
+            .check_and_record(&eth_events(6, vec![transfers(2, &bertha)]))
+            .unwrap();
+    }
+
+    /// Test that a bridge pool root can't be signed with a lower nonce than
+    /// the last signed one
+    #[test]
+    fn test_bridge_pool_nonce_protection() {
+        let mut state = SignerState::default();
+        state.check_and_record(&bp_root(5, 1, 3)).unwrap();
+        assert_eq!(
+            state.check_and_record(&bp_root(6, 2, 2)),
+            Err(DoubleSignError::NonceRegression {
+                nonce: Uint::from(2),
+                last_nonce: Uint::from(3),
+            })
+        );
+        state.check_and_record(&bp_root(6, 2, 3)).unwrap();
+        state.check_and_record(&bp_root(7, 3, 4)).unwrap();
+    }
+
+    /// Test that the signer refuses the vote extensions of other validators
+    /// and protocol txs that don't carry a vote extension of its validator
+    #[test]
+    fn test_signer_refuses_foreign_requests() {
+        let dir = tempfile::tempdir().unwrap();
+        let (protocol_key, eth_bridge_key) = defaults::validator_keys();
+        let server = Server {
+            validator: defaults::validator_address(),
+            signer: LocalSigner {
+                protocol_key: protocol_key.clone(),
+                eth_bridge_key,
+            },
+            auth_key: defaults::bertha_keypair(),
+            node_pk: defaults::albert_keypair().ref_to(),
+            state: Mutex::new(SignerState::default()),
+            state_path: dir.path().join("signer_state"),
+        };
+        let vext = |validator_addr| {
+            Request::Sign(SignRequest::BridgePoolRootVext(bp_root_vext(
+                validator_addr,
+            )))
+        };
+        assert!(matches!(
+            server.respond(vext(defaults::validator_address())),
+            Response::Signature(_)
+        ));
+        assert!(matches!(
+            server.respond(vext(defaults::albert_address())),
+            Response::Error(_)
+        ));
+
+        let protocol_tx = |validator_addr, vext_key: &common::SecretKey| {
+            let ext = bp_root_vext(validator_addr).sign(vext_key);
+            let tx = EthereumTxData::BridgePoolVext(ext)
+                .sign(&protocol_key, ChainId::default());
+            Request::Sign(SignRequest::ProtocolTx(Box::new(tx)))
+        };
+        assert!(matches!(
+            server.respond(protocol_tx(
+                defaults::validator_address(),
+                &protocol_key
+            )),
+            Response::Signature(_)
+        ));
+        // The vote extension must be signed by the validator
+        assert!(matches!(
+            server.respond(protocol_tx(
+                defaults::validator_address(),
+                &defaults::albert_keypair()
+            )),
+            Response::Error(_)
+        ));
+        assert!(matches!(
+            server.respond(protocol_tx(
+                defaults::albert_address(),
+                &protocol_key
+            )),
+            Response::Error(_)
+        ));
+        // Other txs can't be signed
+        let tx = Tx::from_type(TxType::Raw);
+        assert!(matches!(
+            server
+                .respond(Request::Sign(SignRequest::ProtocolTx(Box::new(tx)))),
+            Response::Error(_)
+        ));
+    }
+
+    /// Test that the signer state is persisted
+    #[test]
+    fn test_signer_state_persistence() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("signer_state");
+        assert!(
+            SignerState::load(&path).unwrap().last_votes.is_empty(),
+            "A missing state file must be loaded as an empty state"
+        );
+
+        let mut state = SignerState::default();
+        state.check_and_record(&bp_root(5, 1, 0)).unwrap();
+        state.save(&path).unwrap();
+
+        let mut state = SignerState::load(&path).unwrap();
+        assert!(state.check_and_record(&bp_root(5, 2, 0)).is_err());
+    }
+
+    /// Test that a node can request signatures from a remote signer, which
+    /// match the signatures made with the keys directly, and that both ends
+    /// must authenticate with their pinned keys
+    #[cfg(unix)]
+    #[test]
+    fn test_remote_signer_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let endpoint = SignerEndpoint::Unix(dir.path().join("signer.sock"));
+        let (protocol_key, eth_bridge_key) = defaults::validator_keys();
+        let local = LocalSigner {
+            protocol_key,
+            eth_bridge_key,
+        };
+        let node_key = defaults::albert_keypair();
+        let signer_key = defaults::bertha_keypair();
+
+        {
+            let endpoint = endpoint.clone();
+            let local = local.clone();
+            let state_path = dir.path().join("signer_state");
+            let signer_key = signer_key.clone();
+            let node_pk = node_key.ref_to();
+            std::thread::spawn(move || {
+                run_signer(
+                    &endpoint,
+                    defaults::validator_address(),
+                    local,
+                    state_path,
+                    signer_key,
+                    node_pk,
+                )
+                .unwrap()
+            });
+        }
+        let config = RemoteSignerConfig {
+            validator_address: defaults::validator_address(),
+            endpoint: endpoint.clone(),
+            signer_public_key: signer_key.ref_to(),
+            auth_key_path: None,
+            timeout_ms: RemoteSignerConfig::default_timeout_ms(),
+        };
+        let remote = RemoteSigner::new(&config, node_key.clone());
+        // Wait for the signer to start listening
+        let SignerEndpoint::Unix(socket) = &endpoint else {
+            unreachable!()
+        };
+        while !socket.exists() {
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+
+        for role in [ValidatorKeyRole::Protocol, ValidatorKeyRole::EthBridge] {
+            assert_eq!(
+                remote.public_key(role).unwrap(),
+                local.secret_key(role).ref_to()
+            );
+        }
+        let request = bp_root(5, 1, 0);
+        assert_eq!(
+            remote.sign(&request).unwrap(),
+            local.sign(&request).unwrap()
+        );
+        // A conflicting vote is refused
+        assert!(matches!(
+            remote.sign(&bp_root(5, 2, 0)),
+            Err(Error::Refused(_))
+        ));
+        // A protocol tx carrying a vote extension signed by the signer
+        let ext = bp_root_vext(defaults::validator_address())
+            .sign_with(&remote)
+            .unwrap();
+        EthereumTxData::BridgePoolVext(ext)
+            .sign_with(&remote, ChainId::default())
+            .unwrap();
+
+        // A node that doesn't authenticate with the pinned key is refused
+        let intruder = RemoteSigner::new(&config, defaults::christel_keypair());
+        assert!(intruder.public_key(ValidatorKeyRole::Protocol).is_err());
+        // ... and so is a signer
+        let spoofed = RemoteSigner::new(
+            &RemoteSignerConfig {
+                signer_public_key: defaults::christel_keypair().ref_to(),
+                ..config
+            },
+            node_key,
+        );
+        assert!(matches!(
+            spoofed.public_key(ValidatorKeyRole::Protocol),
+            Err(Error::Authentication(_))
+        ));
+    }
+}
@@ -3,6 +3,8 @@
 use data_encoding::HEXUPPER;
 use masp_primitives::merkle_tree::CommitmentTree;
 use masp_primitives::sapling::Node;
+use namada_sdk::eth_bridge::protocol::transactions::ethereum_events;
+use namada_sdk::eth_bridge::storage::eth_bridge_queries::is_bridge_comptime_enabled;
 use namada_sdk::events::extend::{
     ComposeEvent, Height, IndexedMaspData, Info, MaspDataRefs, TxHash,
 };
@@ -31,6 +33,7 @@ use tendermint::abci::types::Misbehavior;
 
 use super::*;
 use crate::protocol::{DispatchArgs, DispatchError};
+use crate::shell::metrics::WasmCacheMetrics;
 use crate::shell::stats::InternalStats;
 use crate::tendermint::abci::types::VoteInfo;
 use crate::tendermint_proto;
@@ -169,6 +172,7 @@ where
 
         tracing::info!("{}", stats);
         tracing::info!("{}", stats.format_tx_executed());
+        self.record_block_metrics(height, &stats);
 
         // Update the MASP commitment tree anchor if the tree was updated
         let tree_key = token::storage_key::masp_commitment_tree_key();
@@ -187,6 +191,19 @@ where
             self.state.write(&anchor_key, ())?;
         }
 
+        // Refund the expired Bridge pool transfers which can no longer be
+        // relayed, now that the votes on Bridge pool roots were applied
+        if is_bridge_comptime_enabled() {
+            let events =
+                ethereum_events::refund_expired_transfers(&mut self.state)
+                    .map_err(|err| Error::TxApply(err.into()))?;
+            response.events.emit_many(
+                events
+                    .into_iter()
+                    .map(|event| Event::from(event).with(Height(height))),
+            );
+        }
+
         if update_for_tendermint {
             self.update_epoch(&mut response);
             // send the latest oracle configs. These may have changed due to
@@ -426,6 +443,9 @@ where
             }
         }
 
+        if let Ok(gas_used) = tx_logs.tx_event.read_attribute::<GasUsed>() {
+            tx_logs.stats.add_gas_used(gas_used.into());
+        }
         response.events.emit(tx_logs.tx_event);
         None
     }
@@ -474,6 +494,7 @@ where
                 .results
                 .accept(tx_data.tx_index);
             temp_log.commit(tx_logs, response);
+            self.record_tx_history(&extended_tx_result, &tx_data);
 
             // Atomic successful batches or non-atomic batches (even if the
             // inner txs failed) are marked as Ok
@@ -500,6 +521,62 @@ where
             .extend(Batch(&extended_tx_result.tx_result.to_result_string()));
     }
 
+    /// Record the stats of the finalized block and the state of the WASM
+    /// caches in the metrics, if enabled.
+    fn record_block_metrics(&self, height: BlockHeight, stats: &InternalStats) {
+        let Some(metrics) = self.metrics.as_ref() else {
+            return;
+        };
+        metrics.record_block(height, stats);
+        let to_u64 = |size: usize| u64::try_from(size).unwrap_or(u64::MAX);
+        metrics.set_wasm_cache(
+            "tx",
+            WasmCacheMetrics {
+                entries: to_u64(self.tx_wasm_cache.get_size()),
+                weight: to_u64(self.tx_wasm_cache.get_cache_size()),
+                hits: self.tx_wasm_cache.get_hits(),
+                misses: self.tx_wasm_cache.get_misses(),
+            },
+        );
+        metrics.set_wasm_cache(
+            "vp",
+            WasmCacheMetrics {
+                entries: to_u64(self.vp_wasm_cache.get_size()),
+                weight: to_u64(self.vp_wasm_cache.get_cache_size()),
+                hits: self.vp_wasm_cache.get_hits(),
+                misses: self.vp_wasm_cache.get_misses(),
+            },
+        );
+        if let ShellMode::Validator {
+            eth_oracle: Some(eth_oracle),
+            ..
+        } = &self.mode
+        {
+            metrics.set_eth_oracle_stats(
+                eth_oracle.endpoint_stats_receiver.borrow().clone(),
+            );
+        }
+    }
+
+    /// Record the accepted inner txs of a committed batch in the tx
+    /// history index, if enabled.
+    fn record_tx_history(
+        &mut self,
+        extended_tx_result: &namada_sdk::tx::data::ExtendedTxResult<
+            protocol::Error,
+        >,
+        tx_data: &TxData<'_>,
+    ) {
+        if let Some(tx_history) = self.tx_history.as_mut() {
+            tx_history.record_batch(
+                tx_data.tx,
+                tx_data.wrapper_hash.as_ref(),
+                &extended_tx_result.tx_result,
+                tx_data.height,
+            );
+        }
+    }
+
     fn handle_batch_error(
         &mut self,
         response: &mut shim::response::FinalizeBlock,
@@ -541,6 +618,7 @@ where
                 .results
                 .accept(tx_data.tx_index);
             temp_log.commit(tx_logs, response);
+            self.record_tx_history(&extended_tx_result, &tx_data);
             // Commit the successful inner transactions before the error. Drop
             // the current tx write log which might be still populated with data
             // to be discarded (this is the case when we propagate an error
@@ -789,6 +867,7 @@ where
                     tx: &tx,
                     commitments_len,
                     tx_index,
+                    wrapper_hash: None,
                     replay_protection_hashes: None,
                     tx_gas_meter,
                     height,
@@ -862,6 +941,7 @@ where
                     tx: &tx,
                     commitments_len,
                     tx_index,
+                    wrapper_hash: Some(tx_hash),
                     replay_protection_hashes,
                     tx_gas_meter,
                     height,
@@ -898,6 +978,7 @@ struct TxData<'tx> {
     tx: &'tx Tx,
     commitments_len: u64,
     tx_index: usize,
+    wrapper_hash: Option<Hash>,
     replay_protection_hashes: Option<ReplayProtectionHashes>,
     tx_gas_meter: TxGasMeter,
     height: BlockHeight,
@@ -1159,7 +1240,9 @@ where
                 {
                     Ok(batched_result) => {
                         if batched_result.is_accepted() {
-                            state.write_log_mut().commit_batch_and_current_tx();
+                            // Governance commits the batch once all the
+                            // proposal code has been executed successfully
+                            state.write_log_mut().commit_tx_to_batch();
                             Ok(true)
                         } else {
                             tracing::warn!(
@@ -1936,6 +2019,7 @@ mod test_finalize_block {
                 voting_end_epoch: Epoch::default().next(),
                 activation_epoch: Epoch::default().next(),
                 r#type: ProposalType::Default,
+                deposit: None,
             };
 
             namada_sdk::governance::init_proposal::<_, token::Store<_>>(
@@ -1943,6 +2027,7 @@ mod test_finalize_block {
                 &proposal,
                 vec![],
                 None,
+                vec![],
             )
             .unwrap();
 
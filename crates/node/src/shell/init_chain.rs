@@ -14,6 +14,7 @@ use namada_sdk::proof_of_stake::{self, BecomeValidator, PosParams};
 use namada_sdk::state::StorageWrite;
 use namada_sdk::time::{TimeZone, Utc};
 use namada_sdk::token::storage_key::masp_token_map_key;
+use namada_sdk::token::vesting::add_vesting_schedule;
 use namada_sdk::token::{credit_tokens, write_denom};
 use namada_sdk::{eth_bridge, ibc};
 use namada_vm::validate_untrusted_wasm;
@@ -480,7 +481,7 @@ where
             .expect("Couldn't init token accounts");
     }
 
-    /// Init genesis token balances
+    /// Init genesis token balances and their vesting schedules
     fn init_token_balances(
         &mut self,
         genesis: &genesis::chain::Finalized,
@@ -520,6 +521,43 @@ where
                 .expect("Couldn't credit initial balance");
             }
         }
+
+        for (token_alias, schedules) in &genesis.balances.vesting {
+            tracing::debug!("Initializing vesting schedules {token_alias}");
+
+            let Some(token_address) = self
+                .validate(
+                    genesis
+                        .tokens
+                        .token
+                        .get(token_alias)
+                        .ok_or_else(|| {
+                            Panic::MissingTokenConfig(token_alias.to_string())
+                        })
+                        .map(|conf| &conf.address),
+                )
+                .or_placeholder(None)?
+            else {
+                continue;
+            };
+
+            for schedule in schedules {
+                tracing::info!(
+                    "Locking {} {} tokens of {} until epoch {}",
+                    schedule.amount,
+                    token_alias,
+                    schedule.owner,
+                    schedule.end_epoch,
+                );
+                add_vesting_schedule(
+                    &mut self.state,
+                    token_address,
+                    &schedule.owner,
+                    schedule.schedule(),
+                )
+                .expect("Couldn't write initial vesting schedule");
+            }
+        }
         self.proceed_with(())
     }
 
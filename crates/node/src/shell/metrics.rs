@@ -0,0 +1,496 @@
+//! Prometheus metrics of the node, served over HTTP by nodes that set
+//! `prometheus_listen_addr` in their config.
+//!
+//! The metrics are updated by the shell as blocks get processed, and are
+//! rendered in the Prometheus text exposition format on every scrape.
+
+use std::collections::BTreeMap;
+use std::fmt::{self, Write};
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+use namada_sdk::chain::BlockHeight;
+use namada_sdk::state::DbStat;
+use warp::Filter;
+
+use super::stats::InternalStats;
+use super::{Error, ShellResult};
+use crate::ethereum_oracle::quorum::endpoint_stats;
+
+/// The content type of the Prometheus text exposition format.
+const CONTENT_TYPE: &str = "text/plain; version=0.0.4";
+
+/// Upper bounds, in seconds, of the buckets of the histograms of ABCI
+/// handler durations.
+const DURATION_BUCKETS: [f64; 11] = [
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+/// The ABCI handlers whose durations are measured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Handler {
+    /// `PrepareProposal` requests
+    PrepareProposal,
+    /// `ProcessProposal` requests
+    ProcessProposal,
+    /// `FinalizeBlock` requests
+    FinalizeBlock,
+}
+
+impl Handler {
+    fn label(&self) -> &'static str {
+        match self {
+            Self::PrepareProposal => "prepare_proposal",
+            Self::ProcessProposal => "process_proposal",
+            Self::FinalizeBlock => "finalize_block",
+        }
+    }
+}
+
+/// A snapshot of the state of a WASM compilation cache.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct WasmCacheMetrics {
+    /// The number of modules in the in-memory cache
+    pub entries: u64,
+    /// The weight of the in-memory cache
+    pub weight: u64,
+    /// The number of modules fetched from the in-memory cache
+    pub hits: u64,
+    /// The number of modules that were not found in the in-memory cache
+    pub misses: u64,
+}
+
+/// The metrics of a node. Thread-safe.
+#[derive(Debug, Default)]
+pub struct Metrics {
+    inner: Mutex<MetricsInner>,
+}
+
+#[derive(Debug, Default)]
+struct MetricsInner {
+    blocks: u64,
+    last_block_height: u64,
+    txs: BTreeMap<&'static str, u64>,
+    wrapper_txs: u64,
+    last_block_gas_used: u64,
+    gas_used: u64,
+    wasm_caches: BTreeMap<&'static str, WasmCacheMetrics>,
+    durations: BTreeMap<Handler, Histogram>,
+    db_stats: Vec<DbStat>,
+    eth_oracle: Option<endpoint_stats::Stats>,
+}
+
+/// A histogram of durations with the buckets of [`DURATION_BUCKETS`].
+#[derive(Debug, Default)]
+struct Histogram {
+    buckets: [u64; DURATION_BUCKETS.len()],
+    count: u64,
+    sum: Duration,
+}
+
+impl Histogram {
+    fn observe(&mut self, duration: Duration) {
+        let secs = duration.as_secs_f64();
+        for (bound, bucket) in DURATION_BUCKETS.iter().zip(&mut self.buckets) {
+            if secs <= *bound {
+                *bucket = bucket.saturating_add(1);
+            }
+        }
+        self.count = self.count.saturating_add(1);
+        self.sum = self.sum.saturating_add(duration);
+    }
+}
+
+impl Metrics {
+    fn inner(&self) -> MutexGuard<'_, MetricsInner> {
+        // NB: the metrics remain consistent even if a thread panicked while
+        // updating them
+        self.inner.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Record the stats of a finalized block.
+    pub fn record_block(&self, height: BlockHeight, stats: &InternalStats) {
+        let mut inner = self.inner();
+        inner.blocks = inner.blocks.saturating_add(1);
+        inner.last_block_height = height.0;
+        for (outcome, count) in stats.tx_outcomes() {
+            let total = inner.txs.entry(outcome).or_default();
+            *total = total.saturating_add(count);
+        }
+        inner.wrapper_txs =
+            inner.wrapper_txs.saturating_add(stats.wrapper_txs());
+        inner.last_block_gas_used = stats.gas_used();
+        inner.gas_used = inner.gas_used.saturating_add(stats.gas_used());
+    }
+
+    /// Update the state of the WASM compilation cache labeled with `name`.
+    pub fn set_wasm_cache(&self, name: &'static str, cache: WasmCacheMetrics) {
+        self.inner().wasm_caches.insert(name, cache);
+    }
+
+    /// Record the time it took to handle a request.
+    pub fn observe_duration(&self, handler: Handler, duration: Duration) {
+        self.inner()
+            .durations
+            .entry(handler)
+            .or_default()
+            .observe(duration);
+    }
+
+    /// Update the statistics of the DB.
+    pub fn set_db_stats(&self, stats: Vec<DbStat>) {
+        self.inner().db_stats = stats;
+    }
+
+    /// Update the statistics of the Ethereum oracle's RPC endpoints.
+    pub fn set_eth_oracle_stats(&self, stats: endpoint_stats::Stats) {
+        self.inner().eth_oracle = Some(stats);
+    }
+
+    /// Render the metrics in the Prometheus text exposition format.
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        self.inner()
+            .render(&mut out)
+            .expect("Writing to a String cannot fail");
+        out
+    }
+}
+
+impl MetricsInner {
+    fn render(&self, out: &mut String) -> fmt::Result {
+        header(out, "namada_blocks_total", "counter", "Finalized blocks")?;
+        writeln!(out, "namada_blocks_total {}", self.blocks)?;
+
+        header(
+            out,
+            "namada_last_block_height",
+            "gauge",
+            "Height of the last finalized block",
+        )?;
+        writeln!(out, "namada_last_block_height {}", self.last_block_height)?;
+
+        header(
+            out,
+            "namada_inner_txs_total",
+            "counter",
+            "Inner txs by outcome",
+        )?;
+        for (outcome, count) in &self.txs {
+            writeln!(
+                out,
+                "namada_inner_txs_total{{outcome=\"{outcome}\"}} {count}"
+            )?;
+        }
+
+        header(
+            out,
+            "namada_wrapper_txs_total",
+            "counter",
+            "Applied wrapper txs",
+        )?;
+        writeln!(out, "namada_wrapper_txs_total {}", self.wrapper_txs)?;
+
+        header(
+            out,
+            "namada_block_gas_used",
+            "gauge",
+            "Gas used by the txs of the last finalized block",
+        )?;
+        writeln!(out, "namada_block_gas_used {}", self.last_block_gas_used)?;
+
+        header(
+            out,
+            "namada_gas_used_total",
+            "counter",
+            "Gas used by the txs of all the finalized blocks",
+        )?;
+        writeln!(out, "namada_gas_used_total {}", self.gas_used)?;
+
+        self.render_wasm_caches(
+            out,
+            "namada_wasm_cache_entries",
+            "gauge",
+            "Modules in the in-memory WASM compilation cache",
+            |cache| cache.entries,
+        )?;
+        self.render_wasm_caches(
+            out,
+            "namada_wasm_cache_weight",
+            "gauge",
+            "Weight of the in-memory WASM compilation cache",
+            |cache| cache.weight,
+        )?;
+        self.render_wasm_caches(
+            out,
+            "namada_wasm_cache_hits_total",
+            "counter",
+            "Modules fetched from the in-memory WASM compilation cache",
+            |cache| cache.hits,
+        )?;
+        self.render_wasm_caches(
+            out,
+            "namada_wasm_cache_misses_total",
+            "counter",
+            "Modules not found in the in-memory WASM compilation cache",
+            |cache| cache.misses,
+        )?;
+
+        header(
+            out,
+            "namada_abci_handler_duration_seconds",
+            "histogram",
+            "Time taken to handle ABCI requests",
+        )?;
+        for (handler, histogram) in &self.durations {
+            let handler = handler.label();
+            for (bound, count) in DURATION_BUCKETS.iter().zip(histogram.buckets)
+            {
+                writeln!(
+                    out,
+                    "namada_abci_handler_duration_seconds_bucket{{handler=\"\
+                     {handler}\",le=\"{bound}\"}} {count}"
+                )?;
+            }
+            writeln!(
+                out,
+                "namada_abci_handler_duration_seconds_bucket{{handler=\"\
+                 {handler}\",le=\"+Inf\"}} {}",
+                histogram.count
+            )?;
+            writeln!(
+                out,
+                "namada_abci_handler_duration_seconds_sum{{handler=\"\
+                 {handler}\"}} {}",
+                histogram.sum.as_secs_f64()
+            )?;
+            writeln!(
+                out,
+                "namada_abci_handler_duration_seconds_count{{handler=\"\
+                 {handler}\"}} {}",
+                histogram.count
+            )?;
+        }
+
+        header(
+            out,
+            "namada_db_property",
+            "gauge",
+            "Properties reported by the DB for each column family",
+        )?;
+        for DbStat {
+            column_family,
+            name,
+            value,
+        } in &self.db_stats
+        {
+            writeln!(
+                out,
+                "namada_db_property{{cf=\"{}\",property=\"{}\"}} {}",
+                column_family, name, value
+            )?;
+        }
+
+        if let Some(eth_oracle) = &self.eth_oracle {
+            render_eth_oracle(out, eth_oracle)?;
+        }
+        Ok(())
+    }
+
+    fn render_wasm_caches(
+        &self,
+        out: &mut String,
+        name: &str,
+        kind: &str,
+        help: &str,
+        value: impl Fn(&WasmCacheMetrics) -> u64,
+    ) -> fmt::Result {
+        header(out, name, kind, help)?;
+        for (cache_name, cache) in &self.wasm_caches {
+            writeln!(out, "{name}{{cache=\"{cache_name}\"}} {}", value(cache))?;
+        }
+        Ok(())
+    }
+}
+
+/// Render the statistics of the Ethereum oracle's RPC endpoints, which are
+/// labeled with their index in the oracle's config.
+fn render_eth_oracle(
+    out: &mut String,
+    stats: &endpoint_stats::Stats,
+) -> fmt::Result {
+    header(
+        out,
+        "namada_eth_oracle_rpc_errors_total",
+        "counter",
+        "Failed requests to the Ethereum oracle's RPC endpoints",
+    )?;
+    for (endpoint, endpoint_stats) in stats.endpoints.iter().enumerate() {
+        writeln!(
+            out,
+            "namada_eth_oracle_rpc_errors_total{{endpoint=\"{endpoint}\"}} {}",
+            endpoint_stats.errors
+        )?;
+    }
+
+    header(
+        out,
+        "namada_eth_oracle_rpc_divergences_total",
+        "counter",
+        "Responses of the Ethereum oracle's RPC endpoints that disagreed with \
+         a quorum of endpoints",
+    )?;
+    for (endpoint, endpoint_stats) in stats.endpoints.iter().enumerate() {
+        writeln!(
+            out,
+            "namada_eth_oracle_rpc_divergences_total{{endpoint=\"{endpoint}\"\
+             }} {}",
+            endpoint_stats.divergences
+        )?;
+    }
+
+    header(
+        out,
+        "namada_eth_oracle_failed_quorums_total",
+        "counter",
+        "Ethereum blocks on which the oracle's RPC endpoints failed to reach \
+         a quorum",
+    )?;
+    writeln!(
+        out,
+        "namada_eth_oracle_failed_quorums_total {}",
+        stats.failed_quorums
+    )
+}
+
+/// Write the `HELP` and `TYPE` lines of a metric.
+fn header(out: &mut String, name: &str, kind: &str, help: &str) -> fmt::Result {
+    writeln!(out, "# HELP {name} {help}")?;
+    writeln!(out, "# TYPE {name} {kind}")
+}
+
+/// Serve the metrics at `/metrics` on the given address, until a signal is
+/// sent on `abort_recv`.
+pub async fn serve(
+    listen_addr: SocketAddr,
+    metrics: Arc<Metrics>,
+    abort_recv: tokio::sync::oneshot::Receiver<()>,
+) -> ShellResult<()> {
+    let route = warp::get()
+        .and(warp::path("metrics"))
+        .and(warp::path::end())
+        .map(move || {
+            warp::reply::with_header(
+                metrics.render(),
+                "content-type",
+                CONTENT_TYPE,
+            )
+        });
+
+    let (addr, server) = warp::serve(route)
+        .try_bind_with_graceful_shutdown(listen_addr, async move {
+            let _ = abort_recv.await;
+        })
+        .map_err(|err| Error::Metrics(err.to_string()))?;
+    tracing::info!("Serving Prometheus metrics at http://{addr}/metrics");
+    server.await;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test_metrics {
+    use super::*;
+
+    /// Test that handler durations are recorded in cumulative buckets.
+    #[test]
+    fn test_duration_histogram() {
+        let mut histogram = Histogram::default();
+        histogram.observe(Duration::from_millis(1));
+        histogram.observe(Duration::from_millis(200));
+        histogram.observe(Duration::from_secs(60));
+
+        assert_eq!(histogram.buckets, [1, 1, 1, 1, 1, 2, 2, 2, 2, 2, 2]);
+        assert_eq!(histogram.count, 3);
+        assert_eq!(histogram.sum, Duration::from_millis(60_201));
+    }
+
+    /// Test the rendering of the metrics in the text exposition format.
+    #[test]
+    fn test_render_metrics() {
+        let metrics = Metrics::default();
+        let mut stats = InternalStats::default();
+        stats.increment_successful_txs();
+        stats.increment_successful_txs();
+        stats.increment_rejected_txs();
+        stats.increment_wrapper_txs();
+        stats.add_gas_used(1_000);
+        metrics.record_block(BlockHeight(1), &stats);
+        metrics.record_block(BlockHeight(2), &stats);
+        metrics.set_wasm_cache(
+            "vp",
+            WasmCacheMetrics {
+                entries: 2,
+                weight: 2,
+                hits: 7,
+                misses: 3,
+            },
+        );
+        metrics.observe_duration(
+            Handler::FinalizeBlock,
+            Duration::from_millis(20),
+        );
+        metrics.set_db_stats(vec![DbStat {
+            column_family: "subspace",
+            name: "rocksdb.estimate-num-keys",
+            value: 42,
+        }]);
+        metrics.set_eth_oracle_stats(endpoint_stats::Stats {
+            endpoints: vec![
+                endpoint_stats::EndpointStats {
+                    errors: 3,
+                    divergences: 0,
+                },
+                endpoint_stats::EndpointStats {
+                    errors: 0,
+                    divergences: 1,
+                },
+            ],
+            failed_quorums: 2,
+        });
+
+        let rendered = metrics.render();
+        for line in [
+            "# TYPE namada_blocks_total counter",
+            "namada_blocks_total 2",
+            "namada_last_block_height 2",
+            "namada_inner_txs_total{outcome=\"successful\"} 4",
+            "namada_inner_txs_total{outcome=\"rejected\"} 2",
+            "namada_wrapper_txs_total 2",
+            "namada_block_gas_used 1000",
+            "namada_gas_used_total 2000",
+            "namada_wasm_cache_entries{cache=\"vp\"} 2",
+            "namada_wasm_cache_hits_total{cache=\"vp\"} 7",
+            "namada_wasm_cache_misses_total{cache=\"vp\"} 3",
+            "# TYPE namada_abci_handler_duration_seconds histogram",
+            "namada_abci_handler_duration_seconds_bucket{handler=\"\
+             finalize_block\",le=\"0.01\"} 0",
+            "namada_abci_handler_duration_seconds_bucket{handler=\"\
+             finalize_block\",le=\"0.025\"} 1",
+            "namada_abci_handler_duration_seconds_bucket{handler=\"\
+             finalize_block\",le=\"+Inf\"} 1",
+            "namada_abci_handler_duration_seconds_count{handler=\"\
+             finalize_block\"} 1",
+            "namada_db_property{cf=\"subspace\",property=\"rocksdb.\
+             estimate-num-keys\"} 42",
+            "namada_eth_oracle_rpc_errors_total{endpoint=\"0\"} 3",
+            "namada_eth_oracle_rpc_divergences_total{endpoint=\"1\"} 1",
+            "namada_eth_oracle_failed_quorums_total 2",
+        ] {
+            assert!(
+                rendered.lines().any(|rendered| rendered == line),
+                "Missing line {line} in:\n{rendered}"
+            );
+        }
+    }
+}
@@ -12,6 +12,8 @@ pub use init_chain::InitChainValidation;
 use namada_apps_lib::config::NodeLocalConfig;
 use namada_sdk::state::StateRead;
 use namada_vm::wasm::run::check_tx_allowed;
+pub use snapshots::{export_snapshot, import_snapshot};
+pub mod metrics;
 pub mod prepare_proposal;
 use namada_sdk::ibc;
 use namada_sdk::state::State;
@@ -22,6 +24,8 @@ mod stats;
 #[cfg(any(test, feature = "testing"))]
 #[allow(dead_code)]
 pub mod testing;
+mod tx_history;
+mod tx_ordering;
 mod vote_extensions;
 
 use std::cell::RefCell;
@@ -30,8 +34,10 @@ use std::num::NonZeroU64;
 use std::path::{Path, PathBuf};
 #[allow(unused_imports)]
 use std::rc::Rc;
+use std::sync::Arc;
+use std::time::Instant;
 
-use namada_apps_lib::wallet::{self, ValidatorData, ValidatorKeys};
+use namada_apps_lib::wallet;
 use namada_sdk::address::Address;
 use namada_sdk::borsh::{BorshDeserialize, BorshSerializeExt};
 use namada_sdk::chain::{BlockHeight, ChainId};
@@ -67,6 +73,7 @@ use namada_sdk::{
 };
 use namada_vm::wasm::{TxCache, VpCache};
 use namada_vm::{WasmCacheAccess, WasmCacheRwAccess};
+use namada_vote_ext::signer::LocalSigner;
 use namada_vote_ext::EthereumTxData;
 use thiserror::Error;
 use tokio::sync::mpsc::{Receiver, UnboundedSender};
@@ -74,6 +81,7 @@ use tokio::sync::mpsc::{Receiver, UnboundedSender};
 use super::ethereum_oracle::{self as oracle, last_processed_block};
 use crate::config::{self, genesis, TendermintMode, ValidatorLocalConfig};
 use crate::protocol::ShellParams;
+use crate::remote_signer::NodeSigner;
 use crate::shims::abcipp_shim_types::shim;
 use crate::shims::abcipp_shim_types::shim::response::TxResult;
 use crate::shims::abcipp_shim_types::shim::TakeSnapshot;
@@ -132,6 +140,8 @@ pub enum Error {
     RejectedBlockProposal,
     #[error("Received an invalid block proposal")]
     InvalidBlockProposal,
+    #[error("Error serving metrics: {0}")]
+    Metrics(String),
 }
 
 impl From<Error> for TxResult {
@@ -177,7 +187,8 @@ pub fn rollback(config: config::Ledger) -> ShellResult<()> {
 #[allow(dead_code, clippy::large_enum_variant)]
 pub(super) enum ShellMode {
     Validator {
-        data: ValidatorData,
+        address: Address,
+        signer: NodeSigner,
         broadcast_sender: UnboundedSender<Vec<u8>>,
         eth_oracle: Option<EthereumOracleChannels>,
         validator_local_config: Option<ValidatorLocalConfig>,
@@ -260,7 +271,7 @@ impl ShellMode {
     /// Get the validator address if ledger is in validator mode
     pub fn get_validator_address(&self) -> Option<&Address> {
         match &self {
-            ShellMode::Validator { data, .. } => Some(&data.address),
+            ShellMode::Validator { address, .. } => Some(address),
             _ => None,
         }
     }
@@ -279,39 +290,36 @@ impl ShellMode {
         }
     }
 
-    /// Get the protocol keypair for this validator.
+    /// Get the signer of this validator's vote extensions and protocol txs.
+    pub fn get_signer(&self) -> Option<&NodeSigner> {
+        match self {
+            ShellMode::Validator { signer, .. } => Some(signer),
+            _ => None,
+        }
+    }
+
+    /// Get the protocol keypair for this validator, if it's not held by a
+    /// remote signer.
+    #[cfg_attr(not(test), allow(dead_code))]
     pub fn get_protocol_key(&self) -> Option<&common::SecretKey> {
         match self {
             ShellMode::Validator {
-                data:
-                    ValidatorData {
-                        keys:
-                            ValidatorKeys {
-                                protocol_keypair, ..
-                            },
-                        ..
-                    },
+                signer: NodeSigner::Local(signer),
                 ..
-            } => Some(protocol_keypair),
+            } => Some(&signer.protocol_key),
             _ => None,
         }
     }
 
-    /// Get the Ethereum bridge keypair for this validator.
+    /// Get the Ethereum bridge keypair for this validator, if it's not held
+    /// by a remote signer.
     #[cfg_attr(not(test), allow(dead_code))]
     pub fn get_eth_bridge_keypair(&self) -> Option<&common::SecretKey> {
         match self {
             ShellMode::Validator {
-                data:
-                    ValidatorData {
-                        keys:
-                            ValidatorKeys {
-                                eth_bridge_keypair, ..
-                            },
-                        ..
-                    },
+                signer: NodeSigner::Local(signer),
                 ..
-            } => Some(eth_bridge_keypair),
+            } => Some(&signer.eth_bridge_key),
             _ => None,
         }
     }
@@ -345,6 +353,9 @@ pub struct SnapshotSync {
     pub next_chunk: u64,
     pub height: BlockHeight,
     pub expected: Vec<Hash>,
+    /// The trusted app hash at the height of the snapshot, which the
+    /// merkle root of the restored state must match
+    pub merkle_root: Hash,
     pub strikes: u64,
     pub snapshot: std::fs::File,
 }
@@ -381,6 +392,12 @@ where
     /// When set, indicates after how many blocks a new snapshot
     /// will be taken (counting from the first block)
     pub blocks_between_snapshots: Option<NonZeroU64>,
+    /// The policy used to order wrapper txs when proposing a block
+    pub tx_ordering: config::TxOrdering,
+    /// The tx history index, if enabled in the config
+    tx_history: Option<tx_history::TxHistoryIndex>,
+    /// The metrics of the node, if served in the config
+    pub metrics: Option<Arc<metrics::Metrics>>,
     /// Data for a node downloading and apply snapshots as part of
     /// the fast sync protocol.
     pub syncing: Option<SnapshotSync>,
@@ -404,6 +421,7 @@ pub struct EthereumOracleChannels {
     ethereum_receiver: EthereumReceiver,
     control_sender: oracle::control::Sender,
     last_processed_block_receiver: last_processed_block::Receiver,
+    endpoint_stats_receiver: oracle::quorum::endpoint_stats::Receiver,
 }
 
 impl EthereumOracleChannels {
@@ -411,11 +429,13 @@ impl EthereumOracleChannels {
         events_receiver: Receiver<EthereumEvent>,
         control_sender: oracle::control::Sender,
         last_processed_block_receiver: last_processed_block::Receiver,
+        endpoint_stats_receiver: oracle::quorum::endpoint_stats::Receiver,
     ) -> Self {
         Self {
             ethereum_receiver: EthereumReceiver::new(events_receiver),
             control_sender,
             last_processed_block_receiver,
+            endpoint_stats_receiver,
         }
     }
 }
@@ -521,6 +541,7 @@ where
             chain_id.clone(),
             native_token,
             config.shell.storage_read_past_height_limit,
+            config.shell.storage_mode,
             is_key_diff_storable,
         );
         let vp_wasm_cache_dir =
@@ -569,32 +590,73 @@ where
                             None
                         };
 
-                    wallet
-                        .take_validator_data()
-                        .map(|data| ShellMode::Validator {
-                            data,
-                            broadcast_sender,
-                            eth_oracle,
-                            validator_local_config,
-                            local_config,
-                        })
-                        .expect(
-                            "Validator data should have been stored in the \
-                             wallet",
-                        )
+                    let (address, signer) = match config.shell.remote_signer {
+                        Some(remote_signer) => {
+                            tracing::info!(
+                                "Using the remote signer at {}",
+                                remote_signer.endpoint
+                            );
+                            let auth_key_path = remote_signer
+                                .auth_key_path
+                                .clone()
+                                .unwrap_or_else(|| {
+                                    base_dir
+                                        .join(chain_id.as_str())
+                                        .join("remote_signer_auth_key")
+                                });
+                            let auth_key =
+                                crate::remote_signer::load_or_generate_key(
+                                    &auth_key_path,
+                                )
+                                .expect(
+                                    "Failed to load the remote signer \
+                                     authentication key",
+                                );
+                            (
+                                remote_signer.validator_address.clone(),
+                                NodeSigner::Remote(
+                                    crate::remote_signer::RemoteSigner::new(
+                                        &remote_signer,
+                                        auth_key,
+                                    ),
+                                ),
+                            )
+                        }
+                        None => {
+                            let data = wallet.take_validator_data().expect(
+                                "Validator data should have been stored in \
+                                 the wallet",
+                            );
+                            (
+                                data.address,
+                                NodeSigner::Local(LocalSigner {
+                                    protocol_key: data.keys.protocol_keypair,
+                                    eth_bridge_key: data
+                                        .keys
+                                        .eth_bridge_keypair,
+                                }),
+                            )
+                        }
+                    };
+                    ShellMode::Validator {
+                        address,
+                        signer,
+                        broadcast_sender,
+                        eth_oracle,
+                        validator_local_config,
+                        local_config,
+                    }
                 }
                 #[cfg(any(test, fuzzing))]
                 {
-                    let (protocol_keypair, eth_bridge_keypair) =
+                    let (protocol_key, eth_bridge_key) =
                         wallet::defaults::validator_keys();
                     ShellMode::Validator {
-                        data: ValidatorData {
-                            address: wallet::defaults::validator_address(),
-                            keys: ValidatorKeys {
-                                protocol_keypair,
-                                eth_bridge_keypair,
-                            },
-                        },
+                        address: wallet::defaults::validator_address(),
+                        signer: NodeSigner::Local(LocalSigner {
+                            protocol_key,
+                            eth_bridge_key,
+                        }),
                         broadcast_sender,
                         eth_oracle,
                         validator_local_config: None,
@@ -666,12 +728,32 @@ where
             event_log: EventLog::default(),
             scheduled_migration,
             blocks_between_snapshots: config.shell.blocks_between_snapshots,
+            tx_ordering: config.shell.tx_ordering,
+            tx_history: config
+                .shell
+                .tx_history_index
+                .then(tx_history::TxHistoryIndex::default),
+            metrics: config
+                .shell
+                .prometheus_listen_addr
+                .map(|_| Arc::new(metrics::Metrics::default())),
             syncing: None,
         };
         shell.update_eth_oracle(&Default::default());
         shell
     }
 
+    /// Record the time it took to handle a request, if metrics are enabled.
+    pub fn observe_handler_duration(
+        &self,
+        handler: metrics::Handler,
+        start: Instant,
+    ) {
+        if let Some(metrics) = self.metrics.as_ref() {
+            metrics.observe_duration(handler, start.elapsed());
+        }
+    }
+
     /// Return a reference to the [`EventLog`].
     #[inline]
     pub fn event_log(&self) -> &EventLog {
@@ -781,8 +863,21 @@ where
     pub fn commit(&mut self) -> shim::Response {
         self.bump_last_processed_eth_block();
 
+        let mut batch = D::batch();
+        if let Some(tx_history) = self.tx_history.as_mut() {
+            tx_history
+                .write_to_batch(
+                    self.state.db(),
+                    &mut batch,
+                    self.state.in_mem().block.height,
+                )
+                .expect(
+                    "Encountered a storage error while writing the tx history \
+                     index",
+                );
+        }
         self.state
-            .commit_block()
+            .commit_block_with_batch(batch)
             .expect("Encountered a storage error while committing a block");
         let committed_height = self.state.in_mem().get_last_block_height();
         migrations::commit(
@@ -790,6 +885,9 @@ where
             committed_height,
             &mut self.scheduled_migration,
         );
+        if let Some(metrics) = self.metrics.as_ref() {
+            metrics.set_db_stats(self.state.db().stats());
+        }
         let merkle_root = self.state.in_mem().merkle_root();
 
         tracing::info!(
@@ -823,7 +921,13 @@ where
             self.state
                 .db()
                 .path()
-                .map(|p| (p, self.state.in_mem().get_last_block_height()))
+                .map(|p| {
+                    (
+                        p,
+                        self.state.in_mem().get_last_block_height(),
+                        Hash(self.state.in_mem().merkle_root().0),
+                    )
+                })
                 .into()
         } else {
             TakeSnapshot::No
@@ -871,19 +975,18 @@ where
 
         let ext = self.craft_extension();
 
-        let protocol_key = self
+        let signer = self
             .mode
-            .get_protocol_key()
-            .expect("Validators should have protocol keys");
-
-        let protocol_txs = iter_protocol_txs(ext).map(|protocol_tx| {
-            protocol_tx
-                .sign(protocol_key, self.chain_id.clone())
-                .to_bytes()
-        });
-
-        for tx in protocol_txs {
-            self.mode.broadcast(tx);
+            .get_signer()
+            .expect("Validators should have a signer");
+
+        for protocol_tx in iter_protocol_txs(ext) {
+            match protocol_tx.sign_with(signer, self.chain_id.clone()) {
+                Ok(tx) => self.mode.broadcast(tx.to_bytes()),
+                Err(err) => {
+                    tracing::error!("Failed to sign a protocol tx: {err}")
+                }
+            }
         }
     }
 
@@ -908,18 +1011,22 @@ where
             return;
         }
         if let Some(vote_extension) = self.sign_ethereum_events(eth_events) {
-            let protocol_key = self
+            let signer = self
                 .mode
-                .get_protocol_key()
-                .expect("Validators should have protocol keys");
+                .get_signer()
+                .expect("Validators should have a signer");
 
-            let signed_tx = EthereumTxData::EthEventsVext(
+            match EthereumTxData::EthEventsVext(
                 namada_vote_ext::ethereum_events::SignedVext(vote_extension),
             )
-            .sign(protocol_key, self.chain_id.clone())
-            .to_bytes();
-
-            self.mode.broadcast(signed_tx);
+            .sign_with(signer, self.chain_id.clone())
+            {
+                Ok(signed_tx) => self.mode.broadcast(signed_tx.to_bytes()),
+                Err(err) => tracing::error!(
+                    "Failed to sign a protocol tx with expired Ethereum \
+                     events: {err}"
+                ),
+            }
         }
     }
 
@@ -1681,10 +1788,13 @@ pub mod test_utils {
             let (_, last_processed_block_receiver) =
                 last_processed_block::channel();
             let (control_sender, control_receiver) = oracle::control::channel();
+            let (_, endpoint_stats_receiver) =
+                oracle::quorum::endpoint_stats::channel();
             let eth_oracle = EthereumOracleChannels::new(
                 eth_receiver,
                 control_sender,
                 last_processed_block_receiver,
+                endpoint_stats_receiver,
             );
             let base_dir = tempdir().unwrap().as_ref().canonicalize().unwrap();
             let vp_wasm_compilation_cache = 50 * 1024 * 1024; // 50 kiB
@@ -2979,6 +3089,7 @@ mod shell_tests {
             next_chunk: 0,
             height: BlockHeight::first(),
             expected: vec![],
+            merkle_root: Hash(original_root.0),
             strikes: 0,
             snapshot,
         });
@@ -20,7 +20,8 @@ use super::block_alloc::states::{
     WithNormalTxs, WithoutNormalTxs,
 };
 use super::block_alloc::{AllocFailure, BlockAllocator, BlockResources};
-use crate::config::ValidatorLocalConfig;
+use super::tx_ordering::{self, Candidate};
+use crate::config::{TxOrdering, ValidatorLocalConfig};
 use crate::protocol::{self, ShellParams};
 use crate::shell::ShellMode;
 use crate::shims::abcipp_shim_types::shim::{response, TxBytes};
@@ -126,8 +127,9 @@ where
         let mut vp_wasm_cache = self.vp_wasm_cache.clone();
         let mut tx_wasm_cache = self.tx_wasm_cache.clone();
 
-        let txs = txs
-            .iter()
+        let txs = self
+            .order_normal_txs(txs)
+            .into_iter()
             .enumerate()
             .filter_map(|(tx_index, tx_bytes)| {
                 let result = validate_wrapper_bytes(
@@ -188,6 +190,33 @@ where
         (txs, alloc)
     }
 
+    /// Order the wrapper txs retrieved from CometBFT's mempool according
+    /// to the configured [`TxOrdering`] policy. Txs dropped by the policy
+    /// remain in the mempool.
+    fn order_normal_txs<'tx>(&self, txs: &'tx [TxBytes]) -> Vec<&'tx TxBytes> {
+        let candidates = txs
+            .iter()
+            .map(|tx_bytes| {
+                let (signer, priority) = match self.tx_ordering {
+                    TxOrdering::Fifo => (None, Default::default()),
+                    TxOrdering::FeePriority
+                    | TxOrdering::SignerFairness { .. } => {
+                        tx_ordering::fee_priority(&self.state, tx_bytes)
+                            .map_or((None, Default::default()), |(pk, fee)| {
+                                (Some(pk), fee)
+                            })
+                    }
+                };
+                Candidate {
+                    item: tx_bytes,
+                    signer,
+                    priority,
+                }
+            })
+            .collect();
+        tx_ordering::order_candidates(&self.tx_ordering, candidates)
+    }
+
     /// Allocate an initial set of protocol txs and advance to the
     /// next allocation state.
     fn build_protocol_tx_with_normal_txs(
@@ -425,7 +454,7 @@ mod test_prepare_proposal {
     use namada_apps_lib::wallet;
     use namada_replay_protection as replay_protection;
     use namada_sdk::ethereum_events::EthereumEvent;
-    use namada_sdk::key::RefTo;
+    use namada_sdk::key::{common, RefTo};
     use namada_sdk::proof_of_stake::storage::{
         consensus_validator_set_handle,
         read_consensus_validator_set_addresses_with_stake, read_pos_params,
@@ -1510,4 +1539,49 @@ mod test_prepare_proposal {
 
         assert_eq!(computed_min_gas_price, consensus_min_gas_price);
     }
+
+    /// Test that wrapper txs are proposed in mempool order with the FIFO
+    /// policy and by decreasing fee with the fee priority policy.
+    #[test]
+    fn test_fee_priority_ordering() {
+        let (mut shell, _recv, _, _) = test_utils::setup();
+
+        let make_wrapper = |keypair: common::SecretKey, fee: u64| {
+            let mut wrapper =
+                Tx::from_type(TxType::Wrapper(Box::new(WrapperTx::new(
+                    Fee {
+                        amount_per_gas_unit: DenominatedAmount::native(
+                            fee.into(),
+                        ),
+                        token: shell.state.in_mem().native_token.clone(),
+                    },
+                    keypair.ref_to(),
+                    GAS_LIMIT.into(),
+                ))));
+            wrapper.header.chain_id = shell.chain_id.clone();
+            wrapper
+                .set_code(Code::new("wasm_code".as_bytes().to_owned(), None));
+            wrapper
+                .set_data(Data::new("transaction data".as_bytes().to_owned()));
+            wrapper.sign_wrapper(keypair);
+            TxBytes::from(wrapper.to_bytes())
+        };
+        let cheap = make_wrapper(wallet::defaults::albert_keypair(), 100);
+        let expensive = make_wrapper(wallet::defaults::bertha_keypair(), 200);
+
+        let req = RequestPrepareProposal {
+            txs: vec![cheap.clone(), expensive.clone()],
+            max_tx_bytes: 0,
+            time: None,
+            ..Default::default()
+        };
+
+        shell.tx_ordering = TxOrdering::Fifo;
+        let result = shell.prepare_proposal(req.clone());
+        assert_eq!(result.txs, vec![cheap.clone(), expensive.clone()]);
+
+        shell.tx_ordering = TxOrdering::FeePriority;
+        let result = shell.prepare_proposal(req);
+        assert_eq!(result.txs, vec![expensive, cheap]);
+    }
 }
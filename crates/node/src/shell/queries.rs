@@ -36,6 +36,7 @@ where
                 tx_wasm_cache: self.tx_wasm_cache.read_only(),
                 storage_read_past_height_limit: self
                     .storage_read_past_height_limit,
+                tx_history_index: self.tx_history.is_some(),
             };
             namada_sdk::queries::handle_path(ctx, &query)
         };
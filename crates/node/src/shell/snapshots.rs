@@ -1,18 +1,18 @@
-use std::io::Write;
+use std::io::{Seek, Write};
+use std::path::{Path, PathBuf};
 
 use namada_sdk::arith::checked;
-use namada_sdk::borsh::{BorshDeserialize, BorshSerializeExt};
 use namada_sdk::hash::{Hash, Sha256Hasher};
-use namada_sdk::state::{BlockHeight, StorageRead};
+use namada_sdk::state::{BlockHeight, FullAccessState, StateRead, StorageRead};
 
-use super::SnapshotSync;
+use super::{Error, ShellResult, SnapshotSync};
 use crate::shell::Shell;
-use crate::storage;
 use crate::storage::{DbSnapshot, DbSnapshotMeta};
 use crate::tendermint::abci::types::Snapshot;
 use crate::tendermint::abci::{
     request as tm_request, response as tm_response, ApplySnapshotChunkResult,
 };
+use crate::{config, storage};
 
 pub const MAX_SENDER_STRIKES: u64 = 5;
 
@@ -38,18 +38,14 @@ impl Shell<storage::PersistentDB, Sha256Hasher> {
             );
             let Ok(snapshots) = snapshots
                 .map(|result| {
-                    let DbSnapshotMeta {
-                        height,
-                        chunk_hashes,
-                        root_hash,
-                    } = result?;
+                    let meta = result?;
                     std::io::Result::Ok(Snapshot {
-                        height: u32::try_from(height.0).unwrap().into(),
+                        height: u32::try_from(meta.height.0).unwrap().into(),
                         format: DbSnapshot::FORMAT_MAGIC,
                         #[allow(clippy::cast_possible_truncation)]
-                        chunks: chunk_hashes.len() as u32,
-                        hash: root_hash.0.to_vec().into(),
-                        metadata: chunk_hashes.serialize_to_vec().into(),
+                        chunks: meta.chunk_hashes.len() as u32,
+                        hash: meta.root_hash.0.to_vec().into(),
+                        metadata: meta.abci_metadata().into(),
                     })
                 })
                 .collect()
@@ -109,54 +105,24 @@ impl Shell<storage::PersistentDB, Sha256Hasher> {
             );
             return tm_response::OfferSnapshot::Reject;
         }
-        match self.syncing.as_ref() {
-            None => {
-                #[allow(clippy::disallowed_methods)]
-                if self.state.get_block_height().unwrap_or_default().0
-                    < u64::from(req.snapshot.height)
-                {
-                    let Ok(chunks) =
-                        Vec::<Hash>::try_from_slice(&req.snapshot.metadata)
-                    else {
-                        return tm_response::OfferSnapshot::Reject;
-                    };
-                    self.syncing = Some(SnapshotSync {
-                        next_chunk: 0,
-                        height: u64::from(req.snapshot.height).into(),
-                        expected: chunks,
-                        strikes: 0,
-                        snapshot: tempfile::tempfile()
-                            .expect("Failed to create snapshot temp file"),
-                    });
-                    tracing::info!("Accepting snapshot offer");
-                    tm_response::OfferSnapshot::Accept
-                } else {
-                    tracing::info!("Rejecting snapshot offer");
-                    tm_response::OfferSnapshot::Reject
-                }
+        #[allow(clippy::disallowed_methods)]
+        let synced_height = match self.syncing.as_ref() {
+            None => self.state.get_block_height().unwrap_or_default(),
+            Some(snapshot_sync) => snapshot_sync.height,
+        };
+        if synced_height.0 >= u64::from(req.snapshot.height) {
+            tracing::info!("Rejecting snapshot offer");
+            return tm_response::OfferSnapshot::Reject;
+        }
+        match snapshot_sync_of(&req) {
+            Ok(snapshot_sync) => {
+                self.syncing = Some(snapshot_sync);
+                tracing::info!("Accepting snapshot offer");
+                tm_response::OfferSnapshot::Accept
             }
-            Some(snapshot_sync) => {
-                if snapshot_sync.height.0 < u64::from(req.snapshot.height) {
-                    let Ok(chunks) =
-                        Vec::<Hash>::try_from_slice(&req.snapshot.metadata)
-                    else {
-                        tracing::info!("Rejecting snapshot offer");
-                        return tm_response::OfferSnapshot::Reject;
-                    };
-                    self.syncing = Some(SnapshotSync {
-                        next_chunk: 0,
-                        height: u64::from(req.snapshot.height).into(),
-                        expected: chunks,
-                        strikes: 0,
-                        snapshot: tempfile::tempfile()
-                            .expect("Failed to create snapshot temp file"),
-                    });
-                    tracing::info!("Accepting snapshot offer");
-                    tm_response::OfferSnapshot::Accept
-                } else {
-                    tracing::info!("Rejecting snapshot offer");
-                    tm_response::OfferSnapshot::Reject
-                }
+            Err(err) => {
+                tracing::info!("Rejecting snapshot offer: {err}");
+                tm_response::OfferSnapshot::Reject
             }
         }
     }
@@ -258,6 +224,17 @@ impl Shell<storage::PersistentDB, Sha256Hasher> {
         // check if all chunks have been saved, and restore the
         // database from the fetched tar archive
         if snapshot_sync.next_chunk == snapshot_sync.expected.len() as u64 {
+            if let Err(err) = self.verify_synced_snapshot() {
+                tracing::error!(
+                    "Rejecting snapshot that failed to verify: {err}"
+                );
+                self.syncing = None;
+                return tm_response::ApplySnapshotChunk {
+                    result: ApplySnapshotChunkResult::RejectSnapshot,
+                    refetch_chunks: vec![],
+                    reject_senders: vec![],
+                };
+            }
             self.restore_database_from_state_sync();
             self.syncing = None;
             tracing::info!("Snapshot completely applied");
@@ -269,4 +246,431 @@ impl Shell<storage::PersistentDB, Sha256Hasher> {
             reject_senders: vec![],
         }
     }
+
+    /// Verify the state of a fully fetched snapshot against the trusted app
+    /// hash of its height. The snapshot is unpacked into a temporary
+    /// directory, such that the DB of the node is only replaced by a verified
+    /// state.
+    fn verify_synced_snapshot(&mut self) -> ShellResult<()> {
+        let Some(snapshot_sync) = self.syncing.as_mut() else {
+            return Ok(());
+        };
+        snapshot_sync.snapshot.rewind().map_err(Error::Snapshot)?;
+        let unpack_dir =
+            tempfile::tempdir_in(&self.base_dir).map_err(Error::Snapshot)?;
+        DbSnapshot::unpack(&mut snapshot_sync.snapshot, unpack_dir.path())
+            .map_err(Error::Snapshot)?;
+
+        let in_mem = self.state.in_mem();
+        let state = FullAccessState::open(
+            unpack_dir.path().join("db"),
+            None,
+            in_mem.chain_id.clone(),
+            in_mem.native_token.clone(),
+            in_mem.storage_read_past_height_limit,
+            in_mem.storage_mode,
+            super::is_key_diff_storable,
+        );
+        verify_restored_state(
+            &state,
+            snapshot_sync.height,
+            snapshot_sync.merkle_root,
+        )
+    }
+}
+
+/// Check an offered snapshot against its root hash and the trusted app hash
+/// of its height, which CometBFT verifies with its light client, and prepare
+/// to fetch its chunks.
+fn snapshot_sync_of(
+    req: &tm_request::OfferSnapshot,
+) -> Result<SnapshotSync, String> {
+    let (expected, merkle_root) =
+        DbSnapshotMeta::decode_abci_metadata(&req.snapshot.metadata)
+            .map_err(|err| format!("Invalid metadata: {err}"))?;
+    let root_hash = DbSnapshotMeta::root_hash_of(&expected, &merkle_root);
+    if req.snapshot.hash.as_ref() != root_hash.0.as_slice() {
+        return Err(format!(
+            "The root hash of the snapshot did not match, got {root_hash}"
+        ));
+    }
+    if req.app_hash.as_bytes() != merkle_root.0.as_slice() {
+        return Err(format!(
+            "The merkle root {merkle_root} of the snapshot did not match the \
+             trusted app hash"
+        ));
+    }
+    Ok(SnapshotSync {
+        next_chunk: 0,
+        height: u64::from(req.snapshot.height).into(),
+        expected,
+        merkle_root,
+        strikes: 0,
+        snapshot: tempfile::tempfile()
+            .expect("Failed to create snapshot temp file"),
+    })
+}
+
+/// Export a snapshot of the DB of a stopped node to `out_dir`, using the
+/// same chunked format that is served over state sync. The snapshot is
+/// written to `<out_dir>/snapshots/block-<height>`, mirroring the layout
+/// of a node's base directory, along with the merkle root of the state.
+///
+/// Since the DB only holds the full state of the last committed block,
+/// `height` (if provided) must match the last committed height.
+pub fn export_snapshot(
+    config: config::Ledger,
+    height: Option<BlockHeight>,
+    out_dir: PathBuf,
+) -> ShellResult<DbSnapshotMeta> {
+    // NB: opening the DB for writing guarantees that the node is stopped
+    let state = open_state(&config, &config.shell.db_dir(&config.chain_id))?;
+
+    let last_height = state
+        .in_mem()
+        .last_block
+        .as_ref()
+        .map(|last_block| last_block.height)
+        .ok_or_else(|| {
+            snapshot_error(
+                std::io::ErrorKind::NotFound,
+                "The DB does not contain any committed block".to_string(),
+            )
+        })?;
+    if let Some(height) = height {
+        if height != last_height {
+            return Err(snapshot_error(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "A snapshot can only be exported at the last committed \
+                     height {last_height}, but {height} was requested"
+                ),
+            ));
+        }
+    }
+    let merkle_root = Hash(state.in_mem().merkle_root().0);
+
+    tracing::info!(
+        "Exporting snapshot at height {last_height} with merkle root \
+         {merkle_root}"
+    );
+    let snapshot = state
+        .db()
+        .checkpoint(out_dir.clone(), last_height)
+        .map_err(|e| Error::Storage(namada_sdk::state::Error::new(e)))?;
+    snapshot.package(merkle_root).map_err(Error::Snapshot)?;
+
+    DbSnapshot::load_snapshot_metadata(&out_dir, [last_height.0])
+        .next()
+        .expect("Metadata of a single snapshot was requested")
+        .map_err(Error::Snapshot)
+}
+
+/// Import a snapshot exported with [`export_snapshot`] from `from_dir`
+/// into the DB directory of the node. If no `height` is provided, the
+/// latest snapshot found in `from_dir` is imported.
+///
+/// The snapshot itself is untrusted, so its state is verified against the
+/// trusted `app_hash` of its height given by the operator. The hash of each
+/// chunk and the root hash of the snapshot are verified against its metadata
+/// before the DB is unpacked into a temporary directory. The unpacked state
+/// is then opened and its merkle root verified against `app_hash`, before
+/// the DB is moved in place. The node must not have an existing DB.
+pub fn import_snapshot(
+    config: config::Ledger,
+    from_dir: PathBuf,
+    height: Option<BlockHeight>,
+    app_hash: Hash,
+) -> ShellResult<DbSnapshotMeta> {
+    let db_path = config.shell.db_dir(&config.chain_id);
+    if db_path.exists() {
+        return Err(snapshot_error(
+            std::io::ErrorKind::AlreadyExists,
+            format!(
+                "A DB already exists at {}. Reset the node before importing a \
+                 snapshot",
+                db_path.to_string_lossy()
+            ),
+        ));
+    }
+
+    let height = match height {
+        Some(height) => height,
+        None => DbSnapshot::heights_of_stored_snapshots(&from_dir)
+            .map_err(Error::Snapshot)?
+            .into_iter()
+            .max()
+            .map(BlockHeight)
+            .ok_or_else(|| {
+                snapshot_error(
+                    std::io::ErrorKind::NotFound,
+                    format!(
+                        "No snapshots found in {}",
+                        from_dir.to_string_lossy()
+                    ),
+                )
+            })?,
+    };
+
+    tracing::info!("Verifying snapshot at height {height}");
+    let mut archive = tempfile::tempfile().map_err(Error::Snapshot)?;
+    let meta = DbSnapshot::assemble_verified(height, &from_dir, &mut archive)
+        .map_err(Error::Snapshot)?;
+    if meta.merkle_root != app_hash {
+        return Err(snapshot_error(
+            std::io::ErrorKind::InvalidData,
+            format!(
+                "The merkle root {} of the snapshot did not match the trusted \
+                 app hash {app_hash}",
+                meta.merkle_root
+            ),
+        ));
+    }
+    archive.rewind().map_err(Error::Snapshot)?;
+
+    tracing::info!("Unpacking snapshot at height {height}");
+    // NB: the archive holds a single `db` dir, which we unpack next to the
+    // DB path, such that it can be moved in place without copying once
+    // verified. The temporary dir is removed if the verification fails.
+    let db_parent = db_path.parent().unwrap_or_else(|| Path::new("."));
+    std::fs::create_dir_all(db_parent).map_err(Error::Snapshot)?;
+    let unpack_dir =
+        tempfile::tempdir_in(db_parent).map_err(Error::Snapshot)?;
+    DbSnapshot::unpack(&mut archive, unpack_dir.path())
+        .map_err(Error::Snapshot)?;
+    let unpacked_db = unpack_dir.path().join("db");
+    {
+        let state = open_state(&config, &unpacked_db)?;
+        verify_restored_state(&state, meta.height, app_hash)?;
+    }
+    std::fs::rename(unpacked_db, &db_path).map_err(Error::Snapshot)?;
+    Ok(meta)
+}
+
+/// Check that the state restored from a snapshot is at the height of the
+/// snapshot and that its merkle root matches the trusted app hash.
+fn verify_restored_state(
+    state: &FullAccessState<storage::PersistentDB, Sha256Hasher>,
+    height: BlockHeight,
+    app_hash: Hash,
+) -> ShellResult<()> {
+    let restored_height = state
+        .in_mem()
+        .last_block
+        .as_ref()
+        .map(|last_block| last_block.height);
+    if restored_height != Some(height) {
+        return Err(snapshot_error(
+            std::io::ErrorKind::InvalidData,
+            format!(
+                "The restored DB is at height {restored_height:?}, expected \
+                 {height}"
+            ),
+        ));
+    }
+    let merkle_root = Hash(state.in_mem().merkle_root().0);
+    if merkle_root != app_hash {
+        return Err(snapshot_error(
+            std::io::ErrorKind::InvalidData,
+            format!(
+                "The merkle root of the restored state did not match, \
+                 expected {app_hash}, got {merkle_root}"
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// Open the state in the DB at `db_path` of a stopped node. This rebuilds the
+/// merkle tree of the last committed block.
+fn open_state(
+    config: &config::Ledger,
+    db_path: &Path,
+) -> ShellResult<FullAccessState<storage::PersistentDB, Sha256Hasher>> {
+    let chain_dir = config.shell.base_dir.join(config.chain_id.as_str());
+    let read_native_token = || {
+        config::genesis::chain::Finalized::read_native_token(&chain_dir)
+            .map_err(|e| {
+                snapshot_error(
+                    std::io::ErrorKind::NotFound,
+                    format!("Failed to read the native token: {e}"),
+                )
+            })
+    };
+    // For tests use hard-coded native token addr if there's no genesis ...
+    #[cfg(test)]
+    let native_token = if chain_dir
+        .join(config::genesis::templates::TOKENS_FILE_NAME)
+        .exists()
+    {
+        read_native_token()?
+    } else {
+        namada_sdk::address::testing::nam()
+    };
+    // ... Otherwise, look it up from the genesis file
+    #[cfg(not(test))]
+    let native_token = read_native_token()?;
+
+    Ok(FullAccessState::open(
+        db_path,
+        None,
+        config.chain_id.clone(),
+        native_token,
+        config.shell.storage_read_past_height_limit,
+        config.shell.storage_mode,
+        super::is_key_diff_storable,
+    ))
+}
+
+fn snapshot_error(kind: std::io::ErrorKind, msg: String) -> Error {
+    Error::Snapshot(std::io::Error::new(kind, msg))
+}
+
+#[cfg(test)]
+mod test {
+    use namada_sdk::state::StorageWrite;
+    use namada_sdk::storage::Key;
+    use tempfile::tempdir;
+
+    use super::*;
+    use crate::config::TendermintMode;
+    use crate::storage::SnapshotPath;
+
+    /// Commit a block at the first height writing `value` in the DB of a
+    /// new node, returning its config and the merkle root of its state.
+    fn commit_first_block(
+        node_dir: &Path,
+        value: u8,
+    ) -> (config::Ledger, Hash) {
+        let config = config::Ledger::new(
+            node_dir,
+            Default::default(),
+            TendermintMode::Full,
+        );
+        let mut state =
+            open_state(&config, &config.shell.db_dir(&config.chain_id))
+                .expect("Test failed");
+        state.in_mem_mut().block.height = BlockHeight::first();
+        state
+            .write(
+                &Key::parse("bing/bang/bong").expect("Test failed"),
+                [value; 64],
+            )
+            .expect("Test failed");
+        state.commit_block().expect("Test failed");
+        let merkle_root = Hash(state.in_mem().merkle_root().0);
+        (config, merkle_root)
+    }
+
+    /// Test that a snapshot exported from the DB of a node restores
+    /// the same state when imported into another node, and that a
+    /// snapshot whose state doesn't match the trusted app hash is rejected
+    /// without creating a DB.
+    #[test]
+    fn test_export_import_snapshot() {
+        let node_dir = tempdir().expect("Test failed");
+        let (config, merkle_root) = commit_first_block(node_dir.path(), 1);
+
+        // a snapshot can only be exported at the last committed height
+        let out_dir = tempdir().expect("Test failed");
+        let result = export_snapshot(
+            config.clone(),
+            Some(BlockHeight(2)),
+            out_dir.path().to_path_buf(),
+        );
+        assert!(result.is_err());
+        let exported =
+            export_snapshot(config, None, out_dir.path().to_path_buf())
+                .expect("Test failed");
+        assert_eq!(exported.height, BlockHeight::first());
+        assert_eq!(exported.merkle_root, merkle_root);
+
+        // a snapshot that doesn't match the trusted app hash is rejected
+        let import_dir = tempdir().expect("Test failed");
+        let import_config = config::Ledger::new(
+            import_dir.path(),
+            Default::default(),
+            TendermintMode::Full,
+        );
+        let import_db = import_config.shell.db_dir(&import_config.chain_id);
+        let result = import_snapshot(
+            import_config.clone(),
+            out_dir.path().to_path_buf(),
+            None,
+            Hash::sha256(b"trusted"),
+        );
+        assert!(result.is_err());
+        assert!(!import_db.exists());
+
+        // import the snapshot into a new node
+        let imported = import_snapshot(
+            import_config.clone(),
+            out_dir.path().to_path_buf(),
+            None,
+            merkle_root,
+        )
+        .expect("Test failed");
+        assert_eq!(imported.root_hash, exported.root_hash);
+        let state =
+            open_state(&import_config, &import_db).expect("Test failed");
+        assert_eq!(
+            state.in_mem().get_last_block_height(),
+            BlockHeight::first()
+        );
+        assert_eq!(Hash(state.in_mem().merkle_root().0), merkle_root);
+        assert_eq!(
+            state
+                .read_bytes(&Key::parse("bing/bang/bong").expect("Test failed"))
+                .expect("Test failed"),
+            Some(vec![1u8; 64])
+        );
+        drop(state);
+
+        // importing over an existing DB is rejected
+        let result = import_snapshot(
+            import_config,
+            out_dir.path().to_path_buf(),
+            None,
+            merkle_root,
+        );
+        assert!(result.is_err());
+
+        // forge a consistent snapshot of another state, which claims the
+        // trusted merkle root
+        let other_dir = tempdir().expect("Test failed");
+        let (other_config, _) = commit_first_block(other_dir.path(), 2);
+        let forged_dir = tempdir().expect("Test failed");
+        let forged = export_snapshot(
+            other_config,
+            None,
+            forged_dir.path().to_path_buf(),
+        )
+        .expect("Test failed");
+        let forged_path =
+            SnapshotPath(forged_dir.path().to_path_buf(), BlockHeight::first());
+        std::fs::write(forged_path.merkle_root(), merkle_root)
+            .expect("Test failed");
+        std::fs::write(
+            forged_path.chunks_root_hash(),
+            DbSnapshotMeta::root_hash_of(&forged.chunk_hashes, &merkle_root),
+        )
+        .expect("Test failed");
+        let forged_import_dir = tempdir().expect("Test failed");
+        let forged_import_config = config::Ledger::new(
+            forged_import_dir.path(),
+            Default::default(),
+            TendermintMode::Full,
+        );
+        let result = import_snapshot(
+            forged_import_config.clone(),
+            forged_dir.path().to_path_buf(),
+            None,
+            merkle_root,
+        );
+        assert!(result.is_err());
+        let db_path = forged_import_config
+            .shell
+            .db_dir(&forged_import_config.chain_id);
+        assert!(!db_path.exists());
+    }
 }
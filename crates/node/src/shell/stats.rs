@@ -17,6 +17,7 @@ pub struct InternalStats {
     tx_cache_size: (usize, usize),
     tx_executed: HashMap<String, u64>,
     wrapper_txs: u64,
+    gas_used: u64,
 }
 
 impl InternalStats {
@@ -77,6 +78,32 @@ impl InternalStats {
         self.wrapper_txs += 1;
     }
 
+    pub fn add_gas_used(&mut self, gas: u64) {
+        self.gas_used = self.gas_used.saturating_add(gas);
+    }
+
+    /// The number of inner txs by outcome, labeled for metrics.
+    pub fn tx_outcomes(&self) -> [(&'static str, u64); 5] {
+        [
+            ("successful", self.successful_tx),
+            ("rejected", self.rejected_txs),
+            ("errored", self.errored_txs),
+            ("unrun", self.unrun_txs),
+            (
+                "discarded_in_failed_batch",
+                self.successful_tx_in_failed_batch,
+            ),
+        ]
+    }
+
+    pub fn wrapper_txs(&self) -> u64 {
+        self.wrapper_txs
+    }
+
+    pub fn gas_used(&self) -> u64 {
+        self.gas_used
+    }
+
     /// Merges two intances of [`InternalStats`]. The caches stats are left
     /// untouched.
     pub fn merge(&mut self, other: Self) {
@@ -93,6 +120,7 @@ impl InternalStats {
                 .or_insert(cnt);
         }
         self.wrapper_txs += other.wrapper_txs;
+        self.gas_used = self.gas_used.saturating_add(other.gas_used);
     }
 }
 
@@ -102,8 +130,8 @@ impl Display for InternalStats {
             f,
             "Applied {} transactions. Wrappers: {}, successful inner txs: {}, \
              rejected inner txs: {}, errored inner txs: {}, unrun txs: {}, \
-             valid txs discarded by failing atomic batch: {}, vp cache size: \
-             {} - {}, tx cache size {} - {}",
+             valid txs discarded by failing atomic batch: {}, gas used: {}, \
+             vp cache size: {} - {}, tx cache size {} - {}",
             self.successful_tx + self.rejected_txs + self.errored_txs,
             self.wrapper_txs,
             self.successful_tx,
@@ -111,6 +139,7 @@ impl Display for InternalStats {
             self.errored_txs,
             self.unrun_txs,
             self.successful_tx_in_failed_batch,
+            self.gas_used,
             self.vp_cache_size.0,
             self.vp_cache_size.1,
             self.tx_cache_size.0,
@@ -47,7 +47,7 @@ use crate::ethereum_oracle::test_tools::mock_web3_client::{
     TestOracle, Web3Client, Web3Controller,
 };
 use crate::ethereum_oracle::{
-    control, last_processed_block, try_process_eth_events,
+    control, last_processed_block, quorum, try_process_eth_events,
 };
 use crate::shell::testing::utils::TestDir;
 use crate::shell::{EthereumOracleChannels, Shell};
@@ -196,6 +196,7 @@ pub fn mock_services(cfg: MockServicesCfg) -> MockServicesPackage {
     let (last_processed_block_sender, last_processed_block_receiver) =
         last_processed_block::channel();
     let (control_sender, control_receiver) = control::channel();
+    let (_, endpoint_stats_receiver) = quorum::endpoint_stats::channel();
     let eth_oracle_controller = eth_client.controller();
     let oracle = TestOracle::new(
         Either::Left(eth_client),
@@ -209,6 +210,7 @@ pub fn mock_services(cfg: MockServicesCfg) -> MockServicesPackage {
         eth_receiver,
         control_sender,
         last_processed_block_receiver,
+        endpoint_stats_receiver,
     );
     let (tx_broadcaster, tx_receiver) = mpsc::unbounded_channel();
     let ethereum_oracle = MockEthOracle {
@@ -834,6 +836,7 @@ impl Client for MockNode {
                 vp_wasm_cache: borrowed.vp_wasm_cache.read_only(),
                 tx_wasm_cache: borrowed.tx_wasm_cache.read_only(),
                 storage_read_past_height_limit: None,
+                tx_history_index: borrowed.tx_history.is_some(),
             };
             rpc.handle(ctx, &request)
         }
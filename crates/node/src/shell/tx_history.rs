@@ -0,0 +1,323 @@
+//! Index of the inner txs that touched each address, maintained by nodes
+//! that enable `tx_history_index` in their config.
+//!
+//! Entries are collected while finalizing a block and written to the DB in
+//! the same batch as the block. Each address has its own sequence of entries,
+//! such that a page of its history can be read without going through the
+//! previous ones. The entries appended at the last block are removed on
+//! rollback.
+
+use std::collections::BTreeMap;
+
+use either::Either;
+use namada_sdk::address::Address;
+use namada_sdk::borsh::{BorshDeserialize, BorshSerializeExt};
+use namada_sdk::chain::BlockHeight;
+use namada_sdk::events::extend::UserAccount;
+use namada_sdk::events::history::{TxHistoryEntry, TxHistoryKind};
+use namada_sdk::events::Event;
+use namada_sdk::governance::VoteProposalData;
+use namada_sdk::hash::Hash;
+use namada_sdk::state::{DbResult, DB};
+use namada_sdk::token::event::types::{BURN, MINT, TRANSFER};
+use namada_sdk::token::event::{
+    SourceAccounts, TargetAccount, TargetAccounts, TokenAddress,
+};
+use namada_sdk::tx::data::pos::{Bond, Unbond};
+use namada_sdk::tx::data::{compute_inner_tx_hash, TxResult};
+use namada_sdk::tx::{
+    Section, Tx, TX_BOND_WASM, TX_UNBOND_WASM, TX_VOTE_PROPOSAL,
+};
+
+/// Tx history entries of the block being finalized, pending to be written
+/// to the DB.
+#[derive(Debug, Default)]
+pub(super) struct TxHistoryIndex {
+    pending: BTreeMap<Address, Vec<TxHistoryEntry>>,
+}
+
+impl TxHistoryIndex {
+    /// Record the addresses touched by the accepted inner txs of `tx`.
+    pub fn record_batch<E>(
+        &mut self,
+        tx: &Tx,
+        wrapper_hash: Option<&Hash>,
+        tx_result: &TxResult<E>,
+        height: BlockHeight,
+    ) {
+        for cmt in tx.commitments() {
+            let Some(Ok(result)) =
+                tx_result.get_inner_tx_result(wrapper_hash, Either::Right(cmt))
+            else {
+                continue;
+            };
+            if !result.is_accepted() {
+                continue;
+            }
+            let inner_tx_hash =
+                compute_inner_tx_hash(wrapper_hash, Either::Right(cmt));
+            let mut touched = vec![];
+
+            for event in &result.events {
+                touched.extend(token_event_kinds(event));
+            }
+
+            let code_tag = tx
+                .get_section(cmt.code_sechash())
+                .and_then(|section| Section::code_sec(section.as_ref()))
+                .and_then(|code| code.tag);
+            let data = tx.data(cmt).unwrap_or_default();
+            match code_tag.as_deref() {
+                Some(TX_BOND_WASM) => {
+                    if let Ok(bond) = Bond::try_from_slice(&data) {
+                        let source =
+                            bond.source.unwrap_or(bond.validator.clone());
+                        let kind = TxHistoryKind::Bond {
+                            source: source.clone(),
+                            validator: bond.validator.clone(),
+                        };
+                        touched.push((source, kind.clone()));
+                        touched.push((bond.validator, kind));
+                    }
+                }
+                Some(TX_UNBOND_WASM) => {
+                    if let Ok(unbond) = Unbond::try_from_slice(&data) {
+                        let source =
+                            unbond.source.unwrap_or(unbond.validator.clone());
+                        let kind = TxHistoryKind::Unbond {
+                            source: source.clone(),
+                            validator: unbond.validator.clone(),
+                        };
+                        touched.push((source, kind.clone()));
+                        touched.push((unbond.validator, kind));
+                    }
+                }
+                Some(TX_VOTE_PROPOSAL) => {
+                    if let Ok(vote) = VoteProposalData::try_from_slice(&data) {
+                        touched.push((
+                            vote.voter,
+                            TxHistoryKind::Vote {
+                                proposal_id: vote.id,
+                            },
+                        ));
+                    }
+                }
+                _ => {}
+            }
+
+            for (address, kind) in touched {
+                self.record(address, inner_tx_hash, height, kind);
+            }
+        }
+    }
+
+    /// Record a single entry, unless the same entry was already recorded
+    /// for the address.
+    fn record(
+        &mut self,
+        address: Address,
+        inner_tx_hash: Hash,
+        height: BlockHeight,
+        kind: TxHistoryKind,
+    ) {
+        let entry = TxHistoryEntry {
+            inner_tx_hash,
+            height,
+            kind,
+        };
+        let entries = self.pending.entry(address).or_default();
+        if !entries.contains(&entry) {
+            entries.push(entry);
+        }
+    }
+
+    /// Add the pending entries to the batch of the block committed at the
+    /// given height.
+    pub fn write_to_batch<D>(
+        &mut self,
+        db: &D,
+        batch: &mut D::WriteBatch,
+        height: BlockHeight,
+    ) -> DbResult<()>
+    where
+        D: DB,
+    {
+        for (address, entries) in std::mem::take(&mut self.pending) {
+            let entries: Vec<_> = entries
+                .iter()
+                .map(|entry| entry.serialize_to_vec())
+                .collect();
+            db.batch_append_tx_history(batch, &address, height, &entries)?;
+        }
+        Ok(())
+    }
+}
+
+/// Extract the internal addresses debited or credited by a token event.
+fn token_event_kinds(event: &Event) -> Vec<(Address, TxHistoryKind)> {
+    let internal = |account: UserAccount| match account {
+        UserAccount::Internal(address) => Some(address),
+        UserAccount::External(_) => None,
+    };
+
+    let mut touched = vec![];
+    if *event.kind() == TRANSFER {
+        if let Ok(sources) = event.read_attribute::<SourceAccounts>() {
+            for ((account, token), _) in sources.0 {
+                if let Some(address) = internal(account) {
+                    touched.push((address, TxHistoryKind::Debit { token }));
+                }
+            }
+        }
+        if let Ok(targets) = event.read_attribute::<TargetAccounts>() {
+            for ((account, token), _) in targets.0 {
+                if let Some(address) = internal(account) {
+                    touched.push((address, TxHistoryKind::Credit { token }));
+                }
+            }
+        }
+    } else if *event.kind() == MINT || *event.kind() == BURN {
+        let (Ok(account), Ok(token)) = (
+            event.read_attribute::<TargetAccount>(),
+            event.read_attribute::<TokenAddress>(),
+        ) else {
+            return touched;
+        };
+        if let Some(address) = internal(account) {
+            let kind = if *event.kind() == MINT {
+                TxHistoryKind::Credit { token }
+            } else {
+                TxHistoryKind::Debit { token }
+            };
+            touched.push((address, kind));
+        }
+    }
+    touched
+}
+
+#[cfg(test)]
+mod test_tx_history {
+    use namada_sdk::address::testing::{
+        established_address_1, established_address_2, nam,
+    };
+    use namada_sdk::events::EventLevel;
+    use namada_sdk::state::mockdb::MockDB;
+    use namada_sdk::token::event::{TokenEvent, TokenOperation};
+    use namada_sdk::uint::Uint;
+
+    use super::*;
+
+    /// Test that transfer, mint and burn events are mapped to debits and
+    /// credits of the internal accounts involved.
+    #[test]
+    fn test_token_event_kinds() {
+        let alice = established_address_1();
+        let bob = established_address_2();
+
+        let transfer: Event = TokenEvent {
+            level: EventLevel::Tx,
+            operation: TokenOperation::transfer(
+                UserAccount::Internal(alice.clone()),
+                UserAccount::Internal(bob.clone()),
+                nam(),
+                Uint::from(10),
+                Uint::zero(),
+                None,
+            ),
+            descriptor: "transfer".into(),
+        }
+        .into();
+        assert_eq!(
+            token_event_kinds(&transfer),
+            vec![
+                (alice.clone(), TxHistoryKind::Debit { token: nam() }),
+                (bob.clone(), TxHistoryKind::Credit { token: nam() }),
+            ]
+        );
+
+        let ibc_transfer: Event = TokenEvent {
+            level: EventLevel::Tx,
+            operation: TokenOperation::transfer(
+                UserAccount::Internal(alice.clone()),
+                UserAccount::External("cosmos1abc".to_string()),
+                nam(),
+                Uint::from(10),
+                Uint::zero(),
+                None,
+            ),
+            descriptor: "transfer".into(),
+        }
+        .into();
+        assert_eq!(
+            token_event_kinds(&ibc_transfer),
+            vec![(alice.clone(), TxHistoryKind::Debit { token: nam() })]
+        );
+
+        let burn: Event = TokenEvent {
+            level: EventLevel::Tx,
+            operation: TokenOperation::Burn {
+                target_account: UserAccount::Internal(bob.clone()),
+                token: nam(),
+                amount: Uint::from(1),
+                post_balance: Uint::zero(),
+            },
+            descriptor: "burn".into(),
+        }
+        .into();
+        assert_eq!(
+            token_event_kinds(&burn),
+            vec![(bob, TxHistoryKind::Debit { token: nam() })]
+        );
+    }
+
+    /// Test that pending entries are appended to the history of each
+    /// address, in order, and can be read back by sequence number.
+    #[test]
+    fn test_write_tx_history() {
+        let db = MockDB::default();
+        let alice = established_address_1();
+        let mut index = TxHistoryIndex::default();
+
+        let mut expected = vec![];
+        for height in [1_u64, 2, 10] {
+            let height = BlockHeight(height);
+            let entry = TxHistoryEntry {
+                inner_tx_hash: Hash::sha256(height.0.to_be_bytes()),
+                height,
+                kind: TxHistoryKind::Vote { proposal_id: 0 },
+            };
+            index.record(
+                alice.clone(),
+                entry.inner_tx_hash,
+                height,
+                entry.kind.clone(),
+            );
+            // recording the same entry twice is a no-op
+            index.record(
+                alice.clone(),
+                entry.inner_tx_hash,
+                height,
+                entry.kind.clone(),
+            );
+            expected.push(entry);
+            let mut batch = MockDB::batch();
+            index.write_to_batch(&db, &mut batch, height).unwrap();
+            db.exec_batch(batch).unwrap();
+            assert!(index.pending.is_empty());
+        }
+
+        assert_eq!(db.read_tx_history_len(&alice).unwrap(), 3);
+        let stored: Vec<_> = (0..3)
+            .map(|seq| {
+                let bytes =
+                    db.read_tx_history_entry(&alice, seq).unwrap().unwrap();
+                TxHistoryEntry::try_from_slice(&bytes).unwrap()
+            })
+            .collect();
+        assert_eq!(stored, expected);
+        assert!(db.read_tx_history_entry(&alice, 3).unwrap().is_none());
+
+        let bob = established_address_2();
+        assert_eq!(db.read_tx_history_len(&bob).unwrap(), 0);
+    }
+}
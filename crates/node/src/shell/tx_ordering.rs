@@ -0,0 +1,227 @@
+//! Policies used by block proposers to order the wrapper txs retrieved from
+//! CometBFT's mempool.
+//!
+//! With [`TxOrdering::Fifo`], txs are proposed in the order in which they
+//! were handed over by CometBFT. The other policies sort candidates by
+//! their effective fee per gas unit, normalised by the minimum gas price of
+//! the fee token, so that fees paid in different whitelisted tokens can be
+//! compared with each other. Sorting is stable, such that candidates with
+//! the same priority retain their mempool order, which keeps proposals
+//! deterministic.
+
+use std::collections::BTreeMap;
+
+use namada_sdk::key::common;
+use namada_sdk::state::StorageRead;
+use namada_sdk::tx::Tx;
+use namada_sdk::uint::Uint;
+use namada_sdk::{parameters, token};
+
+use crate::config::TxOrdering;
+
+/// Scale applied to fees before normalising them by the minimum gas price
+/// of their fee token, in order to preserve precision.
+const FEE_PRIORITY_SCALE: u64 = 1_000_000_000_000;
+
+/// A candidate for inclusion in a block proposal.
+#[derive(Debug, Clone)]
+pub(super) struct Candidate<T, S> {
+    /// The candidate tx, or a reference to it
+    pub item: T,
+    /// The signer of the candidate, used to enforce fairness caps. This
+    /// is `None` if the candidate could not be decoded.
+    pub signer: Option<S>,
+    /// The effective fee per gas unit paid by the candidate
+    pub priority: Uint,
+}
+
+/// Order the given candidates according to `policy`. Candidates that are
+/// dropped from the result can be proposed in a later block.
+pub(super) fn order_candidates<T, S>(
+    policy: &TxOrdering,
+    mut candidates: Vec<Candidate<T, S>>,
+) -> Vec<T>
+where
+    S: Ord + Clone,
+{
+    match policy {
+        TxOrdering::Fifo => {
+            return candidates
+                .into_iter()
+                .map(|candidate| candidate.item)
+                .collect();
+        }
+        TxOrdering::FeePriority | TxOrdering::SignerFairness { .. } => {
+            // NB: `sort_by` is stable
+            candidates.sort_by(|a, b| b.priority.cmp(&a.priority));
+        }
+    }
+
+    let TxOrdering::SignerFairness { max_txs_per_signer } = policy else {
+        return candidates
+            .into_iter()
+            .map(|candidate| candidate.item)
+            .collect();
+    };
+
+    let mut txs_per_signer: BTreeMap<S, u64> = BTreeMap::new();
+    candidates
+        .into_iter()
+        .filter_map(|candidate| {
+            let Some(signer) = candidate.signer else {
+                // Undecodable candidates are rejected further down the
+                // line, no need to cap them here
+                return Some(candidate.item);
+            };
+            let included = txs_per_signer.entry(signer).or_default();
+            if *included >= max_txs_per_signer.get() {
+                return None;
+            }
+            *included = included.checked_add(1).expect("Cannot overflow");
+            Some(candidate.item)
+        })
+        .collect()
+}
+
+/// Compute the effective fee per gas unit paid by the wrapper tx in
+/// `tx_bytes`, normalised by the minimum gas price of its fee token, along
+/// with the public key of its signer.
+///
+/// Returns `None` if the tx cannot be decoded or if its fee token is not
+/// whitelisted for fee payment. Such txs are rejected by the fee checks
+/// anyway.
+pub(super) fn fee_priority<S>(
+    storage: &S,
+    tx_bytes: &[u8],
+) -> Option<(common::PublicKey, Uint)>
+where
+    S: StorageRead,
+{
+    let tx = Tx::try_from_bytes(tx_bytes).ok()?;
+    let wrapper = tx.header.wrapper()?;
+
+    let amount_per_gas_unit = token::denom_to_amount(
+        wrapper.fee.amount_per_gas_unit,
+        &wrapper.fee.token,
+        storage,
+    )
+    .ok()?;
+    let minimum_gas_price =
+        parameters::read_gas_cost(storage, &wrapper.fee.token).ok()??;
+
+    Some((
+        wrapper.pk,
+        normalize_fee(amount_per_gas_unit, minimum_gas_price),
+    ))
+}
+
+/// Normalise a fee per gas unit by the minimum gas price of its token.
+fn normalize_fee(
+    amount_per_gas_unit: token::Amount,
+    minimum_gas_price: token::Amount,
+) -> Uint {
+    let minimum_gas_price = if minimum_gas_price.is_zero() {
+        Uint::one()
+    } else {
+        minimum_gas_price.raw_amount()
+    };
+    amount_per_gas_unit
+        .raw_amount()
+        .checked_mul_div(Uint::from(FEE_PRIORITY_SCALE), minimum_gas_price)
+        .map(|(priority, _)| priority)
+        .unwrap_or(Uint::MAX)
+}
+
+#[cfg(test)]
+mod test_tx_ordering {
+    use std::num::NonZeroU64;
+
+    use super::*;
+
+    fn candidate(
+        item: u64,
+        signer: Option<&'static str>,
+        priority: u64,
+    ) -> Candidate<u64, &'static str> {
+        Candidate {
+            item,
+            signer,
+            priority: Uint::from(priority),
+        }
+    }
+
+    fn candidates() -> Vec<Candidate<u64, &'static str>> {
+        vec![
+            candidate(0, Some("alice"), 1),
+            candidate(1, Some("bob"), 5),
+            candidate(2, Some("alice"), 5),
+            candidate(3, None, 0),
+            candidate(4, Some("alice"), 9),
+            candidate(5, Some("christel"), 3),
+            candidate(6, Some("alice"), 7),
+        ]
+    }
+
+    /// Test that the FIFO policy preserves the mempool order.
+    #[test]
+    fn test_fifo_ordering() {
+        let ordered = order_candidates(&TxOrdering::Fifo, candidates());
+        assert_eq!(ordered, vec![0, 1, 2, 3, 4, 5, 6]);
+    }
+
+    /// Test that the fee priority policy orders txs by decreasing fee,
+    /// preserving the mempool order of txs with equal fees.
+    #[test]
+    fn test_fee_priority_ordering() {
+        let ordered = order_candidates(&TxOrdering::FeePriority, candidates());
+        assert_eq!(ordered, vec![4, 6, 1, 2, 5, 0, 3]);
+
+        // ordering is deterministic
+        let again = order_candidates(&TxOrdering::FeePriority, candidates());
+        assert_eq!(ordered, again);
+    }
+
+    /// Test that the signer fairness policy caps the number of txs of each
+    /// signer, keeping the highest paying ones.
+    #[test]
+    fn test_signer_fairness_ordering() {
+        let policy = TxOrdering::SignerFairness {
+            max_txs_per_signer: NonZeroU64::new(2).unwrap(),
+        };
+        let ordered = order_candidates(&policy, candidates());
+        assert_eq!(ordered, vec![4, 6, 1, 5, 3]);
+
+        let policy = TxOrdering::SignerFairness {
+            max_txs_per_signer: NonZeroU64::new(1).unwrap(),
+        };
+        let ordered = order_candidates(&policy, candidates());
+        assert_eq!(ordered, vec![4, 1, 5, 3]);
+    }
+
+    /// Test that fees paid in different tokens are normalised by the
+    /// minimum gas price of each token.
+    #[test]
+    fn test_normalize_fee() {
+        // paying twice the minimum gas price is worth the same in any token
+        assert_eq!(
+            normalize_fee(token::Amount::from(20), token::Amount::from(10)),
+            normalize_fee(
+                token::Amount::from(2_000),
+                token::Amount::from(1_000)
+            ),
+        );
+        // a lower raw fee may be worth more in a token with a lower minimum
+        assert!(
+            normalize_fee(token::Amount::from(30), token::Amount::from(10))
+                > normalize_fee(
+                    token::Amount::from(2_000),
+                    token::Amount::from(1_000)
+                ),
+        );
+        // a zero minimum gas price doesn't cause a division by zero
+        assert_eq!(
+            normalize_fee(token::Amount::from(1), token::Amount::zero()),
+            Uint::from(FEE_PRIORITY_SCALE),
+        );
+    }
+}
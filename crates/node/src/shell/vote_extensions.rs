@@ -58,14 +58,11 @@ where
             .mode
             .get_validator_address()
             .expect(VALIDATOR_EXPECT_MSG);
-        let protocol_key = match &self.mode {
-            ShellMode::Validator { data, .. } => &data.keys.protocol_keypair,
-            _ => unreachable!("{VALIDATOR_EXPECT_MSG}"),
-        };
+        let signer = self.mode.get_signer().expect(VALIDATOR_EXPECT_MSG);
         sign_ethereum_events(
             &self.state,
             validator_addr,
-            protocol_key,
+            signer,
             ethereum_events,
         )
         .map(|ethereum_events::SignedVext(ext)| ext)
@@ -79,21 +76,9 @@ where
             .mode
             .get_validator_address()
             .expect(VALIDATOR_EXPECT_MSG);
-        let eth_hot_key = self
-            .mode
-            .get_eth_bridge_keypair()
-            .expect(VALIDATOR_EXPECT_MSG);
-        let protocol_key = match &self.mode {
-            ShellMode::Validator { data, .. } => &data.keys.protocol_keypair,
-            _ => unreachable!("{VALIDATOR_EXPECT_MSG}"),
-        };
-        sign_bridge_pool_root(
-            &self.state,
-            validator_addr,
-            eth_hot_key,
-            protocol_key,
-        )
-        .map(|bridge_pool_roots::SignedVext(ext)| ext)
+        let signer = self.mode.get_signer().expect(VALIDATOR_EXPECT_MSG);
+        sign_bridge_pool_root(&self.state, validator_addr, signer)
+            .map(|bridge_pool_roots::SignedVext(ext)| ext)
     }
 
     /// Extend PreCommit votes with [`validator_set_update::Vext`]
@@ -105,14 +90,11 @@ where
             .mode
             .get_validator_address()
             .expect(VALIDATOR_EXPECT_MSG);
-        let eth_hot_key = self
-            .mode
-            .get_eth_bridge_keypair()
-            .expect("{VALIDATOR_EXPECT_MSG}");
-        sign_validator_set_update::<_, _, governance::Store<_>>(
+        let signer = self.mode.get_signer().expect(VALIDATOR_EXPECT_MSG);
+        sign_validator_set_update::<_, _, governance::Store<_>, _>(
             &self.state,
             validator_addr,
-            eth_hot_key,
+            signer,
         )
     }
 
@@ -57,7 +57,10 @@ mod test_bp_vote_extensions {
     use namada_apps_lib::wallet::defaults::{bertha_address, bertha_keypair};
     use namada_sdk::chain::BlockHeight;
     use namada_sdk::eth_bridge::protocol::validation::bridge_pool_roots::validate_bp_roots_vext;
-    use namada_sdk::eth_bridge::storage::bridge_pool::get_key_from_hash;
+    use namada_sdk::eth_bridge::protocol::validation::VoteExtensionError;
+    use namada_sdk::eth_bridge::storage::bridge_pool::{
+        get_key_from_hash, get_refund_height_key,
+    };
     use namada_sdk::eth_bridge::storage::eth_bridge_queries::{
         is_bridge_comptime_enabled, EthBridgeQueries,
     };
@@ -510,7 +513,7 @@ mod test_bp_vote_extensions {
             to_sign,
         )
         .sig;
-        let bp_root = bridge_pool_roots::Vext {
+        let old_bp_root = bridge_pool_roots::Vext {
             block_height: 2.into(),
             validator_addr: address.clone(),
             sig,
@@ -519,7 +522,7 @@ mod test_bp_vote_extensions {
         assert!(
             validate_bp_roots_vext::<_, _, governance::Store<_>>(
                 &shell.state,
-                &bp_root.0,
+                &old_bp_root.0,
                 shell.get_current_decision_height()
             )
             .is_ok()
@@ -544,6 +547,29 @@ mod test_bp_vote_extensions {
             )
             .is_ok()
         );
+
+        // roots of heights before the last refund of expired transfers
+        // are no longer signed
+        shell
+            .state
+            .write(&get_refund_height_key(), BlockHeight(3))
+            .expect("Test failed");
+        assert!(matches!(
+            validate_bp_roots_vext::<_, _, governance::Store<_>>(
+                &shell.state,
+                &old_bp_root.0,
+                shell.get_current_decision_height()
+            ),
+            Err(VoteExtensionError::UnexpectedBlockHeight)
+        ));
+        assert!(
+            validate_bp_roots_vext::<_, _, governance::Store<_>>(
+                &shell.state,
+                &bp_root.0,
+                shell.get_current_decision_height()
+            )
+            .is_ok()
+        );
     }
 
     /// Test that if the wrong block height is given for the provided root,
@@ -1,6 +1,7 @@
 use std::future::Future;
 use std::path::PathBuf;
 use std::pin::Pin;
+use std::sync::Arc;
 use std::task::{Context, Poll};
 
 use futures::future::FutureExt;
@@ -24,6 +25,7 @@ use super::abcipp_shim_types::shim::{
 };
 use crate::config;
 use crate::config::{Action, ActionAtHeight};
+use crate::shell::metrics::Metrics;
 use crate::shell::{EthereumOracleChannels, Shell};
 use crate::storage::DbSnapshot;
 use crate::tendermint::abci::{request, Request as Req, Response as Resp};
@@ -102,6 +104,11 @@ impl AbcippShim {
         hash_tx(bytes.as_slice())
     }
 
+    /// Get a handle to the metrics of the shell, if enabled
+    pub fn metrics(&self) -> Option<Arc<Metrics>> {
+        self.service.metrics.clone()
+    }
+
     /// Run the shell's blocking loop that receives messages from the
     /// [`AbciService`].
     pub fn run(mut self) {
@@ -220,7 +227,8 @@ impl AbcippShim {
             _ => {}
         }
 
-        let TakeSnapshot::Yes(db_path, height) = take_snapshot else {
+        let TakeSnapshot::Yes(db_path, height, merkle_root) = take_snapshot
+        else {
             return;
         };
         let base_dir = self.service.base_dir.clone();
@@ -237,7 +245,7 @@ impl AbcippShim {
             DbSnapshot::cleanup(height, &base_dir, snapshots_to_keep)
                 .map_err(|e| DbError::DBError(e.to_string()))?;
             snapshot
-                .package()
+                .package(merkle_root)
                 .map_err(|e| DbError::DBError(e.to_string()))
         });
 
@@ -4,6 +4,7 @@ pub mod shim {
     use std::fmt::Debug;
     use std::path::PathBuf;
 
+    use namada_sdk::hash::Hash;
     use namada_sdk::state::BlockHeight;
     use thiserror::Error;
 
@@ -34,19 +35,22 @@ pub mod shim {
 
     #[derive(Debug, Clone)]
     /// Indicate whether a state snapshot should be created
-    /// at a certain point in time
+    /// at a certain point in time, along with the merkle root
+    /// of the state to snapshot
     pub enum TakeSnapshot {
         No,
-        Yes(PathBuf, BlockHeight),
+        Yes(PathBuf, BlockHeight, Hash),
     }
 
-    impl<T: AsRef<std::path::Path>> From<Option<(T, BlockHeight)>>
+    impl<T: AsRef<std::path::Path>> From<Option<(T, BlockHeight, Hash)>>
         for TakeSnapshot
     {
-        fn from(value: Option<(T, BlockHeight)>) -> Self {
+        fn from(value: Option<(T, BlockHeight, Hash)>) -> Self {
             match value {
                 None => TakeSnapshot::No,
-                Some(p) => TakeSnapshot::Yes(p.0.as_ref().to_path_buf(), p.1),
+                Some(p) => {
+                    TakeSnapshot::Yes(p.0.as_ref().to_path_buf(), p.1, p.2)
+                }
             }
         }
     }
@@ -57,7 +57,7 @@ fn new_blake2b() -> Blake2b {
 mod tests {
     use itertools::Itertools;
     use namada_sdk::borsh::BorshDeserialize;
-    use namada_sdk::chain::{BlockHeight, ChainId};
+    use namada_sdk::chain::{BlockHeight, ChainId, Epoch};
     use namada_sdk::collections::HashMap;
     use namada_sdk::eth_bridge::storage::bridge_pool;
     use namada_sdk::eth_bridge::storage::proof::BridgePoolRootProof;
@@ -69,7 +69,7 @@ mod tests {
     use namada_sdk::parameters::Parameters;
     use namada_sdk::state::merkle_tree::NO_DIFF_KEY_PREFIX;
     use namada_sdk::state::{
-        self, StateRead, StorageRead, StorageWrite, StoreType, DB,
+        self, StateRead, StorageMode, StorageRead, StorageWrite, StoreType, DB,
     };
     use namada_sdk::storage::{Key, KeySeg};
     use namada_sdk::token::conversion::update_allowed_conversions;
@@ -95,6 +95,7 @@ mod tests {
             ChainId::default(),
             address::testing::nam(),
             None,
+            StorageMode::default(),
             is_key_diff_storable,
         );
         let key = Key::parse("key").expect("cannot parse the key string");
@@ -157,6 +158,7 @@ mod tests {
             ChainId::default(),
             address::testing::nam(),
             None,
+            StorageMode::default(),
             is_key_diff_storable,
         );
         state
@@ -204,6 +206,7 @@ mod tests {
             ChainId::default(),
             address::testing::nam(),
             None,
+            StorageMode::default(),
             is_key_diff_storable,
         );
         let (loaded_root, height) =
@@ -225,6 +228,7 @@ mod tests {
             ChainId::default(),
             address::testing::nam(),
             None,
+            StorageMode::default(),
             is_key_diff_storable,
         );
 
@@ -272,6 +276,7 @@ mod tests {
             ChainId::default(),
             address::testing::nam(),
             None,
+            StorageMode::default(),
             is_key_diff_storable,
         );
         state
@@ -350,6 +355,7 @@ mod tests {
             ChainId::default(),
             address::testing::nam(),
             None,
+            StorageMode::default(),
             is_key_diff_storable,
         );
 
@@ -444,6 +450,7 @@ mod tests {
             ChainId::default(),
             address::testing::nam(),
             None,
+            StorageMode::default(),
             is_key_diff_storable,
         );
         // Prepare written keys for non-provable data, provable data (IBC), and
@@ -645,6 +652,7 @@ mod tests {
             ChainId::default(),
             address::testing::nam(),
             Some(5),
+            StorageMode::default(),
             is_key_diff_storable,
         );
         let new_epoch_start = BlockHeight(1);
@@ -761,6 +769,161 @@ mod tests {
         assert!(result.is_ok(), "The tree at Height 11 should be restored");
     }
 
+    /// Commit a block starting a new epoch, in which the given key is
+    /// written
+    fn commit_epoch(
+        state: &mut PersistentState,
+        epoch_start: BlockHeight,
+        key: &Key,
+    ) {
+        state
+            .in_mem_mut()
+            .begin_block(epoch_start)
+            .expect("begin_block failed");
+        state
+            .db_write(key, encode(&epoch_start.0))
+            .expect("write failed");
+        if epoch_start.0 > 1 {
+            state.in_mem_mut().block.epoch = state.in_mem().block.epoch.next();
+        }
+        state.in_mem_mut().block.pred_epochs.new_epoch(epoch_start);
+        state.commit_block().expect("commit failed");
+    }
+
+    /// Test that the diffs and Merkle tree stores older than the retained
+    /// blocks are pruned in the pruned mode, but not in the archive mode
+    #[test]
+    fn test_storage_modes() {
+        for mode in [
+            StorageMode::Archive,
+            StorageMode::Pruned { blocks_to_keep: 5 },
+        ] {
+            let db_path = TempDir::new()
+                .expect("Unable to create a temporary DB directory");
+            let mut state = PersistentState::open(
+                db_path.path(),
+                None,
+                ChainId::default(),
+                address::testing::nam(),
+                None,
+                mode,
+                is_key_diff_storable,
+            );
+            let key = ibc_key("key").unwrap();
+
+            commit_epoch(&mut state, BlockHeight(1), &key);
+            commit_epoch(&mut state, BlockHeight(6), &key);
+            let diff = state
+                .db()
+                .read_diffs_val(&key, BlockHeight(1), false)
+                .unwrap();
+            assert!(diff.is_some());
+
+            commit_epoch(&mut state, BlockHeight(11), &key);
+
+            let old_diff = state
+                .db()
+                .read_diffs_val(&key, BlockHeight(1), false)
+                .unwrap();
+            let old_value = state.db_read_with_height(&key, BlockHeight(3));
+            let old_tree =
+                state.get_merkle_tree(1.into(), Some(StoreType::Ibc));
+            match mode {
+                StorageMode::Archive => {
+                    assert!(old_diff.is_some());
+                    let (value, _gas) = old_value.unwrap();
+                    assert_eq!(value, Some(encode(&1_u64)));
+                    assert!(old_tree.is_ok());
+                }
+                _ => {
+                    assert!(old_diff.is_none());
+                    assert!(old_value.is_err());
+                    assert!(old_tree.is_err());
+                    // the retained heights can still be read
+                    let (value, _gas) = state
+                        .db_read_with_height(&key, BlockHeight(6))
+                        .unwrap();
+                    assert_eq!(value, Some(encode(&6_u64)));
+                    let tree =
+                        state.get_merkle_tree(6.into(), Some(StoreType::Ibc));
+                    assert!(tree.is_ok());
+                }
+            }
+        }
+    }
+
+    /// Test that switching an existing DB to the pruned mode also prunes
+    /// the diffs and Merkle tree stores kept while in the archive mode
+    #[test]
+    fn test_switch_to_pruned_storage_mode() {
+        let db_path =
+            TempDir::new().expect("Unable to create a temporary DB directory");
+        let open = |mode| {
+            PersistentState::open(
+                db_path.path(),
+                None,
+                ChainId::default(),
+                address::testing::nam(),
+                None,
+                mode,
+                is_key_diff_storable,
+            )
+        };
+        let key = ibc_key("key").unwrap();
+
+        let mut state = open(StorageMode::Archive);
+        for epoch_start in [1, 6, 11] {
+            commit_epoch(&mut state, BlockHeight(epoch_start), &key);
+        }
+        for height in [1, 6] {
+            let diff = state
+                .db()
+                .read_diffs_val(&key, BlockHeight(height), false)
+                .unwrap();
+            assert!(diff.is_some());
+        }
+        let tree = state.get_merkle_tree(1.into(), Some(StoreType::Ibc));
+        assert!(tree.is_ok());
+        // Release DB lock
+        drop(state);
+
+        let mut state = open(StorageMode::Pruned { blocks_to_keep: 5 });
+        commit_epoch(&mut state, BlockHeight(16), &key);
+
+        // everything before the oldest retained epoch was pruned, including
+        // the data of the epochs before the previous one
+        for (epoch, height) in [(0, 1), (1, 6)] {
+            let diff = state
+                .db()
+                .read_diffs_val(&key, BlockHeight(height), false)
+                .unwrap();
+            assert!(diff.is_none());
+            let stores = state
+                .db()
+                .read_merkle_tree_stores(
+                    Epoch(epoch),
+                    BlockHeight(height),
+                    Some(StoreType::Ibc),
+                )
+                .unwrap();
+            assert!(stores.is_none());
+        }
+        assert_eq!(
+            state.db().read_last_pruned_epoch().unwrap(),
+            Some(Epoch(1))
+        );
+        let diff = state
+            .db()
+            .read_diffs_val(&key, BlockHeight(11), false)
+            .unwrap();
+        assert!(diff.is_some());
+        let (value, _gas) =
+            state.db_read_with_height(&key, BlockHeight(11)).unwrap();
+        assert_eq!(value, Some(encode(&11_u64)));
+        let tree = state.get_merkle_tree(11.into(), Some(StoreType::Ibc));
+        assert!(tree.is_ok());
+    }
+
     /// Test the prefix iterator with RocksDB.
     #[test]
     fn test_persistent_storage_prefix_iter() {
@@ -772,6 +935,7 @@ mod tests {
             ChainId::default(),
             address::testing::nam(),
             None,
+            StorageMode::default(),
             is_key_diff_storable,
         );
 
@@ -879,6 +1043,7 @@ mod tests {
             ChainId::default(),
             address::testing::nam(),
             None,
+            StorageMode::default(),
             // Only merkelize and persist diffs for `test_key_1`
             |key: &Key| -> bool { key == &test_key_1() },
         );
@@ -54,6 +54,7 @@ use std::sync::Mutex;
 use data_encoding::HEXLOWER;
 use itertools::Either;
 use namada_replay_protection as replay_protection;
+use namada_sdk::address::Address;
 use namada_sdk::arith::checked;
 use namada_sdk::borsh::{BorshDeserialize, BorshSerialize, BorshSerializeExt};
 use namada_sdk::collections::HashSet;
@@ -67,8 +68,8 @@ use namada_sdk::state::merkle_tree::{
 };
 use namada_sdk::state::{
     BlockStateRead, BlockStateWrite, DBIter, DBWriteBatch, DbError as Error,
-    DbResult as Result, MerkleTreeStoresRead, PatternIterator, PrefixIterator,
-    StoreType, DB,
+    DbResult as Result, DbStat, MerkleTreeStoresRead, PatternIterator,
+    PrefixIterator, StoreType, DB,
 };
 use namada_sdk::storage::{
     BlockHeader, BlockHeight, DbColFam, Epoch, Key, KeySeg, BLOCK_CF, DIFFS_CF,
@@ -100,7 +101,11 @@ const COMMIT_ONLY_DATA_KEY: &str = "commit_only_data_commitment";
 const CONVERSION_STATE_KEY: &str = "conversion_state";
 const ETHEREUM_HEIGHT_KEY: &str = "ethereum_height";
 const ETH_EVENTS_QUEUE_KEY: &str = "eth_events_queue";
+const LAST_PRUNED_EPOCH_KEY: &str = "last_pruned_epoch";
 const RESULTS_KEY_PREFIX: &str = "results";
+const TX_HISTORY_KEY_PREFIX: &str = "tx_history";
+const TX_HISTORY_HEIGHT_KEY_PREFIX: &str = "tx_history_height";
+const TX_HISTORY_LEN_KEY_SEGMENT: &str = "len";
 const PRED_KEY_PREFIX: &str = "pred";
 
 const MERKLE_TREE_ROOT_KEY_SEGMENT: &str = "root";
@@ -117,6 +122,15 @@ const NEW_DIFF_PREFIX: &str = "new";
 // 10 MB
 const MAX_STATE_SYNC_CHUNK_SIZE: usize = 10_000_000;
 
+/// The RocksDB properties of each column family reported in the DB stats
+const DB_STATS_PROPERTIES: [&str; 5] = [
+    "rocksdb.estimate-num-keys",
+    "rocksdb.estimate-live-data-size",
+    "rocksdb.total-sst-files-size",
+    "rocksdb.cur-size-all-mem-tables",
+    "rocksdb.block-cache-usage",
+];
+
 /// RocksDB handle
 #[derive(Debug)]
 pub struct RocksDB {
@@ -623,6 +637,46 @@ impl RocksDB {
             format!("{RESULTS_KEY_PREFIX}/{}", last_block.height),
         );
 
+        // Truncate the tx history of the addresses touched in the last block
+        tracing::info!("Removing last block tx history");
+        let prefix = format!(
+            "{TX_HISTORY_HEIGHT_KEY_PREFIX}/{}/",
+            last_block.height.raw()
+        );
+        let read_opts = make_iter_read_opts(Some(prefix.clone()));
+        let iter = self.inner.iterator_cf_opt(
+            block_cf,
+            read_opts,
+            IteratorMode::From(prefix.as_bytes(), Direction::Forward),
+        );
+        for (address, prev_len, _gas) in
+            PersistentPrefixIterator(PrefixIterator::new(iter, prefix.clone()))
+        {
+            let address = Address::decode(&address).map_err(|e| {
+                Error::DBError(format!(
+                    "Invalid address in the tx history index: {e}"
+                ))
+            })?;
+            let prev_len: u64 = decode(prev_len)?;
+            let len = self.read_tx_history_len(&address)?;
+            for seq in prev_len..len {
+                batch.0.delete_cf(
+                    block_cf,
+                    format!("{TX_HISTORY_KEY_PREFIX}/{address}/{seq}"),
+                );
+            }
+            self.add_value_to_batch(
+                block_cf,
+                format!(
+                    "{TX_HISTORY_KEY_PREFIX}/{address}/\
+                     {TX_HISTORY_LEN_KEY_SEGMENT}"
+                ),
+                &prev_len,
+                &mut batch,
+            );
+            batch.0.delete_cf(block_cf, format!("{prefix}{address}"));
+        }
+
         // Restore the state of replay protection to the last block
         let reprot_cf = self.get_column_family(REPLAY_PROTECTION_CF)?;
         tracing::info!("Restoring replay protection state");
@@ -834,6 +888,14 @@ impl SnapshotPath {
         buf
     }
 
+    /// Return the path of the merkle root of the state associated with this
+    /// [`SnapshotPath`].
+    pub fn merkle_root(&self) -> PathBuf {
+        let mut buf = self.base();
+        buf.push("merkle-root");
+        buf
+    }
+
     /// Return the temporary rocksdb path associated with this [`SnapshotPath`].
     pub fn temp_rocksdb(&self) -> PathBuf {
         let mut buf = self.base();
@@ -867,24 +929,55 @@ pub struct DbSnapshotMeta {
     pub height: BlockHeight,
     /// List of the hashes of all chunks.
     pub chunk_hashes: Vec<Hash>,
-    /// Hash of all the chunk hashes, forming a shallow tree.
+    /// The merkle root of the state in the snapshot, which is the app hash
+    /// of the block at the snapshot's height.
+    pub merkle_root: Hash,
+    /// Hash of all the chunk hashes and of the merkle root, forming a
+    /// shallow tree.
     pub root_hash: Hash,
 }
 
+impl DbSnapshotMeta {
+    /// Compute the root hash of a snapshot from the hashes of its chunks
+    /// and the merkle root of its state.
+    pub fn root_hash_of(chunk_hashes: &[Hash], merkle_root: &Hash) -> Hash {
+        let hash_of_all_chunks = Hash::sha256(chunk_hashes.serialize_to_vec());
+        Hash::sha256(
+            (DbSnapshot::FORMAT_MAGIC, hash_of_all_chunks, merkle_root)
+                .serialize_to_vec(),
+        )
+    }
+
+    /// The metadata of the snapshot sent to syncing nodes, from which they
+    /// can recompute its root hash.
+    pub fn abci_metadata(&self) -> Vec<u8> {
+        (&self.chunk_hashes, &self.merkle_root).serialize_to_vec()
+    }
+
+    /// Decode the metadata of a snapshot offered to a syncing node, returning
+    /// the hashes of its chunks and its merkle root.
+    pub fn decode_abci_metadata(
+        metadata: &[u8],
+    ) -> std::io::Result<(Vec<Hash>, Hash)> {
+        BorshDeserialize::try_from_slice(metadata)
+    }
+}
+
 #[derive(Clone)]
 pub struct DbSnapshot(pub SnapshotPath);
 
 impl DbSnapshot {
     /// The magic number referring to the format of the snapshot.
-    pub const FORMAT_MAGIC: u32 = 0;
+    pub const FORMAT_MAGIC: u32 = 1;
 
-    /// Package and chunk the contents of the db snapshot.
+    /// Package and chunk the contents of the db snapshot, along with the
+    /// merkle root of the state at the snapshot's height.
     // NB: passing an owned `self` guarantees we don't attempt to call
     // this method again, which removes the temporary checkpoint dir
     // created by rocksdb
-    pub fn package(self) -> std::io::Result<()> {
+    pub fn package(self, merkle_root: Hash) -> std::io::Result<()> {
         self.build_tarball()?;
-        self.chunk_snapshot(MAX_STATE_SYNC_CHUNK_SIZE)?;
+        self.chunk_snapshot(MAX_STATE_SYNC_CHUNK_SIZE, merkle_root)?;
         Ok(())
     }
 
@@ -923,7 +1016,11 @@ impl DbSnapshot {
         std::fs::remove_dir_all(&snapshot_temp_db_path)
     }
 
-    fn chunk_snapshot(&self, max_chunk: usize) -> std::io::Result<()> {
+    fn chunk_snapshot(
+        &self,
+        max_chunk: usize,
+        merkle_root: Hash,
+    ) -> std::io::Result<()> {
         let tarball_path = self.0.temp_tarball("zst");
 
         let mut buf = vec![0; max_chunk];
@@ -967,14 +1064,13 @@ impl DbSnapshot {
             }
         }
 
+        let snapshot_hash =
+            DbSnapshotMeta::root_hash_of(&chunk_hashes, &merkle_root);
         let chunk_hashes = chunk_hashes.serialize_to_vec();
-        let hash_of_all_chunks = Hash::sha256(&chunk_hashes);
-        let snapshot_hash = Hash::sha256(
-            (Self::FORMAT_MAGIC, hash_of_all_chunks).serialize_to_vec(),
-        );
 
         std::fs::remove_file(tarball_path)?;
         std::fs::write(self.0.chunk_hashes(), chunk_hashes)?;
+        std::fs::write(self.0.merkle_root(), merkle_root)?;
         std::fs::write(self.0.chunks_root_hash(), snapshot_hash)?;
 
         Ok(())
@@ -1016,6 +1112,9 @@ impl DbSnapshot {
                 let chunk_hashes = BorshDeserialize::try_from_slice(
                     &std::fs::read(snap.chunk_hashes())?,
                 )?;
+                let merkle_root = BorshDeserialize::try_from_slice(
+                    &std::fs::read(snap.merkle_root())?,
+                )?;
                 let root_hash = BorshDeserialize::try_from_slice(
                     &std::fs::read(snap.chunks_root_hash())?,
                 )?;
@@ -1023,6 +1122,7 @@ impl DbSnapshot {
                 Ok(DbSnapshotMeta {
                     height,
                     chunk_hashes,
+                    merkle_root,
                     root_hash,
                 })
             };
@@ -1072,6 +1172,53 @@ impl DbSnapshot {
         #[allow(clippy::cast_possible_truncation)]
         std::fs::read(snap.chunk_with_id(chunk as _))
     }
+
+    /// Reassemble the archive of the snapshot at the given block height
+    /// into `dest`, verifying the root hash and the hash of each chunk
+    /// against the snapshot's metadata. This only checks the consistency
+    /// of the snapshot, whose merkle root must still be checked against a
+    /// trusted one.
+    pub fn assemble_verified(
+        height: BlockHeight,
+        base_dir: &Path,
+        dest: &mut impl Write,
+    ) -> std::io::Result<DbSnapshotMeta> {
+        let meta = Self::load_snapshot_metadata(base_dir, [height.0])
+            .next()
+            .expect("Metadata of a single snapshot was requested")?;
+
+        let root_hash =
+            DbSnapshotMeta::root_hash_of(&meta.chunk_hashes, &meta.merkle_root);
+        if root_hash != meta.root_hash {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!(
+                    "Root hash of snapshot at height {height} did not match, \
+                     expected {}, got {root_hash}",
+                    meta.root_hash
+                ),
+            ));
+        }
+
+        for (chunk_id, expected_hash) in meta.chunk_hashes.iter().enumerate() {
+            let chunk = Self::load_chunk(height, chunk_id as u64, base_dir)?;
+            let chunk_hash = Hash::sha256(&chunk);
+            if *expected_hash != chunk_hash {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!(
+                        "Hash of chunk {chunk_id} of snapshot at height \
+                         {height} did not match, expected {expected_hash}, \
+                         got {chunk_hash}"
+                    ),
+                ));
+            }
+            dest.write_all(&chunk)?;
+        }
+        dest.flush()?;
+
+        Ok(meta)
+    }
 }
 
 impl DB for RocksDB {
@@ -1781,6 +1928,98 @@ impl DB for RocksDB {
         Ok(())
     }
 
+    fn prune_diffs(
+        &mut self,
+        batch: &mut Self::WriteBatch,
+        from: BlockHeight,
+        to: BlockHeight,
+    ) -> Result<()> {
+        let diffs_cf = self.get_column_family(DIFFS_CF)?;
+        // The diffs keys are prefixed with the height, whose fixed-length
+        // encoding preserves the ordering, so we can delete all the diffs in
+        // the range at once. The range tombstone is then cleaned up by
+        // RocksDB's background compactions.
+        batch.0.delete_range_cf(diffs_cf, from.raw(), to.raw());
+        Ok(())
+    }
+
+    fn read_last_pruned_epoch(&self) -> Result<Option<Epoch>> {
+        let block_cf = self.get_column_family(BLOCK_CF)?;
+        self.read_value(block_cf, LAST_PRUNED_EPOCH_KEY)
+    }
+
+    fn batch_write_last_pruned_epoch(
+        &self,
+        batch: &mut Self::WriteBatch,
+        epoch: Epoch,
+    ) -> Result<()> {
+        let block_cf = self.get_column_family(BLOCK_CF)?;
+        self.add_value_to_batch(block_cf, LAST_PRUNED_EPOCH_KEY, &epoch, batch);
+        Ok(())
+    }
+
+    fn read_tx_history_len(&self, address: &Address) -> Result<u64> {
+        let block_cf = self.get_column_family(BLOCK_CF)?;
+        let key = format!(
+            "{TX_HISTORY_KEY_PREFIX}/{address}/{TX_HISTORY_LEN_KEY_SEGMENT}"
+        );
+        Ok(self.read_value(block_cf, key)?.unwrap_or_default())
+    }
+
+    fn read_tx_history_entry(
+        &self,
+        address: &Address,
+        seq: u64,
+    ) -> Result<Option<Vec<u8>>> {
+        let block_cf = self.get_column_family(BLOCK_CF)?;
+        self.read_value_bytes(
+            block_cf,
+            format!("{TX_HISTORY_KEY_PREFIX}/{address}/{seq}"),
+        )
+    }
+
+    fn batch_append_tx_history(
+        &self,
+        batch: &mut Self::WriteBatch,
+        address: &Address,
+        height: BlockHeight,
+        entries: &[Vec<u8>],
+    ) -> Result<()> {
+        let block_cf = self.get_column_family(BLOCK_CF)?;
+        let len = self.read_tx_history_len(address)?;
+        // Keep the length of the history before this height, such that it
+        // can be truncated back on rollback
+        self.add_value_to_batch(
+            block_cf,
+            format!(
+                "{TX_HISTORY_HEIGHT_KEY_PREFIX}/{}/{address}",
+                height.raw()
+            ),
+            &len,
+            batch,
+        );
+        let mut seq = len;
+        for entry in entries {
+            self.add_value_bytes_to_batch(
+                block_cf,
+                format!("{TX_HISTORY_KEY_PREFIX}/{address}/{seq}"),
+                entry.clone(),
+                batch,
+            );
+            seq = checked!(seq + 1)?;
+        }
+        self.add_value_to_batch(
+            block_cf,
+            format!(
+                "{TX_HISTORY_KEY_PREFIX}/{address}/\
+                 {TX_HISTORY_LEN_KEY_SEGMENT}"
+            ),
+            &seq,
+            batch,
+        );
+        Ok(())
+    }
+
     #[inline]
     fn overwrite_entry(
         &self,
@@ -1840,6 +2079,39 @@ impl DB for RocksDB {
         }
         Ok(db_visitor.take_batch())
     }
+
+    fn stats(&self) -> Vec<DbStat> {
+        let mut stats = vec![];
+        for cf_name in [
+            SUBSPACE_CF,
+            DIFFS_CF,
+            ROLLBACK_CF,
+            STATE_CF,
+            BLOCK_CF,
+            REPLAY_PROTECTION_CF,
+        ] {
+            let Ok(cf) = self.get_column_family(cf_name) else {
+                continue;
+            };
+            for name in DB_STATS_PROPERTIES {
+                match self.inner.property_int_value_cf(cf, name) {
+                    Ok(Some(value)) => stats.push(DbStat {
+                        column_family: cf_name,
+                        name,
+                        value,
+                    }),
+                    Ok(None) => {}
+                    Err(e) => {
+                        tracing::debug!(
+                            "Failed to read RocksDB property {name} of \
+                             {cf_name}: {e}"
+                        );
+                    }
+                }
+            }
+        }
+        stats
+    }
 }
 
 /// A struct that can visit a set of updates,
@@ -1936,6 +2208,15 @@ impl<'iter> DBIter<'iter> for RocksDB {
         iter_subspace_prefix(self, prefix)
     }
 
+    fn iter_prefix_from(
+        &'iter self,
+        prefix: Option<&Key>,
+        from: &str,
+        reverse: bool,
+    ) -> PersistentPrefixIterator<'iter> {
+        iter_subspace_prefix_from(self, prefix, from, reverse)
+    }
+
     fn iter_pattern(
         &'iter self,
         prefix: Option<&Key>,
@@ -2003,6 +2284,35 @@ fn iter_subspace_prefix<'iter>(
     iter_prefix(db, subspace_cf, stripped_prefix, prefix)
 }
 
+fn iter_subspace_prefix_from<'iter>(
+    db: &'iter RocksDB,
+    prefix: Option<&Key>,
+    from: &str,
+    reverse: bool,
+) -> PersistentPrefixIterator<'iter> {
+    let subspace_cf = db
+        .get_column_family(SUBSPACE_CF)
+        .expect("{SUBSPACE_CF} column family should exist");
+    let prefix = match prefix {
+        Some(p) if !p.is_empty() => format!("{p}/"),
+        _ => "".to_owned(),
+    };
+    let mut read_opts = make_iter_read_opts(Some(prefix.clone()));
+    let direction = if reverse {
+        // Stop the iteration at the start of the prefix
+        read_opts.set_iterate_lower_bound(prefix.into_bytes());
+        Direction::Reverse
+    } else {
+        Direction::Forward
+    };
+    let iter = db.inner.iterator_cf_opt(
+        subspace_cf,
+        read_opts,
+        IteratorMode::From(from.as_bytes(), direction),
+    );
+    PersistentPrefixIterator(PrefixIterator::new(iter, "".to_owned()))
+}
+
 fn iter_subspace_pattern<'iter>(
     db: &'iter RocksDB,
     prefix: Option<&Key>,
@@ -2235,7 +2545,7 @@ mod imp {
 #[cfg(test)]
 mod test {
     use namada_apps_lib::collections::HashMap;
-    use namada_sdk::address::EstablishedAddressGen;
+    use namada_sdk::address::{self, EstablishedAddressGen};
     use namada_sdk::state::{MerkleTree, Sha256Hasher};
     use namada_sdk::storage::conversion_state::ConversionState;
     use namada_sdk::storage::types::CommitOnlyData;
@@ -2429,6 +2739,23 @@ mod test {
             .map(|(key, _val, _)| Key::parse(key).unwrap())
             .collect();
         itertools::assert_equal(all_keys, itered_keys);
+
+        // Seek into the prefixes in both directions
+        let iter_from = |prefix: &Key, from: &str, reverse: bool| {
+            db.iter_prefix_from(Some(prefix), from, reverse)
+                .map(|(key, _val, _)| key)
+                .collect::<Vec<String>>()
+        };
+        assert_eq!(iter_from(&prefix_0, "0/b", false), vec!["0/b", "0/c"]);
+        assert_eq!(iter_from(&prefix_0, "0/bb", false), vec!["0/c"]);
+        assert_eq!(iter_from(&prefix_0, "0/b", true), vec!["0/b", "0/a"]);
+        assert_eq!(iter_from(&prefix_0, "0/bb", true), vec!["0/b", "0/a"]);
+        // Prefix "0" shouldn't match prefix "01" from past its end
+        assert_eq!(iter_from(&prefix_0, "00", true), vec!["0/c", "0/b", "0/a"]);
+        // Nor prefix "01" from the start of prefix "1" going backward
+        assert_eq!(iter_from(&prefix_1, "1/a", true), vec!["1/a"]);
+        assert!(iter_from(&prefix_1, "0/c", true).is_empty());
+        assert!(iter_from(&prefix_01, "01/b", false).is_empty());
     }
 
     #[test]
@@ -2445,6 +2772,9 @@ mod test {
             let delete_key = Key::parse("delete").unwrap();
             // A key that's gonna be overwritten on a second block
             let overwrite_key = Key::parse("overwrite").unwrap();
+            // Addresses whose tx history is appended to in both blocks
+            let alice = address::testing::established_address_1();
+            let bob = address::testing::established_address_2();
 
             // Write first block
             let mut batch = RocksDB::batch();
@@ -2485,6 +2815,13 @@ mod test {
                 )
                 .unwrap();
             }
+            db.batch_append_tx_history(
+                &mut batch,
+                &alice,
+                height_0,
+                &[vec![0], vec![1]],
+            )
+            .unwrap();
 
             add_block_to_batch(
                 &db,
@@ -2538,6 +2875,15 @@ mod test {
                 )
                 .unwrap();
             }
+            db.batch_append_tx_history(
+                &mut batch,
+                &alice,
+                height_1,
+                &[vec![2]],
+            )
+            .unwrap();
+            db.batch_append_tx_history(&mut batch, &bob, height_1, &[vec![0]])
+                .unwrap();
 
             add_block_to_batch(
                 &db,
@@ -2564,9 +2910,26 @@ mod test {
                 );
             }
 
+            assert_eq!(db.read_tx_history_len(&alice).unwrap(), 3);
+            assert_eq!(
+                db.read_tx_history_entry(&alice, 2).unwrap(),
+                Some(vec![2])
+            );
+            assert_eq!(db.read_tx_history_len(&bob).unwrap(), 1);
+
             // Rollback to the first block height
             db.rollback(height_0).unwrap();
 
+            // Check that the tx history of the second block was removed
+            assert_eq!(db.read_tx_history_len(&alice).unwrap(), 2);
+            assert_eq!(
+                db.read_tx_history_entry(&alice, 1).unwrap(),
+                Some(vec![1])
+            );
+            assert_eq!(db.read_tx_history_entry(&alice, 2).unwrap(), None);
+            assert_eq!(db.read_tx_history_len(&bob).unwrap(), 0);
+            assert_eq!(db.read_tx_history_entry(&bob, 0).unwrap(), None);
+
             // Check that the values are back to the state at the first block
             let added = db.read_subspace_val(&add_key).unwrap();
             assert_eq!(added, None);
@@ -2808,7 +3171,7 @@ mod test {
 
         let tar = snapshot.0.temp_tarball("zst");
         std::fs::write(tar, vec![16; 21]).expect("Test failed");
-        snapshot.chunk_snapshot(10).unwrap();
+        snapshot.chunk_snapshot(10, Hash::zero()).unwrap();
         let mut file_number = 0;
         for entry in std::fs::read_dir(snapshot_base).expect("Test failed") {
             let entry = entry.expect("Test failed");
@@ -2835,7 +3198,69 @@ mod test {
                 panic!("Found unexpected dir in snapshots")
             }
         }
-        assert_eq!(file_number, 5)
+        assert_eq!(file_number, 6)
+    }
+
+    /// Test that the chunks of a snapshot are reassembled
+    /// and that corrupted chunks are detected.
+    #[test]
+    fn test_assemble_verified_snapshot() {
+        let temp = tempfile::tempdir().expect("Test failed");
+        let base_dir = temp.path().to_path_buf();
+
+        let snap_path = SnapshotPath(base_dir.clone(), BlockHeight::first());
+        std::fs::create_dir_all(snap_path.base()).expect("Test failed");
+        let snapshot = DbSnapshot(snap_path);
+
+        let tarball: Vec<u8> = (0..21).collect();
+        std::fs::write(snapshot.0.temp_tarball("zst"), &tarball)
+            .expect("Test failed");
+        snapshot.chunk_snapshot(10, Hash::zero()).unwrap();
+
+        let mut assembled = vec![];
+        let meta = DbSnapshot::assemble_verified(
+            BlockHeight::first(),
+            &base_dir,
+            &mut assembled,
+        )
+        .expect("Test failed");
+        assert_eq!(assembled, tarball);
+        assert_eq!(meta.chunk_hashes.len(), 3);
+        assert_eq!(meta.merkle_root, Hash::zero());
+
+        // corrupt a chunk
+        std::fs::write(snapshot.0.chunk_with_id(1), [0; 10])
+            .expect("Test failed");
+        let result = DbSnapshot::assemble_verified(
+            BlockHeight::first(),
+            &base_dir,
+            &mut std::io::sink(),
+        );
+        assert_eq!(result.unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+        std::fs::write(snapshot.0.chunk_with_id(1), &tarball[10..20])
+            .expect("Test failed");
+
+        // tamper with the merkle root, which is covered by the root hash
+        std::fs::write(snapshot.0.merkle_root(), Hash::sha256(b"tampered"))
+            .expect("Test failed");
+        let result = DbSnapshot::assemble_verified(
+            BlockHeight::first(),
+            &base_dir,
+            &mut std::io::sink(),
+        );
+        assert_eq!(result.unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+        std::fs::write(snapshot.0.merkle_root(), Hash::zero())
+            .expect("Test failed");
+
+        // corrupt the root hash
+        std::fs::write(snapshot.0.chunks_root_hash(), Hash::zero())
+            .expect("Test failed");
+        let result = DbSnapshot::assemble_verified(
+            BlockHeight::first(),
+            &base_dir,
+            &mut std::io::sink(),
+        );
+        assert_eq!(result.unwrap_err().kind(), std::io::ErrorKind::InvalidData);
     }
 
     /// Test that we correctly delete snapshots
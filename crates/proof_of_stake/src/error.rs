@@ -6,7 +6,7 @@ use namada_core::chain::Epoch;
 use namada_core::dec::Dec;
 use thiserror::Error;
 
-use crate::parameters::MAX_VALIDATOR_METADATA_LEN;
+use crate::parameters::{MAX_REWARD_DESTINATIONS, MAX_VALIDATOR_METADATA_LEN};
 use crate::types::ValidatorState;
 use crate::{rewards, Error};
 
@@ -31,6 +31,10 @@ pub enum InflationError {
 pub enum BecomeValidatorError {
     #[error("The given address {0} is already a validator")]
     AlreadyValidator(Address),
+    #[error(
+        "Commission rate {0} is larger than the maximum commission rate {1}"
+    )]
+    CommissionRateTooLarge(Dec, Dec),
 }
 
 #[allow(missing_docs)]
@@ -45,6 +49,8 @@ pub enum BondError {
     SourceMustNotBeAValidator(Address),
     #[error("The given validator address {0} is inactive")]
     InactiveValidator(Address),
+    #[error("No bond from {0} to the validator {1} could be found")]
+    NoBond(Address, Address),
     #[error("Voting power overflow: {0}")]
     VotingPowerOverflow(TryFromIntError),
 }
@@ -68,6 +74,27 @@ pub enum UnbondError {
     ValidatorIsFrozen(Address),
 }
 
+#[allow(missing_docs)]
+#[derive(Error, Debug)]
+pub enum LiquidStakeError {
+    #[error(
+        "Liquid staking shares can only be issued for validators with an \
+         established address, got {0}"
+    )]
+    NotEstablishedValidator(Address),
+    #[error(
+        "The liquid staking pool of the validator {0} has no value left to \
+         back its outstanding shares"
+    )]
+    PoolDepleted(Address),
+    #[error("The bonded amount is too small to be issued any shares")]
+    NoSharesIssued,
+    #[error("Trying to redeem more shares ({0}) than the amount held ({1})")]
+    InsufficientShares(String, String),
+    #[error("The redeemed shares are not worth any bonded tokens")]
+    NoTokensRedeemed,
+}
+
 #[allow(missing_docs)]
 #[derive(Error, Debug)]
 pub enum SlashError {
@@ -92,6 +119,11 @@ pub enum CommissionRateChangeError {
     LargerThanOne(Dec, Address),
     #[error("Rate change of {0} is too large for validator {1}")]
     RateChangeTooLarge(Dec, Address),
+    #[error(
+        "Unexpected commission rate {0} larger than the maximum commission \
+         rate {1} for validator {2}"
+    )]
+    LargerThanMax(Dec, Dec, Address),
     #[error(
         "There is no maximum rate change written in storage for validator {0}"
     )]
@@ -114,6 +146,11 @@ pub enum UnjailValidatorError {
          {1}: current epoch is {2}"
     )]
     NotEligible(Address, Epoch, Epoch),
+    #[error(
+        "The given address {0} doesn't self-bond the minimum ratio {1} of its \
+         stake"
+    )]
+    InsufficientSelfBond(Address, Dec),
 }
 
 #[allow(missing_docs)]
@@ -174,6 +211,12 @@ pub enum ValidatorMetaDataError {
          {MAX_VALIDATOR_METADATA_LEN} characters"
     )]
     FieldTooLong(&'static str),
+    #[error(
+        "The reward destination must split the commission among 1 to \
+         {MAX_REWARD_DESTINATIONS} addresses with positive ratios that sum up \
+         to 1"
+    )]
+    InvalidRewardDestination,
 }
 
 impl From<BecomeValidatorError> for Error {
@@ -194,6 +237,12 @@ impl From<UnbondError> for Error {
     }
 }
 
+impl From<LiquidStakeError> for Error {
+    fn from(err: LiquidStakeError) -> Self {
+        Self::new(err)
+    }
+}
+
 impl From<CommissionRateChangeError> for Error {
     fn from(err: CommissionRateChangeError) -> Self {
         Self::new(err)
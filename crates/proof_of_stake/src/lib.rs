@@ -19,6 +19,7 @@
 
 pub mod epoched;
 pub mod event;
+pub mod liquid_staking;
 pub mod parameters;
 pub mod queries;
 pub mod rewards;
@@ -37,6 +38,7 @@ use core::fmt::Debug;
 use std::cmp;
 use std::collections::{BTreeMap, BTreeSet};
 use std::marker::PhantomData;
+use std::ops::Bound;
 
 use epoched::EpochOffset;
 pub use error::*;
@@ -67,6 +69,7 @@ use storage::write_validator_name;
 pub use types::GenesisValidator;
 use types::{into_tm_voting_power, DelegationEpochs};
 
+use crate::parameters::MAX_COMPOUNDED_BONDS_PER_EPOCH;
 use crate::queries::{find_bonds, has_bonds};
 use crate::rewards::{
     add_rewards_to_counter, compute_current_rewards_from_bonds,
@@ -80,13 +83,15 @@ use crate::storage::{
     below_capacity_validator_set_handle, bond_handle,
     consensus_validator_set_handle, delegation_targets_handle,
     delegator_redelegated_bonds_handle, delegator_redelegated_unbonds_handle,
-    get_last_reward_claim_epoch, liveness_missed_votes_handle,
-    liveness_sum_missed_votes_handle, read_consensus_validator_set_addresses,
+    get_last_reward_claim_epoch, is_auto_compound_enabled,
+    liveness_missed_votes_handle, liveness_sum_missed_votes_handle,
+    read_auto_compound_bonds, read_consensus_validator_set_addresses,
     read_non_pos_owned_params, read_pos_params,
     read_validator_last_slash_epoch, read_validator_max_commission_rate_change,
-    read_validator_stake, total_bonded_handle, total_consensus_stake_handle,
-    total_unbonded_handle, try_insert_consensus_key, unbond_handle,
-    update_total_deltas, update_validator_deltas, validator_addresses_handle,
+    read_validator_reward_destination, read_validator_stake,
+    total_bonded_handle, total_consensus_stake_handle, total_unbonded_handle,
+    try_insert_consensus_key, unbond_handle, update_total_deltas,
+    update_validator_deltas, validator_addresses_handle,
     validator_commission_rate_handle, validator_consensus_key_handle,
     validator_deltas_handle, validator_eth_cold_key_handle,
     validator_eth_hot_key_handle, validator_incoming_redelegations_handle,
@@ -94,19 +99,21 @@ use crate::storage::{
     validator_rewards_products_handle, validator_set_positions_handle,
     validator_slashes_handle, validator_state_handle,
     validator_total_redelegated_bonded_handle,
-    validator_total_redelegated_unbonded_handle,
+    validator_total_redelegated_unbonded_handle, write_auto_compound,
     write_last_pos_inflation_amount, write_last_reward_claim_epoch,
     write_last_staked_ratio, write_pos_params,
     write_validator_address_raw_hash, write_validator_avatar,
     write_validator_description, write_validator_discord_handle,
     write_validator_email, write_validator_max_commission_rate_change,
-    write_validator_metadata, write_validator_website,
+    write_validator_metadata, write_validator_reward_destination,
+    write_validator_website,
 };
 use crate::storage_key::{bonds_for_source_prefix, is_bond_key};
 use crate::types::{
     BondId, ConsensusValidator, EagerRedelegatedBondsMap,
-    RedelegatedBondsOrUnbonds, RedelegatedTokens, ResultSlashing, Slash,
-    Unbonds, ValidatorMetaData, ValidatorSetUpdate, ValidatorState, VoteInfo,
+    RedelegatedBondsOrUnbonds, RedelegatedTokens, ResultSlashing,
+    RewardDestination, Slash, Unbonds, ValidatorMetaData, ValidatorSetUpdate,
+    ValidatorState, VoteInfo,
 };
 use crate::validator_set_update::{
     copy_validator_sets_and_positions, insert_validator_into_validator_set,
@@ -135,6 +142,14 @@ where
         is_delegator(storage, address, epoch)
     }
 
+    fn delegation_validators(
+        storage: &S,
+        delegator: &Address,
+        epoch: Epoch,
+    ) -> Result<HashSet<Address>> {
+        queries::find_delegation_validators(storage, delegator, &epoch)
+    }
+
     fn pipeline_len(storage: &S) -> Result<u64> {
         let params = storage::read_owned_pos_params(storage)?;
         Ok(params.pipeline_len)
@@ -354,6 +369,31 @@ where
     let staking_token = staking_token_address(storage);
     Token::transfer(storage, &staking_token, source, &ADDRESS, amount)?;
 
+    bond_pos_held_tokens::<S, Gov>(
+        storage,
+        source,
+        validator,
+        amount,
+        current_epoch,
+        offset_opt,
+    )
+}
+
+/// Bond an amount of tokens that is already held by the PoS account from the
+/// `source` to the `validator`. The `source` must be resolved by the caller
+/// and the amount must be non-zero.
+fn bond_pos_held_tokens<S, Gov>(
+    storage: &mut S,
+    source: &Address,
+    validator: &Address,
+    amount: token::Amount,
+    current_epoch: Epoch,
+    offset_opt: Option<u64>,
+) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+    Gov: governance::Read<S>,
+{
     let params = read_pos_params::<S, Gov>(storage)?;
     let offset = offset_opt.unwrap_or(params.pipeline_len);
     let offset_epoch = checked!(current_epoch + offset)?;
@@ -839,6 +879,26 @@ where
         !is_jailed_or_inactive_at_pipeline,
     )?;
 
+    // Jail the validator from the pipeline epoch if it unbonds its self-bond
+    // below the minimum ratio of its stake
+    if source == validator
+        && !is_jailed_or_inactive_at_pipeline
+        && !has_min_self_bond(storage, &params, validator, pipeline_epoch)?
+    {
+        tracing::info!(
+            "Jailing validator {validator} from epoch {pipeline_epoch} for \
+             self-bonding less than the minimum ratio {} of its stake",
+            params.min_self_bond_ratio
+        );
+        jail_validator::<S, Gov>(
+            storage,
+            &params,
+            validator,
+            current_epoch,
+            pipeline_epoch,
+        )?;
+    }
+
     if tracing::level_enabled!(tracing::Level::DEBUG) {
         let bonds = find_bonds(storage, source, validator)?;
         tracing::debug!("\nBonds after decrementing: {bonds:#?}");
@@ -909,6 +969,8 @@ where
         add_rewards_to_counter(storage, source, validator, rewards)?;
     }
 
+    clear_auto_compound_if_unbonded(storage, source, validator)?;
+
     Ok(result_slashing)
 }
 
@@ -1395,6 +1457,14 @@ where
         ));
     }
 
+    if commission_rate > params.max_commission_rate {
+        return Err(BecomeValidatorError::CommissionRateTooLarge(
+            commission_rate,
+            params.max_commission_rate,
+        )
+        .into());
+    }
+
     // The address may not have any bonds if it is going to be initialized as a
     // validator
     if has_bonds::<S, Gov>(storage, address)? {
@@ -1653,6 +1723,8 @@ where
     //     total_slashed,
     // )?;
 
+    clear_auto_compound_if_unbonded(storage, source, validator)?;
+
     Ok(withdrawable_amount)
 }
 
@@ -1690,6 +1762,15 @@ where
             })?;
 
     let params = read_pos_params::<S, Gov>(storage)?;
+    if new_rate > params.max_commission_rate {
+        return Err(CommissionRateChangeError::LargerThanMax(
+            new_rate,
+            params.max_commission_rate,
+            validator.clone(),
+        )
+        .into());
+    }
+
     let commission_handle = validator_commission_rate_handle(validator);
     let pipeline_epoch = checked!(current_epoch + params.pipeline_len)?;
 
@@ -1739,12 +1820,10 @@ where
     // Bonds
     let bonds =
         bond_handle(&bond_id.source, &bond_id.validator).get_data_handler();
-    for next in bonds.iter(storage)? {
+    for next in bonds.iter_range(storage, ..=epoch)? {
         let (start, delta) = next?;
-        if start <= epoch {
-            let amount = amounts.entry(start).or_default();
-            *amount = checked!(amount + delta)?;
-        }
+        let amount = amounts.entry(start).or_default();
+        *amount = checked!(amount + delta)?;
     }
 
     // Add unbonds that are still contributing to stake
@@ -1871,7 +1950,23 @@ where
     Gov: governance::Read<S>,
 {
     let params = read_pos_params::<S, Gov>(storage)?;
-    let mut amounts = bond_amounts_for_query(storage, &params, bond_id, epoch)?;
+    let amounts = slashed_bond_amounts(storage, &params, bond_id, epoch)?;
+    token::Amount::sum(amounts.values().copied())
+        .ok_or_err_msg("token amount overflow")
+}
+
+/// Get the bond amounts after slashing for a given bond ID and epoch, keyed
+/// by the start epoch of the bonds.
+fn slashed_bond_amounts<S>(
+    storage: &S,
+    params: &PosParams,
+    bond_id: &BondId,
+    epoch: Epoch,
+) -> Result<BTreeMap<Epoch, token::Amount>>
+where
+    S: StorageRead,
+{
+    let mut amounts = bond_amounts_for_query(storage, params, bond_id, epoch)?;
 
     if !amounts.is_empty() {
         let slashes = find_validator_slashes(storage, &bond_id.validator)?;
@@ -1904,7 +1999,7 @@ where
 
             let result_fold = fold_and_slash_redelegated_bonds(
                 storage,
-                &params,
+                params,
                 &redelegated_bonds,
                 start,
                 &list_slashes,
@@ -1915,7 +2010,7 @@ where
                 checked!(amount - result_fold.total_redelegated)?;
 
             let after_not_redelegated = apply_list_slashes(
-                &params,
+                params,
                 &list_slashes,
                 total_not_redelegated,
             )?;
@@ -1926,8 +2021,7 @@ where
         }
     }
 
-    token::Amount::sum(amounts.values().copied())
-        .ok_or_err_msg("token amount overflow")
+    Ok(amounts)
 }
 
 /// Get bond amounts within the `claim_start..=claim_end` epoch range for
@@ -1962,7 +2056,7 @@ where
     // `unbond_tokens`
     let bonds =
         bond_handle(&bond_id.source, &bond_id.validator).get_data_handler();
-    for next in bonds.iter(storage)? {
+    for next in bonds.iter_range(storage, ..=claim_end)? {
         let (start, delta) = next?;
 
         for ep in Epoch::iter_bounds_inclusive(claim_start, claim_end) {
@@ -2132,8 +2226,17 @@ where
         }
     }
 
-    // Re-insert the validator into the validator set and update its state
+    // Check that the validator self-bonds enough of its stake to be unjailed
     let pipeline_epoch = checked!(current_epoch + params.pipeline_len)?;
+    if !has_min_self_bond(storage, &params, validator, pipeline_epoch)? {
+        return Err(UnjailValidatorError::InsufficientSelfBond(
+            validator.clone(),
+            params.min_self_bond_ratio,
+        )
+        .into());
+    }
+
+    // Re-insert the validator into the validator set and update its state
     let stake =
         read_validator_stake(storage, &params, validator, pipeline_epoch)?;
 
@@ -2148,6 +2251,27 @@ where
     Ok(())
 }
 
+/// Check if the self-bond of a validator is at least the minimum self-bond
+/// ratio of its stake in the given epoch.
+pub fn has_min_self_bond<S>(
+    storage: &S,
+    params: &PosParams,
+    validator: &Address,
+    epoch: Epoch,
+) -> Result<bool>
+where
+    S: StorageRead,
+{
+    if params.min_self_bond_ratio.is_zero() {
+        return Ok(true);
+    }
+    let self_bond = bond_handle(validator, validator)
+        .get_sum(storage, epoch, params)?
+        .unwrap_or_default();
+    let stake = read_validator_stake(storage, params, validator, epoch)?;
+    Ok(self_bond >= stake.mul_ceil(params.min_self_bond_ratio)?)
+}
+
 /// Check if a validator is frozen.
 ///
 /// A validator is frozen until after all of its enqueued slashes have been
@@ -2742,6 +2866,63 @@ where
     Ok(())
 }
 
+/// Jail the validators that self-bond less than the minimum ratio of their
+/// stake at the pipeline epoch, starting from the pipeline epoch. Unlike
+/// unbonding the self-bond, delegations and the compounding of rewards can
+/// push a validator below the ratio without any action of its own, so this
+/// is checked at the start of every epoch.
+pub fn jail_for_min_self_bond<S, Gov>(
+    storage: &mut S,
+    params: &PosParams,
+    current_epoch: Epoch,
+) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+    Gov: governance::Read<S>,
+{
+    if params.min_self_bond_ratio.is_zero() {
+        return Ok(());
+    }
+    let pipeline_epoch = checked!(current_epoch + params.pipeline_len)?;
+    let validators = validator_addresses_handle()
+        .at(&pipeline_epoch)
+        .iter(storage)?
+        .collect::<Result<Vec<_>>>()?;
+
+    for validator in &validators {
+        let state = validator_state_handle(validator).get(
+            storage,
+            pipeline_epoch,
+            params,
+        )?;
+        let is_active = matches!(
+            state,
+            Some(ValidatorState::Consensus)
+                | Some(ValidatorState::BelowCapacity)
+                | Some(ValidatorState::BelowThreshold)
+        );
+        if !is_active
+            || has_min_self_bond(storage, params, validator, pipeline_epoch)?
+        {
+            continue;
+        }
+        tracing::info!(
+            "Jailing validator {validator} starting in epoch {pipeline_epoch} \
+             for self-bonding less than the minimum ratio {} of its stake",
+            params.min_self_bond_ratio
+        );
+        jail_validator::<S, Gov>(
+            storage,
+            params,
+            validator,
+            current_epoch,
+            pipeline_epoch,
+        )?;
+    }
+
+    Ok(())
+}
+
 /// Change validator's metadata. In addition to changing any of the data from
 /// [`ValidatorMetaData`], the validator's commission rate can be changed within
 /// here as well.
@@ -2755,6 +2936,7 @@ pub fn change_validator_metadata<S, Gov>(
     discord_handle: Option<String>,
     avatar: Option<String>,
     name: Option<String>,
+    reward_destination: Option<RewardDestination>,
     commission_rate: Option<Dec>,
     current_epoch: Epoch,
 ) -> Result<()>
@@ -2780,6 +2962,13 @@ where
     if let Some(name) = name {
         write_validator_name(storage, validator, &name)?;
     }
+    if let Some(reward_destination) = reward_destination {
+        write_validator_reward_destination(
+            storage,
+            validator,
+            &reward_destination,
+        )?;
+    }
     if let Some(commission_rate) = commission_rate {
         change_validator_commission_rate::<S, Gov>(
             storage,
@@ -2828,6 +3017,26 @@ where
     let staking_token = staking_token_address(storage);
     Token::transfer(storage, &staking_token, &ADDRESS, &source, reward_tokens)?;
 
+    // When a validator claims its rewards, also pay out the commissions
+    // tallied for its reward destination
+    if source == *validator {
+        if let Some(destination) =
+            read_validator_reward_destination(storage, validator)?
+        {
+            for address in destination.0.keys() {
+                let commissions =
+                    take_rewards_from_counter(storage, address, validator)?;
+                Token::transfer(
+                    storage,
+                    &staking_token,
+                    &ADDRESS,
+                    address,
+                    commissions,
+                )?;
+            }
+        }
+    }
+
     Ok(reward_tokens)
 }
 
@@ -2857,6 +3066,235 @@ where
     Ok(res)
 }
 
+/// Enable or disable the automatic compounding of the rewards of a bond. When
+/// enabled, the rewards of the bond are re-bonded to the same validator at
+/// every epoch transition. It can only be enabled for an existing bond and it
+/// is disabled once the bond is fully unbonded.
+pub fn set_auto_compound<S>(
+    storage: &mut S,
+    source: Option<&Address>,
+    validator: &Address,
+    enabled: bool,
+) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    if !is_validator(storage, validator)? {
+        return Err(BondError::NotAValidator(validator.clone()).into());
+    }
+    if let Some(source) = source {
+        if source != validator && is_validator(storage, source)? {
+            return Err(
+                BondError::SourceMustNotBeAValidator(source.clone()).into()
+            );
+        }
+    }
+    let source = source.unwrap_or(validator);
+    if enabled && !has_bond(storage, source, validator)? {
+        return Err(BondError::NoBond(source.clone(), validator.clone()).into());
+    }
+    tracing::debug!(
+        "Setting auto-compounding of rewards of {source} --> {validator} to \
+         {enabled}"
+    );
+    write_auto_compound(storage, source, validator, enabled)
+}
+
+/// Check if there is a non-zero bond from `source` to `validator`, including
+/// a bond that only becomes active at the pipeline offset.
+fn has_bond<S>(
+    storage: &S,
+    source: &Address,
+    validator: &Address,
+) -> Result<bool>
+where
+    S: StorageRead,
+{
+    for bond in bond_handle(source, validator)
+        .get_data_handler()
+        .iter(storage)?
+    {
+        let (_start_epoch, amount) = bond?;
+        if !amount.is_zero() {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Disable the automatic compounding of the rewards of a bond that has been
+/// fully unbonded, so that it's not left behind for a bond that no longer
+/// exists.
+fn clear_auto_compound_if_unbonded<S>(
+    storage: &mut S,
+    source: &Address,
+    validator: &Address,
+) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    if is_auto_compound_enabled(storage, source, validator)?
+        && !has_bond(storage, source, validator)?
+    {
+        tracing::debug!(
+            "Disabling auto-compounding of rewards of the unbonded {source} \
+             --> {validator}"
+        );
+        write_auto_compound(storage, source, validator, false)?;
+    }
+    Ok(())
+}
+
+/// Re-bond the rewards of the bonds with automatic compounding enabled. This
+/// must be applied at the start of a new epoch, before the inflation of the
+/// last epoch is applied, so the rewards are claimed up to the epoch before
+/// the last one and are bonded at the pipeline offset of the `current_epoch`.
+fn compound_rewards<S, Gov>(storage: &mut S, current_epoch: Epoch) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+    Gov: governance::Read<S>,
+{
+    let claim_epoch = match current_epoch.prev() {
+        Some(epoch) if epoch != Epoch::default() => epoch,
+        _ => return Ok(()),
+    };
+    // At most `MAX_COMPOUNDED_BONDS_PER_EPOCH` bonds are compounded in an
+    // epoch, continuing after the last bond compounded in the previous
+    // epoch. The rewards of the other bonds keep accruing until their turn.
+    let bonds = read_auto_compound_bonds(storage, None)?;
+    let cursor_key = storage_key::auto_compound_cursor_key();
+    let cursor: Option<BondId> = storage.read(&cursor_key)?;
+    let page: Vec<BondId> = match &cursor {
+        Some(cursor) => bonds
+            .range((Bound::Excluded(cursor), Bound::Unbounded))
+            .chain(bonds.range(..=cursor))
+            .take(MAX_COMPOUNDED_BONDS_PER_EPOCH)
+            .cloned()
+            .collect(),
+        None => bonds
+            .iter()
+            .take(MAX_COMPOUNDED_BONDS_PER_EPOCH)
+            .cloned()
+            .collect(),
+    };
+    match page.last() {
+        Some(last) if page.len() < bonds.len() => {
+            storage.write(&cursor_key, last.clone())?
+        }
+        _ => storage.delete(&cursor_key)?,
+    }
+    for BondId { source, validator } in page {
+        // A bond that cannot be compounded must not halt the epoch
+        // transition. Nothing is changed when it fails, so its rewards are
+        // left to be claimed or compounded later.
+        if let Err(err) = compound_bond_rewards::<S, Gov>(
+            storage,
+            &source,
+            &validator,
+            claim_epoch,
+            current_epoch,
+        ) {
+            tracing::error!(
+                "Failed to compound the rewards of {source} --> {validator}: \
+                 {err}"
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Re-bond the rewards of a bond claimable in the `claim_epoch` at the
+/// pipeline offset of the `current_epoch`. No-op if the rewards were already
+/// claimed in the `claim_epoch`. The rewards are checked to be bondable
+/// before any storage is changed, so on error they are still claimable.
+fn compound_bond_rewards<S, Gov>(
+    storage: &mut S,
+    source: &Address,
+    validator: &Address,
+    claim_epoch: Epoch,
+    current_epoch: Epoch,
+) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+    Gov: governance::Read<S>,
+{
+    let last_claim_epoch =
+        get_last_reward_claim_epoch(storage, source, validator)?;
+    if last_claim_epoch.is_some_and(|epoch| epoch >= claim_epoch) {
+        return Ok(());
+    }
+    let mut reward_tokens = compute_current_rewards_from_bonds::<S, Gov>(
+        storage,
+        source,
+        validator,
+        claim_epoch,
+    )?;
+    let counter_rewards = read_rewards_counter(storage, source, validator)?;
+    checked!(reward_tokens += counter_rewards)?;
+    if !reward_tokens.is_zero() {
+        check_bond_pos_held_tokens::<S, Gov>(
+            storage,
+            source,
+            validator,
+            reward_tokens,
+            current_epoch,
+        )?;
+        tracing::debug!(
+            "Compounding {} reward tokens of {source} --> {validator}",
+            reward_tokens.to_string_native()
+        );
+        // The reward tokens are already held by PoS
+        bond_pos_held_tokens::<S, Gov>(
+            storage,
+            source,
+            validator,
+            reward_tokens,
+            current_epoch,
+            None,
+        )?;
+    }
+    take_rewards_from_counter(storage, source, validator)?;
+    write_last_reward_claim_epoch(storage, source, validator, claim_epoch)
+}
+
+/// Check that the tokens held by PoS can be bonded from `source` to
+/// `validator` at the pipeline offset of the `current_epoch` by
+/// [`bond_pos_held_tokens`], without changing storage.
+fn check_bond_pos_held_tokens<S, Gov>(
+    storage: &S,
+    source: &Address,
+    validator: &Address,
+    amount: token::Amount,
+    current_epoch: Epoch,
+) -> Result<()>
+where
+    S: StorageRead,
+    Gov: governance::Read<S>,
+{
+    let params = read_pos_params::<S, Gov>(storage)?;
+    let pipeline_epoch = checked!(current_epoch + params.pipeline_len)?;
+    if validator_state_handle(validator)
+        .get(storage, pipeline_epoch, &params)?
+        .is_none()
+    {
+        return Err(BondError::NotAValidator(validator.clone()).into());
+    }
+    for bonds in [
+        bond_handle(source, validator),
+        total_bonded_handle(validator),
+    ] {
+        let bonded = bonds
+            .get_delta_val(storage, pipeline_epoch)?
+            .unwrap_or_default();
+        checked!(bonded + amount)?;
+    }
+    // The amount is applied to the stake as a signed change
+    token::Change::try_from(amount.raw_amount())
+        .ok()
+        .ok_or_err_msg("token amount overflow")?;
+    Ok(())
+}
+
 /// Jail a validator by removing it from and updating the validator sets and
 /// changing a its state to `Jailed`. Validators are jailed for liveness and for
 /// misbehaving.
@@ -3031,6 +3469,14 @@ where
             );
             panic!("Error while processing slashes");
         }
+
+        // Invariant: Compound rewards after processing slashes, as the
+        // slashes may affect the rewarded bond amounts
+        compound_rewards::<S, Gov>(storage, current_epoch)?;
+
+        // Invariant: Check the self-bonds after compounding rewards, as the
+        // compounded delegations may push validators below the minimum ratio
+        jail_for_min_self_bond::<S, Gov>(storage, &pos_params, current_epoch)?;
     }
 
     // Consensus set liveness check
@@ -0,0 +1,341 @@
+//! Liquid staking. Tokens bonded to a validator via a liquid bond are pooled
+//! in a regular bond whose source is the share token address of the
+//! validator, and the bonder receives transferable shares of the pool that
+//! are minted in the multitoken. The rewards of the pool are automatically
+//! compounded, and slashes of the validator reduce the value of the pool, so
+//! the exchange rate of the shares for bonded tokens follows both.
+//!
+//! A liquid unbond burns shares and unbonds the tokens that they are worth
+//! from the pool. The resulting unbonds are moved to the redeeming account,
+//! which withdraws them like any other unbonds once they become withdrawable,
+//! such that slashes processed in the meantime still apply.
+
+use std::collections::BTreeMap;
+
+use namada_core::address::{Address, InternalAddress};
+use namada_core::arith::checked;
+use namada_core::chain::Epoch;
+use namada_core::token;
+use namada_systems::{governance, trans_token};
+
+use crate::lazy_map::Collectable;
+use crate::rewards::{
+    compute_current_rewards_from_bonds, read_rewards_counter,
+};
+use crate::storage::{
+    get_last_reward_claim_epoch, read_pos_params, unbond_handle,
+    write_auto_compound,
+};
+use crate::types::{BondId, ShareExchangeRate};
+use crate::{
+    bond_amounts_for_query, bond_pos_held_tokens, compound_bond_rewards,
+    is_validator, slashed_bond_amounts, staking_token_address, unbond_tokens,
+    BondError, LiquidStakeError, OptionExt, PosParams, Result, StorageRead,
+    StorageWrite, ADDRESS,
+};
+
+/// Get the address of the liquid staking share token of a validator. Only
+/// validators with an established address have a share token.
+pub fn share_token_address(validator: &Address) -> Result<Address> {
+    match validator {
+        Address::Established(validator) => Ok(Address::Internal(
+            InternalAddress::StakeShare(validator.clone()),
+        )),
+        _ => {
+            Err(LiquidStakeError::NotEstablishedValidator(validator.clone())
+                .into())
+        }
+    }
+}
+
+/// Get the validator of a liquid staking share token, if the given address
+/// is one.
+pub fn share_token_validator(token: &Address) -> Option<Address> {
+    match token {
+        Address::Internal(InternalAddress::StakeShare(validator)) => {
+            Some(Address::Established(validator.clone()))
+        }
+        _ => None,
+    }
+}
+
+/// Read the exchange rate of the liquid staking shares of a validator for
+/// the bonded tokens of its pool in the given epoch.
+pub fn share_exchange_rate<S, Gov, Token>(
+    storage: &S,
+    validator: &Address,
+    epoch: Epoch,
+) -> Result<ShareExchangeRate>
+where
+    S: StorageRead,
+    Gov: governance::Read<S>,
+    Token: trans_token::Read<S>,
+{
+    let share_token = share_token_address(validator)?;
+    let params = read_pos_params::<S, Gov>(storage)?;
+    let pool_value = pool_value(storage, &params, validator, epoch)?;
+    let share_supply = Token::read_total_supply(storage, &share_token)?;
+    Ok(ShareExchangeRate {
+        pool_value,
+        share_supply,
+    })
+}
+
+/// Read the value of the liquid staking pool of a validator in the given
+/// epoch, which is the bonded amount of the pool after slashes.
+pub fn pool_value<S>(
+    storage: &S,
+    params: &PosParams,
+    validator: &Address,
+    epoch: Epoch,
+) -> Result<token::Amount>
+where
+    S: StorageRead,
+{
+    let bond_id = BondId {
+        source: share_token_address(validator)?,
+        validator: validator.clone(),
+    };
+    token::Amount::sum(
+        slashed_bond_amounts(storage, params, &bond_id, epoch)?.into_values(),
+    )
+    .ok_or_err_msg("token amount overflow")
+}
+
+/// Read the rewards of the liquid staking pool of a validator that are yet to
+/// be compounded. The first liquid bond or unbond of the validator in the
+/// current epoch compounds them before the shares are exchanged.
+pub fn pending_pool_rewards<S, Gov>(
+    storage: &S,
+    validator: &Address,
+    current_epoch: Epoch,
+) -> Result<token::Amount>
+where
+    S: StorageRead,
+    Gov: governance::Read<S>,
+{
+    let share_token = share_token_address(validator)?;
+    let last_claim_epoch =
+        get_last_reward_claim_epoch(storage, &share_token, validator)?;
+    if last_claim_epoch.is_some_and(|epoch| epoch >= current_epoch) {
+        return Ok(token::Amount::zero());
+    }
+    let rewards = compute_current_rewards_from_bonds::<S, Gov>(
+        storage,
+        &share_token,
+        validator,
+        current_epoch,
+    )?;
+    let counter_rewards =
+        read_rewards_counter(storage, &share_token, validator)?;
+    Ok(checked!(rewards + counter_rewards)?)
+}
+
+/// Bond tokens from the `source` to the liquid staking pool of the
+/// `validator` and mint the issued shares to the `source`. Returns the amount
+/// of issued shares.
+pub fn liquid_bond_tokens<S, Gov, Token>(
+    storage: &mut S,
+    source: &Address,
+    validator: &Address,
+    amount: token::Amount,
+    current_epoch: Epoch,
+) -> Result<token::Amount>
+where
+    S: StorageRead + StorageWrite,
+    Gov: governance::Read<S>,
+    Token: trans_token::Write<S>,
+{
+    tracing::debug!(
+        "Liquid bonding token amount {} at epoch {current_epoch}",
+        amount.to_string_native()
+    );
+    if amount.is_zero() {
+        return Ok(token::Amount::zero());
+    }
+    if is_validator(storage, source)? {
+        return Err(BondError::SourceMustNotBeAValidator(source.clone()).into());
+    }
+    let share_token = share_token_address(validator)?;
+
+    // The pending rewards of the pool must be accounted for in its value
+    // before issuing new shares
+    compound_bond_rewards::<S, Gov>(
+        storage,
+        &share_token,
+        validator,
+        current_epoch,
+        current_epoch,
+    )?;
+    let params = read_pos_params::<S, Gov>(storage)?;
+    let pipeline_epoch = checked!(current_epoch + params.pipeline_len)?;
+    let rate = share_exchange_rate::<S, Gov, Token>(
+        storage,
+        validator,
+        pipeline_epoch,
+    )?;
+    let shares = rate
+        .shares_for_tokens(amount)
+        .ok_or_else(|| LiquidStakeError::PoolDepleted(validator.clone()))?;
+    if shares.is_zero() {
+        return Err(LiquidStakeError::NoSharesIssued.into());
+    }
+
+    let staking_token = staking_token_address(storage);
+    Token::transfer(storage, &staking_token, source, &ADDRESS, amount)?;
+    bond_pos_held_tokens::<S, Gov>(
+        storage,
+        &share_token,
+        validator,
+        amount,
+        current_epoch,
+        None,
+    )?;
+    write_auto_compound(storage, &share_token, validator, true)?;
+    Token::mint_tokens(storage, &ADDRESS, &share_token, source, shares)?;
+
+    tracing::debug!(
+        "Issued {} shares of {validator} to {source}",
+        shares.to_string_native()
+    );
+    Ok(shares)
+}
+
+/// Burn liquid staking shares of the `validator` held by the `source` and
+/// unbond the tokens that they are worth from the pool. The unbonds are moved
+/// to the `source`, which can withdraw them once they are withdrawable.
+/// Returns the unbonded amount before any slashes that are yet to be
+/// processed.
+pub fn liquid_unbond_tokens<S, Gov, Token>(
+    storage: &mut S,
+    source: &Address,
+    validator: &Address,
+    shares: token::Amount,
+    current_epoch: Epoch,
+) -> Result<token::Amount>
+where
+    S: StorageRead + StorageWrite,
+    Gov: governance::Read<S>,
+    Token: trans_token::Write<S>,
+{
+    tracing::debug!(
+        "Liquid unbonding {} shares at epoch {current_epoch}",
+        shares.to_string_native()
+    );
+    if shares.is_zero() {
+        return Ok(token::Amount::zero());
+    }
+    let share_token = share_token_address(validator)?;
+    let balance = Token::read_balance(storage, &share_token, source)?;
+    if shares > balance {
+        return Err(LiquidStakeError::InsufficientShares(
+            shares.to_string_native(),
+            balance.to_string_native(),
+        )
+        .into());
+    }
+
+    // Redeem at the value of the pool including its pending rewards
+    compound_bond_rewards::<S, Gov>(
+        storage,
+        &share_token,
+        validator,
+        current_epoch,
+        current_epoch,
+    )?;
+    let params = read_pos_params::<S, Gov>(storage)?;
+    let pipeline_epoch = checked!(current_epoch + params.pipeline_len)?;
+    let rate = share_exchange_rate::<S, Gov, Token>(
+        storage,
+        validator,
+        pipeline_epoch,
+    )?;
+    let value = rate.tokens_for_shares(shares).unwrap_or_default();
+    if value.is_zero() {
+        return Err(LiquidStakeError::NoTokensRedeemed.into());
+    }
+
+    // Find the amount of bonded tokens that is worth the redeemed value
+    let bond_id = BondId {
+        source: share_token.clone(),
+        validator: validator.clone(),
+    };
+    let amount = bonded_amount_for_value(
+        &bond_amounts_for_query(storage, &params, &bond_id, pipeline_epoch)?,
+        &slashed_bond_amounts(storage, &params, &bond_id, pipeline_epoch)?,
+        value,
+    )?;
+
+    Token::burn_tokens(storage, &share_token, source, shares)?;
+    unbond_tokens::<S, Gov>(
+        storage,
+        Some(&share_token),
+        validator,
+        amount,
+        current_epoch,
+        false,
+    )?;
+
+    // Hand the unbonds of the pool over to the source
+    let pool_unbonds = unbond_handle(&share_token, validator);
+    let source_unbonds = unbond_handle(source, validator);
+    let unbonds = pool_unbonds.collect_map(storage)?;
+    for (start, withdrawable) in unbonds {
+        for (withdrawable_epoch, unbond_amount) in withdrawable {
+            source_unbonds.at(&start).try_update(
+                storage,
+                withdrawable_epoch,
+                |current| {
+                    let current = current.unwrap_or_default();
+                    Ok(checked!(current + unbond_amount)?)
+                },
+            )?;
+        }
+        pool_unbonds.remove_all(storage, &start)?;
+    }
+
+    tracing::debug!(
+        "Redeemed {} shares of {validator} for {} bonded tokens",
+        shares.to_string_native(),
+        amount.to_string_native()
+    );
+    Ok(amount)
+}
+
+/// Find the amount of bonded tokens that has to be unbonded from the given
+/// bonds to redeem the given value after slashes. Bonds are unbonded starting
+/// from the most recent one, like in [`unbond_tokens`], so the value of each
+/// is found from its own slashes.
+fn bonded_amount_for_value(
+    raw_amounts: &BTreeMap<Epoch, token::Amount>,
+    slashed_amounts: &BTreeMap<Epoch, token::Amount>,
+    value: token::Amount,
+) -> Result<token::Amount> {
+    let mut remaining = value;
+    let mut amount = token::Amount::zero();
+    for (start, raw_amount) in raw_amounts.iter().rev() {
+        if remaining.is_zero() {
+            break;
+        }
+        let slashed_amount =
+            slashed_amounts.get(start).copied().unwrap_or_default();
+        if slashed_amount.is_zero() {
+            continue;
+        }
+        if slashed_amount <= remaining {
+            checked!(amount += *raw_amount)?;
+            checked!(remaining -= slashed_amount)?;
+        } else {
+            let (partial, _rem) = remaining
+                .raw_amount()
+                .checked_mul_div(
+                    raw_amount.raw_amount(),
+                    slashed_amount.raw_amount(),
+                )
+                .ok_or_err_msg("token amount overflow")?;
+            checked!(amount += token::Amount::from(partial))?;
+            remaining = token::Amount::zero();
+        }
+    }
+    Ok(amount)
+}
@@ -80,6 +80,18 @@ pub struct OwnedPosParams {
     pub rewards_gain_p: Dec,
     /// PoS gain d (read only)
     pub rewards_gain_d: Dec,
+    /// The minimum ratio of a validator's stake that must be self-bonded. It
+    /// is only checked when a validator unbonds its self-bond, which gets it
+    /// jailed if it falls below the ratio, and when it is unjailed. Stake
+    /// delegated to a validator later on may bring it below the ratio, which
+    /// is not re-checked at epoch transitions. Stored under its own key, so it
+    /// is not encoded with the other parameters.
+    #[borsh(skip)]
+    pub min_self_bond_ratio: Dec,
+    /// The maximum commission rate that a validator can set. Stored under its
+    /// own key, so it is not encoded with the other parameters.
+    #[borsh(skip)]
+    pub max_commission_rate: Dec,
 }
 
 impl Default for OwnedPosParams {
@@ -108,6 +120,10 @@ impl Default for OwnedPosParams {
             liveness_threshold: Dec::new(9, 1).expect("Test failed"),
             rewards_gain_p: Dec::from_str("0.25").expect("Test failed"),
             rewards_gain_d: Dec::from_str("0.25").expect("Test failed"),
+            // no minimum self-bond
+            min_self_bond_ratio: Dec::zero(),
+            // no cap on the commission rate
+            max_commission_rate: Dec::one(),
         }
     }
 }
@@ -136,6 +152,10 @@ pub enum ValidationError {
     VotesPerTokenGreaterThanOne(Dec),
     #[error("Liveness threshold cannot be greater than 1, got {0}")]
     LivenessThresholdGreaterThanOne(Dec),
+    #[error("Minimum self-bond ratio must be between 0 and 1, got {0}")]
+    InvalidMinSelfBondRatio(Dec),
+    #[error("Maximum commission rate must be between 0 and 1, got {0}")]
+    InvalidMaxCommissionRate(Dec),
     #[error("Pipeline length must be >= 2, got {0}")]
     PipelineLenTooShort(u64),
     #[error(
@@ -148,6 +168,14 @@ pub enum ValidationError {
 /// The maximum string length of any validator metadata
 pub const MAX_VALIDATOR_METADATA_LEN: u64 = 500;
 
+/// The maximum number of addresses among which the commission of a validator
+/// can be split
+pub const MAX_REWARD_DESTINATIONS: usize = 10;
+
+/// The maximum number of bonds whose rewards are automatically compounded in
+/// a single epoch transition
+pub const MAX_COMPOUNDED_BONDS_PER_EPOCH: usize = 1_000;
+
 /// The number of fundamental units per whole token of the native staking token
 pub const TOKENS_PER_NAM: u64 = 1_000_000;
 
@@ -212,6 +240,22 @@ impl OwnedPosParams {
             ))
         }
 
+        if self.min_self_bond_ratio.is_negative()
+            || self.min_self_bond_ratio > Dec::one()
+        {
+            errors.push(ValidationError::InvalidMinSelfBondRatio(
+                self.min_self_bond_ratio,
+            ))
+        }
+
+        if self.max_commission_rate.is_negative()
+            || self.max_commission_rate > Dec::one()
+        {
+            errors.push(ValidationError::InvalidMaxCommissionRate(
+                self.max_commission_rate,
+            ))
+        }
+
         errors
     }
 
@@ -17,10 +17,10 @@ use crate::storage::{
     consensus_validator_set_handle, get_last_reward_claim_epoch,
     read_last_pos_inflation_amount, read_last_staked_ratio,
     read_owned_pos_params, read_pos_params, read_total_stake,
-    read_validator_stake, rewards_accumulator_handle,
-    validator_commission_rate_handle, validator_rewards_products_handle,
-    validator_state_handle, write_last_pos_inflation_amount,
-    write_last_staked_ratio,
+    read_validator_reward_destination, read_validator_stake,
+    rewards_accumulator_handle, validator_commission_rate_handle,
+    validator_rewards_products_handle, validator_state_handle,
+    write_last_pos_inflation_amount, write_last_staked_ratio,
 };
 use crate::types::{into_tm_voting_power, BondId, ValidatorState, VoteInfo};
 use crate::{
@@ -519,8 +519,23 @@ where
     {
         validator_rewards_products_handle(&validator)
             .insert(storage, last_epoch, product)?;
-        // The commissions belong to the validator
-        add_rewards_to_counter(storage, &validator, &validator, commissions)?;
+        // The commissions belong to the validator, unless it has directed
+        // them to a reward destination
+        match read_validator_reward_destination(storage, &validator)? {
+            Some(destination) => {
+                for (address, share) in destination.split(commissions)? {
+                    add_rewards_to_counter(
+                        storage, &address, &validator, share,
+                    )?;
+                }
+            }
+            None => add_rewards_to_counter(
+                storage,
+                &validator,
+                &validator,
+                commissions,
+            )?,
+        }
     }
 
     // Mint tokens to the PoS account for the last epoch's inflation
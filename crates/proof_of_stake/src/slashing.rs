@@ -6,9 +6,11 @@ use std::collections::{BTreeMap, BTreeSet};
 use borsh::BorshDeserialize;
 use namada_core::address::Address;
 use namada_core::arith::{self, checked};
+use namada_core::borsh::BorshSerializeExt;
 use namada_core::chain::{BlockHeight, Epoch};
 use namada_core::collections::HashMap;
 use namada_core::dec::Dec;
+use namada_core::hash::Hash;
 use namada_core::key::tm_raw_hash_to_string;
 use namada_core::tendermint::abci::types::{Misbehavior, MisbehaviorKind};
 use namada_core::token;
@@ -21,14 +23,17 @@ use crate::storage::{
     enqueued_slashes_handle, read_pos_params, read_validator_last_slash_epoch,
     read_validator_stake, total_bonded_handle, total_unbonded_handle,
     update_total_deltas, update_validator_deltas,
-    validator_outgoing_redelegations_handle, validator_slashes_handle,
-    validator_state_handle, validator_total_redelegated_bonded_handle,
+    validator_outgoing_redelegations_handle, validator_slash_records_handle,
+    validator_slashes_handle, validator_state_handle,
+    validator_total_redelegated_bonded_handle,
     validator_total_redelegated_unbonded_handle,
     write_validator_last_slash_epoch,
 };
 use crate::types::{
-    EagerRedelegatedBondsMap, ResultSlashing, Slash, SlashType, SlashedAmount,
-    Slashes, TotalRedelegatedUnbonded, ValidatorState,
+    BondSlashReport, CubicSlashWindow, CubicSlashWindowEpoch,
+    EagerRedelegatedBondsMap, RedelegationSlashReport, ResultSlashing, Slash,
+    SlashRecord, SlashReport, SlashType, SlashableAmountComputation,
+    SlashedAmount, Slashes, TotalRedelegatedUnbonded, ValidatorState,
 };
 use crate::validator_set_update::update_validator_set;
 use crate::{
@@ -94,6 +99,15 @@ where
             };
             let validator_raw_hash =
                 tm_raw_hash_to_string(evidence.validator.address);
+            let evidence_hash = Hash::sha256(
+                (
+                    slash_type,
+                    validator_raw_hash.clone(),
+                    evidence_height,
+                    evidence.time.to_rfc3339(),
+                )
+                    .serialize_to_vec(),
+            );
             let validator = match storage::find_validator_by_raw_hash(
                 storage,
                 &validator_raw_hash,
@@ -129,6 +143,16 @@ where
                 validator_set_update_epoch,
             ) {
                 tracing::error!("Error in slashing: {}", err);
+                continue;
+            }
+            // Keep the hash of the first evidence in the slash record
+            let slash_records = validator_slash_records_handle(&validator);
+            if let Some(mut record) = slash_records
+                .get(storage, &evidence_height)?
+                .filter(|record| record.evidence_hash.is_none())
+            {
+                record.evidence_hash = Some(evidence_hash);
+                slash_records.insert(storage, evidence_height, record)?;
             }
         }
     }
@@ -176,6 +200,19 @@ where
         enqueued.insert(storage, evidence_block_height, slash)?;
     }
 
+    // Record the slash to be able to report on it once processed
+    validator_slash_records_handle(validator).insert(
+        storage,
+        evidence_block_height,
+        SlashRecord {
+            r#type: slash_type,
+            epoch: evidence_epoch,
+            block_height: evidence_block_height,
+            evidence_hash: None,
+            cubic_window: None,
+        },
+    )?;
+
     // Update the most recent slash (infraction) epoch for the validator
     let last_slash_epoch = read_validator_last_slash_epoch(storage, validator)?;
     if last_slash_epoch.is_none()
@@ -235,8 +272,9 @@ where
     );
 
     // Compute the cubic slash rate
-    let cubic_slash_rate =
-        compute_cubic_slash_rate(storage, &params, infraction_epoch)?;
+    let cubic_window =
+        compute_cubic_slash_window(storage, &params, infraction_epoch)?;
+    let cubic_slash_rate = cubic_window.cubic_rate;
 
     // Collect the enqueued slashes and update their rates
     let mut eager_validator_slashes: BTreeMap<Address, Vec<Slash>> =
@@ -300,7 +338,15 @@ where
     // Write slashes themselves into storage
     for (validator, slashes) in eager_validator_slashes {
         let validator_slashes = validator_slashes_handle(&validator);
+        let slash_records = validator_slash_records_handle(&validator);
         for slash in slashes {
+            // Keep the inputs of the cubic slash rate in the slash record
+            if let Some(mut record) =
+                slash_records.get(storage, &slash.block_height)?
+            {
+                record.cubic_window = Some(cubic_window.clone());
+                slash_records.insert(storage, slash.block_height, record)?;
+            }
             validator_slashes.push(storage, slash)?;
         }
     }
@@ -864,20 +910,186 @@ pub fn compute_slashable_amount(
     amount: token::Amount,
     computed_slashes: &BTreeMap<Epoch, token::Amount>,
 ) -> std::result::Result<token::Amount, arith::Error> {
-    let updated_amount = computed_slashes
-        .iter()
-        .filter(|(&epoch, _)| {
-            // Keep slashes that have been applied and processed before the
-            // current slash occurred. We use `<=` because slashes processed at
-            // `slash.epoch` (at the start of the epoch) are also processed
-            // before this slash occurred.
-            epoch.unchecked_add(params.slash_processing_epoch_offset())
-                <= slash.epoch
-        })
-        .fold(amount, |acc, (_, &amnt)| {
+    compute_slashable_amount_details(params, slash, amount, computed_slashes)
+        .map(|computation| computation.slashed_amount)
+}
+
+/// Same as [`compute_slashable_amount`], but returns all the steps of the
+/// computation.
+pub fn compute_slashable_amount_details(
+    params: &OwnedPosParams,
+    slash: &Slash,
+    amount: token::Amount,
+    computed_slashes: &BTreeMap<Epoch, token::Amount>,
+) -> std::result::Result<SlashableAmountComputation, arith::Error> {
+    let prior_slashed_amounts: BTreeMap<Epoch, token::Amount> =
+        computed_slashes
+            .iter()
+            .filter(|(&epoch, _)| {
+                // Keep slashes that have been applied and processed before the
+                // current slash occurred. We use `<=` because slashes
+                // processed at `slash.epoch` (at the start of the epoch) are
+                // also processed before this slash occurred.
+                epoch.unchecked_add(params.slash_processing_epoch_offset())
+                    <= slash.epoch
+            })
+            .map(|(&epoch, &amount)| (epoch, amount))
+            .collect();
+    let slashable_amount =
+        prior_slashed_amounts.values().fold(amount, |acc, &amnt| {
             acc.checked_sub(amnt).unwrap_or_default()
         });
-    updated_amount.mul_ceil(slash.rate)
+    Ok(SlashableAmountComputation {
+        amount,
+        prior_slashed_amounts,
+        slashable_amount,
+        rate: slash.rate,
+        slashed_amount: slashable_amount.mul_ceil(slash.rate)?,
+    })
+}
+
+/// Explain the computation of the amount slashed by `slash` from some tokens
+/// that are subject to the given list of `slashes` (ordered by misbehaving
+/// epoch), as performed by [`apply_list_slashes`].
+fn explain_slashed_amount(
+    params: &OwnedPosParams,
+    slashes: &[Slash],
+    slash: &Slash,
+    amount: token::Amount,
+) -> std::result::Result<SlashableAmountComputation, arith::Error> {
+    let mut computed_slashes = BTreeMap::<Epoch, token::Amount>::new();
+    for prior_slash in slashes.iter().take_while(|&prior| prior != slash) {
+        let slashed_amount = compute_slashable_amount(
+            params,
+            prior_slash,
+            amount,
+            &computed_slashes,
+        )?;
+        computed_slashes.insert(prior_slash.epoch, slashed_amount);
+    }
+    compute_slashable_amount_details(params, slash, amount, &computed_slashes)
+}
+
+/// Report on how the processed slash of a validator for the infraction at the
+/// given block height was computed and applied to the bonds and outgoing
+/// redelegations of the validator. Returns `None` if there is no such
+/// processed slash.
+///
+/// The report is computed from the current bonds and redelegations, which may
+/// have been partially unbonded or withdrawn since the slash was processed.
+pub fn slash_report<S, Gov>(
+    storage: &S,
+    validator: &Address,
+    block_height: u64,
+) -> Result<Option<SlashReport>>
+where
+    S: StorageRead,
+    Gov: governance::Read<S>,
+{
+    let params = read_pos_params::<S, Gov>(storage)?;
+    let slashes = find_validator_slashes(storage, validator)?;
+    let Some(slash) = slashes
+        .iter()
+        .find(|slash| slash.block_height == block_height)
+        .cloned()
+    else {
+        return Ok(None);
+    };
+    let record = validator_slash_records_handle(validator)
+        .get(storage, &block_height)?;
+
+    // The bonds that were contributing to the validator's stake when the
+    // infraction occurred
+    let mut bonds = vec![];
+    for res in iter_prefix_bytes(storage, &storage_key::bonds_prefix())? {
+        let (key, val_bytes) = res?;
+        let Some((bond_id, start)) = storage_key::is_bond_key(&key) else {
+            continue;
+        };
+        if &bond_id.validator != validator || start > slash.epoch {
+            continue;
+        }
+        let amount =
+            token::Amount::try_from_slice(&val_bytes).into_storage_result()?;
+        if amount.is_zero() {
+            continue;
+        }
+        let bond_slashes = slashes
+            .iter()
+            .filter(|slash| start <= slash.epoch)
+            .cloned()
+            .collect::<Vec<_>>();
+        bonds.push(BondSlashReport {
+            source: bond_id.source,
+            start,
+            computation: explain_slashed_amount(
+                &params,
+                &bond_slashes,
+                &slash,
+                amount,
+            )?,
+        });
+    }
+
+    // The redelegations out of the validator that were still slashable for
+    // the infraction
+    let mut redelegations = vec![];
+    for res in
+        validator_outgoing_redelegations_handle(validator).iter(storage)?
+    {
+        let (
+            NestedSubKey::Data {
+                key: dest_validator,
+                nested_sub_key:
+                    NestedSubKey::Data {
+                        key: bond_start,
+                        nested_sub_key: SubKey::Data(redelegation_start),
+                    },
+            },
+            amount,
+        ) = res?;
+        let redel_bond_start =
+            params.redelegation_end_epoch_from_start(redelegation_start);
+        if !params.in_redelegation_slashing_window(
+            slash.epoch,
+            redelegation_start,
+            redel_bond_start,
+        ) || bond_start > slash.epoch
+        {
+            continue;
+        }
+        let redelegation_slashes = slashes
+            .iter()
+            .filter(|slash| {
+                params.in_redelegation_slashing_window(
+                    slash.epoch,
+                    params.redelegation_start_epoch_from_end(redel_bond_start),
+                    redel_bond_start,
+                ) && bond_start <= slash.epoch
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        redelegations.push(RedelegationSlashReport {
+            dest_validator,
+            bond_start,
+            redelegation_start,
+            computation: explain_slashed_amount(
+                &params,
+                &redelegation_slashes,
+                &slash,
+                amount,
+            )?,
+        });
+    }
+
+    Ok(Some(SlashReport {
+        validator: validator.clone(),
+        type_rate: slash.r#type.get_slash_rate(&params),
+        slash,
+        record,
+        bonds,
+        redelegations,
+    }))
 }
 
 /// Find all slashes and the associated validators in the PoS system
@@ -1227,13 +1439,13 @@ where
 }
 
 /// Calculate the cubic slashing rate using all slashes within a window around
-/// the given infraction epoch. There is no cap on the rate applied within this
-/// function.
-fn compute_cubic_slash_rate<S>(
+/// the given infraction epoch, along with the inputs of the computation. There
+/// is no cap on the rate applied within this function.
+fn compute_cubic_slash_window<S>(
     storage: &S,
     params: &PosParams,
     infraction_epoch: Epoch,
-) -> Result<Dec>
+) -> Result<CubicSlashWindow>
 where
     S: StorageRead,
 {
@@ -1242,13 +1454,15 @@ where
          {infraction_epoch}."
     );
     let mut sum_vp_fraction = Dec::zero();
+    let mut epochs = vec![];
     let (start_epoch, end_epoch) =
         params.cubic_slash_epoch_window(infraction_epoch);
 
     for epoch in Epoch::iter_bounds_inclusive(start_epoch, end_epoch) {
+        let total_consensus_stake =
+            get_total_consensus_stake(storage, epoch, params)?;
         let consensus_stake =
-            Dec::try_from(get_total_consensus_stake(storage, epoch, params)?)
-                .into_storage_result()?;
+            Dec::try_from(total_consensus_stake).into_storage_result()?;
         tracing::debug!(
             "Total consensus stake in epoch {}: {}",
             epoch,
@@ -1257,8 +1471,9 @@ where
         let processing_epoch =
             checked!(epoch + params.slash_processing_epoch_offset())?;
         let slashes = enqueued_slashes_handle().at(&processing_epoch);
-        let infracting_stake =
-            slashes.iter(storage)?.try_fold(Dec::zero(), |acc, res| {
+        let infracting_stake = slashes.iter(storage)?.try_fold(
+            token::Amount::zero(),
+            |acc, res| {
                 let (
                     NestedSubKey::Data {
                         key: validator,
@@ -1272,15 +1487,24 @@ where
                 // tracing::debug!("Val {} stake: {}", &validator,
                 // validator_stake);
 
-                let stake =
-                    Dec::try_from(validator_stake).into_storage_result()?;
-                Ok::<Dec, Error>(checked!(acc + stake)?)
-            })?;
+                Ok::<token::Amount, Error>(checked!(acc + validator_stake)?)
+            },
+        )?;
+        let stake = Dec::try_from(infracting_stake).into_storage_result()?;
         sum_vp_fraction =
-            checked!(sum_vp_fraction + (infracting_stake / consensus_stake))?;
+            checked!(sum_vp_fraction + (stake / consensus_stake))?;
+        epochs.push(CubicSlashWindowEpoch {
+            epoch,
+            infracting_stake,
+            consensus_stake: total_consensus_stake,
+        });
     }
     let nine = Dec::from(9_u64);
     let cubic_rate = checked!(nine * sum_vp_fraction * sum_vp_fraction)?;
     tracing::debug!("Cubic slash rate: {}", cubic_rate);
-    Ok(cubic_rate)
+    Ok(CubicSlashWindow {
+        epochs,
+        sum_vp_fraction,
+        cubic_rate,
+    })
 }
@@ -20,17 +20,17 @@ use crate::types::{
     ConsensusValidatorSets, DelegationTargets, DelegatorRedelegatedBonded,
     DelegatorRedelegatedUnbonded, EpochedSlashes, IncomingRedelegations,
     LivenessMissedVotes, LivenessSumMissedVotes, OutgoingRedelegations,
-    ReverseOrdTokenAmount, RewardsAccumulator, RewardsProducts, Slashes,
-    TotalConsensusStakes, TotalDeltas, TotalRedelegatedBonded,
-    TotalRedelegatedUnbonded, Unbonds, ValidatorAddresses,
-    ValidatorConsensusKeys, ValidatorDeltas, ValidatorEthColdKeys,
-    ValidatorEthHotKeys, ValidatorMetaData, ValidatorProtocolKeys,
-    ValidatorSetPositions, ValidatorState, ValidatorStates,
-    ValidatorTotalUnbonded, WeightedValidator,
+    ReverseOrdTokenAmount, RewardDestination, RewardsAccumulator,
+    RewardsProducts, SlashRecords, Slashes, TotalConsensusStakes, TotalDeltas,
+    TotalRedelegatedBonded, TotalRedelegatedUnbonded, Unbonds,
+    ValidatorAddresses, ValidatorConsensusKeys, ValidatorDeltas,
+    ValidatorEthColdKeys, ValidatorEthHotKeys, ValidatorMetaData,
+    ValidatorProtocolKeys, ValidatorSetPositions, ValidatorState,
+    ValidatorStates, ValidatorTotalUnbonded, WeightedValidator,
 };
 use crate::{
-    storage_key, LazyCollection, LazySet, MetadataError, OwnedPosParams,
-    PosParams, Result, StorageRead, StorageWrite,
+    iter_prefix_bytes, storage_key, LazyCollection, LazySet, MetadataError,
+    OwnedPosParams, PosParams, Result, StorageRead, StorageWrite,
 };
 
 // ---- Storage handles ----
@@ -164,6 +164,12 @@ pub fn validator_slashes_handle(validator: &Address) -> Slashes {
     Slashes::open(key)
 }
 
+/// Get the storage handle to the records of a PoS validator's slashes
+pub fn validator_slash_records_handle(validator: &Address) -> SlashRecords {
+    let key = storage_key::validator_slash_records_key(validator);
+    SlashRecords::open(key)
+}
+
 /// Get the storage handle to list of all slashes to be processed and ultimately
 /// placed in the `validator_slashes_handle`
 pub fn enqueued_slashes_handle() -> EpochedSlashes {
@@ -260,14 +266,25 @@ pub fn delegation_targets_handle(delegator: &Address) -> DelegationTargets {
 
 // ---- Storage read + write ----
 
-/// Read owned PoS parameters
+/// Read owned PoS parameters. The validator self-bond and commission policies
+/// are stored under their own keys, which are missing from chains that were
+/// initialized before they were added, so they fall back to their default
+/// values that don't constrain validators.
 pub fn read_owned_pos_params<S>(storage: &S) -> Result<OwnedPosParams>
 where
     S: StorageRead,
 {
-    Ok(storage
+    let mut params: OwnedPosParams = storage
         .read(&storage_key::params_key())?
-        .expect("PosParams should always exist in storage after genesis"))
+        .expect("PosParams should always exist in storage after genesis");
+    let defaults = OwnedPosParams::default();
+    params.min_self_bond_ratio = storage
+        .read(&storage_key::min_self_bond_ratio_key())?
+        .unwrap_or(defaults.min_self_bond_ratio);
+    params.max_commission_rate = storage
+        .read(&storage_key::max_commission_rate_key())?
+        .unwrap_or(defaults.max_commission_rate);
+    Ok(params)
 }
 
 /// Read PoS parameters
@@ -306,7 +323,15 @@ where
     S: StorageRead + StorageWrite,
 {
     let key = storage_key::params_key();
-    storage.write(&key, params)
+    storage.write(&key, params)?;
+    storage.write(
+        &storage_key::min_self_bond_ratio_key(),
+        params.min_self_bond_ratio,
+    )?;
+    storage.write(
+        &storage_key::max_commission_rate_key(),
+        params.max_commission_rate,
+    )
 }
 
 /// Get the validator address given the raw hash of the Tendermint consensus key
@@ -933,6 +958,35 @@ where
         storage.write(&key, validator_name)
     }
 }
+
+/// Read PoS validator's reward destination.
+pub fn read_validator_reward_destination<S>(
+    storage: &S,
+    validator: &Address,
+) -> Result<Option<RewardDestination>>
+where
+    S: StorageRead,
+{
+    storage.read(&storage_key::validator_reward_destination_key(validator))
+}
+
+/// Write PoS validator's reward destination. If the provided destination is
+/// empty, remove the data so that the commission goes back to the validator.
+pub fn write_validator_reward_destination<S>(
+    storage: &mut S,
+    validator: &Address,
+    reward_destination: &RewardDestination,
+) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let key = storage_key::validator_reward_destination_key(validator);
+    if reward_destination.0.is_empty() {
+        storage.delete(&key)
+    } else {
+        storage.write(&key, reward_destination)
+    }
+}
 /// Write validator's metadata.
 pub fn write_validator_metadata<S>(
     storage: &mut S,
@@ -960,6 +1014,13 @@ where
     if let Some(name) = metadata.name.as_ref() {
         write_validator_name(storage, validator, name)?;
     }
+    if let Some(reward_destination) = metadata.reward_destination.as_ref() {
+        write_validator_reward_destination(
+            storage,
+            validator,
+            reward_destination,
+        )?;
+    }
     Ok(())
 }
 
@@ -977,6 +1038,8 @@ where
     let discord_handle = read_validator_discord_handle(storage, validator)?;
     let avatar = read_validator_avatar(storage, validator)?;
     let name = read_validator_name(storage, validator)?;
+    let reward_destination =
+        read_validator_reward_destination(storage, validator)?;
 
     // Email is the only required field for a validator in storage
     match email {
@@ -987,6 +1050,7 @@ where
             discord_handle,
             avatar,
             name,
+            reward_destination,
         })),
         None => Ok(None),
     }
@@ -1022,6 +1086,62 @@ where
     storage.write(&key, epoch)
 }
 
+/// Check if the rewards of the bond from `source` to `validator` are
+/// automatically compounded
+pub fn is_auto_compound_enabled<S>(
+    storage: &S,
+    source: &Address,
+    validator: &Address,
+) -> Result<bool>
+where
+    S: StorageRead,
+{
+    let key = storage_key::auto_compound_key(source, validator);
+    storage.has_key(&key)
+}
+
+/// Enable or disable the automatic compounding of the rewards of the bond
+/// from `source` to `validator`
+pub fn write_auto_compound<S>(
+    storage: &mut S,
+    source: &Address,
+    validator: &Address,
+    enabled: bool,
+) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let key = storage_key::auto_compound_key(source, validator);
+    if enabled {
+        storage.write(&key, ())
+    } else {
+        storage.delete(&key)
+    }
+}
+
+/// Read the bonds whose rewards are automatically compounded, optionally only
+/// the ones of the given `source`
+pub fn read_auto_compound_bonds<S>(
+    storage: &S,
+    source: Option<&Address>,
+) -> Result<BTreeSet<BondId>>
+where
+    S: StorageRead,
+{
+    let prefix = match source {
+        Some(source) => storage_key::auto_compound_for_source_prefix(source),
+        None => storage_key::auto_compound_prefix(),
+    };
+    let mut bonds = BTreeSet::new();
+    for res in iter_prefix_bytes(storage, &prefix)? {
+        let (key, _) = res?;
+        if let Some(bond_id) = storage_key::is_auto_compound_key(&key) {
+            bonds.insert(bond_id);
+        }
+    }
+    Ok(bonds)
+}
+
 /// Check if the given consensus key is already being used to ensure uniqueness.
 ///
 /// If it's not being used, it will be inserted into the set that's being used
@@ -8,6 +8,8 @@ use crate::types::BondId;
 use crate::{epoched, lazy_map, lazy_vec, Epoch, Key, KeySeg};
 
 const PARAMS_STORAGE_KEY: &str = "params";
+const MIN_SELF_BOND_RATIO_PARAM_KEY: &str = "min_self_bond_ratio";
+const MAX_COMMISSION_RATE_PARAM_KEY: &str = "max_commission_rate";
 const VALIDATOR_ADDRESSES_KEY: &str = "validator_addresses";
 #[allow(missing_docs)]
 pub const VALIDATOR_STORAGE_PREFIX: &str = "validator";
@@ -26,6 +28,7 @@ const VALIDATOR_LAST_KNOWN_PRODUCT_EPOCH_KEY: &str =
 const SLASHES_PREFIX: &str = "slash";
 const ENQUEUED_SLASHES_KEY: &str = "enqueued_slashes";
 const VALIDATOR_LAST_SLASH_EPOCH: &str = "last_slash_epoch";
+const VALIDATOR_SLASH_RECORDS_KEY: &str = "slash_records";
 const BOND_STORAGE_KEY: &str = "bond";
 const UNBOND_STORAGE_KEY: &str = "unbond";
 const VALIDATOR_TOTAL_BONDED_STORAGE_KEY: &str = "total_bonded";
@@ -42,6 +45,8 @@ const CONSENSUS_VALIDATOR_SET_ACCUMULATOR_STORAGE_KEY: &str =
     "validator_rewards_accumulator";
 const LAST_REWARD_CLAIM_EPOCH: &str = "last_reward_claim_epoch";
 const REWARDS_COUNTER_KEY: &str = "validator_rewards_commissions";
+const AUTO_COMPOUND_KEY: &str = "auto_compound";
+const COMPOUND_CURSOR_KEY: &str = "compound_cursor";
 const VALIDATOR_INCOMING_REDELEGATIONS_KEY: &str = "incoming_redelegations";
 const VALIDATOR_OUTGOING_REDELEGATIONS_KEY: &str = "outgoing_redelegations";
 const VALIDATOR_TOTAL_REDELEGATED_BONDED_KEY: &str = "total_redelegated_bonded";
@@ -55,6 +60,7 @@ const VALIDATOR_WEBSITE_KEY: &str = "website";
 const VALIDATOR_DISCORD_KEY: &str = "discord_handle";
 const VALIDATOR_AVATAR_KEY: &str = "avatar";
 const VALIDATOR_NAME_KEY: &str = "name";
+const VALIDATOR_REWARD_DESTINATION_KEY: &str = "reward_destination";
 const LIVENESS_PREFIX: &str = "liveness";
 const LIVENESS_MISSED_VOTES: &str = "missed_votes";
 const LIVENESS_MISSED_VOTES_SUM: &str = "sum_missed_votes";
@@ -78,9 +84,25 @@ pub fn params_key() -> Key {
         .expect("Cannot obtain a storage key")
 }
 
+/// Storage key for the minimum self-bond ratio PoS parameter, which is stored
+/// apart from the other parameters.
+pub fn min_self_bond_ratio_key() -> Key {
+    Key::from(ADDRESS.to_db_key())
+        .push(&MIN_SELF_BOND_RATIO_PARAM_KEY.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Storage key for the maximum commission rate PoS parameter, which is stored
+/// apart from the other parameters.
+pub fn max_commission_rate_key() -> Key {
+    Key::from(ADDRESS.to_db_key())
+        .push(&MAX_COMMISSION_RATE_PARAM_KEY.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
 /// Is storage key for PoS parameters?
 pub fn is_params_key(key: &Key) -> bool {
-    matches!(&key.segments[..], [DbKeySeg::AddressSeg(addr), DbKeySeg::StringSeg(key)] if addr == &ADDRESS && key == PARAMS_STORAGE_KEY)
+    matches!(&key.segments[..], [DbKeySeg::AddressSeg(addr), DbKeySeg::StringSeg(key)] if addr == &ADDRESS && [PARAMS_STORAGE_KEY, MIN_SELF_BOND_RATIO_PARAM_KEY, MAX_COMMISSION_RATE_PARAM_KEY].contains(&key.as_str()))
 }
 
 /// Storage key prefix for validator data.
@@ -278,6 +300,7 @@ pub fn is_validator_metadata_key(key: &Key) -> Option<&Address> {
                     | VALIDATOR_DISCORD_KEY
                     | VALIDATOR_AVATAR_KEY
                     | VALIDATOR_NAME_KEY
+                    | VALIDATOR_REWARD_DESTINATION_KEY
             ) =>
         {
             Some(validator)
@@ -343,6 +366,52 @@ pub fn is_rewards_counter_key(key: &Key) -> Option<BondId> {
     }
 }
 
+/// Storage prefix for the bonds whose rewards are automatically compounded.
+pub fn auto_compound_prefix() -> Key {
+    Key::from(ADDRESS.to_db_key())
+        .push(&AUTO_COMPOUND_KEY.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Storage prefix for the bonds of a source whose rewards are automatically
+/// compounded.
+pub fn auto_compound_for_source_prefix(source: &Address) -> Key {
+    auto_compound_prefix()
+        .push(&source.to_db_key())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Storage key for the automatic compounding of a bond's rewards.
+pub fn auto_compound_key(source: &Address, validator: &Address) -> Key {
+    auto_compound_for_source_prefix(source)
+        .push(&validator.to_db_key())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Storage key for the last bond whose rewards were automatically compounded
+/// in an epoch transition that didn't compound all the bonds.
+pub fn auto_compound_cursor_key() -> Key {
+    Key::from(ADDRESS.to_db_key())
+        .push(&COMPOUND_CURSOR_KEY.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Is the storage key for the automatic compounding of a bond's rewards?
+pub fn is_auto_compound_key(key: &Key) -> Option<BondId> {
+    match &key.segments[..] {
+        [
+            DbKeySeg::AddressSeg(addr),
+            DbKeySeg::StringSeg(key),
+            DbKeySeg::AddressSeg(source),
+            DbKeySeg::AddressSeg(validator),
+        ] if addr == &ADDRESS && key == AUTO_COMPOUND_KEY => Some(BondId {
+            source: source.clone(),
+            validator: validator.clone(),
+        }),
+        _ => None,
+    }
+}
+
 /// Storage key for a validator's incoming redelegations, where the prefixed
 /// validator is the destination validator.
 pub fn validator_incoming_redelegations_key(validator: &Address) -> Key {
@@ -631,6 +700,13 @@ pub fn is_validator_slashes_key(key: &Key) -> Option<Address> {
     }
 }
 
+/// Storage key for the records of a validator's slashes.
+pub fn validator_slash_records_key(validator: &Address) -> Key {
+    validator_prefix(validator)
+        .push(&VALIDATOR_SLASH_RECORDS_KEY.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
 /// Storage key for the last (most recent) epoch in which a slashable offense
 /// was detected for a given validator
 pub fn validator_last_slash_key(validator: &Address) -> Key {
@@ -1036,6 +1112,13 @@ pub fn validator_name_key(validator: &Address) -> Key {
         .expect("Cannot obtain a storage key")
 }
 
+/// Storage key for a validator's reward destination.
+pub fn validator_reward_destination_key(validator: &Address) -> Key {
+    validator_prefix(validator)
+        .push(&VALIDATOR_REWARD_DESTINATION_KEY.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
 /// Storage prefix for the liveness data of the cosnensus validator set.
 pub fn liveness_data_prefix() -> Key {
     Key::from(ADDRESS.to_db_key())
@@ -1,12 +1,16 @@
 use namada_core::address::Address;
 use namada_core::collections::{HashMap, HashSet};
+use namada_core::dec::Dec;
 use namada_core::key::common;
 use namada_events::EmitEvents;
 use namada_state::storage::Result;
 use namada_state::{Epoch, StorageRead, StorageWrite};
 use namada_trans_token as token;
 
-use crate::types::{BondId, BondsAndUnbondsDetails, ResultSlashing, SlashType};
+use crate::types::{
+    BondId, BondsAndUnbondsDetails, ResultSlashing, ShareExchangeRate,
+    SlashReport, SlashType,
+};
 use crate::{BecomeValidator, GenesisValidator, OwnedPosParams, PosParams};
 
 mod helpers;
@@ -169,6 +173,24 @@ where
     crate::unjail_validator::<S, GovStore<S>>(storage, validator, current_epoch)
 }
 
+/// DI indirection
+pub fn change_validator_commission_rate<S>(
+    storage: &mut S,
+    validator: &Address,
+    new_rate: Dec,
+    current_epoch: Epoch,
+) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    crate::change_validator_commission_rate::<S, GovStore<S>>(
+        storage,
+        validator,
+        new_rate,
+        current_epoch,
+    )
+}
+
 /// DI indirection
 pub fn become_validator<S>(
     storage: &mut S,
@@ -326,6 +348,22 @@ where
     )
 }
 
+/// DI indirection
+pub fn slash_report<S>(
+    storage: &S,
+    validator: &Address,
+    block_height: u64,
+) -> Result<Option<SlashReport>>
+where
+    S: StorageRead,
+{
+    crate::slashing::slash_report::<S, GovStore<S>>(
+        storage,
+        validator,
+        block_height,
+    )
+}
+
 /// DI indirection
 pub fn find_delegations<S>(
     storage: &S,
@@ -337,3 +375,57 @@ where
 {
     crate::queries::find_delegations::<S, GovStore<S>>(storage, owner, epoch)
 }
+
+/// DI indirection
+pub fn liquid_bond_tokens<S>(
+    storage: &mut S,
+    source: &Address,
+    validator: &Address,
+    amount: token::Amount,
+    current_epoch: Epoch,
+) -> Result<token::Amount>
+where
+    S: StorageRead + StorageWrite,
+{
+    crate::liquid_staking::liquid_bond_tokens::<S, GovStore<S>, token::Store<_>>(
+        storage,
+        source,
+        validator,
+        amount,
+        current_epoch,
+    )
+}
+
+/// DI indirection
+pub fn liquid_unbond_tokens<S>(
+    storage: &mut S,
+    source: &Address,
+    validator: &Address,
+    shares: token::Amount,
+    current_epoch: Epoch,
+) -> Result<token::Amount>
+where
+    S: StorageRead + StorageWrite,
+{
+    crate::liquid_staking::liquid_unbond_tokens::<S, GovStore<S>, token::Store<_>>(
+        storage,
+        source,
+        validator,
+        shares,
+        current_epoch,
+    )
+}
+
+/// DI indirection
+pub fn share_exchange_rate<S>(
+    storage: &S,
+    validator: &Address,
+    epoch: Epoch,
+) -> Result<ShareExchangeRate>
+where
+    S: StorageRead,
+{
+    crate::liquid_staking::share_exchange_rate::<S, GovStore<S>, token::Store<_>>(
+        storage, validator, epoch,
+    )
+}
@@ -11,6 +11,7 @@ use namada_core::collections::HashSet;
 use namada_core::dec::Dec;
 use namada_core::key::testing::{common_sk_from_simple_seed, gen_keypair};
 use namada_core::key::RefTo;
+use namada_core::uint::Uint;
 use namada_core::{address, key};
 use namada_state::testing::TestState;
 use namada_trans_token::{
@@ -25,19 +26,22 @@ use test_log::test;
 
 use crate::epoched::EpochOffset;
 use crate::lazy_map::Collectable;
+use crate::liquid_staking::{share_token_address, share_token_validator};
 use crate::parameters::testing::arb_pos_params;
-use crate::parameters::OwnedPosParams;
+use crate::parameters::{OwnedPosParams, MAX_REWARD_DESTINATIONS};
 use crate::queries::find_delegation_validators;
 use crate::rewards::{
-    log_block_rewards_aux, update_rewards_products_and_mint_inflation,
-    PosRewardsCalculator,
+    log_block_rewards_aux, read_rewards_counter,
+    update_rewards_products_and_mint_inflation, PosRewardsCalculator,
 };
 use crate::storage::{
     delegation_targets_handle, get_consensus_key_set,
-    liveness_sum_missed_votes_handle,
+    get_last_reward_claim_epoch, is_auto_compound_enabled,
+    liveness_sum_missed_votes_handle, read_auto_compound_bonds,
     read_consensus_validator_set_addresses_with_stake, read_total_stake,
-    read_validator_deltas_value, rewards_accumulator_handle,
-    total_deltas_handle,
+    read_validator_deltas_value, read_validator_metadata,
+    rewards_accumulator_handle, total_deltas_handle,
+    validator_rewards_products_handle, write_validator_reward_destination,
 };
 use crate::tests::helpers::{
     advance_epoch, arb_genesis_validators, arb_params_and_genesis_validators,
@@ -45,22 +49,23 @@ use crate::tests::helpers::{
 };
 use crate::tests::{
     bond_amount, bond_tokens, bonds_and_unbonds, change_consensus_key,
-    find_delegations, process_slashes,
-    read_below_threshold_validator_set_addresses, redelegate_tokens, slash,
-    test_init_genesis, unbond_tokens, unjail_validator, withdraw_tokens,
-    GovStore,
+    find_delegations, liquid_bond_tokens, liquid_unbond_tokens,
+    process_slashes, read_below_threshold_validator_set_addresses,
+    redelegate_tokens, share_exchange_rate, slash, test_init_genesis,
+    unbond_tokens, unjail_validator, withdraw_tokens, GovStore,
 };
 use crate::types::{
     into_tm_voting_power, BondDetails, BondId, BondsAndUnbondsDetails,
-    GenesisValidator, SlashType, UnbondDetails, ValidatorState, VoteInfo,
-    WeightedValidator,
+    GenesisValidator, RewardDestination, SlashType, UnbondDetails,
+    ValidatorState, VoteInfo, WeightedValidator,
 };
 use crate::{
     below_capacity_validator_set_handle, bond_handle,
     consensus_validator_set_handle, is_delegator, is_validator,
-    jail_for_liveness, read_validator_stake, staking_token_address,
-    unbond_handle, validator_consensus_key_handle,
-    validator_set_positions_handle, validator_state_handle, StorageRead,
+    jail_for_liveness, read_validator_stake, set_auto_compound,
+    staking_token_address, storage_key, unbond_handle,
+    validator_consensus_key_handle, validator_set_positions_handle,
+    validator_state_handle, StorageRead, StorageWrite,
 };
 
 proptest! {
@@ -2210,3 +2215,525 @@ fn test_delegation_targets() {
     assert!(de_2.prev_ranges.is_empty());
     assert_eq!(de_2.last_range.1, None);
 }
+
+/// Test that the rewards of the bonds with auto-compounding enabled are
+/// re-bonded to the same validator at the pipeline offset.
+#[test]
+fn test_auto_compound() {
+    let validators =
+        get_genesis_validators(2, vec![token::Amount::native_whole(10); 2]);
+    let validator = validators[0].address.clone();
+    let other_validator = validators[1].address.clone();
+
+    let mut storage = TestState::default();
+    let current_epoch = storage.in_mem().block.epoch;
+    let params = test_init_genesis(
+        &mut storage,
+        OwnedPosParams::default(),
+        validators.into_iter(),
+        current_epoch,
+    )
+    .unwrap();
+    storage.commit_block().unwrap();
+
+    let staking_token = staking_token_address(&storage);
+    let delegator = address::testing::gen_implicit_address();
+    let bond_id = BondId {
+        source: delegator.clone(),
+        validator: validator.clone(),
+    };
+    let bonded = token::Amount::native_whole(1000);
+
+    // Auto-compounding can only be set for an existing bond
+    let res =
+        set_auto_compound(&mut storage, Some(&delegator), &validator, true);
+    assert!(res.is_err());
+
+    credit_tokens(&mut storage, &staking_token, &delegator, bonded).unwrap();
+    bond_tokens(
+        &mut storage,
+        Some(&delegator),
+        &validator,
+        bonded,
+        current_epoch,
+        None,
+    )
+    .unwrap();
+
+    // Auto-compounding can only be set for a bond to a validator from a
+    // non-validator source
+    let res = set_auto_compound(&mut storage, None, &delegator, true);
+    assert!(res.is_err());
+    let res = set_auto_compound(
+        &mut storage,
+        Some(&other_validator),
+        &validator,
+        true,
+    );
+    assert!(res.is_err());
+    set_auto_compound(&mut storage, Some(&delegator), &validator, true)
+        .unwrap();
+    assert!(
+        is_auto_compound_enabled(&storage, &delegator, &validator).unwrap()
+    );
+    let bonds = read_auto_compound_bonds(&storage, None).unwrap();
+    assert_eq!(bonds, [bond_id.clone()].into());
+
+    // Reward the bond in the epoch in which it becomes active
+    let rewarded_epoch = current_epoch + params.pipeline_len;
+    validator_rewards_products_handle(&validator)
+        .insert(&mut storage, rewarded_epoch, Dec::new(1, 1).unwrap())
+        .unwrap();
+
+    // Nothing is compounded until the rewards of the epoch have been applied
+    let mut current_epoch = current_epoch;
+    for _ in 0..=params.pipeline_len {
+        current_epoch = advance_epoch(&mut storage, &params);
+        crate::compound_rewards::<_, GovStore<_>>(&mut storage, current_epoch)
+            .unwrap();
+    }
+    let pipeline_epoch = current_epoch + params.pipeline_len;
+    assert_eq!(
+        bond_amount(&storage, &bond_id, pipeline_epoch).unwrap(),
+        bonded
+    );
+
+    // The rewards are re-bonded at the pipeline offset once the epoch after
+    // the rewarded one has ended
+    current_epoch = advance_epoch(&mut storage, &params);
+    crate::compound_rewards::<_, GovStore<_>>(&mut storage, current_epoch)
+        .unwrap();
+    let pipeline_epoch = current_epoch + params.pipeline_len;
+    let rewards = token::Amount::native_whole(100);
+    assert_eq!(
+        bond_amount(&storage, &bond_id, pipeline_epoch).unwrap(),
+        bonded + rewards
+    );
+    assert_eq!(
+        bond_amount(&storage, &bond_id, pipeline_epoch.prev().unwrap())
+            .unwrap(),
+        bonded
+    );
+    assert_eq!(
+        get_last_reward_claim_epoch(&storage, &delegator, &validator).unwrap(),
+        current_epoch.prev()
+    );
+
+    // The rewards cannot be compounded twice
+    crate::compound_rewards::<_, GovStore<_>>(&mut storage, current_epoch)
+        .unwrap();
+    assert_eq!(
+        bond_amount(&storage, &bond_id, pipeline_epoch).unwrap(),
+        bonded + rewards
+    );
+
+    set_auto_compound(&mut storage, Some(&delegator), &validator, false)
+        .unwrap();
+    assert!(
+        !is_auto_compound_enabled(&storage, &delegator, &validator).unwrap()
+    );
+    assert!(read_auto_compound_bonds(&storage, None).unwrap().is_empty());
+
+    // Fully unbonding the bond disables its auto-compounding
+    set_auto_compound(&mut storage, Some(&delegator), &validator, true)
+        .unwrap();
+    unbond_tokens(
+        &mut storage,
+        Some(&delegator),
+        &validator,
+        bonded + rewards,
+        current_epoch,
+        false,
+    )
+    .unwrap();
+    assert!(
+        !is_auto_compound_enabled(&storage, &delegator, &validator).unwrap()
+    );
+}
+
+/// Test that the rewards of a bond that fails to be compounded are left
+/// untouched to be claimed.
+#[test]
+fn test_auto_compound_failure_keeps_rewards() {
+    let validators =
+        get_genesis_validators(1, vec![token::Amount::native_whole(10)]);
+    let validator = validators[0].address.clone();
+
+    let mut storage = TestState::default();
+    let current_epoch = storage.in_mem().block.epoch;
+    let params = test_init_genesis(
+        &mut storage,
+        OwnedPosParams::default(),
+        validators.into_iter(),
+        current_epoch,
+    )
+    .unwrap();
+    storage.commit_block().unwrap();
+
+    let staking_token = staking_token_address(&storage);
+    let delegator = address::testing::gen_implicit_address();
+    let bond_id = BondId {
+        source: delegator.clone(),
+        validator: validator.clone(),
+    };
+    let bonded = token::Amount::native_whole(1000);
+    credit_tokens(&mut storage, &staking_token, &delegator, bonded).unwrap();
+    bond_tokens(
+        &mut storage,
+        Some(&delegator),
+        &validator,
+        bonded,
+        current_epoch,
+        None,
+    )
+    .unwrap();
+    set_auto_compound(&mut storage, Some(&delegator), &validator, true)
+        .unwrap();
+
+    // Rewards that are too large to be applied to the stake
+    let rewards = token::Amount::from(Uint::MAX / 2 + Uint::one());
+    storage
+        .write(
+            &storage_key::rewards_counter_key(&delegator, &validator),
+            rewards,
+        )
+        .unwrap();
+
+    let mut current_epoch = current_epoch;
+    for _ in 0..2 {
+        current_epoch = advance_epoch(&mut storage, &params);
+    }
+    crate::compound_rewards::<_, GovStore<_>>(&mut storage, current_epoch)
+        .unwrap();
+
+    // The rewards were neither bonded nor taken
+    let pipeline_epoch = current_epoch + params.pipeline_len;
+    assert_eq!(
+        bond_amount(&storage, &bond_id, pipeline_epoch).unwrap(),
+        bonded
+    );
+    assert_eq!(
+        read_rewards_counter(&storage, &delegator, &validator).unwrap(),
+        rewards
+    );
+    assert_eq!(
+        get_last_reward_claim_epoch(&storage, &delegator, &validator).unwrap(),
+        None
+    );
+}
+
+/// Test that liquid staking shares are issued and redeemed at an exchange
+/// rate that follows the rewards of the pool, and that the redeemed tokens are
+/// unbonded to the owner of the shares.
+#[test]
+fn test_liquid_staking() {
+    let validators =
+        get_genesis_validators(2, vec![token::Amount::native_whole(10); 2]);
+    let validator = validators[0].address.clone();
+    let other_validator = validators[1].address.clone();
+
+    let mut storage = TestState::default();
+    let current_epoch = storage.in_mem().block.epoch;
+    let params = test_init_genesis(
+        &mut storage,
+        OwnedPosParams::default(),
+        validators.into_iter(),
+        current_epoch,
+    )
+    .unwrap();
+    storage.commit_block().unwrap();
+
+    let staking_token = staking_token_address(&storage);
+    let share_token = share_token_address(&validator).unwrap();
+    assert_eq!(share_token_validator(&share_token), Some(validator.clone()));
+    let pool_id = BondId {
+        source: share_token.clone(),
+        validator: validator.clone(),
+    };
+    let alice = address::testing::gen_implicit_address();
+    let bob = address::testing::gen_implicit_address();
+    let bonded = token::Amount::native_whole(1000);
+    credit_tokens(&mut storage, &staking_token, &alice, bonded).unwrap();
+
+    // Validators cannot hold shares
+    let res = liquid_bond_tokens(
+        &mut storage,
+        &other_validator,
+        &validator,
+        bonded,
+        current_epoch,
+    );
+    assert!(res.is_err());
+
+    // The first shares are issued 1:1
+    let shares = liquid_bond_tokens(
+        &mut storage,
+        &alice,
+        &validator,
+        bonded,
+        current_epoch,
+    )
+    .unwrap();
+    assert_eq!(shares, bonded);
+    assert_eq!(
+        read_balance(&storage, &share_token, &alice).unwrap(),
+        shares
+    );
+    assert!(
+        read_balance(&storage, &staking_token, &alice)
+            .unwrap()
+            .is_zero()
+    );
+    assert!(
+        is_auto_compound_enabled(&storage, &share_token, &validator).unwrap()
+    );
+
+    // Reward the pool in the epoch in which its bond becomes active
+    let rewarded_epoch = current_epoch + params.pipeline_len;
+    validator_rewards_products_handle(&validator)
+        .insert(&mut storage, rewarded_epoch, Dec::new(1, 1).unwrap())
+        .unwrap();
+    let mut current_epoch = current_epoch;
+    for _ in 0..=params.pipeline_len {
+        current_epoch = advance_epoch(&mut storage, &params);
+    }
+
+    // The rewards are compounded into the pool before issuing new shares, so
+    // the new shares are worth more tokens
+    let rewards = token::Amount::native_whole(100);
+    credit_tokens(&mut storage, &staking_token, &bob, bonded + rewards)
+        .unwrap();
+    let shares = liquid_bond_tokens(
+        &mut storage,
+        &bob,
+        &validator,
+        bonded + rewards,
+        current_epoch,
+    )
+    .unwrap();
+    assert_eq!(shares, bonded);
+    let pipeline_epoch = current_epoch + params.pipeline_len;
+    let rate =
+        share_exchange_rate(&storage, &validator, pipeline_epoch).unwrap();
+    assert_eq!(rate.pool_value, (bonded + rewards) * 2);
+    assert_eq!(rate.share_supply, bonded * 2);
+
+    // Redeeming more shares than held fails
+    let res = liquid_unbond_tokens(
+        &mut storage,
+        &alice,
+        &validator,
+        bonded + token::Amount::from(1),
+        current_epoch,
+    );
+    assert!(res.is_err());
+
+    // Redeemed shares are burned and their value is unbonded to their owner
+    let unbonded = liquid_unbond_tokens(
+        &mut storage,
+        &alice,
+        &validator,
+        bonded,
+        current_epoch,
+    )
+    .unwrap();
+    assert_eq!(unbonded, bonded + rewards);
+    assert!(
+        read_balance(&storage, &share_token, &alice)
+            .unwrap()
+            .is_zero()
+    );
+    assert_eq!(
+        bond_amount(&storage, &pool_id, pipeline_epoch).unwrap(),
+        bonded + rewards
+    );
+    let rate =
+        share_exchange_rate(&storage, &validator, pipeline_epoch).unwrap();
+    assert_eq!(rate.tokens_for_shares(bonded), Some(bonded + rewards));
+    assert!(
+        unbond_handle(&share_token, &validator)
+            .collect_map(&storage)
+            .unwrap()
+            .is_empty()
+    );
+
+    // The owner withdraws the unbonded tokens like any other unbond
+    for _ in 0..params.withdrawable_epoch_offset() {
+        current_epoch = advance_epoch(&mut storage, &params);
+    }
+    let withdrawn =
+        withdraw_tokens(&mut storage, Some(&alice), &validator, current_epoch)
+            .unwrap();
+    assert_eq!(withdrawn, bonded + rewards);
+    assert_eq!(
+        read_balance(&storage, &staking_token, &alice).unwrap(),
+        bonded + rewards
+    );
+}
+
+#[test]
+fn test_reward_destination_split() {
+    let alice = address::testing::established_address_1();
+    let bob = address::testing::established_address_2();
+    let destination = |ratios: Vec<(Address, Dec)>| {
+        RewardDestination(ratios.into_iter().collect())
+    };
+
+    assert!(!RewardDestination::default().is_valid());
+    assert!(destination(vec![(alice.clone(), Dec::one())]).is_valid());
+    let half = destination(vec![(alice.clone(), Dec::new(5, 1).unwrap())]);
+    assert!(!half.is_valid());
+    let negative = destination(vec![
+        (alice.clone(), Dec::new(15, 1).unwrap()),
+        (bob.clone(), Dec::new(-5, 1).unwrap()),
+    ]);
+    assert!(!negative.is_valid());
+    let too_many = (0..=MAX_REWARD_DESTINATIONS as u64)
+        .map(|seed| {
+            (address::testing::address_from_simple_seed(seed), Dec::one())
+        })
+        .collect();
+    assert!(!destination(too_many).is_valid());
+
+    // The rounding remainder goes to the last destination
+    let split = destination(vec![
+        (alice.clone(), Dec::new(1, 1).unwrap()),
+        (bob.clone(), Dec::new(9, 1).unwrap()),
+    ]);
+    assert!(split.is_valid());
+    assert_eq!(
+        split.split(token::Amount::from(99)).unwrap(),
+        vec![
+            (alice, token::Amount::from(9)),
+            (bob, token::Amount::from(90))
+        ]
+    );
+}
+
+#[test]
+fn test_reward_destination() {
+    let validators =
+        get_genesis_validators(1, vec![token::Amount::native_whole(10)]);
+    let validator = validators[0].address.clone();
+
+    let mut storage = TestState::default();
+    let current_epoch = storage.in_mem().block.epoch;
+    let params = test_init_genesis(
+        &mut storage,
+        OwnedPosParams::default(),
+        validators.into_iter(),
+        current_epoch,
+    )
+    .unwrap();
+    storage.commit_block().unwrap();
+
+    // Direct the commission to two addresses
+    let alice = address::testing::established_address_1();
+    let bob = address::testing::established_address_2();
+    let destination = RewardDestination(
+        [
+            (alice.clone(), Dec::new(3, 1).unwrap()),
+            (bob.clone(), Dec::new(7, 1).unwrap()),
+        ]
+        .into(),
+    );
+    write_validator_reward_destination(&mut storage, &validator, &destination)
+        .unwrap();
+    assert_eq!(
+        read_validator_metadata(&storage, &validator)
+            .unwrap()
+            .unwrap()
+            .reward_destination,
+        Some(destination)
+    );
+
+    // Give all the rewards of the epoch to the validator
+    let current_epoch = advance_epoch(&mut storage, &params);
+    let num_blocks_in_last_epoch = 1000;
+    rewards_accumulator_handle()
+        .insert(
+            &mut storage,
+            validator.clone(),
+            Dec::from(num_blocks_in_last_epoch),
+        )
+        .unwrap();
+    let staking_token = staking_token_address(&storage);
+    let total_native_tokens =
+        get_effective_total_native_supply(&storage).unwrap();
+    update_rewards_products_and_mint_inflation::<_, token::Store<_>>(
+        &mut storage,
+        &params,
+        current_epoch.prev().unwrap(),
+        num_blocks_in_last_epoch,
+        token::Amount::native_whole(1000),
+        &staking_token,
+        total_native_tokens,
+    )
+    .unwrap();
+
+    // The 5% commission is split among the destinations instead of being
+    // tallied for the validator
+    assert!(
+        read_rewards_counter(&storage, &validator, &validator)
+            .unwrap()
+            .is_zero()
+    );
+    assert_eq!(
+        read_rewards_counter(&storage, &alice, &validator).unwrap(),
+        token::Amount::native_whole(15)
+    );
+    assert_eq!(
+        read_rewards_counter(&storage, &bob, &validator).unwrap(),
+        token::Amount::native_whole(35)
+    );
+
+    // A destination can claim its share of the commission itself
+    crate::claim_reward_tokens::<_, GovStore<_>, token::Store<_>>(
+        &mut storage,
+        Some(&bob),
+        &validator,
+        current_epoch,
+    )
+    .unwrap();
+    assert_eq!(
+        read_balance(&storage, &staking_token, &bob).unwrap(),
+        token::Amount::native_whole(35)
+    );
+
+    // The rest is paid out when the validator claims its rewards
+    crate::claim_reward_tokens::<_, GovStore<_>, token::Store<_>>(
+        &mut storage,
+        None,
+        &validator,
+        current_epoch,
+    )
+    .unwrap();
+    assert_eq!(
+        read_balance(&storage, &staking_token, &alice).unwrap(),
+        token::Amount::native_whole(15)
+    );
+    assert_eq!(
+        read_balance(&storage, &staking_token, &bob).unwrap(),
+        token::Amount::native_whole(35)
+    );
+    assert!(
+        read_rewards_counter(&storage, &alice, &validator)
+            .unwrap()
+            .is_zero()
+    );
+
+    // Resetting the destination removes it from the metadata
+    write_validator_reward_destination(
+        &mut storage,
+        &validator,
+        &RewardDestination::default(),
+    )
+    .unwrap();
+    assert_eq!(
+        read_validator_metadata(&storage, &validator)
+            .unwrap()
+            .unwrap()
+            .reward_destination,
+        None
+    );
+}
@@ -23,14 +23,17 @@ use proptest::test_runner::Config;
 use test_log::test;
 
 use crate::lazy_map::Collectable;
+use crate::slashing::{
+    compute_slashable_amount, compute_slashable_amount_details,
+};
 use crate::storage::{
     bond_handle, delegator_redelegated_bonds_handle,
     delegator_redelegated_unbonds_handle, enqueued_slashes_handle,
     read_total_stake, read_validator_stake, total_bonded_handle,
     total_unbonded_handle, unbond_handle,
     validator_incoming_redelegations_handle,
-    validator_outgoing_redelegations_handle, validator_slashes_handle,
-    validator_total_redelegated_bonded_handle,
+    validator_outgoing_redelegations_handle, validator_slash_records_handle,
+    validator_slashes_handle, validator_total_redelegated_bonded_handle,
     validator_total_redelegated_unbonded_handle,
 };
 use crate::tests::helpers::{
@@ -39,7 +42,7 @@ use crate::tests::helpers::{
 };
 use crate::tests::{
     bond_amount, bond_tokens, bonds_and_unbonds, process_slashes,
-    redelegate_tokens, slash, test_init_genesis, unbond_tokens,
+    redelegate_tokens, slash, slash_report, test_init_genesis, unbond_tokens,
     withdraw_tokens,
 };
 use crate::types::{BondId, GenesisValidator, Slash, SlashType};
@@ -1808,3 +1811,207 @@ fn test_one_slash_per_block_height() {
         .unwrap();
     assert_eq!(res, exp);
 }
+
+proptest! {
+    // Generate arb valid input for `test_slash_report_aux`
+    #![proptest_config(Config {
+        cases: 1,
+        .. Config::default()
+    })]
+    #[test]
+    fn test_slash_report(
+
+    genesis_validators in arb_genesis_validators(4..5, None),
+
+    ) {
+        test_slash_report_aux(genesis_validators)
+    }
+}
+
+fn test_slash_report_aux(validators: Vec<GenesisValidator>) {
+    let mut storage = TestState::default();
+    let params = OwnedPosParams {
+        unbonding_len: 4,
+        validator_stake_threshold: token::Amount::zero(),
+        ..Default::default()
+    };
+
+    // Genesis
+    let mut current_epoch = storage.in_mem().block.epoch;
+    let params = test_init_genesis(
+        &mut storage,
+        params,
+        validators.clone().into_iter(),
+        current_epoch,
+    )
+    .unwrap();
+    storage.commit_block().unwrap();
+
+    let validator1 = validators[0].address.clone();
+    let validator2 = validators[1].address.clone();
+
+    // Get a delegator with some tokens and bond to validator 1
+    let staking_token = staking_token_address(&storage);
+    let delegator = address::testing::gen_implicit_address();
+    let del_balance = token::Amount::from_uint(1_000_000, 0).unwrap();
+    credit_tokens(&mut storage, &staking_token, &delegator, del_balance)
+        .unwrap();
+    bond_tokens(
+        &mut storage,
+        Some(&delegator),
+        &validator1,
+        10_000.into(),
+        current_epoch,
+        None,
+    )
+    .unwrap();
+
+    // Advance to the epoch in which the bond contributes to the stake
+    for _ in 0..params.pipeline_len {
+        current_epoch = advance_epoch(&mut storage, &params);
+        process_slashes(
+            &mut storage,
+            &mut namada_events::testing::VoidEventSink,
+            current_epoch,
+        )
+        .unwrap();
+    }
+
+    // Redelegate some from validator 1 -> 2
+    redelegate_tokens(
+        &mut storage,
+        &delegator,
+        &validator1,
+        &validator2,
+        current_epoch,
+        2_000.into(),
+    )
+    .unwrap();
+
+    // Discover a misbehavior of validator 1
+    let evidence_epoch = current_epoch;
+    let evidence_block_height = 5_u64;
+    slash(
+        &mut storage,
+        &params,
+        current_epoch,
+        evidence_epoch,
+        evidence_block_height,
+        SlashType::DuplicateVote,
+        &validator1,
+        current_epoch.next(),
+    )
+    .unwrap();
+
+    // The slash is recorded, but it has no report until processed
+    let record = validator_slash_records_handle(&validator1)
+        .get(&storage, &evidence_block_height)
+        .unwrap()
+        .unwrap();
+    assert_eq!(record.epoch, evidence_epoch);
+    assert_eq!(record.r#type, SlashType::DuplicateVote);
+    assert!(record.cubic_window.is_none());
+    assert!(
+        slash_report(&storage, &validator1, evidence_block_height)
+            .unwrap()
+            .is_none()
+    );
+
+    // Advance to the epoch in which the slash is processed
+    let processing_epoch =
+        evidence_epoch + params.slash_processing_epoch_offset();
+    while current_epoch < processing_epoch {
+        current_epoch = advance_epoch(&mut storage, &params);
+        process_slashes(
+            &mut storage,
+            &mut namada_events::testing::VoidEventSink,
+            current_epoch,
+        )
+        .unwrap();
+    }
+
+    // The record has the inputs of the cubic slash rate
+    let record = validator_slash_records_handle(&validator1)
+        .get(&storage, &evidence_block_height)
+        .unwrap()
+        .unwrap();
+    let window = record.cubic_window.clone().unwrap();
+    let (window_start, window_end) =
+        params.cubic_slash_epoch_window(evidence_epoch);
+    assert_eq!(window.epochs.first().unwrap().epoch, window_start);
+    assert_eq!(window.epochs.last().unwrap().epoch, window_end);
+    let infracting = window
+        .epochs
+        .iter()
+        .find(|epoch| epoch.epoch == evidence_epoch)
+        .unwrap();
+    assert_eq!(
+        infracting.infracting_stake,
+        read_validator_stake(&storage, &params, &validator1, evidence_epoch)
+            .unwrap()
+    );
+
+    let report = slash_report(&storage, &validator1, evidence_block_height)
+        .unwrap()
+        .unwrap();
+    let processed_slash = validator_slashes_handle(&validator1)
+        .get(&storage, 0)
+        .unwrap()
+        .unwrap();
+    assert_eq!(report.slash, processed_slash);
+    assert_eq!(report.record, Some(record));
+    assert_eq!(
+        report.type_rate,
+        SlashType::DuplicateVote.get_slash_rate(&params)
+    );
+    assert_eq!(
+        processed_slash.rate,
+        std::cmp::min(
+            Dec::one(),
+            std::cmp::max(report.type_rate, window.cubic_rate)
+        )
+    );
+
+    // The self-bond and the delegation are both slashed
+    assert_eq!(report.bonds.len(), 2);
+    let delegation = report
+        .bonds
+        .iter()
+        .find(|bond| bond.source == delegator)
+        .unwrap();
+    assert_eq!(delegation.computation.amount, 8_000.into());
+    assert!(delegation.computation.prior_slashed_amounts.is_empty());
+    assert_eq!(
+        delegation.computation.slashed_amount,
+        compute_slashable_amount(
+            &params,
+            &processed_slash,
+            8_000.into(),
+            &BTreeMap::new()
+        )
+        .unwrap()
+    );
+
+    // And so is the redelegation
+    assert_eq!(report.redelegations.len(), 1);
+    let redelegation = &report.redelegations[0];
+    assert_eq!(redelegation.dest_validator, validator2);
+    assert_eq!(redelegation.redelegation_start, evidence_epoch);
+    assert_eq!(
+        redelegation.computation,
+        compute_slashable_amount_details(
+            &params,
+            &processed_slash,
+            2_000.into(),
+            &BTreeMap::new()
+        )
+        .unwrap()
+    );
+
+    // There is no report for another block height
+    assert!(
+        slash_report(&storage, &validator1, evidence_block_height + 1)
+            .unwrap()
+            .is_none()
+    );
+}
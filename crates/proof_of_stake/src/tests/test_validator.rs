@@ -12,6 +12,7 @@ use namada_core::key::testing::{
 use namada_core::key::{self, common, RefTo};
 use namada_core::token;
 use namada_state::testing::TestState;
+use namada_state::StorageWrite;
 use namada_trans_token::credit_tokens;
 use proptest::prelude::*;
 use proptest::test_runner::Config;
@@ -25,29 +26,33 @@ use crate::storage::{
     consensus_validator_set_handle, find_validator_by_raw_hash,
     get_num_consensus_validators,
     read_below_capacity_validator_set_addresses_with_stake,
-    read_consensus_validator_set_addresses_with_stake,
+    read_consensus_validator_set_addresses_with_stake, read_owned_pos_params,
     validator_addresses_handle, validator_consensus_key_handle,
-    validator_set_positions_handle, write_validator_address_raw_hash,
+    validator_set_positions_handle, validator_state_handle, write_pos_params,
+    write_validator_address_raw_hash,
 };
 use crate::tests::helpers::{
     advance_epoch, arb_genesis_validators, arb_params_and_genesis_validators,
     get_tendermint_set_updates,
 };
 use crate::tests::{
-    become_validator, bond_tokens, change_consensus_key, init_genesis_helper,
+    become_validator, bond_tokens, change_consensus_key,
+    change_validator_commission_rate, init_genesis_helper,
     read_below_threshold_validator_set_addresses, test_init_genesis,
-    unbond_tokens, update_validator_deltas, withdraw_tokens, GovStore,
+    unbond_tokens, unjail_validator, update_validator_deltas, withdraw_tokens,
+    GovStore,
 };
 use crate::types::{
     into_tm_voting_power, ConsensusValidator, GenesisValidator, Position,
-    ReverseOrdTokenAmount, ValidatorSetUpdate, WeightedValidator,
+    ReverseOrdTokenAmount, ValidatorSetUpdate, ValidatorState,
+    WeightedValidator,
 };
 use crate::validator_set_update::{
     insert_validator_into_validator_set, update_validator_set,
 };
 use crate::{
-    is_validator, lazy_map, staking_token_address, BecomeValidator,
-    OwnedPosParams,
+    is_validator, lazy_map, staking_token_address, storage_key,
+    BecomeValidator, OwnedPosParams,
 };
 
 proptest! {
@@ -1446,3 +1451,293 @@ fn test_purge_validator_information_aux(validators: Vec<GenesisValidator>) {
         assert!(!consensus_val_set.at(&ep).is_empty(&s).unwrap());
     }
 }
+
+proptest! {
+    // Generate arb valid input for `test_validator_policies_aux`
+    #![proptest_config(Config {
+        cases: 1,
+        .. Config::default()
+    })]
+    #[test]
+    fn test_validator_policies(
+
+    genesis_validators in arb_genesis_validators(2..3, None),
+
+    ) {
+        test_validator_policies_aux(genesis_validators)
+    }
+}
+
+/// Test the minimum self-bond ratio and maximum commission rate policies.
+fn test_validator_policies_aux(validators: Vec<GenesisValidator>) {
+    let mut s = TestState::default();
+    let params = OwnedPosParams {
+        min_self_bond_ratio: Dec::new(5, 1).expect("Test failed"),
+        // The genesis validators have a commission rate of 5%
+        max_commission_rate: Dec::new(55, 3).expect("Test failed"),
+        ..Default::default()
+    };
+
+    // Genesis
+    let mut current_epoch = s.in_mem().block.epoch;
+    let params = test_init_genesis(
+        &mut s,
+        params,
+        validators.clone().into_iter(),
+        current_epoch,
+    )
+    .unwrap();
+    s.commit_block().unwrap();
+    current_epoch = advance_epoch(&mut s, &params);
+
+    let validator = validators[0].address.clone();
+    let self_bond = validators[0].tokens;
+
+    // The commission rate cannot be changed above the maximum
+    let res = change_validator_commission_rate(
+        &mut s,
+        &validator,
+        Dec::new(6, 2).expect("Test failed"),
+        current_epoch,
+    );
+    assert!(res.is_err());
+    change_validator_commission_rate(
+        &mut s,
+        &validator,
+        params.max_commission_rate,
+        current_epoch,
+    )
+    .unwrap();
+
+    // A new validator cannot have a commission rate above the maximum
+    let new_validator = address::testing::established_address_3();
+    let consensus_key = common_sk_from_simple_seed(100).to_public();
+    let protocol_key = common_sk_from_simple_seed(101).to_public();
+    let eth_hot_key = key::common::PublicKey::Secp256k1(
+        key::testing::gen_keypair::<key::secp256k1::SigScheme>().ref_to(),
+    );
+    let eth_cold_key = key::common::PublicKey::Secp256k1(
+        key::testing::gen_keypair::<key::secp256k1::SigScheme>().ref_to(),
+    );
+    let res = become_validator(
+        &mut s,
+        BecomeValidator {
+            params: &params,
+            address: &new_validator,
+            consensus_key: &consensus_key,
+            protocol_key: &protocol_key,
+            eth_cold_key: &eth_cold_key,
+            eth_hot_key: &eth_hot_key,
+            current_epoch,
+            commission_rate: Dec::new(1, 1).expect("Test failed"),
+            max_commission_rate_change: Dec::new(5, 2).expect("Test failed"),
+            metadata: Default::default(),
+            offset_opt: None,
+        },
+    );
+    assert!(res.is_err());
+    assert!(!is_validator(&s, &new_validator).unwrap());
+
+    // Delegate as much as the validator's self-bond, which keeps its
+    // self-bond at the minimum ratio
+    let staking_token = staking_token_address(&s);
+    let delegator = address::testing::gen_implicit_address();
+    credit_tokens(&mut s, &staking_token, &delegator, self_bond).unwrap();
+    bond_tokens(
+        &mut s,
+        Some(&delegator),
+        &validator,
+        self_bond,
+        current_epoch,
+        None,
+    )
+    .unwrap();
+
+    // Unbonding any of the self-bond jails the validator at pipeline
+    let pipeline_epoch = current_epoch + params.pipeline_len;
+    unbond_tokens(&mut s, None, &validator, 1.into(), current_epoch, false)
+        .unwrap();
+    let state_handle = validator_state_handle(&validator);
+    assert_ne!(
+        state_handle.get(&s, current_epoch, &params).unwrap(),
+        Some(ValidatorState::Jailed)
+    );
+    assert_eq!(
+        state_handle.get(&s, pipeline_epoch, &params).unwrap(),
+        Some(ValidatorState::Jailed)
+    );
+
+    // Unjailing fails until the validator self-bonds enough again
+    while current_epoch < pipeline_epoch {
+        current_epoch = advance_epoch(&mut s, &params);
+    }
+    assert!(unjail_validator(&mut s, &validator, current_epoch).is_err());
+    credit_tokens(&mut s, &staking_token, &validator, 10.into()).unwrap();
+    bond_tokens(&mut s, None, &validator, 10.into(), current_epoch, None)
+        .unwrap();
+    unjail_validator(&mut s, &validator, current_epoch).unwrap();
+    assert_ne!(
+        state_handle
+            .get(&s, current_epoch + params.pipeline_len, &params)
+            .unwrap(),
+        Some(ValidatorState::Jailed)
+    );
+}
+
+proptest! {
+    // Generate arb valid input for `test_min_self_bond_delegations_aux`
+    #![proptest_config(Config {
+        cases: 1,
+        .. Config::default()
+    })]
+    #[test]
+    fn test_min_self_bond_delegations(
+
+    genesis_validators in arb_genesis_validators(2..3, None),
+
+    ) {
+        test_min_self_bond_delegations_aux(genesis_validators)
+    }
+}
+
+/// Test that a validator whose delegations push its self-bond below the
+/// minimum ratio of its stake is jailed at the start of the next epoch.
+fn test_min_self_bond_delegations_aux(validators: Vec<GenesisValidator>) {
+    let mut s = TestState::default();
+    let params = OwnedPosParams {
+        min_self_bond_ratio: Dec::new(5, 1).expect("Test failed"),
+        ..Default::default()
+    };
+
+    // Genesis
+    let mut current_epoch = s.in_mem().block.epoch;
+    let params = test_init_genesis(
+        &mut s,
+        params,
+        validators.clone().into_iter(),
+        current_epoch,
+    )
+    .unwrap();
+    s.commit_block().unwrap();
+    current_epoch = advance_epoch(&mut s, &params);
+
+    let validator = validators[0].address.clone();
+    let self_bond = validators[0].tokens;
+    let other_validator = validators[1].address.clone();
+    let other_self_bond = validators[1].tokens;
+
+    // Delegate twice the self-bond of the validator, which pushes it below
+    // the minimum ratio, and as much as the self-bond of the other validator,
+    // which keeps it at the minimum ratio
+    let staking_token = staking_token_address(&s);
+    let delegator = address::testing::gen_implicit_address();
+    let delegation = self_bond + self_bond;
+    credit_tokens(
+        &mut s,
+        &staking_token,
+        &delegator,
+        delegation + other_self_bond,
+    )
+    .unwrap();
+    bond_tokens(
+        &mut s,
+        Some(&delegator),
+        &validator,
+        delegation,
+        current_epoch,
+        None,
+    )
+    .unwrap();
+    bond_tokens(
+        &mut s,
+        Some(&delegator),
+        &other_validator,
+        other_self_bond,
+        current_epoch,
+        None,
+    )
+    .unwrap();
+
+    // The delegations alone don't jail the validator
+    let pipeline_epoch = current_epoch + params.pipeline_len;
+    let state_handle = validator_state_handle(&validator);
+    assert_ne!(
+        state_handle.get(&s, pipeline_epoch, &params).unwrap(),
+        Some(ValidatorState::Jailed)
+    );
+
+    // At the start of the next epoch, the validator is jailed from the new
+    // pipeline epoch, while the other validator is not
+    current_epoch = advance_epoch(&mut s, &params);
+    crate::jail_for_min_self_bond::<_, GovStore<_>>(
+        &mut s,
+        &params,
+        current_epoch,
+    )
+    .unwrap();
+    let pipeline_epoch = current_epoch + params.pipeline_len;
+    assert_ne!(
+        state_handle.get(&s, pipeline_epoch - 1, &params).unwrap(),
+        Some(ValidatorState::Jailed)
+    );
+    assert_eq!(
+        state_handle.get(&s, pipeline_epoch, &params).unwrap(),
+        Some(ValidatorState::Jailed)
+    );
+    assert_ne!(
+        validator_state_handle(&other_validator)
+            .get(&s, pipeline_epoch, &params)
+            .unwrap(),
+        Some(ValidatorState::Jailed)
+    );
+
+    // The validator can be unjailed once it self-bonds enough again
+    while current_epoch < pipeline_epoch {
+        current_epoch = advance_epoch(&mut s, &params);
+    }
+    assert!(unjail_validator(&mut s, &validator, current_epoch).is_err());
+    credit_tokens(&mut s, &staking_token, &validator, delegation).unwrap();
+    bond_tokens(&mut s, None, &validator, delegation, current_epoch, None)
+        .unwrap();
+    unjail_validator(&mut s, &validator, current_epoch).unwrap();
+    crate::jail_for_min_self_bond::<_, GovStore<_>>(
+        &mut s,
+        &params,
+        current_epoch,
+    )
+    .unwrap();
+    assert_ne!(
+        state_handle
+            .get(&s, current_epoch + params.pipeline_len, &params)
+            .unwrap(),
+        Some(ValidatorState::Jailed)
+    );
+}
+
+/// Test that the validator policy parameters are stored under their own keys
+/// and that they default when their keys are missing from storage.
+#[test]
+fn test_validator_policies_storage() {
+    let mut s = TestState::default();
+    let params = OwnedPosParams {
+        min_self_bond_ratio: Dec::new(5, 1).expect("Test failed"),
+        max_commission_rate: Dec::new(55, 3).expect("Test failed"),
+        pipeline_len: 3,
+        ..Default::default()
+    };
+    write_pos_params(&mut s, &params).unwrap();
+    let read = read_owned_pos_params(&s).unwrap();
+    assert_eq!(read.min_self_bond_ratio, params.min_self_bond_ratio);
+    assert_eq!(read.max_commission_rate, params.max_commission_rate);
+    assert_eq!(read.pipeline_len, params.pipeline_len);
+
+    // The keys are missing from chains that were initialized before the
+    // policies were added
+    s.delete(&storage_key::min_self_bond_ratio_key()).unwrap();
+    s.delete(&storage_key::max_commission_rate_key()).unwrap();
+    let read = read_owned_pos_params(&s).unwrap();
+    let defaults = OwnedPosParams::default();
+    assert_eq!(read.min_self_bond_ratio, defaults.min_self_bond_ratio);
+    assert_eq!(read.max_commission_rate, defaults.max_commission_rate);
+    assert_eq!(read.pipeline_len, params.pipeline_len);
+}
@@ -9,6 +9,7 @@ use std::hash::Hash;
 
 use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
 use namada_core::address::Address;
+use namada_core::arith::{self, checked};
 use namada_core::collections::HashMap;
 use namada_core::dec::Dec;
 use namada_core::key::common;
@@ -21,7 +22,9 @@ pub use rev_order::ReverseOrdTokenAmount;
 use serde::{Deserialize, Serialize};
 
 use crate::lazy_map::NestedMap;
-use crate::parameters::{PosParams, MAX_VALIDATOR_METADATA_LEN};
+use crate::parameters::{
+    PosParams, MAX_REWARD_DESTINATIONS, MAX_VALIDATOR_METADATA_LEN,
+};
 use crate::{Epoch, KeySeg, LazyMap, LazySet, LazyVec, ValidatorMetaDataError};
 
 /// Stored positions of validators in validator sets
@@ -417,6 +420,10 @@ pub struct ValidatorMetaData {
     pub avatar: Option<String>,
     /// Validator's name
     pub name: Option<String>,
+    /// The destination of the validator's commission, if other than the
+    /// validator itself
+    #[serde(default)]
+    pub reward_destination: Option<RewardDestination>,
 }
 
 impl ValidatorMetaData {
@@ -455,10 +462,76 @@ impl ValidatorMetaData {
                 errors.push(ValidatorMetaDataError::FieldTooLong("name"));
             }
         }
+        if let Some(reward_destination) = self.reward_destination.as_ref() {
+            if !reward_destination.is_valid() {
+                errors.push(ValidatorMetaDataError::InvalidRewardDestination);
+            }
+        }
         errors
     }
 }
 
+/// The destination of a validator's commission, which is split among the
+/// addresses by their ratios.
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    BorshSerialize,
+    BorshSchema,
+    BorshDeserialize,
+    BorshDeserializer,
+    Deserialize,
+    Serialize,
+    Eq,
+    Ord,
+    PartialOrd,
+    PartialEq,
+)]
+pub struct RewardDestination(pub BTreeMap<Address, Dec>);
+
+impl RewardDestination {
+    /// Check that the commission is split among a non-empty and bounded set
+    /// of addresses with positive ratios that sum up to 1.
+    pub fn is_valid(&self) -> bool {
+        if self.0.is_empty() || self.0.len() > MAX_REWARD_DESTINATIONS {
+            return false;
+        }
+        let mut total = Dec::zero();
+        for ratio in self.0.values() {
+            if *ratio <= Dec::zero() {
+                return false;
+            }
+            match total.checked_add(*ratio) {
+                Some(sum) => total = sum,
+                None => return false,
+            }
+        }
+        total == Dec::one()
+    }
+
+    /// Split an amount of commission tokens among the destination addresses.
+    /// The remainder of the rounding is given to the last address.
+    pub fn split(
+        &self,
+        amount: token::Amount,
+    ) -> Result<Vec<(Address, token::Amount)>, arith::Error> {
+        let mut remaining = amount;
+        let mut shares = Vec::with_capacity(self.0.len());
+        let mut destinations = self.0.iter().peekable();
+        while let Some((address, ratio)) = destinations.next() {
+            let share = if destinations.peek().is_some() {
+                amount.mul_floor(*ratio)?
+            } else {
+                remaining
+            };
+            remaining = checked!(remaining - share)?;
+            shares.push((address.clone(), share));
+        }
+        Ok(shares)
+    }
+}
+
 #[cfg(any(test, feature = "testing"))]
 impl Default for ValidatorMetaData {
     fn default() -> Self {
@@ -469,6 +542,7 @@ impl Default for ValidatorMetaData {
             discord_handle: Default::default(),
             avatar: Default::default(),
             name: Default::default(),
+            reward_destination: Default::default(),
         }
     }
 }
@@ -687,6 +761,224 @@ pub enum SlashType {
     LightClientAttack,
 }
 
+/// The record of the evidence behind a slash and of the inputs of its cubic
+/// slashing rate, kept to explain how the slash was computed.
+#[derive(
+    Debug,
+    Clone,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSerialize,
+    BorshSchema,
+    PartialEq,
+    Eq,
+)]
+pub struct SlashRecord {
+    /// A type of slashable event.
+    pub r#type: SlashType,
+    /// Epoch at which the slashable event occurred.
+    pub epoch: Epoch,
+    /// Block height at which the slashable event occurred.
+    pub block_height: u64,
+    /// Hash of the misbehavior evidence reported by CometBFT, if the slash
+    /// originates from it
+    pub evidence_hash: Option<namada_core::hash::Hash>,
+    /// The inputs of the cubic slashing rate, once the slash is processed
+    pub cubic_window: Option<CubicSlashWindow>,
+}
+
+/// Slash records of a validator, keyed by the block height of the slashable
+/// event.
+pub type SlashRecords = LazyMap<u64, SlashRecord>;
+
+/// The inputs and result of the cubic slashing rate computation over the
+/// window of epochs around an infraction epoch.
+#[derive(
+    Debug,
+    Clone,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSerialize,
+    BorshSchema,
+    PartialEq,
+    Eq,
+)]
+pub struct CubicSlashWindow {
+    /// The stake of the validators that misbehaved in each epoch of the
+    /// window, along with the total consensus stake
+    pub epochs: Vec<CubicSlashWindowEpoch>,
+    /// The sum of the fractions of the infracting stake over the window
+    pub sum_vp_fraction: Dec,
+    /// The cubic slashing rate, before capping it at 1
+    pub cubic_rate: Dec,
+}
+
+/// The stakes of an epoch in the cubic slashing window.
+#[derive(
+    Debug,
+    Clone,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSerialize,
+    BorshSchema,
+    PartialEq,
+    Eq,
+)]
+pub struct CubicSlashWindowEpoch {
+    /// The epoch
+    pub epoch: Epoch,
+    /// The total stake of the validators that misbehaved in the epoch
+    pub infracting_stake: token::Amount,
+    /// The total consensus stake in the epoch
+    pub consensus_stake: token::Amount,
+}
+
+/// The computation of the amount slashed from some tokens by a slash, as
+/// performed by [`crate::slashing::compute_slashable_amount`].
+#[derive(
+    Debug,
+    Clone,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSerialize,
+    BorshSchema,
+    PartialEq,
+    Eq,
+)]
+pub struct SlashableAmountComputation {
+    /// The amount of tokens subject to the slash
+    pub amount: token::Amount,
+    /// The amounts taken by the slashes that were processed before the slash
+    /// occurred, keyed by their infraction epoch
+    pub prior_slashed_amounts: BTreeMap<Epoch, token::Amount>,
+    /// The amount remaining after the prior slashes
+    pub slashable_amount: token::Amount,
+    /// The rate of the slash
+    pub rate: Dec,
+    /// The slashed amount, rounded up
+    pub slashed_amount: token::Amount,
+}
+
+/// A report explaining how a processed slash was computed and applied.
+#[derive(
+    Debug,
+    Clone,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSerialize,
+    BorshSchema,
+    PartialEq,
+    Eq,
+)]
+pub struct SlashReport {
+    /// The slashed validator
+    pub validator: Address,
+    /// The slash with its final rate
+    pub slash: Slash,
+    /// The record of the slash evidence and cubic slashing window, if any
+    pub record: Option<SlashRecord>,
+    /// The minimum slash rate for the type of the slash
+    pub type_rate: Dec,
+    /// The amounts slashed from the bonds to the validator
+    pub bonds: Vec<BondSlashReport>,
+    /// The amounts slashed from the redelegations out of the validator
+    pub redelegations: Vec<RedelegationSlashReport>,
+}
+
+/// The amount slashed from a bond.
+#[derive(
+    Debug,
+    Clone,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSerialize,
+    BorshSchema,
+    PartialEq,
+    Eq,
+)]
+pub struct BondSlashReport {
+    /// The source of the bond
+    pub source: Address,
+    /// The first epoch in which the bond contributed to the stake
+    pub start: Epoch,
+    /// The computation of the slashed amount
+    pub computation: SlashableAmountComputation,
+}
+
+/// The amount slashed from a redelegation.
+#[derive(
+    Debug,
+    Clone,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSerialize,
+    BorshSchema,
+    PartialEq,
+    Eq,
+)]
+pub struct RedelegationSlashReport {
+    /// The destination validator of the redelegation
+    pub dest_validator: Address,
+    /// The first epoch in which the redelegated bond contributed to the stake
+    /// of the slashed validator
+    pub bond_start: Epoch,
+    /// The epoch in which the redelegation started
+    pub redelegation_start: Epoch,
+    /// The computation of the slashed amount
+    pub computation: SlashableAmountComputation,
+}
+
+/// The rate at which the liquid staking shares of a validator are exchanged
+/// for bonded tokens.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSerialize,
+    BorshSchema,
+    PartialEq,
+    Eq,
+)]
+pub struct ShareExchangeRate {
+    /// The bonded amount of the liquid staking pool, after slashes
+    pub pool_value: Amount,
+    /// The total supply of the shares
+    pub share_supply: Amount,
+}
+
+impl ShareExchangeRate {
+    /// The shares issued for bonding the given amount of tokens, rounded
+    /// down. Shares are issued 1:1 when there are none outstanding. Returns
+    /// `None` if the outstanding shares are not backed by any tokens.
+    pub fn shares_for_tokens(&self, amount: Amount) -> Option<Amount> {
+        if self.share_supply.is_zero() {
+            return Some(amount);
+        }
+        amount
+            .raw_amount()
+            .checked_mul_div(
+                self.share_supply.raw_amount(),
+                self.pool_value.raw_amount(),
+            )
+            .map(|(shares, _rem)| Amount::from(shares))
+    }
+
+    /// The bonded tokens that the given shares are worth, rounded down.
+    /// Returns `None` if there are no outstanding shares.
+    pub fn tokens_for_shares(&self, shares: Amount) -> Option<Amount> {
+        shares
+            .raw_amount()
+            .checked_mul_div(
+                self.pool_value.raw_amount(),
+                self.share_supply.raw_amount(),
+            )
+            .map(|(tokens, _rem)| Amount::from(tokens))
+    }
+}
+
 /// VoteInfo inspired from tendermint for validators whose signature was
 /// included in the last block
 #[derive(Debug, Clone, BorshDeserialize, BorshSerialize, BorshDeserializer)]
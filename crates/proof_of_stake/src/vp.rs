@@ -4,19 +4,27 @@ use std::collections::{BTreeMap, BTreeSet};
 use std::marker::PhantomData;
 
 use namada_core::address::Address;
+use namada_core::arith::checked;
 use namada_core::booleans::BoolResultUnitExt;
 use namada_core::storage::Key;
-use namada_systems::governance;
+use namada_systems::{governance, trans_token};
 use namada_tx::action::{
-    Action, Bond, ClaimRewards, PosAction, Redelegation, Unbond, Withdraw,
+    Action, AutoCompound, Bond, ClaimRewards, LiquidBond, LiquidUnbond,
+    PosAction, Redelegation, Unbond, Withdraw,
 };
 use namada_tx::BatchedTxRef;
 use namada_vp_env::{Error, Result, VpEnv};
 use thiserror::Error;
 
-use crate::storage::{read_owned_pos_params, read_validator_metadata};
+use crate::liquid_staking::{
+    pending_pool_rewards, pool_value, share_token_address,
+    share_token_validator,
+};
+use crate::storage::{
+    read_owned_pos_params, read_pos_params, read_validator_metadata,
+};
 use crate::storage_key::is_params_key;
-use crate::types::BondId;
+use crate::types::{BondId, ShareExchangeRate};
 use crate::{storage_key, token};
 
 #[allow(missing_docs)]
@@ -26,6 +34,11 @@ pub enum VpError {
         "Action {0} not authorized by {1} which is not part of verifier set"
     )]
     Unauthorized(&'static str, Address),
+    #[error(
+        "Action {0} with the liquid staking share token {1} as a source can \
+         only be applied by a liquid bond or unbond"
+    )]
+    ShareTokenSource(&'static str, Address),
 }
 
 impl From<VpError> for Error {
@@ -35,15 +48,16 @@ impl From<VpError> for Error {
 }
 
 /// Proof-of-Stake validity predicate
-pub struct PosVp<'ctx, CTX, Gov> {
+pub struct PosVp<'ctx, CTX, Gov, TokenKeys> {
     /// Generic types for DI
-    pub _marker: PhantomData<(&'ctx CTX, Gov)>,
+    pub _marker: PhantomData<(&'ctx CTX, Gov, TokenKeys)>,
 }
 
-impl<'ctx, CTX, Gov> PosVp<'ctx, CTX, Gov>
+impl<'ctx, CTX, Gov, TokenKeys> PosVp<'ctx, CTX, Gov, TokenKeys>
 where
     CTX: VpEnv<'ctx> + namada_tx::action::Read<Err = Error>,
     Gov: governance::Read<<CTX as VpEnv<'ctx>>::Pre>,
+    TokenKeys: trans_token::Keys,
 {
     /// Run the validity predicate
     pub fn validate_tx(
@@ -100,6 +114,9 @@ where
         let mut redelegations: BTreeMap<BondId, (Address, token::Amount)> =
             Default::default();
         let mut claimed_rewards: BTreeSet<BondId> = Default::default();
+        let mut auto_compound: BTreeSet<BondId> = Default::default();
+        // Validators whose liquid staking pool is changed
+        let mut liquid_validators: BTreeSet<Address> = Default::default();
         let mut changed_commission: BTreeSet<Address> = Default::default();
         let mut changed_metadata: BTreeSet<Address> = Default::default();
         let mut changed_consensus_key: BTreeSet<Address> = Default::default();
@@ -195,6 +212,40 @@ where
                         }
                         unbonds.insert(bond_id, amount);
                     }
+                    PosAction::LiquidBond(LiquidBond {
+                        validator,
+                        amount: _,
+                        source,
+                    }) => {
+                        if !verifiers.contains(&source) {
+                            tracing::info!(
+                                "Unauthorized PosAction::LiquidBond"
+                            );
+                            return Err(VpError::Unauthorized(
+                                "LiquidBond",
+                                source,
+                            )
+                            .into());
+                        }
+                        liquid_validators.insert(validator);
+                    }
+                    PosAction::LiquidUnbond(LiquidUnbond {
+                        validator,
+                        amount: _,
+                        source,
+                    }) => {
+                        if !verifiers.contains(&source) {
+                            tracing::info!(
+                                "Unauthorized PosAction::LiquidUnbond"
+                            );
+                            return Err(VpError::Unauthorized(
+                                "LiquidUnbond",
+                                source,
+                            )
+                            .into());
+                        }
+                        liquid_validators.insert(validator);
+                    }
                     PosAction::Withdraw(Withdraw { validator, source }) => {
                         let bond_id = BondId {
                             source: source.unwrap_or_else(|| validator.clone()),
@@ -252,6 +303,27 @@ where
                         }
                         claimed_rewards.insert(bond_id);
                     }
+                    PosAction::AutoCompound(AutoCompound {
+                        validator,
+                        source,
+                        enabled: _,
+                    }) => {
+                        let bond_id = BondId {
+                            source: source.unwrap_or_else(|| validator.clone()),
+                            validator,
+                        };
+                        if !verifiers.contains(&bond_id.source) {
+                            tracing::info!(
+                                "Unauthorized PosAction::AutoCompound"
+                            );
+                            return Err(VpError::Unauthorized(
+                                "AutoCompound",
+                                bond_id.source,
+                            )
+                            .into());
+                        }
+                        auto_compound.insert(bond_id);
+                    }
                     PosAction::CommissionChange(validator) => {
                         if !verifiers.contains(&validator) {
                             tracing::info!(
@@ -299,6 +371,44 @@ where
             }
         }
 
+        // The bonds of liquid staking pools can only be changed by liquid
+        // bonds and unbonds
+        let share_token_sources = bonds
+            .keys()
+            .map(|bond_id| ("Bond", bond_id))
+            .chain(unbonds.keys().map(|bond_id| ("Unbond", bond_id)))
+            .chain(withdrawals.iter().map(|bond_id| ("Withdraw", bond_id)))
+            .chain(
+                redelegations
+                    .keys()
+                    .map(|bond_id| ("Redelegation", bond_id)),
+            )
+            .chain(
+                claimed_rewards
+                    .iter()
+                    .map(|bond_id| ("ClaimRewards", bond_id)),
+            )
+            .chain(
+                auto_compound
+                    .iter()
+                    .map(|bond_id| ("AutoCompound", bond_id)),
+            );
+        for (action, bond_id) in share_token_sources {
+            if share_token_validator(&bond_id.source).is_some() {
+                return Err(VpError::ShareTokenSource(
+                    action,
+                    bond_id.source.clone(),
+                )
+                .into());
+            }
+        }
+
+        // The shares minted or burned by liquid bonds and unbonds must match
+        // the change of the value of the pool
+        for validator in &liquid_validators {
+            Self::is_valid_share_supply_change(ctx, validator)?;
+        }
+
         // Validate new and changed validator metadata
         for validator in became_validator.iter().chain(&changed_metadata) {
             let metadata = read_validator_metadata(&ctx.post(), validator)?;
@@ -323,11 +433,134 @@ where
                      governance proposal that has been accepted",
                 ));
             }
+            if key == &storage_key::auto_compound_cursor_key() {
+                return Err(Error::new_const(
+                    "The auto-compounding cursor can only be changed by the \
+                     protocol",
+                ));
+            }
+            if let Some(bond_id) = storage_key::is_auto_compound_key(key) {
+                let is_liquid_pool = share_token_validator(&bond_id.source)
+                    .is_some_and(|validator| {
+                        validator == bond_id.validator
+                            && liquid_validators.contains(&validator)
+                    });
+                // Unbonding the bond fully also disables its auto-compounding
+                let is_cleared_by_unbond = (unbonds.contains_key(&bond_id)
+                    || withdrawals.contains(&bond_id)
+                    || redelegations.contains_key(&bond_id))
+                    && !ctx.has_key_post(key)?;
+                if !is_liquid_pool
+                    && !is_cleared_by_unbond
+                    && !auto_compound.contains(&bond_id)
+                {
+                    return Err(Error::new_alloc(format!(
+                        "Auto-compounding of the bond {bond_id:?} can only be \
+                         changed by its source"
+                    )));
+                }
+            }
+            let pool_validator = storage_key::is_bond_key(key)
+                .map(|(bond_id, _)| bond_id)
+                .or_else(|| {
+                    storage_key::is_unbond_key(key)
+                        .map(|(bond_id, _, _)| bond_id)
+                })
+                .and_then(|bond_id| share_token_validator(&bond_id.source))
+                .or_else(|| {
+                    TokenKeys::is_any_minted_balance_key(key)
+                        .and_then(share_token_validator)
+                });
+            if let Some(validator) = pool_validator {
+                if !liquid_validators.contains(&validator) {
+                    return Err(Error::new_alloc(format!(
+                        "The liquid staking pool of the validator {validator} \
+                         can only be changed by a liquid bond or unbond"
+                    )));
+                }
+            }
             // TODO: validate changes keys against the accumulated changes
         }
         Ok(())
     }
 
+    /// Return `Ok` if the change of the supply of the liquid staking shares
+    /// of the validator matches the change of the value of its pool at the
+    /// exchange rate before the change. The rate includes the pending
+    /// rewards of the pool, which are compounded before any shares are
+    /// exchanged.
+    fn is_valid_share_supply_change(
+        ctx: &'ctx CTX,
+        validator: &Address,
+    ) -> Result<()> {
+        let params = read_pos_params::<_, Gov>(&ctx.pre())?;
+        let current_epoch = ctx.get_block_epoch()?;
+        let pipeline_epoch = checked!(current_epoch + params.pipeline_len)?;
+
+        let pre_pool_value =
+            pool_value(&ctx.pre(), &params, validator, pipeline_epoch)?;
+        let pending_rewards = pending_pool_rewards::<_, Gov>(
+            &ctx.pre(),
+            validator,
+            current_epoch,
+        )?;
+        let pre_value = checked!(pre_pool_value + pending_rewards)?;
+        let post_value =
+            pool_value(&ctx.post(), &params, validator, pipeline_epoch)?;
+
+        let supply_key =
+            TokenKeys::minted_balance_key(&share_token_address(validator)?);
+        let pre_supply: token::Amount =
+            ctx.read_pre(&supply_key)?.unwrap_or_default();
+        let post_supply: token::Amount =
+            ctx.read_post(&supply_key)?.unwrap_or_default();
+        let rate = ShareExchangeRate {
+            pool_value: pre_value,
+            share_supply: pre_supply,
+        };
+
+        if post_value >= pre_value {
+            // The bonded tokens must be issued the shares they are worth
+            let bonded = checked!(post_value - pre_value)?;
+            let expected = rate.shares_for_tokens(bonded).ok_or_else(|| {
+                Error::new_alloc(format!(
+                    "The liquid staking pool of the validator {validator} is \
+                     depleted"
+                ))
+            })?;
+            if post_supply.checked_sub(pre_supply) != Some(expected) {
+                return Err(Error::new_alloc(format!(
+                    "The shares of the liquid staking pool of the validator \
+                     {validator} don't match the bonded amount {}, expected \
+                     {} to be minted",
+                    bonded.to_string_native(),
+                    expected.to_string_native()
+                )));
+            }
+        } else {
+            // The unbonded tokens cannot be worth more than the burned shares
+            let unbonded = checked!(pre_value - post_value)?;
+            let burned =
+                pre_supply.checked_sub(post_supply).ok_or_else(|| {
+                    Error::new_alloc(format!(
+                        "Shares of the liquid staking pool of the validator \
+                         {validator} cannot be minted for an unbond"
+                    ))
+                })?;
+            let value = rate.tokens_for_shares(burned).unwrap_or_default();
+            if unbonded > value {
+                return Err(Error::new_alloc(format!(
+                    "The unbonded amount {} from the liquid staking pool of \
+                     the validator {validator} exceeds the value {} of the \
+                     burned shares",
+                    unbonded.to_string_native(),
+                    value.to_string_native()
+                )));
+            }
+        }
+        Ok(())
+    }
+
     /// Return `Ok` if the changed parameters are valid
     fn is_valid_parameter_change(ctx: &'ctx CTX) -> Result<()> {
         let validation_errors: Vec<crate::parameters::ValidationError> =
@@ -326,6 +326,257 @@ impl TxTransparentTransfer {
     }
 }
 
+/// Token allowance approval transaction arguments
+#[derive(Clone, Debug)]
+pub struct TxApprove<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// The owner of the tokens
+    pub owner: C::Address,
+    /// The spender allowed to transfer the tokens of the owner
+    pub spender: C::Address,
+    /// The approved token address
+    pub token: C::Address,
+    /// The approved token amount. A zero amount revokes the allowance.
+    pub amount: InputAmount,
+    /// The last epoch in which the allowance can be spent, if any
+    pub expiry: Option<Epoch>,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for TxApprove<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        TxApprove {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl<C: NamadaTypes> TxApprove<C> {
+    /// The owner of the tokens
+    pub fn owner(self, owner: C::Address) -> Self {
+        Self { owner, ..self }
+    }
+
+    /// The spender allowed to transfer the tokens of the owner
+    pub fn spender(self, spender: C::Address) -> Self {
+        Self { spender, ..self }
+    }
+
+    /// The approved token address
+    pub fn token(self, token: C::Address) -> Self {
+        Self { token, ..self }
+    }
+
+    /// The approved token amount
+    pub fn amount(self, amount: InputAmount) -> Self {
+        Self { amount, ..self }
+    }
+
+    /// The last epoch in which the allowance can be spent
+    pub fn expiry(self, expiry: Epoch) -> Self {
+        Self {
+            expiry: Some(expiry),
+            ..self
+        }
+    }
+
+    /// Path to the TX WASM code file
+    pub fn tx_code_path(self, tx_code_path: PathBuf) -> Self {
+        Self {
+            tx_code_path,
+            ..self
+        }
+    }
+}
+
+impl TxApprove {
+    /// Build a transaction from this builder
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        tx::build_approve(context, self).await
+    }
+}
+
+/// Transfer from an allowance transaction arguments
+#[derive(Clone, Debug)]
+pub struct TxTransferFrom<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// The owner of the tokens
+    pub owner: C::Address,
+    /// The spender transferring the tokens of the owner
+    pub spender: C::Address,
+    /// Transfer target address
+    pub target: C::Address,
+    /// Transferred token address
+    pub token: C::Address,
+    /// Transferred token amount
+    pub amount: InputAmount,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for TxTransferFrom<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        TxTransferFrom {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl<C: NamadaTypes> TxTransferFrom<C> {
+    /// The owner of the tokens
+    pub fn owner(self, owner: C::Address) -> Self {
+        Self { owner, ..self }
+    }
+
+    /// The spender transferring the tokens of the owner
+    pub fn spender(self, spender: C::Address) -> Self {
+        Self { spender, ..self }
+    }
+
+    /// Transfer target address
+    pub fn receiver(self, target: C::Address) -> Self {
+        Self { target, ..self }
+    }
+
+    /// Transferred token address
+    pub fn token(self, token: C::Address) -> Self {
+        Self { token, ..self }
+    }
+
+    /// Transferred token amount
+    pub fn amount(self, amount: InputAmount) -> Self {
+        Self { amount, ..self }
+    }
+
+    /// Path to the TX WASM code file
+    pub fn tx_code_path(self, tx_code_path: PathBuf) -> Self {
+        Self {
+            tx_code_path,
+            ..self
+        }
+    }
+}
+
+impl TxTransferFrom {
+    /// Build a transaction from this builder
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        tx::build_transfer_from(context, self).await
+    }
+}
+
+/// Vesting transfer transaction arguments
+#[derive(Clone, Debug)]
+pub struct TxVestingTransfer<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// Transfer source address
+    pub source: C::Address,
+    /// Transfer target address, whose received tokens are locked
+    pub target: C::Address,
+    /// Transferred token address
+    pub token: C::Address,
+    /// Transferred and vested token amount
+    pub amount: InputAmount,
+    /// The epoch from which the amount starts vesting
+    pub start_epoch: Epoch,
+    /// The first epoch in which any of the amount is unlocked
+    pub cliff_epoch: Epoch,
+    /// The epoch from which the whole amount is unlocked
+    pub end_epoch: Epoch,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for TxVestingTransfer<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        TxVestingTransfer {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl<C: NamadaTypes> TxVestingTransfer<C> {
+    /// Transfer source address
+    pub fn source(self, source: C::Address) -> Self {
+        Self { source, ..self }
+    }
+
+    /// Transfer target address
+    pub fn receiver(self, target: C::Address) -> Self {
+        Self { target, ..self }
+    }
+
+    /// Transferred token address
+    pub fn token(self, token: C::Address) -> Self {
+        Self { token, ..self }
+    }
+
+    /// Transferred and vested token amount
+    pub fn amount(self, amount: InputAmount) -> Self {
+        Self { amount, ..self }
+    }
+
+    /// The epoch from which the amount starts vesting
+    pub fn start_epoch(self, start_epoch: Epoch) -> Self {
+        Self {
+            start_epoch,
+            ..self
+        }
+    }
+
+    /// The first epoch in which any of the amount is unlocked
+    pub fn cliff_epoch(self, cliff_epoch: Epoch) -> Self {
+        Self {
+            cliff_epoch,
+            ..self
+        }
+    }
+
+    /// The epoch from which the whole amount is unlocked
+    pub fn end_epoch(self, end_epoch: Epoch) -> Self {
+        Self { end_epoch, ..self }
+    }
+
+    /// Path to the TX WASM code file
+    pub fn tx_code_path(self, tx_code_path: PathBuf) -> Self {
+        Self {
+            tx_code_path,
+            ..self
+        }
+    }
+}
+
+impl TxVestingTransfer {
+    /// Build a transaction from this builder
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        tx::build_vesting_transfer(context, self).await
+    }
+}
+
 /// Shielded transfer-specific arguments
 #[derive(Clone, Debug)]
 pub struct TxShieldedTransferData<C: NamadaTypes = SdkTypes> {
@@ -829,56 +1080,50 @@ impl VoteProposal {
     }
 }
 
-/// Transaction to initialize a new account
+/// Transaction to top up the deposit of a governance proposal
 #[derive(Clone, Debug)]
-pub struct TxInitAccount<C: NamadaTypes = SdkTypes> {
+pub struct DepositProposal<C: NamadaTypes = SdkTypes> {
     /// Common tx arguments
     pub tx: Tx<C>,
-    /// Path to the VP WASM code file for the new account
-    pub vp_code_path: PathBuf,
+    /// Proposal id
+    pub proposal_id: u64,
+    /// The address depositing the funds
+    pub depositor: C::Address,
+    /// The amount of native tokens to deposit
+    pub amount: token::Amount,
     /// Path to the TX WASM code file
     pub tx_code_path: PathBuf,
-    /// Public key for the new account
-    pub public_keys: Vec<C::PublicKey>,
-    /// The account multisignature threshold
-    pub threshold: Option<u8>,
 }
 
-impl<C: NamadaTypes> TxBuilder<C> for TxInitAccount<C> {
+impl<C: NamadaTypes> TxBuilder<C> for DepositProposal<C> {
     fn tx<F>(self, func: F) -> Self
     where
         F: FnOnce(Tx<C>) -> Tx<C>,
     {
-        TxInitAccount {
+        DepositProposal {
             tx: func(self.tx),
             ..self
         }
     }
 }
 
-impl<C: NamadaTypes> TxInitAccount<C> {
-    /// A vector of public key to associate with the new account
-    pub fn public_keys(self, public_keys: Vec<C::PublicKey>) -> Self {
+impl<C: NamadaTypes> DepositProposal<C> {
+    /// Proposal id
+    pub fn proposal_id(self, proposal_id: u64) -> Self {
         Self {
-            public_keys,
+            proposal_id,
             ..self
         }
     }
 
-    /// A threshold to associate with the new account
-    pub fn threshold(self, threshold: u8) -> Self {
-        Self {
-            threshold: Some(threshold),
-            ..self
-        }
+    /// The address depositing the funds
+    pub fn depositor(self, depositor: C::Address) -> Self {
+        Self { depositor, ..self }
     }
 
-    /// Path to the VP WASM code file
-    pub fn vp_code_path(self, vp_code_path: PathBuf) -> Self {
-        Self {
-            vp_code_path,
-            ..self
-        }
+    /// The amount of native tokens to deposit
+    pub fn amount(self, amount: token::Amount) -> Self {
+        Self { amount, ..self }
     }
 
     /// Path to the TX WASM code file
@@ -890,50 +1135,312 @@ impl<C: NamadaTypes> TxInitAccount<C> {
     }
 }
 
-impl TxInitAccount {
+impl DepositProposal {
     /// Build a transaction from this builder
     pub async fn build(
         &self,
         context: &impl Namada,
     ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
-        tx::build_init_account(context, self).await
+        tx::build_deposit_proposal(context, self).await
     }
 }
 
-/// Transaction to initialize a new account
+/// Transaction to cancel a governance proposal before its voting start epoch
 #[derive(Clone, Debug)]
-pub struct TxBecomeValidator<C: NamadaTypes = SdkTypes> {
+pub struct CancelProposal<C: NamadaTypes = SdkTypes> {
     /// Common tx arguments
     pub tx: Tx<C>,
-    /// Address of an account that will become a validator.
-    pub address: C::Address,
-    /// Signature scheme
-    pub scheme: SchemeType,
-    /// Consensus key
-    pub consensus_key: Option<C::PublicKey>,
-    /// Ethereum cold key
-    pub eth_cold_key: Option<C::PublicKey>,
-    /// Ethereum hot key
-    pub eth_hot_key: Option<C::PublicKey>,
-    /// Protocol key
-    pub protocol_key: Option<C::PublicKey>,
-    /// Commission rate
-    pub commission_rate: Dec,
-    /// Maximum commission rate change
-    pub max_commission_rate_change: Dec,
-    /// The validator email
-    pub email: String,
-    /// The validator description
-    pub description: Option<String>,
-    /// The validator website
-    pub website: Option<String>,
-    /// The validator's discord handle
-    pub discord_handle: Option<String>,
-    /// The validator's avatar
-    pub avatar: Option<String>,
-    /// The validator's name
-    pub name: Option<String>,
-    /// Path to the TX WASM code file
+    /// Proposal id
+    pub proposal_id: u64,
+    /// The author of the proposal
+    pub author: C::Address,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for CancelProposal<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        CancelProposal {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl<C: NamadaTypes> CancelProposal<C> {
+    /// Proposal id
+    pub fn proposal_id(self, proposal_id: u64) -> Self {
+        Self {
+            proposal_id,
+            ..self
+        }
+    }
+
+    /// The author of the proposal
+    pub fn author(self, author: C::Address) -> Self {
+        Self { author, ..self }
+    }
+
+    /// Path to the TX WASM code file
+    pub fn tx_code_path(self, tx_code_path: PathBuf) -> Self {
+        Self {
+            tx_code_path,
+            ..self
+        }
+    }
+}
+
+impl CancelProposal {
+    /// Build a transaction from this builder
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        tx::build_cancel_proposal(context, self).await
+    }
+}
+
+/// Transaction to amend the content of a governance proposal before its
+/// voting start epoch
+#[derive(Clone, Debug)]
+pub struct AmendProposal<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// Proposal id
+    pub proposal_id: u64,
+    /// The author of the proposal
+    pub author: C::Address,
+    /// The new proposal content (json)
+    pub content: C::Data,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for AmendProposal<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        AmendProposal {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl<C: NamadaTypes> AmendProposal<C> {
+    /// Proposal id
+    pub fn proposal_id(self, proposal_id: u64) -> Self {
+        Self {
+            proposal_id,
+            ..self
+        }
+    }
+
+    /// The author of the proposal
+    pub fn author(self, author: C::Address) -> Self {
+        Self { author, ..self }
+    }
+
+    /// The new proposal content (json)
+    pub fn content(self, content: C::Data) -> Self {
+        Self { content, ..self }
+    }
+
+    /// Path to the TX WASM code file
+    pub fn tx_code_path(self, tx_code_path: PathBuf) -> Self {
+        Self {
+            tx_code_path,
+            ..self
+        }
+    }
+}
+
+impl AmendProposal {
+    /// Build a transaction from this builder
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        tx::build_amend_proposal(context, self).await
+    }
+}
+
+/// Transaction to delegate the governance votes of an address to a
+/// representative
+#[derive(Clone, Debug)]
+pub struct DelegateVotes<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// The address delegating its votes
+    pub delegator: C::Address,
+    /// The representative, or `None` to revoke the delegation
+    pub representative: Option<C::Address>,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for DelegateVotes<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        DelegateVotes {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl<C: NamadaTypes> DelegateVotes<C> {
+    /// The address delegating its votes
+    pub fn delegator(self, delegator: C::Address) -> Self {
+        Self { delegator, ..self }
+    }
+
+    /// The representative, or `None` to revoke the delegation
+    pub fn representative(self, representative: Option<C::Address>) -> Self {
+        Self {
+            representative,
+            ..self
+        }
+    }
+
+    /// Path to the TX WASM code file
+    pub fn tx_code_path(self, tx_code_path: PathBuf) -> Self {
+        Self {
+            tx_code_path,
+            ..self
+        }
+    }
+}
+
+impl DelegateVotes {
+    /// Build a transaction from this builder
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        tx::build_delegate_votes(context, self).await
+    }
+}
+
+/// Transaction to initialize a new account
+#[derive(Clone, Debug)]
+pub struct TxInitAccount<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// Path to the VP WASM code file for the new account
+    pub vp_code_path: PathBuf,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+    /// Public key for the new account
+    pub public_keys: Vec<C::PublicKey>,
+    /// The account multisignature threshold
+    pub threshold: Option<u8>,
+    /// The weights of the public keys, in the same order. If empty, every
+    /// key has a weight of 1
+    pub weights: Vec<u8>,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for TxInitAccount<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        TxInitAccount {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl<C: NamadaTypes> TxInitAccount<C> {
+    /// A vector of public key to associate with the new account
+    pub fn public_keys(self, public_keys: Vec<C::PublicKey>) -> Self {
+        Self {
+            public_keys,
+            ..self
+        }
+    }
+
+    /// A threshold to associate with the new account
+    pub fn threshold(self, threshold: u8) -> Self {
+        Self {
+            threshold: Some(threshold),
+            ..self
+        }
+    }
+
+    /// The weights of the public keys of the new account
+    pub fn weights(self, weights: Vec<u8>) -> Self {
+        Self { weights, ..self }
+    }
+
+    /// Path to the VP WASM code file
+    pub fn vp_code_path(self, vp_code_path: PathBuf) -> Self {
+        Self {
+            vp_code_path,
+            ..self
+        }
+    }
+
+    /// Path to the TX WASM code file
+    pub fn tx_code_path(self, tx_code_path: PathBuf) -> Self {
+        Self {
+            tx_code_path,
+            ..self
+        }
+    }
+}
+
+impl TxInitAccount {
+    /// Build a transaction from this builder
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        tx::build_init_account(context, self).await
+    }
+}
+
+/// Transaction to initialize a new account
+#[derive(Clone, Debug)]
+pub struct TxBecomeValidator<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// Address of an account that will become a validator.
+    pub address: C::Address,
+    /// Signature scheme
+    pub scheme: SchemeType,
+    /// Consensus key
+    pub consensus_key: Option<C::PublicKey>,
+    /// Ethereum cold key
+    pub eth_cold_key: Option<C::PublicKey>,
+    /// Ethereum hot key
+    pub eth_hot_key: Option<C::PublicKey>,
+    /// Protocol key
+    pub protocol_key: Option<C::PublicKey>,
+    /// Commission rate
+    pub commission_rate: Dec,
+    /// Maximum commission rate change
+    pub max_commission_rate_change: Dec,
+    /// The validator email
+    pub email: String,
+    /// The validator description
+    pub description: Option<String>,
+    /// The validator website
+    pub website: Option<String>,
+    /// The validator's discord handle
+    pub discord_handle: Option<String>,
+    /// The validator's avatar
+    pub avatar: Option<String>,
+    /// The validator's name
+    pub name: Option<String>,
+    /// Path to the TX WASM code file
     pub tx_code_path: PathBuf,
     /// Don't encrypt the keypair
     pub unsafe_dont_encrypt: bool,
@@ -1060,6 +1567,9 @@ pub struct TxUpdateAccount<C: NamadaTypes = SdkTypes> {
     pub public_keys: Vec<C::PublicKey>,
     /// The account threshold
     pub threshold: Option<u8>,
+    /// The weights of the public keys, in the same order. If empty, every
+    /// key has a weight of 1
+    pub weights: Vec<u8>,
 }
 
 impl<C: NamadaTypes> TxBuilder<C> for TxUpdateAccount<C> {
@@ -1091,35 +1601,292 @@ impl<C: NamadaTypes> TxUpdateAccount<C> {
         }
     }
 
-    /// Address of the account whose VP is to be updated
+    /// Address of the account whose VP is to be updated
+    pub fn addr(self, addr: C::Address) -> Self {
+        Self { addr, ..self }
+    }
+
+    /// Public keys
+    pub fn public_keys(self, public_keys: Vec<C::PublicKey>) -> Self {
+        Self {
+            public_keys,
+            ..self
+        }
+    }
+
+    /// The account threshold
+    pub fn threshold(self, threshold: u8) -> Self {
+        Self {
+            threshold: Some(threshold),
+            ..self
+        }
+    }
+
+    /// The weights of the public keys
+    pub fn weights(self, weights: Vec<u8>) -> Self {
+        Self { weights, ..self }
+    }
+}
+
+impl TxUpdateAccount {
+    /// Build a transaction from this builder
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        tx::build_update_account(context, self).await
+    }
+}
+
+/// Transaction to configure the social recovery of an account arguments
+#[derive(Clone, Debug)]
+pub struct TxConfigureRecovery<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// Address of the account
+    pub addr: C::Address,
+    /// The guardians that can recover the keys of the account. No guardians
+    /// opt the account out of social recovery.
+    pub guardians: Vec<C::Address>,
+    /// The minimum number of guardians that must approve a recovery
+    pub threshold: u8,
+    /// The number of epochs after which an initiated recovery can be
+    /// finalized
+    pub delay: u64,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for TxConfigureRecovery<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        TxConfigureRecovery {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl<C: NamadaTypes> TxConfigureRecovery<C> {
+    /// Address of the account
+    pub fn addr(self, addr: C::Address) -> Self {
+        Self { addr, ..self }
+    }
+
+    /// The guardians that can recover the keys of the account
+    pub fn guardians(self, guardians: Vec<C::Address>) -> Self {
+        Self { guardians, ..self }
+    }
+
+    /// The minimum number of guardians that must approve a recovery
+    pub fn threshold(self, threshold: u8) -> Self {
+        Self { threshold, ..self }
+    }
+
+    /// The number of epochs after which an initiated recovery can be
+    /// finalized
+    pub fn delay(self, delay: u64) -> Self {
+        Self { delay, ..self }
+    }
+
+    /// Path to the TX WASM code file
+    pub fn tx_code_path(self, tx_code_path: PathBuf) -> Self {
+        Self {
+            tx_code_path,
+            ..self
+        }
+    }
+}
+
+impl TxConfigureRecovery {
+    /// Build a transaction from this builder
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        tx::build_configure_recovery(context, self).await
+    }
+}
+
+/// Transaction for the guardians of an account to initiate the recovery of
+/// its keys arguments
+#[derive(Clone, Debug)]
+pub struct TxInitiateRecovery<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// Address of the recovered account
+    pub addr: C::Address,
+    /// The guardians that approve the recovery. They must all sign the tx.
+    pub guardians: Vec<C::Address>,
+    /// The new public keys of the account
+    pub public_keys: Vec<C::PublicKey>,
+    /// The new account threshold
+    pub threshold: Option<u8>,
+    /// The weights of the new public keys, in the same order. If empty,
+    /// every key has a weight of 1
+    pub weights: Vec<u8>,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for TxInitiateRecovery<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        TxInitiateRecovery {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl<C: NamadaTypes> TxInitiateRecovery<C> {
+    /// Address of the recovered account
+    pub fn addr(self, addr: C::Address) -> Self {
+        Self { addr, ..self }
+    }
+
+    /// The guardians that approve the recovery
+    pub fn guardians(self, guardians: Vec<C::Address>) -> Self {
+        Self { guardians, ..self }
+    }
+
+    /// The new public keys of the account
+    pub fn public_keys(self, public_keys: Vec<C::PublicKey>) -> Self {
+        Self {
+            public_keys,
+            ..self
+        }
+    }
+
+    /// The new account threshold
+    pub fn threshold(self, threshold: u8) -> Self {
+        Self {
+            threshold: Some(threshold),
+            ..self
+        }
+    }
+
+    /// The weights of the new public keys
+    pub fn weights(self, weights: Vec<u8>) -> Self {
+        Self { weights, ..self }
+    }
+
+    /// Path to the TX WASM code file
+    pub fn tx_code_path(self, tx_code_path: PathBuf) -> Self {
+        Self {
+            tx_code_path,
+            ..self
+        }
+    }
+}
+
+impl TxInitiateRecovery {
+    /// Build a transaction from this builder
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        tx::build_initiate_recovery(context, self).await
+    }
+}
+
+/// Transaction to cancel the pending recovery of an account arguments
+#[derive(Clone, Debug)]
+pub struct TxCancelRecovery<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// Address of the recovered account
+    pub addr: C::Address,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for TxCancelRecovery<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        TxCancelRecovery {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl<C: NamadaTypes> TxCancelRecovery<C> {
+    /// Address of the recovered account
+    pub fn addr(self, addr: C::Address) -> Self {
+        Self { addr, ..self }
+    }
+
+    /// Path to the TX WASM code file
+    pub fn tx_code_path(self, tx_code_path: PathBuf) -> Self {
+        Self {
+            tx_code_path,
+            ..self
+        }
+    }
+}
+
+impl TxCancelRecovery {
+    /// Build a transaction from this builder
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        tx::build_cancel_recovery(context, self).await
+    }
+}
+
+/// Transaction to finalize the pending recovery of an account arguments
+#[derive(Clone, Debug)]
+pub struct TxFinalizeRecovery<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// Address of the recovered account
+    pub addr: C::Address,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for TxFinalizeRecovery<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        TxFinalizeRecovery {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl<C: NamadaTypes> TxFinalizeRecovery<C> {
+    /// Address of the recovered account
     pub fn addr(self, addr: C::Address) -> Self {
         Self { addr, ..self }
     }
 
-    /// Public keys
-    pub fn public_keys(self, public_keys: Vec<C::PublicKey>) -> Self {
-        Self {
-            public_keys,
-            ..self
-        }
-    }
-
-    /// The account threshold
-    pub fn threshold(self, threshold: u8) -> Self {
+    /// Path to the TX WASM code file
+    pub fn tx_code_path(self, tx_code_path: PathBuf) -> Self {
         Self {
-            threshold: Some(threshold),
+            tx_code_path,
             ..self
         }
     }
 }
 
-impl TxUpdateAccount {
+impl TxFinalizeRecovery {
     /// Build a transaction from this builder
     pub async fn build(
         &self,
         context: &impl Namada,
     ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
-        tx::build_update_account(context, self).await
+        tx::build_finalize_recovery(context, self).await
     }
 }
 
@@ -1451,6 +2218,24 @@ pub struct QueryProposalVotes<C: NamadaTypes = SdkTypes> {
     pub voter: Option<C::Address>,
 }
 
+/// Query the deposits locked in a proposal
+#[derive(Clone, Debug)]
+pub struct QueryProposalDeposits<C: NamadaTypes = SdkTypes> {
+    /// Common query args
+    pub query: Query<C>,
+    /// Proposal id
+    pub proposal_id: u64,
+}
+
+/// Query the governance vote delegation of an address
+#[derive(Clone, Debug)]
+pub struct QueryVoteDelegation<C: NamadaTypes = SdkTypes> {
+    /// Common query args
+    pub query: Query<C>,
+    /// The address whose representative and constituents to query
+    pub address: C::Address,
+}
+
 /// Query proposal
 #[derive(Clone, Debug)]
 pub struct QueryProposal<C: NamadaTypes = SdkTypes> {
@@ -1570,6 +2355,191 @@ impl ClaimRewards {
     }
 }
 
+/// Auto-compound arguments
+#[derive(Clone, Debug)]
+pub struct AutoCompound<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// Validator address
+    pub validator: C::Address,
+    /// Source address of the bond whose rewards are compounded. For
+    /// self-bonds, the validator is also the source
+    pub source: Option<C::Address>,
+    /// Whether the rewards of the bond are automatically re-bonded
+    pub enabled: bool,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for AutoCompound<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        AutoCompound {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl<C: NamadaTypes> AutoCompound<C> {
+    /// Source address of the bond whose rewards are compounded
+    pub fn source(self, source: C::Address) -> Self {
+        Self {
+            source: Some(source),
+            ..self
+        }
+    }
+
+    /// Whether the rewards of the bond are automatically re-bonded
+    pub fn enabled(self, enabled: bool) -> Self {
+        Self { enabled, ..self }
+    }
+
+    /// Path to the TX WASM code file
+    pub fn tx_code_path(self, tx_code_path: PathBuf) -> Self {
+        Self {
+            tx_code_path,
+            ..self
+        }
+    }
+}
+
+impl AutoCompound {
+    /// Build a transaction from this builder
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        tx::build_auto_compound(context, self).await
+    }
+}
+
+/// Liquid bond arguments
+#[derive(Clone, Debug)]
+pub struct LiquidBond<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// Validator address
+    pub validator: C::Address,
+    /// Amount of tokens to bond to the liquid staking pool
+    pub amount: token::Amount,
+    /// Source address of the bonded tokens, which receives the shares
+    pub source: C::Address,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for LiquidBond<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        LiquidBond {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl<C: NamadaTypes> LiquidBond<C> {
+    /// Validator address
+    pub fn validator(self, validator: C::Address) -> Self {
+        Self { validator, ..self }
+    }
+
+    /// Amount of tokens to bond to the liquid staking pool
+    pub fn amount(self, amount: token::Amount) -> Self {
+        Self { amount, ..self }
+    }
+
+    /// Source address of the bonded tokens
+    pub fn source(self, source: C::Address) -> Self {
+        Self { source, ..self }
+    }
+
+    /// Path to the TX WASM code file
+    pub fn tx_code_path(self, tx_code_path: PathBuf) -> Self {
+        Self {
+            tx_code_path,
+            ..self
+        }
+    }
+}
+
+impl LiquidBond {
+    /// Build a transaction from this builder
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        tx::build_liquid_bond(context, self).await
+    }
+}
+
+/// Liquid unbond arguments
+#[derive(Clone, Debug)]
+pub struct LiquidUnbond<C: NamadaTypes = SdkTypes> {
+    /// Common tx arguments
+    pub tx: Tx<C>,
+    /// Validator address
+    pub validator: C::Address,
+    /// Amount of liquid staking shares to redeem
+    pub shares: token::Amount,
+    /// Owner of the redeemed shares, which receives the unbonded tokens
+    pub source: C::Address,
+    /// Path to the TX WASM code file
+    pub tx_code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for LiquidUnbond<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        LiquidUnbond {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl<C: NamadaTypes> LiquidUnbond<C> {
+    /// Validator address
+    pub fn validator(self, validator: C::Address) -> Self {
+        Self { validator, ..self }
+    }
+
+    /// Amount of liquid staking shares to redeem
+    pub fn shares(self, shares: token::Amount) -> Self {
+        Self { shares, ..self }
+    }
+
+    /// Owner of the redeemed shares
+    pub fn source(self, source: C::Address) -> Self {
+        Self { source, ..self }
+    }
+
+    /// Path to the TX WASM code file
+    pub fn tx_code_path(self, tx_code_path: PathBuf) -> Self {
+        Self {
+            tx_code_path,
+            ..self
+        }
+    }
+}
+
+impl LiquidUnbond {
+    /// Build a transaction from this builder
+    pub async fn build(
+        &self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        tx::build_liquid_unbond(context, self).await
+    }
+}
+
 /// Query asset conversions
 #[derive(Clone, Debug)]
 pub struct QueryConversions<C: NamadaTypes = SdkTypes> {
@@ -1592,6 +2562,19 @@ pub struct QueryAccount<C: NamadaTypes = SdkTypes> {
     pub owner: C::Address,
 }
 
+/// Query the tx history of an address
+#[derive(Clone, Debug)]
+pub struct QueryTxHistory<C: NamadaTypes = SdkTypes> {
+    /// Common query args
+    pub query: Query<C>,
+    /// Address of an owner
+    pub owner: C::Address,
+    /// The page to query, starting from 0
+    pub page: u64,
+    /// The number of entries per page
+    pub per_page: u64,
+}
+
 /// Query token balance(s)
 #[derive(Clone, Debug)]
 pub struct QueryBalance<C: NamadaTypes = SdkTypes> {
@@ -1607,6 +2590,30 @@ pub struct QueryBalance<C: NamadaTypes = SdkTypes> {
     pub height: Option<C::BlockHeight>,
 }
 
+/// Query the allowance of a spender to transfer the tokens of an owner
+#[derive(Clone, Debug)]
+pub struct QueryAllowance<C: NamadaTypes = SdkTypes> {
+    /// Common query args
+    pub query: Query<C>,
+    /// Address of the owner
+    pub owner: C::Address,
+    /// Address of the spender
+    pub spender: C::Address,
+    /// Address of the token
+    pub token: C::Address,
+}
+
+/// Query the locked and spendable parts of a vested token balance
+#[derive(Clone, Debug)]
+pub struct QueryVesting<C: NamadaTypes = SdkTypes> {
+    /// Common query args
+    pub query: Query<C>,
+    /// Address of the owner
+    pub owner: C::Address,
+    /// Address of the token
+    pub token: C::Address,
+}
+
 /// Get an estimate for the MASP rewards accumulated by the next
 /// MASP epoch.
 #[derive(Clone, Debug)]
@@ -1795,6 +2802,10 @@ pub struct MetaDataChange<C: NamadaTypes = SdkTypes> {
     pub avatar: Option<String>,
     /// New validator name
     pub name: Option<String>,
+    /// New validator reward destination, with the ratio of the commission
+    /// going to each address. An empty list resets the destination to the
+    /// validator itself.
+    pub reward_destination: Option<Vec<(C::Address, Dec)>>,
     /// New validator commission rate
     pub commission_rate: Option<Dec>,
     /// Path to the TX WASM code file
@@ -1875,6 +2886,17 @@ impl<C: NamadaTypes> MetaDataChange<C> {
         }
     }
 
+    /// New validator reward destination
+    pub fn reward_destination(
+        self,
+        reward_destination: Vec<(C::Address, Dec)>,
+    ) -> Self {
+        Self {
+            reward_destination: Some(reward_destination),
+            ..self
+        }
+    }
+
     /// New validator commission rate
     pub fn commission_rate(self, commission_rate: Dec) -> Self {
         Self {
@@ -2211,6 +3233,17 @@ pub struct QuerySlashes<C: NamadaTypes = SdkTypes> {
     pub validator: Option<C::Address>,
 }
 
+/// Query the report of a PoS slash
+#[derive(Clone, Debug)]
+pub struct QuerySlashReport<C: NamadaTypes = SdkTypes> {
+    /// Common query args
+    pub query: Query<C>,
+    /// Address of the slashed validator
+    pub validator: C::Address,
+    /// Block height of the infraction
+    pub block_height: BlockHeight,
+}
+
 /// Query PoS rewards
 #[derive(Clone, Debug)]
 pub struct QueryRewards<C: NamadaTypes = SdkTypes> {
@@ -2719,6 +3752,9 @@ pub struct EthereumBridgePool<C: NamadaTypes = SdkTypes> {
     pub fee_payer: Option<C::Address>,
     /// The token in which the gas is being paid
     pub fee_token: C::AddrOrNativeToken,
+    /// The block height from which the transfer is refunded,
+    /// if it hasn't been relayed yet.
+    pub expiry: Option<BlockHeight>,
     /// Path to the tx WASM code file
     pub code_path: PathBuf,
 }
@@ -2787,6 +3823,15 @@ impl<C: NamadaTypes> EthereumBridgePool<C> {
         }
     }
 
+    /// The block height from which the transfer is refunded,
+    /// if it hasn't been relayed yet.
+    pub fn expiry(self, expiry: BlockHeight) -> Self {
+        Self {
+            expiry: Some(expiry),
+            ..self
+        }
+    }
+
     /// Path to the tx WASM code file
     pub fn code_path(self, code_path: PathBuf) -> Self {
         Self { code_path, ..self }
@@ -2803,6 +3848,59 @@ impl EthereumBridgePool {
     }
 }
 
+/// A bump of the relayer fee of a transfer in the Ethereum bridge pool.
+#[derive(Clone, Debug)]
+pub struct BumpBridgePoolFee<C: NamadaTypes = SdkTypes> {
+    /// The args for building a tx to the bridge pool
+    pub tx: Tx<C>,
+    /// The keccak hash of the pending transfer
+    pub transfer: KeccakHash,
+    /// The amount to add to the gas fees of the transfer,
+    /// paid in the same token by its gas payer
+    pub amount: InputAmount,
+    /// Path to the tx WASM code file
+    pub code_path: PathBuf,
+}
+
+impl<C: NamadaTypes> TxBuilder<C> for BumpBridgePoolFee<C> {
+    fn tx<F>(self, func: F) -> Self
+    where
+        F: FnOnce(Tx<C>) -> Tx<C>,
+    {
+        BumpBridgePoolFee {
+            tx: func(self.tx),
+            ..self
+        }
+    }
+}
+
+impl<C: NamadaTypes> BumpBridgePoolFee<C> {
+    /// The keccak hash of the pending transfer
+    pub fn transfer(self, transfer: KeccakHash) -> Self {
+        Self { transfer, ..self }
+    }
+
+    /// The amount to add to the gas fees of the transfer
+    pub fn amount(self, amount: InputAmount) -> Self {
+        Self { amount, ..self }
+    }
+
+    /// Path to the tx WASM code file
+    pub fn code_path(self, code_path: PathBuf) -> Self {
+        Self { code_path, ..self }
+    }
+}
+
+impl BumpBridgePoolFee {
+    /// Build a transaction from this builder
+    pub async fn build(
+        self,
+        context: &impl Namada,
+    ) -> crate::error::Result<(namada_tx::Tx, SigningTxData)> {
+        bridge_pool::build_bump_bridge_pool_fee_tx(context, self).await
+    }
+}
+
 /// Bridge pool proof arguments.
 #[derive(Debug, Clone)]
 pub struct BridgePoolProof<C: NamadaTypes = SdkTypes> {
@@ -1,9 +1,10 @@
 //! Generic Error Type for all of the Shared Crate
 
 use namada_core::address::Address;
-use namada_core::chain::Epoch;
+use namada_core::chain::{BlockHeight, Epoch};
 use namada_core::dec::Dec;
 use namada_core::ethereum_events::EthAddress;
+use namada_core::keccak::KeccakHash;
 use namada_core::{arith, storage};
 use namada_events::EventError;
 use namada_tx::Tx;
@@ -193,6 +194,16 @@ pub enum TxSubmitError {
          to be transferred. Amount to transfer is {2} and the balance is {3}."
     )]
     BalanceTooLow(Address, Address, String, String),
+    /// Allowance is too low
+    #[error(
+        "The allowance of the spender {0} to transfer the token {1} of {2} is \
+         lower than the amount to be transferred. Amount to transfer is {3} \
+         and the allowance is {4}."
+    )]
+    AllowanceTooLow(Address, Address, Address, String, String),
+    /// Vesting schedule is malformed
+    #[error("Invalid vesting schedule: {0}")]
+    InvalidVestingSchedule(String),
     /// Balance is too low for fee payment
     #[error(
         "The balance of the source {0} of token {1} is lower than the amount \
@@ -276,9 +287,18 @@ pub enum TxSubmitError {
     /// Account threshold is not set
     #[error("Account threshold is invalid.")]
     InvalidAccountThreshold,
+    /// Account public key weights are invalid
+    #[error(
+        "Account public key weights are invalid: there must be one non-zero \
+         weight for each public key."
+    )]
+    InvalidAccountWeights,
     /// Not enough signature
-    #[error("Account threshold is {0} but the valid signatures are {1}.")]
-    MissingSigningKeys(u8, u8),
+    #[error(
+        "Account threshold is {0} but the weight of the valid signatures is \
+         {1}."
+    )]
+    MissingSigningKeys(u8, u32),
     /// Invalid owner account
     #[error("The source account {0} is not valid or doesn't exist.")]
     InvalidAccount(String),
@@ -310,6 +330,9 @@ pub enum TxSubmitError {
     /// The metadata string is too long
     #[error("The provided metadata string is too long")]
     MetadataTooLong,
+    /// The reward destination doesn't split the whole commission
+    #[error("The provided reward destination is invalid")]
+    InvalidRewardDestination,
     /// The consensus key is not Ed25519
     #[error("The consensus key must be an ed25519 key")]
     ConsensusKeyNotEd25519,
@@ -369,4 +392,10 @@ pub enum EthereumBridgeError {
     /// Transfer already in pool error.
     #[error("An identical transfer is already present in the Bridge pool")]
     TransferAlreadyInPool,
+    /// Transfer missing from pool error.
+    #[error("Transfer {0} is not present in the Bridge pool")]
+    TransferNotInPool(KeccakHash),
+    /// Expired transfer error.
+    #[error("The expiry height {0} of the transfer has already been reached")]
+    TransferExpired(BlockHeight),
 }
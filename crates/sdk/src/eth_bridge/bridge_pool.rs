@@ -11,16 +11,19 @@ use ethers::providers::Middleware;
 use futures::future::FutureExt;
 use namada_core::address::{Address, InternalAddress};
 use namada_core::arith::checked;
+use namada_core::chain::BlockHeight;
 use namada_core::collections::{HashMap, HashSet};
 use namada_core::eth_abi::Encode;
 use namada_core::eth_bridge_pool::{
-    erc20_token_address, GasFee, PendingTransfer, TransferToEthereum,
-    TransferToEthereumKind,
+    erc20_token_address, BridgePoolChange, GasFee, PendingTransfer,
+    TransferToEthereum, TransferToEthereumKind,
 };
 use namada_core::ethereum_events::EthAddress;
 use namada_core::keccak::KeccakHash;
 use namada_core::voting_power::FractionalVotingPower;
-use namada_ethereum_bridge::storage::bridge_pool::get_pending_key;
+use namada_ethereum_bridge::storage::bridge_pool::{
+    get_key_from_hash, get_pending_key,
+};
 use namada_io::{display, display_line, edisplay_line, Client, Io};
 use namada_token::storage_key::balance_key;
 use namada_token::Amount;
@@ -40,7 +43,9 @@ use crate::queries::{
     GenBridgePoolProofReq, GenBridgePoolProofRsp, TransferToErcArgs,
     TransferToEthereumStatus, RPC,
 };
-use crate::rpc::{query_storage_value, query_wasm_code_hash, validate_amount};
+use crate::rpc::{
+    query_block, query_storage_value, query_wasm_code_hash, validate_amount,
+};
 use crate::signing::{aux_signing_data, validate_transparent_fee};
 use crate::tx::prepare_tx;
 use crate::{args, MaybeSync, Namada, SigningTxData};
@@ -58,6 +63,7 @@ pub async fn build_bridge_pool_tx(
         fee_amount,
         fee_payer,
         fee_token,
+        expiry,
         code_path,
     }: args::EthereumBridgePool,
 ) -> Result<(Tx, SigningTxData), Error> {
@@ -74,6 +80,7 @@ pub async fn build_bridge_pool_tx(
             fee_amount,
             fee_payer,
             fee_token,
+            expiry,
         ),
         query_wasm_code_hash(context, code_path.to_string_lossy()),
         aux_signing_data(
@@ -104,7 +111,88 @@ pub async fn build_bridge_pool_tx(
         tx_code_hash,
         Some(code_path.to_string_lossy().into_owned()),
     )
-    .add_data(transfer);
+    .add_data(BridgePoolChange::AddTransfer { transfer, expiry });
+
+    prepare_tx(
+        &tx_args,
+        &mut tx,
+        fee_amount,
+        signing_data.fee_payer.clone(),
+    )
+    .await?;
+
+    Ok((tx, signing_data))
+}
+
+/// Craft a transaction that bumps the relayer fee of a transfer in the
+/// Ethereum bridge pool.
+pub async fn build_bump_bridge_pool_fee_tx(
+    context: &impl Namada,
+    args::BumpBridgePoolFee {
+        tx: tx_args,
+        transfer: transfer_hash,
+        amount,
+        code_path,
+    }: args::BumpBridgePoolFee,
+) -> Result<(Tx, SigningTxData), Error> {
+    // the fee bump is paid by the gas payer of the transfer,
+    // in the same token as its gas fees
+    let transfer: PendingTransfer = query_storage_value(
+        context.client(),
+        &get_key_from_hash(&transfer_hash),
+    )
+    .await
+    .map_err(|e| match e {
+        Error::Query(QueryError::NoSuchKey(_)) => Error::EthereumBridge(
+            EthereumBridgeError::TransferNotInPool(transfer_hash),
+        ),
+        e => e,
+    })?;
+    let GasFee { token, payer, .. } = transfer.gas_fee;
+
+    let validate_fee_bump =
+        validate_amount(context, amount, &token, tx_args.force).map(|result| {
+            result.map_err(|e| {
+                Error::Other(format!(
+                    "Failed to validate Bridge pool fee bump: {e}",
+                ))
+            })
+        });
+    let (fee_bump, tx_code_hash, signing_data) = futures::try_join!(
+        validate_fee_bump,
+        query_wasm_code_hash(context, code_path.to_string_lossy()),
+        aux_signing_data(
+            context,
+            &tx_args,
+            // token owner
+            Some(payer.clone()),
+            // tx signer
+            Some(payer),
+            vec![],
+            false,
+        ),
+    )?;
+    let (fee_amount, _) =
+        validate_transparent_fee(context, &tx_args, &signing_data.fee_payer)
+            .await?;
+
+    let chain_id = tx_args
+        .chain_id
+        .clone()
+        .ok_or_else(|| Error::Other("No chain id available".into()))?;
+
+    let mut tx = Tx::new(chain_id, tx_args.expiration.to_datetime());
+    if let Some(memo) = &tx_args.memo {
+        tx.add_memo(memo);
+    }
+    tx.add_code_from_hash(
+        tx_code_hash,
+        Some(code_path.to_string_lossy().into_owned()),
+    )
+    .add_data(BridgePoolChange::BumpFee {
+        transfer: transfer_hash,
+        amount: fee_bump.amount(),
+    });
 
     prepare_tx(
         &tx_args,
@@ -130,6 +218,7 @@ async fn validate_bridge_pool_tx(
     fee_amount: args::InputAmount,
     fee_payer: Option<Address>,
     fee_token: Address,
+    expiry: Option<BlockHeight>,
 ) -> Result<PendingTransfer, Error> {
     let token_addr = erc20_token_address(&asset);
     let validate_token_amount =
@@ -196,6 +285,19 @@ async fn validate_bridge_pool_tx(
         ));
     }
 
+    // check that the transfer hasn't expired yet
+    if let Some(expiry) = expiry {
+        let last_height = query_block(context.client())
+            .await?
+            .map(|block| block.height)
+            .unwrap_or_default();
+        if expiry <= last_height {
+            return Err(Error::EthereumBridge(
+                EthereumBridgeError::TransferExpired(expiry),
+            ));
+        }
+    }
+
     let wnam_addr = RPC
         .shell()
         .eth_bridge()
@@ -752,8 +854,10 @@ mod recommendations {
 
     use super::*;
     use crate::eth_bridge::storage::bridge_pool::{
-        get_nonce_key, get_signed_root_key,
+        get_fee_bump_prefix, get_nonce_key, get_signed_root_key,
+        is_fee_bump_key,
     };
+    use crate::rpc::query_storage_prefix;
 
     const fn unsigned_transfer_fee() -> Uint {
         Uint::from_u64(37_500_u64)
@@ -936,12 +1040,18 @@ mod recommendations {
             * signature_checks(voting_powers, &bp_root.signatures)?
             + valset_fee() * valset_size;
 
+        // relayers are paid the fee bumps of transfers, on top
+        // of their gas fees
+        let mut signed_pool =
+            query_signed_bridge_pool(context.client(), context.io()).await?;
+        apply_fee_bumps(&mut signed_pool, query_fee_bumps(context).await?)?;
+
         // we don't recommend transfers that have already been relayed
         let eligible = generate_eligible(
             context.io(),
             &args.conversion_table,
             &in_progress,
-            query_signed_bridge_pool(context.client(), context.io()).await?,
+            signed_pool,
         )?;
 
         let max_gas =
@@ -993,6 +1103,37 @@ mod recommendations {
         Ok(())
     }
 
+    /// Query the fee bumps of the transfers in the Bridge pool,
+    /// indexed by the hash of each transfer.
+    async fn query_fee_bumps(
+        context: &impl Namada,
+    ) -> Result<HashMap<String, Amount>, Error> {
+        let fee_bumps =
+            query_storage_prefix::<_, Amount>(context, &get_fee_bump_prefix())
+                .await?;
+        Ok(fee_bumps
+            .into_iter()
+            .flatten()
+            .filter_map(|(key, fee_bump)| {
+                Some((is_fee_bump_key(&key)?.to_string(), fee_bump))
+            })
+            .collect())
+    }
+
+    /// Add the fee bumps of pending transfers to their gas fees.
+    fn apply_fee_bumps(
+        pool: &mut HashMap<String, PendingTransfer>,
+        fee_bumps: HashMap<String, Amount>,
+    ) -> Result<(), Error> {
+        for (transfer_hash, fee_bump) in fee_bumps {
+            if let Some(pending) = pool.get_mut(&transfer_hash) {
+                pending.gas_fee.amount =
+                    checked!(pending.gas_fee.amount + fee_bump)?;
+            }
+        }
+        Ok(())
+    }
+
     /// Given an ordered list of signatures, figure out the size of the first
     /// subset constituting a 2 / 3 majority.
     ///
@@ -1379,6 +1520,35 @@ mod recommendations {
             });
         }
 
+        /// Test that fee bumps are added to the gas fees of the
+        /// transfers they bump.
+        #[test]
+        fn test_apply_fee_bumps() {
+            let bumped = transfer(100);
+            let bumped_hash = bumped.keccak256().to_string();
+            let not_bumped = transfer(200);
+            let not_bumped_hash = not_bumped.keccak256().to_string();
+            let mut pool: HashMap<_, _> = [
+                (bumped_hash.clone(), bumped),
+                (not_bumped_hash.clone(), not_bumped),
+            ]
+            .into_iter()
+            .collect();
+            let fee_bumps = [
+                (bumped_hash.clone(), Amount::from(50)),
+                // bumps of transfers outside of the pool are ignored
+                (KeccakHash([0; 32]).to_string(), Amount::from(50)),
+            ]
+            .into_iter()
+            .collect();
+            apply_fee_bumps(&mut pool, fee_bumps).unwrap();
+            assert_eq!(pool[&bumped_hash].gas_fee.amount, Amount::from(150));
+            assert_eq!(
+                pool[&not_bumped_hash].gas_fee.amount,
+                Amount::from(200)
+            );
+        }
+
         #[test]
         fn test_signature_count() {
             let voting_powers = VotingPowersMap::from([
@@ -0,0 +1,114 @@
+//! Types of the tx history index. Nodes that enable this index in their
+//! config record the inner txs that touched each address, which can then be
+//! queried with pagination.
+
+use std::fmt;
+
+use namada_core::address::Address;
+use namada_core::borsh::{BorshDeserialize, BorshSerialize};
+use namada_core::chain::BlockHeight;
+use namada_core::hash::Hash;
+use serde::{Deserialize, Serialize};
+
+/// The maximum number of entries in a single page of tx history.
+pub const MAX_TX_HISTORY_PAGE_SIZE: u64 = 100;
+
+/// The way in which an inner tx touched an address.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    BorshSerialize,
+    BorshDeserialize,
+    Serialize,
+    Deserialize,
+)]
+pub enum TxHistoryKind {
+    /// Tokens were debited from the address, in a transfer or a burn.
+    Debit {
+        /// The debited token
+        token: Address,
+    },
+    /// Tokens were credited to the address, in a transfer or a mint.
+    Credit {
+        /// The credited token
+        token: Address,
+    },
+    /// Tokens were bonded. Recorded for both the source and the validator.
+    Bond {
+        /// The source of the bond
+        source: Address,
+        /// The validator receiving the bond
+        validator: Address,
+    },
+    /// Tokens were unbonded. Recorded for both the source and the validator.
+    Unbond {
+        /// The source of the bond
+        source: Address,
+        /// The validator the tokens were unbonded from
+        validator: Address,
+    },
+    /// The address voted on a governance proposal.
+    Vote {
+        /// The id of the voted proposal
+        proposal_id: u64,
+    },
+}
+
+impl fmt::Display for TxHistoryKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Debit { token } => write!(f, "debit of {token}"),
+            Self::Credit { token } => write!(f, "credit of {token}"),
+            Self::Bond { source, validator } => {
+                write!(f, "bond from {source} to {validator}")
+            }
+            Self::Unbond { source, validator } => {
+                write!(f, "unbond of {source} from {validator}")
+            }
+            Self::Vote { proposal_id } => {
+                write!(f, "vote on proposal {proposal_id}")
+            }
+        }
+    }
+}
+
+/// An entry of the tx history of an address.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    BorshSerialize,
+    BorshDeserialize,
+    Serialize,
+    Deserialize,
+)]
+pub struct TxHistoryEntry {
+    /// The hash of the inner tx
+    pub inner_tx_hash: Hash,
+    /// The height of the block in which the tx was applied
+    pub height: BlockHeight,
+    /// How the tx touched the address
+    pub kind: TxHistoryKind,
+}
+
+/// A page of the tx history of an address, ordered by block height.
+#[derive(
+    Debug,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    BorshSerialize,
+    BorshDeserialize,
+    Serialize,
+    Deserialize,
+)]
+pub struct TxHistoryPage {
+    /// The entries of the requested page
+    pub entries: Vec<TxHistoryEntry>,
+    /// The total number of entries in the history of the address
+    pub total: u64,
+}
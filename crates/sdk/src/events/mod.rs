@@ -1,5 +1,7 @@
 //! Logic to do with events emitted by the ledger.
+pub mod history;
 pub mod log;
+pub mod stream;
 
 use namada_core::collections::HashMap;
 pub use namada_events::*;
@@ -0,0 +1,335 @@
+//! Streams of the events emitted by the ledger.
+//!
+//! An [`EventStream`] follows the blocks committed by a node over its
+//! CometBFT RPC, and yields the events of each block that match an
+//! [`EventFilter`]. Streams can be resumed from any height whose results are
+//! still stored by the node, by keeping track of
+//! [`EventStream::next_height`].
+
+use std::collections::{BTreeMap, VecDeque};
+
+use futures::Stream;
+use namada_core::address::Address;
+use namada_core::chain::BlockHeight;
+use namada_io::Client;
+
+use crate::control_flow::time::{self, Duration};
+use crate::error::{Error, QueryError};
+use crate::events::extend::{ExtendAttributesMap, ExtendEventAttributes};
+use crate::events::{Event, EventToEmit};
+use crate::rpc::query_block;
+
+/// The default interval at which an [`EventStream`] polls the node for new
+/// blocks.
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Filter over the events yielded by an [`EventStream`]. An empty filter
+/// matches all events.
+#[derive(Debug, Clone, Default)]
+pub struct EventFilter {
+    domains: Vec<String>,
+    attributes: BTreeMap<String, String>,
+    addresses: Vec<Address>,
+}
+
+impl ExtendAttributesMap for EventFilter {
+    fn with_attribute<DATA>(&mut self, data: DATA) -> &mut Self
+    where
+        DATA: ExtendEventAttributes,
+    {
+        data.extend_event_attributes(&mut self.attributes);
+        self
+    }
+}
+
+impl EventFilter {
+    /// Create a new [`EventFilter`] that matches all events.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Match events of the domain of `E`. If several domains are given,
+    /// events of any of them are matched.
+    pub fn of_domain<E: EventToEmit>(self) -> Self {
+        self.of_raw_domain(E::DOMAIN)
+    }
+
+    /// Match events of the given domain. If several domains are given,
+    /// events of any of them are matched.
+    pub fn of_raw_domain(mut self, domain: impl Into<String>) -> Self {
+        self.domains.push(domain.into());
+        self
+    }
+
+    /// Match events with the given attribute.
+    #[inline]
+    pub fn and_attribute<DATA>(mut self, data: DATA) -> Self
+    where
+        DATA: ExtendEventAttributes,
+    {
+        self.with_attribute(data);
+        self
+    }
+
+    /// Match events with an attribute that mentions the given address. If
+    /// several addresses are given, events mentioning any of them are
+    /// matched.
+    pub fn and_address(mut self, address: Address) -> Self {
+        self.addresses.push(address);
+        self
+    }
+
+    /// Checks if this [`EventFilter`] validates the given [`Event`].
+    pub fn matches(&self, event: &Event) -> bool {
+        let matches_domain = self.domains.is_empty()
+            || self
+                .domains
+                .iter()
+                .any(|domain| event.kind().domain() == domain);
+        if !matches_domain || !event.has_subset_of_attrs(&self.attributes) {
+            return false;
+        }
+        if self.addresses.is_empty() {
+            return true;
+        }
+        // NB: addresses may be nested in structured attribute values (e.g.
+        // the accounts of a transfer), so we look for their encoding
+        // anywhere in the values
+        #[allow(deprecated)]
+        let attributes = event.attributes();
+        self.addresses.iter().any(|address| {
+            let address = address.encode();
+            attributes.values().any(|value| value.contains(&address))
+        })
+    }
+}
+
+/// Stream of the events emitted in the blocks committed by a node.
+#[derive(Debug)]
+pub struct EventStream<'client, C> {
+    client: &'client C,
+    filter: EventFilter,
+    next_height: Option<BlockHeight>,
+    poll_interval: Duration,
+}
+
+impl<'client, C> EventStream<'client, C>
+where
+    C: Client + Sync,
+{
+    /// Create a new [`EventStream`] that yields the events matched by
+    /// `filter`, starting from the block after the last committed one.
+    pub fn new(client: &'client C, filter: EventFilter) -> Self {
+        Self {
+            client,
+            filter,
+            next_height: None,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+        }
+    }
+
+    /// Start (or resume) the stream from the given height.
+    pub fn from_height(mut self, height: BlockHeight) -> Self {
+        self.next_height = Some(height);
+        self
+    }
+
+    /// Set the interval at which the node is polled for new blocks.
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// The height of the next block whose events will be yielded, if known.
+    /// This is the height to resume the stream from.
+    pub fn next_height(&self) -> Option<BlockHeight> {
+        self.next_height
+    }
+
+    /// Wait for the next block to be committed, and return its height along
+    /// with its events matched by the filter of this stream.
+    ///
+    /// On error, the stream is not advanced, such that the same block is
+    /// fetched again on the next call.
+    pub async fn next_block(
+        &mut self,
+    ) -> Result<(BlockHeight, Vec<Event>), Error> {
+        let mut last_committed = self.last_committed_height().await?;
+        let height = *self
+            .next_height
+            .get_or_insert_with(|| last_committed.next_height());
+
+        while height > last_committed {
+            time::sleep(self.poll_interval).await;
+            last_committed = self.last_committed_height().await?;
+        }
+
+        let events = self
+            .client
+            .block_results(height.0)
+            .await
+            .map_err(|e| Error::from(QueryError::General(e.to_string())))?
+            .end_block_events
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|event| match Event::try_from(event) {
+                Ok(event) => Some(event),
+                Err(err) => {
+                    tracing::debug!(
+                        %height,
+                        %err,
+                        "Skipping event that could not be decoded",
+                    );
+                    None
+                }
+            })
+            .filter(|event| self.filter.matches(event))
+            .collect();
+
+        self.next_height = Some(height.next_height());
+        Ok((height, events))
+    }
+
+    /// Convert this [`EventStream`] into a [`Stream`] of events, along with
+    /// the height of the block they were emitted in. Errors are yielded
+    /// without terminating the stream, and the failed request is retried
+    /// after the poll interval.
+    pub fn into_stream(
+        self,
+    ) -> impl Stream<Item = Result<(BlockHeight, Event), Error>> + 'client {
+        let state = (self, VecDeque::new(), false);
+        futures::stream::unfold(
+            state,
+            |(mut stream, mut pending, mut backoff)| async move {
+                loop {
+                    if let Some((height, event)) = pending.pop_front() {
+                        return Some((
+                            Ok((height, event)),
+                            (stream, pending, backoff),
+                        ));
+                    }
+                    if backoff {
+                        time::sleep(stream.poll_interval).await;
+                    }
+                    match stream.next_block().await {
+                        Ok((height, events)) => {
+                            backoff = false;
+                            pending.extend(
+                                events.into_iter().map(|event| (height, event)),
+                            );
+                        }
+                        Err(err) => {
+                            return Some((Err(err), (stream, pending, true)));
+                        }
+                    }
+                }
+            },
+        )
+    }
+
+    async fn last_committed_height(&self) -> Result<BlockHeight, Error> {
+        Ok(query_block(self.client)
+            .await?
+            .map(|block| block.height)
+            .unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod test_event_stream {
+    use namada_core::address::testing::{
+        established_address_1, established_address_2, nam,
+    };
+    use namada_core::hash::Hash;
+    use namada_ethereum_bridge::event::EthBridgeEvent;
+    use namada_ibc::event::types::UPDATE_CLIENT;
+    use namada_ibc::event::IbcEvent;
+    use namada_token::event::{SourceAccounts, TokenEvent};
+
+    use super::*;
+    use crate::events::extend::{
+        event_domain_of, ComposeEvent, Height, TxHash, UserAccount,
+    };
+    use crate::events::{EventLevel, EventTypeBuilder};
+    use crate::tx::event::types::APPLIED;
+
+    /// Test that events survive a roundtrip through their CometBFT
+    /// representation.
+    #[test]
+    fn test_event_cometbft_roundtrip() {
+        let applied: Event = Event::new(APPLIED, EventLevel::Tx)
+            .with(TxHash(Hash::zero()))
+            .with(Height(BlockHeight(7)))
+            .into();
+        let converted = Event::try_from(
+            namada_core::tendermint::abci::Event::from(applied.clone()),
+        )
+        .unwrap();
+        assert_eq!(converted, applied);
+
+        // ibc events carry their domain in the attributes
+        let update_client: Event = Event::new(UPDATE_CLIENT, EventLevel::Tx)
+            .with(event_domain_of::<IbcEvent>())
+            .into();
+        let abci_event =
+            namada_core::tendermint::abci::Event::from(update_client.clone());
+        assert_eq!(abci_event.kind, UPDATE_CLIENT.sub_domain());
+        let converted = Event::try_from(abci_event).unwrap();
+        assert_eq!(converted, update_client);
+    }
+
+    /// Test the matching of events by domain, attributes and address.
+    #[test]
+    fn test_event_filter() {
+        let alice = established_address_1();
+        let bob = established_address_2();
+
+        let token_event: Event = Event::new(
+            EventTypeBuilder::new_of::<TokenEvent>()
+                .with_segment("transfer")
+                .build(),
+            EventLevel::Tx,
+        )
+        .with(TxHash(Hash::zero()))
+        .with(Height(BlockHeight(7)))
+        .with(SourceAccounts(
+            vec![((UserAccount::Internal(alice.clone()), nam()), 1u64.into())]
+                .into(),
+        ))
+        .into();
+        let bridge_event = Event::new(
+            EventTypeBuilder::new_of::<EthBridgeEvent>()
+                .with_segment("relayed")
+                .build(),
+            EventLevel::Block,
+        );
+
+        let filter = EventFilter::new();
+        assert!(filter.matches(&token_event));
+        assert!(filter.matches(&bridge_event));
+
+        let filter = EventFilter::new().of_domain::<TokenEvent>();
+        assert!(filter.matches(&token_event));
+        assert!(!filter.matches(&bridge_event));
+
+        let filter = EventFilter::new()
+            .of_domain::<TokenEvent>()
+            .of_domain::<EthBridgeEvent>();
+        assert!(filter.matches(&token_event));
+        assert!(filter.matches(&bridge_event));
+
+        let filter = EventFilter::new().and_attribute(Height(BlockHeight(7)));
+        assert!(filter.matches(&token_event));
+        assert!(!filter.matches(&bridge_event));
+
+        let filter = EventFilter::new().and_attribute(Height(BlockHeight(8)));
+        assert!(!filter.matches(&token_event));
+
+        let filter = EventFilter::new().and_address(alice);
+        assert!(filter.matches(&token_event));
+        assert!(!filter.matches(&bridge_event));
+
+        let filter = EventFilter::new().and_address(bob);
+        assert!(!filter.matches(&token_event));
+    }
+}
@@ -47,9 +47,11 @@ use std::str::FromStr;
 use args::{DeviceTransport, InputAmount, SdkTypes};
 use masp_primitives::zip32::PseudoExtendedKey;
 use namada_core::address::Address;
+use namada_core::chain::Epoch;
 use namada_core::dec::Dec;
 use namada_core::ethereum_events::EthAddress;
 use namada_core::ibc::core::host::types::identifiers::{ChannelId, PortId};
+use namada_core::keccak::KeccakHash;
 use namada_core::key::*;
 pub use namada_core::masp::{
     ExtendedSpendingKey, ExtendedViewingKey, PaymentAddress, TransferSource,
@@ -66,15 +68,20 @@ use signing::SigningTxData;
 use token::{DenominatedAmount, NATIVE_MAX_DECIMAL_PLACES};
 use tokio::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
 use tx::{
-    ProcessTxResponse, TX_BECOME_VALIDATOR_WASM, TX_BOND_WASM,
-    TX_BRIDGE_POOL_WASM, TX_CHANGE_COMMISSION_WASM,
-    TX_CHANGE_CONSENSUS_KEY_WASM, TX_CHANGE_METADATA_WASM,
-    TX_CLAIM_REWARDS_WASM, TX_DEACTIVATE_VALIDATOR_WASM, TX_IBC_WASM,
-    TX_INIT_ACCOUNT_WASM, TX_INIT_PROPOSAL, TX_REACTIVATE_VALIDATOR_WASM,
-    TX_REDELEGATE_WASM, TX_RESIGN_STEWARD, TX_REVEAL_PK, TX_TRANSFER_WASM,
-    TX_UNBOND_WASM, TX_UNJAIL_VALIDATOR_WASM, TX_UPDATE_ACCOUNT_WASM,
-    TX_UPDATE_STEWARD_COMMISSION, TX_VOTE_PROPOSAL, TX_WITHDRAW_WASM,
-    VP_USER_WASM,
+    ProcessTxResponse, TX_AMEND_PROPOSAL_WASM, TX_APPROVE_WASM,
+    TX_AUTO_COMPOUND_WASM, TX_BECOME_VALIDATOR_WASM, TX_BOND_WASM,
+    TX_BRIDGE_POOL_WASM, TX_CANCEL_PROPOSAL_WASM, TX_CANCEL_RECOVERY_WASM,
+    TX_CHANGE_COMMISSION_WASM, TX_CHANGE_CONSENSUS_KEY_WASM,
+    TX_CHANGE_METADATA_WASM, TX_CLAIM_REWARDS_WASM, TX_CONFIGURE_RECOVERY_WASM,
+    TX_DEACTIVATE_VALIDATOR_WASM, TX_DELEGATE_VOTES_WASM,
+    TX_DEPOSIT_PROPOSAL_WASM, TX_FINALIZE_RECOVERY_WASM, TX_IBC_WASM,
+    TX_INITIATE_RECOVERY_WASM, TX_INIT_ACCOUNT_WASM, TX_INIT_PROPOSAL,
+    TX_LIQUID_BOND_WASM, TX_LIQUID_UNBOND_WASM,
+    TX_REACTIVATE_VALIDATOR_WASM, TX_REDELEGATE_WASM, TX_RESIGN_STEWARD,
+    TX_REVEAL_PK, TX_TRANSFER_FROM_WASM, TX_TRANSFER_WASM, TX_UNBOND_WASM,
+    TX_UNJAIL_VALIDATOR_WASM, TX_UPDATE_ACCOUNT_WASM,
+    TX_UPDATE_STEWARD_COMMISSION, TX_VESTING_TRANSFER_WASM, TX_VOTE_PROPOSAL,
+    TX_WITHDRAW_WASM, VP_USER_WASM,
 };
 use wallet::{Wallet, WalletIo, WalletStorage};
 pub use {namada_io as io, namada_wallet as wallet};
@@ -168,6 +175,70 @@ pub trait Namada: NamadaIo {
         }
     }
 
+    /// Make a TxApprove builder from the given minimum set of arguments
+    fn new_approve(
+        &self,
+        owner: Address,
+        spender: Address,
+        token: Address,
+        amount: InputAmount,
+    ) -> args::TxApprove {
+        args::TxApprove {
+            owner,
+            spender,
+            token,
+            amount,
+            expiry: None,
+            tx_code_path: PathBuf::from(TX_APPROVE_WASM),
+            tx: self.tx_builder(),
+        }
+    }
+
+    /// Make a TxTransferFrom builder from the given minimum set of arguments
+    fn new_transfer_from(
+        &self,
+        owner: Address,
+        spender: Address,
+        target: Address,
+        token: Address,
+        amount: InputAmount,
+    ) -> args::TxTransferFrom {
+        args::TxTransferFrom {
+            owner,
+            spender,
+            target,
+            token,
+            amount,
+            tx_code_path: PathBuf::from(TX_TRANSFER_FROM_WASM),
+            tx: self.tx_builder(),
+        }
+    }
+
+    /// Make a TxVestingTransfer builder from the given minimum set of
+    /// arguments. The amount vests linearly from the start epoch, without a
+    /// cliff.
+    fn new_vesting_transfer(
+        &self,
+        source: Address,
+        target: Address,
+        token: Address,
+        amount: InputAmount,
+        start_epoch: Epoch,
+        end_epoch: Epoch,
+    ) -> args::TxVestingTransfer {
+        args::TxVestingTransfer {
+            source,
+            target,
+            token,
+            amount,
+            start_epoch,
+            cliff_epoch: start_epoch,
+            end_epoch,
+            tx_code_path: PathBuf::from(TX_VESTING_TRANSFER_WASM),
+            tx: self.tx_builder(),
+        }
+    }
+
     /// Make a TxShieldedTransfer builder from the given minimum set of
     /// arguments
     fn new_shielded_transfer(
@@ -231,6 +302,7 @@ pub trait Namada: NamadaIo {
             tx_code_path: PathBuf::from(TX_INIT_ACCOUNT_WASM),
             public_keys,
             threshold,
+            weights: vec![],
         }
     }
 
@@ -347,11 +419,71 @@ pub trait Namada: NamadaIo {
             vp_code_path: None,
             public_keys,
             threshold: Some(threshold),
+            weights: vec![],
             tx_code_path: PathBuf::from(TX_UPDATE_ACCOUNT_WASM),
             tx: self.tx_builder(),
         }
     }
 
+    /// Make a TxConfigureRecovery builder from the given minimum set of
+    /// arguments
+    fn new_configure_recovery(
+        &self,
+        addr: Address,
+        guardians: Vec<Address>,
+        threshold: u8,
+        delay: u64,
+    ) -> args::TxConfigureRecovery {
+        args::TxConfigureRecovery {
+            addr,
+            guardians,
+            threshold,
+            delay,
+            tx_code_path: PathBuf::from(TX_CONFIGURE_RECOVERY_WASM),
+            tx: self.tx_builder(),
+        }
+    }
+
+    /// Make a TxInitiateRecovery builder from the given minimum set of
+    /// arguments
+    fn new_initiate_recovery(
+        &self,
+        addr: Address,
+        guardians: Vec<Address>,
+        public_keys: Vec<common::PublicKey>,
+        threshold: u8,
+    ) -> args::TxInitiateRecovery {
+        args::TxInitiateRecovery {
+            addr,
+            guardians,
+            public_keys,
+            threshold: Some(threshold),
+            weights: vec![],
+            tx_code_path: PathBuf::from(TX_INITIATE_RECOVERY_WASM),
+            tx: self.tx_builder(),
+        }
+    }
+
+    /// Make a TxCancelRecovery builder from the given minimum set of
+    /// arguments
+    fn new_cancel_recovery(&self, addr: Address) -> args::TxCancelRecovery {
+        args::TxCancelRecovery {
+            addr,
+            tx_code_path: PathBuf::from(TX_CANCEL_RECOVERY_WASM),
+            tx: self.tx_builder(),
+        }
+    }
+
+    /// Make a TxFinalizeRecovery builder from the given minimum set of
+    /// arguments
+    fn new_finalize_recovery(&self, addr: Address) -> args::TxFinalizeRecovery {
+        args::TxFinalizeRecovery {
+            addr,
+            tx_code_path: PathBuf::from(TX_FINALIZE_RECOVERY_WASM),
+            tx: self.tx_builder(),
+        }
+    }
+
     /// Make a VoteProposal builder from the given minimum set of arguments
     fn new_proposal_vote(
         &self,
@@ -368,6 +500,66 @@ pub trait Namada: NamadaIo {
         }
     }
 
+    /// Make a DepositProposal builder from the given minimum set of arguments
+    fn new_deposit_proposal(
+        &self,
+        proposal_id: u64,
+        depositor: Address,
+        amount: token::Amount,
+    ) -> args::DepositProposal {
+        args::DepositProposal {
+            proposal_id,
+            depositor,
+            amount,
+            tx_code_path: PathBuf::from(TX_DEPOSIT_PROPOSAL_WASM),
+            tx: self.tx_builder(),
+        }
+    }
+
+    /// Make a CancelProposal builder from the given minimum set of arguments
+    fn new_cancel_proposal(
+        &self,
+        proposal_id: u64,
+        author: Address,
+    ) -> args::CancelProposal {
+        args::CancelProposal {
+            proposal_id,
+            author,
+            tx_code_path: PathBuf::from(TX_CANCEL_PROPOSAL_WASM),
+            tx: self.tx_builder(),
+        }
+    }
+
+    /// Make an AmendProposal builder from the given minimum set of arguments
+    fn new_amend_proposal(
+        &self,
+        proposal_id: u64,
+        author: Address,
+        content: Vec<u8>,
+    ) -> args::AmendProposal {
+        args::AmendProposal {
+            proposal_id,
+            author,
+            content,
+            tx_code_path: PathBuf::from(TX_AMEND_PROPOSAL_WASM),
+            tx: self.tx_builder(),
+        }
+    }
+
+    /// Make a DelegateVotes builder from the given minimum set of arguments
+    fn new_delegate_votes(
+        &self,
+        delegator: Address,
+        representative: Option<Address>,
+    ) -> args::DelegateVotes {
+        args::DelegateVotes {
+            delegator,
+            representative,
+            tx_code_path: PathBuf::from(TX_DELEGATE_VOTES_WASM),
+            tx: self.tx_builder(),
+        }
+    }
+
     /// Make a CommissionRateChange builder from the given minimum set of
     /// arguments
     fn new_change_commission_rate(
@@ -409,6 +601,7 @@ pub trait Namada: NamadaIo {
             discord_handle: None,
             avatar: None,
             name: None,
+            reward_destination: None,
             commission_rate: None,
             tx_code_path: PathBuf::from(TX_CHANGE_METADATA_WASM),
             tx: self.tx_builder(),
@@ -540,6 +733,53 @@ pub trait Namada: NamadaIo {
         }
     }
 
+    /// Make an Auto-compound builder from the given minimum set of arguments
+    fn new_auto_compound(
+        &self,
+        validator: Address,
+        enabled: bool,
+    ) -> args::AutoCompound {
+        args::AutoCompound {
+            validator,
+            source: None,
+            enabled,
+            tx_code_path: PathBuf::from(TX_AUTO_COMPOUND_WASM),
+            tx: self.tx_builder(),
+        }
+    }
+
+    /// Make a LiquidBond builder from the given minimum set of arguments
+    fn new_liquid_bond(
+        &self,
+        source: Address,
+        validator: Address,
+        amount: token::Amount,
+    ) -> args::LiquidBond {
+        args::LiquidBond {
+            validator,
+            amount,
+            source,
+            tx_code_path: PathBuf::from(TX_LIQUID_BOND_WASM),
+            tx: self.tx_builder(),
+        }
+    }
+
+    /// Make a LiquidUnbond builder from the given minimum set of arguments
+    fn new_liquid_unbond(
+        &self,
+        source: Address,
+        validator: Address,
+        shares: token::Amount,
+    ) -> args::LiquidUnbond {
+        args::LiquidUnbond {
+            validator,
+            shares,
+            source,
+            tx_code_path: PathBuf::from(TX_LIQUID_UNBOND_WASM),
+            tx: self.tx_builder(),
+        }
+    }
+
     /// Make a Withdraw builder from the given minimum set of arguments
     fn new_add_erc20_transfer(
         &self,
@@ -562,6 +802,22 @@ pub trait Namada: NamadaIo {
             fee_payer: None,
             fee_token: self.native_token(),
             nut: false,
+            expiry: None,
+            code_path: PathBuf::from(TX_BRIDGE_POOL_WASM),
+            tx: self.tx_builder(),
+        }
+    }
+
+    /// Make a BumpBridgePoolFee builder from the given minimum set of
+    /// arguments
+    fn new_bump_bridge_pool_fee(
+        &self,
+        transfer: KeccakHash,
+        amount: InputAmount,
+    ) -> args::BumpBridgePoolFee {
+        args::BumpBridgePoolFee {
+            transfer,
+            amount,
             code_path: PathBuf::from(TX_BRIDGE_POOL_WASM),
             tx: self.tx_builder(),
         }
@@ -861,7 +1117,7 @@ pub mod testing {
         arb_established_address, arb_non_internal_address,
     };
     use namada_core::collections::{HashMap, HashSet};
-    use namada_core::eth_bridge_pool::PendingTransfer;
+    use namada_core::eth_bridge_pool::{BridgePoolChange, PendingTransfer};
     use namada_core::hash::testing::arb_hash;
     use namada_core::key::testing::arb_common_keypair;
     use namada_core::masp::AssetData;
@@ -878,8 +1134,9 @@ pub mod testing {
     use namada_token::Transfer;
     use namada_tx::data::pgf::UpdateStewardCommission;
     use namada_tx::data::pos::{
-        BecomeValidator, Bond, CommissionChange, ConsensusKeyChange,
-        MetaDataChange, Redelegation, Unbond, Withdraw,
+        AutoCompound, BecomeValidator, Bond, CommissionChange,
+        ConsensusKeyChange, LiquidBond, LiquidUnbond, MetaDataChange,
+        Redelegation, Unbond, Withdraw,
     };
     use namada_tx::data::{Fee, TxType, WrapperTx};
     use proptest::prelude::{Just, Strategy};
@@ -898,9 +1155,9 @@ pub mod testing {
     use crate::time::{DateTime, DateTimeUtc, TimeZone, Utc};
     use crate::tx::data::pgf::tests::arb_update_steward_commission;
     use crate::tx::data::pos::tests::{
-        arb_become_validator, arb_bond, arb_commission_change,
-        arb_consensus_key_change, arb_metadata_change, arb_redelegation,
-        arb_withdraw,
+        arb_auto_compound, arb_become_validator, arb_bond,
+        arb_commission_change, arb_consensus_key_change, arb_liquid_bond,
+        arb_metadata_change, arb_redelegation, arb_withdraw,
     };
     use crate::tx::{
         Authorization, Code, Commitment, Header, MaspBuilder, Section,
@@ -917,6 +1174,9 @@ pub mod testing {
         ConsensusKeyChange(ConsensusKeyChange),
         MetaDataChange(MetaDataChange),
         ClaimRewards(Withdraw),
+        AutoCompound(AutoCompound),
+        LiquidBond(LiquidBond),
+        LiquidUnbond(LiquidUnbond),
         DeactivateValidator(Address),
         InitAccount(InitAccount),
         InitProposal(InitProposalData),
@@ -1238,6 +1498,11 @@ pub mod testing {
             if let ProposalType::DefaultWithWasm(hash) = &mut init_proposal.r#type {
                 let type_hash = tx.add_section(Section::ExtraData(type_extra_data)).get_hash();
                 *hash = type_hash;
+            } else if let ProposalType::DefaultWithWasmBatch(steps) = &mut init_proposal.r#type {
+                for step in steps {
+                    let step_hash = tx.add_section(Section::ExtraData(type_extra_data.clone())).get_hash();
+                    step.code = step_hash;
+                }
             }
             tx.add_data(init_proposal.clone());
             tx.add_code_from_hash(code_hash, Some(TX_INIT_PROPOSAL.to_owned()));
@@ -1355,6 +1620,54 @@ pub mod testing {
         }
     }
 
+    prop_compose! {
+        /// Generate an arbitrary auto-compound transaction
+        pub fn arb_auto_compound_tx()(
+            mut header in arb_header(0),
+            wrapper in arb_wrapper_tx(),
+            auto_compound in arb_auto_compound(),
+            code_hash in arb_hash(),
+        ) -> (Tx, TxData) {
+            header.tx_type = TxType::Wrapper(Box::new(wrapper));
+            let mut tx = Tx { header, sections: vec![] };
+            tx.add_data(auto_compound.clone());
+            tx.add_code_from_hash(code_hash, Some(TX_AUTO_COMPOUND_WASM.to_owned()));
+            (tx, TxData::AutoCompound(auto_compound))
+        }
+    }
+
+    prop_compose! {
+        /// Generate an arbitrary liquid bond transaction
+        pub fn arb_liquid_bond_tx()(
+            mut header in arb_header(0),
+            wrapper in arb_wrapper_tx(),
+            liquid_bond in arb_liquid_bond(),
+            code_hash in arb_hash(),
+        ) -> (Tx, TxData) {
+            header.tx_type = TxType::Wrapper(Box::new(wrapper));
+            let mut tx = Tx { header, sections: vec![] };
+            tx.add_data(liquid_bond.clone());
+            tx.add_code_from_hash(code_hash, Some(TX_LIQUID_BOND_WASM.to_owned()));
+            (tx, TxData::LiquidBond(liquid_bond))
+        }
+    }
+
+    prop_compose! {
+        /// Generate an arbitrary liquid unbond transaction
+        pub fn arb_liquid_unbond_tx()(
+            mut header in arb_header(0),
+            wrapper in arb_wrapper_tx(),
+            liquid_unbond in arb_liquid_bond(),
+            code_hash in arb_hash(),
+        ) -> (Tx, TxData) {
+            header.tx_type = TxType::Wrapper(Box::new(wrapper));
+            let mut tx = Tx { header, sections: vec![] };
+            tx.add_data(liquid_unbond.clone());
+            tx.add_code_from_hash(code_hash, Some(TX_LIQUID_UNBOND_WASM.to_owned()));
+            (tx, TxData::LiquidUnbond(liquid_unbond))
+        }
+    }
+
     prop_compose! {
         /// Generate an arbitrary commission change transaction
         pub fn arb_commission_change_tx()(
@@ -1509,7 +1822,10 @@ pub mod testing {
         ) -> (Tx, TxData) {
             header.tx_type = TxType::Wrapper(Box::new(wrapper));
             let mut tx = Tx { header, sections: vec![] };
-            tx.add_data(pending_transfer.clone());
+            tx.add_data(BridgePoolChange::AddTransfer {
+                transfer: pending_transfer.clone(),
+                expiry: None,
+            });
             tx.add_code_from_hash(code_hash, Some(TX_BRIDGE_POOL_WASM.to_owned()));
             (tx, TxData::PendingTransfer(pending_transfer))
         }
@@ -1629,6 +1945,9 @@ pub mod testing {
             arb_update_account_tx(),
             arb_withdraw_tx(),
             arb_claim_rewards_tx(),
+            arb_auto_compound_tx(),
+            arb_liquid_bond_tx(),
+            arb_liquid_unbond_tx(),
             arb_commission_change_tx(),
             arb_metadata_change_tx(),
             arb_unjail_validator_tx(),
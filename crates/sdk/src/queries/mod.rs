@@ -179,6 +179,7 @@ pub(crate) mod testing {
                 vp_wasm_cache: (),
                 tx_wasm_cache: (),
                 storage_read_past_height_limit: None,
+                tx_history_index: false,
             };
             self.rpc.handle(ctx, &request).map_err(|err| {
                 std::io::Error::new(std::io::ErrorKind::Other, err.to_string())
@@ -1049,6 +1049,7 @@ mod test {
             vp_wasm_cache: (),
             tx_wasm_cache: (),
             storage_read_past_height_limit: None,
+            tx_history_index: false,
         };
         let result = TEST_RPC.handle(ctx, &request);
         assert!(result.is_err());
@@ -1068,6 +1069,7 @@ mod test {
             vp_wasm_cache: (),
             tx_wasm_cache: (),
             storage_read_past_height_limit: None,
+            tx_history_index: false,
         };
         let result = TEST_RPC.handle(ctx, &request);
         assert!(result.is_err());
@@ -1085,6 +1087,7 @@ mod test {
             vp_wasm_cache: (),
             tx_wasm_cache: (),
             storage_read_past_height_limit: None,
+            tx_history_index: false,
         };
         let result = TEST_RPC.handle(ctx, &request);
         assert!(matches!(
@@ -6,7 +6,7 @@ use borsh::BorshDeserialize;
 use masp_primitives::asset_type::AssetType;
 use masp_primitives::merkle_tree::MerklePath;
 use masp_primitives::sapling::Node;
-use namada_account::{Account, AccountPublicKeysMap};
+use namada_account::{Account, PendingRecovery, RecoveryConfig};
 use namada_core::address::Address;
 use namada_core::arith::checked;
 use namada_core::chain::{BlockHeader, BlockHeight, Epoch};
@@ -27,6 +27,9 @@ use namada_tx::data::DryRunResult;
 
 use self::eth_bridge::{EthBridge, ETH_BRIDGE};
 use crate::borsh::BorshSerializeExt;
+use crate::events::history::{
+    TxHistoryEntry, TxHistoryPage, MAX_TX_HISTORY_PAGE_SIZE,
+};
 use crate::events::log::dumb_queries;
 use crate::events::Event;
 use crate::ibc::core::host::types::identifiers::{
@@ -107,12 +110,22 @@ router! {SHELL,
     // was the transaction applied?
     ( "applied" / [tx_hash: Hash] ) -> Option<Event> = applied,
 
+    // Tx history of an address, if indexed by the node
+    ( "tx_history" / [owner: Address] / [page: u64] / [per_page: u64] )
+        -> TxHistoryPage = tx_history,
+
     // Query account subspace
     ( "account" / [owner: Address] ) -> Option<Account> = account,
 
     // Query public key revealad
     ( "revealed" / [owner: Address] ) -> bool = revealed,
 
+    // Query the social recovery config of an account
+    ( "recovery_config" / [owner: Address] ) -> Option<RecoveryConfig> = recovery_config,
+
+    // Query the pending social recovery of an account
+    ( "pending_recovery" / [owner: Address] ) -> Option<PendingRecovery> = pending_recovery,
+
     // IBC UpdateClient event
     ( "ibc_client_update" / [client_id: ClientId] / [consensus_height: BlockHeight] ) -> Option<Event> = ibc_client_update,
 
@@ -583,6 +596,54 @@ where
     Ok(ctx.event_log.with_matcher(matcher).iter().next().cloned())
 }
 
+/// Read a page of the tx history of the given address. Pages are numbered
+/// from 0 and entries are ordered by block height. Fails if the node does
+/// not index the tx history.
+fn tx_history<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    owner: Address,
+    page: u64,
+    per_page: u64,
+) -> namada_storage::Result<TxHistoryPage>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    if !ctx.tx_history_index {
+        return Err(namada_storage::Error::new_const(
+            "The tx history is not indexed by this node",
+        ));
+    }
+    if per_page == 0 || per_page > MAX_TX_HISTORY_PAGE_SIZE {
+        return Err(namada_storage::Error::new_alloc(format!(
+            "The number of entries per page must be between 1 and \
+             {MAX_TX_HISTORY_PAGE_SIZE}, got {per_page}"
+        )));
+    }
+    let start = page
+        .checked_mul(per_page)
+        .ok_or_else(|| namada_storage::Error::new_const("Page out of range"))?;
+
+    let db = ctx.state.db();
+    let total = db.read_tx_history_len(&owner).into_storage_result()?;
+    let end = start.saturating_add(per_page).min(total);
+    let mut entries = vec![];
+    for seq in start..end {
+        let bytes = db
+            .read_tx_history_entry(&owner, seq)
+            .into_storage_result()?
+            .ok_or_else(|| {
+                namada_storage::Error::new_alloc(format!(
+                    "Missing tx history entry {seq} of {owner}"
+                ))
+            })?;
+        entries.push(
+            TxHistoryEntry::try_from_slice(&bytes).into_storage_result()?,
+        );
+    }
+    Ok(TxHistoryPage { entries, total })
+}
+
 fn ibc_client_update<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
     client_id: ClientId,
@@ -634,11 +695,12 @@ where
     let account_exists = namada_account::exists(ctx.state, &owner)?;
 
     if account_exists {
-        let public_keys = namada_account::public_keys(ctx.state, &owner)?;
+        let public_keys_map =
+            namada_account::public_keys_index_map(ctx.state, &owner)?;
         let threshold = namada_account::threshold(ctx.state, &owner)?;
 
         Ok(Some(Account {
-            public_keys_map: AccountPublicKeysMap::from_iter(public_keys),
+            public_keys_map,
             address: owner,
             threshold: threshold.unwrap_or(1),
         }))
@@ -660,6 +722,28 @@ where
     Ok(!public_keys.is_empty())
 }
 
+fn recovery_config<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    owner: Address,
+) -> namada_storage::Result<Option<RecoveryConfig>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    namada_account::read_recovery_config(ctx.state, &owner)
+}
+
+fn pending_recovery<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    owner: Address,
+) -> namada_storage::Result<Option<PendingRecovery>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    namada_account::read_pending_recovery(ctx.state, &owner)
+}
+
 #[cfg(test)]
 mod test {
     use namada_core::address;
@@ -25,6 +25,9 @@ where
     /// limit how many block heights in the past can the storage be
     /// queried for reading values.
     pub storage_read_past_height_limit: Option<u64>,
+    /// Taken from config `tx_history_index`. Whether the node indexes the tx
+    /// history of addresses.
+    pub tx_history_index: bool,
 }
 
 /// A `Router` handles parsing read-only query requests and dispatching them to
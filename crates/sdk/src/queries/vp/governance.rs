@@ -1,5 +1,10 @@
 // cd namada && cargo expand ledger::queries::vp::governance
 
+use std::collections::{BTreeMap, BTreeSet};
+
+use namada_core::address::Address;
+use namada_core::chain::Epoch;
+use namada_core::token;
 use namada_governance::parameters::GovernanceParameters;
 use namada_governance::storage::proposal::StorageProposal;
 use namada_governance::utils::{ProposalResult, Vote};
@@ -11,8 +16,11 @@ use crate::queries::types::RequestCtx;
 router! {GOV,
     ( "proposal" / [id: u64 ] ) -> Option<StorageProposal> = proposal_id,
     ( "proposal" / [id: u64 ] / "votes" ) -> Vec<Vote> = proposal_id_votes,
+    ( "proposal" / [id: u64 ] / "deposits" ) -> BTreeMap<Address, token::Amount> = proposal_id_deposits,
     ( "parameters" ) -> GovernanceParameters = parameters,
     ( "stored_proposal_result" / [id: u64] ) -> Option<ProposalResult> = proposal_result,
+    ( "representative" / [delegator: Address] / [epoch: opt Epoch] ) -> Option<Address> = representative,
+    ( "constituents" / [representative: Address] ) -> BTreeSet<Address> = constituents,
 }
 
 /// Query the provided proposal id
@@ -39,6 +47,18 @@ where
     namada_governance::storage::get_proposal_votes(ctx.state, id)
 }
 
+/// Query the deposits locked in the given proposal id by each depositor
+fn proposal_id_deposits<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    id: u64,
+) -> namada_storage::Result<BTreeMap<Address, token::Amount>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    namada_governance::storage::get_proposal_deposits(ctx.state, id)
+}
+
 /// Get the governance parameters
 fn parameters<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
@@ -61,3 +81,31 @@ where
 {
     namada_governance::storage::get_proposal_result(ctx.state, id)
 }
+
+/// Get the representative of a delegator that is effective in the given
+/// epoch, or in the current epoch if not specified
+fn representative<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    delegator: Address,
+    epoch: Option<Epoch>,
+) -> namada_storage::Result<Option<Address>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let epoch = epoch.unwrap_or(ctx.state.in_mem().last_epoch);
+    namada_governance::storage::get_representative(ctx.state, &delegator, epoch)
+}
+
+/// Get the constituents of a representative, including the ones whose
+/// delegation is not yet effective
+fn constituents<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    representative: Address,
+) -> namada_storage::Result<BTreeSet<Address>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    namada_governance::storage::get_constituents(ctx.state, &representative)
+}
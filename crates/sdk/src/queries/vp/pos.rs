@@ -9,16 +9,18 @@ use namada_core::chain::Epoch;
 use namada_core::collections::{HashMap, HashSet};
 use namada_core::key::{common, tm_consensus_key_raw_hash};
 use namada_core::token;
+use namada_proof_of_stake::liquid_staking::share_exchange_rate;
 use namada_proof_of_stake::parameters::PosParams;
 use namada_proof_of_stake::queries::{
     find_delegation_validators, find_delegations,
 };
 use namada_proof_of_stake::slashing::{
-    find_all_enqueued_slashes, find_all_slashes,
+    find_all_enqueued_slashes, find_all_slashes, slash_report,
 };
 use namada_proof_of_stake::storage::{
-    bond_handle, get_consensus_key, liveness_sum_missed_votes_handle,
-    read_all_validator_addresses,
+    bond_handle, get_consensus_key, is_auto_compound_enabled,
+    liveness_sum_missed_votes_handle, read_all_validator_addresses,
+    read_auto_compound_bonds,
     read_below_capacity_validator_set_addresses_with_stake,
     read_consensus_validator_set_addresses,
     read_consensus_validator_set_addresses_with_stake, read_pos_params,
@@ -30,8 +32,8 @@ use namada_proof_of_stake::storage::{
 pub use namada_proof_of_stake::types::ValidatorStateInfo;
 use namada_proof_of_stake::types::{
     BondId, BondsAndUnbondsDetail, BondsAndUnbondsDetails, CommissionPair,
-    LivenessInfo, Slash, ValidatorLiveness, ValidatorMetaData,
-    WeightedValidator,
+    LivenessInfo, ShareExchangeRate, Slash, SlashReport, ValidatorLiveness,
+    ValidatorMetaData, WeightedValidator,
 };
 use namada_proof_of_stake::{bond_amount, query_reward_tokens};
 use namada_state::{DBIter, KeySeg, StorageHasher, DB};
@@ -59,6 +61,9 @@ router! {POS,
         ( "slashes" / [validator: Address] )
             -> Vec<Slash> = validator_slashes,
 
+        ( "slash_report" / [validator: Address] / [block_height: u64] )
+            -> Option<SlashReport> = validator_slash_report,
+
         ( "commission" / [validator: Address] / [epoch: opt Epoch] )
             -> CommissionPair = validator_commission,
 
@@ -106,6 +111,15 @@ router! {POS,
     ( "rewards" / [validator: Address] / [source: opt Address] )
         -> token::Amount = rewards,
 
+    ( "auto_compound" / [validator: Address] / [source: opt Address] )
+        -> bool = auto_compound,
+
+    ( "auto_compound_bonds" / [source: opt Address] )
+        -> BTreeSet<BondId> = auto_compound_bonds,
+
+    ( "share_exchange_rate" / [validator: Address] / [epoch: opt Epoch] )
+        -> ShareExchangeRate = share_exchange_rate_of,
+
     ( "bond_with_slashing" / [source: Address] / [validator: Address] / [epoch: opt Epoch] )
         -> token::Amount = bond_with_slashing,
 
@@ -602,6 +616,56 @@ where
     )
 }
 
+fn auto_compound<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    validator: Address,
+    source: Option<Address>,
+) -> namada_storage::Result<bool>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let source = source.unwrap_or_else(|| validator.clone());
+    is_auto_compound_enabled(ctx.state, &source, &validator)
+}
+
+/// Find the bonds whose rewards are automatically compounded, optionally only
+/// the ones of the given `source`
+fn auto_compound_bonds<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    source: Option<Address>,
+) -> namada_storage::Result<BTreeSet<BondId>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    read_auto_compound_bonds(ctx.state, source.as_ref())
+}
+
+/// Find the exchange rate of the liquid staking shares of a validator for
+/// bonded tokens. Defaults to the pipeline epoch, at which shares are issued
+/// and redeemed.
+fn share_exchange_rate_of<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    validator: Address,
+    epoch: Option<Epoch>,
+) -> namada_storage::Result<ShareExchangeRate>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let params = read_pos_params::<_, governance::Store<_>>(ctx.state)?;
+    let epoch = epoch.unwrap_or(
+        ctx.state
+            .in_mem()
+            .last_epoch
+            .unchecked_add(params.pipeline_len),
+    );
+    share_exchange_rate::<_, governance::Store<_>, crate::token::Store<_>>(
+        ctx.state, &validator, epoch,
+    )
+}
+
 fn bonds_and_unbonds<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
     source: Option<Address>,
@@ -659,6 +723,20 @@ where
     slash_handle.iter(ctx.state)?.collect()
 }
 
+/// Report on the computation of a processed slash of a validator, identified
+/// by the block height of the infraction
+fn validator_slash_report<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    validator: Address,
+    block_height: u64,
+) -> namada_storage::Result<Option<SlashReport>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    slash_report::<_, governance::Store<_>>(ctx.state, &validator, block_height)
+}
+
 /// All slashes
 fn slashes<D, H, V, T>(
     ctx: RequestCtx<'_, D, H, V, T>,
@@ -858,6 +936,7 @@ mod test {
             vp_wasm_cache: (),
             tx_wasm_cache: (),
             storage_read_past_height_limit: None,
+            tx_history_index: false,
         };
         let result = POS.handle(ctx, &request);
         assert!(result.is_err());
@@ -6,6 +6,8 @@ use namada_proof_of_stake::rewards::{
     estimate_staking_reward_rate, PosRewardsRates,
 };
 use namada_state::{DBIter, StorageHasher, DB};
+use namada_token::allowance::{read_allowance, Allowance};
+use namada_token::vesting::{read_vesting_balance, VestingBalance};
 use namada_token::{
     get_effective_total_native_supply, read_denom, read_total_supply,
 };
@@ -17,6 +19,10 @@ router! {TOKEN,
     ( "total_supply" / [token: Address] ) -> token::Amount = total_supply,
     ( "effective_native_supply" ) -> token::Amount = effective_native_supply,
     ( "staking_rewards_rate" ) -> PosRewardsRates = staking_rewards_rate,
+    ( "allowance" / [token: Address] / [owner: Address] / [spender: Address] )
+        -> Option<Allowance> = allowance,
+    ( "vesting" / [token: Address] / [owner: Address] )
+        -> VestingBalance = vesting,
 }
 
 /// Get the number of decimal places (in base 10) for a
@@ -70,6 +76,33 @@ where
     >(ctx.state)
 }
 
+/// Get the allowance of `spender` to transfer the `token`s of `owner`
+fn allowance<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    token: Address,
+    owner: Address,
+    spender: Address,
+) -> namada_storage::Result<Option<Allowance>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    read_allowance(ctx.state, &token, &owner, &spender)
+}
+
+/// Get the locked and spendable parts of the `token` balance of `owner`
+fn vesting<D, H, V, T>(
+    ctx: RequestCtx<'_, D, H, V, T>,
+    token: Address,
+    owner: Address,
+) -> namada_storage::Result<VestingBalance>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    read_vesting_balance(ctx.state, &token, &owner)
+}
+
 pub mod client_only_methods {
     use borsh::BorshDeserialize;
     use namada_core::address::Address;
@@ -10,7 +10,7 @@ use borsh::BorshDeserialize;
 use masp_primitives::asset_type::AssetType;
 use masp_primitives::merkle_tree::MerklePath;
 use masp_primitives::sapling::Node;
-use namada_account::Account;
+use namada_account::{Account, PendingRecovery, RecoveryConfig};
 use namada_core::address::{Address, InternalAddress};
 use namada_core::arith::checked;
 use namada_core::chain::{BlockHeight, Epoch};
@@ -39,14 +39,17 @@ use namada_ibc::storage::{
 };
 use namada_io::{display_line, edisplay_line, Client, Io};
 use namada_parameters::{storage as params_storage, EpochDuration};
-use namada_proof_of_stake::parameters::PosParams;
+use namada_proof_of_stake::parameters::{OwnedPosParams, PosParams};
 use namada_proof_of_stake::rewards::PosRewardsRates;
+use namada_proof_of_stake::storage_key as pos_storage_key;
 use namada_proof_of_stake::types::{
-    BondsAndUnbondsDetails, CommissionPair, LivenessInfo, ValidatorMetaData,
-    WeightedValidator,
+    BondsAndUnbondsDetails, CommissionPair, LivenessInfo, ShareExchangeRate,
+    SlashReport, ValidatorMetaData, WeightedValidator,
 };
 use namada_state::LastBlock;
+use namada_token::allowance::Allowance;
 use namada_token::masp::MaspTokenRewardData;
+use namada_token::vesting::VestingBalance;
 use namada_tx::data::{BatchedTxResult, DryRunResult, ResultCode, TxResult};
 use namada_tx::event::{Batch as BatchAttr, Code as CodeAttr};
 use serde::Serialize;
@@ -54,6 +57,7 @@ use serde::Serialize;
 use crate::args::InputAmount;
 use crate::control_flow::time;
 use crate::error::{EncodingError, Error, QueryError, TxSubmitError};
+use crate::events::history::TxHistoryPage;
 use crate::events::{extend, Event};
 use crate::internal_macros::echo_error;
 use crate::queries::vp::pos::{
@@ -209,6 +213,21 @@ pub async fn query_results<C: namada_io::Client + Sync>(
     convert_response::<C, _>(RPC.shell().read_results(client).await)
 }
 
+/// Query a page of the tx history of an address. Pages are numbered from 0.
+/// The history is only available from nodes that index it.
+pub async fn query_tx_history<C: namada_io::Client + Sync>(
+    client: &C,
+    owner: &Address,
+    page: u64,
+    per_page: u64,
+) -> Result<TxHistoryPage, Error> {
+    convert_response::<C, _>(
+        RPC.shell()
+            .tx_history(client, owner, &page, &per_page)
+            .await,
+    )
+}
+
 /// Query token amount of owner.
 pub async fn get_token_balance<C: namada_io::Client + Sync>(
     client: &C,
@@ -247,6 +266,32 @@ pub async fn get_staking_rewards_rate<C: Client + Sync>(
     )
 }
 
+/// Query the allowance of `spender` to transfer the `token`s of `owner`.
+pub async fn get_token_allowance<C: Client + Sync>(
+    client: &C,
+    token: &Address,
+    owner: &Address,
+    spender: &Address,
+) -> Result<Option<Allowance>, error::Error> {
+    convert_response::<C, _>(
+        RPC.vp()
+            .token()
+            .allowance(client, token, owner, spender)
+            .await,
+    )
+}
+
+/// Query the locked and spendable parts of the `token` balance of `owner`.
+pub async fn get_token_vesting_balance<C: Client + Sync>(
+    client: &C,
+    token: &Address,
+    owner: &Address,
+) -> Result<VestingBalance, error::Error> {
+    convert_response::<C, _>(
+        RPC.vp().token().vesting(client, token, owner).await,
+    )
+}
+
 /// Check if the given address is a known validator.
 pub async fn is_validator<C: namada_io::Client + Sync>(
     client: &C,
@@ -751,7 +796,42 @@ impl TxResponse {
 pub async fn get_pos_params<C: namada_io::Client + Sync>(
     client: &C,
 ) -> Result<PosParams, error::Error> {
-    convert_response::<C, _>(RPC.vp().pos().pos_params(client).await)
+    let mut params: PosParams =
+        convert_response::<C, _>(RPC.vp().pos().pos_params(client).await)?;
+    // The validator policy parameters are stored under their own keys and
+    // they are not encoded with the rest of the parameters
+    let defaults = OwnedPosParams::default();
+    params.owned.min_self_bond_ratio = query_storage_value_opt(
+        client,
+        &pos_storage_key::min_self_bond_ratio_key(),
+    )
+    .await?
+    .unwrap_or(defaults.min_self_bond_ratio);
+    params.owned.max_commission_rate = query_storage_value_opt(
+        client,
+        &pos_storage_key::max_commission_rate_key(),
+    )
+    .await?
+    .unwrap_or(defaults.max_commission_rate);
+    Ok(params)
+}
+
+/// Query a storage value and decode it with [`BorshDeserialize`], if the key
+/// is present in storage.
+async fn query_storage_value_opt<C, T>(
+    client: &C,
+    key: &Key,
+) -> Result<Option<T>, error::Error>
+where
+    T: BorshDeserialize,
+    C: namada_io::Client + Sync,
+{
+    let (bytes, _proof) =
+        query_storage_value_bytes(client, key, None, false).await?;
+    bytes
+        .map(|bytes| T::try_from_slice(&bytes[..]))
+        .transpose()
+        .map_err(|err| Error::from(EncodingError::Decoding(err.to_string())))
 }
 
 /// Get all validators in the given epoch
@@ -927,6 +1007,35 @@ pub async fn query_incoming_redelegations<C: namada_io::Client + Sync>(
     )
 }
 
+/// Check if the rewards of a bond are automatically compounded
+pub async fn query_auto_compound<C: namada_io::Client + Sync>(
+    client: &C,
+    validator: &Address,
+    source: Option<&Address>,
+) -> Result<bool, error::Error> {
+    convert_response::<C, bool>(
+        RPC.vp()
+            .pos()
+            .auto_compound(client, validator, &source.cloned())
+            .await,
+    )
+}
+
+/// Query the exchange rate of the liquid staking shares of a validator for
+/// bonded tokens. Defaults to the pipeline epoch.
+pub async fn query_share_exchange_rate<C: namada_io::Client + Sync>(
+    client: &C,
+    validator: &Address,
+    epoch: Option<Epoch>,
+) -> Result<ShareExchangeRate, error::Error> {
+    convert_response::<C, ShareExchangeRate>(
+        RPC.vp()
+            .pos()
+            .share_exchange_rate(client, validator, &epoch)
+            .await,
+    )
+}
+
 /// Query a validator's bonds for a given epoch
 pub async fn query_bond<C: namada_io::Client + Sync>(
     client: &C,
@@ -952,6 +1061,21 @@ pub async fn query_last_infraction_epoch<C: namada_io::Client + Sync>(
     )
 }
 
+/// Query the report on how a processed slash of a validator was computed, by
+/// the block height of the infraction
+pub async fn query_slash_report<C: namada_io::Client + Sync>(
+    client: &C,
+    validator: &Address,
+    block_height: u64,
+) -> Result<Option<SlashReport>, error::Error> {
+    convert_response::<C, _>(
+        RPC.vp()
+            .pos()
+            .validator_slash_report(client, validator, &block_height)
+            .await,
+    )
+}
+
 /// Query the accunt substorage space of an address
 pub async fn get_account_info<C: namada_io::Client + Sync>(
     client: &C,
@@ -962,6 +1086,23 @@ pub async fn get_account_info<C: namada_io::Client + Sync>(
     )
 }
 
+/// Query the social recovery config of an account, if it opted in to social
+/// recovery
+pub async fn get_recovery_config<C: namada_io::Client + Sync>(
+    client: &C,
+    owner: &Address,
+) -> Result<Option<RecoveryConfig>, error::Error> {
+    convert_response::<C, _>(RPC.shell().recovery_config(client, owner).await)
+}
+
+/// Query the pending social recovery of an account, if any
+pub async fn get_pending_recovery<C: namada_io::Client + Sync>(
+    client: &C,
+    owner: &Address,
+) -> Result<Option<PendingRecovery>, error::Error> {
+    convert_response::<C, _>(RPC.shell().pending_recovery(client, owner).await)
+}
+
 /// Query if the public_key is revealed
 pub async fn is_public_key_revealed<C: namada_io::Client + Sync>(
     client: &C,
@@ -1019,6 +1160,10 @@ pub async fn query_proposal_result<C: namada_io::Client + Sync>(
                 .await
                 .unwrap_or_default();
             let tally_type = proposal.get_tally_type(is_author_pgf_steward);
+            let tally_params = convert_response::<C, GovernanceParameters>(
+                RPC.vp().gov().parameters(client).await,
+            )?
+            .tally_params(&proposal.r#type, is_author_pgf_steward);
             #[allow(clippy::disallowed_methods)]
             let total_active_voting_power =
                 get_total_active_voting_power(client, tally_epoch)
@@ -1069,6 +1214,7 @@ pub async fn query_proposal_result<C: namada_io::Client + Sync>(
                 proposal_votes,
                 total_active_voting_power,
                 tally_type,
+                tally_params,
             )?
         }
     };
@@ -1169,6 +1315,44 @@ pub async fn query_proposal_votes<C: namada_io::Client + Sync>(
     )
 }
 
+/// Get the deposits locked in a proposal by each depositor
+pub async fn query_proposal_deposits<C: namada_io::Client + Sync>(
+    client: &C,
+    proposal_id: u64,
+) -> Result<BTreeMap<Address, token::Amount>, error::Error> {
+    convert_response::<C, BTreeMap<Address, token::Amount>>(
+        RPC.vp()
+            .gov()
+            .proposal_id_deposits(client, &proposal_id)
+            .await,
+    )
+}
+
+/// Get the governance representative of a delegator in the given epoch, or in
+/// the current epoch if not specified
+pub async fn query_governance_representative<C: namada_io::Client + Sync>(
+    client: &C,
+    delegator: &Address,
+    epoch: Option<Epoch>,
+) -> Result<Option<Address>, error::Error> {
+    convert_response::<C, Option<Address>>(
+        RPC.vp()
+            .gov()
+            .representative(client, delegator, &epoch)
+            .await,
+    )
+}
+
+/// Get the constituents of a governance representative
+pub async fn query_governance_constituents<C: namada_io::Client + Sync>(
+    client: &C,
+    representative: &Address,
+) -> Result<BTreeSet<Address>, error::Error> {
+    convert_response::<C, BTreeSet<Address>>(
+        RPC.vp().gov().constituents(client, representative).await,
+    )
+}
+
 /// Query the information to estimate next epoch start
 pub async fn query_next_epoch_info<C: namada_io::Client + Sync>(
     client: &C,
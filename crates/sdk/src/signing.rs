@@ -12,7 +12,9 @@ use masp_primitives::asset_type::AssetType;
 use masp_primitives::transaction::components::sapling::fees::{
     InputView, OutputView,
 };
-use namada_account::{AccountPublicKeysMap, InitAccount, UpdateAccount};
+use namada_account::{
+    AccountPublicKeysMap, InitAccount, UpdateAccount, DEFAULT_PUBLIC_KEY_WEIGHT,
+};
 use namada_core::address::{Address, ImplicitAddress, InternalAddress, MASP};
 use namada_core::arith::checked;
 use namada_core::collections::{HashMap, HashSet};
@@ -45,16 +47,17 @@ use tokio::sync::RwLock;
 use crate::args::SdkTypes;
 use crate::borsh::BorshSerializeExt;
 use crate::error::{EncodingError, Error, TxSubmitError};
-use crate::eth_bridge_pool::PendingTransfer;
+use crate::eth_bridge_pool::BridgePoolChange;
 use crate::governance::storage::proposal::{AddRemove, PGFAction, PGFTarget};
 use crate::rpc::validate_amount;
 use crate::token::Account;
 use crate::tx::{
-    Commitment, TX_BECOME_VALIDATOR_WASM, TX_BOND_WASM, TX_BRIDGE_POOL_WASM,
-    TX_CHANGE_COMMISSION_WASM, TX_CHANGE_CONSENSUS_KEY_WASM,
-    TX_CHANGE_METADATA_WASM, TX_CLAIM_REWARDS_WASM,
-    TX_DEACTIVATE_VALIDATOR_WASM, TX_IBC_WASM, TX_INIT_ACCOUNT_WASM,
-    TX_INIT_PROPOSAL, TX_REACTIVATE_VALIDATOR_WASM, TX_REDELEGATE_WASM,
+    Commitment, TX_AUTO_COMPOUND_WASM, TX_BECOME_VALIDATOR_WASM, TX_BOND_WASM,
+    TX_BRIDGE_POOL_WASM, TX_CHANGE_COMMISSION_WASM,
+    TX_CHANGE_CONSENSUS_KEY_WASM, TX_CHANGE_METADATA_WASM,
+    TX_CLAIM_REWARDS_WASM, TX_DEACTIVATE_VALIDATOR_WASM, TX_IBC_WASM,
+    TX_INIT_ACCOUNT_WASM, TX_INIT_PROPOSAL, TX_LIQUID_BOND_WASM,
+    TX_LIQUID_UNBOND_WASM, TX_REACTIVATE_VALIDATOR_WASM, TX_REDELEGATE_WASM,
     TX_RESIGN_STEWARD, TX_REVEAL_PK, TX_TRANSFER_WASM, TX_UNBOND_WASM,
     TX_UNJAIL_VALIDATOR_WASM, TX_UPDATE_ACCOUNT_WASM,
     TX_UPDATE_STEWARD_COMMISSION, TX_VOTE_PROPOSAL, TX_WITHDRAW_WASM,
@@ -265,7 +268,7 @@ where
     }
 
     // Then try to sign the raw header with private keys in the software wallet
-    if let Some(account_public_keys_map) = signing_data.account_public_keys_map
+    if let Some(account_public_keys_map) = &signing_data.account_public_keys_map
     {
         let mut wallet = wallet.write().await;
         let mut signing_tx_keypairs = vec![];
@@ -287,8 +290,8 @@ where
         if !signing_tx_keypairs.is_empty() {
             tx.sign_raw(
                 signing_tx_keypairs,
-                account_public_keys_map,
-                signing_data.owner,
+                account_public_keys_map.clone(),
+                signing_data.owner.clone(),
             );
         }
     }
@@ -350,15 +353,25 @@ where
     // as a safeguard to prevent the transmission of private data to the
     // network.
     tx.protocol_filter();
-    // Then make sure that the number of public keys used exceeds the threshold
-    let used_pubkeys_len = used_pubkeys
-        .len()
-        .try_into()
-        .expect("Public keys associated with account exceed 127");
-    if used_pubkeys_len < signing_data.threshold {
+    // Then make sure that the weight of the public keys used meets the
+    // threshold
+    let pubkey_weight = |pubkey: &common::PublicKey| {
+        signing_data
+            .account_public_keys_map
+            .as_ref()
+            .and_then(|map| {
+                map.get_index_from_public_key(pubkey)
+                    .map(|index| map.get_weight_from_index(index))
+            })
+            .unwrap_or(DEFAULT_PUBLIC_KEY_WEIGHT)
+    };
+    let used_weight = used_pubkeys.iter().fold(0_u32, |acc, pubkey| {
+        acc.saturating_add(u32::from(pubkey_weight(pubkey)))
+    });
+    if used_weight < u32::from(signing_data.threshold) {
         Err(Error::from(TxSubmitError::MissingSigningKeys(
             signing_data.threshold,
-            used_pubkeys_len,
+            used_weight,
         )))
     } else {
         Ok(())
@@ -842,6 +855,7 @@ impl<'a> Display for LedgerProposalVote<'a> {
             ProposalVote::Yay => write!(f, "yay"),
             ProposalVote::Nay => write!(f, "nay"),
             ProposalVote::Abstain => write!(f, "abstain"),
+            ProposalVote::NoWithVeto => write!(f, "veto"),
         }
     }
 }
@@ -868,6 +882,29 @@ fn proposal_type_to_ledger_vector(
             output
                 .push(format!("Proposal hash : {}", HEXLOWER.encode(&extra.0)));
         }
+        ProposalType::DefaultWithWasmBatch(steps) => {
+            output.push("Proposal type : Default batch".to_string());
+            for (index, step) in steps.iter().enumerate() {
+                let extra = tx
+                    .get_section(&step.code)
+                    .and_then(|x| Section::extra_data_sec(x.as_ref()))
+                    .ok_or_else(|| {
+                        Error::Other("unable to load vp code".to_string())
+                    })?
+                    .code
+                    .hash();
+                output.push(format!(
+                    "Step {} hash : {}",
+                    index,
+                    HEXLOWER.encode(&extra.0)
+                ));
+                output.push(format!(
+                    "Step {} data : {}",
+                    index,
+                    HEXLOWER.encode(&step.data)
+                ));
+            }
+        }
         ProposalType::PGFSteward(actions) => {
             output.push("Proposal type : PGF Steward".to_string());
             let mut actions = actions.iter().collect::<Vec<_>>();
@@ -1112,6 +1149,12 @@ pub async fn to_ledger_vector(
                     .iter()
                     .map(|k| format!("Public key : {}", k)),
             );
+            tv.output.extend(
+                init_account
+                    .weights
+                    .iter()
+                    .map(|w| format!("Weight : {}", w)),
+            );
             tv.output.extend(vec![
                 format!("Threshold : {}", init_account.threshold),
                 format!("VP type : {}", vp_code),
@@ -1123,6 +1166,12 @@ pub async fn to_ledger_vector(
                     .iter()
                     .map(|k| format!("Public key : {}", k)),
             );
+            tv.output_expert.extend(
+                init_account
+                    .weights
+                    .iter()
+                    .map(|w| format!("Weight : {}", w)),
+            );
             tv.output_expert.extend(vec![
                 format!("Threshold : {}", init_account.threshold),
                 format!("VP type : {}", HEXLOWER.encode(&extra.code.hash().0)),
@@ -1323,6 +1372,12 @@ pub async fn to_ledger_vector(
                     .iter()
                     .map(|k| format!("Public key : {}", k)),
             );
+            tv.output.extend(
+                update_account
+                    .weights
+                    .iter()
+                    .map(|w| format!("Weight : {}", w)),
+            );
             if update_account.threshold.is_some() {
                 tv.output.extend(vec![format!(
                     "Threshold : {}",
@@ -1359,6 +1414,12 @@ pub async fn to_ledger_vector(
                     .iter()
                     .map(|k| format!("Public key : {}", k)),
             );
+            tv.output_expert.extend(
+                update_account
+                    .weights
+                    .iter()
+                    .map(|w| format!("Weight : {}", w)),
+            );
             if let Some(threshold) = update_account.threshold {
                 tv.output_expert
                     .extend(vec![format!("Threshold : {}", threshold,)])
@@ -1786,6 +1847,77 @@ pub async fn to_ledger_vector(
             }
             tv.output_expert
                 .push(format!("Validator : {}", claim.validator));
+        } else if code_sec.tag == Some(TX_AUTO_COMPOUND_WASM.to_string()) {
+            let auto_compound = pos::AutoCompound::try_from_slice(
+                &tx.data(cmt)
+                    .ok_or_else(|| Error::Other("Invalid Data".to_string()))?,
+            )
+            .map_err(|err| {
+                Error::from(EncodingError::Conversion(err.to_string()))
+            })?;
+
+            tv.name = "Auto_Compound_0".to_string();
+
+            tv.output.push("Type : Auto Compound".to_string());
+            if let Some(source) = auto_compound.source.as_ref() {
+                tv.output.push(format!("Source : {}", source));
+            }
+            tv.output
+                .push(format!("Validator : {}", auto_compound.validator));
+            tv.output
+                .push(format!("Enabled : {}", auto_compound.enabled));
+
+            if let Some(source) = auto_compound.source.as_ref() {
+                tv.output_expert.push(format!("Source : {}", source));
+            }
+            tv.output_expert
+                .push(format!("Validator : {}", auto_compound.validator));
+            tv.output_expert
+                .push(format!("Enabled : {}", auto_compound.enabled));
+        } else if code_sec.tag == Some(TX_LIQUID_BOND_WASM.to_string()) {
+            let bond = pos::LiquidBond::try_from_slice(
+                &tx.data(cmt)
+                    .ok_or_else(|| Error::Other("Invalid Data".to_string()))?,
+            )
+            .map_err(|err| {
+                Error::from(EncodingError::Conversion(err.to_string()))
+            })?;
+
+            tv.name = "Liquid_Bond_0".to_string();
+
+            let output = vec![
+                format!("Source : {}", bond.source),
+                format!("Validator : {}", bond.validator),
+                format!(
+                    "Amount : NAM {}",
+                    to_ledger_decimal(&bond.amount.to_string_native())
+                ),
+            ];
+            tv.output.push("Type : Liquid Bond".to_string());
+            tv.output.extend(output.clone());
+            tv.output_expert.extend(output);
+        } else if code_sec.tag == Some(TX_LIQUID_UNBOND_WASM.to_string()) {
+            let unbond = pos::LiquidUnbond::try_from_slice(
+                &tx.data(cmt)
+                    .ok_or_else(|| Error::Other("Invalid Data".to_string()))?,
+            )
+            .map_err(|err| {
+                Error::from(EncodingError::Conversion(err.to_string()))
+            })?;
+
+            tv.name = "Liquid_Unbond_0".to_string();
+
+            let output = vec![
+                format!("Source : {}", unbond.source),
+                format!("Validator : {}", unbond.validator),
+                format!(
+                    "Shares : {}",
+                    to_ledger_decimal(&unbond.amount.to_string_native())
+                ),
+            ];
+            tv.output.push("Type : Liquid Unbond".to_string());
+            tv.output.extend(output.clone());
+            tv.output_expert.extend(output);
         } else if code_sec.tag == Some(TX_CHANGE_COMMISSION_WASM.to_string()) {
             let commission_change = pos::CommissionChange::try_from_slice(
                 &tx.data(cmt)
@@ -1842,6 +1974,18 @@ pub async fn to_ledger_vector(
             if let Some(avatar) = metadata_change.avatar {
                 other_items.push(format!("Avatar : {}", avatar));
             }
+            if let Some(reward_destination) = metadata_change.reward_destination
+            {
+                if reward_destination.is_empty() {
+                    other_items.push("Reward destination : validator".into());
+                }
+                for (address, ratio) in reward_destination {
+                    other_items.push(format!(
+                        "Reward destination : {} ({})",
+                        address, ratio
+                    ));
+                }
+            }
             if let Some(commission_rate) = metadata_change.commission_rate {
                 other_items
                     .push(format!("Commission rate : {}", commission_rate));
@@ -2011,7 +2155,7 @@ pub async fn to_ledger_vector(
 
             tv.output_expert.push(format!("Steward : {}", address));
         } else if code_sec.tag == Some(TX_BRIDGE_POOL_WASM.to_string()) {
-            let transfer = PendingTransfer::try_from_slice(
+            let change = BridgePoolChange::try_from_slice(
                 &tx.data(cmt)
                     .ok_or_else(|| Error::Other("Invalid Data".to_string()))?,
             )
@@ -2019,30 +2163,52 @@ pub async fn to_ledger_vector(
                 Error::from(EncodingError::Conversion(err.to_string()))
             })?;
 
-            tv.name = "Bridge_Pool_Transfer_0".to_string();
+            match change {
+                BridgePoolChange::AddTransfer { transfer, expiry } => {
+                    tv.name = "Bridge_Pool_Transfer_0".to_string();
 
-            tv.output.extend(vec![
-                format!("Type : Bridge Pool Transfer"),
-                format!("Transfer Kind : {}", transfer.transfer.kind),
-                format!("Transfer Sender : {}", transfer.transfer.sender),
-                format!("Transfer Recipient : {}", transfer.transfer.recipient),
-                format!("Transfer Asset : {}", transfer.transfer.asset),
-                format!("Transfer Amount : {}", transfer.transfer.amount),
-                format!("Gas Payer : {}", transfer.gas_fee.payer),
-                format!("Gas Token : {}", transfer.gas_fee.token),
-                format!("Gas Amount : {}", transfer.gas_fee.amount),
-            ]);
+                    let mut output = vec![
+                        format!("Transfer Kind : {}", transfer.transfer.kind),
+                        format!(
+                            "Transfer Sender : {}",
+                            transfer.transfer.sender
+                        ),
+                        format!(
+                            "Transfer Recipient : {}",
+                            transfer.transfer.recipient
+                        ),
+                        format!("Transfer Asset : {}", transfer.transfer.asset),
+                        format!(
+                            "Transfer Amount : {}",
+                            transfer.transfer.amount
+                        ),
+                        format!("Gas Payer : {}", transfer.gas_fee.payer),
+                        format!("Gas Token : {}", transfer.gas_fee.token),
+                        format!("Gas Amount : {}", transfer.gas_fee.amount),
+                    ];
+                    if let Some(expiry) = expiry {
+                        output.push(format!("Expiry Height : {}", expiry));
+                    }
 
-            tv.output_expert.extend(vec![
-                format!("Transfer Kind : {}", transfer.transfer.kind),
-                format!("Transfer Sender : {}", transfer.transfer.sender),
-                format!("Transfer Recipient : {}", transfer.transfer.recipient),
-                format!("Transfer Asset : {}", transfer.transfer.asset),
-                format!("Transfer Amount : {}", transfer.transfer.amount),
-                format!("Gas Payer : {}", transfer.gas_fee.payer),
-                format!("Gas Token : {}", transfer.gas_fee.token),
-                format!("Gas Amount : {}", transfer.gas_fee.amount),
-            ]);
+                    tv.output.push("Type : Bridge Pool Transfer".to_string());
+                    tv.output.extend(output.clone());
+                    tv.output_expert.extend(output);
+                }
+                BridgePoolChange::BumpFee { transfer, amount } => {
+                    tv.name = "Bridge_Pool_Fee_Bump_0".to_string();
+
+                    tv.output.extend(vec![
+                        format!("Type : Bridge Pool Fee Bump"),
+                        format!("Transfer : {}", transfer),
+                        format!("Amount : {}", amount),
+                    ]);
+
+                    tv.output_expert.extend(vec![
+                        format!("Transfer : {}", transfer),
+                        format!("Amount : {}", amount),
+                    ]);
+                }
+            }
         } else {
             tv.name = "Custom_0".to_string();
             tv.output.push("Type : Custom".to_string());
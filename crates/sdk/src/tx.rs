@@ -1,7 +1,7 @@
 //! SDK functions to construct different types of transactions
 
 use std::borrow::Cow;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs::File;
 use std::path::{Path, PathBuf};
 use std::time::Duration;
@@ -21,7 +21,10 @@ use masp_primitives::transaction::components::transparent::fees::{
 use masp_primitives::transaction::components::I128Sum;
 use masp_primitives::transaction::Transaction as MaspTransaction;
 use masp_primitives::zip32::PseudoExtendedKey;
-use namada_account::{InitAccount, UpdateAccount};
+use namada_account::{
+    ConfigureRecovery, InitAccount, InitiateRecovery, RecoveryConfig,
+    UpdateAccount,
+};
 use namada_core::address::{Address, IBC, MASP};
 use namada_core::arith::checked;
 use namada_core::chain::Epoch;
@@ -49,17 +52,21 @@ use namada_governance::cli::onchain::{
 };
 use namada_governance::pgf::cli::steward::Commission;
 use namada_governance::storage::proposal::{
-    InitProposalData, ProposalType, VoteProposalData,
+    AmendProposalData, CancelProposalData, DelegateVotesData,
+    DepositProposalData, InitProposalData, ProposalType, VoteProposalData,
 };
 use namada_governance::storage::vote::ProposalVote;
 use namada_ibc::storage::channel_key;
 use namada_ibc::trace::is_nft_trace;
 use namada_ibc::{MsgNftTransfer, MsgTransfer};
 use namada_io::{display_line, edisplay_line, Client, Io};
+use namada_proof_of_stake::liquid_staking::share_token_address;
 use namada_proof_of_stake::parameters::{
-    PosParams, MAX_VALIDATOR_METADATA_LEN,
+    PosParams, MAX_REWARD_DESTINATIONS, MAX_VALIDATOR_METADATA_LEN,
+};
+use namada_proof_of_stake::types::{
+    CommissionPair, RewardDestination, ValidatorState,
 };
-use namada_proof_of_stake::types::{CommissionPair, ValidatorState};
 use namada_token as token;
 use namada_token::masp::shielded_wallet::ShieldedApi;
 use namada_token::masp::{MaspFeeData, MaspTransferData, ShieldedTransfer};
@@ -107,12 +114,34 @@ pub const TX_REACTIVATE_VALIDATOR_WASM: &str = "tx_reactivate_validator.wasm";
 pub const TX_INIT_PROPOSAL: &str = "tx_init_proposal.wasm";
 /// Vote transaction WASM path
 pub const TX_VOTE_PROPOSAL: &str = "tx_vote_proposal.wasm";
+/// Delegate governance votes transaction WASM path
+pub const TX_DELEGATE_VOTES_WASM: &str = "tx_delegate_votes.wasm";
+/// Proposal deposit transaction WASM path
+pub const TX_DEPOSIT_PROPOSAL_WASM: &str = "tx_deposit_proposal.wasm";
+/// Proposal cancellation transaction WASM path
+pub const TX_CANCEL_PROPOSAL_WASM: &str = "tx_cancel_proposal.wasm";
+/// Proposal amendment transaction WASM path
+pub const TX_AMEND_PROPOSAL_WASM: &str = "tx_amend_proposal.wasm";
 /// Reveal public key transaction WASM path
 pub const TX_REVEAL_PK: &str = "tx_reveal_pk.wasm";
 /// Update validity predicate WASM path
 pub const TX_UPDATE_ACCOUNT_WASM: &str = "tx_update_account.wasm";
+/// Configure social recovery transaction WASM path
+pub const TX_CONFIGURE_RECOVERY_WASM: &str = "tx_configure_recovery.wasm";
+/// Initiate social recovery transaction WASM path
+pub const TX_INITIATE_RECOVERY_WASM: &str = "tx_initiate_recovery.wasm";
+/// Cancel social recovery transaction WASM path
+pub const TX_CANCEL_RECOVERY_WASM: &str = "tx_cancel_recovery.wasm";
+/// Finalize social recovery transaction WASM path
+pub const TX_FINALIZE_RECOVERY_WASM: &str = "tx_finalize_recovery.wasm";
 /// Transparent transfer transaction WASM path
 pub const TX_TRANSFER_WASM: &str = "tx_transfer.wasm";
+/// Token allowance approval transaction WASM path
+pub const TX_APPROVE_WASM: &str = "tx_approve.wasm";
+/// Transfer from a token allowance transaction WASM path
+pub const TX_TRANSFER_FROM_WASM: &str = "tx_transfer_from.wasm";
+/// Vesting transfer transaction WASM path
+pub const TX_VESTING_TRANSFER_WASM: &str = "tx_vesting_transfer.wasm";
 /// IBC transaction WASM path
 pub const TX_IBC_WASM: &str = "tx_ibc.wasm";
 /// User validity predicate WASM path
@@ -125,6 +154,12 @@ pub const TX_UNBOND_WASM: &str = "tx_unbond.wasm";
 pub const TX_WITHDRAW_WASM: &str = "tx_withdraw.wasm";
 /// Claim-rewards WASM path
 pub const TX_CLAIM_REWARDS_WASM: &str = "tx_claim_rewards.wasm";
+/// Auto-compound WASM path
+pub const TX_AUTO_COMPOUND_WASM: &str = "tx_auto_compound.wasm";
+/// Liquid bond WASM path
+pub const TX_LIQUID_BOND_WASM: &str = "tx_liquid_bond.wasm";
+/// Liquid unbond WASM path
+pub const TX_LIQUID_UNBOND_WASM: &str = "tx_liquid_unbond.wasm";
 /// Bridge pool WASM path
 pub const TX_BRIDGE_POOL_WASM: &str = "tx_bridge_pool.wasm";
 /// Change commission WASM path
@@ -712,6 +747,19 @@ pub async fn build_validator_commission_change(
                 *rate,
             )));
         }
+        if *rate > params.max_commission_rate {
+            edisplay_line!(
+                context.io(),
+                "New rate {} is larger than the maximum commission rate {}",
+                rate,
+                params.max_commission_rate
+            );
+            if !tx_args.force {
+                return Err(Error::from(TxSubmitError::InvalidCommissionRate(
+                    *rate,
+                )));
+            }
+        }
 
         let pipeline_epoch_minus_one =
             epoch.unchecked_add(params.pipeline_len - 1);
@@ -821,6 +869,7 @@ pub async fn build_validator_metadata_change(
         discord_handle,
         avatar,
         name,
+        reward_destination,
         commission_rate,
         tx_code_path,
     }: &args::MetaDataChange,
@@ -935,6 +984,32 @@ pub async fn build_validator_metadata_change(
         }
     }
 
+    // If there's a new reward destination, it must either be empty to reset
+    // it or split the whole commission among distinct addresses
+    let reward_destination = reward_destination.as_ref().map(|destination| {
+        let split: BTreeMap<Address, Dec> =
+            destination.iter().cloned().collect();
+        (split, destination.len())
+    });
+    if let Some((split, len)) = reward_destination.as_ref() {
+        let is_valid = split.is_empty()
+            || (split.len() == *len
+                && RewardDestination(split.clone()).is_valid());
+        if !is_valid {
+            edisplay_line!(
+                context.io(),
+                "Invalid reward destination, the commission must be split \
+                 among 1 to {MAX_REWARD_DESTINATIONS} distinct addresses with \
+                 positive ratios that sum up to 1"
+            );
+            if !tx_args.force {
+                return Err(Error::from(
+                    TxSubmitError::InvalidRewardDestination,
+                ));
+            }
+        }
+    }
+
     // If there's a new commission rate, it must be valid
     if let Some(rate) = commission_rate.as_ref() {
         if *rate < Dec::zero() || *rate > Dec::one() {
@@ -949,6 +1024,19 @@ pub async fn build_validator_metadata_change(
                 )));
             }
         }
+        if *rate > params.max_commission_rate {
+            edisplay_line!(
+                context.io(),
+                "New rate {} is larger than the maximum commission rate {}",
+                rate,
+                params.max_commission_rate
+            );
+            if !tx_args.force {
+                return Err(Error::from(TxSubmitError::InvalidCommissionRate(
+                    *rate,
+                )));
+            }
+        }
         let pipeline_epoch_minus_one =
             epoch.unchecked_add(params.pipeline_len - 1);
 
@@ -1025,6 +1113,7 @@ pub async fn build_validator_metadata_change(
         discord_handle: discord_handle.clone(),
         avatar: avatar.clone(),
         name: name.clone(),
+        reward_destination: reward_destination.map(|(split, _len)| split),
         commission_rate: *commission_rate,
     };
 
@@ -1747,6 +1836,65 @@ pub async fn build_claim_rewards(
     .map(|tx| (tx, signing_data))
 }
 
+/// Submit transaction to enable or disable the automatic compounding of the
+/// rewards of a bond
+pub async fn build_auto_compound(
+    context: &impl Namada,
+    args::AutoCompound {
+        tx: tx_args,
+        validator,
+        source,
+        enabled,
+        tx_code_path,
+    }: &args::AutoCompound,
+) -> Result<(Tx, SigningTxData)> {
+    let default_address = source.clone().unwrap_or(validator.clone());
+    let default_signer = Some(default_address.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx_args,
+        Some(default_address),
+        default_signer,
+        vec![],
+        false,
+    )
+    .await?;
+    let (fee_amount, _) =
+        validate_transparent_fee(context, tx_args, &signing_data.fee_payer)
+            .await?;
+
+    // Check that the validator address is actually a validator
+    let validator =
+        known_validator_or_err(validator.clone(), tx_args.force, context)
+            .await?;
+
+    // Check that the source address exists on chain
+    let source = match source.clone() {
+        Some(source) => source_exists_or_err(source, tx_args.force, context)
+            .await
+            .map(Some),
+        None => Ok(source.clone()),
+    }?;
+
+    let data = pos::AutoCompound {
+        validator,
+        source,
+        enabled: *enabled,
+    };
+
+    build(
+        context,
+        tx_args,
+        tx_code_path.clone(),
+        data,
+        do_nothing,
+        fee_amount,
+        &signing_data.fee_payer,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
 /// Submit a transaction to unbond
 pub async fn build_unbond(
     context: &impl Namada,
@@ -2100,6 +2248,178 @@ pub async fn build_bond(
     .map(|tx| (tx, signing_data))
 }
 
+/// Submit a transaction to bond tokens to the liquid staking pool of a
+/// validator
+pub async fn build_liquid_bond(
+    context: &impl Namada,
+    args::LiquidBond {
+        tx: tx_args,
+        validator,
+        amount,
+        source,
+        tx_code_path,
+    }: &args::LiquidBond,
+) -> Result<(Tx, SigningTxData)> {
+    // Require a positive amount of tokens to be bonded
+    if amount.is_zero() {
+        edisplay_line!(
+            context.io(),
+            "The requested bond amount is 0. A positive amount must be \
+             requested."
+        );
+        if !tx_args.force {
+            return Err(Error::from(TxSubmitError::BondIsZero));
+        }
+    }
+
+    // The validator must actually be a validator
+    let validator =
+        known_validator_or_err(validator.clone(), tx_args.force, context)
+            .await?;
+
+    // Validators cannot hold liquid staking shares
+    let source =
+        source_exists_or_err(source.clone(), tx_args.force, context).await?;
+    if rpc::is_validator(context.client(), &source).await? {
+        edisplay_line!(
+            context.io(),
+            "The given source address {} is a validator. A validator is \
+             prohibited from bonding to a liquid staking pool.",
+            &source
+        );
+        if !tx_args.force {
+            return Err(Error::from(TxSubmitError::InvalidBondPair(
+                source.clone(),
+                validator.clone(),
+            )));
+        }
+    }
+
+    let default_signer = Some(source.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx_args,
+        Some(source.clone()),
+        default_signer,
+        vec![],
+        false,
+    )
+    .await?;
+    let (fee_amount, updated_balance) =
+        validate_transparent_fee(context, tx_args, &signing_data.fee_payer)
+            .await?;
+
+    let native_token = context.native_token();
+    let check_balance = if updated_balance.source == source
+        && updated_balance.token == native_token
+    {
+        CheckBalance::Balance(updated_balance.post_balance)
+    } else {
+        CheckBalance::Query(balance_key(&native_token, &source))
+    };
+    check_balance_too_low_err(
+        &native_token,
+        &source,
+        *amount,
+        check_balance,
+        tx_args.force,
+        context,
+    )
+    .await?;
+
+    let data = pos::LiquidBond {
+        validator,
+        amount: *amount,
+        source,
+    };
+
+    build(
+        context,
+        tx_args,
+        tx_code_path.clone(),
+        data,
+        do_nothing,
+        fee_amount,
+        &signing_data.fee_payer,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
+/// Submit a transaction to redeem liquid staking shares of a validator
+pub async fn build_liquid_unbond(
+    context: &impl Namada,
+    args::LiquidUnbond {
+        tx: tx_args,
+        validator,
+        shares,
+        source,
+        tx_code_path,
+    }: &args::LiquidUnbond,
+) -> Result<(Tx, SigningTxData)> {
+    // Require a positive amount of shares to be redeemed
+    if shares.is_zero() {
+        edisplay_line!(
+            context.io(),
+            "The requested amount of shares is 0. A positive amount must be \
+             requested."
+        );
+        if !tx_args.force {
+            return Err(Error::from(TxSubmitError::UnbondIsZero));
+        }
+    }
+
+    // The validator must actually be a validator
+    let validator =
+        known_validator_or_err(validator.clone(), tx_args.force, context)
+            .await?;
+    let share_token = share_token_address(&validator)
+        .map_err(|err| Error::Other(err.to_string()))?;
+
+    let default_signer = Some(source.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx_args,
+        Some(source.clone()),
+        default_signer,
+        vec![],
+        false,
+    )
+    .await?;
+    let (fee_amount, _) =
+        validate_transparent_fee(context, tx_args, &signing_data.fee_payer)
+            .await?;
+
+    // Check the balance of shares of the source
+    check_balance_too_low_err(
+        &share_token,
+        source,
+        *shares,
+        CheckBalance::Query(balance_key(&share_token, source)),
+        tx_args.force,
+        context,
+    )
+    .await?;
+
+    let data = pos::LiquidUnbond {
+        validator,
+        amount: *shares,
+        source: source.clone(),
+    };
+
+    build(
+        context,
+        tx_args,
+        tx_code_path.clone(),
+        data,
+        do_nothing,
+        fee_amount,
+        &signing_data.fee_payer,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
 /// Build a default proposal governance
 pub async fn build_default_proposal(
     context: &impl Namada,
@@ -2145,6 +2465,18 @@ pub async fn build_default_proposal(
                         ProposalType::DefaultWithWasm(extra_section_hash);
                 };
             }
+
+            if let ProposalType::DefaultWithWasmBatch(steps) =
+                &mut init_proposal_data.r#type
+            {
+                for (step, proposal_step) in
+                    steps.iter_mut().zip(proposal.steps)
+                {
+                    let (_, extra_section_hash) =
+                        tx_builder.add_extra_section(proposal_step.code, None);
+                    step.code = extra_section_hash;
+                }
+            }
             Ok(())
         };
 
@@ -2283,7 +2615,15 @@ pub async fn build_vote_proposal(
         )
         .await?;
 
-        if delegation_validators.is_empty() {
+        // Representatives may vote on behalf of their constituents only
+        let is_representative = !rpc::query_governance_constituents(
+            context.client(),
+            voter_address,
+        )
+        .await?
+        .is_empty();
+
+        if delegation_validators.is_empty() && !is_representative {
             edisplay_line!(
                 context.io(),
                 "Voter address {voter_address} does not have any delegations.",
@@ -2316,36 +2656,359 @@ pub async fn build_vote_proposal(
     .map(|tx| (tx, signing_data))
 }
 
-/// Build a pgf funding proposal governance
-pub async fn build_become_validator(
+/// Build a governance vote delegation
+pub async fn build_delegate_votes(
     context: &impl Namada,
-    args::TxBecomeValidator {
+    args::DelegateVotes {
         tx: tx_args,
-        address,
-        scheme: _,
-        consensus_key,
-        eth_cold_key,
-        eth_hot_key,
-        protocol_key,
-        commission_rate,
-        max_commission_rate_change,
-        email,
-        website,
-        description,
-        discord_handle,
-        avatar,
-        name,
-        unsafe_dont_encrypt: _,
+        delegator,
+        representative,
         tx_code_path,
-    }: &args::TxBecomeValidator,
+    }: &args::DelegateVotes,
 ) -> Result<(Tx, SigningTxData)> {
-    // Check that the address is established
-    if !address.is_established() {
-        edisplay_line!(
-            context.io(),
-            "The given address {address} is not established. Only an \
-             established address can become a validator.",
-        );
+    let default_signer = Some(delegator.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx_args,
+        Some(delegator.clone()),
+        default_signer,
+        vec![],
+        false,
+    )
+    .await?;
+    let (fee_amount, _) =
+        validate_transparent_fee(context, tx_args, &signing_data.fee_payer)
+            .await?;
+
+    if representative.as_ref() == Some(delegator) {
+        return Err(Error::Other(format!(
+            "The address {delegator} cannot delegate its votes to itself"
+        )));
+    }
+    if rpc::is_validator(context.client(), delegator).await? {
+        edisplay_line!(
+            context.io(),
+            "The address {delegator} is a validator, which votes with its own \
+             stake and cannot delegate its votes."
+        );
+        if !tx_args.force {
+            return Err(Error::Other(format!(
+                "Validator {delegator} cannot delegate its votes"
+            )));
+        }
+    }
+    if representative.is_none()
+        && rpc::query_governance_representative(
+            context.client(),
+            delegator,
+            None,
+        )
+        .await?
+        .is_none()
+    {
+        edisplay_line!(
+            context.io(),
+            "The address {delegator} has no representative to revoke."
+        );
+        if !tx_args.force {
+            return Err(Error::Other(format!(
+                "The address {delegator} has no representative"
+            )));
+        }
+    }
+
+    let data = DelegateVotesData {
+        delegator: delegator.clone(),
+        representative: representative.clone(),
+    };
+
+    build(
+        context,
+        tx_args,
+        tx_code_path.clone(),
+        data,
+        do_nothing,
+        fee_amount,
+        &signing_data.fee_payer,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
+/// Build a proposal deposit
+pub async fn build_deposit_proposal(
+    context: &impl Namada,
+    args::DepositProposal {
+        tx: tx_args,
+        proposal_id,
+        depositor,
+        amount,
+        tx_code_path,
+    }: &args::DepositProposal,
+) -> Result<(Tx, SigningTxData)> {
+    let default_signer = Some(depositor.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx_args,
+        Some(depositor.clone()),
+        default_signer,
+        vec![],
+        false,
+    )
+    .await?;
+    let (fee_amount, _) =
+        validate_transparent_fee(context, tx_args, &signing_data.fee_payer)
+            .await?;
+
+    if amount.is_zero() {
+        return Err(Error::Other(
+            "A proposal deposit cannot be zero".to_string(),
+        ));
+    }
+
+    let proposal = if let Some(proposal) =
+        rpc::query_proposal_by_id(context.client(), *proposal_id).await?
+    {
+        proposal
+    } else {
+        return Err(Error::from(TxSubmitError::ProposalDoesNotExist(
+            *proposal_id,
+        )));
+    };
+
+    let current_epoch = rpc::query_epoch(context.client()).await?;
+    if current_epoch >= proposal.voting_start_epoch {
+        edisplay_line!(
+            context.io(),
+            "The deposit phase of proposal {} ended at epoch {}.",
+            proposal_id,
+            proposal.voting_start_epoch
+        );
+        if !tx_args.force {
+            return Err(Error::Other(format!(
+                "The deposit phase of proposal {proposal_id} has ended"
+            )));
+        }
+    }
+
+    let data = DepositProposalData {
+        id: *proposal_id,
+        depositor: depositor.clone(),
+        amount: *amount,
+    };
+
+    build(
+        context,
+        tx_args,
+        tx_code_path.clone(),
+        data,
+        do_nothing,
+        fee_amount,
+        &signing_data.fee_payer,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
+/// Check that a proposal can still be modified by the given author before
+/// building a cancellation or an amendment of it
+async fn check_proposal_modifiable(
+    context: &impl Namada,
+    tx_args: &args::Tx,
+    proposal_id: u64,
+    author: &Address,
+) -> Result<()> {
+    let proposal = if let Some(proposal) =
+        rpc::query_proposal_by_id(context.client(), proposal_id).await?
+    {
+        proposal
+    } else {
+        return Err(Error::from(TxSubmitError::ProposalDoesNotExist(
+            proposal_id,
+        )));
+    };
+
+    if &proposal.author != author {
+        edisplay_line!(
+            context.io(),
+            "Proposal {} can only be modified by its author {}.",
+            proposal_id,
+            proposal.author
+        );
+        if !tx_args.force {
+            return Err(Error::Other(format!(
+                "{author} is not the author of proposal {proposal_id}"
+            )));
+        }
+    }
+
+    let current_epoch = rpc::query_epoch(context.client()).await?;
+    if current_epoch >= proposal.voting_start_epoch {
+        edisplay_line!(
+            context.io(),
+            "The voting period of proposal {} started at epoch {}.",
+            proposal_id,
+            proposal.voting_start_epoch
+        );
+        if !tx_args.force {
+            return Err(Error::Other(format!(
+                "Proposal {proposal_id} cannot be modified anymore"
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Build a proposal cancellation
+pub async fn build_cancel_proposal(
+    context: &impl Namada,
+    args::CancelProposal {
+        tx: tx_args,
+        proposal_id,
+        author,
+        tx_code_path,
+    }: &args::CancelProposal,
+) -> Result<(Tx, SigningTxData)> {
+    let default_signer = Some(author.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx_args,
+        Some(author.clone()),
+        default_signer,
+        vec![],
+        false,
+    )
+    .await?;
+    let (fee_amount, _) =
+        validate_transparent_fee(context, tx_args, &signing_data.fee_payer)
+            .await?;
+
+    check_proposal_modifiable(context, tx_args, *proposal_id, author).await?;
+
+    let data = CancelProposalData {
+        id: *proposal_id,
+        author: author.clone(),
+    };
+
+    build(
+        context,
+        tx_args,
+        tx_code_path.clone(),
+        data,
+        do_nothing,
+        fee_amount,
+        &signing_data.fee_payer,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
+/// Build a proposal amendment
+pub async fn build_amend_proposal(
+    context: &impl Namada,
+    args::AmendProposal {
+        tx: tx_args,
+        proposal_id,
+        author,
+        content,
+        tx_code_path,
+    }: &args::AmendProposal,
+) -> Result<(Tx, SigningTxData)> {
+    let default_signer = Some(author.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx_args,
+        Some(author.clone()),
+        default_signer,
+        vec![],
+        false,
+    )
+    .await?;
+    let (fee_amount, _) =
+        validate_transparent_fee(context, tx_args, &signing_data.fee_payer)
+            .await?;
+
+    check_proposal_modifiable(context, tx_args, *proposal_id, author).await?;
+
+    let content: BTreeMap<String, String> = serde_json::from_slice(content)
+        .map_err(|e| TxSubmitError::InvalidProposal(e.to_string()))?;
+    let content = borsh::to_vec(&content)
+        .map_err(|e| Error::from(EncodingError::Conversion(e.to_string())))?;
+
+    let governance_parameters =
+        rpc::query_governance_parameters(context.client()).await;
+    let max_content_size =
+        usize::try_from(governance_parameters.max_proposal_content_size)
+            .unwrap_or(usize::MAX);
+    if content.len() > max_content_size {
+        edisplay_line!(
+            context.io(),
+            "The proposal content is {} bytes long, the maximum is {}.",
+            content.len(),
+            max_content_size
+        );
+        if !tx_args.force {
+            return Err(Error::Other(
+                "The proposal content is too long".to_string(),
+            ));
+        }
+    }
+
+    let data = AmendProposalData {
+        id: *proposal_id,
+        author: author.clone(),
+        content: Hash::default(),
+    };
+    let push_data = |tx_builder: &mut Tx, data: &mut AmendProposalData| {
+        let (_, extra_section_hash) =
+            tx_builder.add_extra_section(content, None);
+        data.content = extra_section_hash;
+        Ok(())
+    };
+
+    build(
+        context,
+        tx_args,
+        tx_code_path.clone(),
+        data,
+        push_data,
+        fee_amount,
+        &signing_data.fee_payer,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
+/// Build a pgf funding proposal governance
+pub async fn build_become_validator(
+    context: &impl Namada,
+    args::TxBecomeValidator {
+        tx: tx_args,
+        address,
+        scheme: _,
+        consensus_key,
+        eth_cold_key,
+        eth_hot_key,
+        protocol_key,
+        commission_rate,
+        max_commission_rate_change,
+        email,
+        website,
+        description,
+        discord_handle,
+        avatar,
+        name,
+        unsafe_dont_encrypt: _,
+        tx_code_path,
+    }: &args::TxBecomeValidator,
+) -> Result<(Tx, SigningTxData)> {
+    // Check that the address is established
+    if !address.is_established() {
+        edisplay_line!(
+            context.io(),
+            "The given address {address} is not established. Only an \
+             established address can become a validator.",
+        );
         if !tx_args.force {
             return Err(Error::Other(
                 "The given address must be established".to_string(),
@@ -2396,6 +3059,21 @@ pub async fn build_become_validator(
             ));
         }
     }
+    let params: PosParams = rpc::get_pos_params(context.client()).await?;
+    if *commission_rate > params.max_commission_rate {
+        edisplay_line!(
+            context.io(),
+            "The validator commission rate {} must not exceed the maximum \
+             commission rate {}.",
+            commission_rate,
+            params.max_commission_rate
+        );
+        if !tx_args.force {
+            return Err(Error::Other(
+                "Invalid validator commission rate".to_string(),
+            ));
+        }
+    }
 
     if *max_commission_rate_change > Dec::one()
         || *max_commission_rate_change < Dec::zero()
@@ -3066,49 +3744,284 @@ pub async fn build_transparent_transfer<N: Namada>(
             validate_amount(context, amount.to_owned(), token, args.tx.force)
                 .await?;
 
-        // Check the balance of the source
-        if let Some(updated_balance) = &updated_balance {
-            let check_balance = if &updated_balance.source == source
-                && &updated_balance.token == token
-            {
-                CheckBalance::Balance(updated_balance.post_balance)
-            } else {
-                CheckBalance::Query(balance_key(token, source))
-            };
+        // Check the balance of the source
+        if let Some(updated_balance) = &updated_balance {
+            let check_balance = if &updated_balance.source == source
+                && &updated_balance.token == token
+            {
+                CheckBalance::Balance(updated_balance.post_balance)
+            } else {
+                CheckBalance::Query(balance_key(token, source))
+            };
+
+            check_balance_too_low_err(
+                token,
+                source,
+                validated_amount.amount(),
+                check_balance,
+                args.tx.force,
+                context,
+            )
+            .await?;
+        }
+
+        // Construct the corresponding transparent Transfer object
+        transfers = transfers
+            .transfer(
+                source.to_owned(),
+                target.to_owned(),
+                token.to_owned(),
+                validated_amount,
+            )
+            .ok_or(Error::Other("Combined transfer overflows".to_string()))?;
+    }
+
+    let tx = build(
+        context,
+        &args.tx,
+        args.tx_code_path.clone(),
+        transfers,
+        do_nothing,
+        fee_amount,
+        &signing_data.fee_payer,
+    )
+    .await?;
+    Ok((tx, signing_data))
+}
+
+/// Build a transaction to approve a spender to transfer the tokens of an
+/// owner
+pub async fn build_approve(
+    context: &impl Namada,
+    args::TxApprove {
+        tx: tx_args,
+        owner,
+        spender,
+        token,
+        amount,
+        expiry,
+        tx_code_path,
+    }: &args::TxApprove,
+) -> Result<(Tx, SigningTxData)> {
+    let default_signer = Some(owner.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx_args,
+        Some(owner.clone()),
+        default_signer,
+        vec![],
+        false,
+    )
+    .await?;
+    let (fee_amount, _) =
+        validate_transparent_fee(context, tx_args, &signing_data.fee_payer)
+            .await?;
+
+    // Check that the spender address exists on chain
+    target_exists_or_err(spender.clone(), tx_args.force, context).await?;
+    // Validate the amount given
+    let amount =
+        validate_amount(context, amount.clone(), token, tx_args.force).await?;
+
+    if let Some(expiry) = expiry {
+        let current_epoch = rpc::query_epoch(context.client()).await?;
+        if *expiry < current_epoch {
+            let err = format!(
+                "The expiry epoch {} of the allowance has already passed. The \
+                 current epoch is {}.",
+                expiry, current_epoch
+            );
+            if tx_args.force {
+                edisplay_line!(context.io(), "{}", err);
+            } else {
+                return Err(Error::Other(err));
+            }
+        }
+    }
+
+    let data = token::Approve {
+        token: token.clone(),
+        owner: owner.clone(),
+        spender: spender.clone(),
+        amount,
+        expiry: *expiry,
+    };
+
+    build(
+        context,
+        tx_args,
+        tx_code_path.clone(),
+        data,
+        do_nothing,
+        fee_amount,
+        &signing_data.fee_payer,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
+/// Build a transaction for a spender to transfer the tokens of an owner,
+/// from its allowance
+pub async fn build_transfer_from(
+    context: &impl Namada,
+    args::TxTransferFrom {
+        tx: tx_args,
+        owner,
+        spender,
+        target,
+        token,
+        amount,
+        tx_code_path,
+    }: &args::TxTransferFrom,
+) -> Result<(Tx, SigningTxData)> {
+    let default_signer = Some(spender.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx_args,
+        Some(spender.clone()),
+        default_signer,
+        vec![],
+        false,
+    )
+    .await?;
+    let (fee_amount, _) =
+        validate_transparent_fee(context, tx_args, &signing_data.fee_payer)
+            .await?;
+
+    // Check that the target address exists on chain
+    target_exists_or_err(target.clone(), tx_args.force, context).await?;
+    // Validate the amount given
+    let amount =
+        validate_amount(context, amount.clone(), token, tx_args.force).await?;
+
+    // Check the remaining allowance of the spender
+    let allowance =
+        rpc::get_token_allowance(context.client(), token, owner, spender)
+            .await?
+            .map(|allowance| allowance.amount)
+            .unwrap_or_default();
+    if allowance < amount.amount() {
+        let err = TxSubmitError::AllowanceTooLow(
+            spender.clone(),
+            token.clone(),
+            owner.clone(),
+            context.format_amount(token, amount.amount()).await,
+            context.format_amount(token, allowance).await,
+        );
+        if tx_args.force {
+            edisplay_line!(context.io(), "{}", err);
+        } else {
+            return Err(Error::from(err));
+        }
+    }
+    // Check the balance of the owner
+    check_balance_too_low_err(
+        token,
+        owner,
+        amount.amount(),
+        CheckBalance::Query(balance_key(token, owner)),
+        tx_args.force,
+        context,
+    )
+    .await?;
+
+    let data = token::TransferFrom {
+        token: token.clone(),
+        owner: owner.clone(),
+        spender: spender.clone(),
+        target: target.clone(),
+        amount,
+    };
+
+    build(
+        context,
+        tx_args,
+        tx_code_path.clone(),
+        data,
+        do_nothing,
+        fee_amount,
+        &signing_data.fee_payer,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
+/// Build a transaction for a transfer of tokens that are locked in the
+/// balance of the target according to a vesting schedule
+pub async fn build_vesting_transfer(
+    context: &impl Namada,
+    args::TxVestingTransfer {
+        tx: tx_args,
+        source,
+        target,
+        token,
+        amount,
+        start_epoch,
+        cliff_epoch,
+        end_epoch,
+        tx_code_path,
+    }: &args::TxVestingTransfer,
+) -> Result<(Tx, SigningTxData)> {
+    let default_signer = Some(source.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx_args,
+        Some(source.clone()),
+        default_signer,
+        vec![],
+        false,
+    )
+    .await?;
+    let (fee_amount, _) =
+        validate_transparent_fee(context, tx_args, &signing_data.fee_payer)
+            .await?;
+
+    // Check that the target address exists on chain
+    target_exists_or_err(target.clone(), tx_args.force, context).await?;
+    // Validate the amount given
+    let amount =
+        validate_amount(context, amount.clone(), token, tx_args.force).await?;
 
-            check_balance_too_low_err(
-                token,
-                source,
-                validated_amount.amount(),
-                check_balance,
-                args.tx.force,
-                context,
-            )
-            .await?;
+    let data = token::VestingTransfer {
+        source: source.clone(),
+        target: target.clone(),
+        token: token.clone(),
+        amount,
+        start_epoch: *start_epoch,
+        cliff_epoch: *cliff_epoch,
+        end_epoch: *end_epoch,
+    };
+    // Check that the vesting schedule is well-formed
+    if let Err(err) = data.schedule().validate() {
+        let err = TxSubmitError::InvalidVestingSchedule(err.to_string());
+        if tx_args.force {
+            edisplay_line!(context.io(), "{}", err);
+        } else {
+            return Err(Error::from(err));
         }
-
-        // Construct the corresponding transparent Transfer object
-        transfers = transfers
-            .transfer(
-                source.to_owned(),
-                target.to_owned(),
-                token.to_owned(),
-                validated_amount,
-            )
-            .ok_or(Error::Other("Combined transfer overflows".to_string()))?;
     }
+    // Check the balance of the source
+    check_balance_too_low_err(
+        token,
+        source,
+        amount.amount(),
+        CheckBalance::Query(balance_key(token, source)),
+        tx_args.force,
+        context,
+    )
+    .await?;
 
-    let tx = build(
+    build(
         context,
-        &args.tx,
-        args.tx_code_path.clone(),
-        transfers,
+        tx_args,
+        tx_code_path.clone(),
+        data,
         do_nothing,
         fee_amount,
         &signing_data.fee_payer,
     )
-    .await?;
-    Ok((tx, signing_data))
+    .await
+    .map(|tx| (tx, signing_data))
 }
 
 /// Build a shielded transfer
@@ -3573,6 +4486,7 @@ pub async fn build_init_account(
         tx_code_path,
         public_keys,
         threshold,
+        weights,
     }: &args::TxInitAccount,
 ) -> Result<(Tx, SigningTxData)> {
     let signing_data =
@@ -3584,10 +4498,11 @@ pub async fn build_init_account(
 
     let vp_code_hash = query_wasm_code_hash_buf(context, vp_code_path).await?;
 
+    let total_weight = total_account_weight(weights, public_keys.len())?;
     let threshold = match threshold {
         Some(threshold) => {
             let threshold = *threshold;
-            if (threshold > 0 && public_keys.len() as u8 >= threshold)
+            if (threshold > 0 && total_weight >= u32::from(threshold))
                 || tx_args.force
             {
                 threshold
@@ -3595,8 +4510,8 @@ pub async fn build_init_account(
                 edisplay_line!(
                     context.io(),
                     "Invalid account threshold: either the provided threshold \
-                     is zero or the number of public keys is less than the \
-                     threshold."
+                     is zero or the total weight of the public keys is less \
+                     than the threshold."
                 );
                 if !tx_args.force {
                     return Err(Error::from(
@@ -3622,6 +4537,7 @@ pub async fn build_init_account(
         // We will add the hash inside the add_code_hash function
         vp_code_hash: Hash::zero(),
         threshold,
+        weights: weights.clone(),
     };
 
     let add_code_hash = |tx: &mut Tx, data: &mut InitAccount| {
@@ -3655,6 +4571,7 @@ pub async fn build_update_account(
         addr,
         public_keys,
         threshold,
+        weights,
     }: &args::TxUpdateAccount,
 ) -> Result<(Tx, SigningTxData)> {
     let default_signer = Some(addr.clone());
@@ -3681,23 +4598,28 @@ pub async fn build_update_account(
         )));
     };
 
+    // The weights apply to the new public keys if any, otherwise to the
+    // current ones. The current weights are kept if neither is updated.
+    let total_weight = if !public_keys.is_empty() {
+        total_account_weight(weights, public_keys.len())?
+    } else if !weights.is_empty() {
+        total_account_weight(weights, account.get_all_public_keys().len())?
+    } else {
+        account.public_keys_map.total_weight()
+    };
+
     let threshold = if let Some(threshold) = threshold {
         let threshold = *threshold;
 
         let invalid_threshold = threshold.is_zero();
-        let invalid_threshold_updated =
-            !public_keys.is_empty() && public_keys.len() < threshold as usize;
-        let invalid_threshold_current = public_keys.is_empty()
-            && account.get_all_public_keys().len() < threshold as usize;
-
-        if invalid_threshold
-            || invalid_threshold_updated
-            || invalid_threshold_current
-        {
+        let invalid_threshold_weight = total_weight < u32::from(threshold);
+
+        if invalid_threshold || invalid_threshold_weight {
             edisplay_line!(
                 context.io(),
                 "Invalid account threshold: either the provided threshold is \
-                 zero or the number of public keys is less than the threshold."
+                 zero or the total weight of the public keys is less than the \
+                 threshold."
             );
             if !tx_args.force {
                 return Err(Error::from(
@@ -3708,10 +4630,10 @@ pub async fn build_update_account(
 
         Some(threshold)
     } else {
-        let invalid_too_few_pks = !public_keys.is_empty()
-            && public_keys.len() < account.threshold as usize;
+        let invalid_too_little_weight =
+            total_weight < u32::from(account.threshold);
 
-        if invalid_too_few_pks {
+        if invalid_too_little_weight {
             return Err(Error::from(TxSubmitError::InvalidAccountThreshold));
         }
 
@@ -3745,6 +4667,7 @@ pub async fn build_update_account(
         vp_code_hash: extra_section_hash,
         public_keys: public_keys.clone(),
         threshold,
+        weights: weights.clone(),
     };
 
     let add_code_hash = |tx: &mut Tx, data: &mut UpdateAccount| {
@@ -3772,6 +4695,289 @@ pub async fn build_update_account(
     .map(|tx| (tx, signing_data))
 }
 
+/// Submit a transaction to configure the social recovery of an account
+pub async fn build_configure_recovery(
+    context: &impl Namada,
+    args::TxConfigureRecovery {
+        tx: tx_args,
+        addr,
+        guardians,
+        threshold,
+        delay,
+        tx_code_path,
+    }: &args::TxConfigureRecovery,
+) -> Result<(Tx, SigningTxData)> {
+    let default_signer = Some(addr.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx_args,
+        Some(addr.clone()),
+        default_signer,
+        vec![],
+        false,
+    )
+    .await?;
+    let (fee_amount, _) =
+        validate_transparent_fee(context, tx_args, &signing_data.fee_payer)
+            .await?;
+
+    let config = if guardians.is_empty() {
+        None
+    } else {
+        // Check that the guardian addresses exist on chain
+        for guardian in guardians {
+            target_exists_or_err(guardian.clone(), tx_args.force, context)
+                .await?;
+        }
+        let config = RecoveryConfig {
+            guardians: guardians.iter().cloned().collect(),
+            threshold: *threshold,
+            delay: *delay,
+        };
+        if let Err(err) = config.validate(addr) {
+            if tx_args.force {
+                edisplay_line!(context.io(), "{}", err);
+            } else {
+                return Err(Error::Other(err.to_string()));
+            }
+        }
+        Some(config)
+    };
+
+    let data = ConfigureRecovery {
+        addr: addr.clone(),
+        config,
+    };
+
+    build(
+        context,
+        tx_args,
+        tx_code_path.clone(),
+        data,
+        do_nothing,
+        fee_amount,
+        &signing_data.fee_payer,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
+/// Submit a transaction for the guardians of an account to initiate the
+/// recovery of its keys. The tx must be signed by every approving guardian.
+pub async fn build_initiate_recovery(
+    context: &impl Namada,
+    args::TxInitiateRecovery {
+        tx: tx_args,
+        addr,
+        guardians,
+        public_keys,
+        threshold,
+        weights,
+        tx_code_path,
+    }: &args::TxInitiateRecovery,
+) -> Result<(Tx, SigningTxData)> {
+    // The signatures are checked by the VPs of the guardians, not by the
+    // account being recovered
+    let default_signer = guardians.first().cloned();
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx_args,
+        None,
+        default_signer,
+        vec![],
+        false,
+    )
+    .await?;
+    let (fee_amount, _) =
+        validate_transparent_fee(context, tx_args, &signing_data.fee_payer)
+            .await?;
+
+    let Some(config) = rpc::get_recovery_config(context.client(), addr).await?
+    else {
+        return Err(Error::Other(format!(
+            "{addr} has not opted in to social recovery"
+        )));
+    };
+    let guardians: BTreeSet<Address> = guardians.iter().cloned().collect();
+    let err = if rpc::get_pending_recovery(context.client(), addr)
+        .await?
+        .is_some()
+    {
+        Some(format!("A recovery of {addr} is already pending."))
+    } else if !guardians.is_subset(&config.guardians) {
+        Some(format!(
+            "Only the guardians of {addr} can approve its recovery."
+        ))
+    } else if guardians.len() < usize::from(config.threshold) {
+        Some(format!(
+            "The recovery of {addr} must be approved by at least {} \
+             guardians, got {}.",
+            config.threshold,
+            guardians.len()
+        ))
+    } else {
+        None
+    };
+    if let Some(err) = err {
+        if tx_args.force {
+            edisplay_line!(context.io(), "{}", err);
+        } else {
+            return Err(Error::Other(err));
+        }
+    }
+
+    let total_weight = total_account_weight(weights, public_keys.len())?;
+    let threshold = match threshold {
+        Some(threshold) => {
+            let threshold = *threshold;
+            if threshold == 0 || total_weight < u32::from(threshold) {
+                edisplay_line!(
+                    context.io(),
+                    "Invalid account threshold: either the provided threshold \
+                     is zero or the total weight of the public keys is less \
+                     than the threshold."
+                );
+                if !tx_args.force {
+                    return Err(Error::from(
+                        TxSubmitError::InvalidAccountThreshold,
+                    ));
+                }
+            }
+            threshold
+        }
+        None => {
+            if public_keys.len() == 1 {
+                1u8
+            } else {
+                return Err(Error::from(
+                    TxSubmitError::MissingAccountThreshold,
+                ));
+            }
+        }
+    };
+
+    let data = InitiateRecovery {
+        addr: addr.clone(),
+        guardians,
+        public_keys: public_keys.clone(),
+        threshold,
+        weights: weights.clone(),
+    };
+
+    build(
+        context,
+        tx_args,
+        tx_code_path.clone(),
+        data,
+        do_nothing,
+        fee_amount,
+        &signing_data.fee_payer,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
+/// Submit a transaction to cancel the pending recovery of an account. The tx
+/// must be signed with the current keys of the account.
+pub async fn build_cancel_recovery(
+    context: &impl Namada,
+    args::TxCancelRecovery {
+        tx: tx_args,
+        addr,
+        tx_code_path,
+    }: &args::TxCancelRecovery,
+) -> Result<(Tx, SigningTxData)> {
+    let default_signer = Some(addr.clone());
+    let signing_data = signing::aux_signing_data(
+        context,
+        tx_args,
+        Some(addr.clone()),
+        default_signer,
+        vec![],
+        false,
+    )
+    .await?;
+    let (fee_amount, _) =
+        validate_transparent_fee(context, tx_args, &signing_data.fee_payer)
+            .await?;
+
+    if rpc::get_pending_recovery(context.client(), addr)
+        .await?
+        .is_none()
+    {
+        let err = format!("There is no pending recovery of {addr}.");
+        if tx_args.force {
+            edisplay_line!(context.io(), "{}", err);
+        } else {
+            return Err(Error::Other(err));
+        }
+    }
+
+    build(
+        context,
+        tx_args,
+        tx_code_path.clone(),
+        addr.clone(),
+        do_nothing,
+        fee_amount,
+        &signing_data.fee_payer,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
+/// Submit a transaction to finalize the pending recovery of an account once
+/// its delay has elapsed. Anyone can finalize a recovery, so the tx only has
+/// to be signed by the fee payer.
+pub async fn build_finalize_recovery(
+    context: &impl Namada,
+    args::TxFinalizeRecovery {
+        tx: tx_args,
+        addr,
+        tx_code_path,
+    }: &args::TxFinalizeRecovery,
+) -> Result<(Tx, SigningTxData)> {
+    let signing_data =
+        signing::aux_signing_data(context, tx_args, None, None, vec![], false)
+            .await?;
+    let (fee_amount, _) =
+        validate_transparent_fee(context, tx_args, &signing_data.fee_payer)
+            .await?;
+
+    let err = match rpc::get_pending_recovery(context.client(), addr).await? {
+        None => Some(format!("There is no pending recovery of {addr}.")),
+        Some(recovery) => {
+            let current_epoch = rpc::query_epoch(context.client()).await?;
+            (current_epoch < recovery.activation_epoch).then(|| {
+                format!(
+                    "The recovery of {addr} can only be finalized from epoch \
+                     {}. The current epoch is {}.",
+                    recovery.activation_epoch, current_epoch
+                )
+            })
+        }
+    };
+    if let Some(err) = err {
+        if tx_args.force {
+            edisplay_line!(context.io(), "{}", err);
+        } else {
+            return Err(Error::Other(err));
+        }
+    }
+
+    build(
+        context,
+        tx_args,
+        tx_code_path.clone(),
+        addr.clone(),
+        do_nothing,
+        fee_amount,
+        &signing_data.fee_payer,
+    )
+    .await
+    .map(|tx| (tx, signing_data))
+}
+
 /// Submit a custom transaction
 pub async fn build_custom(
     context: &impl Namada,
@@ -4220,6 +5426,22 @@ async fn check_balance_too_low_err<N: Namada>(
     }
 }
 
+/// Compute the total weight of the public keys of an account. Empty weights
+/// give every key a weight of 1, otherwise there must be one non-zero weight
+/// for each key.
+fn total_account_weight(weights: &[u8], num_public_keys: usize) -> Result<u32> {
+    if weights.is_empty() {
+        return u32::try_from(num_public_keys)
+            .map_err(|_| Error::from(TxSubmitError::InvalidAccountWeights));
+    }
+    if weights.len() != num_public_keys || weights.contains(&0) {
+        return Err(Error::from(TxSubmitError::InvalidAccountWeights));
+    }
+    Ok(weights
+        .iter()
+        .fold(0_u32, |acc, weight| acc.saturating_add(u32::from(*weight))))
+}
+
 async fn query_wasm_code_hash_buf(
     context: &impl Namada,
     path: &Path,
@@ -18,7 +18,7 @@ use namada_storage::tx_queue::ExpiredTxsQueue;
 use namada_storage::types::CommitOnlyData;
 use namada_storage::{
     BlockHeader, BlockHeight, BlockResults, Epoch, Epochs, EthEventsQueue, Key,
-    KeySeg, StorageHasher, TxIndex, EPOCH_TYPE_LENGTH,
+    KeySeg, StorageHasher, StorageMode, TxIndex, EPOCH_TYPE_LENGTH,
 };
 
 use crate::Result;
@@ -74,6 +74,8 @@ where
     pub eth_events_queue: EthEventsQueue,
     /// How many block heights in the past can the storage be queried
     pub storage_read_past_height_limit: Option<u64>,
+    /// The retention mode of the historical state
+    pub storage_mode: StorageMode,
     /// Data that needs to be committed to the merkle tree
     pub commit_only_data: CommitOnlyData,
     /// Cache of the results of process proposal for the next height to decide.
@@ -136,6 +138,7 @@ where
         chain_id: ChainId,
         native_token: Address,
         storage_read_past_height_limit: Option<u64>,
+        storage_mode: StorageMode,
     ) -> Self {
         let block = BlockStorage {
             tree: MerkleTree::default(),
@@ -164,6 +167,7 @@ where
             ethereum_height: None,
             eth_events_queue: EthEventsQueue::default(),
             storage_read_past_height_limit,
+            storage_mode,
             commit_only_data: CommitOnlyData::default(),
             block_proposals_cache: CLruCache::new(
                 NonZeroUsize::new(10).unwrap(),
@@ -306,20 +310,28 @@ where
             .unwrap_or_default()
     }
 
+    /// Get the oldest height whose state can be restored from the DB
+    pub fn get_oldest_height(&self) -> BlockHeight {
+        let retained_blocks = match self.storage_mode {
+            StorageMode::Standard => self.storage_read_past_height_limit,
+            StorageMode::Archive => None,
+            StorageMode::Pruned { blocks_to_keep } => Some(blocks_to_keep),
+        };
+        let last_height = self.get_last_block_height();
+        match retained_blocks {
+            Some(limit) if limit < last_height.0 => {
+                (last_height.0.checked_sub(limit).expect("Cannot underflow"))
+                    .into()
+            }
+            _ => BlockHeight(1),
+        }
+    }
+
     /// Get the oldest epoch where we can read a value
     pub fn get_oldest_epoch(&self) -> Epoch {
-        let oldest_height = match self.storage_read_past_height_limit {
-            Some(limit) if limit < self.get_last_block_height().0 => (self
-                .get_last_block_height()
-                .0
-                .checked_sub(limit)
-                .expect("Cannot underflow"))
-            .into(),
-            _ => BlockHeight(1),
-        };
         self.block
             .pred_epochs
-            .get_epoch(oldest_height)
+            .get_epoch(self.get_oldest_height())
             .unwrap_or_default()
     }
 }
@@ -60,8 +60,8 @@ pub use namada_storage::types::{KVBytes, PatternIterator, PrefixIterator};
 pub use namada_storage::{
     collections, iter_prefix, iter_prefix_bytes, iter_prefix_with_filter,
     mockdb, tx_queue, BlockStateRead, BlockStateWrite, DBIter, DBWriteBatch,
-    DbError, DbResult, Error, OptionExt, Result, ResultExt, StorageHasher,
-    StorageRead, StorageWrite, DB,
+    DbError, DbResult, DbStat, Error, OptionExt, Result, ResultExt,
+    StorageHasher, StorageMode, StorageRead, StorageWrite, DB,
 };
 use namada_systems::parameters;
 use thiserror::Error;
@@ -282,6 +282,23 @@ macro_rules! impl_storage_read {
                 Ok(iter)
             }
 
+            fn iter_prefix_from<'iter>(
+                &'iter self,
+                prefix: &storage::Key,
+                from: &str,
+                reverse: bool,
+            ) -> namada_storage::Result<Option<Self::PrefixIter<'iter>>> {
+                let (iter, gas) = iter_prefix_post_from(
+                    self.write_log(),
+                    self.db(),
+                    prefix,
+                    from,
+                    reverse,
+                )?;
+                self.charge_gas(gas).into_storage_result()?;
+                Ok(Some(iter))
+            }
+
             fn iter_next<'iter>(
                 &'iter self,
                 iter: &mut Self::PrefixIter<'iter>,
@@ -437,6 +454,14 @@ impl_storage_write!(TxHostEnvState<'_, D, H>);
 pub enum StateError {
     #[error("Merkle tree at the height {height} is not stored")]
     NoMerkleTree { height: BlockHeight },
+    #[error(
+        "State at the height {height} has been pruned, the oldest retained \
+         height is {oldest_height}"
+    )]
+    PrunedHeight {
+        height: BlockHeight,
+        oldest_height: BlockHeight,
+    },
     #[error("{0}")]
     Gas(namada_gas::Error),
 }
@@ -457,6 +482,8 @@ where
     pub storage_iter: Peekable<<D as DBIter<'iter>>::PrefixIter>,
     /// Peekable write log iterator
     pub write_log_iter: Peekable<write_log::PrefixIter>,
+    /// Whether the iterators go backward over the storage keys
+    pub reverse: bool,
 }
 
 /// Iterate write-log storage items prior to a tx execution, matching the
@@ -479,6 +506,7 @@ where
         PrefixIter::<D> {
             storage_iter,
             write_log_iter,
+            reverse: false,
         },
         checked!(len * STORAGE_ACCESS_GAS_PER_BYTE)?.into(),
     ))
@@ -504,6 +532,37 @@ where
         PrefixIter::<D> {
             storage_iter,
             write_log_iter,
+            reverse: false,
+        },
+        checked!(len * STORAGE_ACCESS_GAS_PER_BYTE)?.into(),
+    ))
+}
+
+/// Iterate write-log storage items posterior to a tx execution, matching the
+/// given prefix, starting from the first key that is not smaller than the raw
+/// key `from`, or from the last key that is not greater than `from` in the
+/// reverse order if `reverse` is set. Returns the iterator and gas cost.
+pub fn iter_prefix_post_from<'a, D>(
+    write_log: &'a WriteLog,
+    db: &'a D,
+    prefix: &storage::Key,
+    from: &str,
+    reverse: bool,
+) -> namada_storage::Result<(PrefixIter<'a, D>, Gas)>
+where
+    D: DB + for<'iter> DBIter<'iter>,
+{
+    let storage_iter =
+        db.iter_prefix_from(Some(prefix), from, reverse).peekable();
+    let write_log_iter = write_log
+        .iter_prefix_post_from(prefix, from, reverse)
+        .peekable();
+    let len = checked!(prefix.len() + from.len())? as u64;
+    Ok((
+        PrefixIter::<D> {
+            storage_iter,
+            write_log_iter,
+            reverse,
         },
         checked!(len * STORAGE_ACCESS_GAS_PER_BYTE)?.into(),
     ))
@@ -536,7 +595,14 @@ where
                         what = Next::ReturnStorage;
                     }
                     (Some((storage_key, _, _)), Some((wl_key, _))) => {
-                        if wl_key <= storage_key {
+                        // The write log item comes first if its key is before
+                        // the storage key in the iteration's order
+                        let wl_first = if self.reverse {
+                            wl_key >= storage_key
+                        } else {
+                            wl_key <= storage_key
+                        };
+                        if wl_first {
                             what = Next::ReturnWl {
                                 advance_storage: wl_key == storage_key,
                             };
@@ -655,6 +721,7 @@ pub mod testing {
                 ethereum_height: None,
                 eth_events_queue: EthEventsQueue::default(),
                 storage_read_past_height_limit: Some(1000),
+                storage_mode: StorageMode::default(),
                 commit_only_data: CommitOnlyData::default(),
                 block_proposals_cache: CLruCache::new(
                     NonZeroUsize::new(10).unwrap(),
@@ -1177,6 +1244,44 @@ mod tests {
             read_post.insert(key, val);
         }
         dbg!(keys_to_string(&expected_post), keys_to_string(&read_post));
+
+        // Check the posterior state iterators that start from a key, in the
+        // order of the raw storage keys
+        let expected_raw: BTreeMap<String, i8> = expected_post
+            .iter()
+            .map(|(key, val)| (key.to_string(), *val))
+            .collect();
+        if let Some(from) = expected_raw.keys().nth(expected_raw.len() / 2) {
+            for reverse in [false, true] {
+                let (iter, _gas) = iter_prefix_post_from(
+                    s.write_log(),
+                    s.db(),
+                    &storage::Key::default(),
+                    from,
+                    reverse,
+                )
+                .unwrap();
+                let read_from: Vec<(String, i8)> = iter
+                    .map(|(key, val, _gas)| {
+                        (key, BorshDeserialize::try_from_slice(&val).unwrap())
+                    })
+                    .collect();
+                let expected_from: Vec<(String, i8)> = if reverse {
+                    expected_raw
+                        .range(..=from.clone())
+                        .rev()
+                        .map(|(key, val)| (key.clone(), *val))
+                        .collect()
+                } else {
+                    expected_raw
+                        .range(from.clone()..)
+                        .map(|(key, val)| (key.clone(), *val))
+                        .collect()
+                };
+                assert_eq!(expected_from, read_from);
+            }
+        }
+
         itertools::assert_equal(expected_post, read_post);
     }
 
@@ -26,8 +26,8 @@ use crate::write_log::{StorageModification, WriteLog};
 use crate::{
     is_pending_transfer_key, DBIter, Epoch, Error, Hash, Key, KeySeg,
     LastBlock, MembershipProof, MerkleTree, MerkleTreeError, ProofOps, Result,
-    State, StateError, StateRead, StorageHasher, StoreType, TxWrites, DB,
-    EPOCH_SWITCH_BLOCKS_DELAY, STORAGE_ACCESS_GAS_PER_BYTE,
+    State, StateError, StateRead, StorageHasher, StorageMode, StoreType,
+    TxWrites, DB, EPOCH_SWITCH_BLOCKS_DELAY, STORAGE_ACCESS_GAS_PER_BYTE,
 };
 
 /// Owned state with full R/W access.
@@ -145,6 +145,7 @@ where
         chain_id: ChainId,
         native_token: Address,
         storage_read_past_height_limit: Option<u64>,
+        storage_mode: StorageMode,
         diff_key_filter: fn(&storage::Key) -> bool,
     ) -> Self {
         let write_log = WriteLog::default();
@@ -153,6 +154,7 @@ where
             chain_id,
             native_token,
             storage_read_past_height_limit,
+            storage_mode,
         );
         let mut state = Self(WlState {
             write_log,
@@ -255,13 +257,22 @@ where
     /// Commit the current block's write log to the storage and commit the block
     /// to DB. Starts a new block write log.
     pub fn commit_block(&mut self) -> Result<()> {
+        self.commit_block_with_batch(D::batch())
+    }
+
+    /// Commit the current block's write log to the storage and commit the block
+    /// to DB, along with the writes already in the given batch. Starts a new
+    /// block write log.
+    pub fn commit_block_with_batch(
+        &mut self,
+        mut batch: D::WriteBatch,
+    ) -> Result<()> {
         if self.in_mem.last_epoch != self.in_mem.block.epoch {
             self.in_mem_mut()
                 .update_epoch_in_merkle_tree()
                 .into_storage_result()?;
         }
 
-        let mut batch = D::batch();
         self.commit_write_log_block(&mut batch)
             .into_storage_result()?;
         self.commit_block_from_batch(batch).into_storage_result()
@@ -434,6 +445,9 @@ where
         // Prune provable stores
         let oldest_epoch = self.in_mem.get_oldest_epoch();
         if oldest_epoch.0 > 0 {
+            let pruned_epoch = oldest_epoch
+                .prev()
+                .expect("the previous epoch should exist");
             // Remove stores at the previous epoch because the Merkle tree
             // stores at the starting height of the epoch would be used to
             // restore stores at a height (> oldest_height) in the epoch
@@ -441,14 +455,45 @@ where
                 self.db.prune_merkle_tree_store(
                     batch,
                     st,
-                    Either::Right(
-                        oldest_epoch
-                            .prev()
-                            .expect("the previous epoch should exist"),
-                    ),
+                    Either::Right(pruned_epoch),
                 )?;
             }
 
+            // In the pruned mode, the diffs and the Merkle tree stores of all
+            // the epochs before the oldest one are not needed to restore the
+            // state anymore either. The last pruned epoch is persisted, such
+            // that the data left behind while running in another mode is
+            // also pruned.
+            if let StorageMode::Pruned { .. } = self.in_mem.storage_mode {
+                let first_epoch = match self.db.read_last_pruned_epoch()? {
+                    Some(last) if last >= pruned_epoch => None,
+                    Some(last) => Some(last.next()),
+                    None => Some(Epoch(0)),
+                };
+                if let Some(mut epoch) = first_epoch {
+                    while epoch < pruned_epoch {
+                        for st in StoreType::iter_provable() {
+                            self.0.db.prune_merkle_tree_store(
+                                batch,
+                                st,
+                                Either::Right(epoch),
+                            )?;
+                        }
+                        epoch = epoch.next();
+                    }
+                    if let Some(to) = self
+                        .in_mem
+                        .block
+                        .pred_epochs
+                        .get_start_height_of_epoch(oldest_epoch)
+                    {
+                        self.0.db.prune_diffs(batch, BlockHeight(0), to)?;
+                    }
+                    self.db
+                        .batch_write_last_pruned_epoch(batch, pruned_epoch)?;
+                }
+            }
+
             // Prune the BridgePool subtree stores with invalid nonce
             let mut epoch = match self.get_oldest_epoch_with_valid_nonce()? {
                 Some(epoch) => epoch,
@@ -795,6 +840,22 @@ where
             .unwrap_or(false)
     }
 
+    /// Check that the state at the given height hasn't been pruned from the
+    /// DB.
+    fn ensure_height_retained(&self, height: BlockHeight) -> Result<()> {
+        if let StorageMode::Pruned { .. } = self.in_mem.storage_mode {
+            let oldest_height = self.in_mem.get_oldest_height();
+            if height < oldest_height {
+                return Err(StateError::PrunedHeight {
+                    height,
+                    oldest_height,
+                }
+                .into());
+            }
+        }
+        Ok(())
+    }
+
     /// Returns a value from the specified subspace at the given height (or the
     /// last committed height when 0) and the gas cost.
     pub fn db_read_with_height(
@@ -808,6 +869,7 @@ where
         {
             self.db_read(key)
         } else {
+            self.ensure_height_retained(height)?;
             if !(self.diff_key_filter)(key) {
                 return Ok((None, Gas::default()));
             }
@@ -1006,6 +1068,7 @@ where
         } else {
             height
         };
+        self.ensure_height_retained(height)?;
 
         let epoch = self
             .in_mem
@@ -1,9 +1,10 @@
 //! Write log is temporary storage for modifications performed by a transaction.
 //! before they are committed to the ledger's storage.
 
-use std::collections::{BTreeMap, BTreeSet};
+use std::collections::{btree_map, BTreeMap, BTreeSet};
+use std::iter::Rev;
 
-use itertools::Itertools;
+use itertools::{Either, Itertools};
 use namada_core::address::{Address, EstablishedAddressGen};
 use namada_core::arith::checked;
 use namada_core::collections::{HashMap, HashSet};
@@ -156,9 +157,12 @@ pub struct WriteLog {
 /// Write log prefix iterator
 #[derive(Debug)]
 pub struct PrefixIter {
-    /// The concrete iterator for modifications sorted by storage keys
-    pub iter:
-        std::collections::btree_map::IntoIter<String, StorageModification>,
+    /// The concrete iterator for modifications sorted by storage keys, which
+    /// goes backward for reverse iterators
+    pub iter: Either<
+        btree_map::IntoIter<String, StorageModification>,
+        Rev<btree_map::IntoIter<String, StorageModification>>,
+    >,
 }
 
 impl Iterator for PrefixIter {
@@ -706,13 +710,46 @@ impl WriteLog {
             }
         }
 
-        let iter = matches.into_iter();
+        let iter = Either::Left(matches.into_iter());
         PrefixIter { iter }
     }
 
     /// Iterate modifications posterior of the current tx, whose storage key
     /// matches the given prefix, sorted by their storage key.
     pub fn iter_prefix_post(&self, prefix: &storage::Key) -> PrefixIter {
+        let iter = Either::Left(self.prefix_post_matches(prefix).into_iter());
+        PrefixIter { iter }
+    }
+
+    /// Iterate modifications posterior of the current tx, whose storage key
+    /// matches the given prefix, starting from the first key that is not
+    /// smaller than the raw key `from`, or from the last key that is not
+    /// greater than `from` in the reverse order if `reverse` is set.
+    pub fn iter_prefix_post_from(
+        &self,
+        prefix: &storage::Key,
+        from: &str,
+        reverse: bool,
+    ) -> PrefixIter {
+        let mut matches = self.prefix_post_matches(prefix);
+        let mut above = matches.split_off(from);
+        let iter = if reverse {
+            if let Some((key, modification)) = above.remove_entry(from) {
+                matches.insert(key, modification);
+            }
+            Either::Right(matches.into_iter().rev())
+        } else {
+            Either::Left(above.into_iter())
+        };
+        PrefixIter { iter }
+    }
+
+    /// Find the modifications posterior of the current tx, whose storage key
+    /// matches the given prefix, sorted by their storage key.
+    fn prefix_post_matches(
+        &self,
+        prefix: &storage::Key,
+    ) -> BTreeMap<String, StorageModification> {
         let mut matches = BTreeMap::new();
 
         for (key, modification) in self.block_write_log.iter().chain(
@@ -726,8 +763,7 @@ impl WriteLog {
             }
         }
 
-        let iter = matches.into_iter();
-        PrefixIter { iter }
+        matches
     }
 
     /// Check if the given tx hash has already been processed
@@ -0,0 +1,421 @@
+//! Lazy double-ended queue.
+
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use namada_core::arith::checked;
+use namada_core::borsh::{BorshDeserialize, BorshSerialize};
+use namada_core::storage::{self, DbKeySeg, KeySeg};
+use thiserror::Error;
+
+use super::super::Result;
+use super::LazyCollection;
+use crate::{ResultExt, StorageRead, StorageWrite};
+
+/// Subkey pointing to the index of the first element of the LazyDeque
+pub const FRONT_SUBKEY: &str = "front";
+/// Subkey pointing to the index after the last element of the LazyDeque
+pub const BACK_SUBKEY: &str = "back";
+/// Subkey corresponding to the data elements of the LazyDeque
+pub const DATA_SUBKEY: &str = "data";
+
+/// Using `u64` for deque's storage indices
+pub type Index = u64;
+
+/// The storage index of the first element pushed into an empty deque. It's in
+/// the middle of the indices' range, so that elements can be pushed to both
+/// ends without having to shift the existing ones.
+pub const INITIAL_INDEX: Index = Index::MAX / 2;
+
+/// Lazy double-ended queue.
+///
+/// This can be used as an alternative to `std::collections::VecDeque`. In the
+/// lazy deque, the elements do not reside in memory but are instead read and
+/// written to storage sub-keys of the storage `key` used to construct the
+/// deque.
+///
+/// The elements are stored at consecutive storage indices from the `front`
+/// (inclusive) to the `back` (exclusive) index, so that elements can be pushed
+/// and popped at both ends without moving the other elements.
+#[derive(Clone, Debug)]
+pub struct LazyDeque<T> {
+    key: storage::Key,
+    phantom: PhantomData<T>,
+}
+
+/// Possible sub-keys of a [`LazyDeque`]
+#[derive(Debug, PartialEq)]
+pub enum SubKey {
+    /// Front index sub-key
+    Front,
+    /// Back index sub-key
+    Back,
+    /// Data sub-key, further sub-keyed by its storage index
+    Data(Index),
+}
+
+#[allow(missing_docs)]
+#[derive(Error, Debug)]
+pub enum ValidationError {
+    #[error("A LazyDeque must have both or neither of its front and back")]
+    InvalidBounds,
+    #[error("An empty LazyDeque must be deleted from storage")]
+    EmptyDequeShouldBeDeleted,
+    #[error("Push at a wrong index {0}")]
+    UnexpectedPushIndex(Index),
+    #[error("Pop at a wrong index {0}")]
+    UnexpectedPopIndex(Index),
+    #[error("Update at an index {0} outside of the LazyDeque")]
+    UnexpectedUpdateIndex(Index),
+    #[error(
+        "The change of a LazyDeque's bounds doesn't match the pushed and \
+         popped elements"
+    )]
+    InvalidBoundsDiff,
+    #[error("Invalid storage key {0}")]
+    InvalidSubKey(storage::Key),
+}
+
+impl<T> LazyCollection for LazyDeque<T>
+where
+    T: BorshSerialize + BorshDeserialize + 'static + Debug,
+{
+    type SubKey = SubKey;
+    type Value = T;
+
+    /// Create or use an existing deque with the given storage `key`.
+    fn open(key: storage::Key) -> Self {
+        Self {
+            key,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Check if the given storage key is a valid LazyDeque sub-key and if so
+    /// return which one
+    fn is_valid_sub_key(
+        &self,
+        key: &storage::Key,
+    ) -> crate::Result<Option<SubKey>> {
+        let suffix = match key.split_prefix(&self.key) {
+            None => {
+                // not matching prefix, irrelevant
+                return Ok(None);
+            }
+            Some(None) => {
+                // no suffix, invalid
+                return Err(ValidationError::InvalidSubKey(key.clone()))
+                    .into_storage_result();
+            }
+            Some(Some(suffix)) => suffix,
+        };
+
+        // Match the suffix against expected sub-keys
+        match &suffix.segments[..] {
+            [DbKeySeg::StringSeg(sub)] if sub == FRONT_SUBKEY => {
+                Ok(Some(SubKey::Front))
+            }
+            [DbKeySeg::StringSeg(sub)] if sub == BACK_SUBKEY => {
+                Ok(Some(SubKey::Back))
+            }
+            [DbKeySeg::StringSeg(sub_a), DbKeySeg::StringSeg(sub_b)]
+                if sub_a == DATA_SUBKEY =>
+            {
+                if let Ok(index) = storage::KeySeg::parse(sub_b.clone()) {
+                    Ok(Some(SubKey::Data(index)))
+                } else {
+                    Err(ValidationError::InvalidSubKey(key.clone()))
+                        .into_storage_result()
+                }
+            }
+            _ => Err(ValidationError::InvalidSubKey(key.clone()))
+                .into_storage_result(),
+        }
+    }
+
+    fn is_data_sub_key(&self, key: &storage::Key) -> bool {
+        let sub_key = self.is_valid_sub_key(key);
+        // The `SubKey::Front` and `SubKey::Back` are not data sub-keys
+        matches!(sub_key, Ok(Some(SubKey::Data(_))))
+    }
+}
+
+// Generic `LazyDeque` methods that require no bounds on values `T`
+impl<T> LazyDeque<T> {
+    /// Reads the storage indices of the first element and after the last
+    /// element, or `None` if the deque is empty.
+    pub fn bounds<S>(&self, storage: &S) -> Result<Option<(Index, Index)>>
+    where
+        S: StorageRead,
+    {
+        let front = storage.read(&self.get_front_key())?;
+        let back = storage.read(&self.get_back_key())?;
+        match (front, back) {
+            (Some(front), Some(back)) => Ok(Some((front, back))),
+            (None, None) => Ok(None),
+            _ => Err(ValidationError::InvalidBounds).into_storage_result(),
+        }
+    }
+
+    /// Reads the number of elements in the deque.
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len<S>(&self, storage: &S) -> Result<u64>
+    where
+        S: StorageRead,
+    {
+        match self.bounds(storage)? {
+            Some((front, back)) => Ok(checked!(back - front)?),
+            None => Ok(0),
+        }
+    }
+
+    /// Returns `true` if the deque contains no elements.
+    pub fn is_empty<S>(&self, storage: &S) -> Result<bool>
+    where
+        S: StorageRead,
+    {
+        Ok(self.bounds(storage)?.is_none())
+    }
+
+    /// Get the prefix of deque's elements storage
+    fn get_data_prefix(&self) -> storage::Key {
+        self.key.push(&DATA_SUBKEY.to_owned()).unwrap()
+    }
+
+    /// Get the sub-key of deque's elements storage
+    pub fn get_data_key(&self, index: Index) -> storage::Key {
+        self.get_data_prefix().push(&index).unwrap()
+    }
+
+    /// Get the sub-key of deque's front index storage
+    pub fn get_front_key(&self) -> storage::Key {
+        self.key.push(&FRONT_SUBKEY.to_owned()).unwrap()
+    }
+
+    /// Get the sub-key of deque's back index storage
+    pub fn get_back_key(&self) -> storage::Key {
+        self.key.push(&BACK_SUBKEY.to_owned()).unwrap()
+    }
+
+    /// Write the bounds of the deque. The bounds of an empty deque are deleted
+    /// from storage.
+    fn write_bounds<S>(
+        &self,
+        storage: &mut S,
+        front: Index,
+        back: Index,
+    ) -> Result<()>
+    where
+        S: StorageWrite + StorageRead,
+    {
+        if front == back {
+            storage.delete(&self.get_front_key())?;
+            storage.delete(&self.get_back_key())
+        } else {
+            storage.write(&self.get_front_key(), front)?;
+            storage.write(&self.get_back_key(), back)
+        }
+    }
+}
+
+// `LazyDeque` methods with borsh encoded values `T`
+impl<T> LazyDeque<T>
+where
+    T: BorshSerialize + BorshDeserialize + 'static + Debug,
+{
+    /// Prepends an element to the front of the deque.
+    pub fn push_front<S>(&self, storage: &mut S, val: T) -> Result<()>
+    where
+        S: StorageWrite + StorageRead,
+    {
+        let (front, back) = self
+            .bounds(storage)?
+            .unwrap_or((INITIAL_INDEX, INITIAL_INDEX));
+        let front = checked!(front - 1)?;
+        storage.write(&self.get_data_key(front), val)?;
+        self.write_bounds(storage, front, back)
+    }
+
+    /// Appends an element to the back of the deque.
+    pub fn push_back<S>(&self, storage: &mut S, val: T) -> Result<()>
+    where
+        S: StorageWrite + StorageRead,
+    {
+        let (front, back) = self
+            .bounds(storage)?
+            .unwrap_or((INITIAL_INDEX, INITIAL_INDEX));
+        storage.write(&self.get_data_key(back), val)?;
+        self.write_bounds(storage, front, checked!(back + 1)?)
+    }
+
+    /// Removes the first element from the deque and returns it, or `Ok(None)`
+    /// if it is empty.
+    ///
+    /// Note that an empty deque is completely removed from storage.
+    pub fn pop_front<S>(&self, storage: &mut S) -> Result<Option<T>>
+    where
+        S: StorageWrite + StorageRead,
+    {
+        let Some((front, back)) = self.bounds(storage)? else {
+            return Ok(None);
+        };
+        let data_key = self.get_data_key(front);
+        let popped_val = storage.read(&data_key)?;
+        storage.delete(&data_key)?;
+        self.write_bounds(storage, checked!(front + 1)?, back)?;
+        Ok(popped_val)
+    }
+
+    /// Removes the last element from the deque and returns it, or `Ok(None)`
+    /// if it is empty.
+    ///
+    /// Note that an empty deque is completely removed from storage.
+    pub fn pop_back<S>(&self, storage: &mut S) -> Result<Option<T>>
+    where
+        S: StorageWrite + StorageRead,
+    {
+        let Some((front, back)) = self.bounds(storage)? else {
+            return Ok(None);
+        };
+        let back = checked!(back - 1)?;
+        let data_key = self.get_data_key(back);
+        let popped_val = storage.read(&data_key)?;
+        storage.delete(&data_key)?;
+        self.write_bounds(storage, front, back)?;
+        Ok(popped_val)
+    }
+
+    /// Read the element at the given position from the front of the deque or
+    /// `Ok(None)` if out of bounds.
+    pub fn get<S>(&self, storage: &S, position: u64) -> Result<Option<T>>
+    where
+        S: StorageRead,
+    {
+        match self.bounds(storage)? {
+            Some((front, back)) => {
+                let index = checked!(front + position)?;
+                if index < back {
+                    storage.read(&self.get_data_key(index))
+                } else {
+                    Ok(None)
+                }
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Read the first element
+    pub fn front<S>(&self, storage: &S) -> Result<Option<T>>
+    where
+        S: StorageRead,
+    {
+        match self.bounds(storage)? {
+            Some((front, _back)) => storage.read(&self.get_data_key(front)),
+            None => Ok(None),
+        }
+    }
+
+    /// Read the last element
+    pub fn back<S>(&self, storage: &S) -> Result<Option<T>>
+    where
+        S: StorageRead,
+    {
+        match self.bounds(storage)? {
+            Some((_front, back)) => {
+                storage.read(&self.get_data_key(checked!(back - 1)?))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// An iterator visiting all elements from the front to the back. The
+    /// iterator element type is `Result<T>`, because iterator's call to `next`
+    /// may fail with e.g. out of gas or data decoding error.
+    ///
+    /// Note that this function shouldn't be used in transactions and VPs code
+    /// on unbounded deques to avoid gas usage increasing with the length of
+    /// the deque.
+    pub fn iter<'iter>(
+        &self,
+        storage: &'iter impl StorageRead,
+    ) -> Result<impl Iterator<Item = Result<T>> + 'iter> {
+        let iter = crate::iter_prefix(storage, &self.get_data_prefix())?;
+        Ok(iter.map(|key_val_res| {
+            let (_key, val) = key_val_res?;
+            Ok(val)
+        }))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::testing::TestStorage;
+
+    #[test]
+    fn test_lazy_deque_basics() -> crate::Result<()> {
+        let mut storage = TestStorage::default();
+
+        let key = storage::Key::parse("test").unwrap();
+        let lazy_deque = LazyDeque::<u32>::open(key);
+
+        // The deque should be empty at first
+        assert!(lazy_deque.is_empty(&storage)?);
+        assert_eq!(lazy_deque.len(&storage)?, 0);
+        assert!(lazy_deque.iter(&storage)?.next().is_none());
+        assert!(lazy_deque.pop_front(&mut storage)?.is_none());
+        assert!(lazy_deque.pop_back(&mut storage)?.is_none());
+        assert!(lazy_deque.front(&storage)?.is_none());
+        assert!(lazy_deque.back(&storage)?.is_none());
+        assert!(lazy_deque.get(&storage, 0)?.is_none());
+
+        // Push values to both ends
+        lazy_deque.push_back(&mut storage, 2)?;
+        lazy_deque.push_front(&mut storage, 1)?;
+        lazy_deque.push_back(&mut storage, 3)?;
+        lazy_deque.push_front(&mut storage, 0)?;
+        assert!(!lazy_deque.is_empty(&storage)?);
+        assert_eq!(lazy_deque.len(&storage)?, 4);
+        assert_eq!(
+            lazy_deque.bounds(&storage)?,
+            Some((INITIAL_INDEX - 2, INITIAL_INDEX + 2))
+        );
+        assert_eq!(lazy_deque.front(&storage)?, Some(0));
+        assert_eq!(lazy_deque.back(&storage)?, Some(3));
+        assert_eq!(lazy_deque.get(&storage, 1)?, Some(1));
+        assert_eq!(lazy_deque.get(&storage, 4)?, None);
+        let vals: Vec<u32> =
+            lazy_deque.iter(&storage)?.map(Result::unwrap).collect();
+        assert_eq!(vals, vec![0, 1, 2, 3]);
+
+        // Pop values from both ends
+        assert_eq!(lazy_deque.pop_front(&mut storage)?, Some(0));
+        assert_eq!(lazy_deque.pop_back(&mut storage)?, Some(3));
+        assert_eq!(lazy_deque.pop_back(&mut storage)?, Some(2));
+        assert_eq!(lazy_deque.len(&storage)?, 1);
+        assert_eq!(lazy_deque.front(&storage)?, Some(1));
+        assert_eq!(lazy_deque.back(&storage)?, Some(1));
+        assert_eq!(lazy_deque.pop_front(&mut storage)?, Some(1));
+
+        // An empty deque is removed from storage
+        assert!(lazy_deque.is_empty(&storage)?);
+        assert!(!storage.has_key(&lazy_deque.get_front_key())?);
+        assert!(!storage.has_key(&lazy_deque.get_back_key())?);
+        assert!(lazy_deque.iter(&storage)?.next().is_none());
+
+        let storage_key = lazy_deque.get_data_key(INITIAL_INDEX);
+        assert_eq!(
+            lazy_deque.is_valid_sub_key(&storage_key).unwrap(),
+            Some(SubKey::Data(INITIAL_INDEX))
+        );
+        assert_eq!(
+            lazy_deque
+                .is_valid_sub_key(&lazy_deque.get_front_key())
+                .unwrap(),
+            Some(SubKey::Front)
+        );
+        assert!(!lazy_deque.is_data_sub_key(&lazy_deque.get_back_key()));
+
+        Ok(())
+    }
+}
@@ -4,17 +4,23 @@ use std::collections::BTreeMap;
 use std::fmt::Debug;
 use std::hash::Hash;
 use std::marker::PhantomData;
+use std::ops::RangeBounds;
 
+use namada_core::arith::checked;
 use namada_core::borsh::{BorshDeserialize, BorshSerialize};
 use namada_core::storage::{self, DbKeySeg, KeySeg};
 use thiserror::Error;
 
 use super::super::Result;
-use super::{LazyCollection, ReadError};
+use super::{
+    iter_range_bytes, rev_iter_range_bytes, KeyRange, LazyCollection, ReadError,
+};
 use crate::{ResultExt, StorageRead, StorageWrite};
 
 /// Subkey corresponding to the data elements of the LazyMap
 pub const DATA_SUBKEY: &str = "data";
+/// Subkey pointing to the length of a LazyMap opened with a length counter
+pub const LEN_SUBKEY: &str = "len";
 
 /// Lazy map.
 ///
@@ -27,10 +33,15 @@ pub const DATA_SUBKEY: &str = "data";
 /// [`storage::KeySeg`] and this trait is used to turn the keys into key
 /// segments.
 ///
+/// A simple map may be opened with [`LazyMap::open_with_len`] to keep the
+/// number of its elements in storage, so that its length can be read without
+/// iterating the map.
+///
 /// [`HashMap`]: `namada_core::collections::HashMap`
 #[derive(Debug)]
 pub struct LazyMap<K, V, SON = super::Simple> {
     key: storage::Key,
+    with_len: bool,
     phantom_k: PhantomData<K>,
     phantom_v: PhantomData<V>,
     phantom_son: PhantomData<SON>,
@@ -65,6 +76,10 @@ pub enum ValidationError {
     InvalidSubKey(storage::Key),
     #[error("Invalid nested storage key {0}")]
     InvalidNestedSubKey(storage::Key),
+    #[error("Incorrect difference in LazyMap's length")]
+    InvalidLenDiff,
+    #[error("An empty LazyMap's length must be deleted from storage")]
+    EmptyMapLenShouldBeDeleted,
 }
 
 /// Trait used to facilitate collection of lazy maps into eager maps
@@ -137,6 +152,7 @@ where
     fn open(key: storage::Key) -> Self {
         Self {
             key,
+            with_len: false,
             phantom_k: PhantomData,
             phantom_v: PhantomData,
             phantom_son: PhantomData,
@@ -225,6 +241,7 @@ where
     fn open(key: storage::Key) -> Self {
         Self {
             key,
+            with_len: false,
             phantom_k: PhantomData,
             phantom_v: PhantomData,
             phantom_son: PhantomData,
@@ -260,6 +277,13 @@ where
 
         // Match the suffix against expected sub-keys
         match &suffix.segments[..] {
+            // The length is not a data sub-key, it's validated together with
+            // the data by the collection validation helpers
+            [DbKeySeg::StringSeg(sub)]
+                if self.with_len && sub == LEN_SUBKEY =>
+            {
+                Ok(None)
+            }
             [DbKeySeg::StringSeg(sub_a), DbKeySeg::StringSeg(sub_b)]
                 if sub_a == DATA_SUBKEY =>
             {
@@ -295,6 +319,13 @@ where
         let key_str = key.to_db_key();
         self.get_data_prefix().push(&key_str).unwrap()
     }
+
+    /// Get the sub-key of the map's length, if it was opened with a length
+    /// counter
+    pub fn get_len_key(&self) -> Option<storage::Key> {
+        self.with_len
+            .then(|| self.key.push(&LEN_SUBKEY.to_owned()).unwrap())
+    }
 }
 
 // `LazyMap` methods with nested `LazyCollection`s `V`
@@ -366,6 +397,48 @@ where
         }))
     }
 
+    /// An iterator visiting the key-value elements whose key in this map is
+    /// within the given `range`, where the values are from the inner-most
+    /// collection. The keys are ordered by their storage key encoding, which
+    /// preserves the order of the key type.
+    ///
+    /// The iteration starts from the start of the range when the storage can
+    /// seek into a prefix, which is not the case in transactions and VPs code,
+    /// where the elements before the start of the range are still visited by
+    /// the underlying storage iterator (but not decoded).
+    pub fn iter_range<'iter>(
+        &'iter self,
+        storage: &'iter impl StorageRead,
+        range: impl RangeBounds<K>,
+    ) -> Result<
+        impl Iterator<
+            Item = Result<(
+                <Self as LazyCollection>::SubKey,
+                <Self as LazyCollection>::Value,
+            )>,
+        > + 'iter,
+    > {
+        let iter = iter_range_bytes(
+            storage,
+            &self.get_data_prefix(),
+            KeyRange::new(range),
+        )?;
+        Ok(iter
+            .filter(|key_val_res| match key_val_res {
+                Ok((key, _)) => self.is_data_sub_key(key),
+                Err(_) => true,
+            })
+            .map(|key_val_res| {
+                let (key, val) = key_val_res?;
+                let sub_key = LazyCollection::is_valid_sub_key(self, &key)?
+                    .ok_or(ReadError::UnexpectedlyEmptyStorageKey)
+                    .into_storage_result()?;
+                let val = <Self as LazyCollection>::Value::try_from_slice(&val)
+                    .into_storage_result()?;
+                Ok((sub_key, val))
+            }))
+    }
+
     /// Returns whether the map contains no elements.
     pub fn is_empty<S>(&self, storage: &S) -> Result<bool>
     where
@@ -383,6 +456,22 @@ where
     K: storage::KeySeg,
     V: BorshDeserialize + BorshSerialize + 'static,
 {
+    /// Create or use an existing map with the given storage `key` that keeps
+    /// the number of its elements in a length sub-key, which makes
+    /// [`LazyMap::len`] a single storage read.
+    ///
+    /// The length is only maintained by the handles opened with this
+    /// constructor, so a map must always be opened the same way.
+    pub fn open_with_len(key: storage::Key) -> Self {
+        Self {
+            key,
+            with_len: true,
+            phantom_k: PhantomData,
+            phantom_v: PhantomData,
+            phantom_son: PhantomData,
+        }
+    }
+
     /// Inserts a key-value pair into the map.
     ///
     /// The full storage key identifies the key in the pair, while the value is
@@ -406,6 +495,9 @@ where
 
         let data_key = self.get_data_key(&key);
         Self::write_key_val(storage, &data_key, val)?;
+        if previous.is_none() {
+            self.increment_len(storage)?;
+        }
 
         Ok(previous)
     }
@@ -421,6 +513,7 @@ where
         if value.is_some() {
             let data_key = self.get_data_key(key);
             storage.delete(&data_key)?;
+            self.decrement_len(storage)?;
         }
 
         Ok(value)
@@ -444,8 +537,12 @@ where
     {
         let data_key = self.get_data_key(&key);
         let current = Self::read_key_val(storage, &data_key)?;
+        let is_new = current.is_none();
         let new = f(current);
         Self::write_key_val(storage, &data_key, new)?;
+        if is_new {
+            self.increment_len(storage)?;
+        }
         Ok(())
     }
 
@@ -459,8 +556,12 @@ where
     {
         let data_key = self.get_data_key(&key);
         let current = Self::read_key_val(storage, &data_key)?;
+        let is_new = current.is_none();
         let new = f(current)?;
         Self::write_key_val(storage, &data_key, new)?;
+        if is_new {
+            self.increment_len(storage)?;
+        }
         Ok(())
     }
 
@@ -484,14 +585,19 @@ where
 
     /// Reads the number of elements in the map.
     ///
-    /// Note that this function shouldn't be used in transactions and VPs code
-    /// on unbounded maps to avoid gas usage increasing with the length of the
+    /// Note that unless the map was opened with [`LazyMap::open_with_len`],
+    /// this function shouldn't be used in transactions and VPs code on
+    /// unbounded maps to avoid gas usage increasing with the length of the
     /// set.
     #[allow(clippy::len_without_is_empty)]
     pub fn len<S>(&self, storage: &S) -> Result<u64>
     where
         S: StorageRead,
     {
+        if let Some(len_key) = self.get_len_key() {
+            let len = storage.read(&len_key)?;
+            return Ok(len.unwrap_or_default());
+        }
         let iter = crate::iter_prefix_bytes(storage, &self.get_data_prefix())?;
         iter.count().try_into().into_storage_result()
     }
@@ -519,6 +625,106 @@ where
         }))
     }
 
+    /// An iterator visiting the key-value elements whose key is within the
+    /// given `range`. The keys are ordered by their storage key encoding,
+    /// which preserves the order of the key type.
+    ///
+    /// The iteration starts from the start of the range when the storage can
+    /// seek into a prefix, which is not the case in transactions and VPs code,
+    /// where the elements before the start of the range are still visited by
+    /// the underlying storage iterator (but not decoded).
+    pub fn iter_range<'iter>(
+        &self,
+        storage: &'iter impl StorageRead,
+        range: impl RangeBounds<K>,
+    ) -> Result<impl Iterator<Item = Result<(K, V)>> + 'iter> {
+        let iter = iter_range_bytes(
+            storage,
+            &self.get_data_prefix(),
+            KeyRange::new(range),
+        )?;
+        Ok(iter.map(|key_val_res| Self::decode_key_val(key_val_res?)))
+    }
+
+    /// An iterator visiting the key-value elements whose key is within the
+    /// given `range` in reverse order.
+    ///
+    /// In transactions and VPs code, where the storage cannot seek into a
+    /// prefix, the whole range is read before this function returns.
+    pub fn rev_iter_range<'iter>(
+        &self,
+        storage: &'iter impl StorageRead,
+        range: impl RangeBounds<K>,
+    ) -> Result<impl Iterator<Item = Result<(K, V)>> + 'iter> {
+        let iter = rev_iter_range_bytes(
+            storage,
+            &self.get_data_prefix(),
+            KeyRange::new(range),
+        )?;
+        Ok(iter.map(|key_val_res| Self::decode_key_val(key_val_res?)))
+    }
+
+    /// Returns the element with the lowest key, if any.
+    pub fn first<S>(&self, storage: &S) -> Result<Option<(K, V)>>
+    where
+        S: StorageRead,
+    {
+        self.iter(storage)?.next().transpose()
+    }
+
+    /// Returns the element with the highest key, if any.
+    ///
+    /// Note that in transactions and VPs code, where the storage cannot seek
+    /// into a prefix, this function has to read the whole map, so it shouldn't
+    /// be used there on unbounded maps.
+    pub fn last<S>(&self, storage: &S) -> Result<Option<(K, V)>>
+    where
+        S: StorageRead,
+    {
+        self.rev_iter_range(storage, ..)?.next().transpose()
+    }
+
+    /// Decode a raw key-value element
+    fn decode_key_val((key, val): (storage::Key, Vec<u8>)) -> Result<(K, V)> {
+        let last_key_seg = key
+            .last()
+            .ok_or(ReadError::UnexpectedlyEmptyStorageKey)
+            .into_storage_result()?;
+        let key = K::parse(last_key_seg.raw()).into_storage_result()?;
+        let val = V::try_from_slice(&val).into_storage_result()?;
+        Ok((key, val))
+    }
+
+    /// Increment the length of a map opened with a length counter
+    fn increment_len<S>(&self, storage: &mut S) -> Result<()>
+    where
+        S: StorageWrite + StorageRead,
+    {
+        if let Some(len_key) = self.get_len_key() {
+            let len: u64 = storage.read(&len_key)?.unwrap_or_default();
+            storage.write(&len_key, checked!(len + 1)?)?;
+        }
+        Ok(())
+    }
+
+    /// Decrement the length of a map opened with a length counter. The length
+    /// of an empty map is deleted from storage.
+    fn decrement_len<S>(&self, storage: &mut S) -> Result<()>
+    where
+        S: StorageWrite + StorageRead,
+    {
+        if let Some(len_key) = self.get_len_key() {
+            let len: u64 = storage.read(&len_key)?.unwrap_or_default();
+            let len = checked!(len - 1)?;
+            if len == 0 {
+                storage.delete(&len_key)?;
+            } else {
+                storage.write(&len_key, len)?;
+            }
+        }
+        Ok(())
+    }
+
     // /// Collect the lazy map into an eager map
     // pub fn collect<M, S>(&self, storage: &S) -> Result<M>
     // where
@@ -655,6 +861,119 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_lazy_map_range_iter() -> crate::Result<()> {
+        let mut storage = TestStorage::default();
+
+        let key = storage::Key::parse("test").unwrap();
+        let lazy_map = LazyMap::<u64, String>::open(key);
+
+        assert!(lazy_map.iter_range(&storage, 0..10)?.next().is_none());
+        assert!(lazy_map.first(&storage)?.is_none());
+        assert!(lazy_map.last(&storage)?.is_none());
+
+        // The keys are inserted out of order and some of them are larger than
+        // a single byte to check that the encoding preserves their order
+        for key in [300_u64, 2, 1000, 5, 256, 7] {
+            lazy_map.insert(&mut storage, key, key.to_string())?;
+        }
+        let range_keys = |range: std::ops::Range<u64>| -> Vec<u64> {
+            lazy_map
+                .iter_range(&storage, range)
+                .unwrap()
+                .map(|res| res.unwrap().0)
+                .collect()
+        };
+        assert_eq!(range_keys(0..10), vec![2, 5, 7]);
+        assert_eq!(range_keys(5..300), vec![5, 7, 256]);
+        assert_eq!(range_keys(6..256), vec![7]);
+        assert_eq!(range_keys(1001..2000), Vec::<u64>::new());
+
+        let (key, val) =
+            lazy_map.iter_range(&storage, 256..)?.next().unwrap()?;
+        assert_eq!((key, val.as_str()), (256, "256"));
+        assert_eq!(lazy_map.iter_range(&storage, ..=5)?.count(), 2);
+        assert_eq!(lazy_map.iter_range(&storage, ..)?.count(), 6);
+
+        let rev_keys: Vec<u64> = lazy_map
+            .rev_iter_range(&storage, 5..=1000)?
+            .map(|res| res.unwrap().0)
+            .collect();
+        assert_eq!(rev_keys, vec![1000, 300, 256, 7, 5]);
+
+        assert_eq!(lazy_map.first(&storage)?, Some((2, "2".to_string())));
+        assert_eq!(lazy_map.last(&storage)?, Some((1000, "1000".to_string())));
+
+        // Range iteration over a nested map
+        let key = storage::Key::parse("test_nested").unwrap();
+        let nested_map = NestedMap::<u64, LazyMap<u64, u64>>::open(key);
+        for (outer, inner) in [(1_u64, 10_u64), (2, 20), (2, 21), (3, 30)] {
+            nested_map.at(&outer).insert(&mut storage, inner, inner)?;
+        }
+        let vals: Vec<u64> = nested_map
+            .iter_range(&storage, 2..)?
+            .map(|res| res.unwrap().1)
+            .collect();
+        assert_eq!(vals, vec![20, 21, 30]);
+        let vals: Vec<u64> = nested_map
+            .iter_range(&storage, ..3)?
+            .map(|res| res.unwrap().1)
+            .collect();
+        assert_eq!(vals, vec![10, 20, 21]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_lazy_map_with_len() -> crate::Result<()> {
+        let mut storage = TestStorage::default();
+
+        let key = storage::Key::parse("test").unwrap();
+        let lazy_map = LazyMap::<u32, String>::open_with_len(key.clone());
+        let len_key = lazy_map.get_len_key().unwrap();
+        assert!(LazyMap::<u32, String>::open(key).get_len_key().is_none());
+
+        assert_eq!(lazy_map.len(&storage)?, 0);
+        assert!(!storage.has_key(&len_key)?);
+
+        lazy_map.insert(&mut storage, 1, "one".to_string())?;
+        lazy_map.insert(&mut storage, 2, "two".to_string())?;
+        assert_eq!(lazy_map.len(&storage)?, 2);
+
+        // Overwriting or updating an existing key doesn't change the length
+        lazy_map.insert(&mut storage, 2, "deux".to_string())?;
+        lazy_map.update(&mut storage, 1, |_| "un".to_string())?;
+        assert_eq!(lazy_map.len(&storage)?, 2);
+
+        // Updating a new key does
+        lazy_map.update(&mut storage, 3, |current| {
+            assert!(current.is_none());
+            "three".to_string()
+        })?;
+        lazy_map.try_update(&mut storage, 4, |_| Ok("four".to_string()))?;
+        assert_eq!(lazy_map.len(&storage)?, 4);
+        assert_eq!(storage.read::<u64>(&len_key)?, Some(4));
+
+        // The length is not a data sub-key
+        assert_eq!(lazy_map.is_valid_sub_key(&len_key)?, None);
+        assert!(!lazy_map.is_data_sub_key(&len_key));
+        assert_eq!(lazy_map.iter(&storage)?.count(), 4);
+
+        // Removing a missing key doesn't change the length
+        assert!(lazy_map.remove(&mut storage, &5)?.is_none());
+        assert_eq!(lazy_map.len(&storage)?, 4);
+
+        for key in 1..=4 {
+            assert!(lazy_map.remove(&mut storage, &key)?.is_some());
+        }
+        assert_eq!(lazy_map.len(&storage)?, 0);
+        assert!(lazy_map.is_empty(&storage)?);
+        // The length of an empty map is deleted
+        assert!(!storage.has_key(&len_key)?);
+
+        Ok(())
+    }
+
     #[test]
     fn test_lazy_map_with_addr_key() -> crate::Result<()> {
         let mut storage = TestStorage::default();
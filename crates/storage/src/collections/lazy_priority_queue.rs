@@ -0,0 +1,344 @@
+//! Lazy priority queue.
+
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use namada_core::arith::checked;
+use namada_core::borsh::{BorshDeserialize, BorshSerialize};
+use namada_core::storage::{self, DbKeySeg, KeySeg};
+use thiserror::Error;
+
+use super::super::Result;
+use super::{LazyCollection, ReadError};
+use crate::{ResultExt, StorageRead, StorageWrite};
+
+/// Subkey pointing to the sequence number of the next pushed element
+pub const SEQ_SUBKEY: &str = "seq";
+/// Subkey corresponding to the data elements of the LazyPriorityQueue
+pub const DATA_SUBKEY: &str = "data";
+
+/// Using `u64` for the sequence numbers of the queue's elements
+pub type Seq = u64;
+
+/// Lazy priority queue.
+///
+/// This can be used as an alternative to `std::collections::BinaryHeap` with
+/// the lowest key first. In the lazy priority queue, the elements do not
+/// reside in memory but are instead read and written to storage sub-keys of
+/// the storage `key` used to construct the queue.
+///
+/// Every element is pushed with a priority key `K`, which can be anything that
+/// implements [`storage::KeySeg`] with an encoding that preserves its order
+/// (e.g. integers or epochs), followed by a sequence number, so that the
+/// elements with the same key are popped in the order in which they were
+/// pushed. The elements are popped in the order of their storage keys.
+///
+/// Note that the sequence number is kept in storage when the queue becomes
+/// empty, so that the sequence numbers of the popped elements are never
+/// reused.
+#[derive(Debug)]
+pub struct LazyPriorityQueue<K, V> {
+    key: storage::Key,
+    phantom_k: PhantomData<K>,
+    phantom_v: PhantomData<V>,
+}
+
+/// Possible sub-keys of a [`LazyPriorityQueue`]
+#[derive(Clone, Debug, PartialEq)]
+pub enum SubKey<K> {
+    /// Sequence number sub-key
+    Seq,
+    /// Data sub-key, further sub-keyed by its priority key and sequence
+    /// number
+    Data(K, Seq),
+}
+
+#[allow(missing_docs)]
+#[derive(Error, Debug)]
+pub enum ValidationError {
+    #[error("Push with a wrong sequence number {0}")]
+    UnexpectedPushSeq(Seq),
+    #[error("Update of a pushed element at sequence number {0}")]
+    UnexpectedUpdate(Seq),
+    #[error(
+        "The change of a LazyPriorityQueue's sequence number doesn't match \
+         the pushed elements"
+    )]
+    InvalidSeqDiff,
+    #[error("Invalid storage key {0}")]
+    InvalidSubKey(storage::Key),
+}
+
+impl<K, V> LazyCollection for LazyPriorityQueue<K, V>
+where
+    K: storage::KeySeg + Debug,
+    V: BorshSerialize + BorshDeserialize + 'static + Debug,
+{
+    type SubKey = SubKey<K>;
+    type Value = V;
+
+    /// Create or use an existing queue with the given storage `key`.
+    fn open(key: storage::Key) -> Self {
+        Self {
+            key,
+            phantom_k: PhantomData,
+            phantom_v: PhantomData,
+        }
+    }
+
+    /// Check if the given storage key is a valid LazyPriorityQueue sub-key
+    /// and if so return which one
+    fn is_valid_sub_key(
+        &self,
+        key: &storage::Key,
+    ) -> crate::Result<Option<Self::SubKey>> {
+        let suffix = match key.split_prefix(&self.key) {
+            None => {
+                // not matching prefix, irrelevant
+                return Ok(None);
+            }
+            Some(None) => {
+                // no suffix, invalid
+                return Err(ValidationError::InvalidSubKey(key.clone()))
+                    .into_storage_result();
+            }
+            Some(Some(suffix)) => suffix,
+        };
+
+        // A helper to validate the priority key and sequence number segments
+        let validate_sub_key = |raw_key, raw_seq: &String| {
+            let parsed_key = K::parse(raw_key);
+            let parsed_seq = Seq::parse(raw_seq.clone());
+            match (parsed_key, parsed_seq) {
+                (Ok(key), Ok(seq)) => Ok(Some(SubKey::Data(key, seq))),
+                _ => Err(ValidationError::InvalidSubKey(key.clone()))
+                    .into_storage_result(),
+            }
+        };
+
+        // Match the suffix against expected sub-keys
+        match &suffix.segments[..] {
+            [DbKeySeg::StringSeg(sub)] if sub == SEQ_SUBKEY => {
+                Ok(Some(SubKey::Seq))
+            }
+            [DbKeySeg::StringSeg(sub), key_seg, DbKeySeg::StringSeg(seq)]
+                if sub == DATA_SUBKEY =>
+            {
+                validate_sub_key(key_seg.raw(), seq)
+            }
+            _ => Err(ValidationError::InvalidSubKey(key.clone()))
+                .into_storage_result(),
+        }
+    }
+
+    fn is_data_sub_key(&self, key: &storage::Key) -> bool {
+        let sub_key = self.is_valid_sub_key(key);
+        // The `SubKey::Seq` is not a data sub-key
+        matches!(sub_key, Ok(Some(SubKey::Data(_, _))))
+    }
+}
+
+// `LazyPriorityQueue` methods with borsh encoded values `V`
+impl<K, V> LazyPriorityQueue<K, V>
+where
+    K: storage::KeySeg,
+    V: BorshSerialize + BorshDeserialize + 'static,
+{
+    /// Get the prefix of queue's elements storage
+    fn get_data_prefix(&self) -> storage::Key {
+        self.key.push(&DATA_SUBKEY.to_owned()).unwrap()
+    }
+
+    /// Get the sub-key of an element with the given priority key and sequence
+    /// number
+    pub fn get_data_key(&self, key: &K, seq: Seq) -> storage::Key {
+        self.get_data_prefix()
+            .push(&key.to_db_key())
+            .unwrap()
+            .push(&seq)
+            .unwrap()
+    }
+
+    /// Get the sub-key of queue's sequence number storage
+    pub fn get_seq_key(&self) -> storage::Key {
+        self.key.push(&SEQ_SUBKEY.to_owned()).unwrap()
+    }
+
+    /// Reads the sequence number of the next pushed element.
+    pub fn next_seq<S>(&self, storage: &S) -> Result<Seq>
+    where
+        S: StorageRead,
+    {
+        let seq = storage.read(&self.get_seq_key())?;
+        Ok(seq.unwrap_or_default())
+    }
+
+    /// Pushes an element with the given priority key into the queue.
+    pub fn push<S>(&self, storage: &mut S, key: K, val: V) -> Result<()>
+    where
+        S: StorageWrite + StorageRead,
+    {
+        let seq = self.next_seq(storage)?;
+        storage.write(&self.get_data_key(&key, seq), val)?;
+        storage.write(&self.get_seq_key(), checked!(seq + 1)?)
+    }
+
+    /// Returns the element with the lowest priority key that was pushed first,
+    /// without removing it from the queue.
+    pub fn peek<S>(&self, storage: &S) -> Result<Option<(K, V)>>
+    where
+        S: StorageRead,
+    {
+        Ok(self
+            .peek_with_seq(storage)?
+            .map(|(key, _seq, val)| (key, val)))
+    }
+
+    /// Removes the element with the lowest priority key that was pushed first
+    /// from the queue and returns it, or `Ok(None)` if it is empty.
+    pub fn pop<S>(&self, storage: &mut S) -> Result<Option<(K, V)>>
+    where
+        S: StorageWrite + StorageRead,
+    {
+        match self.peek_with_seq(storage)? {
+            Some((key, seq, val)) => {
+                storage.delete(&self.get_data_key(&key, seq))?;
+                Ok(Some((key, val)))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Returns whether the queue contains no elements.
+    pub fn is_empty<S>(&self, storage: &S) -> Result<bool>
+    where
+        S: StorageRead,
+    {
+        let mut iter =
+            crate::iter_prefix_bytes(storage, &self.get_data_prefix())?;
+        Ok(iter.next().is_none())
+    }
+
+    /// Reads the number of elements in the queue.
+    ///
+    /// Note that this function shouldn't be used in transactions and VPs code
+    /// on unbounded queues to avoid gas usage increasing with the length of
+    /// the queue.
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len<S>(&self, storage: &S) -> Result<u64>
+    where
+        S: StorageRead,
+    {
+        let iter = crate::iter_prefix_bytes(storage, &self.get_data_prefix())?;
+        iter.count().try_into().into_storage_result()
+    }
+
+    /// An iterator visiting all elements in the order in which they would be
+    /// popped. The iterator element type is `Result<(K, V)>`, because
+    /// iterator's call to `next` may fail with e.g. out of gas or data
+    /// decoding error.
+    ///
+    /// Note that this function shouldn't be used in transactions and VPs code
+    /// on unbounded queues to avoid gas usage increasing with the length of
+    /// the queue.
+    pub fn iter<'iter>(
+        &self,
+        storage: &'iter impl StorageRead,
+    ) -> Result<impl Iterator<Item = Result<(K, V)>> + 'iter> {
+        let iter = crate::iter_prefix(storage, &self.get_data_prefix())?;
+        Ok(iter.map(|key_val_res| {
+            let (key, val) = key_val_res?;
+            let (key, _seq) = Self::parse_data_key(&key)?;
+            Ok((key, val))
+        }))
+    }
+
+    /// Read the first element in the queue with its sequence number
+    fn peek_with_seq<S>(&self, storage: &S) -> Result<Option<(K, Seq, V)>>
+    where
+        S: StorageRead,
+    {
+        let mut iter = crate::iter_prefix(storage, &self.get_data_prefix())?;
+        match iter.next() {
+            Some(key_val_res) => {
+                let (key, val) = key_val_res?;
+                let (key, seq) = Self::parse_data_key(&key)?;
+                Ok(Some((key, seq, val)))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Parse the priority key and sequence number from an element's storage
+    /// key
+    fn parse_data_key(key: &storage::Key) -> Result<(K, Seq)> {
+        match &key.segments[..] {
+            [.., key_seg, seq_seg] => {
+                let key = K::parse(key_seg.raw()).into_storage_result()?;
+                let seq = Seq::parse(seq_seg.raw()).into_storage_result()?;
+                Ok((key, seq))
+            }
+            _ => Err(ReadError::UnexpectedlyEmptyStorageKey)
+                .into_storage_result(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use namada_core::chain::Epoch;
+
+    use super::*;
+    use crate::testing::TestStorage;
+
+    #[test]
+    fn test_lazy_priority_queue_basics() -> crate::Result<()> {
+        let mut storage = TestStorage::default();
+
+        let key = storage::Key::parse("test").unwrap();
+        let queue = LazyPriorityQueue::<Epoch, String>::open(key);
+
+        // The queue should be empty at first
+        assert!(queue.is_empty(&storage)?);
+        assert_eq!(queue.len(&storage)?, 0);
+        assert!(queue.peek(&storage)?.is_none());
+        assert!(queue.pop(&mut storage)?.is_none());
+        assert!(queue.iter(&storage)?.next().is_none());
+
+        // Push elements out of order, with some of them at the same priority
+        queue.push(&mut storage, Epoch(300), "c".to_string())?;
+        queue.push(&mut storage, Epoch(2), "a".to_string())?;
+        queue.push(&mut storage, Epoch(300), "d".to_string())?;
+        queue.push(&mut storage, Epoch(7), "b".to_string())?;
+        assert!(!queue.is_empty(&storage)?);
+        assert_eq!(queue.len(&storage)?, 4);
+        assert_eq!(queue.next_seq(&storage)?, 4);
+        assert_eq!(queue.peek(&storage)?, Some((Epoch(2), "a".to_string())));
+        let vals: Vec<String> =
+            queue.iter(&storage)?.map(|res| res.unwrap().1).collect();
+        assert_eq!(vals, vec!["a", "b", "c", "d"]);
+
+        // Pop the elements in the order of priority and then of insertion
+        for expected in ["a", "b", "c", "d"] {
+            let (_key, val) = queue.pop(&mut storage)?.unwrap();
+            assert_eq!(val, expected);
+        }
+        assert!(queue.is_empty(&storage)?);
+        assert!(queue.pop(&mut storage)?.is_none());
+        // The sequence number is not reset
+        assert_eq!(queue.next_seq(&storage)?, 4);
+
+        let storage_key = queue.get_data_key(&Epoch(2), 1);
+        assert_eq!(
+            queue.is_valid_sub_key(&storage_key).unwrap(),
+            Some(SubKey::Data(Epoch(2), 1))
+        );
+        assert_eq!(
+            queue.is_valid_sub_key(&queue.get_seq_key()).unwrap(),
+            Some(SubKey::Seq)
+        );
+        assert!(!queue.is_data_sub_key(&queue.get_seq_key()));
+
+        Ok(())
+    }
+}
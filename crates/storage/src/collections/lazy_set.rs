@@ -2,12 +2,15 @@
 
 use std::fmt::Debug;
 use std::marker::PhantomData;
+use std::ops::RangeBounds;
 
 use namada_core::storage::{self, DbKeySeg, KeySeg};
 use thiserror::Error;
 
 use super::super::Result;
-use super::{LazyCollection, ReadError};
+use super::{
+    iter_range_bytes, rev_iter_range_bytes, KeyRange, LazyCollection, ReadError,
+};
 use crate::{ResultExt, StorageRead, StorageWrite};
 
 /// A lazy set.
@@ -212,6 +215,73 @@ where
             Ok(key)
         }))
     }
+
+    /// An iterator visiting the keys within the given `range`. The keys are
+    /// ordered by their storage key encoding, which preserves the order of the
+    /// key type.
+    ///
+    /// The iteration starts from the start of the range when the storage can
+    /// seek into a prefix, which is not the case in transactions and VPs code,
+    /// where the keys before the start of the range are still visited by the
+    /// underlying storage iterator.
+    pub fn iter_range<'iter>(
+        &self,
+        storage: &'iter impl StorageRead,
+        range: impl RangeBounds<K>,
+    ) -> Result<impl Iterator<Item = Result<K>> + 'iter> {
+        let iter = iter_range_bytes(storage, &self.key, KeyRange::new(range))?;
+        Ok(iter.map(|key_val_res| {
+            let (key, _) = key_val_res?;
+            Self::decode_key(&key)
+        }))
+    }
+
+    /// An iterator visiting the keys within the given `range` in reverse
+    /// order.
+    ///
+    /// In transactions and VPs code, where the storage cannot seek into a
+    /// prefix, the whole range is read before this function returns.
+    pub fn rev_iter_range<'iter>(
+        &self,
+        storage: &'iter impl StorageRead,
+        range: impl RangeBounds<K>,
+    ) -> Result<impl Iterator<Item = Result<K>> + 'iter> {
+        let iter =
+            rev_iter_range_bytes(storage, &self.key, KeyRange::new(range))?;
+        Ok(iter.map(|key_val_res| {
+            let (key, _) = key_val_res?;
+            Self::decode_key(&key)
+        }))
+    }
+
+    /// Returns the lowest key in the set, if any.
+    pub fn first<S>(&self, storage: &S) -> Result<Option<K>>
+    where
+        S: StorageRead,
+    {
+        self.iter(storage)?.next().transpose()
+    }
+
+    /// Returns the highest key in the set, if any.
+    ///
+    /// Note that in transactions and VPs code, where the storage cannot seek
+    /// into a prefix, this function has to read the whole set, so it shouldn't
+    /// be used there on unbounded sets.
+    pub fn last<S>(&self, storage: &S) -> Result<Option<K>>
+    where
+        S: StorageRead,
+    {
+        self.rev_iter_range(storage, ..)?.next().transpose()
+    }
+
+    /// Decode a set's key from its storage sub-key
+    fn decode_key(key: &storage::Key) -> Result<K> {
+        let last_key_seg = key
+            .last()
+            .ok_or(ReadError::UnexpectedlyEmptyStorageKey)
+            .into_storage_result()?;
+        K::parse(last_key_seg.raw()).into_storage_result()
+    }
 }
 
 #[cfg(test)]
@@ -301,6 +371,43 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_lazy_set_range_iter() -> crate::Result<()> {
+        let mut storage = TestStorage::default();
+
+        let key = storage::Key::parse("test").unwrap();
+        let lazy_set = LazySet::<i64>::open(key);
+
+        assert!(lazy_set.iter_range(&storage, ..)?.next().is_none());
+        assert!(lazy_set.first(&storage)?.is_none());
+        assert!(lazy_set.last(&storage)?.is_none());
+
+        for key in [40_i64, -3, 0, 1_000_000, -500, 7] {
+            lazy_set.insert(&mut storage, key)?;
+        }
+        let range_keys = |range: std::ops::RangeInclusive<i64>| -> Vec<i64> {
+            lazy_set
+                .iter_range(&storage, range)
+                .unwrap()
+                .map(Result::unwrap)
+                .collect()
+        };
+        assert_eq!(range_keys(-500..=0), vec![-500, -3, 0]);
+        assert_eq!(range_keys(1..=40), vec![7, 40]);
+        assert_eq!(range_keys(41..=999_999), Vec::<i64>::new());
+
+        let rev_keys: Vec<i64> = lazy_set
+            .rev_iter_range(&storage, -3..)?
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(rev_keys, vec![1_000_000, 40, 7, 0, -3]);
+
+        assert_eq!(lazy_set.first(&storage)?, Some(-500));
+        assert_eq!(lazy_set.last(&storage)?, Some(1_000_000));
+
+        Ok(())
+    }
+
     #[test]
     fn test_lazy_set_with_addr_key() -> crate::Result<()> {
         let mut storage = TestStorage::default();
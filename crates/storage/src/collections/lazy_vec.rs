@@ -2,6 +2,7 @@
 
 use std::fmt::Debug;
 use std::marker::PhantomData;
+use std::ops::{Bound, RangeBounds};
 
 use namada_core::arith::checked;
 use namada_core::borsh::{BorshDeserialize, BorshSerialize};
@@ -9,7 +10,7 @@ use namada_core::storage::{self, DbKeySeg, KeySeg};
 use thiserror::Error;
 
 use super::super::Result;
-use super::LazyCollection;
+use super::{LazyCollection, ReadError};
 use crate::{ResultExt, StorageRead, StorageWrite};
 
 /// Subkey pointing to the length of the LazyVec
@@ -281,6 +282,58 @@ where
             Ok(val)
         }))
     }
+
+    /// An iterator visiting the elements at the indices within the given
+    /// `range` that are smaller than the length of the vector. The elements
+    /// are read one by one by their index, so the iteration cost only depends
+    /// on the size of the range.
+    pub fn iter_range<'iter>(
+        &'iter self,
+        storage: &'iter impl StorageRead,
+        range: impl RangeBounds<Index>,
+    ) -> Result<impl DoubleEndedIterator<Item = Result<T>> + 'iter> {
+        let indices = self.range_indices(storage, range)?;
+        Ok(indices.map(|index| {
+            let data_key = self.get_data_key(index);
+            storage
+                .read(&data_key)?
+                .ok_or(ReadError::MissingElement(data_key))
+                .into_storage_result()
+        }))
+    }
+
+    /// An iterator visiting the elements at the indices within the given
+    /// `range` in reverse order.
+    pub fn rev_iter_range<'iter>(
+        &'iter self,
+        storage: &'iter impl StorageRead,
+        range: impl RangeBounds<Index>,
+    ) -> Result<impl Iterator<Item = Result<T>> + 'iter> {
+        Ok(self.iter_range(storage, range)?.rev())
+    }
+
+    /// Clamp the given `range` of indices to the length of the vector
+    fn range_indices<S>(
+        &self,
+        storage: &S,
+        range: impl RangeBounds<Index>,
+    ) -> Result<std::ops::Range<Index>>
+    where
+        S: StorageRead,
+    {
+        let len = self.len(storage)?;
+        let start = match range.start_bound() {
+            Bound::Included(start) => *start,
+            Bound::Excluded(start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(end) => end.saturating_add(1),
+            Bound::Excluded(end) => *end,
+            Bound::Unbounded => len,
+        };
+        Ok(start.min(len)..end.min(len))
+    }
 }
 
 #[cfg(test)]
@@ -339,6 +392,40 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_lazy_vec_range_iter() -> crate::Result<()> {
+        let mut storage = TestStorage::default();
+
+        let key = storage::Key::parse("test").unwrap();
+        let lazy_vec = LazyVec::<u32>::open(key);
+
+        assert!(lazy_vec.iter_range(&storage, 0..10)?.next().is_none());
+
+        for val in 0..5_u32 {
+            lazy_vec.push(&mut storage, val * 10)?;
+        }
+        let range_vals = |range: std::ops::Range<Index>| -> Vec<u32> {
+            lazy_vec
+                .iter_range(&storage, range)
+                .unwrap()
+                .map(Result::unwrap)
+                .collect()
+        };
+        assert_eq!(range_vals(1..3), vec![10, 20]);
+        // The range is clamped to the length of the vec
+        assert_eq!(range_vals(3..10), vec![30, 40]);
+        assert_eq!(range_vals(5..10), Vec::<u32>::new());
+
+        let rev_vals: Vec<u32> = lazy_vec
+            .rev_iter_range(&storage, ..=2)?
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(rev_vals, vec![20, 10, 0]);
+        assert_eq!(lazy_vec.iter_range(&storage, ..)?.count(), 5);
+
+        Ok(())
+    }
+
     #[test]
     fn test_lazy_vec_with_addr() -> crate::Result<()> {
         let mut storage = TestStorage::default();
@@ -10,24 +10,35 @@
 //! having to check any of the unchanged elements.
 
 use std::fmt::Debug;
+use std::ops::{Bound, RangeBounds};
 
+use itertools::Either;
 use namada_core::borsh::BorshDeserialize;
+use namada_core::storage::{DbKeySeg, KeySeg, KEY_SEGMENT_SEPARATOR};
 use thiserror::Error;
 
+pub mod lazy_deque;
 pub mod lazy_map;
+pub mod lazy_priority_queue;
 pub mod lazy_set;
 pub mod lazy_vec;
 
+pub use lazy_deque::LazyDeque;
 pub use lazy_map::LazyMap;
+pub use lazy_priority_queue::LazyPriorityQueue;
 pub use lazy_set::LazySet;
 pub use lazy_vec::LazyVec;
 use namada_core::storage;
 
+use crate::StorageRead;
+
 #[allow(missing_docs)]
 #[derive(Error, Debug)]
 pub enum ReadError {
     #[error("A storage key was unexpectedly empty")]
     UnexpectedlyEmptyStorageKey,
+    #[error("An element of a lazy collection is missing at {0}")]
+    MissingElement(storage::Key),
 }
 
 /// Simple lazy collection with borsh deserializable elements
@@ -74,3 +85,169 @@ pub trait LazyCollection {
     /// vec, only the element data sub-keys would return `true`.
     fn is_data_sub_key(&self, key: &storage::Key) -> bool;
 }
+
+/// A range of keys of a lazy collection. The bounds are held in the encoding
+/// of the keys' storage key segments, which is the order in which the
+/// collection's elements are iterated.
+#[derive(Clone, Debug)]
+struct KeyRange {
+    start: Bound<String>,
+    end: Bound<String>,
+}
+
+impl KeyRange {
+    /// Encode the bounds of the given range of keys
+    fn new<K>(range: impl RangeBounds<K>) -> Self
+    where
+        K: KeySeg,
+    {
+        Self {
+            start: range.start_bound().map(|key| key.to_db_key().raw()),
+            end: range.end_bound().map(|key| key.to_db_key().raw()),
+        }
+    }
+
+    /// The raw storage key from which a forward iteration over the range of
+    /// the keys under the `prefix` starts
+    fn seek_start(&self, prefix: &storage::Key) -> String {
+        match &self.start {
+            // The sub-keys of an excluded start are skipped by the iterator
+            Bound::Included(start) | Bound::Excluded(start) => {
+                format!("{prefix}{KEY_SEGMENT_SEPARATOR}{start}")
+            }
+            Bound::Unbounded => prefix.to_string(),
+        }
+    }
+
+    /// The raw storage key from which a backward iteration over the range of
+    /// the keys under the `prefix` starts
+    fn seek_end(&self, prefix: &storage::Key) -> String {
+        match &self.end {
+            Bound::Included(end) => {
+                past_sub_keys(format!("{prefix}{KEY_SEGMENT_SEPARATOR}{end}"))
+            }
+            Bound::Excluded(end) => {
+                format!("{prefix}{KEY_SEGMENT_SEPARATOR}{end}")
+            }
+            Bound::Unbounded => past_sub_keys(prefix.to_string()),
+        }
+    }
+
+    /// Check if the encoded key segment is below the start of the range
+    fn is_before_start(&self, seg: &str) -> bool {
+        match &self.start {
+            Bound::Included(start) => seg < start.as_str(),
+            Bound::Excluded(start) => seg <= start.as_str(),
+            Bound::Unbounded => false,
+        }
+    }
+
+    /// Check if the encoded key segment is above the end of the range
+    fn is_past_end(&self, seg: &str) -> bool {
+        match &self.end {
+            Bound::Included(end) => seg > end.as_str(),
+            Bound::Excluded(end) => seg >= end.as_str(),
+            Bound::Unbounded => false,
+        }
+    }
+}
+
+/// Get a raw storage key that is greater than the given raw key and all of its
+/// sub-keys, by appending the character that follows the key segment separator
+fn past_sub_keys(mut key: String) -> String {
+    let separator = KEY_SEGMENT_SEPARATOR as u8;
+    key.push(char::from(
+        separator.checked_add(1).expect("Cannot overflow"),
+    ));
+    key
+}
+
+/// Get the encoded key segment that follows the `prefix` of a lazy collection
+fn range_seg(key: &storage::Key, prefix: &storage::Key) -> String {
+    key.segments
+        .get(prefix.segments.len())
+        .map(DbKeySeg::raw)
+        .unwrap_or_default()
+}
+
+/// Iterate the raw items under the `prefix` of a lazy collection whose key
+/// segment that follows the `prefix` is within the `range`, ordered by the
+/// storage keys.
+///
+/// The iteration starts from the start of the range if the storage can seek
+/// into a prefix, or else the items before the start of the range are still
+/// iterated, but not decoded. The iteration stops at the first item past the
+/// end of the range.
+fn iter_range_bytes<'a>(
+    storage: &'a impl StorageRead,
+    prefix: &storage::Key,
+    range: KeyRange,
+) -> crate::Result<
+    impl Iterator<Item = crate::Result<(storage::Key, Vec<u8>)>> + 'a,
+> {
+    let iter = match storage.iter_prefix_from(
+        prefix,
+        &range.seek_start(prefix),
+        false,
+    )? {
+        Some(iter) => iter,
+        None => storage.iter_prefix(prefix)?,
+    };
+    let start_prefix = prefix.clone();
+    let end_prefix = prefix.clone();
+    let end_range = range.clone();
+    Ok(crate::iter_bytes(storage, iter)
+        .skip_while(move |res| match res {
+            Ok((key, _)) => {
+                range.is_before_start(&range_seg(key, &start_prefix))
+            }
+            // Propagate errors into the iterator's items
+            Err(_) => false,
+        })
+        .take_while(move |res| match res {
+            Ok((key, _)) => {
+                !end_range.is_past_end(&range_seg(key, &end_prefix))
+            }
+            Err(_) => true,
+        }))
+}
+
+/// Iterate the raw items under the `prefix` of a lazy collection whose key
+/// segment that follows the `prefix` is within the `range`, in the reverse
+/// order of the storage keys.
+///
+/// The iteration starts from the end of the range and stops at the first item
+/// before its start. If the storage cannot seek into a prefix, the items of
+/// the range are read before this function returns.
+fn rev_iter_range_bytes<'a>(
+    storage: &'a impl StorageRead,
+    prefix: &storage::Key,
+    range: KeyRange,
+) -> crate::Result<
+    impl Iterator<Item = crate::Result<(storage::Key, Vec<u8>)>> + 'a,
+> {
+    let Some(iter) =
+        storage.iter_prefix_from(prefix, &range.seek_end(prefix), true)?
+    else {
+        let items = iter_range_bytes(storage, prefix, range)?
+            .collect::<crate::Result<Vec<_>>>()?;
+        return Ok(Either::Left(items.into_iter().rev().map(Ok)));
+    };
+    let start_prefix = prefix.clone();
+    let end_prefix = prefix.clone();
+    let start_range = range.clone();
+    Ok(Either::Right(
+        crate::iter_bytes(storage, iter)
+            .skip_while(move |res| match res {
+                Ok((key, _)) => range.is_past_end(&range_seg(key, &end_prefix)),
+                // Propagate errors into the iterator's items
+                Err(_) => false,
+            })
+            .take_while(move |res| match res {
+                Ok((key, _)) => {
+                    !start_range.is_before_start(&range_seg(key, &start_prefix))
+                }
+                Err(_) => true,
+            }),
+    ))
+}
@@ -2,7 +2,7 @@ use std::fmt::Debug;
 use std::num::TryFromIntError;
 
 use itertools::Either;
-use namada_core::address::EstablishedAddressGen;
+use namada_core::address::{Address, EstablishedAddressGen};
 use namada_core::chain::{BlockHeader, BlockHeight, Epoch, Epochs};
 use namada_core::hash::{Error as HashError, Hash};
 use namada_core::storage::{BlockResults, DbColFam, EthEventsQueue, Key};
@@ -15,7 +15,7 @@ use namada_merkle_tree::{
 };
 use regex::Regex;
 use serde::de::DeserializeOwned;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use crate::conversion_state::ConversionState;
@@ -51,6 +51,28 @@ pub enum Error {
 /// A result of a function that may fail
 pub type Result<T> = std::result::Result<T, Error>;
 
+/// The retention mode of the historical state (diffs and Merkle tree stores)
+/// kept in the DB.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize,
+)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum StorageMode {
+    /// Keep the diffs of all blocks. The Merkle tree stores are only kept
+    /// within the `storage_read_past_height_limit`, if any.
+    #[default]
+    Standard,
+    /// Keep the diffs and the Merkle tree stores of all blocks.
+    Archive,
+    /// Only keep the diffs and the Merkle tree stores of the last
+    /// `blocks_to_keep` blocks. Older data is deleted from the DB and
+    /// reclaimed by background compactions.
+    Pruned {
+        /// The number of past blocks whose state can be restored
+        blocks_to_keep: u64,
+    },
+}
+
 /// The block's state as stored in the database.
 pub struct BlockStateRead {
     /// Height of the block
@@ -117,6 +139,17 @@ pub struct BlockStateWrite<'a> {
     pub commit_only_data: &'a CommitOnlyData,
 }
 
+/// A statistic reported by a DB backend, exported in the node's metrics.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DbStat {
+    /// The column family that the statistic pertains to
+    pub column_family: &'static str,
+    /// The name of the statistic
+    pub name: &'static str,
+    /// The value of the statistic
+    pub value: u64,
+}
+
 /// A database backend.
 pub trait DB: Debug {
     /// A DB's cache
@@ -289,6 +322,50 @@ pub trait DB: Debug {
         height: BlockHeight,
     ) -> Result<()>;
 
+    /// Prune the persisted diffs written at the heights in the range
+    /// `[from, to)`
+    fn prune_diffs(
+        &mut self,
+        batch: &mut Self::WriteBatch,
+        from: BlockHeight,
+        to: BlockHeight,
+    ) -> Result<()>;
+
+    /// Read the last epoch whose diffs and Merkle tree stores were pruned in
+    /// the pruned storage mode, if any
+    fn read_last_pruned_epoch(&self) -> Result<Option<Epoch>>;
+
+    /// Batch write the last epoch whose diffs and Merkle tree stores were
+    /// pruned in the pruned storage mode
+    fn batch_write_last_pruned_epoch(
+        &self,
+        batch: &mut Self::WriteBatch,
+        epoch: Epoch,
+    ) -> Result<()>;
+
+    /// Read the number of tx history entries of an address. The history is
+    /// a node-local index that is not part of the state.
+    fn read_tx_history_len(&self, address: &Address) -> Result<u64>;
+
+    /// Read the encoded tx history entry of an address with the given
+    /// sequence number, starting from 0.
+    fn read_tx_history_entry(
+        &self,
+        address: &Address,
+        seq: u64,
+    ) -> Result<Option<Vec<u8>>>;
+
+    /// Batch append the encoded tx history entries of an address applied at
+    /// the given height, such that they can be removed on rollback. The
+    /// entries of an address must only be appended once per batch.
+    fn batch_append_tx_history(
+        &self,
+        batch: &mut Self::WriteBatch,
+        address: &Address,
+        height: BlockHeight,
+        entries: &[Vec<u8>],
+    ) -> Result<()>;
+
     /// Overwrite a new value in storage, taking into
     /// account values stored at a previous height
     fn overwrite_entry(
@@ -307,6 +384,11 @@ pub trait DB: Debug {
     ) -> Result<Self::WriteBatch> {
         unimplemented!()
     }
+
+    /// Read the statistics of the DB backend, if it reports any.
+    fn stats(&self) -> Vec<DbStat> {
+        vec![]
+    }
 }
 
 /// A database prefix iterator.
@@ -324,6 +406,21 @@ pub trait DBIter<'iter> {
     /// ordered by the storage keys.
     fn iter_prefix(&'iter self, prefix: Option<&Key>) -> Self::PrefixIter;
 
+    /// WARNING: This only works for values that have been committed to DB.
+    /// To be able to see values written or deleted, but not yet committed,
+    /// use the `StorageWithWriteLog`.
+    ///
+    /// Read account subspace key value pairs with the given prefix from the DB,
+    /// starting from the first key that is not smaller than the raw key `from`
+    /// in the storage keys order, or from the last key that is not greater
+    /// than `from` in the reverse order if `reverse` is set.
+    fn iter_prefix_from(
+        &'iter self,
+        prefix: Option<&Key>,
+        from: &str,
+        reverse: bool,
+    ) -> Self::PrefixIter;
+
     /// WARNING: This only works for values that have been committed to DB.
     /// To be able to see values written or deleted, but not yet committed,
     /// use the `StorageWithWriteLog`.
@@ -79,6 +79,23 @@ pub trait StorageRead {
         prefix: &Key,
     ) -> Result<Self::PrefixIter<'iter>>;
 
+    /// Storage prefix iterator that starts from the first key that is not
+    /// smaller than the raw key `from`, or from the last key that is not
+    /// greater than `from` going backward if `reverse` is set. The iterator is
+    /// advanced with [`StorageRead::iter_next`].
+    ///
+    /// Returns `None` if the storage cannot seek into a prefix, in which case
+    /// the whole prefix has to be iterated with [`StorageRead::iter_prefix`].
+    fn iter_prefix_from<'iter>(
+        &'iter self,
+        prefix: &Key,
+        from: &str,
+        reverse: bool,
+    ) -> Result<Option<Self::PrefixIter<'iter>>> {
+        let _ = (prefix, from, reverse);
+        Ok(None)
+    }
+
     /// Storage prefix iterator. It will try to read from the storage.
     fn iter_next<'iter>(
         &'iter self,
@@ -181,7 +198,18 @@ pub fn iter_prefix_bytes<'a>(
     prefix: &Key,
 ) -> Result<impl Iterator<Item = Result<(Key, Vec<u8>)>> + 'a> {
     let iter = storage.iter_prefix(prefix)?;
-    let iter = itertools::unfold(iter, |iter| {
+    Ok(iter_bytes(storage, iter))
+}
+
+/// Iterate the items of the given storage prefix iterator.
+pub(crate) fn iter_bytes<'a, S>(
+    storage: &'a S,
+    iter: S::PrefixIter<'a>,
+) -> impl Iterator<Item = Result<(Key, Vec<u8>)>> + 'a
+where
+    S: StorageRead,
+{
+    itertools::unfold(iter, |iter| {
         match storage.iter_next(iter) {
             Ok(Some((key, val))) => {
                 let key = match Key::parse(key).into_storage_result() {
@@ -199,8 +227,7 @@ pub fn iter_prefix_bytes<'a>(
                 Some(Err(err))
             }
         }
-    });
-    Ok(iter)
+    })
 }
 
 /// Iterate Borsh encoded items matching the given prefix, ordered by the
@@ -357,6 +384,11 @@ pub mod testing {
         ) {
             self.mock_block_headers.insert(height, header);
         }
+
+        /// Set the current block epoch in [`TestStorage`].
+        pub fn set_epoch(&mut self, epoch: Epoch) {
+            self.epoch = epoch;
+        }
     }
 
     impl StorageRead for TestStorage {
@@ -380,6 +412,19 @@ pub mod testing {
             })
         }
 
+        fn iter_prefix_from<'iter>(
+            &'iter self,
+            prefix: &Key,
+            from: &str,
+            reverse: bool,
+        ) -> Result<Option<Self::PrefixIter<'iter>>> {
+            let storage_iter =
+                self.db.iter_prefix_from(Some(prefix), from, reverse);
+            Ok(Some(PrefixIter {
+                db_iter: storage_iter,
+            }))
+        }
+
         fn iter_next<'iter>(
             &'iter self,
             iter: &mut Self::PrefixIter<'iter>,
@@ -4,9 +4,11 @@
 
 use std::cell::RefCell;
 use std::collections::{btree_map, BTreeMap};
+use std::iter::Rev;
 use std::path::Path;
 
 use itertools::Either;
+use namada_core::address::Address;
 use namada_core::borsh::{BorshDeserialize, BorshSerialize};
 use namada_core::chain::{BlockHeader, BlockHeight, Epoch};
 use namada_core::hash::Hash;
@@ -35,7 +37,11 @@ const COMMIT_ONLY_DATA_KEY: &str = "commit_only_data_commitment";
 const CONVERSION_STATE_KEY: &str = "conversion_state";
 const ETHEREUM_HEIGHT_KEY: &str = "ethereum_height";
 const ETH_EVENTS_QUEUE_KEY: &str = "eth_events_queue";
+const LAST_PRUNED_EPOCH_KEY: &str = "last_pruned_epoch";
 const RESULTS_KEY_PREFIX: &str = "results";
+const TX_HISTORY_KEY_PREFIX: &str = "tx_history";
+const TX_HISTORY_HEIGHT_KEY_PREFIX: &str = "tx_history_height";
+const TX_HISTORY_LEN_KEY_SEGMENT: &str = "len";
 
 const MERKLE_TREE_ROOT_KEY_SEGMENT: &str = "root";
 const MERKLE_TREE_STORE_KEY_SEGMENT: &str = "store";
@@ -635,6 +641,89 @@ impl DB for MockDB {
         Ok(())
     }
 
+    fn prune_diffs(
+        &mut self,
+        _batch: &mut Self::WriteBatch,
+        from: BlockHeight,
+        to: BlockHeight,
+    ) -> Result<()> {
+        // The raw heights have a fixed length, so they can be compared as
+        // strings
+        let (from, to) = (from.raw(), to.raw());
+        self.0.borrow_mut().retain(|key, _| {
+            let mut segments = key.splitn(3, '/');
+            match (segments.next(), segments.next()) {
+                (Some(height), Some(OLD_DIFF_PREFIX | NEW_DIFF_PREFIX)) => {
+                    height < from.as_str() || height >= to.as_str()
+                }
+                _ => true,
+            }
+        });
+        Ok(())
+    }
+
+    fn read_last_pruned_epoch(&self) -> Result<Option<Epoch>> {
+        self.read_value(LAST_PRUNED_EPOCH_KEY)
+    }
+
+    fn batch_write_last_pruned_epoch(
+        &self,
+        _batch: &mut Self::WriteBatch,
+        epoch: Epoch,
+    ) -> Result<()> {
+        self.write_value(LAST_PRUNED_EPOCH_KEY, &epoch);
+        Ok(())
+    }
+
+    fn read_tx_history_len(&self, address: &Address) -> Result<u64> {
+        let key = format!(
+            "{TX_HISTORY_KEY_PREFIX}/{address}/{TX_HISTORY_LEN_KEY_SEGMENT}"
+        );
+        Ok(self.read_value(key)?.unwrap_or_default())
+    }
+
+    fn read_tx_history_entry(
+        &self,
+        address: &Address,
+        seq: u64,
+    ) -> Result<Option<Vec<u8>>> {
+        let key = format!("{TX_HISTORY_KEY_PREFIX}/{address}/{seq}");
+        Ok(self.0.borrow().get(&key).cloned())
+    }
+
+    fn batch_append_tx_history(
+        &self,
+        _batch: &mut Self::WriteBatch,
+        address: &Address,
+        height: BlockHeight,
+        entries: &[Vec<u8>],
+    ) -> Result<()> {
+        let len = self.read_tx_history_len(address)?;
+        self.write_value(
+            format!(
+                "{TX_HISTORY_HEIGHT_KEY_PREFIX}/{}/{address}",
+                height.raw()
+            ),
+            &len,
+        );
+        let mut seq = len;
+        for entry in entries {
+            self.0.borrow_mut().insert(
+                format!("{TX_HISTORY_KEY_PREFIX}/{address}/{seq}"),
+                entry.clone(),
+            );
+            seq += 1;
+        }
+        self.write_value(
+            format!(
+                "{TX_HISTORY_KEY_PREFIX}/{address}/\
+                 {TX_HISTORY_LEN_KEY_SEGMENT}"
+            ),
+            &seq,
+        );
+        Ok(())
+    }
+
     fn overwrite_entry(
         &self,
         _batch: &mut Self::WriteBatch,
@@ -666,7 +755,34 @@ impl<'iter> DBIter<'iter> for MockDB {
                 None => "".to_string(),
             }
         );
-        let iter = self.0.borrow().clone().into_iter();
+        let iter = Either::Left(self.0.borrow().clone().into_iter());
+        MockPrefixIterator::new(MockIterator { prefix, iter }, stripped_prefix)
+    }
+
+    fn iter_prefix_from(
+        &'iter self,
+        prefix: Option<&Key>,
+        from: &str,
+        reverse: bool,
+    ) -> MockPrefixIterator {
+        let stripped_prefix = "subspace/".to_owned();
+        let prefix = match prefix {
+            Some(prefix) if !prefix.is_empty() => {
+                format!("{stripped_prefix}{prefix}/")
+            }
+            _ => stripped_prefix.clone(),
+        };
+        let from = format!("{stripped_prefix}{from}");
+        let mut below = self.0.borrow().clone();
+        let mut above = below.split_off(&from);
+        let iter = if reverse {
+            if let Some((key, val)) = above.remove_entry(&from) {
+                below.insert(key, val);
+            }
+            Either::Right(below.into_iter().rev())
+        } else {
+            Either::Left(above.into_iter())
+        };
         MockPrefixIterator::new(MockIterator { prefix, iter }, stripped_prefix)
     }
 
@@ -687,7 +803,7 @@ impl<'iter> DBIter<'iter> for MockDB {
     fn iter_results(&'iter self) -> MockPrefixIterator {
         let stripped_prefix = "results/".to_owned();
         let prefix = "results".to_owned();
-        let iter = self.0.borrow().clone().into_iter();
+        let iter = Either::Left(self.0.borrow().clone().into_iter());
         MockPrefixIterator::new(MockIterator { prefix, iter }, stripped_prefix)
     }
 
@@ -708,7 +824,7 @@ impl<'iter> DBIter<'iter> for MockDB {
                 }
             })
             .unwrap_or("".to_string());
-        let iter = self.0.borrow().clone().into_iter();
+        let iter = Either::Left(self.0.borrow().clone().into_iter());
         MockPrefixIterator::new(MockIterator { prefix, iter }, stripped_prefix)
     }
 
@@ -729,7 +845,7 @@ impl<'iter> DBIter<'iter> for MockDB {
                 }
             })
             .unwrap_or("".to_string());
-        let iter = self.0.borrow().clone().into_iter();
+        let iter = Either::Left(self.0.borrow().clone().into_iter());
         MockPrefixIterator::new(MockIterator { prefix, iter }, stripped_prefix)
     }
 
@@ -739,7 +855,7 @@ impl<'iter> DBIter<'iter> for MockDB {
             replay_protection::current_prefix()
         );
         let prefix = stripped_prefix.clone();
-        let iter = self.0.borrow().clone().into_iter();
+        let iter = Either::Left(self.0.borrow().clone().into_iter());
         MockPrefixIterator::new(MockIterator { prefix, iter }, stripped_prefix)
     }
 }
@@ -748,8 +864,11 @@ impl<'iter> DBIter<'iter> for MockDB {
 #[derive(Debug)]
 pub struct MockIterator {
     prefix: String,
-    /// The concrete iterator
-    pub iter: btree_map::IntoIter<String, Vec<u8>>,
+    /// The concrete iterator, which goes backward for reverse iterators
+    pub iter: Either<
+        btree_map::IntoIter<String, Vec<u8>>,
+        Rev<btree_map::IntoIter<String, Vec<u8>>>,
+    >,
 }
 
 /// A prefix iterator for the [`MockDB`].
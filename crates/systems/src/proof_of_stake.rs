@@ -2,6 +2,7 @@
 
 use namada_core::address::Address;
 use namada_core::chain::Epoch;
+use namada_core::collections::HashSet;
 use namada_core::token;
 pub use namada_storage::Result;
 
@@ -20,6 +21,14 @@ pub trait Read<S> {
         epoch: Option<Epoch>,
     ) -> Result<bool>;
 
+    /// Find the validators to which the given address has bonds in the given
+    /// epoch
+    fn delegation_validators(
+        storage: &S,
+        delegator: &Address,
+        epoch: Epoch,
+    ) -> Result<HashSet<Address>>;
+
     /// Read PoS pipeline length parameter
     fn pipeline_len(storage: &S) -> Result<u64>;
 
@@ -58,6 +58,10 @@ pub trait Read<S> {
         token: &Address,
         owner: &Address,
     ) -> Result<token::Amount>;
+
+    /// Read the total minted supply of a given token.
+    fn read_total_supply(storage: &S, token: &Address)
+        -> Result<token::Amount>;
 }
 
 /// Abstract token storage write interface
@@ -84,6 +88,16 @@ pub trait Write<S>: Read<S> {
         amount: token::Amount,
     ) -> Result<()>;
 
+    /// Mint tokens to an account and record the `minter` of the token, which
+    /// has to authorize any change of the token's supply.
+    fn mint_tokens(
+        storage: &mut S,
+        minter: &Address,
+        token: &Address,
+        dest: &Address,
+        amount: token::Amount,
+    ) -> Result<()>;
+
     /// Credit tokens to an account, to be used only by protocol. In
     /// transactions, this would get rejected by the default `vp_token`.
     fn credit_tokens(
@@ -2136,25 +2136,38 @@ fn apply_snapshot() -> Result<()> {
     let db = namada_node::storage::open(node.db_path(), true, None)
         .expect("Could not open DB");
     let last_height = node.block_height();
+    let merkle_root =
+        Hash(node.shell.lock().unwrap().state.in_mem().merkle_root().0);
     let snapshot = db
         .checkpoint(base_dir.to_path_buf(), last_height)
         .expect("Test failed");
-    snapshot.package().expect("Test failed");
+    snapshot.package(merkle_root).expect("Test failed");
     DbSnapshot::cleanup(last_height, base_dir, 1).expect("Test failed");
 
     let (node2, _services) = setup::setup()?;
-    let (offer, resp) = {
-        let shell = node.shell.lock().unwrap();
-        let offer =
-            shell.list_snapshots().snapshots.pop().expect("Test failed");
+    let offer = node
+        .shell
+        .lock()
+        .unwrap()
+        .list_snapshots()
+        .snapshots
+        .pop()
+        .expect("Test failed");
+    // a snapshot that doesn't match the trusted app hash is rejected
+    let resp = {
         let mut shell = node2.shell.lock().unwrap();
-        (
-            offer.clone(),
-            shell.offer_snapshot(tm_request::OfferSnapshot {
-                snapshot: offer,
-                app_hash: Default::default(),
-            }),
-        )
+        shell.offer_snapshot(tm_request::OfferSnapshot {
+            snapshot: offer.clone(),
+            app_hash: Default::default(),
+        })
+    };
+    assert_eq!(tm_response::OfferSnapshot::Reject, resp);
+    let resp = {
+        let mut shell = node2.shell.lock().unwrap();
+        shell.offer_snapshot(tm_request::OfferSnapshot {
+            snapshot: offer.clone(),
+            app_hash: merkle_root.0.to_vec().try_into().expect("Test failed"),
+        })
     };
 
     assert_eq!(tm_response::OfferSnapshot::Accept, resp);
@@ -2238,6 +2251,7 @@ fn snapshot_unhappy_flows() -> Result<()> {
             next_chunk: 0,
             height: Default::default(),
             expected: vec![Default::default()],
+            merkle_root: Default::default(),
             strikes: 0,
             snapshot: tempfile::tempfile().unwrap(),
         });
@@ -15,7 +15,8 @@ mod test_bridge_pool_vp {
         UpgradeableContract,
     };
     use namada_sdk::eth_bridge_pool::{
-        GasFee, PendingTransfer, TransferToEthereum, TransferToEthereumKind,
+        BridgePoolChange, GasFee, PendingTransfer, TransferToEthereum,
+        TransferToEthereumKind,
     };
     use namada_sdk::ethereum_events::EthAddress;
     use namada_sdk::gas::VpGasMeter;
@@ -149,7 +150,11 @@ mod test_bridge_pool_vp {
         transfer: PendingTransfer,
         keypair: &common::SecretKey,
     ) -> BatchedTx {
-        let data = transfer.serialize_to_vec();
+        let data = BridgePoolChange::AddTransfer {
+            transfer,
+            expiry: None,
+        }
+        .serialize_to_vec();
         let wasm_code =
             wasm_loader::read_wasm_or_exit(wasm_dir(), ADD_TRANSFER_WASM);
 
@@ -0,0 +1,116 @@
+#[cfg(test)]
+mod tests {
+    use namada_sdk::address::{self, Address};
+    use namada_sdk::storage;
+    use namada_tx_prelude::collections::{LazyCollection, LazyDeque};
+    use namada_tx_prelude::storage::KeySeg;
+    use namada_tx_prelude::StorageWrite;
+    use namada_vp_prelude::collection_validation::lazy_deque::Action;
+    use namada_vp_prelude::collection_validation::LazyCollectionExt;
+    use test_log::test;
+
+    use crate::tx::tx_host_env;
+    use crate::vp::vp_host_env;
+
+    /// Type of value used in the deque
+    type TestVal = u64;
+
+    #[test]
+    fn lazy_deque_validation() {
+        tx_host_env::init();
+        let address = address::testing::established_address_1();
+        tx_host_env::with(|env| env.spawn_accounts([&address]));
+        let lazy_deque_prefix: storage::Key = address.to_db_key().into();
+        let lazy_deque = LazyDeque::<TestVal>::open(
+            lazy_deque_prefix.push(&"arbitrary".to_string()).unwrap(),
+        );
+
+        // Changes made via the deque's methods are accepted
+        lazy_deque.push_back(tx_host_env::ctx(), 2).unwrap();
+        lazy_deque.push_front(tx_host_env::ctx(), 1).unwrap();
+        lazy_deque.push_back(tx_host_env::ctx(), 3).unwrap();
+        let actions = validate_deque_changes(&address, &lazy_deque).unwrap();
+        // The elements pushed into an empty deque are all pushed to the back
+        assert_eq!(actions.len(), 3);
+        assert!(actions
+            .iter()
+            .all(|action| matches!(action, Action::PushBack(_))));
+        tx_host_env::commit_tx_and_block();
+
+        // Popping from both ends is accepted
+        assert_eq!(lazy_deque.pop_front(tx_host_env::ctx()).unwrap(), Some(1));
+        assert_eq!(lazy_deque.pop_back(tx_host_env::ctx()).unwrap(), Some(3));
+        let actions = validate_deque_changes(&address, &lazy_deque).unwrap();
+        assert!(matches!(
+            &actions[..],
+            [Action::PopFront(1), Action::PopBack(3)]
+                | [Action::PopBack(3), Action::PopFront(1)]
+        ));
+
+        // An element written outside of the bounds is rejected
+        let (front, back) =
+            lazy_deque.bounds(tx_host_env::ctx()).unwrap().unwrap();
+        tx_host_env::ctx()
+            .write(&lazy_deque.get_data_key(back + 1), 4_u64)
+            .unwrap();
+        assert!(validate_deque_changes(&address, &lazy_deque).is_err());
+        tx_host_env::ctx()
+            .delete(&lazy_deque.get_data_key(back + 1))
+            .unwrap();
+
+        // So are bounds extended without writing the new elements
+        tx_host_env::ctx()
+            .write(&lazy_deque.get_front_key(), front - 1)
+            .unwrap();
+        assert!(validate_deque_changes(&address, &lazy_deque).is_err());
+        tx_host_env::ctx()
+            .write(&lazy_deque.get_front_key(), front)
+            .unwrap();
+        assert!(validate_deque_changes(&address, &lazy_deque).is_ok());
+
+        // The bounds of an empty deque must be deleted
+        tx_host_env::commit_tx_and_block();
+        tx_host_env::ctx()
+            .delete(&lazy_deque.get_data_key(front))
+            .unwrap();
+        tx_host_env::ctx()
+            .write(&lazy_deque.get_front_key(), back)
+            .unwrap();
+        assert!(validate_deque_changes(&address, &lazy_deque).is_err());
+        tx_host_env::ctx()
+            .delete(&lazy_deque.get_front_key())
+            .unwrap();
+        tx_host_env::ctx()
+            .delete(&lazy_deque.get_back_key())
+            .unwrap();
+        let actions = validate_deque_changes(&address, &lazy_deque).unwrap();
+        assert!(matches!(&actions[..], [Action::PopFront(2)]));
+    }
+
+    /// Run the lazy deque's validation helpers on the storage changes of the
+    /// current transaction
+    fn validate_deque_changes(
+        address: &Address,
+        lazy_deque: &LazyDeque<TestVal>,
+    ) -> namada_tx_prelude::Result<Vec<Action<TestVal>>> {
+        let tx_env = tx_host_env::take();
+        vp_host_env::init_from_tx(address.clone(), tx_env, |_| {});
+        let changed_keys =
+            vp_host_env::with(|env| env.all_touched_storage_keys());
+
+        let mut validation_builder = None;
+        for key in &changed_keys {
+            let is_sub_key = lazy_deque
+                .accumulate(vp_host_env::ctx(), &mut validation_builder, key)
+                .unwrap();
+            assert!(is_sub_key, "Unexpected changed key {key}");
+        }
+        let result = LazyDeque::<TestVal>::validate(
+            validation_builder.expect("Some keys must have been changed"),
+        );
+
+        // Put the tx_env back
+        tx_host_env::set_from_vp_env(vp_host_env::take());
+        result
+    }
+}
@@ -7,6 +7,7 @@ mod tests {
     use namada_sdk::storage;
     use namada_tx_prelude::collections::{LazyCollection, LazyMap};
     use namada_tx_prelude::storage::KeySeg;
+    use namada_tx_prelude::StorageWrite;
     use namada_vp_prelude::collection_validation::{self, LazyCollectionExt};
     use proptest::prelude::*;
     use proptest::test_runner::Config;
@@ -604,4 +605,117 @@ mod tests {
         }
         collapsed
     }
+
+    #[test]
+    fn lazy_map_len_validation() {
+        tx_host_env::init();
+        let address = address::testing::established_address_1();
+        tx_host_env::with(|env| env.spawn_accounts([&address]));
+        let lazy_map_prefix: storage::Key = address.to_db_key().into();
+        let lazy_map = LazyMap::<TestKey, TestVal>::open_with_len(
+            lazy_map_prefix.push(&"arbitrary".to_string()).unwrap(),
+        );
+        let len_key = lazy_map.get_len_key().unwrap();
+        let val = TestVal { x: 1, y: true };
+
+        // Changes made via the map's methods are accepted
+        lazy_map.insert(tx_host_env::ctx(), 1, val.clone()).unwrap();
+        lazy_map.insert(tx_host_env::ctx(), 2, val.clone()).unwrap();
+        let actions = validate_map_changes(&address, &lazy_map).unwrap();
+        assert_eq!(actions.len(), 2);
+
+        // A length that doesn't match the inserted elements is rejected
+        tx_host_env::ctx().write(&len_key, 3_u64).unwrap();
+        assert!(validate_map_changes(&address, &lazy_map).is_err());
+
+        // So is an element inserted without updating the length
+        tx_host_env::ctx().write(&len_key, 2_u64).unwrap();
+        tx_host_env::ctx()
+            .write(&lazy_map.get_data_key(&3), val.clone())
+            .unwrap();
+        assert!(validate_map_changes(&address, &lazy_map).is_err());
+        tx_host_env::ctx().write(&len_key, 3_u64).unwrap();
+        assert!(validate_map_changes(&address, &lazy_map).is_ok());
+
+        // The length of an empty map must be deleted
+        tx_host_env::commit_tx_and_block();
+        for key in 1..=3 {
+            tx_host_env::ctx()
+                .delete(&lazy_map.get_data_key(&key))
+                .unwrap();
+        }
+        tx_host_env::ctx().write(&len_key, 0_u64).unwrap();
+        assert!(validate_map_changes(&address, &lazy_map).is_err());
+        tx_host_env::ctx().delete(&len_key).unwrap();
+        let actions = validate_map_changes(&address, &lazy_map).unwrap();
+        assert_eq!(actions.len(), 3);
+    }
+
+    /// Test the range iterators in a transaction, whose storage cannot seek
+    /// into a prefix
+    #[test]
+    fn lazy_map_range_iter_in_tx() {
+        tx_host_env::init();
+        let address = address::testing::established_address_1();
+        tx_host_env::with(|env| env.spawn_accounts([&address]));
+        let lazy_map_prefix: storage::Key = address.to_db_key().into();
+        let lazy_map = LazyMap::<TestKey, TestVal>::open(
+            lazy_map_prefix.push(&"arbitrary".to_string()).unwrap(),
+        );
+        let val = |x: TestKey| TestVal { x, y: true };
+
+        // Some of the elements are committed and the rest are in the write log
+        for key in [300, 2, 1000] {
+            lazy_map.insert(tx_host_env::ctx(), key, val(key)).unwrap();
+        }
+        tx_host_env::commit_tx_and_block();
+        for key in [5, 256, 7] {
+            lazy_map.insert(tx_host_env::ctx(), key, val(key)).unwrap();
+        }
+
+        let ctx = &*tx_host_env::ctx();
+        let keys: Vec<TestKey> = lazy_map
+            .iter_range(ctx, 5..300)
+            .unwrap()
+            .map(|res| res.unwrap().0)
+            .collect();
+        assert_eq!(keys, vec![5, 7, 256]);
+        let rev_keys: Vec<TestKey> = lazy_map
+            .rev_iter_range(ctx, 3..=1000)
+            .unwrap()
+            .map(|res| res.unwrap().0)
+            .collect();
+        assert_eq!(rev_keys, vec![1000, 300, 256, 7, 5]);
+        assert_eq!(lazy_map.first(ctx).unwrap(), Some((2, val(2))));
+        assert_eq!(lazy_map.last(ctx).unwrap(), Some((1000, val(1000))));
+    }
+
+    /// Run the lazy map's validation helpers on the storage changes of the
+    /// current transaction
+    fn validate_map_changes(
+        address: &Address,
+        lazy_map: &LazyMap<TestKey, TestVal>,
+    ) -> namada_tx_prelude::Result<
+        Vec<collection_validation::lazy_map::Action<TestKey, TestVal>>,
+    > {
+        let tx_env = tx_host_env::take();
+        vp_host_env::init_from_tx(address.clone(), tx_env, |_| {});
+        let changed_keys =
+            vp_host_env::with(|env| env.all_touched_storage_keys());
+
+        let mut validation_builder = None;
+        for key in &changed_keys {
+            let is_sub_key = lazy_map
+                .accumulate(vp_host_env::ctx(), &mut validation_builder, key)
+                .unwrap();
+            assert!(is_sub_key, "Unexpected changed key {key}");
+        }
+        let result = LazyMap::<TestKey, TestVal>::validate(
+            validation_builder.expect("Some keys must have been changed"),
+        );
+
+        // Put the tx_env back
+        tx_host_env::set_from_vp_env(vp_host_env::take());
+        result
+    }
 }
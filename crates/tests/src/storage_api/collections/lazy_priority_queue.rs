@@ -0,0 +1,113 @@
+#[cfg(test)]
+mod tests {
+    use namada_sdk::address::{self, Address};
+    use namada_sdk::storage;
+    use namada_tx_prelude::collections::{LazyCollection, LazyPriorityQueue};
+    use namada_tx_prelude::storage::KeySeg;
+    use namada_tx_prelude::StorageWrite;
+    use namada_vp_prelude::collection_validation::lazy_priority_queue::Action;
+    use namada_vp_prelude::collection_validation::LazyCollectionExt;
+    use test_log::test;
+
+    use crate::tx::tx_host_env;
+    use crate::vp::vp_host_env;
+
+    /// Type of priority key used in the queue
+    type TestKey = u64;
+    /// Type of value used in the queue
+    type TestVal = String;
+
+    #[test]
+    fn lazy_priority_queue_validation() {
+        tx_host_env::init();
+        let address = address::testing::established_address_1();
+        tx_host_env::with(|env| env.spawn_accounts([&address]));
+        let lazy_queue_prefix: storage::Key = address.to_db_key().into();
+        let lazy_queue = LazyPriorityQueue::<TestKey, TestVal>::open(
+            lazy_queue_prefix.push(&"arbitrary".to_string()).unwrap(),
+        );
+        let seq_key = lazy_queue.get_seq_key();
+
+        // Changes made via the queue's methods are accepted
+        lazy_queue
+            .push(tx_host_env::ctx(), 5, "b".to_string())
+            .unwrap();
+        lazy_queue
+            .push(tx_host_env::ctx(), 1, "a".to_string())
+            .unwrap();
+        let actions = validate_queue_changes(&address, &lazy_queue).unwrap();
+        assert_eq!(actions.len(), 2);
+        tx_host_env::commit_tx_and_block();
+
+        let popped = lazy_queue.pop(tx_host_env::ctx()).unwrap();
+        assert_eq!(popped, Some((1, "a".to_string())));
+        let actions = validate_queue_changes(&address, &lazy_queue).unwrap();
+        assert!(matches!(&actions[..], [Action::Pop(1, val)] if val == "a"));
+
+        // An element pushed without incrementing the sequence number is
+        // rejected
+        tx_host_env::ctx()
+            .write(&lazy_queue.get_data_key(&3, 2), "c".to_string())
+            .unwrap();
+        assert!(validate_queue_changes(&address, &lazy_queue).is_err());
+
+        // So is an element pushed with a sequence number that's been used
+        tx_host_env::ctx().write(&seq_key, 3_u64).unwrap();
+        tx_host_env::ctx()
+            .delete(&lazy_queue.get_data_key(&3, 2))
+            .unwrap();
+        tx_host_env::ctx()
+            .write(&lazy_queue.get_data_key(&3, 0), "c".to_string())
+            .unwrap();
+        assert!(validate_queue_changes(&address, &lazy_queue).is_err());
+        tx_host_env::ctx()
+            .delete(&lazy_queue.get_data_key(&3, 0))
+            .unwrap();
+        tx_host_env::ctx()
+            .write(&lazy_queue.get_data_key(&3, 2), "c".to_string())
+            .unwrap();
+        assert!(validate_queue_changes(&address, &lazy_queue).is_ok());
+
+        // Elements in the queue cannot be updated
+        tx_host_env::commit_tx_and_block();
+        tx_host_env::ctx()
+            .write(&lazy_queue.get_data_key(&5, 0), "d".to_string())
+            .unwrap();
+        assert!(validate_queue_changes(&address, &lazy_queue).is_err());
+
+        // Nor can the sequence number be decreased
+        tx_host_env::ctx()
+            .delete(&lazy_queue.get_data_key(&5, 0))
+            .unwrap();
+        assert!(validate_queue_changes(&address, &lazy_queue).is_ok());
+        tx_host_env::ctx().write(&seq_key, 2_u64).unwrap();
+        assert!(validate_queue_changes(&address, &lazy_queue).is_err());
+    }
+
+    /// Run the lazy priority queue's validation helpers on the storage
+    /// changes of the current transaction
+    fn validate_queue_changes(
+        address: &Address,
+        lazy_queue: &LazyPriorityQueue<TestKey, TestVal>,
+    ) -> namada_tx_prelude::Result<Vec<Action<TestKey, TestVal>>> {
+        let tx_env = tx_host_env::take();
+        vp_host_env::init_from_tx(address.clone(), tx_env, |_| {});
+        let changed_keys =
+            vp_host_env::with(|env| env.all_touched_storage_keys());
+
+        let mut validation_builder = None;
+        for key in &changed_keys {
+            let is_sub_key = lazy_queue
+                .accumulate(vp_host_env::ctx(), &mut validation_builder, key)
+                .unwrap();
+            assert!(is_sub_key, "Unexpected changed key {key}");
+        }
+        let result = LazyPriorityQueue::<TestKey, TestVal>::validate(
+            validation_builder.expect("Some keys must have been changed"),
+        );
+
+        // Put the tx_env back
+        tx_host_env::set_from_vp_env(vp_host_env::take());
+        result
+    }
+}
@@ -43,7 +43,9 @@ mod tests {
     use namada_tx_env::TxEnv;
     use namada_tx_prelude::address::InternalAddress;
     use namada_tx_prelude::chain::ChainId;
-    use namada_tx_prelude::{Address, BatchedTx, StorageRead, StorageWrite};
+    use namada_tx_prelude::{
+        account, Address, BatchedTx, StorageRead, StorageWrite,
+    };
     use namada_vp_prelude::account::AccountPublicKeysMap;
     use namada_vp_prelude::{sha256, VpEnv};
     use prost::Message;
@@ -233,6 +235,49 @@ mod tests {
         tx::ctx().init_account(code_hash, &None, &[]).unwrap();
     }
 
+    /// Test that initializing an account with invalid public key weights
+    /// fails.
+    #[test]
+    fn test_tx_init_account_with_invalid_weights() {
+        let code = TestWasms::VpAlwaysTrue.read_bytes();
+        let vp_code_hash = Hash::sha256(&code);
+        let public_keys = vec![
+            key::testing::keypair_1().ref_to(),
+            key::testing::keypair_2().ref_to(),
+        ];
+        for (threshold, weights, is_valid) in [
+            // one weight for each key
+            (2, vec![2], false),
+            (2, vec![2, 1, 1], false),
+            // non-zero weights
+            (2, vec![0, 2], false),
+            // threshold up to the total weight
+            (3, vec![], false),
+            (4, vec![2, 1], false),
+            (3, vec![2, 1], true),
+        ] {
+            // The environment must be initialized first
+            tx_host_env::init();
+            tx_host_env::with(|env| {
+                // store wasm code
+                let key = Key::wasm_code(&vp_code_hash);
+                env.state.write(&key, &code).unwrap();
+            });
+            let owner =
+                tx::ctx().init_account(vp_code_hash, &None, &[]).unwrap();
+            let data = account::InitAccount {
+                public_keys: public_keys.clone(),
+                vp_code_hash,
+                threshold,
+                weights,
+            };
+            assert_eq!(
+                account::init_account(tx::ctx(), &owner, data).is_ok(),
+                is_valid
+            );
+        }
+    }
+
     /// Test that a tx updating validity predicate that is not in the allowlist
     /// fails.
     #[test]
@@ -22,6 +22,7 @@ use std::collections::BTreeMap;
 
 use namada_core::address::Address;
 use namada_core::borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use namada_core::chain::Epoch;
 use namada_events::EmitEvents;
 use namada_macros::BorshDeserializer;
 #[cfg(feature = "migrations")]
@@ -318,6 +319,111 @@ impl TransparentTransfersRef<'_> {
     }
 }
 
+/// Arguments to approve a spender to transfer the tokens of an owner
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSchema,
+    Hash,
+    Eq,
+    PartialOrd,
+    Serialize,
+    Deserialize,
+)]
+pub struct Approve {
+    /// The approved token
+    pub token: Address,
+    /// The owner of the tokens
+    pub owner: Address,
+    /// The spender allowed to transfer the tokens of the owner
+    pub spender: Address,
+    /// The amount that may be spent. A zero amount revokes the allowance.
+    pub amount: DenominatedAmount,
+    /// The last epoch in which the allowance can be spent, if any
+    pub expiry: Option<Epoch>,
+}
+
+/// Arguments for a transfer of the tokens of an owner by a spender, from its
+/// allowance
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSchema,
+    Hash,
+    Eq,
+    PartialOrd,
+    Serialize,
+    Deserialize,
+)]
+pub struct TransferFrom {
+    /// The transferred token
+    pub token: Address,
+    /// The owner of the tokens
+    pub owner: Address,
+    /// The spender transferring the tokens of the owner
+    pub spender: Address,
+    /// The target of the transfer
+    pub target: Address,
+    /// The transferred amount
+    pub amount: DenominatedAmount,
+}
+
+/// Arguments for a transfer of tokens that are locked in the balance of the
+/// target until they unlock according to a vesting schedule
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSchema,
+    Hash,
+    Eq,
+    PartialOrd,
+    Serialize,
+    Deserialize,
+)]
+pub struct VestingTransfer {
+    /// The source of the transfer
+    pub source: Address,
+    /// The target of the transfer, whose received tokens are locked
+    pub target: Address,
+    /// The transferred token
+    pub token: Address,
+    /// The transferred and vested amount
+    pub amount: DenominatedAmount,
+    /// The epoch from which the amount starts vesting
+    pub start_epoch: Epoch,
+    /// The first epoch in which any of the amount is unlocked
+    pub cliff_epoch: Epoch,
+    /// The epoch from which the whole amount is unlocked
+    pub end_epoch: Epoch,
+}
+
+impl VestingTransfer {
+    /// The vesting schedule of the transferred amount
+    pub fn schedule(&self) -> vesting::VestingSchedule {
+        vesting::VestingSchedule {
+            amount: self.amount.amount(),
+            start_epoch: self.start_epoch,
+            cliff_epoch: self.cliff_epoch,
+            end_epoch: self.end_epoch,
+        }
+    }
+}
+
 #[cfg(all(any(test, feature = "testing"), feature = "masp"))]
 /// Testing helpers and strategies for tokens
 pub mod testing {
@@ -8,7 +8,9 @@ use namada_core::masp;
 use namada_events::EmitEvents;
 use namada_shielded_token::{utils, MaspTxId};
 use namada_storage::{Error, OptionExt, ResultExt};
-pub use namada_trans_token::tx::transfer;
+pub use namada_trans_token::tx::{
+    approve, transfer, transfer_from, vesting_transfer,
+};
 use namada_tx::action::{self, Action, MaspAction};
 use namada_tx::BatchedTx;
 use namada_tx_env::{Address, Result, TxEnv};
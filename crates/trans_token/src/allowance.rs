@@ -0,0 +1,331 @@
+//! Allowances given by token owners to third parties (spenders), which can
+//! then transfer the owner's tokens on their behalf, up to the approved
+//! amount and until an optional expiry epoch.
+
+use std::collections::BTreeSet;
+
+use namada_core::address::Address;
+use namada_core::borsh::{BorshDeserialize, BorshSerialize};
+use namada_core::chain::Epoch;
+use namada_core::token::Amount;
+use namada_vp_env::VpEnv;
+
+use crate::storage_key::{allowance_key, balance_key, is_any_allowance_key};
+use crate::{Error, Key, Result, StorageRead, StorageWrite};
+
+/// An allowance of a spender to transfer the tokens of an owner.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, BorshSerialize, BorshDeserialize,
+)]
+pub struct Allowance {
+    /// The amount that may still be spent
+    pub amount: Amount,
+    /// The last epoch in which the allowance can be spent, if any
+    pub expiry: Option<Epoch>,
+}
+
+impl Allowance {
+    /// Check if the allowance has expired in the given epoch. An allowance
+    /// can still be spent in its expiry epoch.
+    pub fn is_expired(&self, epoch: Epoch) -> bool {
+        self.expiry.is_some_and(|expiry| epoch > expiry)
+    }
+
+    /// If the `post` allowance results from spending some of the `pre`
+    /// allowance, return the spent amount. Spending leaves the expiry of an
+    /// allowance untouched, and an allowance that was spent in full is
+    /// removed from storage.
+    pub fn spent_amount(
+        pre: Option<&Self>,
+        post: Option<&Self>,
+    ) -> Option<Amount> {
+        let pre = pre?;
+        let post_amount = match post {
+            Some(post) if post.expiry != pre.expiry => return None,
+            Some(post) => post.amount,
+            None => Amount::zero(),
+        };
+        pre.amount
+            .checked_sub(post_amount)
+            .filter(|spent| !spent.is_zero())
+    }
+}
+
+/// Read the allowance of `spender` to transfer the `token`s of `owner`.
+pub fn read_allowance<S>(
+    storage: &S,
+    token: &Address,
+    owner: &Address,
+    spender: &Address,
+) -> Result<Option<Allowance>>
+where
+    S: StorageRead,
+{
+    storage.read(&allowance_key(token, owner, spender))
+}
+
+/// Write the allowance of `spender` to transfer the `token`s of `owner`. An
+/// allowance with a zero amount is removed from storage.
+pub fn write_allowance<S>(
+    storage: &mut S,
+    token: &Address,
+    owner: &Address,
+    spender: &Address,
+    allowance: Allowance,
+) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let key = allowance_key(token, owner, spender);
+    if allowance.amount.is_zero() {
+        storage.delete(&key)
+    } else {
+        storage.write(&key, allowance)
+    }
+}
+
+/// Spend `amount` from the allowance of `spender` to transfer the `token`s of
+/// `owner`.
+///
+/// Returns an `Err` if the allowance doesn't exist, has expired or is lower
+/// than `amount`.
+pub fn spend_allowance<S>(
+    storage: &mut S,
+    token: &Address,
+    owner: &Address,
+    spender: &Address,
+    amount: Amount,
+) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    if amount.is_zero() {
+        return Ok(());
+    }
+    let allowance = read_allowance(storage, token, owner, spender)?
+        .ok_or_else(|| {
+            Error::new_alloc(format!(
+                "{spender} has no allowance to spend the {token} tokens of \
+                 {owner}"
+            ))
+        })?;
+    let epoch = storage.get_block_epoch()?;
+    if allowance.is_expired(epoch) {
+        return Err(Error::new_alloc(format!(
+            "The allowance of {spender} to spend the {token} tokens of \
+             {owner} has expired"
+        )));
+    }
+    let amount = allowance.amount.checked_sub(amount).ok_or_else(|| {
+        Error::new_alloc(format!(
+            "The allowance of {spender} to spend the {token} tokens of \
+             {owner} is insufficient"
+        ))
+    })?;
+    write_allowance(
+        storage,
+        token,
+        owner,
+        spender,
+        Allowance {
+            amount,
+            ..allowance
+        },
+    )
+}
+
+/// Sum the unexpired allowances of `owner`'s `token`s that were spent in a
+/// tx by spenders that are verifiers of the tx.
+pub fn total_spent_allowance<'ctx, CTX>(
+    ctx: &CTX,
+    keys_changed: &BTreeSet<Key>,
+    token: &Address,
+    owner: &Address,
+    verifiers: &BTreeSet<Address>,
+) -> Result<Amount>
+where
+    CTX: VpEnv<'ctx>,
+{
+    let epoch = ctx.get_block_epoch()?;
+    let mut total = Amount::zero();
+    for key in keys_changed {
+        let Some([key_token, key_owner, spender]) = is_any_allowance_key(key)
+        else {
+            continue;
+        };
+        if key_token != token || key_owner != owner {
+            continue;
+        }
+        let pre: Option<Allowance> = ctx.read_pre(key)?;
+        let post: Option<Allowance> = ctx.read_post(key)?;
+        let Some(spent) = Allowance::spent_amount(pre.as_ref(), post.as_ref())
+        else {
+            continue;
+        };
+        let is_expired = pre.is_some_and(|pre| pre.is_expired(epoch));
+        if is_expired || !verifiers.contains(spender) {
+            continue;
+        }
+        total = total
+            .checked_add(spent)
+            .ok_or_else(|| Error::new_const("Overflowed in allowance check"))?;
+    }
+    Ok(total)
+}
+
+/// Check that the allowances of `owner`'s `token`s that were changed in a tx
+/// were all spent by spenders that are verifiers of the tx, and that the
+/// spent amounts add up exactly to the debit of the owner's balance. Only
+/// then are the changes of the allowances spends that don't have to be
+/// authorized by the owner.
+pub fn is_spent_by_owner_debit<'ctx, CTX>(
+    ctx: &CTX,
+    keys_changed: &BTreeSet<Key>,
+    token: &Address,
+    owner: &Address,
+    verifiers: &BTreeSet<Address>,
+) -> Result<bool>
+where
+    CTX: VpEnv<'ctx>,
+{
+    let epoch = ctx.get_block_epoch()?;
+    let mut total = Amount::zero();
+    for key in keys_changed {
+        let Some([key_token, key_owner, spender]) = is_any_allowance_key(key)
+        else {
+            continue;
+        };
+        if key_token != token || key_owner != owner {
+            continue;
+        }
+        let pre: Option<Allowance> = ctx.read_pre(key)?;
+        let post: Option<Allowance> = ctx.read_post(key)?;
+        let Some(spent) = Allowance::spent_amount(pre.as_ref(), post.as_ref())
+        else {
+            return Ok(false);
+        };
+        let is_expired = pre.is_some_and(|pre| pre.is_expired(epoch));
+        if is_expired || !verifiers.contains(spender) {
+            return Ok(false);
+        }
+        total = total
+            .checked_add(spent)
+            .ok_or_else(|| Error::new_const("Overflowed in allowance check"))?;
+    }
+
+    let balance_key = balance_key(token, owner);
+    let pre_balance: Amount = ctx.read_pre(&balance_key)?.unwrap_or_default();
+    let post_balance: Amount = ctx.read_post(&balance_key)?.unwrap_or_default();
+    let debit = pre_balance.checked_sub(post_balance);
+    Ok(!total.is_zero() && debit == Some(total))
+}
+
+#[cfg(test)]
+mod test_allowance {
+    use namada_core::address;
+    use namada_state::testing::TestStorage;
+
+    use super::*;
+
+    fn allowance(amount: u64, expiry: Option<u64>) -> Allowance {
+        Allowance {
+            amount: Amount::native_whole(amount),
+            expiry: expiry.map(Epoch),
+        }
+    }
+
+    #[test]
+    fn test_allowance_expiry() {
+        assert!(!allowance(1, None).is_expired(Epoch(u64::MAX)));
+        assert!(!allowance(1, Some(2)).is_expired(Epoch(1)));
+        assert!(!allowance(1, Some(2)).is_expired(Epoch(2)));
+        assert!(allowance(1, Some(2)).is_expired(Epoch(3)));
+    }
+
+    #[test]
+    fn test_spent_amount() {
+        let pre = allowance(10, Some(5));
+        let spent = |post: Option<Allowance>| {
+            Allowance::spent_amount(Some(&pre), post.as_ref())
+        };
+        assert_eq!(
+            spent(Some(allowance(4, Some(5)))),
+            Some(Amount::native_whole(6))
+        );
+        assert_eq!(spent(None), Some(Amount::native_whole(10)));
+        // a change of expiry is not a spend
+        assert_eq!(spent(Some(allowance(4, None))), None);
+        // neither is an increase or no change
+        assert_eq!(spent(Some(allowance(12, Some(5)))), None);
+        assert_eq!(spent(Some(pre)), None);
+        // nor a new allowance
+        assert_eq!(
+            Allowance::spent_amount(None, Some(&allowance(1, None))),
+            None
+        );
+    }
+
+    #[test]
+    fn test_spend_allowance() {
+        let mut storage = TestStorage::default();
+        let token = address::testing::nam();
+        let owner = address::testing::established_address_1();
+        let spender = address::testing::established_address_2();
+
+        // spending without an allowance fails
+        let res = spend_allowance(
+            &mut storage,
+            &token,
+            &owner,
+            &spender,
+            Amount::native_whole(1),
+        );
+        assert!(res.is_err());
+
+        write_allowance(
+            &mut storage,
+            &token,
+            &owner,
+            &spender,
+            allowance(10, None),
+        )
+        .unwrap();
+        spend_allowance(
+            &mut storage,
+            &token,
+            &owner,
+            &spender,
+            Amount::native_whole(4),
+        )
+        .unwrap();
+        let remaining =
+            read_allowance(&storage, &token, &owner, &spender).unwrap();
+        assert_eq!(remaining, Some(allowance(6, None)));
+
+        // overspending fails and leaves the allowance untouched
+        let res = spend_allowance(
+            &mut storage,
+            &token,
+            &owner,
+            &spender,
+            Amount::native_whole(7),
+        );
+        assert!(res.is_err());
+        let remaining =
+            read_allowance(&storage, &token, &owner, &spender).unwrap();
+        assert_eq!(remaining, Some(allowance(6, None)));
+
+        // spending the whole allowance removes it
+        spend_allowance(
+            &mut storage,
+            &token,
+            &owner,
+            &spender,
+            Amount::native_whole(6),
+        )
+        .unwrap();
+        let remaining =
+            read_allowance(&storage, &token, &owner, &spender).unwrap();
+        assert_eq!(remaining, None);
+    }
+}
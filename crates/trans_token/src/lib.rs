@@ -17,10 +17,12 @@
     clippy::print_stderr
 )]
 
+pub mod allowance;
 pub mod event;
 mod storage;
 pub mod storage_key;
 pub mod tx;
+pub mod vesting;
 pub mod vp;
 
 use std::collections::BTreeMap;
@@ -98,6 +100,13 @@ where
     ) -> Result<token::Amount> {
         storage::read_balance(storage, token, owner)
     }
+
+    fn read_total_supply(
+        storage: &S,
+        token: &Address,
+    ) -> Result<token::Amount> {
+        storage::read_total_supply(storage, token)
+    }
 }
 
 impl<S> Write<S> for Store<S>
@@ -123,6 +132,16 @@ where
         storage::burn_tokens(storage, token, source, amount)
     }
 
+    fn mint_tokens(
+        storage: &mut S,
+        minter: &Address,
+        token: &Address,
+        dest: &Address,
+        amount: Amount,
+    ) -> Result<()> {
+        storage::mint_tokens(storage, minter, token, dest, amount)
+    }
+
     fn credit_tokens(
         storage: &mut S,
         token: &Address,
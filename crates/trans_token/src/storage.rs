@@ -185,6 +185,10 @@ where
         Address::Internal(InternalAddress::IbcToken(_)) => {
             return Ok(Some(0u8.into()));
         }
+        Address::Internal(InternalAddress::StakeShare(_)) => {
+            // Liquid staking shares are denominated like the staking token
+            return Ok(Some(token::NATIVE_MAX_DECIMAL_PLACES.into()));
+        }
         token => (denom_key(token), false),
     };
     storage.read(&key).map(|opt_denom| {
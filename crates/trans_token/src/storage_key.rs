@@ -5,6 +5,10 @@ use namada_core::storage::{self, DbKeySeg, KeySeg};
 
 /// Key segment for a balance key
 pub const BALANCE_STORAGE_KEY: &str = "balance";
+/// Key segment for an allowance key
+pub const ALLOWANCE_STORAGE_KEY: &str = "allowance";
+/// Key segment for a vesting schedules key
+pub const VESTING_STORAGE_KEY: &str = "vesting";
 /// Key segment for a denomination key
 pub const DENOM_STORAGE_KEY: &str = "denomination";
 /// Key segment for multitoken minter
@@ -44,6 +48,44 @@ pub fn balance_prefix(token_addr: &Address) -> storage::Key {
     .expect("Cannot obtain a storage key")
 }
 
+/// Obtain a storage key for the allowance of `spender` to spend the tokens
+/// of `owner`.
+pub fn allowance_key(
+    token_addr: &Address,
+    owner: &Address,
+    spender: &Address,
+) -> storage::Key {
+    allowance_prefix(token_addr, owner)
+        .push(&spender.to_db_key())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Obtain a storage key prefix for all the allowances given by `owner`.
+pub fn allowance_prefix(token_addr: &Address, owner: &Address) -> storage::Key {
+    storage::Key::from(
+        Address::Internal(InternalAddress::Multitoken).to_db_key(),
+    )
+    .push(&token_addr.to_db_key())
+    .expect("Cannot obtain a storage key")
+    .push(&ALLOWANCE_STORAGE_KEY.to_owned())
+    .expect("Cannot obtain a storage key")
+    .push(&owner.to_db_key())
+    .expect("Cannot obtain a storage key")
+}
+
+/// Obtain a storage key for the vesting schedules of the tokens of `owner`.
+pub fn vesting_key(token_addr: &Address, owner: &Address) -> storage::Key {
+    storage::Key::from(
+        Address::Internal(InternalAddress::Multitoken).to_db_key(),
+    )
+    .push(&token_addr.to_db_key())
+    .expect("Cannot obtain a storage key")
+    .push(&VESTING_STORAGE_KEY.to_owned())
+    .expect("Cannot obtain a storage key")
+    .push(&owner.to_db_key())
+    .expect("Cannot obtain a storage key")
+}
+
 /// Obtain a storage key prefix for token parameters.
 pub fn parameter_prefix(token_addr: &Address) -> storage::Key {
     storage::Key::from(
@@ -140,6 +182,43 @@ pub fn is_any_token_balance_key(key: &storage::Key) -> Option<[&Address; 2]> {
     }
 }
 
+/// Check if the given storage key is an allowance key for an unspecified
+/// token. If it is, return the token, owner and spender addresses.
+pub fn is_any_allowance_key(key: &storage::Key) -> Option<[&Address; 3]> {
+    match &key.segments[..] {
+        [
+            DbKeySeg::AddressSeg(addr),
+            DbKeySeg::AddressSeg(token),
+            DbKeySeg::StringSeg(allowance),
+            DbKeySeg::AddressSeg(owner),
+            DbKeySeg::AddressSeg(spender),
+        ] if *addr == Address::Internal(InternalAddress::Multitoken)
+            && allowance == ALLOWANCE_STORAGE_KEY =>
+        {
+            Some([token, owner, spender])
+        }
+        _ => None,
+    }
+}
+
+/// Check if the given storage key is a vesting schedules key for an
+/// unspecified token. If it is, return the token and owner addresses.
+pub fn is_any_vesting_key(key: &storage::Key) -> Option<[&Address; 2]> {
+    match &key.segments[..] {
+        [
+            DbKeySeg::AddressSeg(addr),
+            DbKeySeg::AddressSeg(token),
+            DbKeySeg::StringSeg(vesting),
+            DbKeySeg::AddressSeg(owner),
+        ] if *addr == Address::Internal(InternalAddress::Multitoken)
+            && vesting == VESTING_STORAGE_KEY =>
+        {
+            Some([token, owner])
+        }
+        _ => None,
+    }
+}
+
 /// Obtain a storage key denomination of a token.
 pub fn denom_key(token_addr: &Address) -> storage::Key {
     storage::Key::from(token_addr.to_db_key())
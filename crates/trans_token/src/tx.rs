@@ -4,13 +4,16 @@ use std::borrow::Cow;
 use std::collections::{BTreeMap, BTreeSet};
 
 use namada_core::address::Address;
+use namada_core::chain::Epoch;
 use namada_core::collections::HashSet;
 use namada_events::{EmitEvents, EventLevel};
 use namada_state::Error;
 use namada_tx_env::{Result, TxEnv};
 
+use crate::allowance::{spend_allowance, write_allowance, Allowance};
 use crate::event::{TokenEvent, TokenOperation};
 use crate::storage_key::balance_key;
+use crate::vesting::{add_vesting_schedule, VestingSchedule};
 use crate::{read_balance, Amount, UserAccount};
 
 /// Multi-transfer credit or debit amounts
@@ -229,6 +232,77 @@ where
     Ok(())
 }
 
+/// Approve `spender` to transfer up to `amount` of the `token`s of `owner`,
+/// until the end of the `expiry` epoch, if any. This replaces any previous
+/// allowance of `spender`, and a zero `amount` revokes it.
+pub fn approve<ENV>(
+    env: &mut ENV,
+    token: &Address,
+    owner: &Address,
+    spender: &Address,
+    amount: Amount,
+    expiry: Option<Epoch>,
+) -> Result<()>
+where
+    ENV: TxEnv,
+{
+    if owner == spender {
+        return Err(Error::new_const(
+            "An owner cannot give an allowance to itself",
+        ));
+    }
+    // The tx must be authorized by the owner
+    env.insert_verifier(owner)?;
+    write_allowance(env, token, owner, spender, Allowance { amount, expiry })
+}
+
+/// Transfer `amount` of the `token`s of `owner` to `target` on behalf of
+/// `spender`, spending from its allowance.
+///
+/// Returns an `Err` if the allowance of `spender` is missing, expired or
+/// insufficient, or if the transfer itself fails.
+pub fn transfer_from<ENV>(
+    env: &mut ENV,
+    token: &Address,
+    owner: &Address,
+    spender: &Address,
+    target: &Address,
+    amount: Amount,
+    event_desc: Cow<'static, str>,
+) -> Result<()>
+where
+    ENV: TxEnv + EmitEvents,
+{
+    // The tx must be authorized by the spender
+    env.insert_verifier(spender)?;
+    spend_allowance(env, token, owner, spender, amount)?;
+    transfer(env, owner, target, token, amount, event_desc)
+}
+
+/// Transfer the `amount` of a vesting `schedule` of `token`s from `source` to
+/// `target`. The transferred tokens are locked in the balance of `target`
+/// until they unlock according to the schedule.
+pub fn vesting_transfer<ENV>(
+    env: &mut ENV,
+    source: &Address,
+    target: &Address,
+    token: &Address,
+    schedule: VestingSchedule,
+    event_desc: Cow<'static, str>,
+) -> Result<()>
+where
+    ENV: TxEnv + EmitEvents,
+{
+    schedule.validate()?;
+    if source == target {
+        return Err(Error::new_const(
+            "A vesting transfer cannot be made to the source itself",
+        ));
+    }
+    transfer(env, source, target, token, schedule.amount, event_desc)?;
+    add_vesting_schedule(env, token, target, schedule)
+}
+
 #[cfg(test)]
 mod test {
     use std::collections::BTreeMap;
@@ -673,4 +747,120 @@ mod test {
             assert!(events.is_empty());
         });
     }
+
+    #[test]
+    fn test_approve_and_transfer_from_tx() {
+        let owner = address::testing::established_address_1();
+        let spender = address::testing::established_address_2();
+        let target = address::testing::established_address_3();
+        let token = address::testing::established_address_4();
+        let owner_balance = token::Amount::native_whole(10);
+        let allowance = token::Amount::native_whole(5);
+
+        tx_host_env::init();
+
+        tx_host_env::with(|tx_env| {
+            tx_env.spawn_accounts([&owner, &spender, &target, &token]);
+            tx_env.credit_tokens(&owner, &token, owner_balance);
+        });
+
+        // An owner cannot approve itself
+        assert!(
+            approve(ctx(), &token, &owner, &owner, allowance, None).is_err()
+        );
+
+        approve(ctx(), &token, &owner, &spender, allowance, None).unwrap();
+        tx_host_env::with(|tx_env| {
+            assert!(tx_env.verifiers.contains(&owner));
+        });
+
+        // Spending more than the allowance fails
+        let amount = token::Amount::native_whole(6);
+        let res = transfer_from(
+            ctx(),
+            &token,
+            &owner,
+            &spender,
+            &target,
+            amount,
+            EVENT_DESC,
+        );
+        assert!(res.is_err());
+
+        let amount = token::Amount::native_whole(3);
+        transfer_from(
+            ctx(),
+            &token,
+            &owner,
+            &spender,
+            &target,
+            amount,
+            EVENT_DESC,
+        )
+        .unwrap();
+
+        assert_eq!(
+            read_balance(ctx(), &token, &owner).unwrap(),
+            owner_balance - amount
+        );
+        assert_eq!(read_balance(ctx(), &token, &target).unwrap(), amount);
+        assert_eq!(
+            crate::allowance::read_allowance(ctx(), &token, &owner, &spender)
+                .unwrap(),
+            Some(Allowance {
+                amount: allowance - amount,
+                expiry: None
+            })
+        );
+        tx_host_env::with(|tx_env| {
+            assert!(tx_env.verifiers.contains(&spender));
+        });
+    }
+
+    #[test]
+    fn test_vesting_transfer_tx() {
+        let src = address::testing::established_address_1();
+        let dest = address::testing::established_address_2();
+        let token = address::testing::established_address_3();
+        let src_balance = token::Amount::native_whole(100);
+        let schedule = VestingSchedule {
+            amount: token::Amount::native_whole(60),
+            start_epoch: Epoch(0),
+            cliff_epoch: Epoch(2),
+            end_epoch: Epoch(10),
+        };
+
+        tx_host_env::init();
+
+        tx_host_env::with(|tx_env| {
+            tx_env.spawn_accounts([&src, &dest, &token]);
+            tx_env.credit_tokens(&src, &token, src_balance);
+        });
+
+        // A vesting transfer to self is rejected
+        let res =
+            vesting_transfer(ctx(), &src, &src, &token, schedule, EVENT_DESC);
+        assert!(res.is_err());
+
+        vesting_transfer(ctx(), &src, &dest, &token, schedule, EVENT_DESC)
+            .unwrap();
+
+        assert_eq!(
+            read_balance(ctx(), &token, &src).unwrap(),
+            src_balance - schedule.amount
+        );
+        assert_eq!(
+            read_balance(ctx(), &token, &dest).unwrap(),
+            schedule.amount
+        );
+        assert_eq!(
+            crate::vesting::read_vesting_schedules(ctx(), &token, &dest)
+                .unwrap(),
+            vec![schedule]
+        );
+        let balance =
+            crate::vesting::read_vesting_balance(ctx(), &token, &dest).unwrap();
+        assert_eq!(balance.locked, schedule.amount);
+        assert!(balance.spendable.is_zero());
+    }
 }
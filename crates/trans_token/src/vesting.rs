@@ -0,0 +1,305 @@
+//! Vesting schedules of token balances. Tokens received with a vesting
+//! schedule are locked in the balance of their owner, and they unlock after a
+//! cliff epoch linearly until the end epoch of the schedule. The locked part
+//! of a balance cannot be debited.
+
+use namada_core::address::Address;
+use namada_core::borsh::{BorshDeserialize, BorshSerialize};
+use namada_core::chain::Epoch;
+use namada_core::token::Amount;
+use namada_core::uint::Uint;
+
+use crate::storage_key::vesting_key;
+use crate::{read_balance, Error, Result, StorageRead, StorageWrite};
+
+/// The maximum number of vesting schedules of the tokens of an owner. As
+/// anyone can vest tokens for any owner, this bounds the storage and gas that
+/// a third party can make the owner's balance checks consume.
+pub const MAX_VESTING_SCHEDULES: usize = 32;
+
+/// The minimum amount of a new vesting schedule, in whole units of the
+/// vested token, to make filling the schedules of an owner costly.
+pub const MIN_VESTING_WHOLE_AMOUNT: u64 = 1;
+
+/// A vesting schedule of some amount of tokens. Nothing is unlocked before
+/// the cliff epoch. From the cliff epoch on, the amount is unlocked linearly
+/// from the start epoch until the end epoch, when it is fully unlocked.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize,
+)]
+pub struct VestingSchedule {
+    /// The vested amount
+    pub amount: Amount,
+    /// The epoch from which the amount starts vesting
+    pub start_epoch: Epoch,
+    /// The first epoch in which any of the amount is unlocked
+    pub cliff_epoch: Epoch,
+    /// The epoch from which the whole amount is unlocked
+    pub end_epoch: Epoch,
+}
+
+impl VestingSchedule {
+    /// Check that the schedule vests a non-zero amount and that its epochs
+    /// are ordered.
+    pub fn validate(&self) -> Result<()> {
+        if self.amount.is_zero() {
+            return Err(Error::new_const(
+                "A vesting schedule must vest a non-zero amount",
+            ));
+        }
+        if self.start_epoch > self.cliff_epoch
+            || self.cliff_epoch > self.end_epoch
+        {
+            return Err(Error::new_alloc(format!(
+                "The epochs of a vesting schedule must satisfy start <= cliff \
+                 <= end, got {}, {} and {}",
+                self.start_epoch, self.cliff_epoch, self.end_epoch
+            )));
+        }
+        Ok(())
+    }
+
+    /// The amount that is unlocked in the given epoch.
+    pub fn unlocked_amount(&self, epoch: Epoch) -> Amount {
+        if epoch < self.cliff_epoch {
+            return Amount::zero();
+        }
+        if epoch >= self.end_epoch {
+            return self.amount;
+        }
+        let elapsed = epoch.0.saturating_sub(self.start_epoch.0);
+        let duration = self.end_epoch.0.saturating_sub(self.start_epoch.0);
+        self.amount
+            .raw_amount()
+            .checked_mul_div(Uint::from(elapsed), Uint::from(duration))
+            .map(|(unlocked, _rem)| Amount::from(unlocked))
+            // The duration cannot be zero here, as `start <= epoch < end`
+            .unwrap_or_default()
+    }
+
+    /// The amount that is still locked in the given epoch.
+    pub fn locked_amount(&self, epoch: Epoch) -> Amount {
+        self.amount
+            .checked_sub(self.unlocked_amount(epoch))
+            .unwrap_or_default()
+    }
+
+    /// Check if the whole amount is unlocked in the given epoch.
+    pub fn is_fully_unlocked(&self, epoch: Epoch) -> bool {
+        epoch >= self.end_epoch
+    }
+}
+
+/// The locked and spendable parts of a balance.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, BorshSerialize, BorshDeserialize,
+)]
+pub struct VestingBalance {
+    /// The part of the balance that is locked by vesting schedules
+    pub locked: Amount,
+    /// The part of the balance that can be debited
+    pub spendable: Amount,
+}
+
+/// Sum the amounts that are still locked by the given schedules in `epoch`.
+pub fn total_locked_amount<'a>(
+    schedules: impl IntoIterator<Item = &'a VestingSchedule>,
+    epoch: Epoch,
+) -> Result<Amount> {
+    schedules
+        .into_iter()
+        .try_fold(Amount::zero(), |acc, schedule| {
+            acc.checked_add(schedule.locked_amount(epoch))
+        })
+        .ok_or_else(|| Error::new_const("Overflowed in vesting locked amount"))
+}
+
+/// Read the vesting schedules of the `token`s of `owner`.
+pub fn read_vesting_schedules<S>(
+    storage: &S,
+    token: &Address,
+    owner: &Address,
+) -> Result<Vec<VestingSchedule>>
+where
+    S: StorageRead,
+{
+    Ok(storage
+        .read(&vesting_key(token, owner))?
+        .unwrap_or_default())
+}
+
+/// Write the vesting schedules of the `token`s of `owner`. The key is removed
+/// from storage if there are no schedules.
+pub fn write_vesting_schedules<S>(
+    storage: &mut S,
+    token: &Address,
+    owner: &Address,
+    schedules: Vec<VestingSchedule>,
+) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let key = vesting_key(token, owner);
+    if schedules.is_empty() {
+        storage.delete(&key)
+    } else {
+        storage.write(&key, schedules)
+    }
+}
+
+/// Add a vesting schedule to the `token`s of `owner`, pruning the existing
+/// schedules that are already fully unlocked.
+pub fn add_vesting_schedule<S>(
+    storage: &mut S,
+    token: &Address,
+    owner: &Address,
+    schedule: VestingSchedule,
+) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    schedule.validate()?;
+    let epoch = storage.get_block_epoch()?;
+    let mut schedules = read_vesting_schedules(storage, token, owner)?;
+    schedules.retain(|schedule| !schedule.is_fully_unlocked(epoch));
+    schedules.push(schedule);
+    write_vesting_schedules(storage, token, owner, schedules)
+}
+
+/// Read the locked and spendable parts of the balance of `owner` in the
+/// current epoch.
+pub fn read_vesting_balance<S>(
+    storage: &S,
+    token: &Address,
+    owner: &Address,
+) -> Result<VestingBalance>
+where
+    S: StorageRead,
+{
+    let epoch = storage.get_block_epoch()?;
+    let schedules = read_vesting_schedules(storage, token, owner)?;
+    let locked = total_locked_amount(&schedules, epoch)?;
+    let balance = read_balance(storage, token, owner)?;
+    Ok(VestingBalance {
+        locked,
+        spendable: balance.checked_sub(locked).unwrap_or_default(),
+    })
+}
+
+#[cfg(test)]
+mod test_vesting {
+    use namada_core::address;
+    use namada_state::testing::TestState;
+
+    use super::*;
+
+    fn schedule(
+        amount: u64,
+        start: u64,
+        cliff: u64,
+        end: u64,
+    ) -> VestingSchedule {
+        VestingSchedule {
+            amount: Amount::native_whole(amount),
+            start_epoch: Epoch(start),
+            cliff_epoch: Epoch(cliff),
+            end_epoch: Epoch(end),
+        }
+    }
+
+    #[test]
+    fn test_validate_schedule() {
+        assert!(schedule(100, 0, 2, 10).validate().is_ok());
+        assert!(schedule(100, 5, 5, 5).validate().is_ok());
+        assert!(schedule(0, 0, 2, 10).validate().is_err());
+        assert!(schedule(100, 3, 2, 10).validate().is_err());
+        assert!(schedule(100, 0, 11, 10).validate().is_err());
+    }
+
+    #[test]
+    fn test_unlocked_amount() {
+        let schedule = schedule(100, 0, 2, 10);
+        let unlocked = |epoch| schedule.unlocked_amount(Epoch(epoch));
+        // nothing is unlocked before the cliff
+        assert_eq!(unlocked(0), Amount::zero());
+        assert_eq!(unlocked(1), Amount::zero());
+        // from the cliff on, the amount unlocks linearly from the start
+        assert_eq!(unlocked(2), Amount::native_whole(20));
+        assert_eq!(unlocked(5), Amount::native_whole(50));
+        assert_eq!(unlocked(9), Amount::native_whole(90));
+        // and is fully unlocked from the end
+        assert_eq!(unlocked(10), Amount::native_whole(100));
+        assert_eq!(unlocked(u64::MAX), Amount::native_whole(100));
+        assert!(!schedule.is_fully_unlocked(Epoch(9)));
+        assert!(schedule.is_fully_unlocked(Epoch(10)));
+        assert_eq!(schedule.locked_amount(Epoch(5)), Amount::native_whole(50));
+
+        // a schedule that ends at its start unlocks at once
+        let instant = VestingSchedule {
+            amount: Amount::native_whole(100),
+            start_epoch: Epoch(5),
+            cliff_epoch: Epoch(5),
+            end_epoch: Epoch(5),
+        };
+        assert_eq!(instant.unlocked_amount(Epoch(4)), Amount::zero());
+        assert_eq!(
+            instant.unlocked_amount(Epoch(5)),
+            Amount::native_whole(100)
+        );
+    }
+
+    #[test]
+    fn test_vesting_balance() {
+        let mut storage = TestState::default();
+        let token = address::testing::nam();
+        let owner = address::testing::established_address_1();
+
+        let balance = read_vesting_balance(&storage, &token, &owner).unwrap();
+        assert_eq!(balance, VestingBalance::default());
+
+        crate::credit_tokens(
+            &mut storage,
+            &token,
+            &owner,
+            Amount::native_whole(150),
+        )
+        .unwrap();
+        add_vesting_schedule(
+            &mut storage,
+            &token,
+            &owner,
+            schedule(100, 0, 0, 10),
+        )
+        .unwrap();
+        let balance = read_vesting_balance(&storage, &token, &owner).unwrap();
+        assert_eq!(
+            balance,
+            VestingBalance {
+                locked: Amount::native_whole(100),
+                spendable: Amount::native_whole(50),
+            }
+        );
+
+        // invalid schedules are rejected
+        let res = add_vesting_schedule(
+            &mut storage,
+            &token,
+            &owner,
+            schedule(0, 0, 0, 10),
+        );
+        assert!(res.is_err());
+
+        // fully unlocked schedules are pruned when a new one is added
+        storage.in_mem_mut().block.epoch = Epoch(10);
+        add_vesting_schedule(
+            &mut storage,
+            &token,
+            &owner,
+            schedule(10, 10, 12, 20),
+        )
+        .unwrap();
+        let schedules =
+            read_vesting_schedules(&storage, &token, &owner).unwrap();
+        assert_eq!(schedules, vec![schedule(10, 10, 12, 20)]);
+    }
+}
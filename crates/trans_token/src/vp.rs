@@ -10,16 +10,22 @@ use namada_core::storage::{Key, KeySeg};
 use namada_core::token::Amount;
 use namada_systems::{governance, parameters};
 use namada_tx::action::{
-    Action, Bond, ClaimRewards, GovAction, PosAction, Withdraw,
+    Action, Bond, ClaimRewards, GovAction, LiquidBond, PosAction, Withdraw,
 };
 use namada_tx::BatchedTxRef;
 use namada_vp_env::{Error, Result, VpEnv};
 
+use crate::allowance::Allowance;
 use crate::storage_key::{
-    is_any_minted_balance_key, is_any_minter_key, is_any_token_balance_key,
-    is_any_token_parameter_key, minter_key,
+    balance_key, is_any_allowance_key, is_any_minted_balance_key,
+    is_any_minter_key, is_any_token_balance_key, is_any_token_parameter_key,
+    is_any_vesting_key, minter_key, vesting_key,
 };
-use crate::StorageRead;
+use crate::vesting::{
+    total_locked_amount, VestingSchedule, MAX_VESTING_SCHEDULES,
+    MIN_VESTING_WHOLE_AMOUNT,
+};
+use crate::{read_denom, StorageRead};
 
 /// The owner of some balance change.
 #[derive(Copy, Clone, Eq, PartialEq)]
@@ -96,6 +102,8 @@ where
         let mut dec_changes: HashMap<Address, Amount> = HashMap::new();
         let mut inc_mints: HashMap<Address, Amount> = HashMap::new();
         let mut dec_mints: HashMap<Address, Amount> = HashMap::new();
+        // The token balances whose vesting schedules must be checked
+        let mut vested_balances: BTreeSet<(Address, Address)> = BTreeSet::new();
         for key in keys_changed {
             if let Some([token, owner]) = is_any_token_balance_key(key) {
                 if !verifiers.contains(owner) {
@@ -133,6 +141,7 @@ where
                                 "Native token deposit isn't allowed",
                             ));
                         }
+                        vested_balances.insert((token.clone(), owner.clone()));
                         let diff = pre
                             .checked_sub(post)
                             .expect("Underflow shouldn't happen here");
@@ -179,6 +188,12 @@ where
                 Self::is_valid_minter(ctx, token, verifiers)?;
             } else if is_any_token_parameter_key(key).is_some() {
                 return Self::is_valid_parameter(ctx, tx_data);
+            } else if let Some([_token, owner, spender]) =
+                is_any_allowance_key(key)
+            {
+                Self::is_valid_allowance(ctx, key, owner, spender, verifiers)?;
+            } else if let Some([token, owner]) = is_any_vesting_key(key) {
+                vested_balances.insert((token.clone(), owner.clone()));
             } else if key.segments.first()
                 == Some(
                     &Address::Internal(InternalAddress::Multitoken).to_db_key(),
@@ -192,6 +207,10 @@ where
             }
         }
 
+        for (token, owner) in &vested_balances {
+            Self::is_valid_vesting(ctx, token, owner)?;
+        }
+
         let mut all_tokens = BTreeSet::new();
         all_tokens.extend(inc_changes.keys().cloned());
         all_tokens.extend(dec_changes.keys().cloned());
@@ -240,6 +259,120 @@ where
         })
     }
 
+    /// Check a change of the allowance of `spender` to transfer the tokens
+    /// of `owner`. The change itself is authorized by the VPs of the owner
+    /// and of the spender, which are both triggered by the allowance key.
+    pub fn is_valid_allowance(
+        ctx: &'ctx CTX,
+        key: &Key,
+        owner: &Address,
+        spender: &Address,
+        verifiers: &BTreeSet<Address>,
+    ) -> Result<()> {
+        if owner == spender {
+            return Err(Error::new_const(
+                "An owner cannot give an allowance to itself",
+            ));
+        }
+        if !verifiers.contains(owner) {
+            return Err(Error::new_alloc(format!(
+                "The vp of the address {} has not been triggered",
+                owner
+            )));
+        }
+        // Check that the new allowance, if any, is well-formed
+        ctx.read_post::<Allowance>(key)?;
+        Ok(())
+    }
+
+    /// Check the vesting schedules of the `token` balance of `owner`. The
+    /// locked part of the balance cannot be debited, and the schedules can
+    /// only be changed by adding new schedules that are funded by a credit
+    /// of the same tx, or by removing fully unlocked schedules. New schedules
+    /// must vest at least [`MIN_VESTING_WHOLE_AMOUNT`] and the owner cannot
+    /// have more than [`MAX_VESTING_SCHEDULES`] schedules.
+    pub fn is_valid_vesting(
+        ctx: &'ctx CTX,
+        token: &Address,
+        owner: &Address,
+    ) -> Result<()> {
+        let epoch = ctx.get_block_epoch()?;
+        let key = vesting_key(token, owner);
+        let pre: Vec<VestingSchedule> = ctx.read_pre(&key)?.unwrap_or_default();
+        let mut added: Vec<VestingSchedule> =
+            ctx.read_post(&key)?.unwrap_or_default();
+        let locked = total_locked_amount(&added, epoch)?;
+        let post_len = added.len();
+
+        // Schedules that still lock some tokens cannot be removed
+        for schedule in &pre {
+            match added.iter().position(|post| post == schedule) {
+                Some(ix) => {
+                    added.swap_remove(ix);
+                }
+                None if schedule.is_fully_unlocked(epoch) => {}
+                None => {
+                    return Err(Error::new_alloc(format!(
+                        "A locked vesting schedule of the {token} tokens of \
+                         {owner} cannot be removed"
+                    )));
+                }
+            }
+        }
+
+        let balance_key = balance_key(token, owner);
+        let pre_balance: Amount =
+            ctx.read_pre(&balance_key)?.unwrap_or_default();
+        let post_balance: Amount =
+            ctx.read_post(&balance_key)?.unwrap_or_default();
+
+        if !added.is_empty() && post_len > MAX_VESTING_SCHEDULES {
+            return Err(Error::new_alloc(format!(
+                "The {token} tokens of {owner} cannot have more than \
+                 {MAX_VESTING_SCHEDULES} vesting schedules"
+            )));
+        }
+
+        // New schedules must be funded by a credit of the same tx
+        let denom = read_denom(&ctx.pre(), token)?.ok_or_else(|| {
+            Error::new_alloc(format!("No denomination found for {token}"))
+        })?;
+        let min_amount = Amount::from_uint(MIN_VESTING_WHOLE_AMOUNT, denom)
+            .map_err(Error::new)?;
+        let mut added_amount = Amount::zero();
+        for schedule in &added {
+            schedule.validate()?;
+            if schedule.amount < min_amount {
+                return Err(Error::new_alloc(format!(
+                    "A new vesting schedule of the {token} tokens of {owner} \
+                     must vest at least {MIN_VESTING_WHOLE_AMOUNT} whole \
+                     token(s)"
+                )));
+            }
+            added_amount =
+                added_amount.checked_add(schedule.amount).ok_or_else(|| {
+                    Error::new_const("Overflowed in vesting check")
+                })?;
+        }
+        let credited =
+            post_balance.checked_sub(pre_balance).unwrap_or_default();
+        if added_amount > credited {
+            return Err(Error::new_alloc(format!(
+                "The new vesting schedules of the {token} tokens of {owner} \
+                 exceed the credited amount"
+            )));
+        }
+
+        // The locked balance cannot be debited
+        if post_balance < locked {
+            return Err(Error::new_alloc(format!(
+                "The locked balance of the {token} tokens of {owner} cannot \
+                 be debited"
+            )));
+        }
+        Ok(())
+    }
+
     /// Return the minter if the minter is valid and the minter VP exists
     pub fn is_valid_minter(
         ctx: &'ctx CTX,
@@ -264,6 +397,22 @@ where
                     )),
                 }
             }
+            Address::Internal(InternalAddress::StakeShare(_)) => {
+                // Liquid staking shares are minted and burned by PoS only,
+                // whose VP checks the amount against the change of the pool
+                let minter_key = minter_key(token);
+                match ctx.read_post::<Address>(&minter_key)? {
+                    Some(minter) if minter == POS => {
+                        verifiers.contains(&minter).ok_or_else(|| {
+                            Error::new_const("The PoS VP was not triggered")
+                        })
+                    }
+                    _ => Err(Error::new_const(
+                        "Only the PoS account is able to mint liquid staking \
+                         shares",
+                    )),
+                }
+            }
             _ => Err(Error::new_alloc(format!(
                 "Attempted to mint non-IBC token {token}"
             ))),
@@ -307,7 +456,10 @@ fn has_bal_inc_protocol_action(action: &Action, owner: Owner<'_>) -> bool {
             Owner::Protocol => true,
         },
         // NB: only pos or gov balances can decrease with these actions
-        Action::Pos(PosAction::Bond(Bond { .. }))
+        Action::Pos(
+            PosAction::Bond(Bond { .. })
+            | PosAction::LiquidBond(LiquidBond { .. }),
+        )
         | Action::Gov(GovAction::InitProposal { .. }) => {
             owner == Owner::Protocol
         }
@@ -327,6 +479,13 @@ fn has_bal_dec_protocol_action(action: &Action, owner: Owner<'_>) -> bool {
             // NB: pos or gov's balance can decrease
             Owner::Protocol => true,
         },
+        Action::Pos(PosAction::LiquidBond(LiquidBond { source, .. })) => {
+            match owner {
+                Owner::Account(owner) => source == owner,
+                // NB: pos or gov's balance can decrease
+                Owner::Protocol => true,
+            }
+        }
         Action::Gov(GovAction::InitProposal { author: source }) => {
             match owner {
                 Owner::Account(owner) => source == owner,
@@ -353,6 +512,7 @@ mod tests {
         established_address_1, established_address_2, nam,
     };
     use namada_core::borsh::BorshSerializeExt;
+    use namada_core::chain::Epoch;
     use namada_core::key::testing::keypair_1;
     use namada_gas::{TxGasMeter, VpGasMeter};
     use namada_ibc::trace::ibc_token;
@@ -369,7 +529,7 @@ mod tests {
     use namada_vp::native_vp::{self, CtxPreStorageRead};
 
     use super::*;
-    use crate::storage_key::{balance_key, minted_balance_key};
+    use crate::storage_key::minted_balance_key;
 
     const ADDRESS: Address = Address::Internal(InternalAddress::Multitoken);
 
@@ -781,6 +941,124 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_stake_share_minter() {
+        let mut state = init_state();
+        let mut keys_changed = BTreeSet::new();
+
+        // Liquid staking share token of a validator
+        let Address::Established(validator) = established_address_2() else {
+            unreachable!()
+        };
+        let token = Address::Internal(InternalAddress::StakeShare(validator));
+
+        // mint 100
+        let target = established_address_1();
+        let target_key = balance_key(&token, &target);
+        let amount = Amount::native_whole(100);
+        let _ = state
+            .write_log_mut()
+            .write(&target_key, amount.serialize_to_vec())
+            .expect("write failed");
+        keys_changed.insert(target_key);
+        let minted_key = minted_balance_key(&token);
+        let _ = state
+            .write_log_mut()
+            .write(&minted_key, amount.serialize_to_vec())
+            .expect("write failed");
+        keys_changed.insert(minted_key);
+
+        // PoS is the minter
+        let minter_key = minter_key(&token);
+        let _ = state
+            .write_log_mut()
+            .write(&minter_key, POS.serialize_to_vec())
+            .expect("write failed");
+        keys_changed.insert(minter_key.clone());
+
+        let tx_index = TxIndex::default();
+        let BatchedTx { tx, cmt } = dummy_tx(&state);
+        let gas_meter = RefCell::new(VpGasMeter::new_from_tx_meter(
+            &TxGasMeter::new(u64::MAX),
+        ));
+        let (vp_vp_cache, _vp_cache_dir) = vp_cache();
+        let mut verifiers = BTreeSet::new();
+        verifiers.insert(token);
+        verifiers.insert(target);
+
+        // The PoS VP must be triggered for the mint
+        let ctx = Ctx::new(
+            &ADDRESS,
+            &state,
+            &tx,
+            &cmt,
+            &tx_index,
+            &gas_meter,
+            &keys_changed,
+            &verifiers,
+            vp_vp_cache.clone(),
+        );
+        assert!(
+            MultitokenVp::validate_tx(
+                &ctx,
+                &tx.batch_ref_tx(&cmt),
+                &keys_changed,
+                &verifiers
+            )
+            .is_err()
+        );
+
+        verifiers.insert(POS);
+        let ctx = Ctx::new(
+            &ADDRESS,
+            &state,
+            &tx,
+            &cmt,
+            &tx_index,
+            &gas_meter,
+            &keys_changed,
+            &verifiers,
+            vp_vp_cache.clone(),
+        );
+        assert!(
+            MultitokenVp::validate_tx(
+                &ctx,
+                &tx.batch_ref_tx(&cmt),
+                &keys_changed,
+                &verifiers
+            )
+            .is_ok()
+        );
+
+        // No other account can mint the shares
+        let minter = Address::Internal(InternalAddress::Ibc);
+        let _ = state
+            .write_log_mut()
+            .write(&minter_key, minter.serialize_to_vec())
+            .expect("write failed");
+        verifiers.insert(minter);
+        let ctx = Ctx::new(
+            &ADDRESS,
+            &state,
+            &tx,
+            &cmt,
+            &tx_index,
+            &gas_meter,
+            &keys_changed,
+            &verifiers,
+            vp_vp_cache,
+        );
+        assert!(
+            MultitokenVp::validate_tx(
+                &ctx,
+                &tx.batch_ref_tx(&cmt),
+                &keys_changed,
+                &verifiers
+            )
+            .is_err()
+        );
+    }
+
     #[test]
     fn test_invalid_minter_update() {
         let mut state = init_state();
@@ -874,6 +1152,298 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_allowance_update() {
+        let mut state = init_state();
+        let mut keys_changed = BTreeSet::new();
+
+        let owner = established_address_1();
+        let spender = established_address_2();
+        let key = crate::storage_key::allowance_key(&nam(), &owner, &spender);
+        let allowance = Allowance {
+            amount: Amount::native_whole(10),
+            expiry: None,
+        };
+        let _ = state
+            .write_log_mut()
+            .write(&key, allowance.serialize_to_vec())
+            .expect("write failed");
+
+        keys_changed.insert(key);
+
+        let tx_index = TxIndex::default();
+        let BatchedTx { tx, cmt } = dummy_tx(&state);
+        let gas_meter = RefCell::new(VpGasMeter::new_from_tx_meter(
+            &TxGasMeter::new(u64::MAX),
+        ));
+        let (vp_vp_cache, _vp_cache_dir) = vp_cache();
+        // the owner must be a verifier
+        let verifiers = BTreeSet::from([spender.clone()]);
+        let ctx = Ctx::new(
+            &ADDRESS,
+            &state,
+            &tx,
+            &cmt,
+            &tx_index,
+            &gas_meter,
+            &keys_changed,
+            &verifiers,
+            vp_vp_cache.clone(),
+        );
+        let res = MultitokenVp::validate_tx(
+            &ctx,
+            &tx.batch_ref_tx(&cmt),
+            &keys_changed,
+            &verifiers,
+        );
+        assert!(res.is_err());
+
+        let verifiers = BTreeSet::from([owner, spender]);
+        let ctx = Ctx::new(
+            &ADDRESS,
+            &state,
+            &tx,
+            &cmt,
+            &tx_index,
+            &gas_meter,
+            &keys_changed,
+            &verifiers,
+            vp_vp_cache,
+        );
+        let res = MultitokenVp::validate_tx(
+            &ctx,
+            &tx.batch_ref_tx(&cmt),
+            &keys_changed,
+            &verifiers,
+        );
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn test_vesting_locked_balance() {
+        let mut state = init_state();
+        let src = established_address_1();
+        let dest = established_address_2();
+        // `src` has a balance of 100 of which 95 are locked
+        let schedule = VestingSchedule {
+            amount: Amount::native_whole(95),
+            start_epoch: Epoch(0),
+            cliff_epoch: Epoch(1),
+            end_epoch: Epoch(10),
+        };
+        let src_vesting_key = vesting_key(&nam(), &src);
+        state
+            .db_write(&src_vesting_key, vec![schedule].serialize_to_vec())
+            .expect("write failed");
+        // transfer 10
+        let keys_changed = transfer(&mut state, &src, &dest);
+
+        let tx_index = TxIndex::default();
+        let BatchedTx { tx, cmt } = dummy_tx(&state);
+        let gas_meter = RefCell::new(VpGasMeter::new_from_tx_meter(
+            &TxGasMeter::new(u64::MAX),
+        ));
+        let (vp_vp_cache, _vp_cache_dir) = vp_cache();
+        let verifiers = BTreeSet::from([src.clone(), dest.clone()]);
+        let ctx = Ctx::new(
+            &ADDRESS,
+            &state,
+            &tx,
+            &cmt,
+            &tx_index,
+            &gas_meter,
+            &keys_changed,
+            &verifiers,
+            vp_vp_cache.clone(),
+        );
+        let res = MultitokenVp::validate_tx(
+            &ctx,
+            &tx.batch_ref_tx(&cmt),
+            &keys_changed,
+            &verifiers,
+        );
+        assert!(res.is_err());
+
+        // once enough is unlocked, the transfer is valid and the credited
+        // amount may be vested for `dest`
+        state.in_mem_mut().block.epoch = Epoch(5);
+        let dest_schedule = VestingSchedule {
+            amount: Amount::native_whole(10),
+            ..schedule
+        };
+        let dest_vesting_key = vesting_key(&nam(), &dest);
+        let _ = state
+            .write_log_mut()
+            .write(&dest_vesting_key, vec![dest_schedule].serialize_to_vec())
+            .expect("write failed");
+        let mut keys_changed = keys_changed;
+        keys_changed.insert(dest_vesting_key.clone());
+        let ctx = Ctx::new(
+            &ADDRESS,
+            &state,
+            &tx,
+            &cmt,
+            &tx_index,
+            &gas_meter,
+            &keys_changed,
+            &verifiers,
+            vp_vp_cache.clone(),
+        );
+        let res = MultitokenVp::validate_tx(
+            &ctx,
+            &tx.batch_ref_tx(&cmt),
+            &keys_changed,
+            &verifiers,
+        );
+        assert!(res.is_ok());
+
+        // but vesting more than the credited amount is invalid
+        let dest_schedule = VestingSchedule {
+            amount: Amount::native_whole(11),
+            ..schedule
+        };
+        let _ = state
+            .write_log_mut()
+            .write(&dest_vesting_key, vec![dest_schedule].serialize_to_vec())
+            .expect("write failed");
+        let ctx = Ctx::new(
+            &ADDRESS,
+            &state,
+            &tx,
+            &cmt,
+            &tx_index,
+            &gas_meter,
+            &keys_changed,
+            &verifiers,
+            vp_vp_cache.clone(),
+        );
+        let res = MultitokenVp::validate_tx(
+            &ctx,
+            &tx.batch_ref_tx(&cmt),
+            &keys_changed,
+            &verifiers,
+        );
+        assert!(res.is_err());
+
+        // and so is the removal of a locked schedule
+        let _ = state
+            .write_log_mut()
+            .delete(&dest_vesting_key)
+            .expect("delete failed");
+        let _ = state
+            .write_log_mut()
+            .delete(&src_vesting_key)
+            .expect("delete failed");
+        keys_changed.insert(src_vesting_key);
+        let ctx = Ctx::new(
+            &ADDRESS,
+            &state,
+            &tx,
+            &cmt,
+            &tx_index,
+            &gas_meter,
+            &keys_changed,
+            &verifiers,
+            vp_vp_cache,
+        );
+        let res = MultitokenVp::validate_tx(
+            &ctx,
+            &tx.batch_ref_tx(&cmt),
+            &keys_changed,
+            &verifiers,
+        );
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_vesting_schedules_limits() {
+        let mut state = init_state();
+        let src = established_address_1();
+        let dest = established_address_2();
+        // `dest` already has the maximum number of schedules, which are all
+        // fully unlocked
+        let unlocked = VestingSchedule {
+            amount: Amount::native_whole(1),
+            start_epoch: Epoch(0),
+            cliff_epoch: Epoch(0),
+            end_epoch: Epoch(0),
+        };
+        let dest_vesting_key = vesting_key(&nam(), &dest);
+        state
+            .db_write(
+                &dest_vesting_key,
+                vec![unlocked; MAX_VESTING_SCHEDULES].serialize_to_vec(),
+            )
+            .expect("write failed");
+        // transfer 10
+        let mut keys_changed = transfer(&mut state, &src, &dest);
+        keys_changed.insert(dest_vesting_key.clone());
+
+        let tx_index = TxIndex::default();
+        let BatchedTx { tx, cmt } = dummy_tx(&state);
+        let gas_meter = RefCell::new(VpGasMeter::new_from_tx_meter(
+            &TxGasMeter::new(u64::MAX),
+        ));
+        let (vp_vp_cache, _vp_cache_dir) = vp_cache();
+        let verifiers = BTreeSet::from([src.clone(), dest.clone()]);
+        let validate = |state: &TestState| {
+            let ctx = Ctx::new(
+                &ADDRESS,
+                state,
+                &tx,
+                &cmt,
+                &tx_index,
+                &gas_meter,
+                &keys_changed,
+                &verifiers,
+                vp_vp_cache.clone(),
+            );
+            MultitokenVp::validate_tx(
+                &ctx,
+                &tx.batch_ref_tx(&cmt),
+                &keys_changed,
+                &verifiers,
+            )
+        };
+
+        // vesting the credited amount beyond the maximum number of schedules
+        // is invalid
+        let schedule = VestingSchedule {
+            amount: Amount::native_whole(10),
+            start_epoch: Epoch(0),
+            cliff_epoch: Epoch(1),
+            end_epoch: Epoch(10),
+        };
+        let mut schedules = vec![unlocked; MAX_VESTING_SCHEDULES];
+        schedules.push(schedule);
+        let _ = state
+            .write_log_mut()
+            .write(&dest_vesting_key, schedules.serialize_to_vec())
+            .expect("write failed");
+        assert!(validate(&state).is_err());
+
+        // but it is valid once the unlocked schedules are pruned
+        let _ = state
+            .write_log_mut()
+            .write(&dest_vesting_key, vec![schedule].serialize_to_vec())
+            .expect("write failed");
+        assert!(validate(&state).is_ok());
+
+        // and a schedule cannot vest less than the minimum amount
+        let dust = VestingSchedule {
+            amount: Amount::from_uint(MIN_VESTING_WHOLE_AMOUNT, 6)
+                .unwrap()
+                .checked_sub(Amount::from(1))
+                .unwrap(),
+            ..schedule
+        };
+        let _ = state
+            .write_log_mut()
+            .write(&dest_vesting_key, vec![dust].serialize_to_vec())
+            .expect("write failed");
+        assert!(validate(&state).is_err());
+    }
+
     #[test]
     fn test_native_token_not_transferable() {
         let mut state = init_state();
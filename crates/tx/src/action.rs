@@ -15,7 +15,8 @@ use namada_core::storage::KeySeg;
 use namada_core::{address, storage};
 
 pub use crate::data::pos::{
-    Bond, ClaimRewards, Redelegation, Unbond, Withdraw,
+    AutoCompound, Bond, ClaimRewards, LiquidBond, LiquidUnbond, Redelegation,
+    Unbond, Withdraw,
 };
 
 /// Actions applied from txs.
@@ -30,6 +31,7 @@ pub enum Action {
     Pgf(PgfAction),
     Masp(MaspAction),
     IbcShielding,
+    Account(AccountAction),
 }
 
 /// PoS tx actions.
@@ -42,9 +44,12 @@ pub enum PosAction {
     Unjail(Address),
     Bond(Bond),
     Unbond(Unbond),
+    LiquidBond(LiquidBond),
+    LiquidUnbond(LiquidUnbond),
     Withdraw(Withdraw),
     Redelegation(Redelegation),
     ClaimRewards(ClaimRewards),
+    AutoCompound(AutoCompound),
     CommissionChange(Address),
     MetadataChange(Address),
     ConsensusKeyChange(Address),
@@ -56,6 +61,10 @@ pub enum PosAction {
 pub enum GovAction {
     InitProposal { author: Address },
     VoteProposal { id: u64, voter: Address },
+    DelegateVotes { delegator: Address },
+    DepositProposal { id: u64, depositor: Address },
+    CancelProposal { id: u64, author: Address },
+    AmendProposal { id: u64, author: Address },
 }
 
 /// PGF tx actions.
@@ -75,6 +84,13 @@ pub enum MaspAction {
     MaspAuthorizer(Address),
 }
 
+/// Account tx actions.
+#[allow(missing_docs)]
+#[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
+pub enum AccountAction {
+    ApproveRecovery { owner: Address, guardian: Address },
+}
+
 /// Read actions from temporary storage
 pub trait Read {
     /// Storage access errors
@@ -1,5 +1,7 @@
 //! Types used for PoS system transactions
 
+use std::collections::BTreeMap;
+
 use namada_core::address::Address;
 use namada_core::borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
 use namada_core::dec::Dec;
@@ -84,6 +86,35 @@ pub struct Bond {
 /// An unbond of a bond.
 pub type Unbond = Bond;
 
+/// A liquid bond of tokens to a validator, which mints the validator's liquid
+/// staking shares to the source.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSchema,
+    Hash,
+    Eq,
+    Serialize,
+    Deserialize,
+)]
+pub struct LiquidBond {
+    /// Validator address
+    pub validator: Address,
+    /// The amount of tokens to bond, or the amount of shares to redeem for a
+    /// liquid unbond
+    pub amount: token::Amount,
+    /// Source address of the tokens, and owner of the shares
+    pub source: Address,
+}
+
+/// A liquid unbond, which redeems liquid staking shares of a validator.
+pub type LiquidUnbond = LiquidBond;
+
 /// A withdrawal of an unbond.
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(
@@ -130,6 +161,31 @@ pub struct ClaimRewards {
     pub source: Option<Address>,
 }
 
+/// A change of the automatic compounding of the rewards of a bond.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    BorshSchema,
+    Hash,
+    Eq,
+    Serialize,
+    Deserialize,
+)]
+pub struct AutoCompound {
+    /// Validator address
+    pub validator: Address,
+    /// Source address of the bond whose rewards are compounded. For
+    /// self-bonds, the validator is also the source
+    pub source: Option<Address>,
+    /// Whether the rewards of the bond are automatically re-bonded
+    pub enabled: bool,
+}
+
 /// A redelegation of bonded tokens from one validator to another.
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(
@@ -208,6 +264,10 @@ pub struct MetaDataChange {
     pub avatar: Option<String>,
     /// Validator's name
     pub name: Option<String>,
+    /// Validator's reward destination, splitting the commission among the
+    /// addresses by their ratios. An empty map resets the destination to the
+    /// validator itself.
+    pub reward_destination: Option<BTreeMap<Address, Dec>>,
     /// Validator's commission rate
     pub commission_rate: Option<Dec>,
 }
@@ -241,7 +301,7 @@ pub mod tests {
     use namada_core::dec::testing::arb_dec;
     use namada_core::key::testing::{arb_common_pk, arb_pk};
     use namada_core::token::testing::arb_amount;
-    use proptest::{option, prop_compose};
+    use proptest::{collection, option, prop_compose};
 
     use super::*;
 
@@ -260,6 +320,21 @@ pub mod tests {
         }
     }
 
+    prop_compose! {
+        /// Generate an arbitrary liquid bond
+        pub fn arb_liquid_bond()(
+            validator in arb_non_internal_address(),
+            amount in arb_amount(),
+            source in arb_non_internal_address(),
+        ) -> LiquidBond {
+            LiquidBond {
+                validator,
+                amount,
+                source,
+            }
+        }
+    }
+
     prop_compose! {
         /// Generate an arbitrary withdraw
         pub fn arb_withdraw()(
@@ -273,6 +348,21 @@ pub mod tests {
         }
     }
 
+    prop_compose! {
+        /// Generate an arbitrary auto-compound change
+        pub fn arb_auto_compound()(
+            validator in arb_non_internal_address(),
+            source in option::of(arb_non_internal_address()),
+            enabled in proptest::bool::ANY,
+        ) -> AutoCompound {
+            AutoCompound {
+                validator,
+                source,
+                enabled,
+            }
+        }
+    }
+
     prop_compose! {
         /// Generate an arbitrary commission change
         pub fn arb_commission_change()(
@@ -296,6 +386,11 @@ pub mod tests {
             discord_handle in option::of("[a-zA-Z0-9_]*"),
             avatar in option::of("[a-zA-Z0-9_]*"),
             name in option::of("[a-zA-Z0-9_]*"),
+            reward_destination in option::of(collection::btree_map(
+                arb_non_internal_address(),
+                arb_dec(),
+                0..10,
+            )),
             commission_rate in option::of(arb_dec()),
         ) -> MetaDataChange {
             MetaDataChange {
@@ -306,6 +401,7 @@ pub mod tests {
                 discord_handle,
                 avatar,
                 name,
+                reward_destination,
                 commission_rate,
             }
         }
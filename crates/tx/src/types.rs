@@ -491,7 +491,9 @@ impl Tx {
                         witnesses.push(signatures);
                     }
                     // Short-circuit these checks if the threshold is exceeded
-                    if verified_pks.len() >= threshold.into() {
+                    if public_keys_index_map.signing_weight(&verified_pks)
+                        >= threshold.into()
+                    {
                         return Ok(witnesses);
                     }
                 }
@@ -499,7 +501,7 @@ impl Tx {
         }
         Err(VerifySigError::InvalidSectionSignature(format!(
             "signature threshold not met: ({} < {})",
-            verified_pks.len(),
+            public_keys_index_map.signing_weight(&verified_pks),
             threshold
         )))
     }
@@ -1303,6 +1305,65 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_inner_tx_weighted_multisig_signing() {
+        let sk1 = key::testing::keypair_1();
+        let sk2 = key::testing::keypair_2();
+        let sk3 = key::testing::keypair_3();
+
+        // A multisig where the key 1 is worth 2 and the keys 2 and 3 are
+        // worth 1 each, requiring a total weight of 2
+        let pks_map = AccountPublicKeysMap::from_iter(vec![
+            sk1.to_public(),
+            sk2.to_public(),
+            sk3.to_public(),
+        ])
+        .with_weights([(0, 2)]);
+        let threshold = 2_u8;
+
+        let tx = Tx::default();
+        let verify = |tx: &Tx| {
+            tx.verify_signatures(
+                &[tx.header_hash()],
+                pks_map.clone(),
+                &None,
+                threshold,
+                || Ok(()),
+            )
+            .map(|authorizations| authorizations.len())
+        };
+
+        // The heavy key alone meets the threshold
+        {
+            let mut tx = tx.clone();
+            let signatures =
+                tx.compute_section_signature(&[sk1.clone()], &pks_map, None);
+            tx.add_signatures(signatures);
+            assert_matches!(verify(&tx), Ok(1));
+        }
+
+        // A single light key doesn't
+        {
+            let mut tx = tx.clone();
+            let signatures =
+                tx.compute_section_signature(&[sk2.clone()], &pks_map, None);
+            tx.add_signatures(signatures);
+            assert_matches!(
+                verify(&tx),
+                Err(VerifySigError::InvalidSectionSignature(_))
+            );
+        }
+
+        // But both light keys together do
+        {
+            let mut tx = tx.clone();
+            let signatures =
+                tx.compute_section_signature(&[sk2, sk3], &pks_map, None);
+            tx.add_signatures(signatures);
+            assert_matches!(verify(&tx), Ok(1));
+        }
+    }
+
     #[test]
     fn test_inner_tx_sections() {
         let mut tx = Tx::default();
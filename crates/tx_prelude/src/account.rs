@@ -1,6 +1,7 @@
 //! Account related functions.
 
 pub use namada_account::*;
+use namada_tx::action::{AccountAction, Action, Write};
 
 use super::*;
 
@@ -16,5 +17,46 @@ pub fn init_account(
         owner,
         &data.public_keys,
         data.threshold,
-    )
+    )?;
+    namada_account::set_public_key_weights(ctx, owner, &data.weights)?;
+    namada_account::validate_public_key_weights(ctx, owner)
+}
+
+/// Opt in to or out of the social recovery of an account
+pub fn configure_recovery(ctx: &mut Ctx, data: ConfigureRecovery) -> TxResult {
+    // The tx must be authorized by the account
+    ctx.insert_verifier(&data.addr)?;
+
+    namada_account::write_recovery_config(ctx, &data.addr, data.config)
+}
+
+/// Initiate the recovery of the keys of an account, approved by its
+/// guardians
+pub fn initiate_recovery(ctx: &mut Ctx, data: InitiateRecovery) -> TxResult {
+    // The tx must be authorized by every approving guardian
+    for guardian in &data.guardians {
+        ctx.insert_verifier(guardian)?;
+        ctx.push_action(Action::Account(AccountAction::ApproveRecovery {
+            owner: data.addr.clone(),
+            guardian: guardian.clone(),
+        }))?;
+    }
+
+    namada_account::initiate_recovery(
+        ctx,
+        &data.addr,
+        data.guardians,
+        data.public_keys,
+        data.threshold,
+        data.weights,
+    )?;
+    Ok(())
+}
+
+/// Cancel the pending recovery of the keys of an account
+pub fn cancel_recovery(ctx: &mut Ctx, owner: &Address) -> TxResult {
+    // The tx must be authorized by the account
+    ctx.insert_verifier(owner)?;
+
+    namada_account::cancel_recovery(ctx, owner)
 }
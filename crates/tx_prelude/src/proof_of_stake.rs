@@ -2,21 +2,28 @@
 
 use namada_core::dec::Dec;
 use namada_core::key;
+use namada_proof_of_stake::liquid_staking::{
+    liquid_bond_tokens, liquid_unbond_tokens,
+};
 pub use namada_proof_of_stake::parameters::PosParams;
 pub use namada_proof_of_stake::queries::find_delegation_validators;
 use namada_proof_of_stake::storage::read_pos_params;
-use namada_proof_of_stake::types::{ResultSlashing, ValidatorMetaData};
+use namada_proof_of_stake::types::{
+    ResultSlashing, RewardDestination, ValidatorMetaData,
+};
 use namada_proof_of_stake::{
     become_validator, bond_tokens, change_consensus_key,
     change_validator_commission_rate, change_validator_metadata,
     claim_reward_tokens, deactivate_validator, reactivate_validator,
-    redelegate_tokens, unbond_tokens, unjail_validator, withdraw_tokens,
+    redelegate_tokens, set_auto_compound, unbond_tokens, unjail_validator,
+    withdraw_tokens,
 };
 pub use namada_proof_of_stake::{
     is_validator, parameters, storage, storage_key, types,
 };
 use namada_tx::action::{
-    Action, ClaimRewards, PosAction, Redelegation, Unbond, Withdraw, Write,
+    Action, AutoCompound, ClaimRewards, LiquidBond, LiquidUnbond, PosAction,
+    Redelegation, Unbond, Withdraw, Write,
 };
 use namada_tx::data::pos::{BecomeValidator, Bond};
 
@@ -84,6 +91,62 @@ impl Ctx {
         )
     }
 
+    /// Bond tokens from the `source` to the liquid staking pool of the
+    /// `validator`, receiving transferable shares of the pool. Returns the
+    /// amount of issued shares.
+    pub fn liquid_bond_tokens(
+        &mut self,
+        source: &Address,
+        validator: &Address,
+        amount: token::Amount,
+    ) -> Result<token::Amount> {
+        // The tx must be authorized by the source address
+        self.insert_verifier(source)?;
+
+        self.push_action(Action::Pos(PosAction::LiquidBond(LiquidBond {
+            validator: validator.clone(),
+            amount,
+            source: source.clone(),
+        })))?;
+
+        let current_epoch = self.get_block_epoch()?;
+        liquid_bond_tokens::<_, governance::Store<_>, token::Store<_>>(
+            self,
+            source,
+            validator,
+            amount,
+            current_epoch,
+        )
+    }
+
+    /// Redeem liquid staking shares of the `validator` held by the `source`,
+    /// unbonding the tokens that they are worth to the `source`. Returns the
+    /// unbonded amount.
+    pub fn liquid_unbond_tokens(
+        &mut self,
+        source: &Address,
+        validator: &Address,
+        shares: token::Amount,
+    ) -> Result<token::Amount> {
+        // The tx must be authorized by the source address
+        self.insert_verifier(source)?;
+
+        self.push_action(Action::Pos(PosAction::LiquidUnbond(LiquidUnbond {
+            validator: validator.clone(),
+            amount: shares,
+            source: source.clone(),
+        })))?;
+
+        let current_epoch = self.get_block_epoch()?;
+        liquid_unbond_tokens::<_, governance::Store<_>, token::Store<_>>(
+            self,
+            source,
+            validator,
+            shares,
+            current_epoch,
+        )
+    }
+
     /// Withdraw unbonded tokens from a self-bond to a validator when
     /// `source` is `None` or equal to the `validator` address, or withdraw
     /// unbonded tokens delegated to the `validator` to the `source`.
@@ -222,6 +285,26 @@ impl Ctx {
         )
     }
 
+    /// Enable or disable the automatic compounding of the rewards of a bond
+    pub fn set_auto_compound(
+        &mut self,
+        source: Option<&Address>,
+        validator: &Address,
+        enabled: bool,
+    ) -> TxResult {
+        // The tx must be authorized by the source address
+        let verifier = source.as_ref().unwrap_or(&validator);
+        self.insert_verifier(verifier)?;
+
+        self.push_action(Action::Pos(PosAction::AutoCompound(AutoCompound {
+            validator: validator.clone(),
+            source: source.cloned(),
+            enabled,
+        })))?;
+
+        set_auto_compound(self, source, validator, enabled)
+    }
+
     /// Attempt to initialize a validator account. On success, returns the
     /// initialized validator account's address.
     pub fn become_validator(
@@ -273,6 +356,7 @@ impl Ctx {
                     discord_handle,
                     avatar,
                     name,
+                    reward_destination: None,
                 },
                 offset_opt: None,
             },
@@ -326,6 +410,7 @@ impl Ctx {
         discord_handle: Option<String>,
         avatar: Option<String>,
         name: Option<String>,
+        reward_destination: Option<RewardDestination>,
         commission_rate: Option<Dec>,
     ) -> TxResult {
         // The tx must be authorized by the source address
@@ -345,6 +430,7 @@ impl Ctx {
             discord_handle,
             avatar,
             name,
+            reward_destination,
             commission_rate,
             current_epoch,
         )
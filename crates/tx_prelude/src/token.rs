@@ -6,7 +6,8 @@ pub use namada_token::testing;
 pub use namada_token::tx::apply_shielded_transfer;
 use namada_token::TransparentTransfersRef;
 pub use namada_token::{
-    storage_key, utils, Amount, DenominatedAmount, Store, Transfer,
+    storage_key, utils, Amount, Approve, DenominatedAmount, Store, Transfer,
+    TransferFrom, VestingTransfer,
 };
 use namada_tx::BatchedTx;
 use namada_tx_env::Address;
@@ -27,6 +28,46 @@ pub fn transfer(
     namada_token::tx::transfer(ctx, src, dest, token, amount, EVENT_DESC.into())
 }
 
+/// Approve a spender to transfer up to some amount of the tokens of an owner.
+pub fn approve(ctx: &mut Ctx, approve: &Approve) -> TxResult {
+    namada_token::tx::approve(
+        ctx,
+        &approve.token,
+        &approve.owner,
+        &approve.spender,
+        approve.amount.amount(),
+        approve.expiry,
+    )
+}
+
+/// Transfer the tokens of an owner on behalf of a spender, from its allowance,
+/// insert the verifiers expected by the VPs and emit an event.
+pub fn transfer_from(ctx: &mut Ctx, transfer: &TransferFrom) -> TxResult {
+    namada_token::tx::transfer_from(
+        ctx,
+        &transfer.token,
+        &transfer.owner,
+        &transfer.spender,
+        &transfer.target,
+        transfer.amount.amount(),
+        EVENT_DESC.into(),
+    )
+}
+
+/// Transfer tokens that are locked in the balance of the target according to
+/// a vesting schedule, insert the verifier expected by the VP and emit an
+/// event.
+pub fn vesting_transfer(ctx: &mut Ctx, transfer: &VestingTransfer) -> TxResult {
+    namada_token::tx::vesting_transfer(
+        ctx,
+        &transfer.source,
+        &transfer.target,
+        &transfer.token,
+        transfer.schedule(),
+        EVENT_DESC.into(),
+    )
+}
+
 /// Transparent and shielded token transfers that can be used in a transaction.
 pub fn multi_transfer(
     ctx: &mut Ctx,
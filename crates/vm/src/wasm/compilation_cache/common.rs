@@ -8,6 +8,7 @@ use std::fs;
 use std::marker::PhantomData;
 use std::num::NonZeroUsize;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, RwLock};
 use std::thread::sleep;
 use std::time::Duration;
@@ -32,6 +33,8 @@ pub struct Cache<N, A> {
     progress: Arc<RwLock<HashMap<Hash, Compilation>>>,
     /// In-memory LRU cache of compiled modules
     in_memory: Arc<RwLock<MemoryCache>>,
+    /// Hits and misses of the in-memory cache
+    stats: Arc<CacheStats>,
     /// The cache's name
     name: PhantomData<N>,
     /// Cache access level
@@ -56,6 +59,20 @@ pub trait CacheName: Clone + std::fmt::Debug {
 /// In-memory LRU cache of compiled modules
 type MemoryCache = CLruCache<Hash, Module, RandomState, ModuleCacheScale>;
 
+/// Hits and misses of the in-memory cache
+#[derive(Debug, Default)]
+struct CacheStats {
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl CacheStats {
+    fn record(&self, hit: bool) {
+        let counter = if hit { &self.hits } else { &self.misses };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
 /// Compilation progress
 #[derive(Debug)]
 enum Compilation {
@@ -107,6 +124,7 @@ impl<N: CacheName, A: WasmCacheAccess> Cache<N, A> {
             dir,
             progress: Default::default(),
             in_memory,
+            stats: Default::default(),
             name: Default::default(),
             access: Default::default(),
             store: Arc::new(store()),
@@ -141,6 +159,18 @@ impl<N: CacheName, A: WasmCacheAccess> Cache<N, A> {
         self.in_memory.read().unwrap().weight()
     }
 
+    /// Get the number of modules fetched from the in-memory cache since it
+    /// was created
+    pub fn get_hits(&self) -> u64 {
+        self.stats.hits.load(Ordering::Relaxed)
+    }
+
+    /// Get the number of modules that were not found in the in-memory cache
+    /// since it was created
+    pub fn get_misses(&self) -> u64 {
+        self.stats.misses.load(Ordering::Relaxed)
+    }
+
     /// Get a WASM module from LRU cache, from a file or compile it and cache
     /// it. Updates the position in the LRU cache.
     fn get(&mut self, hash: &Hash) -> Result<Option<Module>, wasm::run::Error> {
@@ -151,9 +181,11 @@ impl<N: CacheName, A: WasmCacheAccess> Cache<N, A> {
                 N::name(),
                 hash.to_string()
             );
+            self.stats.record(true);
             return Ok(Some(module.clone()));
         }
         drop(in_memory);
+        self.stats.record(false);
 
         let mut iter = 0;
         let exponential_backoff = ExponentialBackoff {
@@ -257,9 +289,11 @@ impl<N: CacheName, A: WasmCacheAccess> Cache<N, A> {
                 N::name(),
                 hash.to_string()
             );
+            self.stats.record(true);
             return Ok(Some(module.clone()));
         }
         drop(in_memory);
+        self.stats.record(false);
 
         let mut iter = 0;
         let exponential_backoff = ExponentialBackoff {
@@ -502,6 +536,7 @@ impl<N: CacheName, A: WasmCacheAccess> Cache<N, A> {
             dir: self.dir.clone(),
             progress: self.progress.clone(),
             in_memory: self.in_memory.clone(),
+            stats: self.stats.clone(),
             name: Default::default(),
             access: Default::default(),
             store: self.store.clone(),
@@ -906,6 +941,25 @@ mod test {
         );
     }
 
+    /// Test that the hits and misses of the in-memory cache are counted and
+    /// shared with its read-only copies.
+    #[test]
+    fn test_cache_hits_and_misses() {
+        let tx_no_op = load_wasm(TestWasms::TxNoOp.path());
+        let (mut cache, _tmp_dir) = cache(10);
+        let mut read_only = cache.read_only();
+
+        let fetched = cache.fetch(&tx_no_op.hash).unwrap();
+        assert!(fetched.is_none());
+        assert_eq!((cache.get_hits(), cache.get_misses()), (0, 1));
+
+        cache.compile_or_fetch(&tx_no_op.code).unwrap().unwrap();
+        cache.fetch(&tx_no_op.hash).unwrap().unwrap();
+        read_only.fetch(&tx_no_op.hash).unwrap().unwrap();
+        assert_eq!((cache.get_hits(), cache.get_misses()), (2, 1));
+        assert_eq!((read_only.get_hits(), read_only.get_misses()), (2, 1));
+    }
+
     #[test]
     fn test_pre_compile_valid_wasm() {
         // Load some WASMs and find their hashes and in-memory size
@@ -15,6 +15,8 @@ use namada_macros::BorshDeserializer;
 use namada_migrations::*;
 use namada_tx::Signed;
 
+use crate::signer::{SignRequest, ValidatorSigner};
+
 /// A vote extension containing a validator's signature
 /// of the current root and nonce of the
 /// Ethereum bridge pool.
@@ -92,6 +94,17 @@ impl Vext {
     pub fn sign(&self, sk: &common::SecretKey) -> SignedVext {
         SignedVext(Signed::new(sk, self.clone()))
     }
+
+    /// Creates a new [`Vext`] signed with the protocol key of a validator's
+    /// `signer`.
+    pub fn sign_with<S: ValidatorSigner>(
+        &self,
+        signer: &S,
+    ) -> Result<SignedVext, S::Error> {
+        let sig =
+            signer.sign(&SignRequest::BridgePoolRootVext(self.clone()))?;
+        Ok(SignedVext(Signed::new_from(self.clone(), sig)))
+    }
 }
 
 /// A collection of validator signatures over the
@@ -15,6 +15,8 @@ use namada_macros::BorshDeserializer;
 use namada_migrations::*;
 use namada_tx::Signed;
 
+use crate::signer::{SignRequest, ValidatorSigner};
+
 /// Type alias for an [`EthereumEventsVext`].
 pub type Vext = EthereumEventsVext;
 
@@ -88,6 +90,16 @@ impl Vext {
     pub fn sign(self, signing_key: &common::SecretKey) -> Signed<Self> {
         Signed::new(signing_key, self)
     }
+
+    /// Sign a [`Vext`] with the protocol key of a validator's `signer`,
+    /// and return the signed data.
+    pub fn sign_with<S: ValidatorSigner>(
+        self,
+        signer: &S,
+    ) -> Result<Signed<Self>, S::Error> {
+        let sig = signer.sign(&SignRequest::EthereumEvents(self.clone()))?;
+        Ok(Signed::new_from(self, sig))
+    }
 }
 
 /// Aggregates an Ethereum event with the corresponding
@@ -19,8 +19,11 @@
 
 pub mod bridge_pool_roots;
 pub mod ethereum_events;
+pub mod signer;
 pub mod validator_set_update;
 
+use std::collections::BTreeMap;
+
 use namada_core::borsh::{
     BorshDeserialize, BorshSchema, BorshSerialize, BorshSerializeExt,
 };
@@ -33,6 +36,8 @@ use namada_tx::data::protocol::{ProtocolTx, ProtocolTxType};
 use namada_tx::data::TxType;
 use namada_tx::{Authorization, Signed, Tx, TxError};
 
+use crate::signer::{SignRequest, ValidatorKeyRole, ValidatorSigner};
+
 /// This type represents the data we pass to the extension of
 /// a vote at the PreCommit phase of Tendermint.
 #[derive(
@@ -172,14 +177,7 @@ impl EthereumTxData {
         signing_key: &common::SecretKey,
         chain_id: ChainId,
     ) -> Tx {
-        let (tx_data, tx_type) = self.serialize();
-        let mut outer_tx =
-            Tx::from_type(TxType::Protocol(Box::new(ProtocolTx {
-                pk: signing_key.to_public(),
-                tx: tx_type,
-            })));
-        outer_tx.header.chain_id = chain_id;
-        outer_tx.set_data(namada_tx::Data::new(tx_data));
+        let mut outer_tx = self.protocol_tx(signing_key.to_public(), chain_id);
         outer_tx.add_section(namada_tx::Section::Authorization(
             Authorization::new(
                 outer_tx.sechashes(),
@@ -190,6 +188,39 @@ impl EthereumTxData {
         outer_tx
     }
 
+    /// Sign transaction Ethereum data with the protocol key of a
+    /// validator's signer and wrap it in a [`Tx`].
+    pub fn sign_with<S: ValidatorSigner>(
+        &self,
+        signer: &S,
+        chain_id: ChainId,
+    ) -> Result<Tx, S::Error> {
+        let pk = signer.public_key(ValidatorKeyRole::Protocol)?;
+        let mut outer_tx = self.protocol_tx(pk.clone(), chain_id);
+        let sig = signer
+            .sign(&SignRequest::ProtocolTx(Box::new(outer_tx.clone())))?;
+        let auth = Authorization {
+            targets: outer_tx.sechashes(),
+            signer: namada_tx::Signer::PubKeys(vec![pk]),
+            signatures: BTreeMap::from([(0, sig)]),
+        };
+        outer_tx.add_section(namada_tx::Section::Authorization(auth));
+        Ok(outer_tx)
+    }
+
+    /// Wrap transaction Ethereum data in an unsigned protocol [`Tx`].
+    fn protocol_tx(&self, pk: common::PublicKey, chain_id: ChainId) -> Tx {
+        let (tx_data, tx_type) = self.serialize();
+        let mut outer_tx =
+            Tx::from_type(TxType::Protocol(Box::new(ProtocolTx {
+                pk,
+                tx: tx_type,
+            })));
+        outer_tx.header.chain_id = chain_id;
+        outer_tx.set_data(namada_tx::Data::new(tx_data));
+        outer_tx
+    }
+
     /// Serialize Ethereum protocol transaction data.
     pub fn serialize(&self) -> (Vec<u8>, ProtocolTxType) {
         macro_rules! match_of_type {
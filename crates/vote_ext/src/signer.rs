@@ -0,0 +1,300 @@
+//! Signing of vote extensions and protocol transactions with a validator's
+//! protocol and Ethereum bridge keys. The keys may either be held by the node
+//! itself or by a remote signer, in which case the node only ever sees the
+//! signatures.
+
+use std::convert::Infallible;
+use std::fmt::Display;
+
+use namada_core::address::Address;
+use namada_core::borsh::{BorshDeserialize, BorshSerialize};
+use namada_core::chain::BlockHeight;
+use namada_core::hash::Hash;
+use namada_core::keccak::{keccak_hash, KeccakHash};
+use namada_core::key::{
+    common, RefTo, SerializeWithBorsh, SigScheme, Signable, SignableEthMessage,
+    VerifySigError,
+};
+use namada_core::uint::Uint;
+use namada_tx::data::TxType;
+use namada_tx::{Authorization, Tx};
+
+use crate::validator_set_update::SerializeWithAbiEncode;
+use crate::{
+    bridge_pool_roots, ethereum_events, validator_set_update, EthereumTxData,
+};
+
+/// The keys of a validator that can be requested to sign data
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    BorshSerialize,
+    BorshDeserialize,
+)]
+pub enum ValidatorKeyRole {
+    /// The protocol key, used to sign vote extensions and protocol txs
+    Protocol,
+    /// The Ethereum bridge hot key, used to sign the bridge pool root and
+    /// validator set updates that are relayed to Ethereum
+    EthBridge,
+}
+
+/// A request to sign some data with one of the keys of a validator. The data
+/// is typed, so that a signer can inspect what it's asked to sign, e.g. to
+/// protect the validator from double signing vote extensions.
+#[derive(Clone, Debug, PartialEq, BorshSerialize, BorshDeserialize)]
+pub enum SignRequest {
+    /// An Ethereum events vote extension, signed with the protocol key
+    EthereumEvents(ethereum_events::Vext),
+    /// A bridge pool root vote extension, signed with the protocol key
+    BridgePoolRootVext(bridge_pool_roots::Vext),
+    /// The bridge pool root and nonce at some block height, whose Keccak
+    /// hash is signed with the Ethereum bridge key
+    BridgePoolRoot {
+        /// The block height of the signed root and nonce
+        block_height: BlockHeight,
+        /// The bridge pool root
+        root: KeccakHash,
+        /// The bridge pool nonce
+        nonce: Uint,
+    },
+    /// A validator set update vote extension, signed with the Ethereum
+    /// bridge key
+    ValidatorSetUpdate(validator_set_update::Vext),
+    /// An unsigned protocol tx, whose hash committing to its sections is
+    /// signed with the protocol key
+    ProtocolTx(Box<Tx>),
+}
+
+impl SignRequest {
+    /// Get the role of the key that signs this request
+    pub fn key_role(&self) -> ValidatorKeyRole {
+        match self {
+            Self::EthereumEvents(_)
+            | Self::BridgePoolRootVext(_)
+            | Self::ProtocolTx(_) => ValidatorKeyRole::Protocol,
+            Self::BridgePoolRoot { .. } | Self::ValidatorSetUpdate(_) => {
+                ValidatorKeyRole::EthBridge
+            }
+        }
+    }
+
+    /// Get the address of the validator that the requested vote extension
+    /// belongs to. Other requests aren't bound to a validator, so we return
+    /// `None` for them.
+    pub fn validator_addr(&self) -> Option<&Address> {
+        match self {
+            Self::EthereumEvents(ext) => Some(&ext.validator_addr),
+            Self::BridgePoolRootVext(ext) => Some(&ext.validator_addr),
+            Self::ValidatorSetUpdate(ext) => Some(&ext.validator_addr),
+            Self::BridgePoolRoot { .. } | Self::ProtocolTx(_) => None,
+        }
+    }
+
+    /// Sign the requested data with the given key. The signatures are the
+    /// same as the ones of the signed vote extensions and protocol txs made
+    /// with the key directly.
+    pub fn sign(&self, key: &common::SecretKey) -> common::Signature {
+        match self {
+            Self::EthereumEvents(ext) => {
+                sign_with::<_, SerializeWithBorsh>(key, ext)
+            }
+            Self::BridgePoolRootVext(ext) => {
+                sign_with::<_, SerializeWithBorsh>(key, ext)
+            }
+            Self::BridgePoolRoot { root, nonce, .. } => {
+                let root_and_nonce = root_and_nonce_hash(root, nonce);
+                sign_with::<_, SignableEthMessage>(key, &root_and_nonce)
+            }
+            Self::ValidatorSetUpdate(ext) => {
+                sign_with::<_, SerializeWithAbiEncode>(key, ext)
+            }
+            Self::ProtocolTx(tx) => {
+                common::SigScheme::sign(key, protocol_tx_sighash(tx))
+            }
+        }
+    }
+
+    /// Verify a signature of this request made by the secret key counterpart
+    /// of the given public key
+    pub fn verify(
+        &self,
+        pk: &common::PublicKey,
+        sig: &common::Signature,
+    ) -> Result<(), VerifySigError> {
+        match self {
+            Self::EthereumEvents(ext) => {
+                verify_with::<_, SerializeWithBorsh>(pk, ext, sig)
+            }
+            Self::BridgePoolRootVext(ext) => {
+                verify_with::<_, SerializeWithBorsh>(pk, ext, sig)
+            }
+            Self::BridgePoolRoot { root, nonce, .. } => {
+                let root_and_nonce = root_and_nonce_hash(root, nonce);
+                verify_with::<_, SignableEthMessage>(pk, &root_and_nonce, sig)
+            }
+            Self::ValidatorSetUpdate(ext) => {
+                verify_with::<_, SerializeWithAbiEncode>(pk, ext, sig)
+            }
+            Self::ProtocolTx(tx) => common::SigScheme::verify_signature(
+                pk,
+                &protocol_tx_sighash(tx),
+                sig,
+            ),
+        }
+    }
+
+    /// Check that a requested protocol tx is one that a validator broadcasts
+    /// by itself: a protocol tx of the validator's protocol key carrying one
+    /// of its vote extensions, which must have been signed with its own keys
+    /// beforehand. Other requests carry typed data that a signer can inspect
+    /// directly, so they are accepted.
+    pub fn check_protocol_tx(
+        &self,
+        validator: &Address,
+        protocol_pk: &common::PublicKey,
+        eth_bridge_pk: &common::PublicKey,
+    ) -> Result<(), String> {
+        let Self::ProtocolTx(tx) = self else {
+            return Ok(());
+        };
+        let TxType::Protocol(protocol_tx) = &tx.header().tx_type else {
+            return Err("Expected a protocol tx".to_string());
+        };
+        if &protocol_tx.pk != protocol_pk {
+            return Err(format!(
+                "The protocol tx must be signed with the protocol key \
+                 {protocol_pk}, got {}",
+                protocol_tx.pk
+            ));
+        }
+        let (validator_addr, verified) =
+            match EthereumTxData::try_from(tx.as_ref())
+                .map_err(|err| err.to_string())?
+            {
+                EthereumTxData::EthEventsVext(ext) => (
+                    ext.0.data.validator_addr.clone(),
+                    ext.0.verify(protocol_pk),
+                ),
+                EthereumTxData::BridgePoolVext(ext) => (
+                    ext.0.data.validator_addr.clone(),
+                    ext.0.verify(protocol_pk),
+                ),
+                EthereumTxData::ValSetUpdateVext(ext) => (
+                    ext.0.data.validator_addr.clone(),
+                    ext.0.verify(eth_bridge_pk),
+                ),
+                _ => {
+                    return Err("Only protocol txs carrying a vote extension \
+                                can be signed"
+                        .to_string());
+                }
+            };
+        if &validator_addr != validator {
+            return Err(format!(
+                "The vote extension of the protocol tx belongs to the \
+                 validator {validator_addr}"
+            ));
+        }
+        verified.map_err(|err| {
+            format!("Invalid signature of the vote extension: {err}")
+        })
+    }
+}
+
+/// The hash committing to the sections of a protocol tx, which its author
+/// signs
+pub fn protocol_tx_sighash(tx: &Tx) -> Hash {
+    Authorization {
+        targets: tx.sechashes(),
+        signer: namada_tx::Signer::PubKeys(vec![]),
+        signatures: Default::default(),
+    }
+    .get_raw_hash()
+}
+
+/// The Keccak hash of a bridge pool root and nonce, as signed by validators
+pub fn root_and_nonce_hash(root: &KeccakHash, nonce: &Uint) -> KeccakHash {
+    keccak_hash([root.0.as_slice(), nonce.to_bytes().as_slice()].concat())
+}
+
+/// Sign data serialized with `S`
+fn sign_with<T, S: Signable<T>>(
+    key: &common::SecretKey,
+    data: &T,
+) -> common::Signature {
+    common::SigScheme::sign_with_hasher::<S::Hasher>(key, S::as_signable(data))
+}
+
+/// Verify a signature of data serialized with `S`
+fn verify_with<T, S: Signable<T>>(
+    pk: &common::PublicKey,
+    data: &T,
+    sig: &common::Signature,
+) -> Result<(), VerifySigError> {
+    common::SigScheme::verify_signature_with_hasher::<S::Hasher>(
+        pk,
+        &S::as_signable(data),
+        sig,
+    )
+}
+
+/// A signer of vote extensions and protocol txs with the keys of a validator
+pub trait ValidatorSigner {
+    /// The error of a failed signing request
+    type Error: Display;
+
+    /// Get the public key of one of the validator's keys
+    fn public_key(
+        &self,
+        role: ValidatorKeyRole,
+    ) -> Result<common::PublicKey, Self::Error>;
+
+    /// Sign the request with the validator's key of its role
+    fn sign(
+        &self,
+        request: &SignRequest,
+    ) -> Result<common::Signature, Self::Error>;
+}
+
+/// A signer that holds the keys of a validator in memory
+#[derive(Clone, Debug)]
+pub struct LocalSigner {
+    /// The protocol key
+    pub protocol_key: common::SecretKey,
+    /// The Ethereum bridge hot key
+    pub eth_bridge_key: common::SecretKey,
+}
+
+impl LocalSigner {
+    /// Get the secret key of the given role
+    pub fn secret_key(&self, role: ValidatorKeyRole) -> &common::SecretKey {
+        match role {
+            ValidatorKeyRole::Protocol => &self.protocol_key,
+            ValidatorKeyRole::EthBridge => &self.eth_bridge_key,
+        }
+    }
+}
+
+impl ValidatorSigner for LocalSigner {
+    type Error = Infallible;
+
+    fn public_key(
+        &self,
+        role: ValidatorKeyRole,
+    ) -> Result<common::PublicKey, Self::Error> {
+        Ok(self.secret_key(role).ref_to())
+    }
+
+    fn sign(
+        &self,
+        request: &SignRequest,
+    ) -> Result<common::Signature, Self::Error> {
+        Ok(request.sign(self.secret_key(request.key_role())))
+    }
+}
@@ -18,6 +18,8 @@ use namada_macros::BorshDeserializer;
 use namada_migrations::*;
 use namada_tx::Signed;
 
+use crate::signer::{SignRequest, ValidatorSigner};
+
 // the contract versions and namespaces plugged into validator set hashes
 // TODO(namada#249): ideally, these values should not be hardcoded
 const BRIDGE_CONTRACT_VERSION: u8 = 1;
@@ -159,6 +161,17 @@ impl Vext {
     pub fn sign(&self, sk: &common::SecretKey) -> SignedVext {
         SignedVext(Signed::new(sk, self.clone()))
     }
+
+    /// Creates a new [`Vext`] signed with the Ethereum bridge key of a
+    /// validator's `signer`.
+    pub fn sign_with<S: ValidatorSigner>(
+        &self,
+        signer: &S,
+    ) -> Result<SignedVext, S::Error> {
+        let sig =
+            signer.sign(&SignRequest::ValidatorSetUpdate(self.clone()))?;
+        Ok(SignedVext(Signed::new_from(self.clone(), sig)))
+    }
 }
 
 /// Container type for both kinds of Ethereum bridge addresses:
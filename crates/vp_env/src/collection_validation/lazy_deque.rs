@@ -0,0 +1,237 @@
+//! LazyDeque validation helpers
+
+use std::fmt::Debug;
+use std::ops::Range;
+
+use namada_core::arith::checked;
+use namada_core::borsh::{BorshDeserialize, BorshSerialize};
+use namada_core::storage;
+use namada_storage::collections::lazy_deque::{
+    Index, LazyDeque, SubKey, ValidationError,
+};
+use namada_storage::collections::LazyCollection;
+use namada_storage::ResultExt;
+
+use super::{read_data, Data, LazyCollectionExt, ValidationBuilder};
+use crate::VpEnv;
+
+/// Possible sub-keys of a [`LazyDeque`], together with their [`Data`]
+/// that contains prior and posterior state.
+#[derive(Debug)]
+pub enum SubKeyWithData<T> {
+    /// The front and back index sub-keys, which are read together in both
+    /// prior and posterior state even if they haven't changed
+    Bounds {
+        /// Prior front and back indices
+        pre: (Option<Index>, Option<Index>),
+        /// Posterior front and back indices
+        post: (Option<Index>, Option<Index>),
+    },
+    /// Data sub-key, further sub-keyed by its storage index
+    Data(Index, Data<T>),
+}
+
+/// Possible actions that can modify a [`LazyDeque`]. This roughly corresponds
+/// to the methods that have `StorageWrite` access.
+#[derive(Clone, Debug)]
+pub enum Action<T> {
+    /// Push a value `T` to the front of a [`LazyDeque<T>`]
+    PushFront(T),
+    /// Push a value `T` to the back of a [`LazyDeque<T>`]
+    PushBack(T),
+    /// Pop a value `T` from the front of a [`LazyDeque<T>`]
+    PopFront(T),
+    /// Pop a value `T` from the back of a [`LazyDeque<T>`]
+    PopBack(T),
+    /// Update a value `T` at a storage index from pre to post state in a
+    /// [`LazyDeque<T>`]
+    Update {
+        /// storage index at which the value is updated
+        index: Index,
+        /// value before the update
+        pre: T,
+        /// value after the update
+        post: T,
+    },
+}
+
+impl<T> LazyCollectionExt for LazyDeque<T>
+where
+    T: BorshSerialize + BorshDeserialize + 'static + Debug,
+{
+    type Action = Action<T>;
+    type SubKeyWithData = SubKeyWithData<T>;
+
+    /// Only reads the data sub-keys, the bounds are read by `accumulate`
+    fn read_sub_key_data<ENV>(
+        env: &ENV,
+        storage_key: &storage::Key,
+        sub_key: Self::SubKey,
+    ) -> namada_storage::Result<Option<Self::SubKeyWithData>>
+    where
+        ENV: for<'a> VpEnv<'a>,
+    {
+        let change = match sub_key {
+            SubKey::Front | SubKey::Back => None,
+            SubKey::Data(index) => {
+                let data = read_data(env, storage_key)?;
+                data.map(|data| SubKeyWithData::Data(index, data))
+            }
+        };
+        Ok(change)
+    }
+
+    /// The validation rules for a [`LazyDeque`] are:
+    ///   - The front and back indices must be both present or both absent and
+    ///     an empty deque must be deleted from storage.
+    ///   - Elements can only be added at the indices that are within the
+    ///     posterior bounds, but outside of the prior bounds and vice versa for
+    ///     removed elements, and every such index must have been changed.
+    ///   - Updated elements must be within both the prior and posterior bounds.
+    fn validate_changed_sub_keys(
+        keys: Vec<Self::SubKeyWithData>,
+    ) -> namada_storage::Result<Vec<Self::Action>> {
+        let mut actions = vec![];
+        let mut pre = 0..0;
+        let mut post = 0..0;
+        let mut added: u64 = 0;
+        let mut removed: u64 = 0;
+
+        for key in keys {
+            match key {
+                SubKeyWithData::Bounds {
+                    pre: pre_bounds,
+                    post: post_bounds,
+                } => {
+                    pre = bounds_range(pre_bounds)?;
+                    post = bounds_range(post_bounds)?;
+                }
+                SubKeyWithData::Data(index, data) => {
+                    actions.push((index, data));
+                }
+            }
+        }
+
+        let actions = actions
+            .into_iter()
+            .map(|(index, data)| match data {
+                Data::Add { post: val } => {
+                    if !post.contains(&index) || pre.contains(&index) {
+                        return Err(ValidationError::UnexpectedPushIndex(
+                            index,
+                        ))
+                        .into_storage_result();
+                    }
+                    added = checked!(added + 1)?;
+                    // Elements pushed into an empty deque are treated as
+                    // pushed to the back
+                    if !pre.is_empty() && index < pre.start {
+                        Ok(Action::PushFront(val))
+                    } else {
+                        Ok(Action::PushBack(val))
+                    }
+                }
+                Data::Update {
+                    pre: pre_val,
+                    post: post_val,
+                } => {
+                    if !post.contains(&index) || !pre.contains(&index) {
+                        return Err(ValidationError::UnexpectedUpdateIndex(
+                            index,
+                        ))
+                        .into_storage_result();
+                    }
+                    Ok(Action::Update {
+                        index,
+                        pre: pre_val,
+                        post: post_val,
+                    })
+                }
+                Data::Delete { pre: val } => {
+                    if !pre.contains(&index) || post.contains(&index) {
+                        return Err(ValidationError::UnexpectedPopIndex(index))
+                            .into_storage_result();
+                    }
+                    removed = checked!(removed + 1)?;
+                    if !post.is_empty() && index >= post.end {
+                        Ok(Action::PopBack(val))
+                    } else {
+                        Ok(Action::PopFront(val))
+                    }
+                }
+            })
+            .collect::<namada_storage::Result<Vec<_>>>()?;
+
+        // Every index that was added to or removed from the bounds must have
+        // been changed
+        let common =
+            range_len(pre.start.max(post.start)..pre.end.min(post.end))?;
+        let expected_added = checked!(range_len(post.clone())? - common)?;
+        let expected_removed = checked!(range_len(pre)? - common)?;
+        if added != expected_added || removed != expected_removed {
+            return Err(ValidationError::InvalidBoundsDiff)
+                .into_storage_result();
+        }
+
+        Ok(actions)
+    }
+
+    /// Accumulate storage changes. The bounds of the deque are read once, when
+    /// the builder is created, so that the data changes are always validated
+    /// against them.
+    fn accumulate<ENV>(
+        &self,
+        env: &ENV,
+        builder: &mut Option<ValidationBuilder<Self::SubKeyWithData>>,
+        key_changed: &storage::Key,
+    ) -> namada_storage::Result<bool>
+    where
+        ENV: for<'a> VpEnv<'a>,
+    {
+        let Some(sub) = self.is_valid_sub_key(key_changed)? else {
+            return Ok(false);
+        };
+        let is_bounds_key = matches!(sub, SubKey::Front | SubKey::Back);
+        let change = Self::read_sub_key_data(env, key_changed, sub)?;
+        if is_bounds_key || change.is_some() {
+            let is_new_builder = builder.is_none();
+            let builder =
+                builder.get_or_insert_with(ValidationBuilder::default);
+            if is_new_builder {
+                let front_key = self.get_front_key();
+                let back_key = self.get_back_key();
+                builder.changes.push(SubKeyWithData::Bounds {
+                    pre: (env.read_pre(&front_key)?, env.read_pre(&back_key)?),
+                    post: (
+                        env.read_post(&front_key)?,
+                        env.read_post(&back_key)?,
+                    ),
+                });
+            }
+            builder.changes.extend(change);
+        }
+        Ok(true)
+    }
+}
+
+/// Get the range of storage indices of a deque from its front and back
+fn bounds_range(
+    (front, back): (Option<Index>, Option<Index>),
+) -> namada_storage::Result<Range<Index>> {
+    match (front, back) {
+        (None, None) => Ok(0..0),
+        (Some(front), Some(back)) if front < back => Ok(front..back),
+        (Some(_), Some(_)) => Err(ValidationError::EmptyDequeShouldBeDeleted)
+            .into_storage_result(),
+        _ => Err(ValidationError::InvalidBounds).into_storage_result(),
+    }
+}
+
+/// Get the number of indices in a range
+fn range_len(range: Range<Index>) -> namada_storage::Result<u64> {
+    if range.is_empty() {
+        Ok(0)
+    } else {
+        Ok(checked!(range.end - range.start)?)
+    }
+}
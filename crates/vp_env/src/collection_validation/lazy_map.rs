@@ -3,13 +3,17 @@
 use core::fmt::Debug;
 use core::hash::Hash;
 
+use namada_core::arith::checked;
 use namada_core::borsh::{BorshDeserialize, BorshSerialize};
 use namada_core::collections::HashMap;
 use namada_core::storage;
-use namada_storage::collections::lazy_map::{LazyMap, NestedSubKey, SubKey};
-use namada_storage::collections::{Nested, Simple};
+use namada_storage::collections::lazy_map::{
+    LazyMap, NestedSubKey, SubKey, ValidationError,
+};
+use namada_storage::collections::{LazyCollection, Nested, Simple};
+use namada_storage::ResultExt;
 
-use super::{read_data, Data, LazyCollectionExt};
+use super::{read_data, Data, LazyCollectionExt, ValidationBuilder};
 use crate::VpEnv;
 
 /// Possible sub-keys of a [`LazyMap`], together with their [`Data`]
@@ -18,6 +22,9 @@ use crate::VpEnv;
 pub enum SubKeyWithData<K, V> {
     /// Data sub-key, further sub-keyed by its literal map key
     Data(K, Data<V>),
+    /// Length sub-key of a map opened with a length counter. The data is
+    /// `None` when the length is absent in both prior and posterior state.
+    Len(Option<Data<u64>>),
 }
 
 /// Possible actions that can modify a simple (not nested) [`LazyMap`]. This
@@ -137,21 +144,95 @@ where
         Ok(data.map(|data| SubKeyWithData::Data(key, data)))
     }
 
+    /// The validation rules for a [`LazyMap`] opened with a length counter
+    /// are:
+    ///   - A difference in the map's length must correspond to the difference
+    ///     in how many elements were inserted versus how many elements were
+    ///     removed.
+    ///   - The length of an empty map must be deleted from storage
     fn validate_changed_sub_keys(
         keys: Vec<Self::SubKeyWithData>,
     ) -> namada_storage::Result<Vec<Self::Action>> {
-        Ok(keys
-            .into_iter()
-            .map(|change| {
-                let SubKeyWithData::Data(key, data) = change;
-                match data {
-                    Data::Add { post } => Action::Insert(key, post),
+        let mut actions = vec![];
+        let mut len_change = None;
+        let mut inserted: u64 = 0;
+        let mut removed: u64 = 0;
+
+        for change in keys {
+            match change {
+                SubKeyWithData::Len(data) => len_change = Some(data),
+                SubKeyWithData::Data(key, data) => match data {
+                    Data::Add { post } => {
+                        actions.push(Action::Insert(key, post));
+                        inserted = checked!(inserted + 1)?;
+                    }
                     Data::Update { pre, post } => {
-                        Action::Update { key, pre, post }
+                        actions.push(Action::Update { key, pre, post });
                     }
-                    Data::Delete { pre } => Action::Remove(key, pre),
-                }
-            })
-            .collect())
+                    Data::Delete { pre } => {
+                        actions.push(Action::Remove(key, pre));
+                        removed = checked!(removed + 1)?;
+                    }
+                },
+            }
+        }
+
+        // Only the maps opened with a length counter have a length change
+        if let Some(len_data) = len_change {
+            let is_len_written = matches!(
+                len_data,
+                Some(Data::Add { .. } | Data::Update { .. })
+            );
+            let (len_pre, len_post) = match len_data {
+                None => (0, 0),
+                Some(Data::Add { post }) => (0, post),
+                Some(Data::Update { pre, post }) => (pre, post),
+                Some(Data::Delete { pre }) => (pre, 0),
+            };
+            if is_len_written && len_post == 0 {
+                return Err(ValidationError::EmptyMapLenShouldBeDeleted)
+                    .into_storage_result();
+            }
+            if checked!(len_pre + inserted)? != checked!(len_post + removed)? {
+                return Err(ValidationError::InvalidLenDiff)
+                    .into_storage_result();
+            }
+        }
+
+        Ok(actions)
+    }
+
+    /// Accumulate storage changes, including the length of a map opened with
+    /// a length counter. The length is read once, when the builder is created,
+    /// so that it's validated even when it hasn't been changed.
+    fn accumulate<ENV>(
+        &self,
+        env: &ENV,
+        builder: &mut Option<ValidationBuilder<Self::SubKeyWithData>>,
+        key_changed: &storage::Key,
+    ) -> namada_storage::Result<bool>
+    where
+        ENV: for<'a> VpEnv<'a>,
+    {
+        let len_key = self.get_len_key();
+        let is_len_key = len_key.as_ref() == Some(key_changed);
+        let change = if is_len_key {
+            None
+        } else if let Some(sub) = self.is_valid_sub_key(key_changed)? {
+            Self::read_sub_key_data(env, key_changed, sub)?
+        } else {
+            return Ok(false);
+        };
+        if is_len_key || change.is_some() {
+            let is_new_builder = builder.is_none();
+            let builder =
+                builder.get_or_insert_with(ValidationBuilder::default);
+            if let Some(len_key) = len_key.filter(|_| is_new_builder) {
+                let len_data = read_data(env, &len_key)?;
+                builder.changes.push(SubKeyWithData::Len(len_data));
+            }
+            builder.changes.extend(change);
+        }
+        Ok(true)
     }
 }
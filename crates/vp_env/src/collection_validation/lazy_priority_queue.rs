@@ -0,0 +1,162 @@
+//! LazyPriorityQueue validation helpers
+
+use std::fmt::Debug;
+
+use namada_core::arith::checked;
+use namada_core::borsh::{BorshDeserialize, BorshSerialize};
+use namada_core::storage;
+use namada_storage::collections::lazy_priority_queue::{
+    LazyPriorityQueue, Seq, SubKey, ValidationError,
+};
+use namada_storage::collections::LazyCollection;
+use namada_storage::ResultExt;
+
+use super::{read_data, Data, LazyCollectionExt, ValidationBuilder};
+use crate::VpEnv;
+
+/// Possible sub-keys of a [`LazyPriorityQueue`], together with their
+/// [`Data`] that contains prior and posterior state.
+#[derive(Debug)]
+pub enum SubKeyWithData<K, V> {
+    /// The sequence number sub-key, which is read in both prior and
+    /// posterior state even if it hasn't changed
+    Seq {
+        /// Prior sequence number of the next pushed element
+        pre: Seq,
+        /// Posterior sequence number of the next pushed element
+        post: Seq,
+    },
+    /// Data sub-key, further sub-keyed by its priority key and sequence
+    /// number
+    Data(K, Seq, Data<V>),
+}
+
+/// Possible actions that can modify a [`LazyPriorityQueue`]. This roughly
+/// corresponds to the methods that have `StorageWrite` access.
+#[derive(Clone, Debug)]
+pub enum Action<K, V> {
+    /// Push a value `V` with a priority key `K` into a
+    /// [`LazyPriorityQueue<K, V>`]
+    Push(K, V),
+    /// Pop a value `V` with a priority key `K` from a
+    /// [`LazyPriorityQueue<K, V>`]
+    Pop(K, V),
+}
+
+impl<K, V> LazyCollectionExt for LazyPriorityQueue<K, V>
+where
+    K: storage::KeySeg + Debug,
+    V: BorshSerialize + BorshDeserialize + 'static + Debug,
+{
+    type Action = Action<K, V>;
+    type SubKeyWithData = SubKeyWithData<K, V>;
+
+    /// Only reads the data sub-keys, the sequence number is read by
+    /// `accumulate`
+    fn read_sub_key_data<ENV>(
+        env: &ENV,
+        storage_key: &storage::Key,
+        sub_key: Self::SubKey,
+    ) -> namada_storage::Result<Option<Self::SubKeyWithData>>
+    where
+        ENV: for<'a> VpEnv<'a>,
+    {
+        let change = match sub_key {
+            SubKey::Seq => None,
+            SubKey::Data(key, seq) => {
+                let data = read_data(env, storage_key)?;
+                data.map(|data| SubKeyWithData::Data(key, seq, data))
+            }
+        };
+        Ok(change)
+    }
+
+    /// The validation rules for a [`LazyPriorityQueue`] are:
+    ///   - The sequence number must not decrease and every sequence number in
+    ///     between its prior and posterior value must have been used by a
+    ///     pushed element.
+    ///   - Elements can only be pushed with a sequence number that is within
+    ///     the prior and posterior value.
+    ///   - The elements in the queue cannot be updated.
+    fn validate_changed_sub_keys(
+        keys: Vec<Self::SubKeyWithData>,
+    ) -> namada_storage::Result<Vec<Self::Action>> {
+        let mut data = vec![];
+        let mut seqs = 0..0;
+        for key in keys {
+            match key {
+                SubKeyWithData::Seq { pre, post } => {
+                    if post < pre {
+                        return Err(ValidationError::InvalidSeqDiff)
+                            .into_storage_result();
+                    }
+                    seqs = pre..post;
+                }
+                SubKeyWithData::Data(key, seq, change) => {
+                    data.push((key, seq, change));
+                }
+            }
+        }
+
+        let mut pushed: u64 = 0;
+        let mut actions = Vec::with_capacity(data.len());
+        for (key, seq, change) in data {
+            match change {
+                Data::Add { post } => {
+                    if !seqs.contains(&seq) {
+                        return Err(ValidationError::UnexpectedPushSeq(seq))
+                            .into_storage_result();
+                    }
+                    pushed = checked!(pushed + 1)?;
+                    actions.push(Action::Push(key, post));
+                }
+                Data::Update { .. } => {
+                    return Err(ValidationError::UnexpectedUpdate(seq))
+                        .into_storage_result();
+                }
+                Data::Delete { pre } => {
+                    actions.push(Action::Pop(key, pre));
+                }
+            }
+        }
+
+        if pushed != checked!(seqs.end - seqs.start)? {
+            return Err(ValidationError::InvalidSeqDiff).into_storage_result();
+        }
+
+        Ok(actions)
+    }
+
+    /// Accumulate storage changes. The sequence number of the queue is read
+    /// once, when the builder is created, so that the pushed elements are
+    /// always validated against it.
+    fn accumulate<ENV>(
+        &self,
+        env: &ENV,
+        builder: &mut Option<ValidationBuilder<Self::SubKeyWithData>>,
+        key_changed: &storage::Key,
+    ) -> namada_storage::Result<bool>
+    where
+        ENV: for<'a> VpEnv<'a>,
+    {
+        let Some(sub) = self.is_valid_sub_key(key_changed)? else {
+            return Ok(false);
+        };
+        let is_seq_key = matches!(sub, SubKey::Seq);
+        let change = Self::read_sub_key_data(env, key_changed, sub)?;
+        if is_seq_key || change.is_some() {
+            let is_new_builder = builder.is_none();
+            let builder =
+                builder.get_or_insert_with(ValidationBuilder::default);
+            if is_new_builder {
+                let seq_key = self.get_seq_key();
+                builder.changes.push(SubKeyWithData::Seq {
+                    pre: env.read_pre(&seq_key)?.unwrap_or_default(),
+                    post: env.read_post(&seq_key)?.unwrap_or_default(),
+                });
+            }
+            builder.changes.extend(change);
+        }
+        Ok(true)
+    }
+}
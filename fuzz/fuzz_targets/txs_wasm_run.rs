@@ -18,7 +18,7 @@ use namada_node::shims::abcipp_shim_types::shim::request::{
 use namada_node::shims::abcipp_shim_types::shim::response::TxResult;
 use namada_node::shims::abcipp_shim_types::shim::TxBytes;
 use namada_sdk::address::Address;
-use namada_sdk::eth_bridge_pool::PendingTransfer;
+use namada_sdk::eth_bridge_pool::{BridgePoolChange, PendingTransfer};
 use namada_sdk::ibc::apps::nft_transfer::types::msgs::transfer::MsgTransfer as IbcMsgNftTransfer;
 use namada_sdk::ibc::apps::transfer::types::msgs::transfer::MsgTransfer as IbcMsgTransfer;
 use namada_sdk::ibc::core::handler::types::msgs::MsgEnvelope;
@@ -161,8 +161,11 @@ fn run(kinds: NonEmptyVec<TxKind>) {
                 tx.add_data(data);
                 tx::TX_CHANGE_METADATA_WASM
             }
-            BridgePool(data) => {
-                tx.add_data(data);
+            BridgePool(transfer) => {
+                tx.add_data(BridgePoolChange::AddTransfer {
+                    transfer,
+                    expiry: None,
+                });
                 tx::TX_BRIDGE_POOL_WASM
             }
             ResignSteward(data) => {
@@ -0,0 +1,42 @@
+//! A tx to amend the content of a governance proposal before its voting start
+//! epoch.
+
+use namada_tx_prelude::action::{Action, GovAction, Write};
+use namada_tx_prelude::governance::event::GovernanceEvent;
+use namada_tx_prelude::*;
+
+#[transaction]
+fn apply_tx(ctx: &mut Ctx, tx_data: BatchedTx) -> TxResult {
+    let data = ctx.get_tx_data(&tx_data)?;
+    let BatchedTx { tx, cmt: _ } = tx_data;
+    let tx_data = governance::AmendProposalData::try_from_slice(&data[..])
+        .wrap_err("Failed to decode AmendProposalData value")?;
+
+    // The tx must be authorized by the author address
+    ctx.insert_verifier(&tx_data.author)?;
+
+    ctx.push_action(Action::Gov(GovAction::AmendProposal {
+        id: tx_data.id,
+        author: tx_data.author.clone(),
+    }))?;
+
+    // Get the content from the referred to section
+    let content = tx
+        .get_section(&tx_data.content)
+        .ok_or_err_msg("Missing proposal content")
+        .inspect_err(|_| {
+            ctx.set_commitment_sentinel();
+        })?
+        .extra_data()
+        .ok_or_err_msg("Missing full proposal content")
+        .inspect_err(|_| {
+            ctx.set_commitment_sentinel();
+        })?;
+
+    debug_log!("apply_tx called to amend a governance proposal");
+
+    governance::amend_proposal(ctx, &tx_data, content)
+        .wrap_err("Failed to amend the governance proposal")?;
+
+    ctx.emit_event(GovernanceEvent::amended_proposal(tx_data.id))
+}
@@ -0,0 +1,27 @@
+//! A tx to cancel a governance proposal before its voting start epoch.
+
+use namada_tx_prelude::action::{Action, GovAction, Write};
+use namada_tx_prelude::governance::event::GovernanceEvent;
+use namada_tx_prelude::*;
+
+#[transaction]
+fn apply_tx(ctx: &mut Ctx, tx_data: BatchedTx) -> TxResult {
+    let data = ctx.get_tx_data(&tx_data)?;
+    let tx_data = governance::CancelProposalData::try_from_slice(&data[..])
+        .wrap_err("Failed to decode CancelProposalData value")?;
+
+    // The tx must be authorized by the author address
+    ctx.insert_verifier(&tx_data.author)?;
+
+    ctx.push_action(Action::Gov(GovAction::CancelProposal {
+        id: tx_data.id,
+        author: tx_data.author.clone(),
+    }))?;
+
+    debug_log!("apply_tx called to cancel a governance proposal");
+
+    governance::cancel_proposal(ctx, &tx_data)
+        .wrap_err("Failed to cancel the governance proposal")?;
+
+    ctx.emit_event(GovernanceEvent::cancelled_proposal(tx_data.id))
+}
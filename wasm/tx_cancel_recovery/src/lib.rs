@@ -0,0 +1,12 @@
+//! A tx for an account to cancel the pending recovery of its keys.
+
+use namada_tx_prelude::*;
+
+#[transaction]
+fn apply_tx(ctx: &mut Ctx, tx_data: BatchedTx) -> TxResult {
+    let data = ctx.get_tx_data(&tx_data)?;
+    let owner = Address::try_from_slice(&data[..])
+        .wrap_err("Failed to decode the address of the recovered account")?;
+    account::cancel_recovery(ctx, &owner)
+        .wrap_err("Failed to cancel the account's social recovery")
+}
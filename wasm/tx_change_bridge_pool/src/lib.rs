@@ -1,17 +1,37 @@
 //! A tx for adding a transfer request across the Ethereum bridge
-//! into the bridge pool.
+//! into the bridge pool, or for bumping the relayer fee of a
+//! transfer in the pool. The data of older txs, which only held the
+//! transfer to add, is still accepted.
+use namada_tx_prelude::eth_abi::Encode;
 use namada_tx_prelude::eth_bridge_pool::{
-    get_pending_key, GasFee, PendingTransfer, TransferToEthereum,
+    get_expiry_key, get_fee_bump_key, get_key_from_hash, get_pending_key,
+    BridgePoolChange, GasFee, PendingTransfer, TransferToEthereum,
     BRIDGE_POOL_ADDRESS,
 };
+use namada_tx_prelude::keccak::KeccakHash;
 use namada_tx_prelude::parameters::native_erc20_key;
 use namada_tx_prelude::*;
 
 #[transaction]
 fn apply_tx(ctx: &mut Ctx, tx_data: BatchedTx) -> TxResult {
     let data = ctx.get_tx_data(&tx_data)?;
-    let transfer = PendingTransfer::try_from_slice(&data[..])
-        .map_err(|e| Error::wrap("Error deserializing PendingTransfer", e))?;
+    let change = BridgePoolChange::try_from_slice(&data[..])
+        .map_err(|e| Error::wrap("Error deserializing BridgePoolChange", e))?;
+    match change {
+        BridgePoolChange::AddTransfer { transfer, expiry } => {
+            add_transfer(ctx, transfer, expiry)
+        }
+        BridgePoolChange::BumpFee { transfer, amount } => {
+            bump_fee(ctx, transfer, amount)
+        }
+    }
+}
+
+fn add_transfer(
+    ctx: &mut Ctx,
+    transfer: PendingTransfer,
+    expiry: Option<BlockHeight>,
+) -> TxResult {
     debug_log!("Received transfer to add to Bridge pool");
     // pay the gas fees
     let GasFee {
@@ -37,6 +57,11 @@ fn apply_tx(ctx: &mut Ctx, tx_data: BatchedTx) -> TxResult {
         token::transfer(ctx, sender, &BRIDGE_POOL_ADDRESS, &token, amount)?;
     }
     debug_log!("Bridge pool escrow succeeded");
+    // set the expiry height of the transfer
+    if let Some(expiry) = expiry {
+        ctx.write(&get_expiry_key(&transfer.keccak256()), expiry)
+            .wrap_err("Could not write the expiry height of the transfer")?;
+    }
     // add transfer into the pool
     let pending_key = get_pending_key(&transfer);
     ctx.write(&pending_key, transfer)
@@ -44,6 +69,35 @@ fn apply_tx(ctx: &mut Ctx, tx_data: BatchedTx) -> TxResult {
     Ok(())
 }
 
+fn bump_fee(
+    ctx: &mut Ctx,
+    transfer_hash: KeccakHash,
+    amount: token::Amount,
+) -> TxResult {
+    debug_log!("Received a relayer fee bump of Bridge pool transfer");
+    let transfer: PendingTransfer = ctx
+        .read(&get_key_from_hash(&transfer_hash))
+        .wrap_err("Could not read the pending transfer")?
+        .ok_or_err_msg("The transfer must be pending in the Bridge pool")?;
+    // pay the fee bump
+    let GasFee {
+        ref token, payer, ..
+    } = transfer.gas_fee;
+    token::transfer(ctx, &payer, &BRIDGE_POOL_ADDRESS, token, amount)?;
+    debug_log!("Bridge pool fee bump transfer succeeded");
+    // add it to the previous fee bumps of the transfer
+    let fee_bump_key = get_fee_bump_key(&transfer_hash);
+    let fee_bump = ctx
+        .read::<token::Amount>(&fee_bump_key)
+        .wrap_err("Could not read the fee bump of the transfer")?
+        .unwrap_or_default()
+        .checked_add(amount)
+        .ok_or_err_msg("The fee bump of the transfer overflowed")?;
+    ctx.write(&fee_bump_key, fee_bump)
+        .wrap_err("Could not write the fee bump of the transfer")?;
+    Ok(())
+}
+
 fn native_erc20_address(ctx: &mut Ctx) -> Result<EthAddress> {
     debug_log!("Trying to get wnam key for Bridge pool transfer");
     let addr = ctx
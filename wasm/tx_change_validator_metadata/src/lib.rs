@@ -1,6 +1,7 @@
 //! A tx for a validator to change various metadata, including its commission
 //! rate.
 
+use namada_tx_prelude::proof_of_stake::types::RewardDestination;
 use namada_tx_prelude::transaction::pos::MetaDataChange;
 use namada_tx_prelude::*;
 
@@ -15,6 +16,7 @@ fn apply_tx(ctx: &mut Ctx, tx_data: BatchedTx) -> TxResult {
         discord_handle,
         avatar,
         name,
+        reward_destination,
         commission_rate,
     } = transaction::pos::MetaDataChange::try_from_slice(&data[..])
         .wrap_err("Failed to decode MetaDataChange value")?;
@@ -26,6 +28,7 @@ fn apply_tx(ctx: &mut Ctx, tx_data: BatchedTx) -> TxResult {
         discord_handle,
         avatar,
         name,
+        reward_destination.map(RewardDestination),
         commission_rate,
     )
     .wrap_err("Failed to update validator's metadata")
@@ -0,0 +1,15 @@
+//! A tx to opt in to or out of the social recovery of an account.
+//! This tx uses `account::ConfigureRecovery` as its input.
+
+use namada_tx_prelude::*;
+
+#[transaction]
+fn apply_tx(ctx: &mut Ctx, tx_data: BatchedTx) -> TxResult {
+    let data = ctx.get_tx_data(&tx_data)?;
+    let tx_data = account::ConfigureRecovery::try_from_slice(&data[..])
+        .wrap_err("Failed to decode ConfigureRecovery tx data")?;
+    debug_log!("apply_tx called to configure recovery: {:#?}", tx_data);
+
+    account::configure_recovery(ctx, tx_data)
+        .wrap_err("Failed to configure the account's social recovery")
+}
@@ -0,0 +1,24 @@
+//! A tx to delegate the governance votes of an address to a representative,
+//! or to revoke the delegation.
+
+use namada_tx_prelude::action::{Action, GovAction, Write};
+use namada_tx_prelude::*;
+
+#[transaction]
+fn apply_tx(ctx: &mut Ctx, tx_data: BatchedTx) -> TxResult {
+    let data = ctx.get_tx_data(&tx_data)?;
+    let tx_data = governance::DelegateVotesData::try_from_slice(&data[..])
+        .wrap_err("Failed to decode DelegateVotesData value")?;
+
+    // The tx must be authorized by the delegator
+    ctx.insert_verifier(&tx_data.delegator)?;
+
+    ctx.push_action(Action::Gov(GovAction::DelegateVotes {
+        delegator: tx_data.delegator.clone(),
+    }))?;
+
+    debug_log!("apply_tx called to delegate governance votes");
+
+    governance::storage::delegate_votes(ctx, tx_data)
+        .wrap_err("Failed to delegate governance votes")
+}
@@ -0,0 +1,24 @@
+//! A tx to top up the deposit of a governance proposal.
+
+use namada_tx_prelude::action::{Action, GovAction, Write};
+use namada_tx_prelude::*;
+
+#[transaction]
+fn apply_tx(ctx: &mut Ctx, tx_data: BatchedTx) -> TxResult {
+    let data = ctx.get_tx_data(&tx_data)?;
+    let tx_data = governance::DepositProposalData::try_from_slice(&data[..])
+        .wrap_err("Failed to decode DepositProposalData value")?;
+
+    // The tx must be authorized by the depositor
+    ctx.insert_verifier(&tx_data.depositor)?;
+
+    ctx.push_action(Action::Gov(GovAction::DepositProposal {
+        id: tx_data.id,
+        depositor: tx_data.depositor.clone(),
+    }))?;
+
+    debug_log!("apply_tx called to deposit funds to a governance proposal");
+
+    governance::deposit_proposal::<_, token::Store<_>>(ctx, &tx_data)
+        .wrap_err("Failed to deposit funds to the governance proposal")
+}
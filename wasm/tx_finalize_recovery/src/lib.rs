@@ -0,0 +1,13 @@
+//! A tx to finalize the pending recovery of the keys of an account, once its
+//! delay has elapsed. Anyone can submit this tx.
+
+use namada_tx_prelude::*;
+
+#[transaction]
+fn apply_tx(ctx: &mut Ctx, tx_data: BatchedTx) -> TxResult {
+    let data = ctx.get_tx_data(&tx_data)?;
+    let owner = Address::try_from_slice(&data[..])
+        .wrap_err("Failed to decode the address of the recovered account")?;
+    account::finalize_recovery(ctx, &owner)
+        .wrap_err("Failed to finalize the account's social recovery")
+}
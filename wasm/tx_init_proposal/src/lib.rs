@@ -49,10 +49,30 @@ fn apply_tx(ctx: &mut Ctx, tx_data: BatchedTx) -> TxResult {
         .transpose()
         .wrap_err("Failed to retrieve proposal code")?;
 
+    // Get the codes of the steps of a batch proposal from the referred to
+    // sections
+    let step_codes = tx_data
+        .get_section_step_code_hashes()
+        .into_iter()
+        .map(|hash| {
+            tx.get_section(&hash)
+                .ok_or_err_msg("Missing proposal step code")
+                .inspect_err(|_| {
+                    ctx.set_commitment_sentinel();
+                })?
+                .extra_data()
+                .ok_or_err_msg("Missing full proposal step code")
+                .inspect_err(|_| {
+                    ctx.set_commitment_sentinel();
+                })
+        })
+        .collect::<Result<Vec<_>>>()
+        .wrap_err("Failed to retrieve proposal step codes")?;
+
     log_string("apply_tx called to create a new governance proposal");
 
     let proposal_id = governance::init_proposal::<_, token::Store<_>>(
-        ctx, &tx_data, content, code,
+        ctx, &tx_data, content, code, step_codes,
     )
     .wrap_err("Failed to initialize new governance proposal")?;
 
@@ -0,0 +1,15 @@
+//! A tx for the guardians of an account to initiate the recovery of its keys.
+//! This tx uses `account::InitiateRecovery` as its input.
+
+use namada_tx_prelude::*;
+
+#[transaction]
+fn apply_tx(ctx: &mut Ctx, tx_data: BatchedTx) -> TxResult {
+    let data = ctx.get_tx_data(&tx_data)?;
+    let tx_data = account::InitiateRecovery::try_from_slice(&data[..])
+        .wrap_err("Failed to decode InitiateRecovery tx data")?;
+    debug_log!("apply_tx called to initiate recovery: {:#?}", tx_data);
+
+    account::initiate_recovery(ctx, tx_data)
+        .wrap_err("Failed to initiate the account's social recovery")
+}
@@ -0,0 +1,126 @@
+//! A tx for a user to bond tokens to the liquid staking pool of a validator
+//! in exchange for transferable shares of the pool.
+
+use namada_tx_prelude::*;
+
+#[transaction]
+fn apply_tx(ctx: &mut Ctx, tx_data: BatchedTx) -> TxResult {
+    let data = ctx.get_tx_data(&tx_data)?;
+    let bond = transaction::pos::LiquidBond::try_from_slice(&data[..])
+        .wrap_err("Failed to decode LiquidBond tx data")?;
+
+    ctx.liquid_bond_tokens(&bond.source, &bond.validator, bond.amount)
+        .wrap_err("Failed to liquid bond tokens")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use namada_tests::log::test;
+    use namada_tests::native_vp::pos::init_pos;
+    use namada_tests::native_vp::TestNativeVpEnv;
+    use namada_tests::tx::*;
+    use namada_tests::validation::PosVp;
+    use namada_tx_prelude::address::testing::{
+        established_address_1, established_address_2,
+    };
+    use namada_tx_prelude::address::InternalAddress;
+    use namada_tx_prelude::chain::ChainId;
+    use namada_tx_prelude::dec::Dec;
+    use namada_tx_prelude::gas::VpGasMeter;
+    use namada_tx_prelude::key::RefTo;
+    use namada_tx_prelude::proof_of_stake::parameters::OwnedPosParams;
+    use namada_tx_prelude::proof_of_stake::types::GenesisValidator;
+
+    use super::*;
+
+    /// A liquid bond is accepted by the PoS VP, unless the tx mints more
+    /// shares than the bonded tokens are worth.
+    #[test]
+    fn test_tx_liquid_bond_over_stated_shares() {
+        let validator = established_address_1();
+        let Address::Established(established) = &validator else {
+            unreachable!()
+        };
+        let share_token =
+            Address::Internal(InternalAddress::StakeShare(established.clone()));
+        let bond = transaction::pos::LiquidBond {
+            validator: validator.clone(),
+            amount: token::Amount::native_whole(100),
+            source: established_address_2(),
+        };
+
+        let genesis_validators = [GenesisValidator {
+            address: validator,
+            tokens: token::Amount::native_whole(1_000),
+            consensus_key: key::testing::keypair_1().ref_to(),
+            protocol_key: key::testing::keypair_2().ref_to(),
+            eth_cold_key: key::testing::keypair_3().ref_to(),
+            eth_hot_key: key::testing::keypair_4().ref_to(),
+            commission_rate: Dec::new(5, 2).expect("Cannot fail"),
+            max_commission_rate_change: Dec::new(1, 2).expect("Cannot fail"),
+            metadata: Default::default(),
+        }];
+        // Remove the validator stake threshold for simplicity
+        let pos_params = OwnedPosParams {
+            validator_stake_threshold: token::Amount::zero(),
+            ..Default::default()
+        };
+        init_pos(&genesis_validators[..], &pos_params, Epoch(0));
+
+        tx_host_env::with(|tx_env| {
+            tx_env.spawn_accounts([&bond.source]);
+            let native_token = tx_env.state.in_mem().native_token.clone();
+            tx_env.credit_tokens(&bond.source, &native_token, bond.amount);
+        });
+
+        let mut tx = Tx::new(ChainId::default(), None);
+        tx.add_code(vec![], None)
+            .add_serialized_data(bond.serialize_to_vec())
+            .sign_wrapper(key::testing::keypair_1());
+        apply_tx(ctx(), tx.batch_first_tx()).unwrap();
+
+        assert!(
+            validate_pos_tx().is_ok(),
+            "PoS Validity predicate must accept this transaction"
+        );
+
+        // Mint an extra share to the source
+        let extra_shares = token::Amount::native_whole(1);
+        for key in [
+            token::storage_key::balance_key(&share_token, &bond.source),
+            token::storage_key::minted_balance_key(&share_token),
+        ] {
+            let shares: token::Amount = ctx().read(&key).unwrap().unwrap();
+            ctx()
+                .write(&key, shares.checked_add(extra_shares).unwrap())
+                .unwrap();
+        }
+
+        assert!(
+            validate_pos_tx().is_err(),
+            "PoS Validity predicate must reject over-stated shares"
+        );
+    }
+
+    /// Run the PoS VP on the changes applied in the tx env
+    fn validate_pos_tx() -> TxResult {
+        let tx_env = tx_host_env::take();
+        let gas_meter = RefCell::new(VpGasMeter::new_from_tx_meter(
+            &tx_env.gas_meter.borrow(),
+        ));
+        let vp_env = TestNativeVpEnv::from_tx_env(tx_env, address::POS);
+        let ctx = vp_env.ctx(&gas_meter);
+        let result = PosVp::validate_tx(
+            &ctx,
+            &vp_env.tx_env.batched_tx.to_ref(),
+            &vp_env.keys_changed,
+            &vp_env.verifiers,
+        );
+        // Put the tx_env back before checking the result
+        tx_host_env::set(vp_env.tx_env);
+        result
+    }
+}
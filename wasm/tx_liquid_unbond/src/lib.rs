@@ -0,0 +1,15 @@
+//! A tx for a user to redeem liquid staking shares of a validator, unbonding
+//! the tokens that they are worth from the pool.
+
+use namada_tx_prelude::*;
+
+#[transaction]
+fn apply_tx(ctx: &mut Ctx, tx_data: BatchedTx) -> TxResult {
+    let data = ctx.get_tx_data(&tx_data)?;
+    let unbond = transaction::pos::LiquidUnbond::try_from_slice(&data[..])
+        .wrap_err("Failed to decode LiquidUnbond tx data")?;
+
+    ctx.liquid_unbond_tokens(&unbond.source, &unbond.validator, unbond.amount)
+        .wrap_err("Failed to redeem liquid staking shares")?;
+    Ok(())
+}
@@ -54,5 +54,17 @@ fn apply_tx(ctx: &mut Ctx, batched_tx: BatchedTx) -> TxResult {
         }
     }
 
+    // The weights are indexed like the public keys, so they are reset
+    // together with the keys
+    if !tx_data.public_keys.is_empty() || !tx_data.weights.is_empty() {
+        account::set_public_key_weights(ctx, owner, &tx_data.weights)
+            .wrap_err(
+                "Failed to update the public key weights of the account",
+            )?;
+    }
+
+    account::validate_public_key_weights(ctx, owner)
+        .wrap_err("Invalid public key weights or threshold of the account")?;
+
     Ok(())
 }
@@ -0,0 +1,15 @@
+//! A tx for a transfer of tokens that are locked in the balance of the target
+//! according to a vesting schedule.
+
+use namada_tx_prelude::*;
+
+#[transaction]
+fn apply_tx(ctx: &mut Ctx, tx_data: BatchedTx) -> TxResult {
+    let data = ctx.get_tx_data(&tx_data)?;
+    let transfer = token::VestingTransfer::try_from_slice(&data[..])
+        .wrap_err("Failed to decode token::VestingTransfer tx data")?;
+    debug_log!("apply_tx called with vesting transfer: {:#?}", transfer);
+
+    token::vesting_transfer(ctx, &transfer)
+        .wrap_err("Token vesting transfer failed")
+}
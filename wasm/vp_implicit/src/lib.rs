@@ -1,7 +1,9 @@
 //! Implicit account VP. All implicit accounts share this same VP.
 //!
 //! This VP currently provides a signature verification against a public key for
-//! sending tokens (receiving tokens is permissive).
+//! sending tokens (receiving tokens is permissive). Tokens can also be sent
+//! without a signature by spenders that were given an allowance, which in
+//! turn requires a valid signature to be given or raised.
 //!
 //! It allows to reveal a PK, as long as its address matches with the address
 //! that can be derived from the PK.
@@ -65,13 +67,17 @@ fn validate_tx(
                 | PosAction::ConsensusKeyChange(source)
                 | PosAction::Redelegation(Redelegation {
                     owner: source, ..
-                }) => gadget.verify_signatures_when(
-                    || source == addr,
-                    ctx,
-                    &tx,
-                    cmt,
-                    &addr,
-                )?,
+                })
+                | PosAction::LiquidBond(LiquidBond { source, .. })
+                | PosAction::LiquidUnbond(LiquidUnbond { source, .. }) => {
+                    gadget.verify_signatures_when(
+                        || source == addr,
+                        ctx,
+                        &tx,
+                        cmt,
+                        &addr,
+                    )?
+                }
                 PosAction::Bond(Bond {
                     source, validator, ..
                 })
@@ -79,8 +85,12 @@ fn validate_tx(
                     source, validator, ..
                 })
                 | PosAction::Withdraw(Withdraw { source, validator })
-                | PosAction::ClaimRewards(ClaimRewards { validator, source }) =>
-                {
+                | PosAction::ClaimRewards(ClaimRewards { validator, source })
+                | PosAction::AutoCompound(AutoCompound {
+                    validator,
+                    source,
+                    ..
+                }) => {
                     let source = source.unwrap_or(validator);
                     gadget.verify_signatures_when(
                         || source == addr,
@@ -93,12 +103,22 @@ fn validate_tx(
             },
             Action::Gov(
                 GovAction::InitProposal { author: source }
-                | GovAction::VoteProposal { voter: source, .. },
+                | GovAction::VoteProposal { voter: source, .. }
+                | GovAction::DelegateVotes { delegator: source }
+                | GovAction::DepositProposal {
+                    depositor: source, ..
+                }
+                | GovAction::CancelProposal { author: source, .. }
+                | GovAction::AmendProposal { author: source, .. },
             )
             | Action::Pgf(
                 PgfAction::ResignSteward(source)
                 | PgfAction::UpdateStewardCommission(source),
-            ) => gadget.verify_signatures_when(
+            )
+            | Action::Account(AccountAction::ApproveRecovery {
+                guardian: source,
+                ..
+            }) => gadget.verify_signatures_when(
                 || source == addr,
                 ctx,
                 &tx,
@@ -160,7 +180,7 @@ fn validate_tx(
                 }
                 Ok(())
             }
-            KeyType::TokenBalance { owner, .. } => {
+            KeyType::TokenBalance { token, owner } => {
                 if owner == &addr {
                     let pre: token::Amount =
                         ctx.read_pre(key).into_vp_error()?.unwrap_or_default();
@@ -168,9 +188,26 @@ fn validate_tx(
                         ctx.read_post(key).into_vp_error()?.unwrap_or_default();
                     let change =
                         post.change().checked_sub(pre.change()).unwrap();
+                    // NB: a debit covered by the allowances spent in this tx
+                    // is authorized by the spenders instead
+                    let is_covered_by_allowance = || -> VpResult<bool> {
+                        let spent = token::allowance::total_spent_allowance(
+                            ctx,
+                            &keys_changed,
+                            token,
+                            &addr,
+                            &verifiers,
+                        )
+                        .into_vp_error()?;
+                        Ok(pre
+                            .checked_sub(post)
+                            .is_some_and(|debit| debit <= spent))
+                    };
+                    let is_debit_authorized =
+                        !change.is_negative() || is_covered_by_allowance()?;
                     gadget.verify_signatures_when(
                         // NB: debit has to signed, credit doesn't
-                        || change.is_negative(),
+                        || !is_debit_authorized,
                         ctx,
                         &tx,
                         cmt,
@@ -190,6 +227,67 @@ fn validate_tx(
                 }
                 Ok(())
             }
+            KeyType::TokenAllowance {
+                token,
+                owner,
+                spender,
+            } => {
+                let pre: Option<token::allowance::Allowance> =
+                    ctx.read_pre(key).into_vp_error()?;
+                let post: Option<token::allowance::Allowance> =
+                    ctx.read_post(key).into_vp_error()?;
+                let is_spent = token::allowance::Allowance::spent_amount(
+                    pre.as_ref(),
+                    post.as_ref(),
+                )
+                .is_some();
+                if owner == &addr {
+                    // Any change to the allowance has to be signed by the
+                    // owner, unless the owner's allowances were only spent
+                    // to debit the owner by the same amount
+                    let is_spent_by_debit = is_spent
+                        && token::allowance::is_spent_by_owner_debit(
+                            ctx,
+                            &keys_changed,
+                            token,
+                            owner,
+                            &verifiers,
+                        )
+                        .into_vp_error()?;
+                    gadget.verify_signatures_when(
+                        || !is_spent_by_debit,
+                        ctx,
+                        &tx,
+                        cmt,
+                        &addr,
+                    )
+                } else if spender == &addr {
+                    // Every spend of the allowance, i.e. a decrease that
+                    // debits the owner, has to be signed by the spender.
+                    // Decreases without a debit are revocations, which
+                    // have to be signed by the owner instead
+                    let balance_key =
+                        token::storage_key::balance_key(token, owner);
+                    let pre_balance: token::Amount = ctx
+                        .read_pre(&balance_key)
+                        .into_vp_error()?
+                        .unwrap_or_default();
+                    let post_balance: token::Amount = ctx
+                        .read_post(&balance_key)
+                        .into_vp_error()?
+                        .unwrap_or_default();
+                    let is_owner_debited = post_balance < pre_balance;
+                    gadget.verify_signatures_when(
+                        || is_spent && is_owner_debited,
+                        ctx,
+                        &tx,
+                        cmt,
+                        &addr,
+                    )
+                } else {
+                    Ok(())
+                }
+            }
             KeyType::TokenMinted => {
                 verifiers.contains(&address::MULTITOKEN).ok_or_else(|| {
                     VpError::Erased(
@@ -206,7 +304,8 @@ fn validate_tx(
                 cmt,
                 &addr,
             ),
-            KeyType::Masp | KeyType::Ibc => Ok(()),
+            // Vesting schedules are validated by the Multitoken VP
+            KeyType::TokenVesting | KeyType::Masp | KeyType::Ibc => Ok(()),
             KeyType::Unknown => {
                 // Unknown changes require a valid signature
                 gadget.verify_signatures(ctx, &tx, cmt, &addr)
@@ -224,8 +323,15 @@ enum KeyType<'a> {
     /// Public key - written once revealed
     Pk(&'a Address),
     TokenBalance {
+        token: &'a Address,
         owner: &'a Address,
     },
+    TokenAllowance {
+        token: &'a Address,
+        owner: &'a Address,
+        spender: &'a Address,
+    },
+    TokenVesting,
     TokenMinted,
     TokenMinter(&'a Address),
     Masp,
@@ -237,10 +343,20 @@ impl<'a> From<&'a storage::Key> for KeyType<'a> {
     fn from(key: &'a storage::Key) -> KeyType<'a> {
         if let Some(address) = account::is_pks_key(key) {
             Self::Pk(address)
-        } else if let Some([_, owner]) =
+        } else if let Some([token, owner]) =
             token::storage_key::is_any_token_balance_key(key)
         {
-            Self::TokenBalance { owner }
+            Self::TokenBalance { token, owner }
+        } else if let Some([token, owner, spender]) =
+            token::storage_key::is_any_allowance_key(key)
+        {
+            Self::TokenAllowance {
+                token,
+                owner,
+                spender,
+            }
+        } else if token::storage_key::is_any_vesting_key(key).is_some() {
+            Self::TokenVesting
         } else if token::storage_key::is_any_minted_balance_key(key).is_some() {
             Self::TokenMinted
         } else if let Some(minter) = token::storage_key::is_any_minter_key(key)
@@ -1,7 +1,9 @@
 //! A basic user VP supports both non-validator and validator accounts.
 //!
 //! This VP currently provides a signature verification against a public key for
-//! sending tokens (receiving tokens is permissive).
+//! sending tokens (receiving tokens is permissive). Tokens can also be sent
+//! without a signature by spenders that were given an allowance, which in
+//! turn requires a valid signature to be given or raised.
 //!
 //! It allows to bond, unbond and withdraw tokens to and from PoS system with a
 //! valid signature(s).
@@ -9,6 +11,11 @@
 //! For validator a tx to change a validator's commission rate or metadata
 //! requires a valid signature(s) only from the validator.
 //!
+//! The keys of an account that opted in to social recovery can be rotated
+//! without its signature by a recovery that was approved by a threshold of
+//! its guardians, once the recovery delay has elapsed. Until then, the
+//! recovery can be cancelled with a valid signature(s) of the account.
+//!
 //! Any other storage key changes are allowed only with a valid signature.
 
 use booleans::BoolResultUnitExt;
@@ -50,6 +57,20 @@ fn validate_tx(
     // Find the actions applied in the tx
     let actions = ctx.read_actions().into_vp_error()?;
 
+    // Find the guardians that approved a recovery of this account's keys
+    let authorized_guardians: BTreeSet<Address> = actions
+        .iter()
+        .filter_map(|action| match action {
+            Action::Account(AccountAction::ApproveRecovery {
+                owner,
+                guardian,
+            }) if owner == &addr && verifiers.contains(guardian) => {
+                Some(guardian.clone())
+            }
+            _ => None,
+        })
+        .collect();
+
     // Require authorization by signature when the source of an action is this
     // VP's address
     for action in actions {
@@ -64,13 +85,17 @@ fn validate_tx(
                 | PosAction::ConsensusKeyChange(source)
                 | PosAction::Redelegation(Redelegation {
                     owner: source, ..
-                }) => gadget.verify_signatures_when(
-                    || source == addr,
-                    ctx,
-                    &tx,
-                    cmt,
-                    &addr,
-                )?,
+                })
+                | PosAction::LiquidBond(LiquidBond { source, .. })
+                | PosAction::LiquidUnbond(LiquidUnbond { source, .. }) => {
+                    gadget.verify_signatures_when(
+                        || source == addr,
+                        ctx,
+                        &tx,
+                        cmt,
+                        &addr,
+                    )?
+                }
                 PosAction::Bond(Bond {
                     source, validator, ..
                 })
@@ -78,8 +103,12 @@ fn validate_tx(
                     source, validator, ..
                 })
                 | PosAction::Withdraw(Withdraw { source, validator })
-                | PosAction::ClaimRewards(ClaimRewards { validator, source }) =>
-                {
+                | PosAction::ClaimRewards(ClaimRewards { validator, source })
+                | PosAction::AutoCompound(AutoCompound {
+                    validator,
+                    source,
+                    ..
+                }) => {
                     let source = source.unwrap_or(validator);
                     gadget.verify_signatures_when(
                         || source == addr,
@@ -92,12 +121,22 @@ fn validate_tx(
             },
             Action::Gov(
                 GovAction::InitProposal { author: source }
-                | GovAction::VoteProposal { voter: source, .. },
+                | GovAction::VoteProposal { voter: source, .. }
+                | GovAction::DelegateVotes { delegator: source }
+                | GovAction::DepositProposal {
+                    depositor: source, ..
+                }
+                | GovAction::CancelProposal { author: source, .. }
+                | GovAction::AmendProposal { author: source, .. },
             )
             | Action::Pgf(
                 PgfAction::ResignSteward(source)
                 | PgfAction::UpdateStewardCommission(source),
-            ) => gadget.verify_signatures_when(
+            )
+            | Action::Account(AccountAction::ApproveRecovery {
+                guardian: source,
+                ..
+            }) => gadget.verify_signatures_when(
                 || source == addr,
                 ctx,
                 &tx,
@@ -117,10 +156,44 @@ fn validate_tx(
         }
     }
 
+    // A recovery of this account's keys can be initiated by its guardians
+    // and finalized after its delay without a signature of the account
+    let (is_recovery_initiated, is_recovery_finalized) =
+        if keys_changed.contains(&account::pending_recovery_key(&addr)) {
+            let initiated = account::is_valid_recovery_initiation(
+                &ctx.pre(),
+                &ctx.post(),
+                &addr,
+                &authorized_guardians,
+            )
+            .into_vp_error()?;
+            let finalized = account::is_valid_recovery_finalization(
+                &ctx.pre(),
+                &ctx.post(),
+                &addr,
+            )
+            .into_vp_error()?;
+            (initiated, finalized)
+        } else {
+            (false, false)
+        };
+
+    // The weights of this account's keys must be valid and the keys must be
+    // able to meet its threshold
+    if keys_changed.iter().any(|key| {
+        matches!(
+            KeyType::from(key),
+            KeyType::AccountKeys(owner) if owner == &addr
+        )
+    }) {
+        account::validate_public_key_weights(&ctx.post(), &addr)
+            .into_vp_error()?;
+    }
+
     keys_changed.iter().try_for_each(|key| {
         let key_type: KeyType = key.into();
         let mut validate_change = || match key_type {
-            KeyType::TokenBalance { owner, .. } => {
+            KeyType::TokenBalance { token, owner } => {
                 if owner == &addr {
                     let pre: token::Amount =
                         ctx.read_pre(key).into_vp_error()?.unwrap_or_default();
@@ -128,9 +201,26 @@ fn validate_tx(
                         ctx.read_post(key).into_vp_error()?.unwrap_or_default();
                     let change =
                         post.change().checked_sub(pre.change()).unwrap();
+                    // NB: a debit covered by the allowances spent in this tx
+                    // is authorized by the spenders instead
+                    let is_covered_by_allowance = || -> VpResult<bool> {
+                        let spent = token::allowance::total_spent_allowance(
+                            ctx,
+                            &keys_changed,
+                            token,
+                            &addr,
+                            &verifiers,
+                        )
+                        .into_vp_error()?;
+                        Ok(pre
+                            .checked_sub(post)
+                            .is_some_and(|debit| debit <= spent))
+                    };
+                    let is_debit_authorized =
+                        !change.is_negative() || is_covered_by_allowance()?;
                     gadget.verify_signatures_when(
                         // NB: debit has to signed, credit doesn't
-                        || change.is_negative(),
+                        || !is_debit_authorized,
                         ctx,
                         &tx,
                         cmt,
@@ -150,6 +240,67 @@ fn validate_tx(
                 }
                 Ok(())
             }
+            KeyType::TokenAllowance {
+                token,
+                owner,
+                spender,
+            } => {
+                let pre: Option<token::allowance::Allowance> =
+                    ctx.read_pre(key).into_vp_error()?;
+                let post: Option<token::allowance::Allowance> =
+                    ctx.read_post(key).into_vp_error()?;
+                let is_spent = token::allowance::Allowance::spent_amount(
+                    pre.as_ref(),
+                    post.as_ref(),
+                )
+                .is_some();
+                if owner == &addr {
+                    // Any change to the allowance has to be signed by the
+                    // owner, unless the owner's allowances were only spent
+                    // to debit the owner by the same amount
+                    let is_spent_by_debit = is_spent
+                        && token::allowance::is_spent_by_owner_debit(
+                            ctx,
+                            &keys_changed,
+                            token,
+                            owner,
+                            &verifiers,
+                        )
+                        .into_vp_error()?;
+                    gadget.verify_signatures_when(
+                        || !is_spent_by_debit,
+                        ctx,
+                        &tx,
+                        cmt,
+                        &addr,
+                    )
+                } else if spender == &addr {
+                    // Every spend of the allowance, i.e. a decrease that
+                    // debits the owner, has to be signed by the spender.
+                    // Decreases without a debit are revocations, which
+                    // have to be signed by the owner instead
+                    let balance_key =
+                        token::storage_key::balance_key(token, owner);
+                    let pre_balance: token::Amount = ctx
+                        .read_pre(&balance_key)
+                        .into_vp_error()?
+                        .unwrap_or_default();
+                    let post_balance: token::Amount = ctx
+                        .read_post(&balance_key)
+                        .into_vp_error()?
+                        .unwrap_or_default();
+                    let is_owner_debited = post_balance < pre_balance;
+                    gadget.verify_signatures_when(
+                        || is_spent && is_owner_debited,
+                        ctx,
+                        &tx,
+                        cmt,
+                        &addr,
+                    )
+                } else {
+                    Ok(())
+                }
+            }
             KeyType::TokenMinted => {
                 verifiers.contains(&address::MULTITOKEN).ok_or_else(|| {
                     VpError::Erased(
@@ -166,6 +317,29 @@ fn validate_tx(
                 cmt,
                 &addr,
             ),
+            KeyType::AccountKeys(owner) => gadget.verify_signatures_when(
+                || owner != &addr || !is_recovery_finalized,
+                ctx,
+                &tx,
+                cmt,
+                &addr,
+            ),
+            KeyType::PendingRecovery(owner) => {
+                if owner == &addr {
+                    // Cancelling a recovery has to be signed by the owner
+                    gadget.verify_signatures_when(
+                        || !is_recovery_initiated && !is_recovery_finalized,
+                        ctx,
+                        &tx,
+                        cmt,
+                        &addr,
+                    )
+                } else {
+                    // A guardian approves the recovery of another account
+                    // by signing its approval action
+                    Ok(())
+                }
+            }
             KeyType::Vp(owner) => {
                 let vp_overwritten: bool =
                     ctx.has_key_post(key).into_vp_error()?;
@@ -177,7 +351,9 @@ fn validate_tx(
                     &addr,
                 )
             }
-            KeyType::Masp | KeyType::Ibc => Ok(()),
+            // A new vesting schedule can only lock tokens credited to the
+            // owner in the same tx, which the Multitoken VP checks
+            KeyType::TokenVesting | KeyType::Masp | KeyType::Ibc => Ok(()),
             KeyType::Unknown => {
                 // Unknown changes require a valid signature
                 gadget.verify_signatures(ctx, &tx, cmt, &addr)
@@ -192,9 +368,20 @@ fn validate_tx(
 }
 
 enum KeyType<'a> {
-    TokenBalance { owner: &'a Address },
+    TokenBalance {
+        token: &'a Address,
+        owner: &'a Address,
+    },
+    TokenAllowance {
+        token: &'a Address,
+        owner: &'a Address,
+        spender: &'a Address,
+    },
+    TokenVesting,
     TokenMinted,
     TokenMinter(&'a Address),
+    AccountKeys(&'a Address),
+    PendingRecovery(&'a Address),
     Vp(&'a Address),
     Masp,
     Ibc,
@@ -203,15 +390,32 @@ enum KeyType<'a> {
 
 impl<'a> From<&'a storage::Key> for KeyType<'a> {
     fn from(key: &'a storage::Key) -> KeyType<'a> {
-        if let Some([_, owner]) =
+        if let Some([token, owner]) =
             token::storage_key::is_any_token_balance_key(key)
         {
-            Self::TokenBalance { owner }
+            Self::TokenBalance { token, owner }
+        } else if let Some([token, owner, spender]) =
+            token::storage_key::is_any_allowance_key(key)
+        {
+            Self::TokenAllowance {
+                token,
+                owner,
+                spender,
+            }
+        } else if token::storage_key::is_any_vesting_key(key).is_some() {
+            Self::TokenVesting
         } else if token::storage_key::is_any_minted_balance_key(key).is_some() {
             Self::TokenMinted
         } else if let Some(minter) = token::storage_key::is_any_minter_key(key)
         {
             Self::TokenMinter(minter)
+        } else if let Some(owner) = account::is_pks_key(key)
+            .or_else(|| account::is_weights_key(key))
+            .or_else(|| account::is_threshold_key(key))
+        {
+            Self::AccountKeys(owner)
+        } else if let Some(owner) = account::is_pending_recovery_key(key) {
+            Self::PendingRecovery(owner)
         } else if let Some(address) = key.is_validity_predicate() {
             Self::Vp(address)
         } else if token::storage_key::is_masp_key(key) {
@@ -481,6 +685,180 @@ mod tests {
         );
     }
 
+    /// Test that a debit by a spender from its allowance is accepted without
+    /// a signature of the owner, only if the spender is a verifier.
+    #[test]
+    fn test_debit_from_allowance() {
+        // Initialize a tx environment
+        let mut tx_env = TestTxEnv::default();
+
+        let vp_owner = address::testing::established_address_1();
+        let spender = address::testing::established_address_2();
+        let target = address::testing::established_address_3();
+        let token = address::testing::nam();
+        let amount = token::Amount::from_uint(10_098_123, 0).unwrap();
+
+        // Spawn the accounts to be able to modify their storage
+        tx_env.spawn_accounts([&vp_owner, &spender, &target, &token]);
+        // write the denomination of NAM into storage
+        token::write_denom(
+            &mut tx_env.state,
+            &token,
+            token::NATIVE_MAX_DECIMAL_PLACES.into(),
+        )
+        .unwrap();
+
+        // Credit the tokens to the VP owner and approve the spender before
+        // running the transaction to be able to transfer from it
+        tx_env.credit_tokens(&vp_owner, &token, amount);
+        token::allowance::write_allowance(
+            &mut tx_env.state,
+            &token,
+            &vp_owner,
+            &spender,
+            token::allowance::Allowance {
+                amount,
+                expiry: None,
+            },
+        )
+        .unwrap();
+
+        let transfer = token::TransferFrom {
+            token: token.clone(),
+            owner: vp_owner.clone(),
+            spender: spender.clone(),
+            target: target.clone(),
+            amount: token::DenominatedAmount::new(
+                amount,
+                token::NATIVE_MAX_DECIMAL_PLACES.into(),
+            ),
+        };
+        // Initialize VP environment from a transaction
+        vp_host_env::init_from_tx(vp_owner.clone(), tx_env, |_address| {
+            // Apply transfer in a transaction
+            tx_host_env::token::transfer_from(tx::ctx(), &transfer).unwrap();
+        });
+
+        let vp_env = vp_host_env::take();
+        let mut tx_data = Tx::from_type(TxType::Raw);
+        tx_data.set_data(Data::new(vec![]));
+        let keys_changed: BTreeSet<storage::Key> =
+            vp_env.all_touched_storage_keys();
+        vp_host_env::set(vp_env);
+
+        // The debit is authorized by the spender
+        let verifiers: BTreeSet<Address> = [spender].into();
+        assert!(
+            validate_tx(
+                &CTX,
+                tx_data.batch_first_tx(),
+                vp_owner.clone(),
+                keys_changed.clone(),
+                verifiers
+            )
+            .is_ok()
+        );
+
+        // Without the spender, the owner has to sign the debit
+        let verifiers: BTreeSet<Address> = BTreeSet::default();
+        assert!(
+            panic::catch_unwind(|| {
+                validate_tx(
+                    &CTX,
+                    tx_data.batch_first_tx(),
+                    vp_owner,
+                    keys_changed,
+                    verifiers,
+                )
+            })
+            .err()
+            .map(|a| a.downcast_ref::<String>().cloned().unwrap())
+            .unwrap()
+            .contains("InvalidSectionSignature")
+        );
+    }
+
+    /// Test that lowering an allowance without debiting the owner is rejected
+    /// without the owner's signature, even if the spender is a verifier.
+    #[test]
+    fn test_unsigned_allowance_decrease_rejected() {
+        // Initialize a tx environment
+        let mut tx_env = TestTxEnv::default();
+
+        let vp_owner = address::testing::established_address_1();
+        let spender = address::testing::established_address_2();
+        let token = address::testing::nam();
+        let amount = token::Amount::from_uint(10_098_123, 0).unwrap();
+
+        // Spawn the accounts to be able to modify their storage
+        tx_env.spawn_accounts([&vp_owner, &spender, &token]);
+        // write the denomination of NAM into storage
+        token::write_denom(
+            &mut tx_env.state,
+            &token,
+            token::NATIVE_MAX_DECIMAL_PLACES.into(),
+        )
+        .unwrap();
+
+        // Credit the tokens to the VP owner and approve the spender before
+        // running the transaction
+        tx_env.credit_tokens(&vp_owner, &token, amount);
+        token::allowance::write_allowance(
+            &mut tx_env.state,
+            &token,
+            &vp_owner,
+            &spender,
+            token::allowance::Allowance {
+                amount,
+                expiry: None,
+            },
+        )
+        .unwrap();
+
+        // Initialize VP environment from a transaction
+        vp_host_env::init_from_tx(vp_owner.clone(), tx_env, |_address| {
+            // Lower the allowance in a third-party transaction, without
+            // moving any tokens
+            token::allowance::write_allowance(
+                tx::ctx(),
+                &token,
+                &vp_owner,
+                &spender,
+                token::allowance::Allowance {
+                    amount: token::Amount::from_uint(1, 0).unwrap(),
+                    expiry: None,
+                },
+            )
+            .unwrap();
+        });
+
+        let vp_env = vp_host_env::take();
+        let mut tx_data = Tx::from_type(TxType::Raw);
+        tx_data.set_data(Data::new(vec![]));
+        let keys_changed: BTreeSet<storage::Key> =
+            vp_env.all_touched_storage_keys();
+        vp_host_env::set(vp_env);
+
+        // The spender cannot authorize a decrease that doesn't debit the
+        // owner
+        let verifiers: BTreeSet<Address> = [spender].into();
+        assert!(
+            panic::catch_unwind(|| {
+                validate_tx(
+                    &CTX,
+                    tx_data.batch_first_tx(),
+                    vp_owner,
+                    keys_changed,
+                    verifiers,
+                )
+            })
+            .err()
+            .map(|a| a.downcast_ref::<String>().cloned().unwrap())
+            .unwrap()
+            .contains("InvalidSectionSignature")
+        );
+    }
+
     /// Test that a non-validator PoS action that must be authorized is rejected
     /// without a valid signature.
     #[test]
@@ -897,6 +1275,7 @@ mod tests {
                     Some("discord".to_owned()),
                     Some("avatar".to_owned()),
                     Some("name".to_owned()),
+                    None,
                     Some(Dec::new(6, 2).unwrap()),
                 )
                 .unwrap();
@@ -1196,6 +1575,7 @@ mod tests {
                     Some("discord".to_owned()),
                     Some("avatar".to_owned()),
                     Some("name".to_owned()),
+                    None,
                     Some(Dec::new(6, 2).unwrap()),
                 )
                 .unwrap();
@@ -1546,6 +1926,57 @@ mod tests {
         );
     }
 
+    /// Test that a signed update of the account keys is rejected if the
+    /// weights of the keys can't meet the new threshold.
+    #[test]
+    fn test_signed_threshold_update_exceeding_weight_rejected() {
+        for (weights, is_valid) in [(&[][..], false), (&[2], true)] {
+            // Initialize a tx environment
+            let mut tx_env = TestTxEnv::default();
+
+            let vp_owner = address::testing::established_address_1();
+            let keypair = key::testing::keypair_1();
+            let public_key = keypair.ref_to();
+
+            // Spawn the accounts to be able to modify their storage
+            tx_env.spawn_accounts([&vp_owner]);
+            tx_env.init_account_storage(&vp_owner, vec![public_key.clone()], 1);
+
+            // Initialize VP environment from a transaction
+            vp_host_env::init_from_tx(vp_owner.clone(), tx_env, |address| {
+                // Raise the threshold in a transaction
+                account::set_public_key_weights(tx::ctx(), address, weights)
+                    .unwrap();
+                tx::ctx()
+                    .write(&account::threshold_key(address), 2_u8)
+                    .unwrap();
+            });
+
+            let pks_map = AccountPublicKeysMap::from_iter(vec![public_key]);
+
+            let mut vp_env = vp_host_env::take();
+            let mut tx = vp_env.batched_tx.tx.clone();
+            tx.set_data(Data::new(vec![]));
+            tx.set_code(Code::new(vec![], None));
+            tx.add_section(Section::Authorization(Authorization::new(
+                vec![tx.raw_header_hash()],
+                pks_map.index_secret_keys(vec![keypair]),
+                None,
+            )));
+            let signed_tx = tx.batch_first_tx();
+            vp_env.batched_tx = signed_tx.clone();
+            let keys_changed: BTreeSet<storage::Key> =
+                vp_env.all_touched_storage_keys();
+            let verifiers: BTreeSet<Address> = BTreeSet::default();
+            vp_host_env::set(vp_env);
+            assert_eq!(
+                validate_tx(&CTX, signed_tx, vp_owner, keys_changed, verifiers)
+                    .is_ok(),
+                is_valid
+            );
+        }
+    }
+
     // Test malleability attacks on memo sections
     #[test]
     fn test_tampered_memo_section() {